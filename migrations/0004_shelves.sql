@@ -0,0 +1,24 @@
+-- User-defined shelves: manual (hand-picked, ordered) or smart (saved filter query)
+
+CREATE TABLE shelves (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    description TEXT,
+    kind TEXT NOT NULL DEFAULT 'manual' CHECK (kind IN ('manual', 'smart')),
+    query TEXT CHECK ((kind = 'smart') = (query IS NOT NULL)),
+    pinned INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    UNIQUE(user_id, name)
+);
+CREATE INDEX idx_shelves_user_id ON shelves(user_id);
+
+CREATE TABLE shelf_books (
+    shelf_id INTEGER NOT NULL REFERENCES shelves(id) ON DELETE CASCADE,
+    book_id INTEGER NOT NULL REFERENCES books(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    added_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    PRIMARY KEY (shelf_id, book_id)
+);
+CREATE INDEX idx_shelf_books_book_id ON shelf_books(book_id);
+CREATE INDEX idx_shelf_books_position ON shelf_books(shelf_id, position);
@@ -0,0 +1,11 @@
+-- Personal tags: free-form labels a user attaches to books
+
+CREATE TABLE book_tags (
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    book_id INTEGER NOT NULL REFERENCES books(id) ON DELETE CASCADE,
+    tag TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    PRIMARY KEY (user_id, book_id, tag)
+);
+CREATE INDEX idx_book_tags_user_tag ON book_tags(user_id, tag);
+CREATE INDEX idx_book_tags_book_id ON book_tags(book_id);
@@ -0,0 +1,4 @@
+-- Genre hierarchy: an optional parent genre ("Space Opera" under "Science Fiction")
+
+ALTER TABLE genres ADD COLUMN parent_id INTEGER REFERENCES genres(id) ON DELETE SET NULL;
+CREATE INDEX idx_genres_parent_id ON genres(parent_id);
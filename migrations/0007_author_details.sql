@@ -0,0 +1,9 @@
+-- Author details: biography, life dates, nationality, website and pen names
+
+ALTER TABLE authors ADD COLUMN bio TEXT;
+ALTER TABLE authors ADD COLUMN birth_year INTEGER;
+ALTER TABLE authors ADD COLUMN death_year INTEGER;
+ALTER TABLE authors ADD COLUMN nationality TEXT;
+ALTER TABLE authors ADD COLUMN website TEXT;
+-- JSON array of alternative names, matched alongside `name` when looking authors up
+ALTER TABLE authors ADD COLUMN aliases_json TEXT NOT NULL DEFAULT '[]';
@@ -0,0 +1,14 @@
+-- Per-user reading targets, counted in books or pages over a date range
+
+CREATE TABLE reading_goals (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    metric TEXT NOT NULL DEFAULT 'books' CHECK (metric IN ('books', 'pages')),
+    target INTEGER NOT NULL CHECK (target > 0),
+    start_date TEXT NOT NULL,
+    end_date TEXT NOT NULL CHECK (end_date >= start_date),
+    genre_id INTEGER REFERENCES genres(id) ON DELETE CASCADE,
+    format TEXT CHECK (format IS NULL OR format IN ('physical', 'ereader', 'audiobook')),
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
+CREATE INDEX idx_reading_goals_user_id ON reading_goals(user_id, start_date);
@@ -0,0 +1,36 @@
+-- Reading challenges: lists of prompts, each optionally matched by a filter rule.
+-- Shared challenges are visible to every user; entries track each user's progress.
+
+CREATE TABLE challenges (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    description TEXT,
+    year INTEGER,
+    shared INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
+CREATE INDEX idx_challenges_user_id ON challenges(user_id);
+CREATE INDEX idx_challenges_shared ON challenges(shared) WHERE shared = 1;
+
+CREATE TABLE challenge_prompts (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    challenge_id INTEGER NOT NULL REFERENCES challenges(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    label TEXT NOT NULL,
+    rule TEXT
+);
+CREATE INDEX idx_challenge_prompts_position ON challenge_prompts(challenge_id, position);
+
+CREATE TABLE challenge_entries (
+    challenge_id INTEGER NOT NULL REFERENCES challenges(id) ON DELETE CASCADE,
+    prompt_id INTEGER NOT NULL REFERENCES challenge_prompts(id) ON DELETE CASCADE,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    reading_id INTEGER NOT NULL REFERENCES readings(id) ON DELETE CASCADE,
+    assigned_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    PRIMARY KEY (prompt_id, user_id),
+    -- A reading counts towards at most one prompt per challenge
+    UNIQUE (challenge_id, user_id, reading_id)
+);
+CREATE INDEX idx_challenge_entries_user_id ON challenge_entries(user_id, challenge_id);
+CREATE INDEX idx_challenge_entries_reading_id ON challenge_entries(reading_id);
@@ -0,0 +1,11 @@
+-- Per-year reading aggregates behind the all-time stats cache, so a reading
+-- change only recomputes the years it touches. Year 0 holds readings without
+-- the date that would place them in a year.
+
+CREATE TABLE stats_year_partials (
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    year INTEGER NOT NULL,
+    data TEXT NOT NULL,
+    computed_at TEXT NOT NULL DEFAULT (datetime('now')),
+    PRIMARY KEY (user_id, year)
+);
@@ -0,0 +1,10 @@
+-- Instance-wide stats across every user who hasn't opted out, cached as a
+-- single row next to the per-user stats cache.
+
+ALTER TABLE users ADD COLUMN household_opt_out INTEGER NOT NULL DEFAULT 0;
+
+CREATE TABLE household_stats_cache (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    data TEXT NOT NULL,
+    computed_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
@@ -0,0 +1,18 @@
+-- Audiobook running time, so listening can be counted in hours rather than
+-- pages, and narrators as a contributor role.
+
+ALTER TABLE books ADD COLUMN duration_minutes INTEGER;
+
+-- SQLite can't alter a CHECK constraint, so rebuild book_authors with the new role
+CREATE TABLE book_authors_new (
+    book_id INTEGER NOT NULL REFERENCES books(id) ON DELETE CASCADE,
+    author_id INTEGER NOT NULL REFERENCES authors(id) ON DELETE CASCADE,
+    role TEXT NOT NULL DEFAULT 'author' CHECK (role IN ('author', 'editor', 'translator', 'narrator')),
+    PRIMARY KEY (book_id, author_id, role)
+);
+-- Keep rowid order, which is the order authors are listed in
+INSERT INTO book_authors_new (book_id, author_id, role)
+    SELECT book_id, author_id, role FROM book_authors ORDER BY rowid;
+DROP TABLE book_authors;
+ALTER TABLE book_authors_new RENAME TO book_authors;
+CREATE INDEX idx_book_authors_author ON book_authors(author_id);
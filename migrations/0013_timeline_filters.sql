@@ -0,0 +1,4 @@
+-- Timeline filters by type and action, newest first
+CREATE INDEX idx_timeline_type_occurred ON timeline_events(entity_type, occurred_at DESC);
+CREATE INDEX idx_timeline_action_occurred ON timeline_events(action, occurred_at DESC);
+CREATE INDEX idx_timeline_user_type_occurred ON timeline_events(user_id, entity_type, occurred_at DESC);
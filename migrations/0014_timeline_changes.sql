@@ -0,0 +1,30 @@
+-- Shelf moves, book club changes and goal milestones get their own entity
+-- types, and change events keep their before/after values.
+
+-- SQLite can't alter a CHECK constraint, so rebuild timeline_events
+CREATE TABLE timeline_events_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    entity_type TEXT NOT NULL CHECK (entity_type IN ('author', 'book', 'reading', 'genre', 'user_book', 'goal')),
+    entity_id INTEGER NOT NULL,
+    action TEXT NOT NULL,
+    occurred_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    title TEXT NOT NULL,
+    details_json TEXT,
+    genres_json TEXT,
+    reading_data_json TEXT,
+    changes_json TEXT,
+    user_id INTEGER REFERENCES users(id) ON DELETE SET NULL
+);
+INSERT INTO timeline_events_new (id, entity_type, entity_id, action, occurred_at, title, details_json, genres_json, reading_data_json, user_id)
+    SELECT id, entity_type, entity_id, action, occurred_at, title, details_json, genres_json, reading_data_json, user_id
+    FROM timeline_events;
+DROP TABLE timeline_events;
+ALTER TABLE timeline_events_new RENAME TO timeline_events;
+
+CREATE INDEX idx_timeline_events_entity ON timeline_events(entity_type, entity_id);
+CREATE INDEX idx_timeline_events_occurred_at ON timeline_events(occurred_at DESC);
+CREATE INDEX idx_timeline_events_user_id ON timeline_events(user_id);
+CREATE INDEX idx_timeline_user_occurred ON timeline_events(user_id, occurred_at DESC);
+CREATE INDEX idx_timeline_type_occurred ON timeline_events(entity_type, occurred_at DESC);
+CREATE INDEX idx_timeline_action_occurred ON timeline_events(action, occurred_at DESC);
+CREATE INDEX idx_timeline_user_type_occurred ON timeline_events(user_id, entity_type, occurred_at DESC);
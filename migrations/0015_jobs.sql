@@ -0,0 +1,20 @@
+-- Durable background job queue. Finished jobs are deleted; failed jobs stay
+-- for inspection on the admin page.
+CREATE TABLE jobs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    kind TEXT NOT NULL,
+    payload TEXT NOT NULL,
+    dedupe_key TEXT,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'running', 'failed')),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    max_attempts INTEGER NOT NULL DEFAULT 5,
+    run_at TEXT NOT NULL,
+    last_error TEXT,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+-- At most one pending job per dedupe key; a job already running doesn't block a new one
+CREATE UNIQUE INDEX idx_jobs_pending_dedupe ON jobs(dedupe_key)
+    WHERE status = 'pending' AND dedupe_key IS NOT NULL;
+CREATE INDEX idx_jobs_status_run_at ON jobs(status, run_at);
@@ -0,0 +1,14 @@
+-- Atom/RSS feeds of a user's timeline. Feeds of a public profile are open;
+-- otherwise each feed is read with its own secret token.
+
+ALTER TABLE users ADD COLUMN public_profile INTEGER NOT NULL DEFAULT 0;
+
+CREATE TABLE feed_tokens (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    feed TEXT NOT NULL CHECK (feed IN ('all', 'finished')),
+    token_hash TEXT NOT NULL UNIQUE,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    -- Regenerating a feed's token replaces the old one
+    UNIQUE (user_id, feed)
+);
@@ -0,0 +1,38 @@
+-- Minimal ActivityPub support. A user federates once they have an actor
+-- row, which holds the key pair their outgoing requests are signed with.
+
+CREATE TABLE activitypub_actors (
+    user_id INTEGER PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    -- Actor URIs must never change, so the origin they were minted under
+    -- is kept rather than recomputed from configuration
+    origin TEXT NOT NULL,
+    public_key_pem TEXT NOT NULL,
+    private_key_pem TEXT NOT NULL,
+    manually_approves_followers INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+CREATE TABLE activitypub_followers (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL REFERENCES activitypub_actors(user_id) ON DELETE CASCADE,
+    actor_uri TEXT NOT NULL,
+    inbox TEXT NOT NULL,
+    shared_inbox TEXT,
+    -- The remote Follow, echoed back in our Accept or Reject
+    follow_activity TEXT NOT NULL,
+    status TEXT NOT NULL CHECK (status IN ('pending', 'accepted')),
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    UNIQUE (user_id, actor_uri)
+);
+
+CREATE TABLE activitypub_outbox (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL REFERENCES activitypub_actors(user_id) ON DELETE CASCADE,
+    reading_id INTEGER NOT NULL,
+    -- The full Create activity as delivered
+    activity TEXT NOT NULL,
+    published_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    UNIQUE (user_id, reading_id)
+);
+
+CREATE INDEX idx_activitypub_outbox_user ON activitypub_outbox(user_id, published_at);
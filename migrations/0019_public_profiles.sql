@@ -0,0 +1,10 @@
+-- Public profile pages at /u/{username}, and what they reveal. Private
+-- readings are hidden from everyone but their reader.
+
+ALTER TABLE readings ADD COLUMN private INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE users ADD COLUMN profile_show_ratings INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE users ADD COLUMN profile_show_quick_reviews INTEGER NOT NULL DEFAULT 1;
+
+-- Saved shelves listed on the owner's public profile
+ALTER TABLE shelves ADD COLUMN public INTEGER NOT NULL DEFAULT 0;
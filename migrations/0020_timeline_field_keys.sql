@@ -0,0 +1,36 @@
+-- Key stored event details and changes by what they hold, so privacy
+-- redaction no longer depends on their display labels.
+
+UPDATE timeline_events
+SET details_json = (
+    SELECT json_group_array(
+        CASE json_extract(value, '$.label')
+            WHEN 'Author' THEN json_set(value, '$.key', 'author')
+            WHEN 'Format' THEN json_set(value, '$.key', 'format')
+            WHEN 'Rating' THEN json_set(value, '$.key', 'rating')
+            WHEN 'Notes' THEN json_set(value, '$.key', 'quick_reviews')
+            WHEN 'Genres' THEN json_set(value, '$.key', 'genres')
+            WHEN 'Pages' THEN json_set(value, '$.key', 'pages')
+            WHEN 'Progress' THEN json_set(value, '$.key', 'progress')
+            ELSE json(value)
+        END
+    )
+    FROM json_each(details_json)
+)
+WHERE details_json IS NOT NULL AND json_valid(details_json) AND json_array_length(details_json) > 0;
+
+UPDATE timeline_events
+SET changes_json = (
+    SELECT json_group_array(
+        CASE json_extract(value, '$.label')
+            WHEN 'Rating' THEN json_set(value, '$.key', 'rating')
+            WHEN 'Status' THEN json_set(value, '$.key', 'status')
+            WHEN 'Shelf' THEN json_set(value, '$.key', 'shelf')
+            WHEN 'Book club' THEN json_set(value, '$.key', 'book_club')
+            WHEN 'Reached' THEN json_set(value, '$.key', 'milestone')
+            ELSE json(value)
+        END
+    )
+    FROM json_each(changes_json)
+)
+WHERE changes_json IS NOT NULL AND json_valid(changes_json) AND json_array_length(changes_json) > 0;
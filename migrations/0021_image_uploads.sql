@@ -0,0 +1,18 @@
+-- Images uploaded with create and edit forms wait here for the background
+-- worker, so job payloads carry a reference instead of the whole upload.
+CREATE TABLE image_uploads (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    data_url TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+-- Move uploads of queued jobs out of their payloads. Failed jobs won't run
+-- again, so theirs are dropped.
+INSERT INTO image_uploads (id, data_url)
+    SELECT id, json_extract(payload, '$.data_url') FROM jobs
+    WHERE kind = 'process_image' AND status != 'failed'
+      AND json_extract(payload, '$.data_url') IS NOT NULL;
+
+UPDATE jobs
+SET payload = json_remove(json_set(payload, '$.upload_id', id), '$.data_url')
+WHERE kind = 'process_image';
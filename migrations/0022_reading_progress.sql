@@ -0,0 +1,2 @@
+-- How far into the book a reader is, for reading progress milestones.
+ALTER TABLE readings ADD COLUMN current_page INTEGER;
@@ -6,6 +6,7 @@ use thiserror::Error;
 use tracing::error;
 
 use crate::domain::RepositoryError;
+use crate::domain::filters::FilterError;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ErrorResponse {
@@ -88,3 +89,9 @@ impl From<RepositoryError> for AppError {
         }
     }
 }
+
+impl From<FilterError> for AppError {
+    fn from(value: FilterError) -> Self {
+        Self::Validation(value.to_string())
+    }
+}
@@ -69,7 +69,7 @@ impl RateLimitLayer {
     /// Create a rate limiter allowing `requests` per minute per IP.
     pub fn per_minute(requests: u32) -> Self {
         Self {
-            limiter: Arc::new(RateLimiter::new(requests, Duration::from_secs(60))),
+            limiter: Arc::new(RateLimiter::new(requests, Duration::from_mins(1))),
         }
     }
 }
@@ -131,7 +131,7 @@ mod tests {
 
     #[test]
     fn allows_requests_within_limit() {
-        let limiter = RateLimiter::new(5, Duration::from_secs(60));
+        let limiter = RateLimiter::new(5, Duration::from_mins(1));
         let ip: IpAddr = "127.0.0.1".parse().unwrap();
 
         for _ in 0..5 {
@@ -141,7 +141,7 @@ mod tests {
 
     #[test]
     fn rejects_requests_over_limit() {
-        let limiter = RateLimiter::new(3, Duration::from_secs(60));
+        let limiter = RateLimiter::new(3, Duration::from_mins(1));
         let ip: IpAddr = "127.0.0.1".parse().unwrap();
 
         for _ in 0..3 {
@@ -152,7 +152,7 @@ mod tests {
 
     #[test]
     fn tracks_ips_independently() {
-        let limiter = RateLimiter::new(2, Duration::from_secs(60));
+        let limiter = RateLimiter::new(2, Duration::from_mins(1));
         let ip1: IpAddr = "10.0.0.1".parse().unwrap();
         let ip2: IpAddr = "10.0.0.2".parse().unwrap();
 
@@ -168,7 +168,7 @@ mod tests {
     #[test]
     fn refills_tokens_over_time() {
         // 60 tokens per 60 seconds = 1 token per second
-        let limiter = RateLimiter::new(60, Duration::from_secs(60));
+        let limiter = RateLimiter::new(60, Duration::from_mins(1));
         let ip: IpAddr = "127.0.0.1".parse().unwrap();
 
         // Exhaust all tokens
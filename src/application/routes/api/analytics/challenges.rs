@@ -0,0 +1,409 @@
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Redirect, Response};
+use serde::Deserialize;
+use tracing::info;
+
+use crate::application::auth::AuthenticatedUser;
+use crate::application::errors::{ApiError, AppError};
+use crate::application::routes::support::{
+    FlexiblePayload, PayloadSource, clearable, empty_string_as_none, impl_has_changes,
+    is_datastar_request, render_redirect_script, update_response, validate_update,
+};
+use crate::application::state::AppState;
+use crate::domain::RepositoryError;
+use crate::domain::challenges::{
+    ChallengeProgress, ChallengePrompt, ChallengeSummary, ChallengeWithPrompts, NewChallenge,
+    NewChallengePrompt, UpdateChallenge, UpdateChallengePrompt,
+};
+use crate::domain::ids::{ChallengeId, ChallengePromptId, ReadingId, UserId};
+use crate::domain::readings::ReadingStatus;
+
+pub(crate) const CHALLENGES_PAGE_PATH: &str = "/challenges";
+
+fn challenge_path(id: ChallengeId) -> String {
+    format!("/challenges/{id}")
+}
+
+/// Fetches a challenge the user may see: their own, or any shared one.
+pub(crate) async fn visible_challenge(
+    state: &AppState,
+    id: ChallengeId,
+    user_id: UserId,
+) -> Result<ChallengeWithPrompts, AppError> {
+    let challenge = state.challenge_repo.get(id).await.map_err(AppError::from)?;
+    if !challenge.challenge.is_visible_to(user_id) {
+        return Err(AppError::NotFound);
+    }
+    Ok(challenge)
+}
+
+/// Fetches a challenge for editing; `None` when the user may see it but not change it.
+async fn editable_challenge(
+    state: &AppState,
+    id: ChallengeId,
+    auth_user: &AuthenticatedUser,
+) -> Result<Option<ChallengeWithPrompts>, AppError> {
+    let challenge = visible_challenge(state, id, auth_user.effective.id).await?;
+    let editable = challenge
+        .challenge
+        .is_editable_by(auth_user.effective.id, auth_user.real.is_admin);
+    Ok(editable.then_some(challenge))
+}
+
+fn find_prompt(
+    challenge: &ChallengeWithPrompts,
+    prompt_id: ChallengePromptId,
+) -> Result<&ChallengePrompt, AppError> {
+    challenge
+        .prompts
+        .iter()
+        .find(|p| p.id == prompt_id)
+        .ok_or(AppError::NotFound)
+}
+
+#[tracing::instrument(skip(state, auth_user))]
+pub(crate) async fn list_challenges(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+) -> Result<Json<Vec<ChallengeSummary>>, ApiError> {
+    let summaries = state
+        .challenge_service
+        .summaries(auth_user.effective.id)
+        .await
+        .map_err(AppError::from)?;
+    Ok(Json(summaries))
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct NewChallengeSubmission {
+    name: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    year: Option<i32>,
+    #[serde(default)]
+    shared: Option<bool>,
+    #[serde(default)]
+    prompts: Vec<NewChallengePrompt>,
+}
+
+impl NewChallengeSubmission {
+    fn into_new(self, user_id: UserId) -> Result<NewChallenge, AppError> {
+        let challenge = NewChallenge {
+            user_id,
+            name: self.name,
+            description: self.description,
+            year: self.year,
+            shared: self.shared.unwrap_or(false),
+            prompts: self.prompts,
+        }
+        .normalize();
+        challenge.validate().map_err(AppError::validation)?;
+        Ok(challenge)
+    }
+}
+
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn create_challenge(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    payload: FlexiblePayload<NewChallengeSubmission>,
+) -> Result<Response, ApiError> {
+    let (submission, source) = payload.into_parts();
+    let new_challenge = submission.into_new(auth_user.effective.id)?;
+
+    let challenge = state
+        .challenge_repo
+        .insert(new_challenge)
+        .await
+        .map_err(AppError::from)?;
+    info!(
+        challenge_id = %challenge.challenge.id,
+        name = %challenge.challenge.name,
+        prompts = challenge.prompts.len(),
+        "challenge created"
+    );
+
+    let detail_url = challenge_path(challenge.challenge.id);
+    if is_datastar_request(&headers) {
+        render_redirect_script(&detail_url).map_err(ApiError::from)
+    } else if matches!(source, PayloadSource::Form) {
+        Ok(Redirect::to(&detail_url).into_response())
+    } else {
+        Ok((StatusCode::CREATED, Json(challenge)).into_response())
+    }
+}
+
+#[tracing::instrument(skip(state, auth_user))]
+pub(crate) async fn get_challenge(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path(id): Path<ChallengeId>,
+) -> Result<Json<ChallengeProgress>, ApiError> {
+    let challenge = visible_challenge(&state, id, auth_user.effective.id).await?;
+    let progress = state
+        .challenge_service
+        .progress(challenge, auth_user.effective.id)
+        .await
+        .map_err(AppError::from)?;
+    Ok(Json(progress))
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct UpdateChallengeSubmission {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    /// An empty value lets readings from any year count.
+    #[serde(default, deserialize_with = "clearable")]
+    #[allow(clippy::option_option)] // absent vs cleared
+    year: Option<Option<i32>>,
+    #[serde(default)]
+    shared: Option<bool>,
+}
+
+impl UpdateChallengeSubmission {
+    fn into_update(self) -> UpdateChallenge {
+        UpdateChallenge {
+            name: self.name,
+            description: self.description,
+            year: self.year,
+            shared: self.shared,
+        }
+        .normalize()
+    }
+}
+
+impl_has_changes!(UpdateChallenge, name, description, year, shared);
+
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn update_challenge(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    Path(id): Path<ChallengeId>,
+    payload: FlexiblePayload<UpdateChallengeSubmission>,
+) -> Result<Response, ApiError> {
+    let (submission, source) = payload.into_parts();
+    let update = submission.into_update();
+    validate_update(&update, Option::<&String>::None)?;
+    update.validate().map_err(AppError::validation)?;
+
+    if editable_challenge(&state, id, &auth_user).await?.is_none() {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    }
+
+    let challenge = state
+        .challenge_repo
+        .update(id, update)
+        .await
+        .map_err(AppError::from)?;
+    info!(%id, "challenge updated");
+
+    update_response(
+        &headers,
+        source,
+        &challenge_path(id),
+        Json(challenge).into_response(),
+    )
+}
+
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn delete_challenge(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    Path(id): Path<ChallengeId>,
+) -> Result<Response, ApiError> {
+    if editable_challenge(&state, id, &auth_user).await?.is_none() {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    }
+    state
+        .challenge_repo
+        .delete(id)
+        .await
+        .map_err(AppError::from)?;
+    info!(%id, "challenge deleted");
+
+    if is_datastar_request(&headers) {
+        render_redirect_script(CHALLENGES_PAGE_PATH).map_err(ApiError::from)
+    } else {
+        Ok(StatusCode::NO_CONTENT.into_response())
+    }
+}
+
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn add_prompt(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    Path(id): Path<ChallengeId>,
+    payload: FlexiblePayload<NewChallengePrompt>,
+) -> Result<Response, ApiError> {
+    let (prompt, source) = payload.into_parts();
+    let prompt = prompt.normalize();
+    prompt.validate().map_err(AppError::validation)?;
+
+    if editable_challenge(&state, id, &auth_user).await?.is_none() {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    }
+
+    let prompt = state
+        .challenge_repo
+        .add_prompt(id, prompt)
+        .await
+        .map_err(AppError::from)?;
+    info!(%id, prompt_id = %prompt.id, "challenge prompt added");
+
+    let detail_url = challenge_path(id);
+    if is_datastar_request(&headers) {
+        render_redirect_script(&detail_url).map_err(ApiError::from)
+    } else if matches!(source, PayloadSource::Form) {
+        Ok(Redirect::to(&detail_url).into_response())
+    } else {
+        Ok((StatusCode::CREATED, Json(prompt)).into_response())
+    }
+}
+
+impl_has_changes!(UpdateChallengePrompt, label, rule);
+
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn update_prompt(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    Path((id, prompt_id)): Path<(ChallengeId, ChallengePromptId)>,
+    payload: FlexiblePayload<UpdateChallengePrompt>,
+) -> Result<Response, ApiError> {
+    let (update, source) = payload.into_parts();
+    let update = update.normalize();
+    validate_update(&update, Option::<&String>::None)?;
+    update.validate().map_err(AppError::validation)?;
+
+    let Some(challenge) = editable_challenge(&state, id, &auth_user).await? else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+    find_prompt(&challenge, prompt_id)?;
+
+    let prompt = state
+        .challenge_repo
+        .update_prompt(prompt_id, update)
+        .await
+        .map_err(AppError::from)?;
+    info!(%id, %prompt_id, "challenge prompt updated");
+
+    update_response(
+        &headers,
+        source,
+        &challenge_path(id),
+        Json(prompt).into_response(),
+    )
+}
+
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn delete_prompt(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    Path((id, prompt_id)): Path<(ChallengeId, ChallengePromptId)>,
+) -> Result<Response, ApiError> {
+    let Some(challenge) = editable_challenge(&state, id, &auth_user).await? else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+    find_prompt(&challenge, prompt_id)?;
+
+    state
+        .challenge_repo
+        .delete_prompt(prompt_id)
+        .await
+        .map_err(AppError::from)?;
+    info!(%id, %prompt_id, "challenge prompt deleted");
+
+    if is_datastar_request(&headers) {
+        render_redirect_script(&challenge_path(id)).map_err(ApiError::from)
+    } else {
+        Ok(StatusCode::NO_CONTENT.into_response())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct AssignEntrySubmission {
+    reading_id: ReadingId,
+}
+
+/// Fills a prompt with one of the user's finished readings. Any finished
+/// reading may be chosen by hand, whether or not it matches the prompt's rule.
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn assign_entry(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    Path((id, prompt_id)): Path<(ChallengeId, ChallengePromptId)>,
+    payload: FlexiblePayload<AssignEntrySubmission>,
+) -> Result<Response, ApiError> {
+    let (submission, source) = payload.into_parts();
+    let user_id = auth_user.effective.id;
+    let challenge = visible_challenge(&state, id, user_id).await?;
+    let prompt = find_prompt(&challenge, prompt_id)?;
+
+    let reading = match state.reading_repo.get(submission.reading_id).await {
+        Ok(reading) if reading.user_id == user_id => reading,
+        Ok(_) | Err(RepositoryError::NotFound) => {
+            return Err(AppError::validation("reading does not exist").into());
+        }
+        Err(err) => return Err(AppError::from(err).into()),
+    };
+    if reading.status != ReadingStatus::Read {
+        return Err(AppError::validation("only finished readings can fill a prompt").into());
+    }
+    if !challenge.challenge.accepts(reading.finished_at) {
+        return Err(AppError::validation(format!(
+            "this challenge only counts readings finished in {}",
+            challenge.challenge.year.unwrap_or_default()
+        ))
+        .into());
+    }
+
+    let entry = state
+        .challenge_repo
+        .assign(prompt, user_id, reading.id)
+        .await
+        .map_err(AppError::from)?;
+    info!(%id, %prompt_id, reading_id = %reading.id, "challenge prompt filled");
+
+    update_response(
+        &headers,
+        source,
+        &challenge_path(id),
+        Json(entry).into_response(),
+    )
+}
+
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn remove_entry(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    Path((id, prompt_id)): Path<(ChallengeId, ChallengePromptId)>,
+) -> Result<Response, ApiError> {
+    let user_id = auth_user.effective.id;
+    let challenge = visible_challenge(&state, id, user_id).await?;
+    find_prompt(&challenge, prompt_id)?;
+
+    state
+        .challenge_repo
+        .unassign(prompt_id, user_id)
+        .await
+        .map_err(AppError::from)?;
+    info!(%id, %prompt_id, "challenge prompt cleared");
+
+    if is_datastar_request(&headers) {
+        render_redirect_script(&challenge_path(id)).map_err(ApiError::from)
+    } else {
+        Ok(StatusCode::NO_CONTENT.into_response())
+    }
+}
@@ -0,0 +1,317 @@
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Redirect, Response};
+use chrono::{Datelike, NaiveDate, Utc};
+use serde::Deserialize;
+use tracing::{info, warn};
+
+use crate::application::auth::AuthenticatedUser;
+use crate::application::errors::{ApiError, AppError};
+use crate::application::routes::support::{
+    FlexiblePayload, PayloadSource, clearable, empty_string_as_none, impl_has_changes,
+    is_datastar_request, render_redirect_script, update_response, validate_update,
+};
+use crate::application::state::AppState;
+use crate::domain::RepositoryError;
+use crate::domain::goals::{
+    GoalMetric, GoalProgress, NewReadingGoal, ReadingGoal, UpdateReadingGoal, year_range,
+};
+use crate::domain::ids::{GenreId, GoalId, UserId};
+use crate::domain::readings::ReadingFormat;
+
+const GOALS_PAGE_PATH: &str = "/stats";
+
+/// Fetches a goal, treating goals owned by other users as missing.
+async fn owned_goal(
+    state: &AppState,
+    id: GoalId,
+    user_id: UserId,
+) -> Result<ReadingGoal, AppError> {
+    let goal = state.goal_repo.get(id).await.map_err(AppError::from)?;
+    if goal.user_id != user_id {
+        return Err(AppError::NotFound);
+    }
+    Ok(goal)
+}
+
+async fn validate_genre(state: &AppState, genre_id: GenreId) -> Result<(), AppError> {
+    match state.genre_repo.get(genre_id).await {
+        Ok(_) => Ok(()),
+        Err(RepositoryError::NotFound) => Err(AppError::validation("genre does not exist")),
+        Err(err) => Err(AppError::from(err)),
+    }
+}
+
+fn parse_metric(value: &str) -> Result<GoalMetric, AppError> {
+    value
+        .trim()
+        .parse()
+        .map_err(|()| AppError::validation("metric must be books or pages"))
+}
+
+/// Parses an optional format, where an empty value means "any format".
+fn parse_format(value: &str) -> Result<Option<ReadingFormat>, AppError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    value
+        .parse()
+        .map(Some)
+        .map_err(|()| AppError::validation("format must be physical, ereader or audiobook"))
+}
+
+fn year_period(year: i32) -> Result<(NaiveDate, NaiveDate), AppError> {
+    year_range(year).ok_or_else(|| AppError::validation("invalid goal year"))
+}
+
+fn today() -> NaiveDate {
+    Utc::now().date_naive()
+}
+
+#[tracing::instrument(skip(state, auth_user))]
+pub(crate) async fn list_goals(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+) -> Result<Json<Vec<GoalProgress>>, ApiError> {
+    let progress = state
+        .goal_service
+        .progress_for_user(auth_user.effective.id, today())
+        .await
+        .map_err(AppError::from)?;
+    Ok(Json(progress))
+}
+
+/// A goal covers either a calendar `year` or an explicit `start_date` and
+/// `end_date`; with neither it covers the current year.
+#[derive(Debug, Deserialize)]
+pub(crate) struct NewGoalSubmission {
+    #[serde(default)]
+    metric: Option<String>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    target: Option<i64>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    year: Option<i32>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    start_date: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    end_date: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    genre_id: Option<i64>,
+    #[serde(default)]
+    format: Option<String>,
+}
+
+impl NewGoalSubmission {
+    fn into_new(self, user_id: UserId) -> Result<NewReadingGoal, AppError> {
+        let (start_date, end_date) = match (self.year, self.start_date, self.end_date) {
+            (Some(year), None, None) => year_period(year)?,
+            (None, Some(start), Some(end)) => (start, end),
+            (None, None, None) => year_period(today().year())?,
+            (Some(_), _, _) => {
+                return Err(AppError::validation(
+                    "give either a year or a start and end date, not both",
+                ));
+            }
+            _ => {
+                return Err(AppError::validation(
+                    "custom goals need both a start and an end date",
+                ));
+            }
+        };
+
+        let goal = NewReadingGoal {
+            user_id,
+            metric: match self.metric.as_deref() {
+                None | Some("") => GoalMetric::default(),
+                Some(value) => parse_metric(value)?,
+            },
+            target: self
+                .target
+                .ok_or_else(|| AppError::validation("goal target is required"))?,
+            start_date,
+            end_date,
+            genre_id: self.genre_id.map(GenreId::new),
+            format: parse_format(self.format.as_deref().unwrap_or_default())?,
+        };
+        goal.validate().map_err(AppError::validation)?;
+        Ok(goal)
+    }
+}
+
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn create_goal(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    payload: FlexiblePayload<NewGoalSubmission>,
+) -> Result<Response, ApiError> {
+    let (submission, source) = payload.into_parts();
+    let new_goal = submission.into_new(auth_user.effective.id)?;
+    if let Some(genre_id) = new_goal.genre_id {
+        validate_genre(&state, genre_id).await?;
+    }
+
+    let goal = state
+        .goal_repo
+        .insert(new_goal)
+        .await
+        .map_err(AppError::from)?;
+    info!(goal_id = %goal.id, metric = goal.metric.as_str(), target = goal.target, "goal created");
+
+    if is_datastar_request(&headers) {
+        render_redirect_script(GOALS_PAGE_PATH).map_err(ApiError::from)
+    } else if matches!(source, PayloadSource::Form) {
+        Ok(Redirect::to(GOALS_PAGE_PATH).into_response())
+    } else {
+        let progress = state
+            .goal_service
+            .progress(goal, today())
+            .await
+            .map_err(AppError::from)?;
+        Ok((StatusCode::CREATED, Json(progress)).into_response())
+    }
+}
+
+#[tracing::instrument(skip(state, auth_user))]
+pub(crate) async fn get_goal(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path(id): Path<GoalId>,
+) -> Result<Json<GoalProgress>, ApiError> {
+    let goal = owned_goal(&state, id, auth_user.effective.id).await?;
+    let progress = state
+        .goal_service
+        .progress(goal, today())
+        .await
+        .map_err(AppError::from)?;
+    Ok(Json(progress))
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct UpdateGoalSubmission {
+    #[serde(default)]
+    metric: Option<String>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    target: Option<i64>,
+    /// Moves the goal to cover this calendar year.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    year: Option<i32>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    start_date: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    end_date: Option<NaiveDate>,
+    /// An empty value counts books from every genre.
+    #[serde(default, deserialize_with = "clearable")]
+    #[allow(clippy::option_option)] // absent vs cleared
+    genre_id: Option<Option<i64>>,
+    /// An empty value counts readings in any format.
+    #[serde(default, deserialize_with = "clearable")]
+    #[allow(clippy::option_option)] // absent vs cleared
+    format: Option<Option<String>>,
+}
+
+impl UpdateGoalSubmission {
+    fn into_update(self) -> Result<UpdateReadingGoal, AppError> {
+        let (start_date, end_date) = match self.year {
+            Some(_) if self.start_date.is_some() || self.end_date.is_some() => {
+                return Err(AppError::validation(
+                    "give either a year or a start and end date, not both",
+                ));
+            }
+            Some(year) => {
+                let (start, end) = year_period(year)?;
+                (Some(start), Some(end))
+            }
+            None => (self.start_date, self.end_date),
+        };
+
+        Ok(UpdateReadingGoal {
+            metric: self.metric.as_deref().map(parse_metric).transpose()?,
+            target: self.target,
+            start_date,
+            end_date,
+            genre_id: self.genre_id.map(|id| id.map(GenreId::new)),
+            format: self
+                .format
+                .map(|f| parse_format(f.as_deref().unwrap_or_default()))
+                .transpose()?,
+        })
+    }
+}
+
+impl_has_changes!(
+    UpdateReadingGoal,
+    metric,
+    target,
+    start_date,
+    end_date,
+    genre_id,
+    format
+);
+
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn update_goal(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    Path(id): Path<GoalId>,
+    payload: FlexiblePayload<UpdateGoalSubmission>,
+) -> Result<Response, ApiError> {
+    let (submission, source) = payload.into_parts();
+    let update = submission.into_update()?;
+    validate_update(&update, Option::<&String>::None)?;
+
+    let existing = owned_goal(&state, id, auth_user.effective.id).await?;
+    update
+        .validate_against(&existing)
+        .map_err(AppError::validation)?;
+    if let Some(Some(genre_id)) = update.genre_id {
+        validate_genre(&state, genre_id).await?;
+    }
+
+    let goal = state
+        .goal_repo
+        .update(id, update)
+        .await
+        .map_err(AppError::from)?;
+    info!(%id, "goal updated");
+
+    let progress = state
+        .goal_service
+        .progress(goal, today())
+        .await
+        .map_err(AppError::from)?;
+    update_response(
+        &headers,
+        source,
+        GOALS_PAGE_PATH,
+        Json(progress).into_response(),
+    )
+}
+
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn delete_goal(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    Path(id): Path<GoalId>,
+) -> Result<Response, ApiError> {
+    owned_goal(&state, id, auth_user.effective.id).await?;
+    state.goal_repo.delete(id).await.map_err(AppError::from)?;
+    if let Err(err) = state
+        .timeline_repo
+        .delete_by_entity("goal", i64::from(id))
+        .await
+    {
+        warn!(%id, error = %err, "failed to delete goal timeline events");
+    }
+    info!(%id, "goal deleted");
+
+    if is_datastar_request(&headers) {
+        render_redirect_script(GOALS_PAGE_PATH).map_err(ApiError::from)
+    } else {
+        Ok(StatusCode::NO_CONTENT.into_response())
+    }
+}
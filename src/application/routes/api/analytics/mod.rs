@@ -1 +1,4 @@
+pub(crate) mod challenges;
+pub(crate) mod goals;
+pub(crate) mod review;
 pub(crate) mod stats;
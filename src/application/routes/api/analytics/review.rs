@@ -0,0 +1,91 @@
+use axum::Json;
+use axum::body::Body;
+use axum::extract::{Path, State};
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+
+use crate::application::auth::AuthenticatedUser;
+use crate::application::errors::{ApiError, AppError};
+use crate::application::services::stats::compute_year_review;
+use crate::application::state::AppState;
+use crate::domain::ids::UserId;
+use crate::domain::review::YearInReview;
+use crate::domain::stats::StatsPeriod;
+use crate::infrastructure::review_card::{CardCover, render_review_card};
+
+/// Rejects years a calendar period can't be built for.
+pub(crate) fn validate_review_year(year: i32) -> Result<(), AppError> {
+    if StatsPeriod::year(year).is_none() {
+        return Err(AppError::validation(format!("year {year} is out of range")));
+    }
+    Ok(())
+}
+
+pub(crate) async fn load_year_review(
+    state: &AppState,
+    user_id: UserId,
+    year: i32,
+) -> Result<YearInReview, AppError> {
+    validate_review_year(year)?;
+    compute_year_review(&*state.stats_repo, &*state.reading_repo, user_id, year)
+        .await
+        .map_err(AppError::from)
+}
+
+/// The year in review: top books, favourite author and genre, first and last
+/// finished books, and the most-used quick reviews.
+#[tracing::instrument(skip(state, auth_user))]
+pub(crate) async fn get_review(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path(year): Path<i32>,
+) -> Result<Json<YearInReview>, ApiError> {
+    let review = load_year_review(&state, auth_user.effective.id, year).await?;
+    Ok(Json(review))
+}
+
+/// The year in review as a shareable PNG card, with cover thumbnails for the
+/// featured books.
+#[tracing::instrument(skip(state, auth_user))]
+pub(crate) async fn get_review_card(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path(year): Path<i32>,
+) -> Result<Response, ApiError> {
+    let review = load_year_review(&state, auth_user.effective.id, year).await?;
+
+    let mut covers = Vec::new();
+    for book in review.featured_books() {
+        let thumbnail = state
+            .image_repo
+            .get_thumbnail("book", i64::from(book.book_id))
+            .await
+            .ok()
+            .map(|image| image.thumbnail_data);
+        covers.push(CardCover {
+            title: book.title.clone(),
+            thumbnail,
+        });
+    }
+
+    let _permit = state
+        .image_semaphore
+        .acquire()
+        .await
+        .map_err(|_| AppError::unexpected("image processing unavailable"))?;
+    let png = tokio::task::spawn_blocking(move || render_review_card(&review, &covers))
+        .await
+        .map_err(|e| AppError::unexpected(format!("review card task failed: {e}")))?
+        .map_err(|e| AppError::unexpected(format!("failed to render review card: {e}")))?;
+
+    Ok(Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "image/png")
+        .header(
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"booklog-{year}-in-review.png\""),
+        )
+        .header(header::CACHE_CONTROL, "private, no-cache")
+        .body(Body::from(png))
+        .unwrap_or_else(|_| StatusCode::INTERNAL_SERVER_ERROR.into_response()))
+}
@@ -1,11 +1,27 @@
 use axum::Json;
-use axum::extract::State;
+use axum::extract::{Query, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use chrono::{Days, NaiveDate, Utc};
+use serde::Deserialize;
+use tracing::info;
 
 use crate::application::auth::AuthenticatedUser;
 use crate::application::errors::{ApiError, AppError};
-use crate::application::services::stats::compute_all_stats;
+use crate::application::routes::support::{FlexiblePayload, update_response};
+use crate::application::services::stats::{
+    compute_stats_report, load_household_stats, rebuild_household_stats, rebuild_stats,
+};
 use crate::application::state::AppState;
-use crate::domain::stats::CachedStats;
+use crate::domain::activity::ReadingActivity;
+use crate::domain::household::HouseholdStats;
+use crate::domain::stats::{CachedStats, StatsPeriod, StatsReport};
+
+/// Days covered by the activity endpoint when no range is given: a year, as
+/// on the stats page heatmap.
+const DEFAULT_ACTIVITY_DAYS: u64 = 364;
+/// Longest range the activity endpoint returns in one response.
+const MAX_ACTIVITY_DAYS: i64 = 3660;
 
 /// Force an immediate stats recomputation, bypassing the debounce timer.
 #[tracing::instrument(skip(state, auth_user))]
@@ -14,15 +30,150 @@ pub(crate) async fn recompute_stats(
     auth_user: AuthenticatedUser,
 ) -> Result<Json<CachedStats>, ApiError> {
     let user_id = auth_user.effective.id;
-    let cached = compute_all_stats(&*state.stats_repo, user_id)
+    let metrics = state.stats_invalidator.metrics();
+    let rebuild = rebuild_stats(&*state.stats_repo, user_id, &metrics)
         .await
         .map_err(AppError::from)?;
 
-    state
+    Ok(Json(rebuild.stats))
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct ActivityQuery {
+    #[serde(default)]
+    from: Option<NaiveDate>,
+    #[serde(default)]
+    to: Option<NaiveDate>,
+}
+
+/// Day-by-day reading activity and streaks. Defaults to the year up to today.
+#[tracing::instrument(skip(state, auth_user))]
+pub(crate) async fn get_activity(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Query(query): Query<ActivityQuery>,
+) -> Result<Json<ReadingActivity>, ApiError> {
+    let today = Utc::now().date_naive();
+    let to = query.to.unwrap_or(today);
+    let from = query
+        .from
+        .or_else(|| to.checked_sub_days(Days::new(DEFAULT_ACTIVITY_DAYS)))
+        .unwrap_or(to);
+    if from > to {
+        return Err(AppError::validation("from must be on or before to").into());
+    }
+    if (to - from).num_days() >= MAX_ACTIVITY_DAYS {
+        return Err(AppError::validation(format!(
+            "date range cannot be longer than {MAX_ACTIVITY_DAYS} days"
+        ))
+        .into());
+    }
+
+    let spans = state
         .stats_repo
-        .store_cached(user_id, &cached)
+        .reading_spans(auth_user.effective.id)
+        .await
+        .map_err(AppError::from)?;
+    Ok(Json(ReadingActivity::build(&spans, from, to, today)))
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct StatsQuery {
+    #[serde(default)]
+    from: Option<NaiveDate>,
+    #[serde(default)]
+    to: Option<NaiveDate>,
+    #[serde(default)]
+    compare_from: Option<NaiveDate>,
+    #[serde(default)]
+    compare_to: Option<NaiveDate>,
+}
+
+/// Stats over a date range, computed live. Defaults to the last twelve months.
+/// With `compare_from` and `compare_to`, the report also holds stats for that
+/// range and the deltas between the two.
+#[tracing::instrument(skip(state, auth_user))]
+pub(crate) async fn get_stats(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Query(query): Query<StatsQuery>,
+) -> Result<Json<StatsReport>, ApiError> {
+    let to = query.to.unwrap_or_else(|| Utc::now().date_naive());
+    let from = match query.from {
+        Some(from) => from,
+        None => StatsPeriod::last_twelve_months(to)
+            .map(|p| p.from)
+            .ok_or_else(|| AppError::validation("to is out of range"))?,
+    };
+    let period = StatsPeriod::new(from, to).map_err(AppError::Validation)?;
+
+    let compare = match (query.compare_from, query.compare_to) {
+        (Some(from), Some(to)) => Some(
+            StatsPeriod::new(from, to)
+                .map_err(|e| AppError::validation(format!("compare: {e}")))?,
+        ),
+        (None, None) => None,
+        _ => {
+            return Err(
+                AppError::validation("compare_from and compare_to must be given together").into(),
+            );
+        }
+    };
+
+    let report = compute_stats_report(
+        &*state.stats_repo,
+        auth_user.effective.id,
+        &period,
+        compare.as_ref(),
+    )
+    .await
+    .map_err(AppError::from)?;
+    Ok(Json(report))
+}
+
+/// Stats across every user who hasn't opted out, served from the household cache.
+#[tracing::instrument(skip(state, _auth_user))]
+pub(crate) async fn get_household_stats(
+    State(state): State<AppState>,
+    _auth_user: AuthenticatedUser,
+) -> Result<Json<HouseholdStats>, ApiError> {
+    let household = load_household_stats(&*state.stats_repo)
+        .await
+        .map_err(AppError::from)?;
+    Ok(Json(household))
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct HouseholdPrivacySubmission {
+    opt_out: bool,
+}
+
+/// Leave the user's readings out of the household stats, or include them
+/// again. The cached aggregate is rebuilt right away rather than debounced,
+/// so opting out takes effect immediately.
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn set_household_privacy(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    payload: FlexiblePayload<HouseholdPrivacySubmission>,
+) -> Result<Response, ApiError> {
+    let (submission, source) = payload.into_parts();
+    let user_id = auth_user.effective.id;
+    state
+        .user_repo
+        .set_household_opt_out(user_id, submission.opt_out)
+        .await
+        .map_err(AppError::from)?;
+    rebuild_household_stats(&*state.stats_repo)
         .await
         .map_err(AppError::from)?;
+    info!(%user_id, opt_out = submission.opt_out, "household stats privacy updated");
 
-    Ok(Json(cached))
+    update_response(
+        &headers,
+        source,
+        "/stats/household",
+        StatusCode::NO_CONTENT.into_response(),
+    )
 }
@@ -0,0 +1,111 @@
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+use tracing::info;
+
+use crate::application::auth::AuthenticatedUser;
+use crate::application::errors::{ApiError, AppError};
+use crate::application::routes::support::{FlexiblePayload, PayloadSource, update_response};
+use crate::application::state::AppState;
+use crate::domain::ids::FollowerId;
+
+/// The origin actor URIs are minted under: the configured base URL, or
+/// the host the request came in on when none is set.
+pub(crate) fn request_origin(headers: &HeaderMap) -> Result<String, AppError> {
+    let base_url = crate::base_url();
+    if !base_url.is_empty() {
+        return Ok(base_url.trim_end_matches('/').to_string());
+    }
+    headers
+        .get(header::HOST)
+        .and_then(|host| host.to_str().ok())
+        .map(|host| format!("http://{host}"))
+        .ok_or_else(|| AppError::Validation("request has no host".to_string()))
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct FederationSettingsSubmission {
+    enabled: bool,
+    #[serde(default)]
+    manually_approve: bool,
+}
+
+/// Opt in to or out of federation, and choose whether followers are
+/// accepted automatically.
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn update_federation_settings(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    payload: FlexiblePayload<FederationSettingsSubmission>,
+) -> Result<Response, ApiError> {
+    let (submission, source) = payload.into_parts();
+    let user_id = auth_user.effective.id;
+    if submission.enabled {
+        let origin = request_origin(&headers)?;
+        state
+            .federation
+            .enable(user_id, &origin, submission.manually_approve)
+            .await
+            .map_err(AppError::from)?;
+    } else {
+        state
+            .federation
+            .disable(user_id)
+            .await
+            .map_err(AppError::from)?;
+    }
+    info!(%user_id, enabled = submission.enabled, manually_approve = submission.manually_approve, "federation settings updated");
+
+    update_response(
+        &headers,
+        source,
+        "/admin",
+        StatusCode::NO_CONTENT.into_response(),
+    )
+}
+
+#[tracing::instrument(skip(state, auth_user, headers), fields(username = %auth_user.effective.username))]
+pub(crate) async fn approve_follower(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    Path(id): Path<FollowerId>,
+) -> Result<Response, ApiError> {
+    state
+        .federation
+        .approve_follower(&auth_user.effective, id)
+        .await
+        .map_err(AppError::from)?;
+    info!(follower_id = %id, "follower approved");
+
+    update_response(
+        &headers,
+        PayloadSource::Json,
+        "/admin",
+        StatusCode::NO_CONTENT.into_response(),
+    )
+}
+
+#[tracing::instrument(skip(state, auth_user, headers), fields(username = %auth_user.effective.username))]
+pub(crate) async fn reject_follower(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    Path(id): Path<FollowerId>,
+) -> Result<Response, ApiError> {
+    state
+        .federation
+        .reject_follower(&auth_user.effective, id)
+        .await
+        .map_err(AppError::from)?;
+    info!(follower_id = %id, "follower rejected");
+
+    update_response(
+        &headers,
+        PayloadSource::Json,
+        "/admin",
+        StatusCode::NO_CONTENT.into_response(),
+    )
+}
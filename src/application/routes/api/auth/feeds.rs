@@ -0,0 +1,115 @@
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use serde::{Deserialize, Serialize};
+use tracing::{error, info};
+
+use crate::application::auth::AuthenticatedUser;
+use crate::application::errors::{ApiError, AppError};
+use crate::application::routes::app::feeds::{FeedFormat, feed_url};
+use crate::application::routes::support::{
+    FlexiblePayload, is_datastar_request, render_signals_json, update_response,
+};
+use crate::application::state::AppState;
+use crate::domain::feed_tokens::{FeedKind, NewFeedToken};
+use crate::infrastructure::auth::{generate_session_token, hash_token};
+
+#[derive(Debug, Serialize)]
+pub struct FeedTokenResponse {
+    pub feed: FeedKind,
+    pub atom_url: String,
+    pub rss_url: String,
+}
+
+/// Issue a new secret token for one of the user's feeds, replacing any
+/// earlier one. The token is only ever returned here, inside the feed URLs.
+#[tracing::instrument(skip(state, auth_user, headers), fields(username = %auth_user.effective.username))]
+pub async fn regenerate_feed_token(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    Path(feed): Path<FeedKind>,
+) -> Result<Response, ApiError> {
+    let user = auth_user.effective;
+    // URL-safe, since it travels in the feed's query string
+    let token_value = generate_session_token();
+
+    state
+        .feed_token_repo
+        .replace(NewFeedToken::new(user.id, feed, hash_token(&token_value)))
+        .await
+        .map_err(|err| {
+            error!(error = %err, "failed to store feed token");
+            ApiError::from(AppError::unexpected("failed to store feed token"))
+        })?;
+
+    info!(user_id = %user.id, feed = feed.as_str(), "feed token regenerated");
+
+    let response = FeedTokenResponse {
+        feed,
+        atom_url: feed_url(&user.username, feed, FeedFormat::Atom, Some(&token_value)),
+        rss_url: feed_url(&user.username, feed, FeedFormat::Rss, Some(&token_value)),
+    };
+
+    if is_datastar_request(&headers) {
+        use serde_json::Value;
+        let signals = vec![
+            ("_feed-atom-url", Value::String(response.atom_url)),
+            ("_feed-rss-url", Value::String(response.rss_url)),
+            ("_feed-created", Value::String(feed.as_str().to_string())),
+        ];
+        render_signals_json(&signals).map_err(ApiError::from)
+    } else {
+        Ok(Json(response).into_response())
+    }
+}
+
+#[tracing::instrument(skip(state, auth_user), fields(username = %auth_user.effective.username))]
+pub async fn revoke_feed_token(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path(feed): Path<FeedKind>,
+) -> Result<StatusCode, ApiError> {
+    let user_id = auth_user.effective.id;
+    state
+        .feed_token_repo
+        .delete(user_id, feed)
+        .await
+        .map_err(AppError::from)?;
+
+    info!(%user_id, feed = feed.as_str(), "feed token revoked");
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct ProfileVisibilitySubmission {
+    public: bool,
+}
+
+/// Make the user's profile public, opening their feeds to anyone, or
+/// private again so feeds need a token.
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn set_profile_visibility(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    payload: FlexiblePayload<ProfileVisibilitySubmission>,
+) -> Result<Response, ApiError> {
+    let (submission, source) = payload.into_parts();
+    let user_id = auth_user.effective.id;
+    state
+        .user_repo
+        .set_public_profile(user_id, submission.public)
+        .await
+        .map_err(AppError::from)?;
+    info!(%user_id, public = submission.public, "profile visibility updated");
+
+    update_response(
+        &headers,
+        source,
+        "/admin",
+        StatusCode::NO_CONTENT.into_response(),
+    )
+}
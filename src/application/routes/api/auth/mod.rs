@@ -1,2 +1,6 @@
+pub(crate) mod federation;
+pub(crate) mod feeds;
+pub(crate) mod profile;
 pub(crate) mod tokens;
 pub(crate) mod webauthn;
+pub(crate) mod webhooks;
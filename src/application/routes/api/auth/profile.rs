@@ -0,0 +1,62 @@
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+use tracing::info;
+
+use crate::application::auth::AuthenticatedUser;
+use crate::application::errors::{ApiError, AppError};
+use crate::application::routes::support::{FlexiblePayload, update_response};
+use crate::application::state::AppState;
+use crate::domain::users::ProfilePrivacy;
+
+/// Fields left out keep their current setting, so each toggle can be
+/// submitted on its own.
+#[derive(Debug, Deserialize)]
+pub(crate) struct ProfilePrivacySubmission {
+    #[serde(default)]
+    show_ratings: Option<bool>,
+    #[serde(default)]
+    show_quick_reviews: Option<bool>,
+}
+
+/// Choose what the public profile shows alongside the user's books.
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn set_profile_privacy(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    payload: FlexiblePayload<ProfilePrivacySubmission>,
+) -> Result<Response, ApiError> {
+    let (submission, source) = payload.into_parts();
+    let user_id = auth_user.effective.id;
+    let current = state
+        .user_repo
+        .get_profile_privacy(user_id)
+        .await
+        .map_err(AppError::from)?;
+    let privacy = ProfilePrivacy {
+        show_ratings: submission.show_ratings.unwrap_or(current.show_ratings),
+        show_quick_reviews: submission
+            .show_quick_reviews
+            .unwrap_or(current.show_quick_reviews),
+    };
+    state
+        .user_repo
+        .set_profile_privacy(user_id, privacy)
+        .await
+        .map_err(AppError::from)?;
+    info!(
+        %user_id,
+        show_ratings = privacy.show_ratings,
+        show_quick_reviews = privacy.show_quick_reviews,
+        "profile privacy updated"
+    );
+
+    update_response(
+        &headers,
+        source,
+        "/admin",
+        StatusCode::NO_CONTENT.into_response(),
+    )
+}
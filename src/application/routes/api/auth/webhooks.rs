@@ -0,0 +1,174 @@
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::application::auth::AuthenticatedUser;
+use crate::application::errors::{ApiError, AppError};
+use crate::application::routes::support::{
+    FlexiblePayload, PayloadSource, is_datastar_request, render_signals_json, update_response,
+};
+use crate::application::state::AppState;
+use crate::domain::ids::WebhookId;
+use crate::domain::webhooks::{Webhook, is_webhook_event};
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct CreateWebhookRequest {
+    url: String,
+    /// Timeline actions to send; empty sends them all.
+    #[serde(default, deserialize_with = "deserialize_events")]
+    events: Vec<String>,
+    /// Admins only: receive every user's events, not just their own.
+    #[serde(default)]
+    all_users: bool,
+}
+
+/// Accepts a JSON array or, from forms, a comma-separated string.
+fn deserialize_events<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value: Option<serde_json::Value> = Option::deserialize(deserializer)?;
+    let events: Vec<String> = match value {
+        None | Some(serde_json::Value::Null) => Vec::new(),
+        Some(serde_json::Value::String(s)) => s.split(',').map(str::to_string).collect(),
+        Some(serde_json::Value::Array(arr)) => arr
+            .iter()
+            .filter_map(|v| v.as_str().map(str::to_string))
+            .collect(),
+        Some(_) => return Err(serde::de::Error::custom("invalid events")),
+    };
+    Ok(events
+        .into_iter()
+        .map(|event| event.trim().to_string())
+        .filter(|event| !event.is_empty())
+        .collect())
+}
+
+/// A new webhook, with the signing secret shown only this once.
+#[derive(Debug, Serialize)]
+pub struct CreateWebhookResponse {
+    #[serde(flatten)]
+    pub webhook: Webhook,
+    pub secret: String,
+}
+
+#[tracing::instrument(skip(state, auth_user, headers, payload), fields(username = %auth_user.effective.username))]
+pub(crate) async fn create_webhook(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    payload: FlexiblePayload<CreateWebhookRequest>,
+) -> Result<Response, ApiError> {
+    let (request, source) = payload.into_parts();
+    let user = auth_user.effective;
+
+    let url = request.url.trim();
+    match url::Url::parse(url) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => {}
+        _ => return Err(AppError::validation("webhook URL must be an http(s) URL").into()),
+    }
+    if let Some(unknown) = request.events.iter().find(|e| !is_webhook_event(e)) {
+        return Err(AppError::validation(format!("unknown webhook event: {unknown}")).into());
+    }
+    if request.all_users && !user.is_admin {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    }
+    if !user.is_admin
+        && let Err(err) = state.webhooks.check_destination(url).await
+    {
+        return Err(AppError::validation(format!("webhook URL is not allowed: {err:#}")).into());
+    }
+
+    let owner = (!request.all_users).then_some(user.id);
+    let mut webhook = state
+        .webhooks
+        .create(owner, url, request.events)
+        .await
+        .map_err(AppError::from)?;
+
+    if is_datastar_request(&headers) {
+        use serde_json::Value;
+        let signals = vec![
+            ("_webhook-secret", Value::String(webhook.secret)),
+            ("_webhook-created", Value::Bool(true)),
+        ];
+        render_signals_json(&signals).map_err(ApiError::from)
+    } else {
+        let secret = std::mem::take(&mut webhook.secret);
+        let json = (
+            StatusCode::CREATED,
+            Json(CreateWebhookResponse { webhook, secret }),
+        )
+            .into_response();
+        update_response(&headers, source, "/admin", json)
+    }
+}
+
+/// The user's webhooks, plus admin-level ones for admins.
+#[tracing::instrument(skip(state, auth_user), fields(username = %auth_user.effective.username))]
+pub(crate) async fn list_webhooks(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+) -> Result<Json<Vec<Webhook>>, ApiError> {
+    let user = auth_user.effective;
+    let webhooks = state
+        .webhook_repo
+        .list_for_user(user.id, user.is_admin)
+        .await
+        .map_err(AppError::from)?;
+    Ok(Json(webhooks))
+}
+
+#[tracing::instrument(skip(state, auth_user, headers), fields(username = %auth_user.effective.username))]
+pub(crate) async fn delete_webhook(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    Path(id): Path<WebhookId>,
+) -> Result<Response, ApiError> {
+    let user = auth_user.effective;
+    state
+        .webhooks
+        .delete(user.id, user.is_admin, id)
+        .await
+        .map_err(AppError::from)?;
+
+    update_response(
+        &headers,
+        PayloadSource::Json,
+        "/admin",
+        StatusCode::NO_CONTENT.into_response(),
+    )
+}
+
+/// Queue a `test` event for the webhook.
+#[tracing::instrument(skip(state, auth_user, headers), fields(username = %auth_user.effective.username))]
+pub(crate) async fn send_test_event(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    Path(id): Path<WebhookId>,
+) -> Result<Response, ApiError> {
+    let user = auth_user.effective;
+    let webhook = state
+        .webhooks
+        .get_managed(user.id, user.is_admin, id)
+        .await
+        .map_err(AppError::from)?;
+    state
+        .webhooks
+        .send_test(&webhook)
+        .await
+        .map_err(AppError::from)?;
+    info!(webhook_id = %id, "webhook test event queued");
+
+    update_response(
+        &headers,
+        PayloadSource::Json,
+        "/admin",
+        StatusCode::ACCEPTED.into_response(),
+    )
+}
@@ -12,11 +12,13 @@ use crate::application::routes::api::macros::{
 };
 use crate::application::routes::support::impl_has_changes;
 use crate::application::routes::support::{
-    FlexiblePayload, ListQuery, PayloadSource, is_datastar_request, render_redirect_script,
-    update_response, validate_update,
+    FlexiblePayload, ListQuery, PayloadSource, clearable, empty_string_as_none,
+    is_datastar_request, render_redirect_script, update_response, validate_update,
 };
 use crate::application::state::AppState;
-use crate::domain::authors::{Author, AuthorSortKey, NewAuthor, UpdateAuthor};
+use crate::domain::authors::{
+    Author, AuthorDetails, AuthorSortKey, NewAuthor, UpdateAuthor, normalize_aliases,
+};
 use crate::domain::ids::AuthorId;
 use crate::domain::images::ImageData;
 use crate::domain::listing::{ListRequest, SortDirection};
@@ -62,10 +64,39 @@ pub(crate) async fn list_authors(
     Ok(Json(authors))
 }
 
+/// Aliases submitted either as a JSON list or as a comma-separated form field.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum AliasList {
+    List(Vec<String>),
+    Text(String),
+}
+
+impl AliasList {
+    fn into_vec(self) -> Vec<String> {
+        match self {
+            AliasList::List(aliases) => aliases,
+            AliasList::Text(text) => text.split(',').map(str::to_string).collect(),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub(crate) struct NewAuthorSubmission {
     name: String,
     #[serde(default)]
+    bio: Option<String>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    birth_year: Option<i32>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    death_year: Option<i32>,
+    #[serde(default)]
+    nationality: Option<String>,
+    #[serde(default)]
+    website: Option<String>,
+    #[serde(default)]
+    aliases: Option<AliasList>,
+    #[serde(default)]
     created_at: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(default)]
     image: ImageData,
@@ -75,6 +106,14 @@ impl NewAuthorSubmission {
     fn into_parts(self) -> (NewAuthor, Option<String>) {
         let author = NewAuthor {
             name: self.name,
+            details: AuthorDetails {
+                bio: self.bio,
+                birth_year: self.birth_year,
+                death_year: self.death_year,
+                nationality: self.nationality,
+                website: self.website,
+                aliases: self.aliases.map(AliasList::into_vec).unwrap_or_default(),
+            },
             created_at: self.created_at,
         };
         (author, self.image.into_inner())
@@ -93,6 +132,10 @@ pub(crate) async fn create_author(
     let (submission, source) = payload.into_parts();
     let (new_author, image_data_url) = submission.into_parts();
     let new_author = new_author.normalize();
+    new_author
+        .details
+        .validate()
+        .map_err(AppError::validation)?;
     let user_id = auth_user.effective.id;
     let author = state
         .author_service
@@ -101,7 +144,7 @@ pub(crate) async fn create_author(
         .map_err(AppError::from)?;
 
     info!(author_id = %author.id, name = %author.name, "author created");
-    state.stats_invalidator.invalidate(user_id);
+    state.stats_invalidator.invalidate(user_id).await;
 
     save_deferred_image(
         &state,
@@ -140,6 +183,22 @@ pub(crate) struct UpdateAuthorSubmission {
     #[serde(default)]
     name: Option<String>,
     #[serde(default)]
+    bio: Option<String>,
+    /// An empty value clears the year.
+    #[serde(default, deserialize_with = "clearable")]
+    #[allow(clippy::option_option)] // absent vs cleared
+    birth_year: Option<Option<i32>>,
+    /// An empty value clears the year.
+    #[serde(default, deserialize_with = "clearable")]
+    #[allow(clippy::option_option)] // absent vs cleared
+    death_year: Option<Option<i32>>,
+    #[serde(default)]
+    nationality: Option<String>,
+    #[serde(default)]
+    website: Option<String>,
+    #[serde(default)]
+    aliases: Option<AliasList>,
+    #[serde(default)]
     created_at: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(default)]
     image: ImageData,
@@ -149,13 +208,29 @@ impl UpdateAuthorSubmission {
     fn into_parts(self) -> (UpdateAuthor, Option<String>) {
         let update = UpdateAuthor {
             name: self.name,
+            bio: self.bio,
+            birth_year: self.birth_year,
+            death_year: self.death_year,
+            nationality: self.nationality,
+            website: self.website,
+            aliases: self.aliases.map(AliasList::into_vec),
             created_at: self.created_at,
         };
         (update, self.image.into_inner())
     }
 }
 
-impl_has_changes!(UpdateAuthor, name, created_at);
+impl_has_changes!(
+    UpdateAuthor,
+    name,
+    bio,
+    birth_year,
+    death_year,
+    nationality,
+    website,
+    aliases,
+    created_at
+);
 
 #[tracing::instrument(skip(state, auth_user, headers))]
 pub(crate) async fn update_author(
@@ -167,19 +242,33 @@ pub(crate) async fn update_author(
 ) -> Result<Response, ApiError> {
     let (submission, source) = payload.into_parts();
     let (update, image_data_url) = submission.into_parts();
+    let mut update = update.normalize();
 
     validate_update(&update, image_data_url.as_ref())?;
 
+    let existing = state.author_repo.get(id).await.map_err(AppError::from)?;
+    update
+        .validate_against(&existing)
+        .map_err(AppError::validation)?;
+    if let Some(aliases) = update.aliases.take() {
+        let name = update.name.as_deref().unwrap_or(&existing.name);
+        update.aliases = Some(normalize_aliases(aliases, name));
+    }
+
     let author = state
         .author_repo
         .update(id, update)
         .await
         .map_err(AppError::from)?;
     info!(%id, "author updated");
-    state.stats_invalidator.invalidate(auth_user.effective.id);
+    state
+        .stats_invalidator
+        .invalidate(auth_user.effective.id)
+        .await;
     state
         .timeline_invalidator
-        .invalidate("author", i64::from(id));
+        .invalidate("author", i64::from(id))
+        .await;
 
     save_deferred_image(
         &state,
@@ -233,11 +322,25 @@ pub(crate) async fn extract_author(
 
     if is_datastar_request(&headers) {
         use serde_json::Value;
+        let year =
+            |year: Option<i32>| Value::String(year.map(|y| y.to_string()).unwrap_or_default());
         let signals = vec![
             (
                 "_author-name",
                 Value::String(result.name.unwrap_or_default()),
             ),
+            ("_author-bio", Value::String(result.bio.unwrap_or_default())),
+            ("_author-birth-year", year(result.birth_year)),
+            ("_author-death-year", year(result.death_year)),
+            (
+                "_author-nationality",
+                Value::String(result.nationality.unwrap_or_default()),
+            ),
+            (
+                "_author-website",
+                Value::String(result.website.unwrap_or_default()),
+            ),
+            ("_author-aliases", Value::String(result.aliases.join(", "))),
             ("_extracted", Value::Bool(true)),
         ];
         crate::application::routes::support::render_signals_json(&signals).map_err(ApiError::from)
@@ -11,15 +11,17 @@ use crate::application::routes::api::images::save_deferred_image;
 use crate::application::routes::api::macros::{define_delete_handler, define_enriched_get_handler};
 use crate::application::routes::support::{
     FlexiblePayload, ListQuery, PayloadSource, empty_string_as_none, empty_strings_as_vec_i64,
-    impl_has_changes, is_datastar_request, render_redirect_script, validate_update,
+    impl_has_changes, is_datastar_request, parse_filter_query, render_redirect_script,
+    validate_update,
 };
 use crate::application::state::AppState;
 use crate::domain::book_items::{
     AuthorRole, BookAuthor, BookSortKey, BookWithAuthors, NewBook, UpdateBook,
 };
+use crate::domain::filters::FilterTarget;
 use crate::domain::ids::{AuthorId, BookId, GenreId};
 use crate::domain::images::ImageData;
-use crate::domain::listing::ListRequest;
+use crate::domain::listing::{ListRequest, Page, SortKey};
 use crate::presentation::web::templates::BookListTemplate;
 use crate::presentation::web::views::{BookView, ListNavigator, Paginated};
 use tracing::info;
@@ -34,11 +36,15 @@ pub(crate) async fn load_book_page(
     search: Option<&str>,
     user_id: Option<crate::domain::ids::UserId>,
 ) -> Result<(Paginated<BookView>, ListNavigator<BookSortKey>), AppError> {
-    let page = state
-        .book_repo
-        .list(&request, search)
-        .await
-        .map_err(AppError::from)?;
+    let query = parse_filter_query(search, FilterTarget::Books);
+    let page = match &query {
+        Ok(query) => state
+            .book_repo
+            .list(&request, query.as_ref())
+            .await
+            .map_err(AppError::from)?,
+        Err(_) => Page::empty(),
+    };
 
     let library_ids = if let Some(uid) = user_id {
         state
@@ -50,13 +56,17 @@ pub(crate) async fn load_book_page(
         std::collections::HashSet::new()
     };
 
-    Ok(crate::application::routes::support::build_page_view(
+    let (books, navigator) = crate::application::routes::support::build_page_view(
         page,
         request,
         |bwa| BookView::from_domain(bwa, &library_ids),
         BOOK_PAGE_PATH,
         BOOK_FRAGMENT_PATH,
         search.map(String::from),
+    );
+    Ok((
+        books,
+        navigator.with_search_error(query.err().map(|err| err.to_string())),
     ))
 }
 
@@ -69,6 +79,11 @@ pub(crate) struct NewBookSubmission {
     description: Option<String>,
     #[serde(default, deserialize_with = "empty_string_as_none")]
     page_count: Option<i32>,
+    /// Audiobook length; forms split it into hours and minutes.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    duration_hours: Option<i32>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    duration_minutes: Option<i32>,
     #[serde(default, deserialize_with = "empty_string_as_none")]
     year_published: Option<i32>,
     #[serde(default)]
@@ -126,6 +141,7 @@ impl NewBookSubmission {
                 isbn: self.isbn,
                 description: self.description,
                 page_count: self.page_count,
+                duration_minutes: total_duration(self.duration_hours, self.duration_minutes),
                 year_published: self.year_published,
                 publisher: self.publisher,
                 language: self.language,
@@ -139,6 +155,19 @@ impl NewBookSubmission {
     }
 }
 
+/// Combines the submitted hours and minutes into a running time in minutes.
+pub(crate) fn total_duration(hours: Option<i32>, minutes: Option<i32>) -> Option<i32> {
+    if hours.is_none() && minutes.is_none() {
+        return None;
+    }
+    Some(
+        hours
+            .unwrap_or(0)
+            .saturating_mul(60)
+            .saturating_add(minutes.unwrap_or(0)),
+    )
+}
+
 #[tracing::instrument(skip(state, auth_user, headers, query))]
 pub(crate) async fn create_book(
     State(state): State<AppState>,
@@ -163,7 +192,7 @@ pub(crate) async fn create_book(
         .map_err(AppError::from)?;
 
     info!(book_id = %book.id, title = %book.title, "book created");
-    state.stats_invalidator.invalidate(user_id);
+    state.stats_invalidator.invalidate(user_id).await;
 
     save_deferred_image(
         &state,
@@ -203,9 +232,24 @@ pub(crate) async fn create_book(
 #[tracing::instrument(skip(state))]
 pub(crate) async fn list_books(
     State(state): State<AppState>,
+    Query(params): Query<BooksQuery>,
 ) -> Result<Json<Vec<BookWithAuthors>>, ApiError> {
-    let books = state.book_repo.list_all().await.map_err(AppError::from)?;
-    Ok(Json(books))
+    let query =
+        parse_filter_query(params.q.as_deref(), FilterTarget::Books).map_err(AppError::from)?;
+    let sort_key = BookSortKey::default();
+    let request = ListRequest::show_all(sort_key, sort_key.default_direction());
+    let page = state
+        .book_repo
+        .list(&request, query.as_ref())
+        .await
+        .map_err(AppError::from)?;
+    Ok(Json(page.items))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BooksQuery {
+    /// Filter query, e.g. `genre:fantasy year>=2000 author:"Le Guin"`.
+    pub q: Option<String>,
 }
 
 define_enriched_get_handler!(
@@ -226,6 +270,11 @@ pub(crate) struct UpdateBookSubmission {
     description: Option<String>,
     #[serde(default, deserialize_with = "empty_string_as_none")]
     page_count: Option<i32>,
+    /// Audiobook length; forms split it into hours and minutes.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    duration_hours: Option<i32>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    duration_minutes: Option<i32>,
     #[serde(default, deserialize_with = "empty_string_as_none")]
     year_published: Option<i32>,
     #[serde(default)]
@@ -289,6 +338,7 @@ impl UpdateBookSubmission {
             isbn: self.isbn,
             description: self.description,
             page_count: self.page_count,
+            duration_minutes: total_duration(self.duration_hours, self.duration_minutes),
             year_published: self.year_published,
             publisher: self.publisher,
             language: self.language,
@@ -308,6 +358,7 @@ impl_has_changes!(
     isbn,
     description,
     page_count,
+    duration_minutes,
     year_published,
     publisher,
     language,
@@ -338,8 +389,14 @@ pub(crate) async fn update_book(
         .map_err(AppError::from)?;
 
     info!(%id, "book updated");
-    state.stats_invalidator.invalidate(auth_user.effective.id);
-    state.timeline_invalidator.invalidate("book", i64::from(id));
+    state
+        .stats_invalidator
+        .invalidate(auth_user.effective.id)
+        .await;
+    state
+        .timeline_invalidator
+        .invalidate("book", i64::from(id))
+        .await;
 
     if let Some(cover_id) = &selected_cover_id {
         super::scan::promote_cover_suggestion(&state, "book", i64::from(id), cover_id).await;
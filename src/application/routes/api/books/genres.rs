@@ -11,8 +11,8 @@ use crate::application::routes::api::macros::{
 };
 use crate::application::routes::support::impl_has_changes;
 use crate::application::routes::support::{
-    FlexiblePayload, ListQuery, PayloadSource, is_datastar_request, render_redirect_script,
-    update_response, validate_update,
+    FlexiblePayload, ListQuery, PayloadSource, clearable, empty_string_as_none,
+    is_datastar_request, render_redirect_script, update_response, validate_update,
 };
 use crate::application::state::AppState;
 use crate::domain::genres::{Genre, GenreSortKey, NewGenre, UpdateGenre};
@@ -60,9 +60,30 @@ pub(crate) async fn list_genres(
     Ok(Json(genres))
 }
 
+/// Rejects parents that do not exist, and parents that would put `id` under
+/// itself or one of its own subgenres.
+async fn validate_parent(
+    state: &AppState,
+    id: Option<GenreId>,
+    parent_id: GenreId,
+) -> Result<(), AppError> {
+    let tree = state.genre_repo.tree().await.map_err(AppError::from)?;
+    if tree.get(parent_id).is_none() {
+        return Err(AppError::validation("parent genre does not exist"));
+    }
+    if id.is_some_and(|id| tree.would_create_cycle(id, parent_id)) {
+        return Err(AppError::validation(
+            "a genre cannot sit under itself or one of its subgenres",
+        ));
+    }
+    Ok(())
+}
+
 #[derive(Debug, Deserialize)]
 pub(crate) struct NewGenreSubmission {
     name: String,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    parent_id: Option<i64>,
     #[serde(default)]
     created_at: Option<chrono::DateTime<chrono::Utc>>,
 }
@@ -71,6 +92,7 @@ impl NewGenreSubmission {
     fn into_new(self) -> NewGenre {
         NewGenre {
             name: self.name,
+            parent_id: self.parent_id.map(GenreId::new),
             created_at: self.created_at,
         }
     }
@@ -88,6 +110,9 @@ pub(crate) async fn create_genre(
     let (submission, source) = payload.into_parts();
     let new_genre = submission.into_new();
     let new_genre = new_genre.normalize();
+    if let Some(parent_id) = new_genre.parent_id {
+        validate_parent(&state, None, parent_id).await?;
+    }
     let user_id = auth_user.effective.id;
     let genre = state
         .genre_service
@@ -96,7 +121,7 @@ pub(crate) async fn create_genre(
         .map_err(AppError::from)?;
 
     info!(genre_id = %genre.id, name = %genre.name, "genre created");
-    state.stats_invalidator.invalidate(user_id);
+    state.stats_invalidator.invalidate(user_id).await;
 
     let detail_url = format!("/genres/{}", genre.id);
 
@@ -126,6 +151,10 @@ define_get_handler!(get_genre, GenreId, Genre, genre_repo);
 pub(crate) struct UpdateGenreSubmission {
     #[serde(default)]
     name: Option<String>,
+    /// An empty value moves the genre back to the top level.
+    #[serde(default, deserialize_with = "clearable")]
+    #[allow(clippy::option_option)] // absent vs cleared
+    parent_id: Option<Option<i64>>,
     #[serde(default)]
     created_at: Option<chrono::DateTime<chrono::Utc>>,
 }
@@ -134,12 +163,13 @@ impl UpdateGenreSubmission {
     fn into_update(self) -> UpdateGenre {
         UpdateGenre {
             name: self.name,
+            parent_id: self.parent_id.map(|id| id.map(GenreId::new)),
             created_at: self.created_at,
         }
     }
 }
 
-impl_has_changes!(UpdateGenre, name, created_at);
+impl_has_changes!(UpdateGenre, name, parent_id, created_at);
 
 #[tracing::instrument(skip(state, auth_user, headers))]
 pub(crate) async fn update_genre(
@@ -153,6 +183,9 @@ pub(crate) async fn update_genre(
     let update = submission.into_update();
 
     validate_update(&update, Option::<&String>::None)?;
+    if let Some(Some(parent_id)) = update.parent_id {
+        validate_parent(&state, Some(id), parent_id).await?;
+    }
 
     let genre = state
         .genre_repo
@@ -160,10 +193,14 @@ pub(crate) async fn update_genre(
         .await
         .map_err(AppError::from)?;
     info!(%id, "genre updated");
-    state.stats_invalidator.invalidate(auth_user.effective.id);
+    state
+        .stats_invalidator
+        .invalidate(auth_user.effective.id)
+        .await;
     state
         .timeline_invalidator
-        .invalidate("genre", i64::from(id));
+        .invalidate("genre", i64::from(id))
+        .await;
 
     let detail_url = format!("/genres/{}", genre.id);
     update_response(&headers, source, &detail_url, Json(genre).into_response())
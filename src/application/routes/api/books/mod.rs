@@ -4,4 +4,6 @@ pub(crate) mod books;
 pub(crate) mod genres;
 pub(crate) mod readings;
 pub(crate) mod scan;
+pub(crate) mod shelves;
+pub(crate) mod tags;
 pub(crate) mod user_books;
@@ -11,13 +11,14 @@ use crate::application::errors::{ApiError, AppError};
 use crate::application::routes::api::macros::define_enriched_get_handler;
 use crate::application::routes::support::{
     FlexiblePayload, ListQuery, PayloadSource, empty_string_as_none, impl_has_changes,
-    is_datastar_request, validate_update,
+    is_datastar_request, parse_filter_query, validate_update,
 };
 use crate::application::state::AppState;
+use crate::domain::filters::FilterTarget;
 use crate::domain::ids::{BookId, ReadingId, UserId};
-use crate::domain::listing::ListRequest;
+use crate::domain::listing::{ListRequest, Page};
 use crate::domain::readings::{
-    NewReading, QuickReview, ReadingFilter, ReadingFormat, ReadingSortKey, ReadingStatus,
+    NewReading, QuickReview, Reading, ReadingFilter, ReadingFormat, ReadingSortKey, ReadingStatus,
     ReadingWithBook, UpdateReading,
 };
 use crate::domain::user_books::{NewUserBook, Shelf, user_book_timeline_event};
@@ -82,11 +83,15 @@ pub(crate) async fn load_reading_page(
     request: ListRequest<ReadingSortKey>,
     search: Option<&str>,
 ) -> Result<ReadingPageData, AppError> {
-    let page = state
-        .reading_repo
-        .list(filter, &request, search)
-        .await
-        .map_err(AppError::from)?;
+    let query = parse_filter_query(search, FilterTarget::Readings);
+    let page = match &query {
+        Ok(query) => state
+            .reading_repo
+            .list(filter, &request, query.as_ref())
+            .await
+            .map_err(AppError::from)?,
+        Err(_) => Page::empty(),
+    };
 
     let (readings, navigator) = crate::application::routes::support::build_page_view(
         page,
@@ -99,7 +104,7 @@ pub(crate) async fn load_reading_page(
 
     Ok(ReadingPageData {
         readings,
-        navigator,
+        navigator: navigator.with_search_error(query.err().map(|err| err.to_string())),
     })
 }
 
@@ -119,6 +124,8 @@ pub(crate) struct NewReadingSubmission {
     #[serde(default, deserialize_with = "deserialize_quick_reviews")]
     quick_reviews: Vec<QuickReview>,
     #[serde(default)]
+    private: Option<bool>,
+    #[serde(default)]
     created_at: Option<DateTime<Utc>>,
     #[serde(default)]
     book_club: Option<bool>,
@@ -163,6 +170,7 @@ impl NewReadingSubmission {
             finished_at,
             rating,
             quick_reviews: self.quick_reviews,
+            private: self.private.unwrap_or(false),
             created_at: self.created_at,
         })
     }
@@ -211,7 +219,15 @@ pub(crate) async fn create_reading(
     }
 
     info!(reading_id = %reading.id, "reading created");
-    state.stats_invalidator.invalidate(user_id);
+    record_goal_milestones(&state, &reading).await;
+    state
+        .federation
+        .queue_finished_reading(None, &reading)
+        .await;
+    state
+        .stats_invalidator
+        .invalidate_readings(user_id, &[&reading])
+        .await;
 
     let detail_url = format!("/readings/{}", reading.id);
 
@@ -250,13 +266,15 @@ pub(crate) async fn list_readings(
         Some(book_id) => ReadingFilter::for_book(book_id),
         None => ReadingFilter::all(),
     };
+    let query =
+        parse_filter_query(params.q.as_deref(), FilterTarget::Readings).map_err(AppError::from)?;
     let request = ListRequest::show_all(
         ReadingSortKey::CreatedAt,
         crate::domain::listing::SortDirection::Desc,
     );
     let page = state
         .reading_repo
-        .list(filter, &request, None)
+        .list(filter, &request, query.as_ref())
         .await
         .map_err(AppError::from)?;
     Ok(Json(page.items))
@@ -287,6 +305,10 @@ pub(crate) struct UpdateReadingSubmission {
     #[serde(default, deserialize_with = "deserialize_quick_reviews")]
     quick_reviews: Vec<QuickReview>,
     #[serde(default)]
+    private: Option<bool>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    current_page: Option<i32>,
+    #[serde(default)]
     created_at: Option<DateTime<Utc>>,
     #[serde(default)]
     book_club: Option<bool>,
@@ -303,6 +325,10 @@ impl UpdateReadingSubmission {
             ));
         }
 
+        if self.current_page.is_some_and(|page| page < 0) {
+            return Err(AppError::validation("current page can't be negative"));
+        }
+
         let format = parse_optional_enum::<ReadingFormat>(self.format);
 
         Ok(UpdateReading {
@@ -317,6 +343,8 @@ impl UpdateReadingSubmission {
             } else {
                 Some(self.quick_reviews)
             },
+            private: self.private,
+            current_page: self.current_page,
             created_at: self.created_at,
         })
     }
@@ -331,6 +359,8 @@ impl_has_changes!(
     finished_at,
     rating,
     quick_reviews,
+    private,
+    current_page,
     created_at
 );
 
@@ -355,24 +385,11 @@ pub(crate) async fn update_reading(
         return Err(AppError::NotFound.into());
     }
 
-    let is_finishing = update
-        .status
-        .as_ref()
-        .is_some_and(|s| matches!(s, ReadingStatus::Read));
-
-    let reading = if is_finishing {
-        state
-            .reading_service
-            .finish(id, update)
-            .await
-            .map_err(AppError::from)?
-    } else {
-        state
-            .reading_repo
-            .update(id, update)
-            .await
-            .map_err(AppError::from)?
-    };
+    let reading = state
+        .reading_service
+        .update(&existing, update)
+        .await
+        .map_err(AppError::from)?;
 
     // Update book_club flag on the UserBook if provided
     if let Some(book_club) = book_club
@@ -382,18 +399,27 @@ pub(crate) async fn update_reading(
             .await
         && user_book.book_club != book_club
         && let Err(err) = state
-            .user_book_repo
-            .set_book_club(user_book.id, book_club)
+            .user_book_service
+            .set_book_club(&user_book, book_club)
             .await
     {
         tracing::warn!(error = %err, "failed to update book_club on user_book");
     }
 
     info!(%id, "reading updated");
-    state.stats_invalidator.invalidate(auth_user.effective.id);
+    record_goal_milestones(&state, &reading).await;
+    state
+        .federation
+        .queue_finished_reading(Some(&existing), &reading)
+        .await;
+    state
+        .stats_invalidator
+        .invalidate_readings(auth_user.effective.id, &[&existing, &reading])
+        .await;
     state
         .timeline_invalidator
-        .invalidate("reading", i64::from(id));
+        .invalidate("reading", i64::from(id))
+        .await;
 
     if is_datastar_request(&headers) {
         let from_reading_page = headers
@@ -452,7 +478,10 @@ pub(crate) async fn delete_reading(
     }
 
     info!(%id, "reading deleted");
-    state.stats_invalidator.invalidate(auth_user.effective.id);
+    state
+        .stats_invalidator
+        .invalidate_readings(auth_user.effective.id, &[&existing])
+        .await;
 
     if is_datastar_request(&headers) {
         let from_data_page = headers
@@ -476,6 +505,8 @@ pub(crate) async fn delete_reading(
 #[derive(Debug, Deserialize)]
 pub struct ReadingsQuery {
     pub book_id: Option<BookId>,
+    /// Filter query, e.g. `status:read rating>=4 finished:2024`.
+    pub q: Option<String>,
 }
 
 async fn create_on_shelf(
@@ -517,7 +548,7 @@ async fn create_on_shelf(
         .map_err(AppError::from)?;
 
     info!(user_book_id = %user_book.id, %book_id, "book shelved");
-    state.stats_invalidator.invalidate(user_id);
+    state.stats_invalidator.invalidate(user_id).await;
 
     record_user_book_timeline_event(state, &user_book).await;
 
@@ -532,6 +563,20 @@ async fn create_on_shelf(
     }
 }
 
+/// Goals only count finished readings, so only those can pass a milestone.
+async fn record_goal_milestones(state: &AppState, reading: &Reading) {
+    if reading.status != ReadingStatus::Read {
+        return;
+    }
+    if let Err(err) = state
+        .goal_service
+        .record_milestones(reading.user_id, Utc::now().date_naive())
+        .await
+    {
+        warn!(error = %err, reading_id = %reading.id, "failed to record goal milestones");
+    }
+}
+
 async fn record_user_book_timeline_event(
     state: &AppState,
     user_book: &crate::domain::user_books::UserBook,
@@ -553,12 +598,20 @@ async fn record_user_book_timeline_event(
         }
     }
 
-    if let Err(err) = state
+    match state
         .timeline_repo
         .insert(user_book_timeline_event(user_book, &book, &authors))
         .await
     {
-        warn!(error = %err, user_book_id = %user_book.id, "failed to record shelf timeline event");
+        Ok(event) => {
+            state
+                .timeline_publisher
+                .publish(event, user_book.user_id)
+                .await;
+        }
+        Err(err) => {
+            warn!(error = %err, user_book_id = %user_book.id, "failed to record shelf timeline event");
+        }
     }
 }
 
@@ -8,12 +8,13 @@ use tracing::info;
 
 use crate::application::auth::AuthenticatedUser;
 use crate::application::errors::{ApiError, AppError};
+use crate::application::routes::api::books::books::total_duration;
 use crate::application::routes::api::images::{resolve_image_url, save_deferred_image};
 use crate::application::routes::support::{
     FlexiblePayload, empty_string_as_none, is_datastar_request,
 };
 use crate::application::state::AppState;
-use crate::domain::books::authors::NewAuthor;
+use crate::domain::books::authors::{AuthorDetails, NewAuthor};
 use crate::domain::books::books::{AuthorRole, BookAuthor, NewBook};
 use crate::domain::errors::RepositoryError;
 use crate::domain::ids::{BookId, GenreId};
@@ -33,14 +34,14 @@ pub(crate) async fn extract_book_scan(
 ) -> Result<Response, ApiError> {
     let (input, _) = payload.into_parts();
 
-    let available_genres = load_genre_names(&state).await;
+    let genre_outline = load_genre_outline(&state).await;
     let (result, usage) = ai::extract_book(
         &state.http_client,
         &state.openrouter_url,
         &state.openrouter_api_key,
         &state.openrouter_model,
         &input,
-        &available_genres,
+        &genre_outline,
     )
     .await
     .map_err(ApiError::from)?;
@@ -108,6 +109,28 @@ fn build_extraction_signals(
             "_book-pages",
             Value::String(result.page_count.map(|p| p.to_string()).unwrap_or_default()),
         ),
+        (
+            "_book-narrator",
+            Value::String(result.narrator.unwrap_or_default()),
+        ),
+        (
+            "_book-duration-hours",
+            Value::String(
+                result
+                    .duration_minutes
+                    .map(|m| (m / 60).to_string())
+                    .unwrap_or_default(),
+            ),
+        ),
+        (
+            "_book-duration-minutes",
+            Value::String(
+                result
+                    .duration_minutes
+                    .map(|m| (m % 60).to_string())
+                    .unwrap_or_default(),
+            ),
+        ),
         (
             "_book-year",
             Value::String(
@@ -229,6 +252,12 @@ pub(crate) struct BookScanSubmission {
     #[serde(default)]
     book_pages: Option<String>,
     #[serde(default)]
+    book_narrator: Option<String>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    book_duration_hours: Option<i32>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    book_duration_minutes: Option<i32>,
+    #[serde(default)]
     book_year: Option<String>,
     #[serde(default)]
     book_publisher: Option<String>,
@@ -273,14 +302,14 @@ async fn extract_into_submission(
         image: submission.image.take(),
         prompt: submission.prompt.take(),
     };
-    let available_genres = load_genre_names(state).await;
+    let genre_outline = load_genre_outline(state).await;
     let (result, usage) = ai::extract_book(
         &state.http_client,
         &state.openrouter_url,
         &state.openrouter_api_key,
         &state.openrouter_model,
         &input,
-        &available_genres,
+        &genre_outline,
     )
     .await
     .map_err(ApiError::from)?;
@@ -300,6 +329,13 @@ async fn extract_into_submission(
     if let Some(pages) = result.page_count {
         submission.book_pages = Some(pages.to_string());
     }
+    if result.narrator.is_some() {
+        submission.book_narrator = result.narrator;
+    }
+    if let Some(minutes) = result.duration_minutes {
+        submission.book_duration_hours = Some(minutes / 60);
+        submission.book_duration_minutes = Some(minutes % 60);
+    }
     if let Some(year) = result.year_published {
         submission.book_year = Some(year.to_string());
     }
@@ -395,15 +431,28 @@ async fn submit_new_book(
     // Resolve genre names to IDs if user manually edited the genre text
     resolve_submission_genres(state, &mut submission).await;
 
+    let mut authors = vec![BookAuthor {
+        author_id: author.id,
+        role: AuthorRole::default(),
+    }];
+    if let Some(narrator) = normalize_opt(submission.book_narrator.take()) {
+        let narrator = resolve_or_create_author(state, &narrator, user_id).await?;
+        authors.push(BookAuthor {
+            author_id: narrator.id,
+            role: AuthorRole::Narrator,
+        });
+    }
+
     let new_book = NewBook {
         title: submission.book_title.trim().to_string(),
-        authors: vec![BookAuthor {
-            author_id: author.id,
-            role: AuthorRole::default(),
-        }],
+        authors,
         isbn: normalize_opt(submission.book_isbn),
         description: normalize_opt(submission.book_description),
         page_count: submission.book_pages.and_then(|s| s.parse().ok()),
+        duration_minutes: total_duration(
+            submission.book_duration_hours,
+            submission.book_duration_minutes,
+        ),
         year_published: submission.book_year.and_then(|s| s.parse().ok()),
         publisher: normalize_opt(submission.book_publisher),
         language: normalize_opt(submission.book_language),
@@ -440,7 +489,7 @@ async fn submit_new_book(
         submission.book_club.unwrap_or(false),
     )
     .await;
-    state.stats_invalidator.invalidate(user_id);
+    state.stats_invalidator.invalidate(user_id).await;
 
     let redirect = format!("/books/{}", book.id);
     let book_id = book.id.into_inner();
@@ -490,6 +539,7 @@ async fn resolve_or_create_author(
 ) -> Result<crate::domain::books::authors::Author, ApiError> {
     let new_author = NewAuthor {
         name: name.to_string(),
+        details: AuthorDetails::default(),
         created_at: None,
     }
     .normalize();
@@ -551,15 +601,13 @@ fn normalize_opt(value: Option<String>) -> Option<String> {
 }
 
 /// Load all genre names for the AI prompt.
-async fn load_genre_names(state: &AppState) -> Vec<String> {
-    use crate::domain::genres::GenreSortKey;
-    use crate::domain::listing::SortDirection;
+/// The genre hierarchy as an indented outline for the extraction prompt.
+async fn load_genre_outline(state: &AppState) -> String {
     state
         .genre_repo
-        .list_all_sorted(GenreSortKey::Name, SortDirection::Asc)
+        .tree()
         .await
-        .ok()
-        .map(|genres| genres.into_iter().map(|g| g.name).collect())
+        .map(|tree| tree.outline())
         .unwrap_or_default()
 }
 
@@ -592,6 +640,7 @@ pub(crate) async fn resolve_or_create_genre(state: &AppState, name: &str) -> Opt
         Err(RepositoryError::NotFound) => {
             let new_genre = crate::domain::genres::NewGenre {
                 name: trimmed.to_string(),
+                parent_id: None,
                 created_at: None,
             };
             match state.genre_repo.insert(new_genre).await {
@@ -687,7 +736,7 @@ async fn submit_existing_book(
         submission.book_club.unwrap_or(false),
     )
     .await;
-    state.stats_invalidator.invalidate(user_id);
+    state.stats_invalidator.invalidate(user_id).await;
 
     let redirect = format!("/books/{}", book.id);
     let book_id_raw = book.id.into_inner();
@@ -0,0 +1,327 @@
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Redirect, Response};
+use serde::Deserialize;
+use tracing::info;
+
+use crate::application::auth::AuthenticatedUser;
+use crate::application::errors::{ApiError, AppError};
+use crate::application::routes::support::{
+    FlexiblePayload, PayloadSource, impl_has_changes, is_datastar_request, render_redirect_script,
+    update_response, validate_update,
+};
+use crate::application::state::AppState;
+use crate::domain::filters::{FilterQuery, FilterTarget};
+use crate::domain::ids::{BookId, ShelfId, UserId};
+use crate::domain::shelves::{
+    NewSavedShelf, SavedShelf, SavedShelfWithBooks, ShelfKind, UpdateSavedShelf,
+};
+
+pub(crate) const SHELF_PAGE_PATH: &str = "/shelves";
+
+/// Fetches a shelf, treating shelves owned by other users as missing.
+pub(crate) async fn owned_shelf(
+    state: &AppState,
+    id: ShelfId,
+    user_id: UserId,
+) -> Result<SavedShelf, AppError> {
+    let shelf = state.shelf_repo.get(id).await.map_err(AppError::from)?;
+    if shelf.user_id != user_id {
+        return Err(AppError::NotFound);
+    }
+    Ok(shelf)
+}
+
+/// Rejects smart shelf queries that would not parse when the shelf is opened.
+fn validate_query(query: &str) -> Result<(), AppError> {
+    if query.trim().is_empty() {
+        return Err(AppError::validation("smart shelves need a filter query"));
+    }
+    FilterQuery::parse(query, FilterTarget::UserBooks)?;
+    Ok(())
+}
+
+#[tracing::instrument(skip(state, auth_user))]
+pub(crate) async fn list_shelves(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+) -> Result<Json<Vec<SavedShelf>>, ApiError> {
+    let shelves = state
+        .shelf_repo
+        .list_by_user(auth_user.effective.id)
+        .await
+        .map_err(AppError::from)?;
+    Ok(Json(shelves))
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct NewShelfSubmission {
+    name: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    kind: Option<String>,
+    #[serde(default)]
+    query: Option<String>,
+    #[serde(default)]
+    pinned: Option<bool>,
+    #[serde(default)]
+    public: Option<bool>,
+}
+
+impl NewShelfSubmission {
+    fn into_new(self, user_id: UserId) -> Result<NewSavedShelf, AppError> {
+        let kind = match self.kind.as_deref().map(str::trim) {
+            None | Some("") => ShelfKind::default(),
+            Some(value) => value
+                .parse()
+                .map_err(|()| AppError::validation("kind must be manual or smart"))?,
+        };
+
+        let new_shelf = NewSavedShelf {
+            user_id,
+            name: self.name,
+            description: self.description,
+            kind,
+            query: self.query,
+            pinned: self.pinned.unwrap_or(false),
+            public: self.public.unwrap_or(false),
+        }
+        .normalize();
+
+        if new_shelf.name.is_empty() {
+            return Err(AppError::validation("shelf name cannot be empty"));
+        }
+        if new_shelf.kind == ShelfKind::Smart {
+            validate_query(new_shelf.query.as_deref().unwrap_or_default())?;
+        }
+        Ok(new_shelf)
+    }
+}
+
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn create_shelf(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    payload: FlexiblePayload<NewShelfSubmission>,
+) -> Result<Response, ApiError> {
+    let (submission, source) = payload.into_parts();
+    let new_shelf = submission.into_new(auth_user.effective.id)?;
+
+    let shelf = state
+        .shelf_repo
+        .insert(new_shelf)
+        .await
+        .map_err(AppError::from)?;
+
+    info!(shelf_id = %shelf.id, name = %shelf.name, kind = shelf.kind.as_str(), "shelf created");
+
+    let detail_url = format!("/shelves/{}", shelf.id);
+    if is_datastar_request(&headers) {
+        render_redirect_script(&detail_url).map_err(ApiError::from)
+    } else if matches!(source, PayloadSource::Form) {
+        Ok(Redirect::to(&detail_url).into_response())
+    } else {
+        Ok((StatusCode::CREATED, Json(shelf)).into_response())
+    }
+}
+
+#[tracing::instrument(skip(state, auth_user))]
+pub(crate) async fn get_shelf(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path(id): Path<ShelfId>,
+) -> Result<Json<SavedShelfWithBooks>, ApiError> {
+    let shelf = owned_shelf(&state, id, auth_user.effective.id).await?;
+    let books = state
+        .shelf_service
+        .books(&shelf)
+        .await
+        .map_err(AppError::from)?;
+    Ok(Json(SavedShelfWithBooks { shelf, books }))
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct UpdateShelfSubmission {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    query: Option<String>,
+    #[serde(default)]
+    pinned: Option<bool>,
+    #[serde(default)]
+    public: Option<bool>,
+}
+
+impl UpdateShelfSubmission {
+    fn into_update(self) -> UpdateSavedShelf {
+        UpdateSavedShelf {
+            name: self.name,
+            description: self.description,
+            query: self.query,
+            pinned: self.pinned,
+            public: self.public,
+        }
+        .normalize()
+    }
+}
+
+impl_has_changes!(UpdateSavedShelf, name, description, query, pinned, public);
+
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn update_shelf(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    Path(id): Path<ShelfId>,
+    payload: FlexiblePayload<UpdateShelfSubmission>,
+) -> Result<Response, ApiError> {
+    let (submission, source) = payload.into_parts();
+    let update = submission.into_update();
+    validate_update(&update, Option::<&String>::None)?;
+
+    let existing = owned_shelf(&state, id, auth_user.effective.id).await?;
+    if update.name.as_deref() == Some("") {
+        return Err(AppError::validation("shelf name cannot be empty").into());
+    }
+    if let Some(query) = &update.query {
+        if existing.kind == ShelfKind::Manual {
+            return Err(AppError::validation("manual shelves do not have a query").into());
+        }
+        validate_query(query)?;
+    }
+
+    let shelf = state
+        .shelf_repo
+        .update(id, update)
+        .await
+        .map_err(AppError::from)?;
+    info!(%id, "shelf updated");
+
+    let detail_url = format!("/shelves/{}", shelf.id);
+    update_response(&headers, source, &detail_url, Json(shelf).into_response())
+}
+
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn delete_shelf(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    Path(id): Path<ShelfId>,
+) -> Result<Response, ApiError> {
+    owned_shelf(&state, id, auth_user.effective.id).await?;
+    state.shelf_repo.delete(id).await.map_err(AppError::from)?;
+    info!(%id, "shelf deleted");
+
+    if is_datastar_request(&headers) {
+        render_redirect_script(SHELF_PAGE_PATH).map_err(ApiError::from)
+    } else {
+        Ok(StatusCode::NO_CONTENT.into_response())
+    }
+}
+
+/// Fetches a shelf for editing its book list, rejecting smart shelves.
+async fn owned_manual_shelf(
+    state: &AppState,
+    id: ShelfId,
+    user_id: UserId,
+) -> Result<SavedShelf, AppError> {
+    let shelf = owned_shelf(state, id, user_id).await?;
+    if shelf.kind == ShelfKind::Smart {
+        return Err(AppError::validation(
+            "smart shelves are filled by their query; edit the query instead",
+        ));
+    }
+    Ok(shelf)
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct AddShelfBookSubmission {
+    book_id: BookId,
+}
+
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn add_shelf_book(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    Path(id): Path<ShelfId>,
+    payload: FlexiblePayload<AddShelfBookSubmission>,
+) -> Result<Response, ApiError> {
+    let (submission, source) = payload.into_parts();
+    owned_manual_shelf(&state, id, auth_user.effective.id).await?;
+
+    state
+        .shelf_repo
+        .add_book(id, submission.book_id)
+        .await
+        .map_err(AppError::from)?;
+    info!(%id, book_id = %submission.book_id, "book added to shelf");
+
+    let detail_url = format!("/shelves/{id}");
+    update_response(
+        &headers,
+        source,
+        &detail_url,
+        StatusCode::NO_CONTENT.into_response(),
+    )
+}
+
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn remove_shelf_book(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    Path((id, book_id)): Path<(ShelfId, BookId)>,
+) -> Result<Response, ApiError> {
+    owned_manual_shelf(&state, id, auth_user.effective.id).await?;
+
+    state
+        .shelf_repo
+        .remove_book(id, book_id)
+        .await
+        .map_err(AppError::from)?;
+    info!(%id, %book_id, "book removed from shelf");
+
+    if is_datastar_request(&headers) {
+        render_redirect_script(&format!("/shelves/{id}")).map_err(ApiError::from)
+    } else {
+        Ok(StatusCode::NO_CONTENT.into_response())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct MoveShelfBookSubmission {
+    position: usize,
+}
+
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn move_shelf_book(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    Path((id, book_id)): Path<(ShelfId, BookId)>,
+    payload: FlexiblePayload<MoveShelfBookSubmission>,
+) -> Result<Response, ApiError> {
+    let (submission, source) = payload.into_parts();
+    owned_manual_shelf(&state, id, auth_user.effective.id).await?;
+
+    state
+        .shelf_repo
+        .move_book(id, book_id, submission.position)
+        .await
+        .map_err(AppError::from)?;
+    info!(%id, %book_id, position = submission.position, "shelf book moved");
+
+    let detail_url = format!("/shelves/{id}");
+    update_response(
+        &headers,
+        source,
+        &detail_url,
+        StatusCode::NO_CONTENT.into_response(),
+    )
+}
@@ -0,0 +1,166 @@
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+use tracing::info;
+
+use crate::application::auth::AuthenticatedUser;
+use crate::application::errors::{ApiError, AppError};
+use crate::application::routes::support::{
+    FlexiblePayload, is_datastar_request, render_redirect_script, update_response,
+};
+use crate::application::state::AppState;
+use crate::domain::ids::BookId;
+use crate::domain::tags::{MAX_TAG_LENGTH, TagCount, normalize_tag, normalize_tags};
+
+/// Default number of suggestions returned for tag autocomplete.
+const DEFAULT_TAG_LIMIT: u32 = 20;
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct TagListQuery {
+    #[serde(default)]
+    q: Option<String>,
+    #[serde(default)]
+    limit: Option<u32>,
+}
+
+/// Lists the current user's tags with usage counts. `q` narrows the result to
+/// tags starting with the given prefix, for autocomplete.
+#[tracing::instrument(skip(state, auth_user))]
+pub(crate) async fn list_tags(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Query(query): Query<TagListQuery>,
+) -> Result<Json<Vec<TagCount>>, ApiError> {
+    let prefix = query.q.as_deref().and_then(normalize_tag);
+    let tags = state
+        .tag_repo
+        .counts_for_user(
+            auth_user.effective.id,
+            prefix.as_deref(),
+            Some(query.limit.unwrap_or(DEFAULT_TAG_LIMIT)),
+        )
+        .await
+        .map_err(AppError::from)?;
+    Ok(Json(tags))
+}
+
+#[tracing::instrument(skip(state, auth_user))]
+pub(crate) async fn get_book_tags(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path(book_id): Path<BookId>,
+) -> Result<Json<Vec<String>>, ApiError> {
+    state.book_repo.get(book_id).await.map_err(AppError::from)?;
+    let tags = state
+        .tag_repo
+        .list_for_book(auth_user.effective.id, book_id)
+        .await
+        .map_err(AppError::from)?;
+    Ok(Json(tags))
+}
+
+/// Tags submitted either as a JSON list or as a comma-separated form field.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum TagList {
+    List(Vec<String>),
+    Text(String),
+}
+
+impl TagList {
+    fn normalize(self) -> Vec<String> {
+        match self {
+            TagList::List(tags) => normalize_tags(tags),
+            TagList::Text(text) => normalize_tags(text.split(',')),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct SetTagsSubmission {
+    tags: TagList,
+}
+
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn set_book_tags(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    Path(book_id): Path<BookId>,
+    payload: FlexiblePayload<SetTagsSubmission>,
+) -> Result<Response, ApiError> {
+    let (submission, source) = payload.into_parts();
+    let tags = submission.tags.normalize();
+
+    state.book_repo.get(book_id).await.map_err(AppError::from)?;
+    state
+        .tag_repo
+        .set_for_book(auth_user.effective.id, book_id, &tags)
+        .await
+        .map_err(AppError::from)?;
+    info!(%book_id, count = tags.len(), "book tags set");
+
+    let detail_url = format!("/books/{book_id}");
+    update_response(&headers, source, &detail_url, Json(tags).into_response())
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct AddTagSubmission {
+    tag: String,
+}
+
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn add_book_tag(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    Path(book_id): Path<BookId>,
+    payload: FlexiblePayload<AddTagSubmission>,
+) -> Result<Response, ApiError> {
+    let (submission, source) = payload.into_parts();
+    let tag = normalize_tag(&submission.tag).ok_or_else(|| {
+        AppError::validation(format!(
+            "tags must be between 1 and {MAX_TAG_LENGTH} characters"
+        ))
+    })?;
+
+    state.book_repo.get(book_id).await.map_err(AppError::from)?;
+    state
+        .tag_repo
+        .add(auth_user.effective.id, book_id, &tag)
+        .await
+        .map_err(AppError::from)?;
+    info!(%book_id, %tag, "book tag added");
+
+    let detail_url = format!("/books/{book_id}");
+    update_response(
+        &headers,
+        source,
+        &detail_url,
+        StatusCode::NO_CONTENT.into_response(),
+    )
+}
+
+#[tracing::instrument(skip(state, auth_user, headers))]
+pub(crate) async fn remove_book_tag(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    headers: HeaderMap,
+    Path((book_id, tag)): Path<(BookId, String)>,
+) -> Result<Response, ApiError> {
+    let tag = normalize_tag(&tag).ok_or(AppError::NotFound)?;
+    state
+        .tag_repo
+        .remove(auth_user.effective.id, book_id, &tag)
+        .await
+        .map_err(AppError::from)?;
+    info!(%book_id, %tag, "book tag removed");
+
+    if is_datastar_request(&headers) {
+        render_redirect_script(&format!("/books/{book_id}")).map_err(ApiError::from)
+    } else {
+        Ok(StatusCode::NO_CONTENT.into_response())
+    }
+}
@@ -1,5 +1,5 @@
 use axum::Json;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use serde::Deserialize;
@@ -7,9 +7,12 @@ use tracing::info;
 
 use crate::application::auth::AuthenticatedUser;
 use crate::application::errors::{ApiError, AppError};
+use crate::application::routes::support::parse_filter_query;
 use crate::application::state::AppState;
+use crate::domain::filters::FilterTarget;
 use crate::domain::ids::{BookId, UserBookId};
-use crate::domain::user_books::{NewUserBook, Shelf, UserBook};
+use crate::domain::tags::normalize_tags;
+use crate::domain::user_books::{NewUserBook, Shelf, UserBook, UserBookWithTags};
 
 #[derive(Debug, Deserialize)]
 pub(crate) struct NewUserBookSubmission {
@@ -18,6 +21,8 @@ pub(crate) struct NewUserBookSubmission {
     shelf: Option<String>,
     #[serde(default)]
     book_club: Option<bool>,
+    #[serde(default)]
+    tags: Vec<String>,
 }
 
 #[tracing::instrument(skip(state, auth_user))]
@@ -45,8 +50,17 @@ pub(crate) async fn create_user_book(
         .await
         .map_err(AppError::from)?;
 
+    let tags = normalize_tags(&submission.tags);
+    if !tags.is_empty() {
+        state
+            .tag_repo
+            .set_for_book(user_id, submission.book_id, &tags)
+            .await
+            .map_err(AppError::from)?;
+    }
+
     info!(user_book_id = %user_book.id, book_id = %submission.book_id, shelf = %shelf.as_str(), "user book created");
-    state.stats_invalidator.invalidate(user_id);
+    state.stats_invalidator.invalidate(user_id).await;
 
     Ok((StatusCode::CREATED, Json(user_book)).into_response())
 }
@@ -74,13 +88,16 @@ pub(crate) async fn move_user_book(
         .map_err(|()| AppError::validation("invalid shelf value"))?;
 
     let user_book = state
-        .user_book_repo
-        .move_shelf(id, shelf)
+        .user_book_service
+        .move_shelf(&existing, shelf)
         .await
         .map_err(AppError::from)?;
 
     info!(%id, shelf = %shelf.as_str(), "user book moved");
-    state.stats_invalidator.invalidate(auth_user.effective.id);
+    state
+        .stats_invalidator
+        .invalidate(auth_user.effective.id)
+        .await;
 
     Ok(Json(user_book))
 }
@@ -103,13 +120,16 @@ pub(crate) async fn set_book_club_user_book(
     }
 
     let user_book = state
-        .user_book_repo
-        .set_book_club(id, submission.book_club)
+        .user_book_service
+        .set_book_club(&existing, submission.book_club)
         .await
         .map_err(AppError::from)?;
 
     info!(%id, book_club = submission.book_club, "user book book_club updated");
-    state.stats_invalidator.invalidate(auth_user.effective.id);
+    state
+        .stats_invalidator
+        .invalidate(auth_user.effective.id)
+        .await;
 
     Ok(Json(user_book))
 }
@@ -132,7 +152,10 @@ pub(crate) async fn delete_user_book(
         .map_err(AppError::from)?;
 
     info!(%id, "user book deleted");
-    state.stats_invalidator.invalidate(auth_user.effective.id);
+    state
+        .stats_invalidator
+        .invalidate(auth_user.effective.id)
+        .await;
 
     Ok(StatusCode::NO_CONTENT)
 }
@@ -141,27 +164,36 @@ pub(crate) async fn delete_user_book(
 pub(crate) async fn list_user_books(
     State(state): State<AppState>,
     auth_user: AuthenticatedUser,
-) -> Result<Json<Vec<UserBook>>, ApiError> {
+    Query(params): Query<UserBooksQuery>,
+) -> Result<Json<Vec<UserBookWithTags>>, ApiError> {
     let user_id = auth_user.effective.id;
-    let book_ids = state
-        .user_book_repo
-        .book_ids_for_user(user_id, None)
-        .await
-        .map_err(AppError::from)?;
+    let query =
+        parse_filter_query(params.q.as_deref(), FilterTarget::UserBooks).map_err(AppError::from)?;
 
     // Return simple list of user-book associations
-    // For full details, use the list_by_user endpoint with pagination
     let request = crate::domain::listing::ListRequest::show_all(
         crate::domain::user_books::UserBookSortKey::CreatedAt,
         crate::domain::listing::SortDirection::Desc,
     );
     let page = state
         .user_book_repo
-        .list_by_user(user_id, None, &request, None)
+        .list_by_user(user_id, None, &request, query.as_ref())
         .await
         .map_err(AppError::from)?;
 
-    let user_books: Vec<UserBook> = page.items.into_iter().map(|d| d.user_book).collect();
-    let _ = book_ids; // used only for potential future filtering
+    let user_books = page
+        .items
+        .into_iter()
+        .map(|d| UserBookWithTags {
+            user_book: d.user_book,
+            tags: d.tags,
+        })
+        .collect();
     Ok(Json(user_books))
 }
+
+#[derive(Debug, Deserialize)]
+pub struct UserBooksQuery {
+    /// Filter query, e.g. `status:read club:yes genre:fantasy`.
+    pub q: Option<String>,
+}
@@ -3,11 +3,12 @@ use axum::extract::{Path, State};
 use axum::http::{HeaderMap, StatusCode, header};
 use axum::response::{IntoResponse, Response};
 use serde::Deserialize;
-use tracing::info;
+use tracing::{error, info};
 
 use crate::application::auth::AuthenticatedUser;
 use crate::application::errors::{ApiError, AppError};
 use crate::application::routes::support::{FlexiblePayload, is_datastar_request, render_fragment};
+use crate::application::services::BackgroundJob;
 use crate::application::state::AppState;
 use crate::domain::images::EntityImage;
 use crate::infrastructure::image_processing::process_data_url;
@@ -187,8 +188,9 @@ pub(crate) async fn delete_image(
     }
 }
 
-/// Process and save a deferred image upload (from a create form).
-/// Called after entity creation when the form included an image data URL.
+/// Queue a deferred image upload (from a create or edit form) for the
+/// background worker to process and save. The upload waits in the image
+/// store, so the job payload only references it.
 /// Accepts `Option<&str>` and no-ops on `None` or empty strings.
 pub(crate) async fn save_deferred_image(
     state: &AppState,
@@ -199,39 +201,22 @@ pub(crate) async fn save_deferred_image(
     let Some(data_url) = data_url.filter(|s| !s.is_empty()) else {
         return;
     };
-    let Ok(_permit) = state.image_semaphore.acquire().await else {
-        tracing::warn!(
-            entity_type,
-            entity_id,
-            "image semaphore closed, skipping deferred image"
-        );
-        return;
-    };
-
-    let data_url = data_url.to_string();
-    let processed = match tokio::task::spawn_blocking(move || process_data_url(&data_url)).await {
-        Ok(Ok(p)) => p,
-        Ok(Err(err)) => {
-            tracing::warn!(entity_type, entity_id, error = %err, "failed to process deferred image");
-            return;
-        }
+    let upload_id = match state.image_repo.stage_upload(data_url).await {
+        Ok(id) => id,
         Err(err) => {
-            tracing::warn!(entity_type, entity_id, error = %err, "deferred image task panicked");
+            error!(error = %err, entity_type, entity_id, "failed to stage deferred image");
             return;
         }
     };
-    let image = EntityImage {
-        entity_type: entity_type.to_string(),
-        entity_id,
-        content_type: processed.content_type,
-        image_data: processed.image_data,
-        thumbnail_data: processed.thumbnail_data,
-    };
-    if let Err(err) = state.image_repo.upsert(image).await {
-        tracing::warn!(entity_type, entity_id, error = %err, "failed to save deferred image");
-    } else {
-        info!(entity_type, entity_id, "deferred image saved");
-    }
+    state
+        .job_queue
+        .enqueue(BackgroundJob::ProcessImage {
+            entity_type: entity_type.to_string(),
+            entity_id,
+            upload_id,
+        })
+        .await;
+    info!(entity_type, entity_id, "deferred image queued");
 }
 
 pub(crate) async fn resolve_image_url(
@@ -122,7 +122,7 @@ macro_rules! define_delete_handler {
             }
 
             tracing::info!(%id, "entity deleted");
-            state.stats_invalidator.invalidate(auth_user.effective.id);
+            state.stats_invalidator.invalidate(auth_user.effective.id).await;
 
             if crate::application::routes::support::is_datastar_request(&headers) {
                 let from_data_page = headers
@@ -6,18 +6,21 @@ pub(crate) mod macros;
 pub(crate) mod system;
 
 // Re-exports
-pub(crate) use analytics::stats;
-pub(crate) use auth::{tokens, webauthn};
-pub(crate) use books::{authors, books as book_routes, genres, readings, scan, user_books};
-pub(crate) use system::{admin, backup};
+pub(crate) use analytics::{challenges, goals, review, stats};
+pub(crate) use auth::{federation, feeds, profile, tokens, webauthn, webhooks};
+pub(crate) use books::{
+    authors, books as book_routes, genres, readings, scan, shelves, tags, user_books,
+};
+pub(crate) use system::{admin, backup, export};
 
 use axum::extract::DefaultBodyLimit;
-use axum::routing::{get, post};
+use axum::routing::{get, post, put};
 
 use crate::application::state::AppState;
 
 pub(super) fn router() -> axum::Router<AppState> {
     entity_routes()
+        .merge(analytics_routes())
         .merge(scan_routes())
         .merge(auth_admin_routes())
         .merge(image_routes())
@@ -75,6 +78,71 @@ fn entity_routes() -> axum::Router<AppState> {
                 .patch(user_books::set_book_club_user_book)
                 .delete(user_books::delete_user_book),
         )
+        .route(
+            "/shelves",
+            get(shelves::list_shelves).post(shelves::create_shelf),
+        )
+        .route(
+            "/shelves/{id}",
+            get(shelves::get_shelf)
+                .put(shelves::update_shelf)
+                .delete(shelves::delete_shelf),
+        )
+        .route("/shelves/{id}/books", post(shelves::add_shelf_book))
+        .route(
+            "/shelves/{id}/books/{book_id}",
+            axum::routing::put(shelves::move_shelf_book).delete(shelves::remove_shelf_book),
+        )
+        .route("/tags", get(tags::list_tags))
+        .route(
+            "/books/{id}/tags",
+            get(tags::get_book_tags)
+                .put(tags::set_book_tags)
+                .post(tags::add_book_tag),
+        )
+        .route(
+            "/books/{id}/tags/{tag}",
+            axum::routing::delete(tags::remove_book_tag),
+        )
+}
+
+fn analytics_routes() -> axum::Router<AppState> {
+    axum::Router::new()
+        .route("/stats", get(stats::get_stats))
+        .route("/stats/activity", get(stats::get_activity))
+        .route("/stats/household", get(stats::get_household_stats))
+        .route(
+            "/stats/household/privacy",
+            put(stats::set_household_privacy),
+        )
+        .route("/review/{year}", get(review::get_review))
+        .route("/review/{year}/card.png", get(review::get_review_card))
+        .route("/goals", get(goals::list_goals).post(goals::create_goal))
+        .route(
+            "/goals/{id}",
+            get(goals::get_goal)
+                .put(goals::update_goal)
+                .delete(goals::delete_goal),
+        )
+        .route(
+            "/challenges",
+            get(challenges::list_challenges).post(challenges::create_challenge),
+        )
+        .route(
+            "/challenges/{id}",
+            get(challenges::get_challenge)
+                .put(challenges::update_challenge)
+                .delete(challenges::delete_challenge),
+        )
+        .route("/challenges/{id}/prompts", post(challenges::add_prompt))
+        .route(
+            "/challenges/{id}/prompts/{prompt_id}",
+            axum::routing::put(challenges::update_prompt).delete(challenges::delete_prompt),
+        )
+        .route(
+            "/challenges/{id}/prompts/{prompt_id}/entry",
+            axum::routing::put(challenges::assign_entry).delete(challenges::remove_entry),
+        )
 }
 
 fn scan_routes() -> axum::Router<AppState> {
@@ -100,6 +168,33 @@ fn auth_admin_routes() -> axum::Router<AppState> {
             post(tokens::create_token).get(tokens::list_tokens),
         )
         .route("/tokens/{id}/revoke", post(tokens::revoke_token))
+        .route(
+            "/feeds/{feed}/token",
+            post(feeds::regenerate_feed_token).delete(feeds::revoke_feed_token),
+        )
+        .route("/profile/visibility", put(feeds::set_profile_visibility))
+        .route("/profile/privacy", put(profile::set_profile_privacy))
+        .route(
+            "/federation/settings",
+            put(federation::update_federation_settings),
+        )
+        .route(
+            "/federation/followers/{id}/approve",
+            post(federation::approve_follower),
+        )
+        .route(
+            "/federation/followers/{id}/reject",
+            post(federation::reject_follower),
+        )
+        .route(
+            "/webhooks",
+            post(webhooks::create_webhook).get(webhooks::list_webhooks),
+        )
+        .route(
+            "/webhooks/{id}",
+            axum::routing::delete(webhooks::delete_webhook),
+        )
+        .route("/webhooks/{id}/test", post(webhooks::send_test_event))
         .route("/passkeys", get(admin::list_passkeys))
         .route(
             "/passkeys/{id}",
@@ -111,6 +206,7 @@ fn auth_admin_routes() -> axum::Router<AppState> {
             post(backup::restore_backup).layer(DefaultBodyLimit::max(50 * 1024 * 1024)),
         )
         .route("/backup/reset", post(backup::reset_database))
+        .route("/export/library", get(export::export_library))
         .route("/admin/invite", post(admin::create_invite))
         .route(
             "/admin/impersonate/{user_id}",
@@ -118,6 +214,7 @@ fn auth_admin_routes() -> axum::Router<AppState> {
         )
         .route("/admin/stop-impersonation", post(admin::stop_impersonation))
         .route("/stats/recompute", post(stats::recompute_stats))
+        .route("/timeline", get(system::timeline::list_timeline))
         .route(
             "/timeline/rebuild",
             post(system::timeline::rebuild_timeline),
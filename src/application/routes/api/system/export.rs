@@ -0,0 +1,102 @@
+use std::collections::{HashMap, HashSet};
+
+use axum::Json;
+use axum::extract::State;
+
+use crate::application::auth::AuthenticatedUser;
+use crate::application::errors::{ApiError, AppError};
+use crate::application::state::AppState;
+use crate::domain::authors::Author;
+use crate::domain::ids::{AuthorId, BookId};
+use crate::domain::library::{LibraryBook, LibraryExport};
+use crate::domain::listing::{ListRequest, SortDirection};
+use crate::domain::readings::{Reading, ReadingFilter, ReadingSortKey};
+use crate::domain::user_books::UserBookSortKey;
+
+/// GET /api/v1/export/library — the caller's books, readings, tags, authors
+/// and genres in one document, for `booklog export`.
+#[tracing::instrument(skip(state, auth_user))]
+pub(crate) async fn export_library(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+) -> Result<Json<LibraryExport>, ApiError> {
+    let user = auth_user.effective;
+
+    let user_books = state
+        .user_book_repo
+        .list_by_user(
+            user.id,
+            None,
+            &ListRequest::show_all(UserBookSortKey::CreatedAt, SortDirection::Asc),
+            None,
+        )
+        .await
+        .map_err(AppError::from)?
+        .items;
+
+    let mut readings_by_book: HashMap<BookId, Vec<Reading>> = HashMap::new();
+    let readings = state
+        .reading_repo
+        .list(
+            ReadingFilter::for_user(user.id),
+            &ListRequest::show_all(ReadingSortKey::CreatedAt, SortDirection::Asc),
+            None,
+        )
+        .await
+        .map_err(AppError::from)?
+        .items;
+    for rwb in readings {
+        readings_by_book
+            .entry(rwb.reading.book_id)
+            .or_default()
+            .push(rwb.reading);
+    }
+
+    let book_ids: Vec<i64> = user_books
+        .iter()
+        .map(|entry| i64::from(entry.user_book.book_id))
+        .collect();
+    let covers: HashSet<i64> = state
+        .image_repo
+        .entity_ids_with_images("book", &book_ids)
+        .await
+        .map_err(AppError::from)?;
+
+    let books: Vec<LibraryBook> = user_books
+        .into_iter()
+        .map(|entry| LibraryBook {
+            readings: readings_by_book
+                .remove(&entry.user_book.book_id)
+                .unwrap_or_default(),
+            has_cover: covers.contains(&i64::from(entry.user_book.book_id)),
+            shelf: entry.user_book.shelf,
+            added_at: entry.user_book.created_at,
+            tags: entry.tags,
+            book: entry.book,
+        })
+        .collect();
+
+    let author_ids: HashSet<AuthorId> = books
+        .iter()
+        .flat_map(|entry| entry.book.authors.iter().map(|a| a.author_id))
+        .collect();
+    let mut authors: Vec<Author> = state
+        .author_repo
+        .list_all()
+        .await
+        .map_err(AppError::from)?
+        .into_iter()
+        .filter(|author| author_ids.contains(&author.id))
+        .collect();
+    authors.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let genres = state.genre_repo.list_all().await.map_err(AppError::from)?;
+
+    Ok(Json(LibraryExport {
+        username: user.username,
+        exported_at: chrono::Utc::now(),
+        books,
+        authors,
+        genres,
+    }))
+}
@@ -1,3 +1,4 @@
 pub(crate) mod admin;
 pub(crate) mod backup;
+pub(crate) mod export;
 pub(crate) mod timeline;
@@ -1,9 +1,67 @@
-use axum::extract::State;
+use axum::Json;
+use axum::extract::{Query, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
+use chrono::NaiveDate;
+use serde::Deserialize;
 
 use crate::application::auth::AuthenticatedUser;
+use crate::application::errors::{ApiError, AppError};
+use crate::application::routes::support::empty_string_as_none;
 use crate::application::state::AppState;
+use crate::domain::ids::{GenreId, UserId};
+use crate::domain::listing::{ListRequest, SortKey};
+use crate::domain::timeline::{TimelineEvent, TimelineFilter, TimelineSortKey};
+
+/// Timeline filters as query parameters, shared by the API and the timeline page.
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct TimelineFilterQuery {
+    #[serde(default, rename = "type", deserialize_with = "empty_string_as_none")]
+    entity_type: Option<String>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    action: Option<String>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    genre: Option<i64>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    user: Option<i64>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    since: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    until: Option<NaiveDate>,
+}
+
+impl TimelineFilterQuery {
+    pub(crate) fn into_filter(self) -> TimelineFilter {
+        TimelineFilter {
+            user_id: self.user.map(UserId::new),
+            entity_type: self.entity_type,
+            action: self.action,
+            exclude_action: None,
+            genre_id: self.genre.map(GenreId::new),
+            since: self.since,
+            until: self.until,
+            viewer: None,
+        }
+    }
+}
+
+/// Signed-in callers also see their own private readings.
+#[tracing::instrument(skip(state, auth_user))]
+pub(crate) async fn list_timeline(
+    State(state): State<AppState>,
+    auth_user: Result<AuthenticatedUser, StatusCode>,
+    Query(query): Query<TimelineFilterQuery>,
+) -> Result<Json<Vec<TimelineEvent>>, ApiError> {
+    let viewer = auth_user.ok().map(|auth_user| auth_user.effective.id);
+    let sort_key = TimelineSortKey::default();
+    let request = ListRequest::show_all(sort_key, sort_key.default_direction());
+    let page = state
+        .timeline_repo
+        .list(&query.into_filter().seen_by(viewer), &request)
+        .await
+        .map_err(AppError::from)?;
+    Ok(Json(page.items))
+}
 
 /// Trigger a full timeline rebuild via the background task.
 #[tracing::instrument(skip(state, _auth_user))]
@@ -11,6 +69,6 @@ pub(crate) async fn rebuild_timeline(
     State(state): State<AppState>,
     _auth_user: AuthenticatedUser,
 ) -> impl IntoResponse {
-    state.timeline_invalidator.invalidate_full();
+    state.timeline_invalidator.invalidate_full().await;
     StatusCode::NO_CONTENT
 }
@@ -8,8 +8,24 @@ use tower_cookies::Cookies;
 use tracing::{error, warn};
 
 use crate::application::auth::impersonation_info;
+use crate::application::routes::app::feeds::{FeedFormat, feed_url};
 use crate::application::routes::render_html;
+use crate::application::services::federation::handle_host;
+use crate::application::services::jobs::RECENT_FAILURE_LIMIT;
+use crate::application::services::stats::StatsMetricsSnapshot;
 use crate::application::state::AppState;
+use crate::domain::RepositoryError;
+use crate::domain::feed_tokens::FeedKind;
+use crate::domain::formatting::format_number;
+use crate::domain::ids::WebhookId;
+use crate::domain::jobs::JobQueueHealth;
+use crate::domain::timeline::TIMELINE_ACTIONS;
+use crate::domain::users::User;
+use crate::domain::webhooks::DeliveryStatus;
+use crate::presentation::web::views::ShelfLinkView;
+
+/// Webhook deliveries listed on the admin page.
+const WEBHOOK_DELIVERY_LIMIT: i64 = 20;
 
 // --- View types ---
 
@@ -28,21 +44,6 @@ fn format_cost(cost: f64) -> String {
     }
 }
 
-fn format_number(n: i64) -> String {
-    if n < 1_000 {
-        return n.to_string();
-    }
-    let s = n.to_string();
-    let mut result = String::with_capacity(s.len() + s.len() / 3);
-    for (i, c) in s.chars().rev().enumerate() {
-        if i > 0 && i % 3 == 0 {
-            result.push(',');
-        }
-        result.push(c);
-    }
-    result.chars().rev().collect()
-}
-
 #[derive(Serialize)]
 pub struct PasskeyView {
     pub id: i64,
@@ -59,6 +60,76 @@ pub struct TokenView {
     pub last_used_at: Option<String>,
 }
 
+pub struct ProfileSettingsView {
+    pub public_profile: bool,
+    pub profile_url: String,
+    /// Embeddable "currently reading" widget; `.svg` and `.json` variants
+    /// sit alongside it.
+    pub widget_url: String,
+    pub show_ratings: bool,
+    pub show_quick_reviews: bool,
+    /// Shelves listed on the profile, chosen from each shelf's page.
+    pub public_shelves: Vec<ShelfLinkView>,
+}
+
+pub struct FeedSettingsView {
+    pub public_profile: bool,
+    pub feeds: Vec<FeedLinkView>,
+}
+
+pub struct FeedLinkView {
+    pub kind: &'static str,
+    pub label: &'static str,
+    pub token_created_at: Option<String>,
+    /// Only known up front for public profiles; private feed URLs carry a
+    /// token that is shown once, when created.
+    pub atom_url: Option<String>,
+    pub rss_url: Option<String>,
+}
+
+pub struct FederationSettingsView {
+    pub enabled: bool,
+    /// `@name@host`, for searching from other servers.
+    pub handle: Option<String>,
+    pub manually_approves_followers: bool,
+    pub followers: Vec<FollowerView>,
+}
+
+pub struct FollowerView {
+    pub id: i64,
+    pub actor_uri: String,
+    pub pending: bool,
+    pub created_at: String,
+}
+
+pub struct WebhookSettingsView {
+    pub webhooks: Vec<WebhookView>,
+    /// Newest first, across all the webhooks above.
+    pub deliveries: Vec<DeliveryView>,
+    /// The timeline actions a webhook can subscribe to, for the form hint.
+    pub events: String,
+}
+
+pub struct WebhookView {
+    pub id: i64,
+    pub url: String,
+    pub events: String,
+    /// Admin-level: receives every user's events.
+    pub all_users: bool,
+    pub created_at: String,
+}
+
+pub struct DeliveryView {
+    pub url: String,
+    pub event: String,
+    pub delivered: bool,
+    pub status: &'static str,
+    pub attempts: u32,
+    /// The response code, or the transport error if there was no response.
+    pub outcome: Option<String>,
+    pub updated_at: String,
+}
+
 pub struct UserView {
     pub id: i64,
     pub username: String,
@@ -70,6 +141,50 @@ fn format_date(dt: DateTime<Utc>) -> String {
     dt.format("%Y-%m-%d").to_string()
 }
 
+fn format_date_time(dt: DateTime<Utc>) -> String {
+    dt.format("%Y-%m-%d %H:%M UTC").to_string()
+}
+
+pub struct JobQueueView {
+    pub pending: i64,
+    pub running: i64,
+    pub failed: i64,
+    pub overdue: i64,
+    pub oldest_pending_at: Option<String>,
+    pub failures: Vec<FailedJobView>,
+}
+
+pub struct FailedJobView {
+    pub id: i64,
+    pub kind: String,
+    pub attempts: u32,
+    pub error: String,
+    pub failed_at: String,
+}
+
+impl From<JobQueueHealth> for JobQueueView {
+    fn from(health: JobQueueHealth) -> Self {
+        Self {
+            pending: health.pending,
+            running: health.running,
+            failed: health.failed,
+            overdue: health.overdue,
+            oldest_pending_at: health.oldest_pending_at.map(format_date_time),
+            failures: health
+                .recent_failures
+                .into_iter()
+                .map(|job| FailedJobView {
+                    id: job.id.into_inner(),
+                    kind: job.kind.replace('_', " "),
+                    attempts: job.attempts,
+                    error: job.last_error.unwrap_or_default(),
+                    failed_at: format_date_time(job.updated_at),
+                })
+                .collect(),
+        }
+    }
+}
+
 // --- Templates ---
 
 #[derive(Template)]
@@ -85,7 +200,13 @@ struct AdminTemplate {
     ai_usage: Option<AiUsageView>,
     passkeys: Vec<PasskeyView>,
     tokens: Vec<TokenView>,
+    profile: ProfileSettingsView,
+    feeds: FeedSettingsView,
+    federation: FederationSettingsView,
+    webhooks: WebhookSettingsView,
     users: Vec<UserView>,
+    stats_metrics: StatsMetricsSnapshot,
+    job_queue: Option<JobQueueView>,
 }
 
 // --- Page handler ---
@@ -140,6 +261,11 @@ pub(crate) async fn admin_page(
         })
         .collect();
 
+    let profile = load_profile_settings(&state, &auth_user).await?;
+    let feeds = load_feed_settings(&state, &auth_user).await?;
+    let federation = load_federation_settings(&state, &auth_user).await?;
+    let webhooks = load_webhook_settings(&state, &auth_user).await?;
+
     let ai_usage = match state.ai_usage_repo.summary_for_user(auth_user.id).await {
         Ok(summary) => Some(summary),
         Err(err) => {
@@ -182,7 +308,17 @@ pub(crate) async fn admin_page(
         ai_usage,
         passkeys,
         tokens,
+        profile,
+        feeds,
+        federation,
+        webhooks,
         users,
+        stats_metrics: state.stats_invalidator.metrics().snapshot(),
+        job_queue: if auth_user.is_admin {
+            load_job_queue(&state).await
+        } else {
+            None
+        },
     };
 
     render_html(template).map(IntoResponse::into_response)
@@ -190,6 +326,203 @@ pub(crate) async fn admin_page(
 
 // --- Helpers ---
 
+async fn load_profile_settings(
+    state: &AppState,
+    user: &User,
+) -> Result<ProfileSettingsView, StatusCode> {
+    let privacy = state
+        .user_repo
+        .get_profile_privacy(user.id)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "failed to load profile privacy for admin page");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    let shelves = state
+        .shelf_repo
+        .list_by_user(user.id)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "failed to list shelves for admin page");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    let profile_url = format!("{}/u/{}", crate::base_url(), user.username);
+    Ok(ProfileSettingsView {
+        public_profile: user.public_profile,
+        widget_url: format!("{profile_url}/widget"),
+        profile_url,
+        show_ratings: privacy.show_ratings,
+        show_quick_reviews: privacy.show_quick_reviews,
+        public_shelves: shelves
+            .into_iter()
+            .filter(|shelf| shelf.public)
+            .map(ShelfLinkView::from)
+            .collect(),
+    })
+}
+
+async fn load_feed_settings(state: &AppState, user: &User) -> Result<FeedSettingsView, StatusCode> {
+    let tokens = state
+        .feed_token_repo
+        .list_by_user(user.id)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "failed to list feed tokens for admin page");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    let feeds = FeedKind::ALL
+        .into_iter()
+        .map(|kind| {
+            let public_url = |format| {
+                user.public_profile
+                    .then(|| feed_url(&user.username, kind, format, None))
+            };
+            FeedLinkView {
+                kind: kind.as_str(),
+                label: kind.label(),
+                token_created_at: tokens
+                    .iter()
+                    .find(|token| token.feed == kind)
+                    .map(|token| format_date(token.created_at)),
+                atom_url: public_url(FeedFormat::Atom),
+                rss_url: public_url(FeedFormat::Rss),
+            }
+        })
+        .collect();
+
+    Ok(FeedSettingsView {
+        public_profile: user.public_profile,
+        feeds,
+    })
+}
+
+async fn load_federation_settings(
+    state: &AppState,
+    user: &User,
+) -> Result<FederationSettingsView, StatusCode> {
+    let actor = match state.federation_repo.get_actor(user.id).await {
+        Ok(actor) => actor,
+        Err(RepositoryError::NotFound) => {
+            return Ok(FederationSettingsView {
+                enabled: false,
+                handle: None,
+                manually_approves_followers: false,
+                followers: Vec::new(),
+            });
+        }
+        Err(err) => {
+            error!(error = %err, "failed to load federation settings for admin page");
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let followers = state
+        .federation
+        .list_followers(user.id)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "failed to list followers for admin page");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .into_iter()
+        .map(|follower| FollowerView {
+            id: i64::from(follower.id),
+            pending: !follower.is_accepted(),
+            actor_uri: follower.actor_uri,
+            created_at: format_date(follower.created_at),
+        })
+        .collect();
+
+    let handle = handle_host(&actor.origin).map(|host| format!("@{}@{host}", user.username));
+
+    Ok(FederationSettingsView {
+        enabled: true,
+        handle,
+        manually_approves_followers: actor.manually_approves_followers,
+        followers,
+    })
+}
+
+async fn load_webhook_settings(
+    state: &AppState,
+    user: &User,
+) -> Result<WebhookSettingsView, StatusCode> {
+    let webhooks = state
+        .webhook_repo
+        .list_for_user(user.id, user.is_admin)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "failed to list webhooks for admin page");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    let ids: Vec<WebhookId> = webhooks.iter().map(|webhook| webhook.id).collect();
+    let deliveries = state
+        .webhook_repo
+        .list_recent_deliveries(&ids, WEBHOOK_DELIVERY_LIMIT)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "failed to list webhook deliveries for admin page");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    let deliveries = deliveries
+        .into_iter()
+        .map(|delivery| DeliveryView {
+            url: webhooks
+                .iter()
+                .find(|webhook| webhook.id == delivery.webhook_id)
+                .map(|webhook| webhook.url.clone())
+                .unwrap_or_default(),
+            event: delivery.event,
+            delivered: delivery.status == DeliveryStatus::Delivered,
+            status: delivery.status.as_str(),
+            attempts: delivery.attempts,
+            outcome: delivery
+                .response_status
+                .map(|status| format!("HTTP {status}"))
+                .or(delivery.error),
+            updated_at: format_date_time(delivery.updated_at),
+        })
+        .collect();
+    let webhooks = webhooks
+        .into_iter()
+        .map(|webhook| WebhookView {
+            id: i64::from(webhook.id),
+            events: if webhook.events.is_empty() {
+                "All events".to_string()
+            } else {
+                webhook.events.join(", ")
+            },
+            all_users: webhook.user_id.is_none(),
+            url: webhook.url,
+            created_at: format_date(webhook.created_at),
+        })
+        .collect();
+
+    Ok(WebhookSettingsView {
+        webhooks,
+        deliveries,
+        events: TIMELINE_ACTIONS.join(", "),
+    })
+}
+
+async fn load_job_queue(state: &AppState) -> Option<JobQueueView> {
+    match state
+        .job_queue
+        .repository()
+        .health(Utc::now(), RECENT_FAILURE_LIMIT)
+        .await
+    {
+        Ok(health) => Some(JobQueueView::from(health)),
+        Err(err) => {
+            warn!(error = %err, "failed to load job queue health");
+            None
+        }
+    }
+}
+
 pub(crate) async fn extract_user_from_session(
     state: &AppState,
     cookies: &Cookies,
@@ -134,6 +134,8 @@ pub(crate) async fn author_edit_page(
     )
     .await;
 
+    let details = author.details;
+    let year = |year: Option<i32>| year.map(|y| y.to_string()).unwrap_or_default();
     let template = AuthorEditTemplate {
         nav_active: "data",
         is_authenticated,
@@ -142,6 +144,12 @@ pub(crate) async fn author_edit_page(
         impersonated_username,
         id: author.id.to_string(),
         name: author.name,
+        bio: details.bio.unwrap_or_default(),
+        birth_year: year(details.birth_year),
+        death_year: year(details.death_year),
+        nationality: details.nationality.unwrap_or_default(),
+        website: details.website.unwrap_or_default(),
+        aliases: details.aliases.join(", "),
         image_url,
     };
 
@@ -13,6 +13,7 @@ use crate::presentation::web::templates::{
 };
 use crate::presentation::web::views::{
     AuthorOptionView, BookDetailView, BookLibraryInfo, BookReadingCardView, GenreOptionView,
+    ShelfLinkView, ShelfOptionView, TagView,
 };
 
 #[tracing::instrument(skip(state, cookies))]
@@ -72,6 +73,9 @@ pub(crate) async fn book_detail_page(
         (None, Vec::new(), None)
     };
 
+    let (shelves, shelf_options) = load_book_shelves(&state, user_id, id).await;
+    let (tags, tag_suggestions) = load_book_tags(&state, user_id, id).await;
+
     let edit_url = format!("/books/{id}/edit");
     let view = BookDetailView::from_domain(enriched);
 
@@ -88,11 +92,82 @@ pub(crate) async fn book_detail_page(
         library_info,
         readings,
         active_reading_id,
+        shelves,
+        shelf_options,
+        tags,
+        tag_suggestions,
     };
 
     render_html(template).map(IntoResponse::into_response)
 }
 
+/// Shelves holding the book, plus manual shelves it could still be added to.
+async fn load_book_shelves(
+    state: &AppState,
+    user_id: Option<crate::domain::ids::UserId>,
+    book_id: crate::domain::ids::BookId,
+) -> (Vec<ShelfLinkView>, Vec<ShelfOptionView>) {
+    let Some(uid) = user_id else {
+        return (Vec::new(), Vec::new());
+    };
+
+    let holding = state
+        .shelf_service
+        .shelves_for_book(uid, book_id)
+        .await
+        .unwrap_or_default();
+    let options = state
+        .shelf_repo
+        .list_by_user(uid)
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|shelf| {
+            shelf.kind == crate::domain::shelves::ShelfKind::Manual
+                && !holding.iter().any(|held| held.id == shelf.id)
+        })
+        .map(ShelfOptionView::from)
+        .collect();
+
+    (
+        holding.into_iter().map(ShelfLinkView::from).collect(),
+        options,
+    )
+}
+
+/// The user's tags on the book, plus their other tags as suggestions.
+async fn load_book_tags(
+    state: &AppState,
+    user_id: Option<crate::domain::ids::UserId>,
+    book_id: crate::domain::ids::BookId,
+) -> (Vec<TagView>, Vec<String>) {
+    let Some(uid) = user_id else {
+        return (Vec::new(), Vec::new());
+    };
+
+    let tags = state
+        .tag_repo
+        .list_for_book(uid, book_id)
+        .await
+        .unwrap_or_default();
+    let suggestions = state
+        .tag_repo
+        .counts_for_user(uid, None, None)
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .map(|count| count.tag)
+        .filter(|tag| !tags.contains(tag))
+        .collect();
+
+    (
+        tags.into_iter()
+            .map(|tag| TagView::for_book(book_id, tag))
+            .collect(),
+        suggestions,
+    )
+}
+
 #[tracing::instrument(skip(state, cookies))]
 pub(crate) async fn book_edit_page(
     State(state): State<AppState>,
@@ -150,6 +225,14 @@ pub(crate) async fn book_edit_page(
         isbn: book.isbn.unwrap_or_default(),
         description: book.description.unwrap_or_default(),
         page_count: book.page_count.map(|p| p.to_string()).unwrap_or_default(),
+        duration_hours: book
+            .duration_minutes
+            .map(|m| (m / 60).to_string())
+            .unwrap_or_default(),
+        duration_minutes: book
+            .duration_minutes
+            .map(|m| (m % 60).to_string())
+            .unwrap_or_default(),
         year_published: book
             .year_published
             .map(|y| y.to_string())
@@ -0,0 +1,168 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Redirect, Response};
+
+use super::admin::extract_user_from_session;
+use crate::application::auth::impersonation_info;
+use crate::application::errors::{AppError, map_app_error};
+use crate::application::routes::api::challenges::visible_challenge;
+use crate::application::routes::render_html;
+use crate::application::state::AppState;
+use crate::domain::challenges::{Challenge, ChallengeSummary};
+use crate::domain::ids::{ChallengeId, UserId};
+use crate::domain::listing::{ListRequest, SortDirection};
+use crate::domain::readings::{ReadingFilter, ReadingSortKey, ReadingStatus};
+use crate::presentation::web::templates::{
+    ChallengeDetailTemplate, ChallengeEditTemplate, ChallengesTemplate,
+};
+use crate::presentation::web::views::{
+    ChallengeDetailView, ChallengeHeaderView, PromptReadingView,
+};
+
+#[tracing::instrument(skip(state, cookies))]
+pub(crate) async fn challenges_page(
+    State(state): State<AppState>,
+    cookies: tower_cookies::Cookies,
+) -> Result<Response, StatusCode> {
+    let Some(user_id) = crate::application::routes::authenticated_user_id(&state, &cookies).await
+    else {
+        return Ok(Redirect::to("/login").into_response());
+    };
+    let (is_impersonating, impersonated_username) = impersonation_info(&state, &cookies).await;
+
+    let challenges = state
+        .challenge_service
+        .summaries(user_id)
+        .await
+        .map_err(|e| map_app_error(e.into()))?
+        .into_iter()
+        .map(ChallengeHeaderView::from)
+        .collect();
+
+    let template = ChallengesTemplate {
+        nav_active: "stats",
+        is_authenticated: true,
+        version_info: &crate::VERSION_INFO,
+        is_impersonating,
+        impersonated_username,
+        challenges,
+    };
+
+    render_html(template).map(IntoResponse::into_response)
+}
+
+#[tracing::instrument(skip(state, cookies))]
+pub(crate) async fn challenge_detail_page(
+    State(state): State<AppState>,
+    cookies: tower_cookies::Cookies,
+    Path(id): Path<ChallengeId>,
+) -> Result<Response, StatusCode> {
+    let Some(user_id) = crate::application::routes::authenticated_user_id(&state, &cookies).await
+    else {
+        return Ok(Redirect::to("/login").into_response());
+    };
+    let (is_impersonating, impersonated_username) = impersonation_info(&state, &cookies).await;
+
+    let challenge = visible_challenge(&state, id, user_id)
+        .await
+        .map_err(map_app_error)?;
+    let can_edit = is_editable(&state, &cookies, &challenge.challenge, user_id).await;
+    let reading_options = finished_readings(&state, user_id, &challenge.challenge)
+        .await
+        .map_err(map_app_error)?;
+    let progress = state
+        .challenge_service
+        .progress(challenge, user_id)
+        .await
+        .map_err(|e| map_app_error(e.into()))?;
+
+    let template = ChallengeDetailTemplate {
+        nav_active: "stats",
+        is_authenticated: true,
+        version_info: &crate::VERSION_INFO,
+        is_impersonating,
+        impersonated_username,
+        challenge: ChallengeDetailView::from(progress),
+        can_edit,
+        edit_url: format!("/challenges/{id}/edit"),
+        reading_options,
+    };
+
+    render_html(template).map(IntoResponse::into_response)
+}
+
+#[tracing::instrument(skip(state, cookies))]
+pub(crate) async fn challenge_edit_page(
+    State(state): State<AppState>,
+    cookies: tower_cookies::Cookies,
+    Path(id): Path<ChallengeId>,
+) -> Result<Response, StatusCode> {
+    let Some(user_id) = crate::application::routes::authenticated_user_id(&state, &cookies).await
+    else {
+        return Ok(Redirect::to("/login").into_response());
+    };
+    let (is_impersonating, impersonated_username) = impersonation_info(&state, &cookies).await;
+
+    let challenge = visible_challenge(&state, id, user_id)
+        .await
+        .map_err(map_app_error)?;
+    if !is_editable(&state, &cookies, &challenge.challenge, user_id).await {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    let total = challenge.prompts.len();
+
+    let template = ChallengeEditTemplate {
+        nav_active: "stats",
+        is_authenticated: true,
+        version_info: &crate::VERSION_INFO,
+        is_impersonating,
+        impersonated_username,
+        challenge: ChallengeHeaderView::from(ChallengeSummary {
+            challenge: challenge.challenge,
+            completed: 0,
+            total,
+        }),
+    };
+
+    render_html(template).map(IntoResponse::into_response)
+}
+
+/// Admin rights come from the real session user, so an admin impersonating
+/// someone keeps them, matching the API.
+async fn is_editable(
+    state: &AppState,
+    cookies: &tower_cookies::Cookies,
+    challenge: &Challenge,
+    user_id: UserId,
+) -> bool {
+    let is_admin = extract_user_from_session(state, cookies)
+        .await
+        .is_some_and(|user| user.is_admin);
+    challenge.is_editable_by(user_id, is_admin)
+}
+
+/// The user's finished readings that count towards the challenge, offered
+/// for filling prompts by hand.
+async fn finished_readings(
+    state: &AppState,
+    user_id: UserId,
+    challenge: &Challenge,
+) -> Result<Vec<PromptReadingView>, AppError> {
+    let request = ListRequest::show_all(ReadingSortKey::FinishedAt, SortDirection::Desc);
+    let readings = state
+        .reading_repo
+        .list(
+            ReadingFilter::for_user_status(user_id, ReadingStatus::Read),
+            &request,
+            None,
+        )
+        .await
+        .map_err(AppError::from)?;
+
+    Ok(readings
+        .items
+        .into_iter()
+        .filter(|r| challenge.accepts(r.reading.finished_at))
+        .map(PromptReadingView::from)
+        .collect())
+}
@@ -6,8 +6,9 @@ use serde::Deserialize;
 use crate::application::auth::impersonation_info;
 use crate::application::errors::{AppError, map_app_error};
 use crate::application::routes::render_html;
-use crate::application::routes::support::{ListQuery, is_datastar_request};
+use crate::application::routes::support::{ListQuery, is_datastar_request, parse_filter_query};
 use crate::application::state::AppState;
+use crate::domain::filters::FilterTarget;
 use crate::presentation::web::templates::{
     DataTemplate, GenreListTemplate, Tab, UserBookListTemplate, render_template,
 };
@@ -151,20 +152,14 @@ async fn render_user_books(
     let page_path = format!("/data?type={shelf_str}");
     let fragment_path = format!("/data?type={shelf_str}#user-book-list");
 
-    let page = if let Some(uid) = user_id {
-        state
+    let query = parse_filter_query(search.as_deref(), FilterTarget::UserBooks);
+    let page = match (user_id, &query) {
+        (Some(uid), Ok(query)) => state
             .user_book_repo
-            .list_by_user(uid, Some(shelf), &request, search.as_deref())
+            .list_by_user(uid, Some(shelf), &request, query.as_ref())
             .await
-            .map_err(AppError::from)?
-    } else {
-        crate::domain::listing::Page::new(
-            Vec::new(),
-            1,
-            crate::domain::listing::DEFAULT_PAGE_SIZE,
-            0,
-            false,
-        )
+            .map_err(AppError::from)?,
+        _ => crate::domain::listing::Page::empty(),
     };
 
     let (user_books, navigator) = crate::application::routes::support::build_page_view(
@@ -175,6 +170,7 @@ async fn render_user_books(
         fragment_path,
         search,
     );
+    let navigator = navigator.with_search_error(query.err().map(|err| err.to_string()));
 
     render_list(
         UserBookListTemplate {
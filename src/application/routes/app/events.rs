@@ -0,0 +1,247 @@
+use std::collections::VecDeque;
+use std::convert::Infallible;
+
+use askama::Template;
+use axum::extract::{Query, State};
+use axum::http::HeaderMap;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use futures::stream::{self, Stream};
+use serde::Deserialize;
+use tracing::warn;
+
+use crate::application::routes::api::system::timeline::TimelineFilterQuery;
+use crate::application::services::live::{LiveMessage, LiveSubscription};
+use crate::application::services::{LiveUpdate, Received, Replay};
+use crate::application::state::AppState;
+use crate::domain::ids::UserId;
+use crate::domain::timeline::{TimelineEvent, TimelineFilter};
+use crate::presentation::web::templates::{
+    HomeActivityTemplate, HomeStatsTemplate, TimelineEventTemplate, TimelineLiveNoticeTemplate,
+    render_template,
+};
+use crate::presentation::web::views::TimelineEventView;
+
+const TIMELINE_REFRESHED_NOTICE: &str = "Some timeline entries have been updated.";
+const TIMELINE_GAP_NOTICE: &str = "You may have missed some activity while disconnected.";
+
+/// The page a live connection patches.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum LivePage {
+    Timeline,
+    Home,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EventsQuery {
+    page: LivePage,
+}
+
+/// Server-sent Datastar patches for timeline and stats changes. Reconnecting
+/// clients send `Last-Event-ID` and are replayed what they missed, or told
+/// to reload when it is no longer buffered. A filtered timeline passes its
+/// filters and is only sent the events they match.
+#[tracing::instrument(skip(state, cookies, headers, filter_query))]
+pub(crate) async fn live_events(
+    State(state): State<AppState>,
+    cookies: tower_cookies::Cookies,
+    headers: HeaderMap,
+    Query(query): Query<EventsQuery>,
+    Query(filter_query): Query<TimelineFilterQuery>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let user_id = crate::application::routes::authenticated_user_id(&state, &cookies).await;
+    let filter = filter_query.into_filter().seen_by(user_id);
+    let last_event_id = headers
+        .get("last-event-id")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok());
+
+    let mut subscription = state.live_updates.subscribe(last_event_id);
+    let replay = std::mem::take(&mut subscription.replay);
+    let connection = LiveConnection {
+        state,
+        page: query.page,
+        user_id,
+        filter,
+        subscription,
+    };
+
+    let mut pending = VecDeque::new();
+    match replay {
+        Replay::Missed(messages) => {
+            for message in &messages {
+                pending.extend(connection.render(message).await);
+            }
+        }
+        Replay::Gap => pending.extend(connection.render_gap().await),
+    }
+
+    let stream = stream::unfold(
+        (connection, pending),
+        |(mut connection, mut pending)| async move {
+            loop {
+                if let Some(event) = pending.pop_front() {
+                    return Some((Ok(event), (connection, pending)));
+                }
+                match connection.subscription.recv().await {
+                    Received::Message(message) => pending.extend(connection.render(&message).await),
+                    Received::Lagged => pending.extend(connection.render_gap().await),
+                    Received::Closed => return None,
+                }
+            }
+        },
+    );
+
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
+struct LiveConnection {
+    state: AppState,
+    page: LivePage,
+    user_id: Option<UserId>,
+    filter: TimelineFilter,
+    subscription: LiveSubscription,
+}
+
+impl LiveConnection {
+    /// Patches for one message. Messages this page doesn't show still send
+    /// their id so a later reconnect resumes from here.
+    async fn render(&self, message: &LiveMessage) -> Vec<Event> {
+        let patches = match (self.page, &message.update) {
+            (
+                LivePage::Timeline,
+                LiveUpdate::TimelineEvent {
+                    event,
+                    user_id,
+                    private,
+                },
+            ) if !private || self.user_id == Some(*user_id) => {
+                if self.matches_filter(event).await {
+                    vec![timeline_event_patch(event)]
+                } else {
+                    Vec::new()
+                }
+            }
+            (LivePage::Timeline, LiveUpdate::TimelineRefreshed) => {
+                vec![timeline_notice_patch(TIMELINE_REFRESHED_NOTICE)]
+            }
+            (
+                LivePage::Home,
+                LiveUpdate::TimelineEvent {
+                    user_id, private, ..
+                },
+            ) if self.user_id.map_or(!private, |id| id == *user_id) => {
+                vec![self.home_activity_patch().await]
+            }
+            (LivePage::Home, LiveUpdate::TimelineRefreshed) => {
+                vec![self.home_activity_patch().await]
+            }
+            (LivePage::Home, LiveUpdate::StatsRefreshed(user_id))
+                if self.user_id == Some(*user_id) =>
+            {
+                vec![self.home_stats_patch().await]
+            }
+            _ => Vec::new(),
+        };
+
+        let mut events = patches.into_iter().flatten().collect::<Vec<_>>();
+        if events.is_empty() {
+            events.push(Event::default().comment("skipped"));
+        }
+        let id = message.id.to_string();
+        events.into_iter().map(|event| event.id(&id)).collect()
+    }
+
+    /// Whether the timeline's filter, if any, lists the event.
+    async fn matches_filter(&self, event: &TimelineEvent) -> bool {
+        if self.filter.is_empty() {
+            return true;
+        }
+        match self
+            .state
+            .timeline_repo
+            .matches(event.id, &self.filter)
+            .await
+        {
+            Ok(matches) => matches,
+            Err(err) => {
+                warn!(error = %err, "failed to match live event against timeline filter");
+                false
+            }
+        }
+    }
+
+    /// Patches for when messages were missed: the timeline asks for a
+    /// reload, the home page re-renders everything it streams.
+    async fn render_gap(&self) -> Vec<Event> {
+        let patches = match self.page {
+            LivePage::Timeline => vec![timeline_notice_patch(TIMELINE_GAP_NOTICE)],
+            LivePage::Home => vec![
+                self.home_activity_patch().await,
+                self.home_stats_patch().await,
+            ],
+        };
+        patches.into_iter().flatten().collect()
+    }
+
+    async fn home_activity_patch(&self) -> Option<Event> {
+        let recent_events = super::home::load_recent_events(&self.state, self.user_id)
+            .await
+            .inspect_err(
+                |err| warn!(error = ?err, "failed to load recent activity for live update"),
+            )
+            .ok()?;
+        patch_elements(
+            HomeActivityTemplate { recent_events },
+            "#home-recent-activity",
+            "outer",
+        )
+    }
+
+    async fn home_stats_patch(&self) -> Option<Event> {
+        let stats = super::home::load_stats(&self.state, self.user_id)
+            .await
+            .inspect_err(|err| warn!(error = ?err, "failed to load stats for live update"))
+            .ok()?;
+        let stat_cards = super::home::load_stat_cards(&self.state, self.user_id).await;
+        let goals = super::stats::load_goal_views(&self.state, self.user_id, true).await;
+        patch_elements(
+            HomeStatsTemplate {
+                stats,
+                stat_cards,
+                goals,
+            },
+            "#home-stats",
+            "outer",
+        )
+    }
+}
+
+fn timeline_event_patch(event: &TimelineEvent) -> Option<Event> {
+    let template = TimelineEventTemplate {
+        event: TimelineEventView::from_domain(event.clone()),
+        month_anchor: "live",
+    };
+    patch_elements(template, "#timeline-live", "prepend")
+}
+
+fn timeline_notice_patch(message: &'static str) -> Option<Event> {
+    patch_elements(
+        TimelineLiveNoticeTemplate { message },
+        "#timeline-live-notice",
+        "outer",
+    )
+}
+
+/// A `datastar-patch-elements` event carrying a rendered template.
+fn patch_elements<T: Template>(template: T, selector: &str, mode: &str) -> Option<Event> {
+    let html = render_template(template)
+        .inspect_err(|err| warn!(error = %err, selector, "failed to render live update"))
+        .ok()?;
+    let mut data = format!("selector {selector}\nmode {mode}");
+    for line in html.lines().filter(|line| !line.trim().is_empty()) {
+        data.push_str("\nelements ");
+        data.push_str(line);
+    }
+    Some(Event::default().event("datastar-patch-elements").data(data))
+}
@@ -0,0 +1,143 @@
+use axum::Json;
+use axum::body::Bytes;
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, StatusCode, Uri};
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+use serde_json::Value;
+use tracing::{error, warn};
+
+use crate::application::services::federation::{InboxError, InboxRequest, handle_host};
+use crate::application::state::AppState;
+use crate::domain::RepositoryError;
+use crate::domain::federation::Actor;
+use crate::domain::users::User;
+use crate::infrastructure::activitypub::ACTIVITY_JSON;
+
+fn activity_json(document: Value) -> Response {
+    ([("content-type", ACTIVITY_JSON)], Json(document)).into_response()
+}
+
+/// Users who haven't opted in to federation look the same as missing ones.
+async fn find_actor(state: &AppState, username: &str) -> Result<(User, Actor), StatusCode> {
+    state
+        .federation
+        .find_actor(username)
+        .await
+        .map_err(|err| match err {
+            RepositoryError::NotFound => StatusCode::NOT_FOUND,
+            err => {
+                error!(error = %err, "failed to load federated actor");
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        })
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WebfingerQuery {
+    resource: String,
+}
+
+/// Resolve `acct:name@host` to the user's actor.
+#[tracing::instrument(skip(state))]
+pub(crate) async fn webfinger(
+    State(state): State<AppState>,
+    Query(query): Query<WebfingerQuery>,
+) -> Result<Response, StatusCode> {
+    let account = query
+        .resource
+        .strip_prefix("acct:")
+        .ok_or(StatusCode::NOT_FOUND)?;
+    let (username, host) = account.split_once('@').ok_or(StatusCode::NOT_FOUND)?;
+    let (user, actor) = find_actor(&state, username).await?;
+
+    let actor_host = handle_host(&actor.origin).ok_or(StatusCode::NOT_FOUND)?;
+    if !host.eq_ignore_ascii_case(&actor_host) {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    let document = state.federation.webfinger_document(&user, &actor, host);
+    Ok(([("content-type", "application/jrd+json")], Json(document)).into_response())
+}
+
+#[tracing::instrument(skip(state))]
+pub(crate) async fn actor(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<Response, StatusCode> {
+    let (user, actor) = find_actor(&state, &name).await?;
+    Ok(activity_json(
+        state.federation.actor_document(&user, &actor),
+    ))
+}
+
+#[tracing::instrument(skip(state))]
+pub(crate) async fn outbox(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<Response, StatusCode> {
+    let (user, actor) = find_actor(&state, &name).await?;
+    let document = state
+        .federation
+        .outbox_document(&user, &actor)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "failed to load outbox");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    Ok(activity_json(document))
+}
+
+#[tracing::instrument(skip(state))]
+pub(crate) async fn followers(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<Response, StatusCode> {
+    let (user, actor) = find_actor(&state, &name).await?;
+    let document = state
+        .federation
+        .followers_document(&user, &actor)
+        .await
+        .map_err(|err| {
+            error!(error = %err, "failed to load followers");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    Ok(activity_json(document))
+}
+
+/// Receive a signed activity from a remote server.
+#[tracing::instrument(skip(state, uri, headers, body))]
+pub(crate) async fn inbox(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+    uri: Uri,
+    headers: HeaderMap,
+    body: Bytes,
+) -> StatusCode {
+    let path_and_query = uri
+        .path_and_query()
+        .map_or_else(|| uri.path(), |path| path.as_str());
+    let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+    let request = InboxRequest {
+        path_and_query,
+        body: &body,
+        header: &header,
+    };
+
+    match state.federation.handle_inbox(&name, request).await {
+        Ok(()) => StatusCode::ACCEPTED,
+        Err(InboxError::NotFound) => StatusCode::NOT_FOUND,
+        Err(InboxError::Unauthorized(reason)) => {
+            warn!(%reason, "rejected inbox request");
+            StatusCode::UNAUTHORIZED
+        }
+        Err(InboxError::Invalid(reason)) => {
+            warn!(%reason, "invalid inbox activity");
+            StatusCode::BAD_REQUEST
+        }
+        Err(InboxError::Repository(err)) => {
+            error!(error = %err, "failed to handle inbox activity");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
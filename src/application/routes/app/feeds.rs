@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+use tracing::{error, warn};
+
+use crate::application::state::AppState;
+use crate::domain::RepositoryError;
+use crate::domain::analytics::timeline::{TimelineEvent, TimelineFilter, TimelineSortKey};
+use crate::domain::feed_tokens::FeedKind;
+use crate::domain::ids::{ReadingId, TimelineEventId};
+use crate::domain::listing::{ListRequest, PageSize, SortKey};
+use crate::domain::users::User;
+use crate::infrastructure::auth::hash_token;
+use crate::presentation::web::templates::{AtomFeedTemplate, RssFeedTemplate, render_template};
+use crate::presentation::web::views::{FeedEnclosureView, FeedEntryView, FeedView};
+
+/// Entries per feed; readers only poll for what's new.
+const FEED_ENTRY_LIMIT: u32 = 30;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum FeedFormat {
+    Atom,
+    Rss,
+}
+
+impl FeedFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            FeedFormat::Atom => "atom",
+            FeedFormat::Rss => "rss",
+        }
+    }
+
+    fn content_type(self) -> &'static str {
+        match self {
+            FeedFormat::Atom => "application/atom+xml; charset=utf-8",
+            FeedFormat::Rss => "application/rss+xml; charset=utf-8",
+        }
+    }
+}
+
+/// Absolute URL of a user's feed, with its secret token for private profiles.
+pub(crate) fn feed_url(
+    username: &str,
+    kind: FeedKind,
+    format: FeedFormat,
+    token: Option<&str>,
+) -> String {
+    let mut url = format!(
+        "{}/users/{username}/feed.{}",
+        crate::base_url(),
+        format.extension()
+    );
+    let mut params = Vec::new();
+    if kind != FeedKind::All {
+        params.push(format!("kind={}", kind.as_str()));
+    }
+    if let Some(token) = token {
+        params.push(format!("token={token}"));
+    }
+    if !params.is_empty() {
+        url.push('?');
+        url.push_str(&params.join("&"));
+    }
+    url
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FeedQuery {
+    #[serde(default)]
+    kind: Option<FeedKind>,
+    #[serde(default)]
+    token: Option<String>,
+}
+
+#[tracing::instrument(skip(state, query), fields(kind = ?query.kind))]
+pub(crate) async fn atom_feed(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+    Query(query): Query<FeedQuery>,
+) -> Result<Response, StatusCode> {
+    render_feed(&state, &name, query, FeedFormat::Atom).await
+}
+
+#[tracing::instrument(skip(state, query), fields(kind = ?query.kind))]
+pub(crate) async fn rss_feed(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+    Query(query): Query<FeedQuery>,
+) -> Result<Response, StatusCode> {
+    render_feed(&state, &name, query, FeedFormat::Rss).await
+}
+
+async fn render_feed(
+    state: &AppState,
+    username: &str,
+    query: FeedQuery,
+    format: FeedFormat,
+) -> Result<Response, StatusCode> {
+    let kind = query.kind.unwrap_or(FeedKind::All);
+    let user = match state.user_repo.get_by_username(username).await {
+        Ok(user) => user,
+        Err(RepositoryError::NotFound) => return Err(StatusCode::NOT_FOUND),
+        Err(err) => {
+            error!(error = %err, "failed to load user for feed");
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+    // Private feeds look the same as missing ones without the right token
+    if !can_read_feed(state, &user, kind, query.token.as_deref()).await {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    let events = load_feed_events(state, &user, kind).await.map_err(|err| {
+        error!(error = %err, "failed to load feed events");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    let updated = events.first().map(|event| event.occurred_at);
+
+    let base_url = crate::base_url();
+    let mut enclosures = cover_enclosures(state, &events, base_url)
+        .await
+        .unwrap_or_else(|err| {
+            warn!(error = %err, "failed to load feed cover enclosures");
+            HashMap::new()
+        });
+    let entries = events
+        .into_iter()
+        .map(|event| {
+            let enclosure = enclosures.remove(&event.id);
+            FeedEntryView::from_domain(event, base_url, enclosure)
+        })
+        .collect();
+    let feed = FeedView::new(
+        &user.username,
+        kind,
+        feed_url(&user.username, kind, format, None),
+        base_url,
+        updated,
+        entries,
+    );
+
+    let body = match format {
+        FeedFormat::Atom => render_template(AtomFeedTemplate { feed }),
+        FeedFormat::Rss => render_template(RssFeedTemplate { feed }),
+    }
+    .map_err(|err| {
+        error!(error = %err, "failed to render feed");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    Ok((
+        [
+            ("content-type", format.content_type()),
+            ("cache-control", "private, max-age=300"),
+        ],
+        body,
+    )
+        .into_response())
+}
+
+/// Feeds of public profiles are open; private ones need the token issued
+/// for that feed.
+async fn can_read_feed(state: &AppState, user: &User, kind: FeedKind, token: Option<&str>) -> bool {
+    if user.public_profile {
+        return true;
+    }
+    let Some(token) = token else {
+        return false;
+    };
+    match state
+        .feed_token_repo
+        .get_by_token_hash(&hash_token(token))
+        .await
+    {
+        Ok(feed_token) => feed_token.user_id == user.id && feed_token.feed == kind,
+        Err(RepositoryError::NotFound) => false,
+        Err(err) => {
+            warn!(error = %err, "feed token lookup failed");
+            false
+        }
+    }
+}
+
+/// The user's latest events, without what their profile privacy hides.
+async fn load_feed_events(
+    state: &AppState,
+    user: &User,
+    kind: FeedKind,
+) -> Result<Vec<TimelineEvent>, RepositoryError> {
+    let mut filter = TimelineFilter::for_user(user.id);
+    if kind == FeedKind::Finished {
+        filter.entity_type = Some("reading".to_string());
+        filter.action = Some("finished".to_string());
+    }
+    let privacy = state.user_repo.get_profile_privacy(user.id).await?;
+    if !privacy.show_ratings {
+        // A rating change is all a `rated` event says
+        filter.exclude_action = Some("rated".to_string());
+    }
+    let sort_key = <TimelineSortKey as SortKey>::default();
+    let request = ListRequest::new(
+        1,
+        PageSize::limited(FEED_ENTRY_LIMIT),
+        sort_key,
+        sort_key.default_direction(),
+    );
+    let page = state.timeline_repo.list(&filter, &request).await?;
+    Ok(page
+        .items
+        .into_iter()
+        .map(|mut event| {
+            event.redact(privacy);
+            event
+        })
+        .collect())
+}
+
+/// The cover thumbnails of the books the events are about, by event id.
+async fn cover_enclosures(
+    state: &AppState,
+    events: &[TimelineEvent],
+    base_url: &str,
+) -> Result<HashMap<TimelineEventId, FeedEnclosureView>, RepositoryError> {
+    let reading_ids: Vec<ReadingId> = events
+        .iter()
+        .filter(|event| event.entity_type == "reading")
+        .map(|event| ReadingId::new(event.entity_id))
+        .collect();
+    let reading_books = state.reading_repo.book_ids(&reading_ids).await?;
+
+    let event_books: Vec<(TimelineEventId, i64)> = events
+        .iter()
+        .filter_map(|event| {
+            let book_id = match event.entity_type.as_str() {
+                "book" | "user_book" => event.entity_id,
+                "reading" => reading_books
+                    .get(&ReadingId::new(event.entity_id))?
+                    .into_inner(),
+                _ => return None,
+            };
+            Some((event.id, book_id))
+        })
+        .collect();
+    let mut book_ids: Vec<i64> = event_books.iter().map(|(_, book_id)| *book_id).collect();
+    book_ids.sort_unstable();
+    book_ids.dedup();
+    let thumbnails = state.image_repo.thumbnail_info("book", &book_ids).await?;
+
+    Ok(event_books
+        .into_iter()
+        .filter_map(|(event_id, book_id)| {
+            let thumbnail = thumbnails.get(&book_id)?;
+            Some((
+                event_id,
+                FeedEnclosureView {
+                    url: format!("{base_url}/api/v1/book/{book_id}/thumbnail"),
+                    length: thumbnail.length,
+                    content_type: thumbnail.content_type.clone(),
+                },
+            ))
+        })
+        .collect())
+}
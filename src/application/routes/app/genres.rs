@@ -7,7 +7,7 @@ use crate::application::errors::map_app_error;
 use crate::application::routes::render_html;
 use crate::application::state::AppState;
 use crate::presentation::web::templates::{GenreDetailTemplate, GenreEditTemplate};
-use crate::presentation::web::views::{AuthorBookCardView, GenreDetailView};
+use crate::presentation::web::views::{AuthorBookCardView, GenreDetailView, GenreOptionView};
 
 #[tracing::instrument(skip(state, cookies))]
 pub(crate) async fn genre_detail_page(
@@ -29,8 +29,14 @@ pub(crate) async fn genre_detail_page(
         .await
         .map_err(map_app_error)?;
 
+    let tree = state
+        .genre_repo
+        .tree()
+        .await
+        .map_err(|e| map_app_error(e.into()))?;
+
     let edit_url = format!("/genres/{}/edit", genre.id);
-    let view = GenreDetailView::from_domain(genre);
+    let view = GenreDetailView::from_domain(genre, &tree);
 
     let template = GenreDetailTemplate {
         nav_active: "data",
@@ -119,6 +125,27 @@ pub(crate) async fn genre_edit_page(
         .await
         .map_err(|e| map_app_error(e.into()))?;
 
+    // A genre cannot move under itself or any of its own subgenres.
+    let tree = state
+        .genre_repo
+        .tree()
+        .await
+        .map_err(|e| map_app_error(e.into()))?;
+    let excluded = tree.descendant_ids(genre.id);
+    let mut parent_options: Vec<GenreOptionView> = tree
+        .roots()
+        .into_iter()
+        .flat_map(|root| tree.descendant_ids(root.id))
+        .filter(|id| !excluded.contains(id))
+        .filter_map(|id| {
+            Some(GenreOptionView {
+                id: id.to_string(),
+                name: tree.path_name(id)?,
+            })
+        })
+        .collect();
+    parent_options.sort_by_key(|option| option.name.to_lowercase());
+
     let template = GenreEditTemplate {
         nav_active: "data",
         is_authenticated,
@@ -127,6 +154,8 @@ pub(crate) async fn genre_edit_page(
         impersonated_username,
         id: genre.id.to_string(),
         name: genre.name,
+        parent_id: genre.parent_id.map(|id| id.to_string()).unwrap_or_default(),
+        parent_options,
     };
 
     render_html(template).map(IntoResponse::into_response)
@@ -8,7 +8,7 @@ use crate::application::state::AppState;
 use crate::domain::ids::UserId;
 use crate::domain::listing::{ListRequest, PageSize, SortDirection, SortKey};
 use crate::domain::readings::{ReadingFilter, ReadingSortKey, ReadingStatus};
-use crate::domain::timeline::TimelineSortKey;
+use crate::domain::timeline::{TimelineFilter, TimelineSortKey};
 use crate::domain::user_books::{Shelf, UserBookSortKey};
 use rand::seq::SliceRandom;
 
@@ -16,7 +16,7 @@ use crate::application::auth::impersonation_info;
 use crate::domain::stats::CachedStats;
 use crate::presentation::web::templates::HomeTemplate;
 use crate::presentation::web::views::{
-    ReadingView, StatCard, StatsView, TimelineEventView, UserBookView,
+    ReadingView, ShelfView, StatCard, StatsView, TimelineEventView, UserBookView,
 };
 
 #[tracing::instrument(skip(state, cookies))]
@@ -27,25 +27,15 @@ pub(crate) async fn home_page(
     let user_id = crate::application::routes::authenticated_user_id(&state, &cookies).await;
     let is_authenticated = user_id.is_some();
 
-    let (content, stats_view) = tokio::try_join!(
+    let (content, stats_view, pinned_shelves) = tokio::try_join!(
         load_home_content(&state, user_id),
         load_stats(&state, user_id),
+        load_pinned_shelves(&state, user_id),
     )
     .map_err(map_app_error)?;
 
-    let stat_cards = if let Some(uid) = user_id {
-        state
-            .stats_repo
-            .get_cached(uid)
-            .await
-            .ok()
-            .flatten()
-            .map(build_stat_cards)
-            .unwrap_or_default()
-    } else {
-        Vec::new()
-    };
-
+    let stat_cards = load_stat_cards(&state, user_id).await;
+    let goals = super::stats::load_goal_views(&state, user_id, true).await;
     let (is_impersonating, impersonated_username) = impersonation_info(&state, &cookies).await;
 
     let template = HomeTemplate {
@@ -58,14 +48,45 @@ pub(crate) async fn home_page(
         currently_reading: content.currently_reading,
         recently_added: content.recently_added,
         wishlist: content.wishlist,
+        pinned_shelves,
         recent_events: content.recent_events,
         stats: stats_view,
         stat_cards,
+        goals,
     };
 
     render_html(template).map(IntoResponse::into_response)
 }
 
+/// Stat cards from the user's cached stats; empty until they're computed.
+pub(super) async fn load_stat_cards(state: &AppState, user_id: Option<UserId>) -> Vec<StatCard> {
+    let Some(uid) = user_id else {
+        return Vec::new();
+    };
+    state
+        .stats_repo
+        .get_cached(uid)
+        .await
+        .ok()
+        .flatten()
+        .map(build_stat_cards)
+        .unwrap_or_default()
+}
+
+/// The "Recent Activity" list: the user's own events, or everyone's when
+/// signed out.
+pub(super) async fn load_recent_events(
+    state: &AppState,
+    user_id: Option<UserId>,
+) -> Result<Vec<TimelineEventView>, AppError> {
+    let page = fetch_recent_events(state, user_id).await?;
+    Ok(page
+        .items
+        .into_iter()
+        .map(TimelineEventView::from_domain)
+        .collect())
+}
+
 struct HomeContent {
     currently_reading: Vec<ReadingView>,
     recently_added: Vec<UserBookView>,
@@ -161,12 +182,6 @@ async fn fetch_home_pages(
         ReadingSortKey::CreatedAt,
         SortDirection::Desc,
     );
-    let events_req = ListRequest::new(
-        1,
-        PageSize::limited(5),
-        TimelineSortKey::default(),
-        TimelineSortKey::default().default_direction(),
-    );
     let added_req = ListRequest::new(
         1,
         PageSize::limited(10),
@@ -199,13 +214,7 @@ async fn fetch_home_pages(
                 Ok(empty_reading())
             }
         },
-        async {
-            state
-                .timeline_repo
-                .list(user_id, &events_req)
-                .await
-                .map_err(AppError::from)
-        },
+        fetch_recent_events(state, user_id),
         async {
             if let Some(uid) = user_id {
                 state
@@ -231,6 +240,49 @@ async fn fetch_home_pages(
     )
 }
 
+async fn fetch_recent_events(
+    state: &AppState,
+    user_id: Option<UserId>,
+) -> Result<crate::domain::listing::Page<crate::domain::timeline::TimelineEvent>, AppError> {
+    let request = ListRequest::new(
+        1,
+        PageSize::limited(5),
+        TimelineSortKey::default(),
+        TimelineSortKey::default().default_direction(),
+    );
+    state
+        .timeline_repo
+        .list(
+            &user_id
+                .map_or_else(TimelineFilter::all, TimelineFilter::for_user)
+                .seen_by(user_id),
+            &request,
+        )
+        .await
+        .map_err(AppError::from)
+}
+
+async fn load_pinned_shelves(
+    state: &AppState,
+    user_id: Option<UserId>,
+) -> Result<Vec<ShelfView>, AppError> {
+    let Some(uid) = user_id else {
+        return Ok(Vec::new());
+    };
+
+    let shelves = state
+        .shelf_repo
+        .list_by_user(uid)
+        .await
+        .map_err(AppError::from)?;
+
+    let mut views = Vec::new();
+    for shelf in shelves.into_iter().filter(|s| s.pinned) {
+        views.push(super::shelves::load_shelf_view(state, shelf).await?);
+    }
+    Ok(views)
+}
+
 fn enrich_thumbnail(
     book_id: &str,
     thumbnail_url: &mut Option<String>,
@@ -243,7 +295,10 @@ fn enrich_thumbnail(
     }
 }
 
-async fn load_stats(state: &AppState, user_id: Option<UserId>) -> Result<StatsView, AppError> {
+pub(super) async fn load_stats(
+    state: &AppState,
+    user_id: Option<UserId>,
+) -> Result<StatsView, AppError> {
     let Some(uid) = user_id else {
         return Ok(StatsView {
             library: 0,
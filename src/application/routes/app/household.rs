@@ -0,0 +1,45 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Redirect, Response};
+
+use crate::application::auth::impersonation_info;
+use crate::application::errors::map_app_error;
+use crate::application::routes::render_html;
+use crate::application::services::stats::load_household_stats;
+use crate::application::state::AppState;
+use crate::presentation::web::templates::HouseholdTemplate;
+use crate::presentation::web::views::HouseholdStatsView;
+
+#[tracing::instrument(skip(state, cookies))]
+pub(crate) async fn household_page(
+    State(state): State<AppState>,
+    cookies: tower_cookies::Cookies,
+) -> Result<Response, StatusCode> {
+    let Some(user_id) = crate::application::routes::authenticated_user_id(&state, &cookies).await
+    else {
+        return Ok(Redirect::to("/login").into_response());
+    };
+    let (is_impersonating, impersonated_username) = impersonation_info(&state, &cookies).await;
+
+    let household = load_household_stats(&*state.stats_repo)
+        .await
+        .map_err(|e| map_app_error(e.into()))?;
+    let opted_out = state
+        .user_repo
+        .get(user_id)
+        .await
+        .map_err(|e| map_app_error(e.into()))?
+        .household_opt_out;
+
+    let template = HouseholdTemplate {
+        nav_active: "stats",
+        is_authenticated: true,
+        version_info: &crate::VERSION_INFO,
+        is_impersonating,
+        impersonated_username,
+        stats: HouseholdStatsView::from(&household),
+        opted_out,
+    };
+
+    render_html(template).map(IntoResponse::into_response)
+}
@@ -3,13 +3,22 @@ mod admin;
 pub(super) mod auth;
 mod authors;
 mod books;
+mod challenges;
 mod data;
+mod events;
+mod federation;
+pub(crate) mod feeds;
 pub(crate) mod genres;
 mod home;
+mod household;
+mod profile;
 mod readings;
+mod review;
+pub(crate) mod shelves;
 mod stats;
 mod timeline;
 mod webauthn;
+mod widget;
 
 use axum::response::IntoResponse;
 use axum::routing::{get, post};
@@ -57,7 +66,21 @@ pub(super) fn router() -> axum::Router<AppState> {
         .route("/data", get(data::data_page))
         .route("/add", get(add::add_page))
         .route("/timeline", get(timeline::timeline_page))
+        .route("/events", get(events::live_events))
+        .route("/.well-known/webfinger", get(federation::webfinger))
+        .route("/users/{name}", get(federation::actor))
+        .route("/users/{name}/outbox", get(federation::outbox))
+        .route("/users/{name}/followers", get(federation::followers))
+        .route("/users/{name}/inbox", post(federation::inbox))
+        .route("/users/{name}/feed.atom", get(feeds::atom_feed))
+        .route("/users/{name}/feed.rss", get(feeds::rss_feed))
+        .route("/u/{name}", get(profile::profile_page))
+        .route("/u/{name}/widget", get(widget::widget_html))
+        .route("/u/{name}/widget.json", get(widget::widget_json))
+        .route("/u/{name}/widget.svg", get(widget::widget_svg))
         .route("/stats", get(stats::stats_page))
+        .route("/stats/household", get(household::household_page))
+        .route("/review/{year}", get(review::review_page))
         .route("/authors/{id}", get(authors::author_detail_page))
         .route("/authors/{id}/edit", get(authors::author_edit_page))
         .route("/books/{id}", get(books::book_detail_page))
@@ -67,6 +90,15 @@ pub(super) fn router() -> axum::Router<AppState> {
         .route("/readings/{id}/edit", get(readings::reading_edit_page))
         .route("/genres/{id}", get(genres::genre_detail_page))
         .route("/genres/{id}/edit", get(genres::genre_edit_page))
+        .route("/shelves", get(shelves::shelves_page))
+        .route("/shelves/{id}", get(shelves::shelf_detail_page))
+        .route("/shelves/{id}/edit", get(shelves::shelf_edit_page))
+        .route("/challenges", get(challenges::challenges_page))
+        .route("/challenges/{id}", get(challenges::challenge_detail_page))
+        .route(
+            "/challenges/{id}/edit",
+            get(challenges::challenge_edit_page),
+        )
         .route("/static/css/styles.css", get(styles))
         .route("/static/js/webauthn.js", get(webauthn_js))
         .route(
@@ -0,0 +1,155 @@
+use std::collections::HashSet;
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use tracing::error;
+
+use crate::application::auth::impersonation_info;
+use crate::application::errors::{AppError, map_app_error};
+use crate::application::routes::app::feeds::{FeedFormat, feed_url};
+use crate::application::routes::render_html;
+use crate::application::state::AppState;
+use crate::domain::RepositoryError;
+use crate::domain::feed_tokens::FeedKind;
+use crate::domain::ids::UserId;
+use crate::domain::listing::{ListRequest, SortDirection};
+use crate::domain::readings::{ReadingFilter, ReadingSortKey, ReadingStatus, ReadingWithBook};
+use crate::presentation::web::templates::ProfileTemplate;
+use crate::presentation::web::views::{ProfileReadingView, ProfileStatsView, ShelfView};
+
+/// Finished books listed under "Recently finished".
+const RECENT_FINISHES_LIMIT: usize = 10;
+
+/// A reader's public profile. Profiles that aren't public look the same as
+/// missing ones, and private readings never appear.
+#[tracing::instrument(skip(state, cookies))]
+pub(crate) async fn profile_page(
+    State(state): State<AppState>,
+    cookies: tower_cookies::Cookies,
+    Path(username): Path<String>,
+) -> Result<Response, StatusCode> {
+    let user = match state.user_repo.get_by_username(&username).await {
+        Ok(user) if user.public_profile => user,
+        Ok(_) | Err(RepositoryError::NotFound) => return Err(StatusCode::NOT_FOUND),
+        Err(err) => {
+            error!(error = %err, "failed to load user for profile");
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+    let privacy = state
+        .user_repo
+        .get_profile_privacy(user.id)
+        .await
+        .map_err(|e| map_app_error(e.into()))?;
+
+    let reading = public_readings(
+        &state,
+        user.id,
+        ReadingStatus::Reading,
+        ReadingSortKey::StartedAt,
+    )
+    .await
+    .map_err(map_app_error)?;
+    let finished = public_readings(
+        &state,
+        user.id,
+        ReadingStatus::Read,
+        ReadingSortKey::FinishedAt,
+    )
+    .await
+    .map_err(map_app_error)?;
+    let headline = ProfileStatsView::new(&finished, chrono::Utc::now().date_naive(), privacy);
+
+    let book_ids: Vec<i64> = reading
+        .iter()
+        .chain(finished.iter().take(RECENT_FINISHES_LIMIT))
+        .map(|rwb| i64::from(rwb.reading.book_id))
+        .collect();
+    let books_with_images: HashSet<i64> = state
+        .image_repo
+        .entity_ids_with_images("book", &book_ids)
+        .await
+        .unwrap_or_default();
+    let to_view = |rwb: ReadingWithBook| {
+        let mut view = ProfileReadingView::from_domain(rwb, privacy);
+        if view
+            .book_id
+            .parse::<i64>()
+            .is_ok_and(|id| books_with_images.contains(&id))
+        {
+            view.thumbnail_url = Some(format!("/api/v1/book/{}/thumbnail", view.book_id));
+        }
+        view
+    };
+    let currently_reading = reading.into_iter().map(to_view).collect();
+    let recent_finishes = finished
+        .into_iter()
+        .take(RECENT_FINISHES_LIMIT)
+        .map(to_view)
+        .collect();
+
+    let shelves = load_public_shelves(&state, user.id)
+        .await
+        .map_err(map_app_error)?;
+
+    let is_authenticated = crate::application::routes::is_authenticated(&state, &cookies).await;
+    let (is_impersonating, impersonated_username) = impersonation_info(&state, &cookies).await;
+
+    let template = ProfileTemplate {
+        nav_active: "",
+        is_authenticated,
+        version_info: &crate::VERSION_INFO,
+        is_impersonating,
+        impersonated_username,
+        feed_url: feed_url(&user.username, FeedKind::All, FeedFormat::Atom, None),
+        username: user.username,
+        currently_reading,
+        recent_finishes,
+        stats: headline,
+        shelves,
+    };
+
+    render_html(template).map(IntoResponse::into_response)
+}
+
+/// The user's readings with `status` that aren't private, newest first.
+pub(super) async fn public_readings(
+    state: &AppState,
+    user_id: UserId,
+    status: ReadingStatus,
+    sort_key: ReadingSortKey,
+) -> Result<Vec<ReadingWithBook>, AppError> {
+    let request = ListRequest::show_all(sort_key, SortDirection::Desc);
+    let page = state
+        .reading_repo
+        .list(
+            ReadingFilter::for_user_status(user_id, status),
+            &request,
+            None,
+        )
+        .await
+        .map_err(AppError::from)?;
+    Ok(page
+        .items
+        .into_iter()
+        .filter(|rwb| !rwb.reading.private)
+        .collect())
+}
+
+async fn load_public_shelves(
+    state: &AppState,
+    user_id: UserId,
+) -> Result<Vec<ShelfView>, AppError> {
+    let shelves = state
+        .shelf_repo
+        .list_by_user(user_id)
+        .await
+        .map_err(AppError::from)?;
+
+    let mut views = Vec::new();
+    for shelf in shelves.into_iter().filter(|shelf| shelf.public) {
+        views.push(super::shelves::load_shelf_view(state, shelf).await?);
+    }
+    Ok(views)
+}
@@ -135,6 +135,11 @@ pub(crate) async fn reading_edit_page(
             .finished_at
             .map(|d| d.to_string())
             .unwrap_or_default(),
+        current_page: reading
+            .reading
+            .current_page
+            .map(|p| p.to_string())
+            .unwrap_or_default(),
         rating: reading
             .reading
             .rating
@@ -148,6 +153,7 @@ pub(crate) async fn reading_edit_page(
             .collect::<Vec<_>>()
             .join(","),
         book_club: is_book_club,
+        private: reading.reading.private,
         book_options,
     };
 
@@ -0,0 +1,65 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Redirect, Response};
+
+use super::stats::build_year_tabs;
+use crate::application::auth::impersonation_info;
+use crate::application::errors::{AppError, map_app_error};
+use crate::application::routes::api::review::load_year_review;
+use crate::application::routes::render_html;
+use crate::application::state::AppState;
+use crate::presentation::web::templates::YearReviewTemplate;
+use crate::presentation::web::views::YearReviewView;
+
+#[tracing::instrument(skip(state, cookies))]
+pub(crate) async fn review_page(
+    State(state): State<AppState>,
+    cookies: tower_cookies::Cookies,
+    Path(year): Path<i32>,
+) -> Result<Response, StatusCode> {
+    let Some(user_id) = crate::application::routes::authenticated_user_id(&state, &cookies).await
+    else {
+        return Ok(Redirect::to("/login").into_response());
+    };
+    let (is_impersonating, impersonated_username) = impersonation_info(&state, &cookies).await;
+
+    let review = load_year_review(&state, user_id, year)
+        .await
+        .map_err(|e| match e {
+            AppError::Validation(_) => StatusCode::NOT_FOUND,
+            other => map_app_error(other),
+        })?;
+
+    let book_ids: Vec<i64> = review
+        .top_books
+        .iter()
+        .chain(&review.first_finished)
+        .chain(&review.last_finished)
+        .map(|b| i64::from(b.book_id))
+        .collect();
+    let books_with_images = state
+        .image_repo
+        .entity_ids_with_images("book", &book_ids)
+        .await
+        .unwrap_or_default();
+
+    // Only years with data get a tab, with "All Time" dropped
+    let year_tabs = build_year_tabs(&state, Some(user_id))
+        .await
+        .into_iter()
+        .filter(|tab| tab.key != "all")
+        .collect();
+
+    let template = YearReviewTemplate {
+        nav_active: "stats",
+        is_authenticated: true,
+        version_info: &crate::VERSION_INFO,
+        is_impersonating,
+        impersonated_username,
+        has_data: !review.is_empty(),
+        review: YearReviewView::from_domain(&review, &books_with_images),
+        year_tabs,
+    };
+
+    render_html(template).map(IntoResponse::into_response)
+}
@@ -0,0 +1,143 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Redirect, Response};
+
+use crate::application::auth::impersonation_info;
+use crate::application::errors::{AppError, map_app_error};
+use crate::application::routes::api::shelves::owned_shelf;
+use crate::application::routes::render_html;
+use crate::application::state::AppState;
+use crate::domain::ids::ShelfId;
+use crate::domain::shelves::SavedShelf;
+use crate::presentation::web::templates::{
+    ShelfDetailTemplate, ShelfEditTemplate, ShelvesTemplate,
+};
+use crate::presentation::web::views::ShelfView;
+
+#[tracing::instrument(skip(state, cookies))]
+pub(crate) async fn shelves_page(
+    State(state): State<AppState>,
+    cookies: tower_cookies::Cookies,
+) -> Result<Response, StatusCode> {
+    let Some(user_id) = crate::application::routes::authenticated_user_id(&state, &cookies).await
+    else {
+        return Ok(Redirect::to("/login").into_response());
+    };
+    let (is_impersonating, impersonated_username) = impersonation_info(&state, &cookies).await;
+
+    let shelves = state
+        .shelf_repo
+        .list_by_user(user_id)
+        .await
+        .map_err(|e| map_app_error(e.into()))?;
+
+    let mut views = Vec::with_capacity(shelves.len());
+    for shelf in shelves {
+        views.push(
+            load_shelf_view(&state, shelf)
+                .await
+                .map_err(map_app_error)?,
+        );
+    }
+
+    let template = ShelvesTemplate {
+        nav_active: "data",
+        is_authenticated: true,
+        version_info: &crate::VERSION_INFO,
+        is_impersonating,
+        impersonated_username,
+        shelves: views,
+    };
+
+    render_html(template).map(IntoResponse::into_response)
+}
+
+#[tracing::instrument(skip(state, cookies))]
+pub(crate) async fn shelf_detail_page(
+    State(state): State<AppState>,
+    cookies: tower_cookies::Cookies,
+    Path(id): Path<ShelfId>,
+) -> Result<Response, StatusCode> {
+    let Some(user_id) = crate::application::routes::authenticated_user_id(&state, &cookies).await
+    else {
+        return Ok(Redirect::to("/login").into_response());
+    };
+    let (is_impersonating, impersonated_username) = impersonation_info(&state, &cookies).await;
+
+    let shelf = owned_shelf(&state, id, user_id)
+        .await
+        .map_err(map_app_error)?;
+    let view = load_shelf_view(&state, shelf)
+        .await
+        .map_err(map_app_error)?;
+
+    let template = ShelfDetailTemplate {
+        nav_active: "data",
+        is_authenticated: true,
+        version_info: &crate::VERSION_INFO,
+        is_impersonating,
+        impersonated_username,
+        edit_url: format!("/shelves/{id}/edit"),
+        shelf: view,
+    };
+
+    render_html(template).map(IntoResponse::into_response)
+}
+
+#[tracing::instrument(skip(state, cookies))]
+pub(crate) async fn shelf_edit_page(
+    State(state): State<AppState>,
+    cookies: tower_cookies::Cookies,
+    Path(id): Path<ShelfId>,
+) -> Result<Response, StatusCode> {
+    let Some(user_id) = crate::application::routes::authenticated_user_id(&state, &cookies).await
+    else {
+        return Ok(Redirect::to("/login").into_response());
+    };
+    let (is_impersonating, impersonated_username) = impersonation_info(&state, &cookies).await;
+
+    let shelf = owned_shelf(&state, id, user_id)
+        .await
+        .map_err(map_app_error)?;
+
+    let template = ShelfEditTemplate {
+        nav_active: "data",
+        is_authenticated: true,
+        version_info: &crate::VERSION_INFO,
+        is_impersonating,
+        impersonated_username,
+        shelf: ShelfView::from_domain(shelf, Vec::new()),
+    };
+
+    render_html(template).map(IntoResponse::into_response)
+}
+
+/// Resolves a shelf's books and attaches cover thumbnails where available.
+pub(crate) async fn load_shelf_view(
+    state: &AppState,
+    shelf: SavedShelf,
+) -> Result<ShelfView, AppError> {
+    let books = state
+        .shelf_service
+        .books(&shelf)
+        .await
+        .map_err(AppError::from)?;
+
+    let book_ids: Vec<i64> = books.iter().map(|bwa| i64::from(bwa.book.id)).collect();
+    let books_with_images: std::collections::HashSet<i64> = state
+        .image_repo
+        .entity_ids_with_images("book", &book_ids)
+        .await
+        .unwrap_or_default();
+
+    let mut view = ShelfView::from_domain(shelf, books);
+    for card in &mut view.books {
+        if let Ok(id) = card.book_id.parse::<i64>()
+            && books_with_images.contains(&id)
+        {
+            card.thumbnail_url = Some(format!("/api/v1/book/{}/thumbnail", card.book_id));
+        }
+    }
+
+    Ok(view)
+}
@@ -1,24 +1,46 @@
 use axum::extract::{Query, State};
 use axum::http::{HeaderMap, StatusCode, header::HeaderValue};
 use axum::response::{Html, IntoResponse, Response};
-use chrono::Utc;
+use chrono::{Days, NaiveDate, Utc};
 use serde::Deserialize;
 
 use crate::application::auth::impersonation_info;
 use crate::application::errors::map_app_error;
 use crate::application::routes::render_html;
 use crate::application::routes::support::is_datastar_request;
-use crate::application::services::stats::{compute_all_stats, compute_stats_for_year};
+use crate::application::services::stats::{compute_all_stats, compute_stats_report};
 use crate::application::state::AppState;
+use crate::domain::activity::ReadingActivity;
+use crate::domain::formatting::format_number;
 use crate::domain::ids::UserId;
-use crate::domain::stats::{BookSummaryStats, CachedStats, ReadingStats};
+use crate::domain::stats::{
+    BookSummaryStats, CachedStats, PeriodStats, ReadingStats, StatsPeriod, StatsReport,
+};
 use crate::presentation::web::templates::{
-    StatsContentTemplate, StatsPageTemplate, YearTab, render_template,
+    StatsContentTemplate, StatsPageTemplate, StatsRangeForm, StatsRangePreset, YearTab,
+    render_template,
+};
+use crate::presentation::web::views::{
+    GoalView, HeatmapView, ListeningStatsView, SentimentStatsView, StatsComparisonView,
+    TagCloudView,
 };
 
+/// Most-used tags shown in the stats page tag cloud.
+const TAG_CLOUD_LIMIT: u32 = 50;
+/// Days before today shown in the all-time heatmap, for 52 full weeks.
+const HEATMAP_DAYS: u64 = 363;
+/// Longest custom range that still gets a heatmap.
+const MAX_HEATMAP_DAYS: i64 = 366;
+
+/// Dates are kept as strings so a half-filled range form doesn't fail the
+/// whole page; anything that doesn't parse is ignored.
 #[derive(Debug, Deserialize)]
 pub(crate) struct StatsQuery {
     year: Option<String>,
+    from: Option<String>,
+    to: Option<String>,
+    compare_from: Option<String>,
+    compare_to: Option<String>,
 }
 
 #[tracing::instrument(skip(state, cookies, headers, query))]
@@ -32,14 +54,36 @@ pub(crate) async fn stats_page(
     let is_authenticated = user_id.is_some();
 
     let year_filter = parse_year_filter(query.year.as_ref());
+    let custom_range = parse_period(query.from.as_deref(), query.to.as_deref());
+    let period = custom_range.or_else(|| year_filter.and_then(StatsPeriod::year));
+    let compare = period.and(parse_period(
+        query.compare_from.as_deref(),
+        query.compare_to.as_deref(),
+    ));
 
-    let cached = match year_filter {
-        Some(year) => compute_year_stats(&state, user_id, year).await?,
-        None => load_or_compute(&state, user_id).await?,
+    let (cached, comparison) = match period {
+        Some(period) => {
+            let report = compute_period_report(&state, user_id, &period, compare.as_ref()).await?;
+            let comparison = StatsComparisonView::from_report(&report);
+            (period_cache(report.current), comparison)
+        }
+        None => (load_or_compute(&state, user_id).await?, None),
     };
 
-    let is_year_view = year_filter.is_some();
-    let content_html = render_stats_content(&cached, is_year_view)?;
+    let is_year_view = period.is_some();
+    let tag_cloud = if is_year_view {
+        Vec::new()
+    } else {
+        load_tag_cloud(&state, user_id).await
+    };
+    let heatmap = load_heatmap(&state, user_id, period.as_ref()).await;
+    let content = StatsContent {
+        period_label: custom_range.map(|p| p.label()),
+        comparison,
+        tag_cloud,
+        heatmap,
+    };
+    let content_html = render_stats_content(&cached, is_year_view, content)?;
 
     // Datastar fragment request: return the content wrapped in its container
     // and use `replace` mode so the entire element is swapped via
@@ -63,15 +107,23 @@ pub(crate) async fn stats_page(
     }
 
     // Full page request
-    let cache_age = if year_filter.is_none() {
+    let cache_age = if period.is_none() {
         format_cache_age(&cached.computed_at)
     } else {
         String::new()
     };
 
     let year_tabs = build_year_tabs(&state, user_id).await;
-    let active_year = year_filter.map_or_else(|| "all".to_string(), |y| y.to_string());
+    let active_year = match (custom_range, year_filter) {
+        (Some(_), _) => "range".to_string(),
+        (None, Some(year)) => year.to_string(),
+        (None, None) => "all".to_string(),
+    };
+    let range_presets = build_range_presets(&year_tabs);
+    // Tabs run newest first, so without a selected year this is the latest
+    let review_year = year_filter.or_else(|| year_tabs.iter().find_map(|t| t.key.parse().ok()));
 
+    let goals = load_goal_views(&state, user_id, false).await;
     let (is_impersonating, impersonated_username) = impersonation_info(&state, &cookies).await;
 
     let template = StatsPageTemplate {
@@ -85,6 +137,15 @@ pub(crate) async fn stats_page(
         content: content_html,
         year_tabs,
         active_year,
+        range_form: StatsRangeForm {
+            from: query.from.unwrap_or_default(),
+            to: query.to.unwrap_or_default(),
+            compare_from: query.compare_from.unwrap_or_default(),
+            compare_to: query.compare_to.unwrap_or_default(),
+        },
+        range_presets,
+        goals,
+        review_year,
     };
 
     render_html(template).map(IntoResponse::into_response)
@@ -102,8 +163,44 @@ fn parse_year_filter(year: Option<&String>) -> Option<i32> {
     })
 }
 
+/// Parse a `from`/`to` pair of ISO dates, ignoring blank, invalid or reversed ranges.
+fn parse_period(from: Option<&str>, to: Option<&str>) -> Option<StatsPeriod> {
+    let parse = |s: &str| NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d").ok();
+    StatsPeriod::new(parse(from?)?, parse(to?)?).ok()
+}
+
+/// Quick links for common ranges: the last twelve months on their own and
+/// against the twelve before, and the latest two years side by side.
+fn build_range_presets(year_tabs: &[YearTab]) -> Vec<StatsRangePreset> {
+    let Some(recent) = StatsPeriod::last_twelve_months(Utc::now().date_naive()) else {
+        return Vec::new();
+    };
+    let mut presets = vec![StatsRangePreset::new("Last 12 months", &recent, None)];
+    if let Some(previous) = recent.preceding() {
+        presets.push(StatsRangePreset::new(
+            "Last 12 months vs previous",
+            &recent,
+            Some(&previous),
+        ));
+    }
+
+    let mut years = year_tabs
+        .iter()
+        .filter_map(|tab| tab.key.parse::<i32>().ok());
+    if let Some((latest, before)) = years.next().zip(years.next())
+        && let Some((latest, before)) = StatsPeriod::year(latest).zip(StatsPeriod::year(before))
+    {
+        presets.push(StatsRangePreset::new(
+            &format!("{} vs {}", latest.label(), before.label()),
+            &latest,
+            Some(&before),
+        ));
+    }
+    presets
+}
+
 /// Build year tabs from available years.
-async fn build_year_tabs(state: &AppState, user_id: Option<UserId>) -> Vec<YearTab> {
+pub(crate) async fn build_year_tabs(state: &AppState, user_id: Option<UserId>) -> Vec<YearTab> {
     let Some(uid) = user_id else {
         return vec![];
     };
@@ -155,27 +252,132 @@ async fn load_or_compute(
     }
 }
 
-/// Compute year-specific stats on demand (no caching).
-async fn compute_year_stats(
+/// Compute stats for a period, and optionally a second one to compare it
+/// against, on demand (no caching).
+async fn compute_period_report(
     state: &AppState,
     user_id: Option<UserId>,
-    year: i32,
-) -> Result<CachedStats, StatusCode> {
+    period: &StatsPeriod,
+    compare: Option<&StatsPeriod>,
+) -> Result<StatsReport, StatusCode> {
     if let Some(uid) = user_id {
-        compute_stats_for_year(&*state.stats_repo, uid, year)
+        compute_stats_report(&*state.stats_repo, uid, period, compare)
             .await
             .map_err(|e| map_app_error(e.into()))
     } else {
-        Ok(CachedStats {
-            book_summary: BookSummaryStats::default(),
-            reading: ReadingStats::default(),
-            computed_at: chrono::Utc::now().to_rfc3339(),
-        })
+        Ok(StatsReport::new(
+            PeriodStats {
+                period: *period,
+                book_summary: BookSummaryStats::default(),
+                reading: ReadingStats::default(),
+            },
+            None,
+        ))
     }
 }
 
+/// Period stats in the shape the stats content template renders.
+fn period_cache(stats: PeriodStats) -> CachedStats {
+    CachedStats {
+        book_summary: stats.book_summary,
+        reading: stats.reading,
+        computed_at: chrono::Utc::now().to_rfc3339(),
+    }
+}
+
+/// Load the user's tag cloud. Tags are not cached with the other stats since
+/// they change independently of readings.
+async fn load_tag_cloud(state: &AppState, user_id: Option<UserId>) -> Vec<TagCloudView> {
+    let Some(uid) = user_id else {
+        return Vec::new();
+    };
+    let counts = state
+        .tag_repo
+        .counts_for_user(uid, None, Some(TAG_CLOUD_LIMIT))
+        .await
+        .unwrap_or_default();
+    TagCloudView::from_counts(counts)
+}
+
+/// Build the daily activity heatmap: the selected period if it's no longer
+/// than a year, or the last 52 weeks. Computed live from reading dates, like goals.
+async fn load_heatmap(
+    state: &AppState,
+    user_id: Option<UserId>,
+    period: Option<&StatsPeriod>,
+) -> Option<HeatmapView> {
+    let uid = user_id?;
+    let today = Utc::now().date_naive();
+    let (from, to) = match period {
+        Some(p) if (p.to - p.from).num_days() < MAX_HEATMAP_DAYS => (p.from, p.to),
+        Some(_) => return None,
+        None => (today.checked_sub_days(Days::new(HEATMAP_DAYS))?, today),
+    };
+    let spans = match state.stats_repo.reading_spans(uid).await {
+        Ok(spans) => spans,
+        Err(err) => {
+            tracing::warn!(error = %err, "failed to load reading activity");
+            return None;
+        }
+    };
+    let activity = ReadingActivity::build(&spans, from, to, today);
+    (activity.active_days > 0).then(|| HeatmapView::from(activity))
+}
+
+/// Load the user's reading goals with their progress. With `active_only`,
+/// goals whose period does not include today are left out. Goals are computed
+/// live rather than cached, since they are cheap and change with every reading.
+pub(crate) async fn load_goal_views(
+    state: &AppState,
+    user_id: Option<UserId>,
+    active_only: bool,
+) -> Vec<GoalView> {
+    let Some(uid) = user_id else {
+        return Vec::new();
+    };
+    let today = Utc::now().date_naive();
+    let progress = match state.goal_service.progress_for_user(uid, today).await {
+        Ok(progress) => progress,
+        Err(err) => {
+            tracing::warn!(error = %err, "failed to load reading goals");
+            return Vec::new();
+        }
+    };
+
+    let tree = if progress.iter().any(|p| p.goal.genre_id.is_some()) {
+        state.genre_repo.tree().await.ok()
+    } else {
+        None
+    };
+
+    progress
+        .into_iter()
+        .filter(|p| !active_only || p.goal.is_active(today))
+        .map(|p| {
+            let genre_name = p
+                .goal
+                .genre_id
+                .zip(tree.as_ref())
+                .and_then(|(id, tree)| tree.path_name(id));
+            GoalView::from_domain(p, genre_name)
+        })
+        .collect()
+}
+
+/// Parts of the stats content that aren't part of `CachedStats`.
+struct StatsContent {
+    period_label: Option<String>,
+    comparison: Option<StatsComparisonView>,
+    tag_cloud: Vec<TagCloudView>,
+    heatmap: Option<HeatmapView>,
+}
+
 /// Render the stats content HTML from `CachedStats`.
-fn render_stats_content(cached: &CachedStats, is_year_view: bool) -> Result<String, StatusCode> {
+fn render_stats_content(
+    cached: &CachedStats,
+    is_year_view: bool,
+    content: StatsContent,
+) -> Result<String, StatusCode> {
     let has_data = cached.reading.books_all_time > 0 || cached.book_summary.unique_genres > 0;
     let rating_chart_data = build_rating_chart_data(&cached.reading.rating_distribution);
     let activity_chart_data = if is_year_view {
@@ -212,6 +414,12 @@ fn render_stats_content(cached: &CachedStats, is_year_view: bool) -> Result<Stri
         avg_rating_formatted,
         avg_days_formatted,
         rating_chart_data,
+        period_label: content.period_label,
+        comparison: content.comparison,
+        tag_cloud: content.tag_cloud,
+        heatmap: content.heatmap,
+        sentiment: SentimentStatsView::from_stats(&cached.reading, &cached.book_summary),
+        listening: ListeningStatsView::from_stats(&cached.reading, is_year_view),
     })
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
 }
@@ -268,19 +476,3 @@ fn build_rating_chart_data(distribution: &[(f64, u64)]) -> String {
         .collect::<Vec<_>>()
         .join("|")
 }
-
-/// Format a number with thousand separators.
-fn format_number(n: i64) -> String {
-    if n < 1_000 {
-        return n.to_string();
-    }
-    let s = n.to_string();
-    let mut result = String::with_capacity(s.len() + s.len() / 3);
-    for (i, c) in s.chars().rev().enumerate() {
-        if i > 0 && i % 3 == 0 {
-            result.push(',');
-        }
-        result.push(c);
-    }
-    result.chars().rev().collect()
-}
@@ -6,18 +6,18 @@ use serde::Deserialize;
 
 use crate::application::auth::impersonation_info;
 use crate::application::errors::{AppError, map_app_error};
+use crate::application::routes::api::system::timeline::TimelineFilterQuery;
 use crate::application::routes::render_html;
 use crate::application::routes::support::{is_datastar_request, normalize_request};
 use crate::application::state::AppState;
 use crate::domain::listing::{ListRequest, PageSize, SortDirection, SortKey};
-use crate::domain::timeline::{TimelineEvent, TimelineSortKey};
+use crate::domain::timeline::{TimelineFilter, TimelineSortKey};
 use crate::presentation::web::templates::{TimelineChunkTemplate, TimelineTemplate};
 use crate::presentation::web::views::{
-    ListNavigator, Paginated, TimelineEventView, TimelineMonthView,
+    ListNavigator, Paginated, TimelineEventView, TimelineFiltersView, TimelineMonthView,
+    timeline_filter_path, timeline_filter_query,
 };
 
-const TIMELINE_PAGE_PATH: &str = "/timeline";
-const TIMELINE_FRAGMENT_PATH: &str = "/timeline";
 const TIMELINE_DEFAULT_PAGE_SIZE: u32 = 20;
 
 #[derive(Debug, Deserialize)]
@@ -44,10 +44,10 @@ impl TimelineQuery {
         let page_size = match &self.page_size {
             Some(PageSizeParam::Number(value)) => PageSize::limited(*value),
             Some(PageSizeParam::Text(text)) if text.eq_ignore_ascii_case("all") => PageSize::All,
-            Some(PageSizeParam::Text(text)) => text
-                .parse::<u32>()
-                .map(PageSize::limited)
-                .unwrap_or(PageSize::limited(TIMELINE_DEFAULT_PAGE_SIZE)),
+            Some(PageSizeParam::Text(text)) => text.parse::<u32>().map_or(
+                PageSize::limited(TIMELINE_DEFAULT_PAGE_SIZE),
+                PageSize::limited,
+            ),
             None => PageSize::limited(TIMELINE_DEFAULT_PAGE_SIZE),
         };
 
@@ -71,23 +71,39 @@ impl TimelineQuery {
     }
 }
 
-#[tracing::instrument(skip(state, cookies, headers, query))]
+#[tracing::instrument(skip(state, cookies, headers, query, filter_query))]
 pub(crate) async fn timeline_page(
     State(state): State<AppState>,
     cookies: tower_cookies::Cookies,
     headers: HeaderMap,
     Query(query): Query<TimelineQuery>,
+    Query(filter_query): Query<TimelineFilterQuery>,
 ) -> Result<Response, StatusCode> {
     let request = query.to_request();
-    let is_authenticated = crate::application::routes::is_authenticated(&state, &cookies).await;
+    let viewer = crate::application::routes::authenticated_user_id(&state, &cookies).await;
+    let is_authenticated = viewer.is_some();
+    let filter = filter_query.into_filter().seen_by(viewer);
 
     if is_datastar_request(&headers) {
-        return render_timeline_chunk(state, request, is_authenticated)
+        return render_timeline_chunk(state, request, &filter, is_authenticated)
             .await
             .map_err(map_app_error);
     }
 
-    let data = load_timeline_page(&state, request)
+    // New events only belong at the top of the first, newest-first page. The
+    // stream gets the filter so it only sends events that match it.
+    let live_events_url = (request.page == 1
+        && request.sort_key == TimelineSortKey::default()
+        && request.sort_direction == TimelineSortKey::default().default_direction())
+    .then(|| match timeline_filter_query(&filter).as_str() {
+        "" => "/events?page=timeline".to_string(),
+        query => format!("/events?page=timeline&{query}"),
+    });
+
+    let data = load_timeline_page(&state, request, &filter)
+        .await
+        .map_err(map_app_error)?;
+    let filters = load_filters_view(&state, &filter, is_authenticated)
         .await
         .map_err(map_app_error)?;
 
@@ -102,23 +118,35 @@ pub(crate) async fn timeline_page(
         events: data.events,
         navigator: data.navigator,
         months: data.months,
+        filters,
+        live_events_url,
     };
 
     render_html(template).map(IntoResponse::into_response)
 }
 
-struct TimelinePreparedEvent {
-    anchor: String,
-    heading: String,
-    view: TimelineEventView,
+/// Choices for the filter form. Usernames are only listed to signed-in users.
+async fn load_filters_view(
+    state: &AppState,
+    filter: &TimelineFilter,
+    is_authenticated: bool,
+) -> Result<TimelineFiltersView, AppError> {
+    let genres = state.genre_repo.list_all().await.map_err(AppError::from)?;
+    let users = if is_authenticated {
+        state.user_repo.list_all().await.map_err(AppError::from)?
+    } else {
+        Vec::new()
+    };
+    Ok(TimelineFiltersView::new(filter, &genres, &users))
 }
 
 async fn render_timeline_chunk(
     state: AppState,
     request: ListRequest<TimelineSortKey>,
+    filter: &TimelineFilter,
     is_authenticated: bool,
 ) -> Result<Response, AppError> {
-    let data = load_timeline_page(&state, request).await?;
+    let data = load_timeline_page(&state, request, filter).await?;
     let template = TimelineChunkTemplate {
         is_authenticated,
         events: data.events,
@@ -139,24 +167,20 @@ struct TimelinePageData {
 async fn load_timeline_page(
     state: &AppState,
     request: ListRequest<TimelineSortKey>,
+    filter: &TimelineFilter,
 ) -> Result<TimelinePageData, AppError> {
     let page = state
         .timeline_repo
-        .list(None, &request)
+        .list(filter, &request)
         .await
         .map_err(AppError::from)?;
 
     let normalized_request = normalize_request(request, &page);
 
-    let prepared_events = page
-        .items
-        .into_iter()
-        .map(prepare_event)
-        .collect::<Vec<_>>();
-
-    let views = prepared_events
+    let months = TimelineMonthView::group(page.items);
+    let views = months
         .iter()
-        .map(|prepared| prepared.view.clone())
+        .flat_map(|month| month.events.iter().cloned())
         .collect::<Vec<_>>();
 
     let events = Paginated::new(
@@ -166,13 +190,9 @@ async fn load_timeline_page(
         page.total,
         page.showing_all,
     );
-    let months = build_months(prepared_events);
-    let navigator = ListNavigator::new(
-        TIMELINE_PAGE_PATH,
-        TIMELINE_FRAGMENT_PATH,
-        normalized_request,
-        None,
-    );
+    // Page links keep the filters in effect
+    let path = timeline_filter_path(filter);
+    let navigator = ListNavigator::new(path.clone(), path, normalized_request, None);
 
     Ok(TimelinePageData {
         events,
@@ -180,36 +200,3 @@ async fn load_timeline_page(
         months,
     })
 }
-
-fn prepare_event(event: TimelineEvent) -> TimelinePreparedEvent {
-    let anchor = event.occurred_at.format("%Y-%m").to_string();
-    let heading = event.occurred_at.format("%B %Y").to_string();
-    let view = TimelineEventView::from_domain(event);
-
-    TimelinePreparedEvent {
-        anchor,
-        heading,
-        view,
-    }
-}
-
-fn build_months(prepared_events: Vec<TimelinePreparedEvent>) -> Vec<TimelineMonthView> {
-    let mut months: Vec<TimelineMonthView> = Vec::new();
-
-    for prepared in prepared_events {
-        if let Some(last) = months.last_mut()
-            && last.anchor == prepared.anchor
-        {
-            last.events.push(prepared.view);
-            continue;
-        }
-
-        months.push(TimelineMonthView {
-            anchor: prepared.anchor,
-            heading: prepared.heading,
-            events: vec![prepared.view],
-        });
-    }
-
-    months
-}
@@ -0,0 +1,283 @@
+use std::collections::HashSet;
+
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, HeaderValue, StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use base64::Engine as _;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::error;
+
+use crate::application::errors::map_app_error;
+use crate::application::state::AppState;
+use crate::domain::RepositoryError;
+use crate::domain::readings::{ReadingSortKey, ReadingStatus, ReadingWithBook};
+use crate::domain::users::User;
+use crate::presentation::web::templates::{
+    ReadingBadgeTemplate, ReadingWidgetTemplate, render_template,
+};
+use crate::presentation::web::views::{WidgetPalette, WidgetReadingView, WidgetView};
+
+/// Readings a widget lists unless `count` says otherwise.
+const DEFAULT_WIDGET_COUNT: usize = 3;
+/// Upper bound for `count`, to keep embeds small.
+const MAX_WIDGET_COUNT: usize = 10;
+
+/// Widgets change rarely; embedding pages revalidate with the entity tag after this.
+const WIDGET_CACHE_CONTROL: &str = "public, max-age=300";
+
+/// Lets any site frame the HTML widget, which loads nothing but its own
+/// cover thumbnails.
+const WIDGET_CSP: &str = "default-src 'none'; img-src 'self'; style-src 'unsafe-inline'; \
+                          frame-ancestors *";
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WidgetTheme {
+    #[default]
+    Light,
+    Dark,
+}
+
+impl WidgetTheme {
+    fn palette(self) -> WidgetPalette {
+        match self {
+            WidgetTheme::Light => WidgetPalette::LIGHT,
+            WidgetTheme::Dark => WidgetPalette::DARK,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WidgetQuery {
+    #[serde(default)]
+    theme: WidgetTheme,
+    #[serde(default)]
+    count: Option<usize>,
+}
+
+impl WidgetQuery {
+    fn count(&self) -> usize {
+        self.count
+            .unwrap_or(DEFAULT_WIDGET_COUNT)
+            .clamp(1, MAX_WIDGET_COUNT)
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct WidgetResponse {
+    username: String,
+    profile_url: String,
+    readings: Vec<WidgetReading>,
+}
+
+#[derive(Debug, Serialize)]
+struct WidgetReading {
+    book_id: i64,
+    title: String,
+    authors: String,
+    started_at: Option<chrono::NaiveDate>,
+    cover_url: Option<String>,
+}
+
+/// An iframe-friendly HTML list of what a reader is reading right now.
+#[tracing::instrument(skip(state, headers))]
+pub(crate) async fn widget_html(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(username): Path<String>,
+    Query(query): Query<WidgetQuery>,
+) -> Result<Response, StatusCode> {
+    let (user, readings) = load_widget_readings(&state, &username, query.count()).await?;
+    let covers = books_with_covers(&state, &readings).await;
+
+    let readings = readings
+        .into_iter()
+        .enumerate()
+        .map(|(index, rwb)| {
+            let cover_url = cover_url(&covers, &rwb);
+            WidgetReadingView::from_domain(rwb, index, cover_url, None)
+        })
+        .collect();
+    let body = render_widget(ReadingWidgetTemplate {
+        widget: widget_view(user, query.theme, readings),
+    })?;
+
+    let mut response = cached_response(&headers, "text/html; charset=utf-8", body);
+    response.headers_mut().insert(
+        header::CONTENT_SECURITY_POLICY,
+        HeaderValue::from_static(WIDGET_CSP),
+    );
+    Ok(response)
+}
+
+/// The same list as an SVG badge, with covers inlined so it also works as
+/// a plain `<img>`.
+#[tracing::instrument(skip(state, headers))]
+pub(crate) async fn widget_svg(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(username): Path<String>,
+    Query(query): Query<WidgetQuery>,
+) -> Result<Response, StatusCode> {
+    let (user, readings) = load_widget_readings(&state, &username, query.count()).await?;
+
+    let mut views = Vec::with_capacity(readings.len());
+    for (index, rwb) in readings.into_iter().enumerate() {
+        let cover = state
+            .image_repo
+            .get_thumbnail("book", i64::from(rwb.reading.book_id))
+            .await
+            .ok()
+            .map(|image| {
+                format!(
+                    "data:{};base64,{}",
+                    image.content_type,
+                    STANDARD.encode(image.thumbnail_data)
+                )
+            });
+        views.push(WidgetReadingView::from_domain(rwb, index, None, cover));
+    }
+    let body = render_widget(ReadingBadgeTemplate {
+        widget: widget_view(user, query.theme, views),
+    })?;
+
+    Ok(cached_response(&headers, "image/svg+xml", body))
+}
+
+/// The same list as JSON, for sites that render their own.
+#[tracing::instrument(skip(state, headers))]
+pub(crate) async fn widget_json(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(username): Path<String>,
+    Query(query): Query<WidgetQuery>,
+) -> Result<Response, StatusCode> {
+    let (user, readings) = load_widget_readings(&state, &username, query.count()).await?;
+    let covers = books_with_covers(&state, &readings).await;
+
+    let response = WidgetResponse {
+        profile_url: profile_url(&user),
+        username: user.username,
+        readings: readings
+            .into_iter()
+            .map(|rwb| WidgetReading {
+                book_id: rwb.reading.book_id.into_inner(),
+                cover_url: cover_url(&covers, &rwb),
+                started_at: rwb.reading.started_at,
+                authors: rwb.author_names,
+                title: rwb.book_title,
+            })
+            .collect(),
+    };
+    let body = serde_json::to_string(&response).map_err(|err| {
+        error!(error = %err, "failed to serialize widget");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let mut response = cached_response(&headers, "application/json", body);
+    response.headers_mut().insert(
+        header::ACCESS_CONTROL_ALLOW_ORIGIN,
+        HeaderValue::from_static("*"),
+    );
+    Ok(response)
+}
+
+/// The reader's newest non-private active readings. Widgets follow the
+/// public profile setting, so a private profile looks like a missing one.
+async fn load_widget_readings(
+    state: &AppState,
+    username: &str,
+    count: usize,
+) -> Result<(User, Vec<ReadingWithBook>), StatusCode> {
+    let user = match state.user_repo.get_by_username(username).await {
+        Ok(user) if user.public_profile => user,
+        Ok(_) | Err(RepositoryError::NotFound) => return Err(StatusCode::NOT_FOUND),
+        Err(err) => {
+            error!(error = %err, "failed to load user for widget");
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+    let mut readings = super::profile::public_readings(
+        state,
+        user.id,
+        ReadingStatus::Reading,
+        ReadingSortKey::StartedAt,
+    )
+    .await
+    .map_err(map_app_error)?;
+    readings.truncate(count);
+    Ok((user, readings))
+}
+
+async fn books_with_covers(state: &AppState, readings: &[ReadingWithBook]) -> HashSet<i64> {
+    let book_ids: Vec<i64> = readings
+        .iter()
+        .map(|rwb| i64::from(rwb.reading.book_id))
+        .collect();
+    state
+        .image_repo
+        .entity_ids_with_images("book", &book_ids)
+        .await
+        .unwrap_or_default()
+}
+
+fn cover_url(covers: &HashSet<i64>, rwb: &ReadingWithBook) -> Option<String> {
+    let book_id = i64::from(rwb.reading.book_id);
+    covers
+        .contains(&book_id)
+        .then(|| format!("{}/api/v1/book/{book_id}/thumbnail", crate::base_url()))
+}
+
+fn profile_url(user: &User) -> String {
+    format!("{}/u/{}", crate::base_url(), user.username)
+}
+
+fn widget_view(user: User, theme: WidgetTheme, readings: Vec<WidgetReadingView>) -> WidgetView {
+    WidgetView {
+        profile_url: profile_url(&user),
+        username: user.username,
+        palette: theme.palette(),
+        readings,
+    }
+}
+
+fn render_widget<T: askama::Template>(template: T) -> Result<String, StatusCode> {
+    render_template(template).map_err(|err| {
+        error!(error = %err, "failed to render widget");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+/// Serve `body` with a content-derived entity tag, answering `304 Not Modified`
+/// when the embedding page already has this version.
+fn cached_response(headers: &HeaderMap, content_type: &'static str, body: String) -> Response {
+    let digest = Sha256::digest(body.as_bytes());
+    // Weak, since compression may re-encode the same content
+    let etag = format!("W/\"{}\"", URL_SAFE_NO_PAD.encode(&digest[..16]));
+    let cache_headers = [
+        (header::ETAG, etag.clone()),
+        (header::CACHE_CONTROL, WIDGET_CACHE_CONTROL.to_string()),
+    ];
+
+    let not_modified = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| {
+            value
+                .split(',')
+                .map(str::trim)
+                .any(|candidate| candidate == "*" || weak_eq(candidate, &etag))
+        });
+    if not_modified {
+        return (StatusCode::NOT_MODIFIED, cache_headers).into_response();
+    }
+
+    (cache_headers, [(header::CONTENT_TYPE, content_type)], body).into_response()
+}
+
+/// Entity tags compare weakly for `If-None-Match`, ignoring the `W/` prefix.
+fn weak_eq(a: &str, b: &str) -> bool {
+    a.trim_start_matches("W/") == b.trim_start_matches("W/")
+}
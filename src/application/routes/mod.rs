@@ -47,15 +47,11 @@ pub fn app_router(state: AppState) -> axum::Router {
                     axum::http::header::X_CONTENT_TYPE_OPTIONS,
                     HeaderValue::from_static("nosniff"),
                 ))
-                .layer(SetResponseHeaderLayer::overriding(
-                    axum::http::header::X_FRAME_OPTIONS,
-                    HeaderValue::from_static("DENY"),
-                ))
                 .layer(SetResponseHeaderLayer::overriding(
                     axum::http::header::REFERRER_POLICY,
                     HeaderValue::from_static("strict-origin-when-cross-origin"),
                 ))
-                .layer(SetResponseHeaderLayer::overriding(
+                .layer(SetResponseHeaderLayer::if_not_present(
                     axum::http::header::CONTENT_SECURITY_POLICY,
                     HeaderValue::from_static(
                         "default-src 'self'; \
@@ -66,6 +62,11 @@ pub fn app_router(state: AppState) -> axum::Router {
                          frame-ancestors 'none'",
                     ),
                 ))
+                // Inside the CSP layer, so it only sees a policy the handler set
+                .layer(SetResponseHeaderLayer::overriding(
+                    axum::http::header::X_FRAME_OPTIONS,
+                    deny_framing,
+                ))
                 .layer(SetResponseHeaderLayer::overriding(
                     axum::http::header::STRICT_TRANSPORT_SECURITY,
                     HeaderValue::from_static("max-age=63072000; includeSubDomains"),
@@ -75,6 +76,15 @@ pub fn app_router(state: AppState) -> axum::Router {
         .with_state(state)
 }
 
+/// `DENY`, unless the handler set its own CSP; embeddable responses say who
+/// may frame them through `frame-ancestors` instead.
+fn deny_framing<B>(response: &axum::http::Response<B>) -> Option<HeaderValue> {
+    (!response
+        .headers()
+        .contains_key(axum::http::header::CONTENT_SECURITY_POLICY))
+    .then(|| HeaderValue::from_static("DENY"))
+}
+
 #[derive(Clone)]
 struct BooklogMakeSpan;
 
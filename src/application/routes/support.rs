@@ -12,6 +12,7 @@ use tracing::warn;
 
 use crate::application::errors::{ApiError, AppError};
 use crate::application::state::AppState;
+use crate::domain::filters::{FilterError, FilterQuery, FilterTarget};
 use crate::domain::listing::{
     DEFAULT_PAGE_SIZE, ListRequest, Page, PageSize, SortDirection, SortKey,
 };
@@ -145,6 +146,14 @@ impl ListQuery {
     }
 }
 
+/// Parses an optional search box value as a structured filter query.
+pub(crate) fn parse_filter_query(
+    search: Option<&str>,
+    target: FilterTarget,
+) -> Result<Option<FilterQuery>, FilterError> {
+    search.map(|s| FilterQuery::parse(s, target)).transpose()
+}
+
 pub fn normalize_request<K, T>(request: ListRequest<K>, page: &Page<T>) -> ListRequest<K>
 where
     K: SortKey,
@@ -447,6 +456,20 @@ where
     deserializer.deserialize_any(EmptyStringVisitor(PhantomData))
 }
 
+/// Deserialize a field that can be cleared, for `Option<Option<T>>` updates.
+///
+/// Pair with `#[serde(default)]`: an absent field stays `None` (don't change),
+/// while an empty string or `null` becomes `Some(None)` (clear).
+#[allow(clippy::option_option)] // absent vs cleared
+pub(crate) fn clearable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: de::Deserializer<'de>,
+    T: FromStr,
+    <T as FromStr>::Err: fmt::Display,
+{
+    empty_string_as_none(deserializer).map(Some)
+}
+
 /// Deserialize a `Vec<i64>`, treating entries with empty strings as absent.
 ///
 /// HTML form hidden inputs always submit their value, even when empty.
@@ -614,6 +637,24 @@ mod tests {
         assert_eq!(t.v, None);
     }
 
+    #[test]
+    fn clearable_distinguishes_absent_from_cleared() {
+        #[derive(Deserialize)]
+        struct T {
+            #[serde(default, deserialize_with = "clearable")]
+            v: Option<Option<i64>>,
+        }
+
+        let t: T = serde_json::from_str(r#"{}"#).unwrap();
+        assert_eq!(t.v, None);
+        let t: T = serde_json::from_str(r#"{"v": ""}"#).unwrap();
+        assert_eq!(t.v, Some(None));
+        let t: T = serde_json::from_str(r#"{"v": null}"#).unwrap();
+        assert_eq!(t.v, Some(None));
+        let t: T = serde_json::from_str(r#"{"v": "7"}"#).unwrap();
+        assert_eq!(t.v, Some(Some(7)));
+    }
+
     #[test]
     fn empty_strings_as_vec_i64_parses_empty_string() {
         #[derive(Deserialize)]
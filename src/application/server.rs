@@ -9,9 +9,7 @@ use tracing::info;
 use webauthn_rs::prelude::*;
 
 use crate::application::routes::app_router;
-use crate::application::services::stats::stats_recomputation_task;
-use crate::application::services::timeline_refresh::{TimelineInvalidation, timeline_rebuild_task};
-use crate::application::services::{StatsInvalidator, TimelineInvalidator};
+use crate::application::services::JobWorker;
 use crate::application::state::{AppState, AppStateConfig};
 use crate::domain::registration_tokens::NewRegistrationToken;
 use crate::domain::repositories::{RegistrationTokenRepository, UserRepository};
@@ -26,6 +24,7 @@ pub struct ServerConfig {
     pub insecure_cookies: bool,
     pub openrouter_api_key: String,
     pub openrouter_model: String,
+    pub allow_private_network: bool,
 }
 
 pub async fn serve(config: ServerConfig) -> anyhow::Result<()> {
@@ -42,12 +41,6 @@ pub async fn serve(config: ServerConfig) -> anyhow::Result<()> {
             .context("failed to build WebAuthn instance")?,
     );
 
-    let (stats_tx, stats_rx) = tokio::sync::mpsc::channel::<crate::domain::ids::UserId>(32);
-    let stats_invalidator = StatsInvalidator::new(stats_tx);
-
-    let (timeline_tx, timeline_rx) = tokio::sync::mpsc::channel::<TimelineInvalidation>(32);
-    let timeline_invalidator = TimelineInvalidator::new(timeline_tx);
-
     let state = AppState::from_database(
         &database,
         AppStateConfig {
@@ -56,55 +49,17 @@ pub async fn serve(config: ServerConfig) -> anyhow::Result<()> {
             openrouter_url: crate::infrastructure::ai::OPENROUTER_URL.to_string(),
             openrouter_api_key: config.openrouter_api_key,
             openrouter_model: config.openrouter_model,
-            stats_invalidator: stats_invalidator.clone(),
-            timeline_invalidator,
+            allow_private_network: config.allow_private_network,
         },
     );
 
-    // Spawn background stats recomputation task
-    let stats_repo = Arc::clone(&state.stats_repo);
-    tokio::spawn(stats_recomputation_task(
-        stats_rx,
-        stats_repo,
-        std::time::Duration::from_secs(2),
-    ));
-
-    // Spawn background timeline rebuild task
-    tokio::spawn(timeline_rebuild_task(
-        timeline_rx,
-        Arc::clone(&state.author_repo),
-        Arc::clone(&state.book_repo),
-        Arc::clone(&state.genre_repo),
-        Arc::clone(&state.reading_repo),
-        Arc::clone(&state.timeline_repo),
-        std::time::Duration::from_secs(2),
-    ));
-
-    // Spawn background cover suggestion cleanup task (hourly, removes >24h old)
-    let cover_repo = Arc::clone(&state.cover_suggestion_repo);
-    tokio::spawn(async move {
-        let mut interval = tokio::time::interval(std::time::Duration::from_secs(3600));
-        loop {
-            interval.tick().await;
-            match cover_repo
-                .delete_older_than(std::time::Duration::from_secs(86400))
-                .await
-            {
-                Ok(count) if count > 0 => {
-                    info!(count, "cleaned up expired cover suggestions");
-                }
-                Err(err) => {
-                    tracing::warn!(error = %err, "cover suggestion cleanup failed");
-                }
-                _ => {}
-            }
-        }
-    });
+    // Spawn the background job worker (stats, timeline, images, cleanup)
+    tokio::spawn(JobWorker::new(&state, std::time::Duration::from_secs(1)).run());
 
     // Seed the stats cache on startup for all existing users
     if let Ok(users) = state.user_repo.list_all().await {
         for user in users {
-            stats_invalidator.invalidate(user.id);
+            state.stats_invalidator.invalidate(user.id).await;
         }
     }
 
@@ -125,6 +80,7 @@ pub async fn serve(config: ServerConfig) -> anyhow::Result<()> {
         .await
         .with_context(|| format!("failed to bind to {}", config.bind_address))?;
 
+    let live_updates = state.live_updates.clone();
     let app = app_router(state);
 
     info!(
@@ -137,7 +93,11 @@ pub async fn serve(config: ServerConfig) -> anyhow::Result<()> {
         listener,
         app.into_make_service_with_connect_info::<SocketAddr>(),
     )
-    .with_graceful_shutdown(shutdown_signal())
+    .with_graceful_shutdown(async move {
+        shutdown_signal().await;
+        // Live connections never finish on their own
+        live_updates.close();
+    })
     .await
     .context("server terminated unexpectedly")?;
 
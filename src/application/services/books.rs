@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use tracing::warn;
 
+use crate::application::services::TimelinePublisher;
 use crate::domain::book_items::{Book, NewBook, book_timeline_event};
 use crate::domain::errors::RepositoryError;
 use crate::domain::ids::UserId;
@@ -15,6 +16,7 @@ pub struct BookService {
     authors: Arc<dyn AuthorRepository>,
     genres: Arc<dyn GenreRepository>,
     timeline: Arc<dyn TimelineEventRepository>,
+    events: TimelinePublisher,
 }
 
 impl BookService {
@@ -23,12 +25,14 @@ impl BookService {
         authors: Arc<dyn AuthorRepository>,
         genres: Arc<dyn GenreRepository>,
         timeline: Arc<dyn TimelineEventRepository>,
+        events: TimelinePublisher,
     ) -> Self {
         Self {
             books,
             authors,
             genres,
             timeline,
+            events,
         }
     }
 
@@ -57,7 +61,7 @@ impl BookService {
             None
         };
 
-        if let Err(err) = self
+        match self
             .timeline
             .insert(book_timeline_event(
                 &book,
@@ -68,7 +72,10 @@ impl BookService {
             ))
             .await
         {
-            warn!(error = %err, book_id = %book.id, "failed to record book timeline event");
+            Ok(event) => self.events.publish(event, user_id).await,
+            Err(err) => {
+                warn!(error = %err, book_id = %book.id, "failed to record book timeline event");
+            }
         }
 
         Ok(book)
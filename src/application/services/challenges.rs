@@ -0,0 +1,143 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::domain::challenges::{
+    ChallengeProgress, ChallengeSummary, ChallengeWithPrompts, PromptProgress, parse_rule,
+};
+use crate::domain::errors::RepositoryError;
+use crate::domain::filters::{FilterField, FilterOp, FilterTerm, FilterValue};
+use crate::domain::ids::{ReadingId, UserId};
+use crate::domain::listing::{ListRequest, SortDirection};
+use crate::domain::readings::{ReadingFilter, ReadingSortKey, ReadingStatus, ReadingWithBook};
+use crate::domain::repositories::{ChallengeRepository, ReadingRepository};
+
+/// Suggestions shown per open prompt, most recently finished first.
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Works out a user's progress through challenges: which prompts their
+/// readings fill, and which finished readings could fill the rest.
+#[derive(Clone)]
+pub struct ChallengeService {
+    challenges: Arc<dyn ChallengeRepository>,
+    readings: Arc<dyn ReadingRepository>,
+}
+
+impl ChallengeService {
+    pub fn new(
+        challenges: Arc<dyn ChallengeRepository>,
+        readings: Arc<dyn ReadingRepository>,
+    ) -> Self {
+        Self {
+            challenges,
+            readings,
+        }
+    }
+
+    /// Every challenge the user can see, with how many prompts they have filled.
+    pub async fn summaries(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<ChallengeSummary>, RepositoryError> {
+        let challenges = self.challenges.list_visible(user_id).await?;
+        let mut completed: HashMap<_, usize> = HashMap::new();
+        for entry in self.challenges.entries_for_user(user_id).await? {
+            *completed.entry(entry.challenge_id).or_default() += 1;
+        }
+
+        Ok(challenges
+            .into_iter()
+            .map(|c| ChallengeSummary {
+                completed: completed.get(&c.challenge.id).copied().unwrap_or(0),
+                total: c.prompts.len(),
+                challenge: c.challenge,
+            })
+            .collect())
+    }
+
+    pub async fn progress(
+        &self,
+        challenge: ChallengeWithPrompts,
+        user_id: UserId,
+    ) -> Result<ChallengeProgress, RepositoryError> {
+        let ChallengeWithPrompts { challenge, prompts } = challenge;
+        let entries: HashMap<_, _> = self
+            .challenges
+            .entries_for_user(user_id)
+            .await?
+            .into_iter()
+            .filter(|e| e.challenge_id == challenge.id)
+            .map(|e| (e.prompt_id, e.reading_id))
+            .collect();
+        let assigned: HashSet<_> = entries.values().copied().collect();
+
+        let year_term = challenge.period().map(|(start, end)| FilterTerm {
+            field: FilterField::Finished,
+            op: FilterOp::Matches,
+            value: FilterValue::Period { start, end },
+            negated: false,
+        });
+
+        let mut progress = Vec::with_capacity(prompts.len());
+        for prompt in prompts {
+            let entry = match entries.get(&prompt.id) {
+                Some(reading_id) => Some(self.readings.get_with_book(*reading_id).await?),
+                None => None,
+            };
+
+            let suggestions = match (&entry, prompt.rule.as_deref()) {
+                (None, Some(rule)) => {
+                    self.suggestions(user_id, rule, year_term.as_ref(), &assigned)
+                        .await?
+                }
+                _ => Vec::new(),
+            };
+
+            progress.push(PromptProgress {
+                prompt,
+                entry,
+                suggestions,
+            });
+        }
+
+        Ok(ChallengeProgress {
+            completed: progress.iter().filter(|p| p.entry.is_some()).count(),
+            total: progress.len(),
+            challenge,
+            prompts: progress,
+        })
+    }
+
+    /// Finished readings matching a prompt rule, skipping any already used
+    /// elsewhere in the challenge.
+    async fn suggestions(
+        &self,
+        user_id: UserId,
+        rule: &str,
+        year_term: Option<&FilterTerm>,
+        assigned: &HashSet<ReadingId>,
+    ) -> Result<Vec<ReadingWithBook>, RepositoryError> {
+        // Rules are validated when saved; one that no longer parses just
+        // stops suggesting.
+        let Ok(mut query) = parse_rule(rule) else {
+            return Ok(Vec::new());
+        };
+        query.terms.extend(year_term.cloned());
+
+        let request = ListRequest::show_all(ReadingSortKey::FinishedAt, SortDirection::Desc);
+        let readings = self
+            .readings
+            .list(
+                ReadingFilter::for_user_status(user_id, ReadingStatus::Read),
+                &request,
+                Some(&query),
+            )
+            .await?;
+
+        Ok(readings
+            .items
+            .into_iter()
+            .filter(|r| !assigned.contains(&r.reading.id))
+            .take(MAX_SUGGESTIONS)
+            .collect())
+    }
+}
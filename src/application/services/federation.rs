@@ -0,0 +1,600 @@
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::sync::Arc;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde_json::{Value, json};
+use thiserror::Error;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::application::services::jobs::{BackgroundJob, JobQueue};
+use crate::domain::RepositoryError;
+use crate::domain::federation::{
+    Actor, Follower, FollowerStatus, NewActor, NewFollower, NewOutboxActivity,
+};
+use crate::domain::formatting::format_rating;
+use crate::domain::ids::{FollowerId, ReadingId, UserId};
+use crate::domain::readings::{Reading, ReadingStatus, ReadingWithBook};
+use crate::domain::repositories::{
+    FederationRepository, ImageRepository, ReadingRepository, UserRepository,
+};
+use crate::domain::users::{ProfilePrivacy, User};
+use crate::infrastructure::activitypub::{self, ACTIVITY_JSON};
+use crate::infrastructure::http_signatures::{generate_key_pair, parse_signature_header, verify};
+use crate::infrastructure::outbound::OutboundClient;
+
+const ACTIVITY_STREAMS: &str = "https://www.w3.org/ns/activitystreams";
+const SECURITY: &str = "https://w3id.org/security/v1";
+const PUBLIC: &str = "https://www.w3.org/ns/activitystreams#Public";
+/// Activities listed in the outbox; older ones have already federated.
+const OUTBOX_LIMIT: i64 = 20;
+
+pub fn actor_uri(origin: &str, username: &str) -> String {
+    format!("{origin}/users/{username}")
+}
+
+/// The host in the user's `@name@host` handle: the origin's host and port.
+pub fn handle_host(origin: &str) -> Option<String> {
+    let origin = url::Url::parse(origin).ok()?;
+    let host = origin.host_str()?;
+    Some(match origin.port() {
+        Some(port) => format!("{host}:{port}"),
+        None => host.to_string(),
+    })
+}
+
+pub fn key_id(origin: &str, username: &str) -> String {
+    format!("{}#main-key", actor_uri(origin, username))
+}
+
+/// Why an inbox POST was refused.
+#[derive(Debug, Error)]
+pub enum InboxError {
+    #[error("not found")]
+    NotFound,
+    #[error("signature rejected: {0}")]
+    Unauthorized(String),
+    #[error("invalid activity: {0}")]
+    Invalid(String),
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
+
+/// A signed request to a user's inbox, as received.
+pub struct InboxRequest<'a> {
+    pub path_and_query: &'a str,
+    pub body: &'a [u8],
+    /// Looks up request headers by lowercase name.
+    pub header: &'a (dyn Fn(&str) -> Option<&'a str> + Sync),
+}
+
+/// Publishes finished readings to the fediverse for users who opt in and
+/// answers follow requests from remote servers. All outgoing requests go
+/// through the job queue, so unreachable servers are retried.
+#[derive(Clone)]
+pub struct FederationService {
+    federation_repo: Arc<dyn FederationRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    reading_repo: Arc<dyn ReadingRepository>,
+    image_repo: Arc<dyn ImageRepository>,
+    job_queue: JobQueue,
+    outbound: OutboundClient,
+}
+
+impl FederationService {
+    pub fn new(
+        federation_repo: Arc<dyn FederationRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        reading_repo: Arc<dyn ReadingRepository>,
+        image_repo: Arc<dyn ImageRepository>,
+        job_queue: JobQueue,
+        outbound: OutboundClient,
+    ) -> Self {
+        Self {
+            federation_repo,
+            user_repo,
+            reading_repo,
+            image_repo,
+            job_queue,
+            outbound,
+        }
+    }
+
+    /// The federated user called `username`, or `NotFound` if there's no
+    /// such user or they haven't opted in.
+    pub async fn find_actor(&self, username: &str) -> Result<(User, Actor), RepositoryError> {
+        let user = self.user_repo.get_by_username(username).await?;
+        let actor = self.federation_repo.get_actor(user.id).await?;
+        Ok((user, actor))
+    }
+
+    /// Opt in to federation, minting the user's actor under `origin`. A
+    /// no-op apart from the approval setting when they already federate.
+    pub async fn enable(
+        &self,
+        user_id: UserId,
+        origin: &str,
+        manually_approves_followers: bool,
+    ) -> Result<Actor, RepositoryError> {
+        match self.federation_repo.get_actor(user_id).await {
+            Ok(mut actor) => {
+                if actor.manually_approves_followers != manually_approves_followers {
+                    self.federation_repo
+                        .set_manually_approves_followers(user_id, manually_approves_followers)
+                        .await?;
+                    actor.manually_approves_followers = manually_approves_followers;
+                }
+                return Ok(actor);
+            }
+            Err(RepositoryError::NotFound) => {}
+            Err(err) => return Err(err),
+        }
+
+        // RSA key generation takes long enough to stall the runtime
+        let keys = tokio::task::spawn_blocking(generate_key_pair)
+            .await
+            .map_err(|err| RepositoryError::unexpected(format!("key generation panicked: {err}")))?
+            .map_err(|err| RepositoryError::unexpected(format!("key generation failed: {err}")))?;
+
+        let actor = self
+            .federation_repo
+            .insert_actor(NewActor {
+                user_id,
+                origin: origin.to_string(),
+                public_key_pem: keys.public_key_pem,
+                private_key_pem: keys.private_key_pem,
+                manually_approves_followers,
+            })
+            .await?;
+        info!(%user_id, origin, "federation enabled");
+        Ok(actor)
+    }
+
+    /// Opt out. The actor, its followers and its outbox are removed.
+    pub async fn disable(&self, user_id: UserId) -> Result<(), RepositoryError> {
+        match self.federation_repo.delete_actor(user_id).await {
+            Ok(()) => {
+                info!(%user_id, "federation disabled");
+                Ok(())
+            }
+            Err(RepositoryError::NotFound) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn actor_document(&self, user: &User, actor: &Actor) -> Value {
+        let id = actor_uri(&actor.origin, &user.username);
+        json!({
+            "@context": [ACTIVITY_STREAMS, SECURITY],
+            "id": id,
+            "type": "Person",
+            "preferredUsername": user.username,
+            "name": user.username,
+            "url": id,
+            "inbox": format!("{id}/inbox"),
+            "outbox": format!("{id}/outbox"),
+            "followers": format!("{id}/followers"),
+            "manuallyApprovesFollowers": actor.manually_approves_followers,
+            "publicKey": {
+                "id": key_id(&actor.origin, &user.username),
+                "owner": id,
+                "publicKeyPem": actor.public_key_pem,
+            },
+        })
+    }
+
+    /// The JRD answering `acct:username@host` lookups.
+    pub fn webfinger_document(&self, user: &User, actor: &Actor, host: &str) -> Value {
+        let id = actor_uri(&actor.origin, &user.username);
+        json!({
+            "subject": format!("acct:{}@{host}", user.username),
+            "aliases": [id],
+            "links": [{
+                "rel": "self",
+                "type": ACTIVITY_JSON,
+                "href": id,
+            }],
+        })
+    }
+
+    pub async fn outbox_document(
+        &self,
+        user: &User,
+        actor: &Actor,
+    ) -> Result<Value, RepositoryError> {
+        let id = format!("{}/outbox", actor_uri(&actor.origin, &user.username));
+        let total = self.federation_repo.count_activities(user.id).await?;
+        let items: Vec<Value> = self
+            .federation_repo
+            .list_activities(user.id, OUTBOX_LIMIT)
+            .await?
+            .into_iter()
+            .filter_map(|activity| serde_json::from_str(&activity.activity).ok())
+            .collect();
+        Ok(json!({
+            "@context": ACTIVITY_STREAMS,
+            "id": id,
+            "type": "OrderedCollection",
+            "totalItems": total,
+            "orderedItems": items,
+        }))
+    }
+
+    /// Only the count is shown; who follows a user isn't published.
+    pub async fn followers_document(
+        &self,
+        user: &User,
+        actor: &Actor,
+    ) -> Result<Value, RepositoryError> {
+        let id = format!("{}/followers", actor_uri(&actor.origin, &user.username));
+        let total = self
+            .federation_repo
+            .list_followers(user.id)
+            .await?
+            .iter()
+            .filter(|follower| follower.is_accepted())
+            .count();
+        Ok(json!({
+            "@context": ACTIVITY_STREAMS,
+            "id": id,
+            "type": "OrderedCollection",
+            "totalItems": total,
+        }))
+    }
+
+    pub async fn list_followers(&self, user_id: UserId) -> Result<Vec<Follower>, RepositoryError> {
+        self.federation_repo.list_followers(user_id).await
+    }
+
+    /// Verify and act on an activity posted to `username`'s inbox. Follows
+    /// are recorded (and accepted unless the user approves them by hand)
+    /// and undone follows removed; other activities are ignored.
+    pub async fn handle_inbox(
+        &self,
+        username: &str,
+        request: InboxRequest<'_>,
+    ) -> Result<(), InboxError> {
+        let (user, actor) = self.find_actor(username).await.map_err(|err| match err {
+            RepositoryError::NotFound => InboxError::NotFound,
+            err => InboxError::Repository(err),
+        })?;
+
+        let signature = (request.header)("signature")
+            .ok_or_else(|| InboxError::Unauthorized("request is not signed".to_string()))
+            .and_then(|value| {
+                parse_signature_header(value)
+                    .map_err(|err| InboxError::Unauthorized(err.to_string()))
+            })?;
+        let sender = activitypub::fetch_actor(&self.outbound, &signature.key_id)
+            .await
+            .map_err(|err| InboxError::Unauthorized(format!("{err:#}")))?;
+        verify(
+            &signature,
+            &sender.public_key_pem,
+            "POST",
+            request.path_and_query,
+            request.body,
+            request.header,
+        )
+        .map_err(|err| InboxError::Unauthorized(err.to_string()))?;
+
+        let activity: Value = serde_json::from_slice(request.body)
+            .map_err(|err| InboxError::Invalid(err.to_string()))?;
+        // A server may only speak for its own actors
+        if activity["actor"].as_str() != Some(sender.id.as_str()) {
+            return Err(InboxError::Unauthorized(
+                "activity actor does not match the signing key".to_string(),
+            ));
+        }
+
+        let our_uri = actor_uri(&actor.origin, &user.username);
+        match activity["type"].as_str() {
+            Some("Follow") => {
+                if object_id(&activity["object"]) != Some(our_uri.as_str()) {
+                    return Err(InboxError::Invalid(
+                        "follow is for another actor".to_string(),
+                    ));
+                }
+                let status = if actor.manually_approves_followers {
+                    FollowerStatus::Pending
+                } else {
+                    FollowerStatus::Accepted
+                };
+                let follower = self
+                    .federation_repo
+                    .upsert_follower(NewFollower {
+                        user_id: user.id,
+                        actor_uri: sender.id,
+                        inbox: sender.inbox,
+                        shared_inbox: sender.shared_inbox,
+                        follow_activity: activity.to_string(),
+                        status,
+                    })
+                    .await?;
+                info!(user_id = %user.id, follower = %follower.actor_uri, status = status.as_str(), "follow received");
+                if follower.is_accepted() {
+                    self.answer_follow(&user, &actor, &follower, "Accept").await;
+                }
+            }
+            Some("Undo") if activity["object"]["type"].as_str() == Some("Follow") => {
+                match self
+                    .federation_repo
+                    .delete_follower_by_actor(user.id, &sender.id)
+                    .await
+                {
+                    Ok(()) => info!(user_id = %user.id, follower = %sender.id, "follow undone"),
+                    Err(RepositoryError::NotFound) => {}
+                    Err(err) => return Err(err.into()),
+                }
+            }
+            other => {
+                info!(user_id = %user.id, kind = ?other, "ignoring inbox activity");
+            }
+        }
+        Ok(())
+    }
+
+    /// Accept a follow the user was holding for approval.
+    pub async fn approve_follower(
+        &self,
+        user: &User,
+        follower_id: FollowerId,
+    ) -> Result<(), RepositoryError> {
+        let (actor, follower) = self.own_follower(user, follower_id).await?;
+        self.federation_repo
+            .set_follower_status(follower.id, FollowerStatus::Accepted)
+            .await?;
+        self.answer_follow(user, &actor, &follower, "Accept").await;
+        Ok(())
+    }
+
+    /// Turn down a pending follow, or remove an existing follower.
+    pub async fn reject_follower(
+        &self,
+        user: &User,
+        follower_id: FollowerId,
+    ) -> Result<(), RepositoryError> {
+        let (actor, follower) = self.own_follower(user, follower_id).await?;
+        self.federation_repo.delete_follower(follower.id).await?;
+        self.answer_follow(user, &actor, &follower, "Reject").await;
+        Ok(())
+    }
+
+    async fn own_follower(
+        &self,
+        user: &User,
+        follower_id: FollowerId,
+    ) -> Result<(Actor, Follower), RepositoryError> {
+        let actor = self.federation_repo.get_actor(user.id).await?;
+        let follower = self.federation_repo.get_follower(follower_id).await?;
+        if follower.user_id != user.id {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok((actor, follower))
+    }
+
+    /// Queue an `Accept` or `Reject` of the follower's original `Follow`.
+    async fn answer_follow(&self, user: &User, actor: &Actor, follower: &Follower, kind: &str) {
+        let id = actor_uri(&actor.origin, &user.username);
+        let follow: Value = serde_json::from_str(&follower.follow_activity).unwrap_or(Value::Null);
+        let answer = json!({
+            "@context": ACTIVITY_STREAMS,
+            "id": format!("{id}#{}/{}", kind.to_lowercase(), Uuid::new_v4()),
+            "type": kind,
+            "actor": id,
+            "object": follow,
+        });
+        self.job_queue
+            .enqueue(BackgroundJob::DeliverActivity {
+                user_id: user.id,
+                inbox: follower.inbox.clone(),
+                activity: answer.to_string(),
+            })
+            .await;
+    }
+
+    /// Queue publishing a reading that has just been finished. `before` is
+    /// the reading prior to an update, so re-saving a finished reading
+    /// doesn't publish it again.
+    pub async fn queue_finished_reading(&self, before: Option<&Reading>, after: &Reading) {
+        let newly_finished = after.status == ReadingStatus::Read
+            && before.is_none_or(|before| before.status != ReadingStatus::Read);
+        if !newly_finished {
+            return;
+        }
+        self.job_queue
+            .enqueue(BackgroundJob::FederateReading {
+                reading_id: after.id,
+            })
+            .await;
+    }
+
+    /// Publish a finished reading as a `Create` note and queue its delivery
+    /// to every accepted follower. Does nothing if the reader doesn't
+    /// federate or the reading was already published.
+    pub async fn publish_reading(&self, reading_id: ReadingId) -> Result<(), RepositoryError> {
+        let reading = match self.reading_repo.get_with_book(reading_id).await {
+            Ok(reading) => reading,
+            Err(RepositoryError::NotFound) => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        if reading.reading.status != ReadingStatus::Read || reading.reading.private {
+            return Ok(());
+        }
+        let user_id = reading.reading.user_id;
+        let actor = match self.federation_repo.get_actor(user_id).await {
+            Ok(actor) => actor,
+            Err(RepositoryError::NotFound) => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        let user = self.user_repo.get(user_id).await?;
+        let privacy = self.user_repo.get_profile_privacy(user_id).await?;
+
+        let cover = self
+            .image_repo
+            .get_thumbnail("book", reading.reading.book_id.into_inner())
+            .await
+            .ok()
+            .map(|image| image.content_type);
+        let activity = create_note(
+            &actor,
+            &user,
+            privacy,
+            &reading,
+            cover.as_deref(),
+            Utc::now(),
+        );
+
+        let Some(stored) = self
+            .federation_repo
+            .insert_activity(NewOutboxActivity {
+                user_id,
+                reading_id,
+                activity: activity.to_string(),
+            })
+            .await?
+        else {
+            return Ok(());
+        };
+        info!(%user_id, %reading_id, "reading published");
+
+        // Followers on the same server share one delivery
+        let inboxes: BTreeSet<String> = self
+            .federation_repo
+            .list_followers(user_id)
+            .await?
+            .iter()
+            .filter(|follower| follower.is_accepted())
+            .map(|follower| follower.delivery_inbox().to_string())
+            .collect();
+        for inbox in inboxes {
+            self.job_queue
+                .enqueue(BackgroundJob::DeliverActivity {
+                    user_id,
+                    inbox,
+                    activity: stored.activity.clone(),
+                })
+                .await;
+        }
+        Ok(())
+    }
+
+    /// Sign and POST an activity to a remote inbox. Errors are returned so
+    /// the job is retried; deliveries for users who stopped federating are
+    /// dropped.
+    pub async fn deliver(
+        &self,
+        user_id: UserId,
+        inbox: &str,
+        activity: &str,
+    ) -> Result<(), String> {
+        let actor = match self.federation_repo.get_actor(user_id).await {
+            Ok(actor) => actor,
+            Err(RepositoryError::NotFound) => {
+                warn!(%user_id, inbox, "dropping delivery for user who no longer federates");
+                return Ok(());
+            }
+            Err(err) => return Err(err.to_string()),
+        };
+        let user = self
+            .user_repo
+            .get(user_id)
+            .await
+            .map_err(|err| err.to_string())?;
+        activitypub::deliver(
+            &self.outbound,
+            inbox,
+            activity,
+            &key_id(&actor.origin, &user.username),
+            &actor.private_key_pem,
+        )
+        .await
+        .map_err(|err| format!("{err:#}"))
+    }
+}
+
+/// The `id` of an activity's object, whether it's embedded or a bare URI.
+fn object_id(object: &Value) -> Option<&str> {
+    object.as_str().or_else(|| object["id"].as_str())
+}
+
+/// The `Create` activity for a finished reading. The rating is left out
+/// when the reader hides ratings on their profile.
+fn create_note(
+    actor: &Actor,
+    user: &User,
+    privacy: ProfilePrivacy,
+    reading: &ReadingWithBook,
+    cover_content_type: Option<&str>,
+    published: DateTime<Utc>,
+) -> Value {
+    let id = actor_uri(&actor.origin, &user.username);
+    let note_id = format!("{id}/readings/{}", reading.reading.id);
+    let published = published.to_rfc3339_opts(SecondsFormat::Secs, true);
+
+    let book_url = format!("{}/books/{}", actor.origin, reading.reading.book_id);
+    let mut content = format!(
+        r#"<p>Finished reading <a href="{}">{}</a>"#,
+        escape_html(&book_url),
+        escape_html(&reading.book_title)
+    );
+    if !reading.author_names.is_empty() {
+        content.push_str(" by ");
+        content.push_str(&escape_html(&reading.author_names));
+    }
+    content.push('.');
+    if let Some(rating) = reading.reading.rating.filter(|_| privacy.show_ratings) {
+        let _ = write!(content, " Rated {}.", format_rating(rating));
+    }
+    content.push_str("</p>");
+
+    let attachment: Vec<Value> = cover_content_type
+        .map(|media_type| {
+            json!({
+                "type": "Document",
+                "mediaType": media_type,
+                "url": format!("{}/api/v1/book/{}/image", actor.origin, reading.reading.book_id),
+                "name": format!("Cover of {}", reading.book_title),
+            })
+        })
+        .into_iter()
+        .collect();
+
+    let to = [PUBLIC];
+    let cc = [format!("{id}/followers")];
+    json!({
+        "@context": ACTIVITY_STREAMS,
+        "id": format!("{note_id}/activity"),
+        "type": "Create",
+        "actor": id,
+        "published": published,
+        "to": to,
+        "cc": cc,
+        "object": {
+            "id": note_id,
+            "type": "Note",
+            "attributedTo": id,
+            "published": published,
+            "to": to,
+            "cc": cc,
+            "content": content,
+            "attachment": attachment,
+        },
+    })
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use chrono::{NaiveDate, Utc};
+
+use crate::application::services::TimelinePublisher;
+use crate::domain::errors::RepositoryError;
+use crate::domain::goals::{GoalProgress, ReadingGoal, goal_milestone_event, recorded_milestone};
+use crate::domain::ids::UserId;
+use crate::domain::listing::{ListRequest, SortKey};
+use crate::domain::repositories::{GoalRepository, TimelineEventRepository};
+use crate::domain::timeline::{TimelineFilter, TimelineSortKey};
+
+/// Measures reading goals against the readings finished so far.
+#[derive(Clone)]
+pub struct GoalService {
+    goal_repo: Arc<dyn GoalRepository>,
+    timeline_repo: Arc<dyn TimelineEventRepository>,
+    events: TimelinePublisher,
+}
+
+impl GoalService {
+    pub fn new(
+        goal_repo: Arc<dyn GoalRepository>,
+        timeline_repo: Arc<dyn TimelineEventRepository>,
+        events: TimelinePublisher,
+    ) -> Self {
+        Self {
+            goal_repo,
+            timeline_repo,
+            events,
+        }
+    }
+
+    pub async fn progress(
+        &self,
+        goal: ReadingGoal,
+        today: NaiveDate,
+    ) -> Result<GoalProgress, RepositoryError> {
+        let current = self.goal_repo.progress(&goal).await?;
+        Ok(GoalProgress::new(goal, current, today))
+    }
+
+    /// Progress for all of a user's goals, most recent period first.
+    pub async fn progress_for_user(
+        &self,
+        user_id: UserId,
+        today: NaiveDate,
+    ) -> Result<Vec<GoalProgress>, RepositoryError> {
+        let goals = self.goal_repo.list_by_user(user_id).await?;
+        let mut progress = Vec::with_capacity(goals.len());
+        for goal in goals {
+            progress.push(self.progress(goal, today).await?);
+        }
+        Ok(progress)
+    }
+
+    /// Records a timeline event for each of the user's goals that has
+    /// passed a milestone since the last one recorded for it.
+    pub async fn record_milestones(
+        &self,
+        user_id: UserId,
+        today: NaiveDate,
+    ) -> Result<(), RepositoryError> {
+        let filter = TimelineFilter {
+            entity_type: Some("goal".to_string()),
+            action: Some("milestone".to_string()),
+            ..TimelineFilter::for_user(user_id)
+        };
+        let sort_key = <TimelineSortKey as SortKey>::default();
+        let request = ListRequest::show_all(sort_key, sort_key.default_direction());
+        let recorded = self.timeline_repo.list(&filter, &request).await?.items;
+
+        for progress in self.progress_for_user(user_id, today).await? {
+            let goal_id = progress.goal.id.into_inner();
+            let previous = recorded
+                .iter()
+                .filter(|e| e.entity_id == goal_id)
+                .filter_map(recorded_milestone)
+                .max();
+            if let Some(event) = goal_milestone_event(&progress, previous, Utc::now()) {
+                let event = self.timeline_repo.insert(event).await?;
+                self.events.publish(event, user_id).await;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,510 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::task::JoinSet;
+use tracing::{error, info, warn};
+
+use crate::application::services::federation::FederationService;
+use crate::application::services::live::{LiveUpdate, LiveUpdates};
+use crate::application::services::stats::{
+    StatsMetrics, rebuild_household_stats, rebuild_stats, reconcile_stats, refresh_stats_years,
+};
+use crate::application::services::timeline_refresh::TimelineRebuilder;
+use crate::application::services::webhooks::WebhookService;
+use crate::application::state::AppState;
+use crate::domain::RepositoryError;
+use crate::domain::ids::{ImageUploadId, ReadingId, TimelineEventId, UserId, WebhookDeliveryId};
+use crate::domain::images::EntityImage;
+use crate::domain::jobs::{Job, NewJob, retry_delay};
+use crate::domain::repositories::{
+    CoverSuggestionRepository, ImageRepository, JobRepository, StatsRepository,
+};
+use crate::infrastructure::image_processing::process_data_url;
+
+/// How often expired cover suggestions are removed.
+const COVER_CLEANUP_EVERY: Duration = Duration::from_hours(1);
+/// Age after which an unused cover suggestion is removed.
+const COVER_SUGGESTION_MAX_AGE: Duration = Duration::from_hours(24);
+/// How often every cached user's stats are rebuilt to catch drift.
+const STATS_RECONCILIATION_EVERY: Duration = Duration::from_hours(6);
+/// Jobs run at once, so slow network deliveries don't hold up local work.
+const MAX_RUNNING_JOBS: usize = 16;
+/// Recent failures shown on the admin page.
+pub const RECENT_FAILURE_LIMIT: i64 = 10;
+
+/// Work done by the background worker. Stored as JSON in the job payload.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BackgroundJob {
+    /// Refresh a user's cached stats. `years: None` rebuilds every partial.
+    RefreshStats {
+        user_id: UserId,
+        years: Option<BTreeSet<Option<i32>>>,
+    },
+    RefreshHouseholdStats,
+    /// Rebuild every cached user's stats and record the drift found.
+    ReconcileStats,
+    /// Refresh an entity's timeline snapshots and those of its dependents.
+    RefreshTimeline {
+        entity_type: String,
+        entity_id: i64,
+    },
+    RebuildTimeline,
+    CleanupCoverSuggestions,
+    /// Process an image uploaded with a create or edit form, held in the
+    /// image store until then.
+    ProcessImage {
+        entity_type: String,
+        entity_id: i64,
+        upload_id: ImageUploadId,
+    },
+    /// Publish a finished reading to the reader's fediverse followers.
+    FederateReading {
+        reading_id: ReadingId,
+    },
+    /// Sign and POST an `ActivityPub` activity to a remote inbox.
+    DeliverActivity {
+        user_id: UserId,
+        inbox: String,
+        activity: String,
+    },
+    /// Queue deliveries of a new timeline event to subscribed webhooks.
+    DispatchWebhooks {
+        event_id: TimelineEventId,
+    },
+    /// POST a recorded webhook delivery to its endpoint.
+    DeliverWebhook {
+        delivery_id: WebhookDeliveryId,
+    },
+}
+
+impl BackgroundJob {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::RefreshStats { .. } => "refresh_stats",
+            Self::RefreshHouseholdStats => "refresh_household_stats",
+            Self::ReconcileStats => "reconcile_stats",
+            Self::RefreshTimeline { .. } => "refresh_timeline",
+            Self::RebuildTimeline => "rebuild_timeline",
+            Self::CleanupCoverSuggestions => "cleanup_cover_suggestions",
+            Self::ProcessImage { .. } => "process_image",
+            Self::FederateReading { .. } => "federate_reading",
+            Self::DeliverActivity { .. } => "deliver_activity",
+            Self::DispatchWebhooks { .. } => "dispatch_webhooks",
+            Self::DeliverWebhook { .. } => "deliver_webhook",
+        }
+    }
+
+    /// Identical pending work collapses into one job. Images and deliveries
+    /// aren't deduped since a later job for the same target carries
+    /// different data.
+    pub fn dedupe_key(&self) -> Option<String> {
+        match self {
+            Self::RefreshStats { user_id, years } => {
+                let years = years.as_ref().map_or_else(
+                    || "all".to_string(),
+                    |years| {
+                        years
+                            .iter()
+                            .map(|year| year.map_or_else(|| "none".to_string(), |y| y.to_string()))
+                            .collect::<Vec<_>>()
+                            .join(",")
+                    },
+                );
+                Some(format!("stats:{user_id}:{years}"))
+            }
+            Self::RefreshTimeline {
+                entity_type,
+                entity_id,
+            } => Some(format!("timeline:{entity_type}:{entity_id}")),
+            Self::RebuildTimeline => Some("timeline:all".to_string()),
+            Self::FederateReading { reading_id } => Some(format!("federate:{reading_id}")),
+            Self::DispatchWebhooks { event_id } => Some(format!("webhooks:{event_id}")),
+            Self::ProcessImage { .. }
+            | Self::DeliverActivity { .. }
+            | Self::DeliverWebhook { .. } => None,
+            Self::RefreshHouseholdStats | Self::ReconcileStats | Self::CleanupCoverSuggestions => {
+                Some(self.kind().to_string())
+            }
+        }
+    }
+
+    /// Jobs that queue their next run once they finish.
+    fn recurs_every(&self) -> Option<Duration> {
+        match self {
+            Self::CleanupCoverSuggestions => Some(COVER_CLEANUP_EVERY),
+            Self::ReconcileStats => Some(STATS_RECONCILIATION_EVERY),
+            _ => None,
+        }
+    }
+
+    fn to_new_job(&self, run_at: DateTime<Utc>) -> Result<NewJob, RepositoryError> {
+        let payload = serde_json::to_string(self)
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+        Ok(NewJob::new(self.kind(), payload, self.dedupe_key(), run_at))
+    }
+}
+
+/// Queues background jobs. Enqueue failures are logged rather than returned,
+/// so handlers can treat a queued job like any other side effect.
+#[derive(Clone)]
+pub struct JobQueue {
+    jobs: Arc<dyn JobRepository>,
+}
+
+impl JobQueue {
+    pub fn new(jobs: Arc<dyn JobRepository>) -> Self {
+        Self { jobs }
+    }
+
+    /// Queue a job to run as soon as the worker picks it up.
+    pub async fn enqueue(&self, job: BackgroundJob) {
+        self.enqueue_at(job, Utc::now()).await;
+    }
+
+    pub async fn enqueue_at(&self, job: BackgroundJob, run_at: DateTime<Utc>) {
+        let result = match job.to_new_job(run_at) {
+            Ok(new_job) => self.jobs.enqueue(new_job).await,
+            Err(err) => Err(err),
+        };
+        if let Err(err) = result {
+            error!(error = %err, kind = job.kind(), "failed to enqueue background job");
+        }
+    }
+
+    pub fn repository(&self) -> Arc<dyn JobRepository> {
+        Arc::clone(&self.jobs)
+    }
+}
+
+/// Runs queued jobs: claims due jobs, retries failures with exponential
+/// backoff and marks jobs failed once they run out of attempts.
+pub struct JobWorker {
+    queue: JobQueue,
+    stats_repo: Arc<dyn StatsRepository>,
+    stats_metrics: Arc<StatsMetrics>,
+    timeline: TimelineRebuilder,
+    image_repo: Arc<dyn ImageRepository>,
+    cover_suggestion_repo: Arc<dyn CoverSuggestionRepository>,
+    federation: FederationService,
+    webhooks: WebhookService,
+    live: LiveUpdates,
+    poll_interval: Duration,
+}
+
+impl JobWorker {
+    pub fn new(state: &AppState, poll_interval: Duration) -> Self {
+        Self {
+            queue: state.job_queue.clone(),
+            stats_repo: Arc::clone(&state.stats_repo),
+            stats_metrics: state.stats_invalidator.metrics(),
+            timeline: TimelineRebuilder::new(
+                Arc::clone(&state.author_repo),
+                Arc::clone(&state.book_repo),
+                Arc::clone(&state.genre_repo),
+                Arc::clone(&state.reading_repo),
+                Arc::clone(&state.timeline_repo),
+            ),
+            image_repo: Arc::clone(&state.image_repo),
+            cover_suggestion_repo: Arc::clone(&state.cover_suggestion_repo),
+            federation: state.federation.clone(),
+            webhooks: state.webhooks.clone(),
+            live: state.live_updates.clone(),
+            poll_interval,
+        }
+    }
+
+    /// Long-lived worker loop — spawn with `tokio::spawn`. Jobs left running
+    /// by a previous process are returned to the queue first. Up to
+    /// [`MAX_RUNNING_JOBS`] jobs run concurrently, and a free slot is filled
+    /// as soon as a job finishes.
+    pub async fn run(self) {
+        match self.queue.jobs.requeue_running().await {
+            Ok(count) if count > 0 => info!(count, "requeued interrupted background jobs"),
+            Ok(_) => {}
+            Err(err) => warn!(error = %err, "failed to requeue interrupted background jobs"),
+        }
+        self.schedule_recurring().await;
+
+        let poll_interval = self.poll_interval;
+        let worker = Arc::new(self);
+        let mut running = JoinSet::new();
+        loop {
+            let free = MAX_RUNNING_JOBS - running.len();
+            let claimed = if free > 0 {
+                worker.claim_due(free).await
+            } else {
+                Vec::new()
+            };
+            for job in claimed {
+                let worker = Arc::clone(&worker);
+                running.spawn(async move { worker.run_job(&job).await });
+            }
+
+            // Claim again once a job finishes or the next poll is due
+            tokio::select! {
+                Some(result) = running.join_next(), if !running.is_empty() => {
+                    if let Err(err) = result {
+                        error!(error = %err, "background job task panicked");
+                    }
+                }
+                () = tokio::time::sleep(poll_interval) => {}
+            }
+        }
+    }
+
+    /// Queue the first run of each recurring job. A no-op for jobs already
+    /// queued, so restarts keep the existing schedule.
+    async fn schedule_recurring(&self) {
+        let now = Utc::now();
+        self.queue
+            .enqueue_at(BackgroundJob::CleanupCoverSuggestions, now)
+            .await;
+        self.queue
+            .enqueue_at(
+                BackgroundJob::ReconcileStats,
+                now + STATS_RECONCILIATION_EVERY,
+            )
+            .await;
+    }
+
+    /// Claim up to `limit` due jobs.
+    async fn claim_due(&self, limit: usize) -> Vec<Job> {
+        let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+        match self.queue.jobs.claim_due(Utc::now(), limit).await {
+            Ok(jobs) => jobs,
+            Err(err) => {
+                error!(error = %err, "failed to claim background jobs");
+                Vec::new()
+            }
+        }
+    }
+
+    async fn run_job(&self, job: &Job) {
+        let task = match serde_json::from_str::<BackgroundJob>(&job.payload) {
+            Ok(task) => task,
+            Err(err) => {
+                self.record_failure(job, &format!("invalid payload: {err}"))
+                    .await;
+                return;
+            }
+        };
+
+        match self.execute(&task).await {
+            Ok(()) => {
+                if let Err(err) = self.queue.jobs.complete(job.id).await {
+                    error!(error = %err, job_id = %job.id, "failed to complete background job");
+                }
+            }
+            Err(message) if job.can_retry() => {
+                let run_at = Utc::now() + retry_delay(job.attempts);
+                warn!(
+                    job_id = %job.id,
+                    kind = %job.kind,
+                    attempts = job.attempts,
+                    error = %message,
+                    %run_at,
+                    "background job failed, will retry"
+                );
+                if let Err(err) = self.queue.jobs.retry(job, run_at, &message).await {
+                    error!(error = %err, job_id = %job.id, "failed to reschedule background job");
+                }
+                return;
+            }
+            Err(message) => {
+                self.record_failure(job, &message).await;
+                self.discard(&task).await;
+            }
+        }
+
+        if let Some(every) = task.recurs_every() {
+            self.queue.enqueue_at(task, Utc::now() + every).await;
+        }
+    }
+
+    async fn record_failure(&self, job: &Job, message: &str) {
+        error!(
+            job_id = %job.id,
+            kind = %job.kind,
+            attempts = job.attempts,
+            error = %message,
+            "background job failed"
+        );
+        if let Err(err) = self.queue.jobs.fail(job.id, message).await {
+            error!(error = %err, job_id = %job.id, "failed to mark background job failed");
+        }
+    }
+
+    /// Drop what a job that won't run again was holding on to.
+    async fn discard(&self, task: &BackgroundJob) {
+        if let BackgroundJob::ProcessImage { upload_id, .. } = task
+            && let Err(err) = self.image_repo.delete_upload(*upload_id).await
+        {
+            warn!(error = %err, %upload_id, "failed to discard image upload");
+        }
+    }
+
+    async fn execute(&self, task: &BackgroundJob) -> Result<(), String> {
+        match task {
+            BackgroundJob::RefreshStats { user_id, years } => {
+                let stats_repo = &*self.stats_repo;
+                match years {
+                    Some(years) => {
+                        refresh_stats_years(stats_repo, *user_id, years, &self.stats_metrics).await
+                    }
+                    None => rebuild_stats(stats_repo, *user_id, &self.stats_metrics)
+                        .await
+                        .map(|_| ()),
+                }
+                .map_err(|err| err.to_string())?;
+                self.live.publish(LiveUpdate::StatsRefreshed(*user_id));
+                self.queue
+                    .enqueue(BackgroundJob::RefreshHouseholdStats)
+                    .await;
+                Ok(())
+            }
+            BackgroundJob::RefreshHouseholdStats => rebuild_household_stats(&*self.stats_repo)
+                .await
+                .map(|_| ())
+                .map_err(|err| err.to_string()),
+            BackgroundJob::ReconcileStats => {
+                reconcile_stats(&*self.stats_repo, &self.stats_metrics)
+                    .await
+                    .map_err(|err| err.to_string())
+            }
+            BackgroundJob::RefreshTimeline {
+                entity_type,
+                entity_id,
+            } => {
+                self.timeline
+                    .refresh_entity(entity_type, *entity_id)
+                    .await
+                    .map_err(|err| err.to_string())?;
+                self.live.publish(LiveUpdate::TimelineRefreshed);
+                Ok(())
+            }
+            BackgroundJob::RebuildTimeline => {
+                self.timeline
+                    .full_rebuild()
+                    .await
+                    .map_err(|err| err.to_string())?;
+                self.live.publish(LiveUpdate::TimelineRefreshed);
+                Ok(())
+            }
+            BackgroundJob::CleanupCoverSuggestions => {
+                let count = self
+                    .cover_suggestion_repo
+                    .delete_older_than(COVER_SUGGESTION_MAX_AGE)
+                    .await
+                    .map_err(|err| err.to_string())?;
+                if count > 0 {
+                    info!(count, "cleaned up expired cover suggestions");
+                }
+                Ok(())
+            }
+            BackgroundJob::ProcessImage {
+                entity_type,
+                entity_id,
+                upload_id,
+            } => {
+                self.process_image(entity_type, *entity_id, *upload_id)
+                    .await
+            }
+            BackgroundJob::FederateReading { reading_id } => self
+                .federation
+                .publish_reading(*reading_id)
+                .await
+                .map_err(|err| err.to_string()),
+            BackgroundJob::DeliverActivity {
+                user_id,
+                inbox,
+                activity,
+            } => self.federation.deliver(*user_id, inbox, activity).await,
+            BackgroundJob::DispatchWebhooks { event_id } => self
+                .webhooks
+                .dispatch(*event_id)
+                .await
+                .map_err(|err| err.to_string()),
+            BackgroundJob::DeliverWebhook { delivery_id } => {
+                self.webhooks.deliver(*delivery_id).await
+            }
+        }
+    }
+
+    async fn process_image(
+        &self,
+        entity_type: &str,
+        entity_id: i64,
+        upload_id: ImageUploadId,
+    ) -> Result<(), String> {
+        let data_url = self
+            .image_repo
+            .get_upload(upload_id)
+            .await
+            .map_err(|err| format!("failed to load upload {upload_id}: {err}"))?;
+        let processed = tokio::task::spawn_blocking(move || process_data_url(&data_url))
+            .await
+            .map_err(|err| format!("image task panicked: {err}"))?
+            .map_err(|err| err.to_string())?;
+
+        self.image_repo
+            .upsert(EntityImage {
+                entity_type: entity_type.to_string(),
+                entity_id,
+                content_type: processed.content_type,
+                image_data: processed.image_data,
+                thumbnail_data: processed.thumbnail_data,
+            })
+            .await
+            .map_err(|err| err.to_string())?;
+        info!(entity_type, entity_id, "deferred image saved");
+        if let Err(err) = self.image_repo.delete_upload(upload_id).await {
+            warn!(error = %err, %upload_id, "failed to remove processed image upload");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_jobs_dedupe_per_user_and_years() {
+        let years = |ys: &[Option<i32>]| Some(ys.iter().copied().collect());
+        let job = |user_id, years| BackgroundJob::RefreshStats {
+            user_id: UserId::new(user_id),
+            years,
+        };
+
+        assert_eq!(
+            job(1, years(&[Some(2024), None])).dedupe_key().as_deref(),
+            Some("stats:1:none,2024")
+        );
+        assert_eq!(job(1, None).dedupe_key().as_deref(), Some("stats:1:all"));
+        assert_ne!(
+            job(1, None).dedupe_key(),
+            job(2, None).dedupe_key(),
+            "different users never collapse"
+        );
+    }
+
+    #[test]
+    fn payload_round_trips_with_kind_tag() {
+        let job = BackgroundJob::RefreshTimeline {
+            entity_type: "book".to_string(),
+            entity_id: 7,
+        };
+        let new_job = job.to_new_job(Utc::now()).unwrap();
+
+        assert_eq!(new_job.kind, "refresh_timeline");
+        assert!(new_job.payload.contains(r#""kind":"refresh_timeline""#));
+        assert_eq!(
+            serde_json::from_str::<BackgroundJob>(&new_job.payload).unwrap(),
+            job
+        );
+    }
+}
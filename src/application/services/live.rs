@@ -0,0 +1,264 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use tokio::sync::{broadcast, watch};
+
+use crate::domain::ids::UserId;
+use crate::domain::timeline::TimelineEvent;
+
+/// Messages kept for replay to clients reconnecting with a `Last-Event-ID`.
+const REPLAY_CAPACITY: usize = 256;
+
+/// A change connected pages may want to show without a reload.
+#[derive(Debug, Clone)]
+pub enum LiveUpdate {
+    /// A new event was written to the timeline by `user_id`.
+    TimelineEvent {
+        event: Box<TimelineEvent>,
+        user_id: UserId,
+        /// About a private reading, so only `user_id` may see it.
+        private: bool,
+    },
+    /// Existing timeline events were rewritten by a background refresh.
+    TimelineRefreshed,
+    /// A user's cached stats were recomputed.
+    StatsRefreshed(UserId),
+}
+
+#[derive(Debug, Clone)]
+pub struct LiveMessage {
+    /// Sent as the SSE event id; increases across restarts.
+    pub id: u64,
+    pub update: LiveUpdate,
+}
+
+/// What a (re)connecting client missed since its last event id.
+#[derive(Debug)]
+pub enum Replay {
+    /// Messages newer than the last one seen, oldest first.
+    Missed(Vec<LiveMessage>),
+    /// Some messages are no longer buffered; the page should reload.
+    Gap,
+}
+
+impl Default for Replay {
+    fn default() -> Self {
+        Replay::Missed(Vec::new())
+    }
+}
+
+/// An open connection's view of the hub.
+pub struct LiveSubscription {
+    pub replay: Replay,
+    receiver: broadcast::Receiver<LiveMessage>,
+    closed: watch::Receiver<bool>,
+}
+
+/// The next thing a subscription sees.
+#[derive(Debug)]
+pub enum Received {
+    Message(LiveMessage),
+    /// The connection fell too far behind and messages were dropped.
+    Lagged,
+    /// The server is shutting down.
+    Closed,
+}
+
+impl LiveSubscription {
+    pub async fn recv(&mut self) -> Received {
+        tokio::select! {
+            result = self.receiver.recv() => match result {
+                Ok(message) => Received::Message(message),
+                Err(broadcast::error::RecvError::Lagged(_)) => Received::Lagged,
+                Err(broadcast::error::RecvError::Closed) => Received::Closed,
+            },
+            _ = self.closed.wait_for(|closed| *closed) => Received::Closed,
+        }
+    }
+}
+
+/// Broadcast hub for live page updates, fed by the services that write
+/// timeline events and by the job worker after stats refreshes.
+#[derive(Clone)]
+pub struct LiveUpdates {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    sender: broadcast::Sender<LiveMessage>,
+    state: Mutex<ReplayBuffer>,
+    closed: watch::Sender<bool>,
+}
+
+struct ReplayBuffer {
+    next_id: u64,
+    recent: VecDeque<LiveMessage>,
+}
+
+impl LiveUpdates {
+    pub fn new() -> Self {
+        // Seeding from the clock keeps ids increasing across restarts, so a
+        // client reconnecting to a new process sees a gap, not a replay.
+        let first_id = u64::try_from(chrono::Utc::now().timestamp_micros()).unwrap_or(1);
+        Self::starting_at(first_id)
+    }
+
+    fn starting_at(first_id: u64) -> Self {
+        let (sender, _) = broadcast::channel(REPLAY_CAPACITY);
+        Self {
+            inner: Arc::new(Inner {
+                sender,
+                state: Mutex::new(ReplayBuffer {
+                    next_id: first_id,
+                    recent: VecDeque::with_capacity(REPLAY_CAPACITY),
+                }),
+                closed: watch::Sender::new(false),
+            }),
+        }
+    }
+
+    pub fn publish(&self, update: LiveUpdate) {
+        let mut state = self.lock();
+        let message = LiveMessage {
+            id: state.next_id,
+            update,
+        };
+        state.next_id += 1;
+        if state.recent.len() == REPLAY_CAPACITY {
+            state.recent.pop_front();
+        }
+        state.recent.push_back(message.clone());
+        // No receivers just means nobody is watching
+        let _ = self.inner.sender.send(message);
+    }
+
+    pub fn publish_event(&self, event: TimelineEvent, user_id: UserId) {
+        self.publish(LiveUpdate::TimelineEvent {
+            event: Box::new(event),
+            user_id,
+            private: false,
+        });
+    }
+
+    /// Publish an event about a private reading, shown only to its reader.
+    pub fn publish_private_event(&self, event: TimelineEvent, user_id: UserId) {
+        self.publish(LiveUpdate::TimelineEvent {
+            event: Box::new(event),
+            user_id,
+            private: true,
+        });
+    }
+
+    /// Subscribe to new messages, along with whatever was missed since
+    /// `last_event_id`. Both are taken under one lock so nothing published
+    /// in between is lost or delivered twice.
+    pub fn subscribe(&self, last_event_id: Option<u64>) -> LiveSubscription {
+        let state = self.lock();
+        let receiver = self.inner.sender.subscribe();
+        let replay = match last_event_id {
+            None => Replay::default(),
+            Some(last_id) => state.replay_after(last_id),
+        };
+        LiveSubscription {
+            replay,
+            receiver,
+            closed: self.inner.closed.subscribe(),
+        }
+    }
+
+    /// End every open subscription so graceful shutdown isn't held up by
+    /// long-lived connections.
+    pub fn close(&self) {
+        self.inner.closed.send_replace(true);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, ReplayBuffer> {
+        self.inner
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for LiveUpdates {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReplayBuffer {
+    fn replay_after(&self, last_id: u64) -> Replay {
+        let newest_id = self.next_id.saturating_sub(1);
+        if last_id > newest_id {
+            // An id from before a restart whose clock ran ahead
+            return Replay::Gap;
+        }
+        if last_id == newest_id {
+            return Replay::Missed(Vec::new());
+        }
+        match self.recent.front() {
+            Some(oldest) if oldest.id <= last_id + 1 => Replay::Missed(
+                self.recent
+                    .iter()
+                    .filter(|message| message.id > last_id)
+                    .cloned()
+                    .collect(),
+            ),
+            _ => Replay::Gap,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn missed_ids(replay: Replay) -> Vec<u64> {
+        match replay {
+            Replay::Missed(messages) => messages.iter().map(|m| m.id).collect(),
+            Replay::Gap => panic!("expected a replay, got a gap"),
+        }
+    }
+
+    #[test]
+    fn reconnecting_replays_messages_after_last_event_id() {
+        let live = LiveUpdates::starting_at(10);
+        for _ in 0..3 {
+            live.publish(LiveUpdate::TimelineRefreshed);
+        }
+
+        assert_eq!(missed_ids(live.subscribe(Some(10)).replay), vec![11, 12]);
+        assert!(missed_ids(live.subscribe(Some(12)).replay).is_empty());
+        assert!(missed_ids(live.subscribe(None).replay).is_empty());
+    }
+
+    #[test]
+    fn reconnecting_past_the_buffer_reports_a_gap() {
+        let live = LiveUpdates::starting_at(1);
+        for _ in 0..REPLAY_CAPACITY + 5 {
+            live.publish(LiveUpdate::TimelineRefreshed);
+        }
+
+        assert!(matches!(live.subscribe(Some(2)).replay, Replay::Gap));
+        assert!(matches!(
+            live.subscribe(Some(1_000_000)).replay,
+            Replay::Gap
+        ));
+        assert_eq!(missed_ids(live.subscribe(Some(259)).replay), vec![260, 261]);
+    }
+
+    #[tokio::test]
+    async fn subscriptions_receive_new_messages_until_closed() {
+        let live = LiveUpdates::starting_at(1);
+        let mut subscription = live.subscribe(None);
+
+        live.publish(LiveUpdate::StatsRefreshed(UserId::new(7)));
+        match subscription.recv().await {
+            Received::Message(message) => assert_eq!(message.id, 1),
+            other => panic!("expected a message, got {other:?}"),
+        }
+
+        live.close();
+        assert!(matches!(subscription.recv().await, Received::Closed));
+    }
+}
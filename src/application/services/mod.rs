@@ -1,12 +1,30 @@
 mod books;
+mod challenges;
+pub mod federation;
+mod goals;
+pub mod jobs;
+pub mod live;
 mod readings;
+mod shelves;
 pub mod stats;
+pub mod timeline_publisher;
 pub mod timeline_refresh;
+mod user_books;
+pub mod webhooks;
 
 pub use books::BookService;
+pub use challenges::ChallengeService;
+pub use federation::FederationService;
+pub use goals::GoalService;
+pub use jobs::{BackgroundJob, JobQueue, JobWorker};
+pub use live::{LiveUpdate, LiveUpdates, Received, Replay};
 pub use readings::ReadingService;
+pub use shelves::ShelfService;
 pub use stats::StatsInvalidator;
+pub use timeline_publisher::TimelinePublisher;
 pub use timeline_refresh::TimelineInvalidator;
+pub use user_books::UserBookService;
+pub use webhooks::WebhookService;
 
 use std::sync::Arc;
 
@@ -17,7 +35,8 @@ use crate::domain::ids::UserId;
 use crate::domain::repositories::TimelineEventRepository;
 
 /// Generates a service struct with a `create` method that inserts via the
-/// repository and then records a timeline event (fire-and-forget).
+/// repository and then records a timeline event (fire-and-forget),
+/// publishing it to live page connections and webhooks.
 ///
 /// Use this for entities whose `to_timeline_event()` method needs `&self`
 /// and a `UserId`. For entities that need enrichment or cross-repo lookups,
@@ -33,16 +52,19 @@ macro_rules! define_simple_service {
         pub struct $service {
             repo: Arc<dyn $repo_trait>,
             timeline_repo: Arc<dyn TimelineEventRepository>,
+            events: TimelinePublisher,
         }
 
         impl $service {
             pub fn new(
                 repo: Arc<dyn $repo_trait>,
                 timeline_repo: Arc<dyn TimelineEventRepository>,
+                events: TimelinePublisher,
             ) -> Self {
                 Self {
                     repo,
                     timeline_repo,
+                    events,
                 }
             }
 
@@ -52,16 +74,17 @@ macro_rules! define_simple_service {
                 user_id: UserId,
             ) -> Result<$entity, RepositoryError> {
                 let entity = self.repo.insert(new).await?;
-                if let Err(err) = self
+                match self
                     .timeline_repo
                     .insert(entity.to_timeline_event(user_id))
                     .await
                 {
-                    warn!(
+                    Ok(event) => self.events.publish(event, user_id).await,
+                    Err(err) => warn!(
                         error = %err,
                         id = %entity.id,
                         concat!("failed to record ", $entity_name, " timeline event"),
-                    );
+                    ),
                 }
                 Ok(entity)
             }
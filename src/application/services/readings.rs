@@ -2,13 +2,21 @@ use std::sync::Arc;
 
 use tracing::warn;
 
+use crate::application::services::{TimelinePublisher, UserBookService};
+use crate::domain::authors::Author;
+use crate::domain::book_items::Book;
 use crate::domain::errors::RepositoryError;
-use crate::domain::ids::ReadingId;
-use crate::domain::readings::{NewReading, Reading, UpdateReading, reading_timeline_event};
+use crate::domain::goals::recorded_milestone;
+use crate::domain::listing::{ListRequest, SortKey};
+use crate::domain::readings::{
+    NewReading, Reading, ReadingStatus, UpdateReading, reading_change_event,
+    reading_milestone_event, reading_timeline_event,
+};
 use crate::domain::repositories::{
     AuthorRepository, BookRepository, ReadingRepository, TimelineEventRepository,
     UserBookRepository,
 };
+use crate::domain::timeline::{TimelineEvent, TimelineFilter, TimelineSortKey};
 use crate::domain::user_books::{NewUserBook, Shelf};
 
 #[derive(Clone)]
@@ -18,6 +26,8 @@ pub struct ReadingService {
     authors: Arc<dyn AuthorRepository>,
     timeline: Arc<dyn TimelineEventRepository>,
     user_books: Arc<dyn UserBookRepository>,
+    user_book_service: UserBookService,
+    events: TimelinePublisher,
 }
 
 impl ReadingService {
@@ -27,13 +37,23 @@ impl ReadingService {
         authors: Arc<dyn AuthorRepository>,
         timeline: Arc<dyn TimelineEventRepository>,
         user_books: Arc<dyn UserBookRepository>,
+        events: TimelinePublisher,
     ) -> Self {
+        let user_book_service = UserBookService::new(
+            Arc::clone(&user_books),
+            Arc::clone(&books),
+            Arc::clone(&authors),
+            Arc::clone(&timeline),
+            events.clone(),
+        );
         Self {
             readings,
             books,
             authors,
             timeline,
             user_books,
+            user_book_service,
+            events,
         }
     }
 
@@ -47,8 +67,8 @@ impl ReadingService {
         match self.user_books.get_by_user_and_book(user_id, book_id).await {
             Ok(existing) if existing.shelf == Shelf::Wishlist => {
                 if let Err(err) = self
-                    .user_books
-                    .move_shelf(existing.id, Shelf::Library)
+                    .user_book_service
+                    .move_shelf(&existing, Shelf::Library)
                     .await
                 {
                     warn!(error = %err, %user_id, %book_id, "failed to move book from wishlist to library");
@@ -72,26 +92,118 @@ impl ReadingService {
         Ok(reading)
     }
 
-    /// Transition a reading to finished status, records a timeline event.
-    pub async fn finish(
+    /// Apply an update, defaulting the finish date when a reading is
+    /// finished. Status transitions and rating changes record a timeline
+    /// event with the before/after values, and progress past a new
+    /// milestone records a `milestone` event.
+    pub async fn update(
         &self,
-        id: ReadingId,
+        existing: &Reading,
         mut update: UpdateReading,
     ) -> Result<Reading, RepositoryError> {
-        if update.finished_at.is_none() {
+        if update.status == Some(ReadingStatus::Read) && update.finished_at.is_none() {
             update.finished_at = Some(chrono::Utc::now().date_naive());
         }
-        let reading = self.readings.update(id, update).await?;
-        self.record_timeline_event(&reading).await;
+        let reading = self.readings.update(existing.id, update).await?;
+        self.record_change_event(existing, &reading).await;
+        self.record_milestone_event(existing, &reading).await;
         Ok(reading)
     }
 
     async fn record_timeline_event(&self, reading: &Reading) {
+        let Some((book, authors)) = self.book_and_authors(reading).await else {
+            return;
+        };
+
+        match self
+            .timeline
+            .insert(reading_timeline_event(reading, &book, &authors))
+            .await
+        {
+            Ok(event) => self.publish(event, reading).await,
+            Err(err) => {
+                warn!(error = %err, reading_id = %reading.id, "failed to record reading timeline event");
+            }
+        }
+    }
+
+    async fn record_change_event(&self, before: &Reading, after: &Reading) {
+        if before.status == after.status && before.rating == after.rating {
+            return;
+        }
+        let Some((book, authors)) = self.book_and_authors(after).await else {
+            return;
+        };
+
+        let Some(event) = reading_change_event(before, after, &book, &authors) else {
+            return;
+        };
+        match self.timeline.insert(event).await {
+            Ok(event) => self.publish(event, after).await,
+            Err(err) => {
+                warn!(error = %err, reading_id = %after.id, "failed to record reading change timeline event");
+            }
+        }
+    }
+
+    async fn record_milestone_event(&self, before: &Reading, after: &Reading) {
+        if before.current_page == after.current_page {
+            return;
+        }
+        let Some((book, authors)) = self.book_and_authors(after).await else {
+            return;
+        };
+        let previous = match self.recorded_milestone(after).await {
+            Ok(previous) => previous,
+            Err(err) => {
+                warn!(error = %err, reading_id = %after.id, "failed to load recorded reading milestones");
+                return;
+            }
+        };
+
+        let Some(event) = reading_milestone_event(before, after, previous, &book, &authors) else {
+            return;
+        };
+        match self.timeline.insert(event).await {
+            Ok(event) => self.publish(event, after).await,
+            Err(err) => {
+                warn!(error = %err, reading_id = %after.id, "failed to record reading milestone timeline event");
+            }
+        }
+    }
+
+    /// The highest milestone already recorded for the reading.
+    async fn recorded_milestone(&self, reading: &Reading) -> Result<Option<u8>, RepositoryError> {
+        let filter = TimelineFilter {
+            entity_type: Some("reading".to_string()),
+            action: Some("milestone".to_string()),
+            ..TimelineFilter::for_user(reading.user_id)
+        }
+        .seen_by(Some(reading.user_id));
+        let sort_key = <TimelineSortKey as SortKey>::default();
+        let request = ListRequest::show_all(sort_key, sort_key.default_direction());
+        let recorded = self.timeline.list(&filter, &request).await?.items;
+        Ok(recorded
+            .iter()
+            .filter(|e| e.entity_id == reading.id.into_inner())
+            .filter_map(recorded_milestone)
+            .max())
+    }
+
+    async fn publish(&self, event: TimelineEvent, reading: &Reading) {
+        if reading.private {
+            self.events.publish_private(event, reading.user_id).await;
+        } else {
+            self.events.publish(event, reading.user_id).await;
+        }
+    }
+
+    async fn book_and_authors(&self, reading: &Reading) -> Option<(Book, Vec<Author>)> {
         let book = match self.books.get(reading.book_id).await {
             Ok(b) => b,
             Err(err) => {
                 warn!(error = %err, reading_id = %reading.id, "failed to fetch book for reading timeline event");
-                return;
+                return None;
             }
         };
 
@@ -104,13 +216,6 @@ impl ReadingService {
                 }
             }
         }
-
-        if let Err(err) = self
-            .timeline
-            .insert(reading_timeline_event(reading, &book, &authors))
-            .await
-        {
-            warn!(error = %err, reading_id = %reading.id, "failed to record reading timeline event");
-        }
+        Some((book, authors))
     }
 }
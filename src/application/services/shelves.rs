@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use tracing::warn;
+
+use crate::domain::book_items::BookWithAuthors;
+use crate::domain::errors::RepositoryError;
+use crate::domain::filters::{FilterQuery, FilterTarget};
+use crate::domain::ids::{BookId, UserId};
+use crate::domain::listing::{ListRequest, SortDirection};
+use crate::domain::repositories::{BookRepository, ShelfRepository, UserBookRepository};
+use crate::domain::shelves::{SavedShelf, ShelfKind};
+use crate::domain::user_books::UserBookSortKey;
+
+/// Resolves the books on saved shelves. Manual shelves read their stored
+/// book list; smart shelves evaluate their query against the owner's library.
+#[derive(Clone)]
+pub struct ShelfService {
+    shelves: Arc<dyn ShelfRepository>,
+    books: Arc<dyn BookRepository>,
+    user_books: Arc<dyn UserBookRepository>,
+}
+
+impl ShelfService {
+    pub fn new(
+        shelves: Arc<dyn ShelfRepository>,
+        books: Arc<dyn BookRepository>,
+        user_books: Arc<dyn UserBookRepository>,
+    ) -> Self {
+        Self {
+            shelves,
+            books,
+            user_books,
+        }
+    }
+
+    #[allow(clippy::similar_names)] // self vs shelf
+    pub async fn books(&self, shelf: &SavedShelf) -> Result<Vec<BookWithAuthors>, RepositoryError> {
+        match shelf.kind {
+            ShelfKind::Manual => self.books.list_by_shelf(shelf.id).await,
+            ShelfKind::Smart => {
+                let query = smart_query(shelf)?;
+                let request = ListRequest::show_all(UserBookSortKey::Title, SortDirection::Asc);
+                let page = self
+                    .user_books
+                    .list_by_user(shelf.user_id, None, &request, Some(&query))
+                    .await?;
+                Ok(page.items.into_iter().map(|ub| ub.book).collect())
+            }
+        }
+    }
+
+    /// All of a user's shelves, manual or smart, that currently hold the book.
+    /// A smart shelf whose query can't be evaluated is logged and left out,
+    /// so it doesn't hide the others.
+    pub async fn shelves_for_book(
+        &self,
+        user_id: UserId,
+        book_id: BookId,
+    ) -> Result<Vec<SavedShelf>, RepositoryError> {
+        let manual_ids: Vec<_> = self
+            .shelves
+            .list_containing_book(user_id, book_id)
+            .await?
+            .into_iter()
+            .map(|s| s.id)
+            .collect();
+
+        let mut shelves = Vec::new();
+        for shelf in self.shelves.list_by_user(user_id).await? {
+            let holds_book = match shelf.kind {
+                ShelfKind::Manual => manual_ids.contains(&shelf.id),
+                ShelfKind::Smart => {
+                    let matches = match smart_query(&shelf) {
+                        Ok(query) => {
+                            self.user_books
+                                .matches_query(user_id, book_id, &query)
+                                .await
+                        }
+                        Err(err) => Err(err),
+                    };
+                    match matches {
+                        Ok(matches) => matches,
+                        Err(err) => {
+                            warn!(error = %err, shelf_id = %shelf.id, "skipping smart shelf");
+                            false
+                        }
+                    }
+                }
+            };
+            if holds_book {
+                shelves.push(shelf);
+            }
+        }
+        Ok(shelves)
+    }
+}
+
+fn smart_query(shelf: &SavedShelf) -> Result<FilterQuery, RepositoryError> {
+    let query = shelf.query.as_deref().unwrap_or_default();
+    FilterQuery::parse(query, FilterTarget::UserBooks).map_err(|err| {
+        RepositoryError::unexpected(format!("invalid query on shelf {}: {err}", shelf.id))
+    })
+}
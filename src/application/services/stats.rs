@@ -1,63 +1,292 @@
-use std::collections::HashSet;
+use std::collections::BTreeSet;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 
-use tokio::sync::mpsc;
-use tracing::{error, info};
+use chrono::{Datelike, Utc};
+use tracing::{error, info, warn};
 
+use crate::application::services::jobs::{BackgroundJob, JobQueue};
+use crate::domain::RepositoryError;
+use crate::domain::filters::{FilterField, FilterOp, FilterQuery, FilterTerm, FilterValue};
+use crate::domain::household::HouseholdStats;
 use crate::domain::ids::UserId;
-use crate::domain::repositories::StatsRepository;
-use crate::domain::stats::CachedStats;
+use crate::domain::listing::{ListRequest, SortDirection};
+use crate::domain::readings::{Reading, ReadingFilter, ReadingSortKey, ReadingStatus};
+use crate::domain::repositories::{ReadingRepository, StatsRepository};
+use crate::domain::review::YearInReview;
+use crate::domain::stats::{CachedStats, PeriodStats, ReadingStats, StatsPeriod, StatsReport};
+use crate::domain::stats_cache::{YearStatsPartial, reading_stats_years};
 
-/// Sends invalidation signals to the background stats recomputer.
-/// Non-blocking and fire-and-forget — safe to call from any handler.
+/// Timings for stats cache updates, shown on the admin page.
+#[derive(Debug, Default)]
+pub struct StatsMetrics {
+    full_runs: AtomicU64,
+    full_ms: AtomicU64,
+    incremental_runs: AtomicU64,
+    incremental_ms: AtomicU64,
+    reconciliations: AtomicU64,
+    last_reconciliation_drift: AtomicU64,
+}
+
+/// A point-in-time copy of [`StatsMetrics`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatsMetricsSnapshot {
+    pub full_runs: u64,
+    pub full_avg_ms: u64,
+    pub incremental_runs: u64,
+    pub incremental_avg_ms: u64,
+    pub reconciliations: u64,
+    /// Partials found out of date by the most recent reconciliation.
+    pub last_reconciliation_drift: u64,
+}
+
+impl StatsMetrics {
+    fn record(runs: &AtomicU64, total_ms: &AtomicU64, elapsed: Duration) {
+        runs.fetch_add(1, Ordering::Relaxed);
+        total_ms.fetch_add(elapsed.as_millis() as u64, Ordering::Relaxed);
+    }
+
+    pub fn record_full(&self, elapsed: Duration) {
+        Self::record(&self.full_runs, &self.full_ms, elapsed);
+    }
+
+    pub fn record_incremental(&self, elapsed: Duration) {
+        Self::record(&self.incremental_runs, &self.incremental_ms, elapsed);
+    }
+
+    fn record_reconciliation(&self, drift: u64) {
+        self.reconciliations.fetch_add(1, Ordering::Relaxed);
+        self.last_reconciliation_drift
+            .store(drift, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> StatsMetricsSnapshot {
+        let average = |runs: u64, total: u64| total.checked_div(runs).unwrap_or(0);
+        let full_runs = self.full_runs.load(Ordering::Relaxed);
+        let incremental_runs = self.incremental_runs.load(Ordering::Relaxed);
+        StatsMetricsSnapshot {
+            full_runs,
+            full_avg_ms: average(full_runs, self.full_ms.load(Ordering::Relaxed)),
+            incremental_runs,
+            incremental_avg_ms: average(
+                incremental_runs,
+                self.incremental_ms.load(Ordering::Relaxed),
+            ),
+            reconciliations: self.reconciliations.load(Ordering::Relaxed),
+            last_reconciliation_drift: self.last_reconciliation_drift.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Queues stats recomputation jobs for the background worker.
 #[derive(Clone)]
 pub struct StatsInvalidator {
-    tx: mpsc::Sender<UserId>,
+    queue: JobQueue,
+    metrics: Arc<StatsMetrics>,
 }
 
 impl StatsInvalidator {
-    pub fn new(tx: mpsc::Sender<UserId>) -> Self {
-        Self { tx }
-    }
-
-    /// Signal that stats need recomputation for the given user.
-    pub fn invalidate(&self, user_id: UserId) {
-        let _ = self.tx.try_send(user_id);
-    }
-}
-
-/// Listens for invalidation signals, debounces, and recomputes all stats.
-/// Runs as a long-lived background task — spawn with `tokio::spawn`.
-pub async fn stats_recomputation_task(
-    mut rx: mpsc::Receiver<UserId>,
-    stats_repo: Arc<dyn StatsRepository>,
-    debounce: Duration,
-) {
-    loop {
-        let Some(first_user_id) = rx.recv().await else {
-            break;
-        };
-
-        // Debounce: wait then drain any accumulated signals, collecting unique user IDs
-        let mut user_ids = HashSet::new();
-        user_ids.insert(first_user_id);
-        tokio::time::sleep(debounce).await;
-        while let Ok(uid) = rx.try_recv() {
-            user_ids.insert(uid);
+    pub fn new(queue: JobQueue) -> Self {
+        Self {
+            queue,
+            metrics: Arc::new(StatsMetrics::default()),
         }
+    }
 
-        for user_id in user_ids {
-            match compute_all_stats(&*stats_repo, user_id).await {
-                Ok(cached) => {
-                    if let Err(err) = stats_repo.store_cached(user_id, &cached).await {
-                        error!(error = %err, %user_id, "failed to store stats cache");
-                    }
-                }
-                Err(err) => error!(error = %err, %user_id, "stats recomputation failed"),
-            }
+    /// Queue a recomputation of all stats for the given user.
+    pub async fn invalidate(&self, user_id: UserId) {
+        self.queue
+            .enqueue(BackgroundJob::RefreshStats {
+                user_id,
+                years: None,
+            })
+            .await;
+    }
+
+    /// Queue a refresh of the years the given readings touch. Pass both the
+    /// old and new versions of an updated reading so both sets of years are
+    /// refreshed.
+    pub async fn invalidate_readings(&self, user_id: UserId, readings: &[&Reading]) {
+        let years = readings
+            .iter()
+            .flat_map(|reading| reading_stats_years(reading))
+            .collect();
+        self.queue
+            .enqueue(BackgroundJob::RefreshStats {
+                user_id,
+                years: Some(years),
+            })
+            .await;
+    }
+
+    pub fn metrics(&self) -> Arc<StatsMetrics> {
+        Arc::clone(&self.metrics)
+    }
+}
+
+/// Rebuilds every cached user's stats from scratch, catching any partial
+/// that incremental updates missed, and records the drift found.
+pub async fn reconcile_stats(
+    stats_repo: &dyn StatsRepository,
+    metrics: &StatsMetrics,
+) -> Result<(), RepositoryError> {
+    let user_ids = stats_repo.cached_user_ids().await?;
+
+    let mut drift = 0;
+    for user_id in user_ids {
+        match rebuild_stats(stats_repo, user_id, metrics).await {
+            Ok(rebuild) => drift += rebuild.drifted_years,
+            Err(err) => error!(error = %err, %user_id, "stats reconciliation failed"),
+        }
+    }
+    rebuild_household_stats(stats_repo).await?;
+    metrics.record_reconciliation(drift);
+    if drift > 0 {
+        warn!(drift, "stats reconciliation corrected out-of-date partials");
+    } else {
+        info!("stats reconciliation found no drift");
+    }
+    Ok(())
+}
+
+/// Result of a full stats rebuild.
+#[derive(Debug)]
+pub struct StatsRebuild {
+    pub stats: CachedStats,
+    /// Stored partials that differed from freshly computed ones.
+    pub drifted_years: u64,
+}
+
+/// Recomputes every per-year partial for the user, replaces the stored ones
+/// and caches the merged stats.
+pub async fn rebuild_stats(
+    repo: &dyn StatsRepository,
+    user_id: UserId,
+    metrics: &StatsMetrics,
+) -> Result<StatsRebuild, RepositoryError> {
+    let start = Instant::now();
+
+    let stored = repo.get_partials(user_id).await?;
+    let mut partials = Vec::new();
+    for year in repo.partial_years(user_id).await? {
+        partials.push(repo.year_partial(user_id, year).await?);
+    }
+
+    let mut drifted_years = 0;
+    for partial in &partials {
+        if stored.iter().find(|p| p.year == partial.year) != Some(partial) {
+            drifted_years += 1;
+            repo.store_partial(user_id, partial).await?;
         }
     }
+    for stale in stored
+        .iter()
+        .filter(|s| !partials.iter().any(|p| p.year == s.year))
+    {
+        drifted_years += 1;
+        repo.delete_partial(user_id, stale.year).await?;
+    }
+
+    let stats = store_merged_stats(repo, user_id, &partials).await?;
+
+    let elapsed = start.elapsed();
+    metrics.record_full(elapsed);
+    info!(
+        duration_ms = elapsed.as_millis(),
+        %user_id,
+        mode = "full",
+        years = partials.len(),
+        drifted_years,
+        "stats computed"
+    );
+    Ok(StatsRebuild {
+        stats,
+        drifted_years,
+    })
+}
+
+/// Recomputes only the given years' partials and re-merges the cached stats.
+/// Falls back to a full rebuild when the user has no cached stats yet.
+pub async fn refresh_stats_years(
+    repo: &dyn StatsRepository,
+    user_id: UserId,
+    years: &BTreeSet<Option<i32>>,
+    metrics: &StatsMetrics,
+) -> Result<(), RepositoryError> {
+    if repo.get_cached(user_id).await?.is_none() {
+        return rebuild_stats(repo, user_id, metrics).await.map(|_| ());
+    }
+
+    let start = Instant::now();
+    for &year in years {
+        let partial = repo.year_partial(user_id, year).await?;
+        if partial.is_empty() {
+            repo.delete_partial(user_id, year).await?;
+        } else {
+            repo.store_partial(user_id, &partial).await?;
+        }
+    }
+
+    let partials = repo.get_partials(user_id).await?;
+    store_merged_stats(repo, user_id, &partials).await?;
+
+    let elapsed = start.elapsed();
+    metrics.record_incremental(elapsed);
+    info!(
+        duration_ms = elapsed.as_millis(),
+        %user_id,
+        mode = "incremental",
+        years = years.len(),
+        "stats computed"
+    );
+    Ok(())
+}
+
+/// Merges partials with the current-state counters and book summary, which
+/// are cheap enough to recompute every time, and stores the snapshot.
+async fn store_merged_stats(
+    repo: &dyn StatsRepository,
+    user_id: UserId,
+    partials: &[YearStatsPartial],
+) -> Result<CachedStats, RepositoryError> {
+    let (book_summary, counters) =
+        tokio::join!(repo.book_summary(user_id), repo.reading_counters(user_id));
+
+    let cached = CachedStats {
+        book_summary: book_summary?,
+        reading: ReadingStats::from_partials(partials, counters?, Utc::now().year()),
+        computed_at: Utc::now().to_rfc3339(),
+    };
+    repo.store_cached(user_id, &cached).await?;
+    Ok(cached)
+}
+
+/// Recomputes the stats across all users who haven't opted out and caches them.
+pub async fn rebuild_household_stats(
+    repo: &dyn StatsRepository,
+) -> Result<HouseholdStats, RepositoryError> {
+    let start = Instant::now();
+    let month = HouseholdStats::month_start(Utc::now().date_naive());
+    let stats = repo.household_stats(month).await?;
+    repo.store_cached_household(&stats).await?;
+    info!(
+        duration_ms = start.elapsed().as_millis(),
+        members = stats.members,
+        "household stats computed"
+    );
+    Ok(stats)
+}
+
+/// Household stats from the cache, recomputed when there are none yet or
+/// they were computed in an earlier month.
+pub async fn load_household_stats(
+    repo: &dyn StatsRepository,
+) -> Result<HouseholdStats, RepositoryError> {
+    match repo.get_cached_household().await? {
+        Some(cached) if cached.is_current(Utc::now().date_naive()) => Ok(cached),
+        _ => rebuild_household_stats(repo).await,
+    }
 }
 
 /// Runs all stats queries and assembles a complete `CachedStats` snapshot.
@@ -65,7 +294,7 @@ pub async fn stats_recomputation_task(
 pub async fn compute_all_stats(
     repo: &dyn StatsRepository,
     user_id: UserId,
-) -> Result<CachedStats, crate::domain::RepositoryError> {
+) -> Result<CachedStats, RepositoryError> {
     let start = Instant::now();
 
     let (book_summary, reading) =
@@ -81,25 +310,83 @@ pub async fn compute_all_stats(
     Ok(cached)
 }
 
-/// Compute stats for a specific year. Not cached — runs on demand.
-pub async fn compute_stats_for_year(
+/// Compute stats for a date range. Not cached — runs on demand.
+pub async fn compute_stats_for_period(
     repo: &dyn StatsRepository,
     user_id: UserId,
-    year: i32,
-) -> Result<CachedStats, crate::domain::RepositoryError> {
+    period: &StatsPeriod,
+) -> Result<PeriodStats, crate::domain::RepositoryError> {
     let start = Instant::now();
 
     let (book_summary, reading) = tokio::join!(
-        repo.book_summary_for_year(user_id, year),
-        repo.reading_summary_for_year(user_id, year),
+        repo.book_summary_for_period(user_id, period),
+        repo.reading_summary_for_period(user_id, period),
     );
 
-    let cached = CachedStats {
+    let stats = PeriodStats {
+        period: *period,
         book_summary: book_summary?,
         reading: reading?,
-        computed_at: chrono::Utc::now().to_rfc3339(),
     };
 
-    info!(duration_ms = start.elapsed().as_millis(), %user_id, year, "year stats computed");
-    Ok(cached)
+    info!(
+        duration_ms = start.elapsed().as_millis(),
+        %user_id,
+        from = %period.from,
+        to = %period.to,
+        "period stats computed"
+    );
+    Ok(stats)
+}
+
+/// Compute stats for a period and, when given, a second period to compare
+/// it against.
+pub async fn compute_stats_report(
+    repo: &dyn StatsRepository,
+    user_id: UserId,
+    period: &StatsPeriod,
+    compare: Option<&StatsPeriod>,
+) -> Result<StatsReport, crate::domain::RepositoryError> {
+    let current = compute_stats_for_period(repo, user_id, period).await?;
+    let compare = match compare {
+        Some(compare) => Some(compute_stats_for_period(repo, user_id, compare).await?),
+        None => None,
+    };
+    Ok(StatsReport::new(current, compare))
+}
+
+/// Build the year in review from the year's stats and the readings finished
+/// in it. Not cached — runs on demand.
+pub async fn compute_year_review(
+    stats_repo: &dyn StatsRepository,
+    reading_repo: &dyn ReadingRepository,
+    user_id: UserId,
+    year: i32,
+) -> Result<YearInReview, RepositoryError> {
+    let period = StatsPeriod::year(year)
+        .ok_or_else(|| RepositoryError::unexpected(format!("year {year} out of range")))?;
+    let stats = compute_stats_for_period(stats_repo, user_id, &period).await?;
+
+    let finished_in_year = FilterQuery {
+        text: None,
+        terms: vec![FilterTerm {
+            field: FilterField::Finished,
+            op: FilterOp::Matches,
+            value: FilterValue::Period {
+                start: period.from,
+                end: period.to,
+            },
+            negated: false,
+        }],
+    };
+    let request = ListRequest::show_all(ReadingSortKey::FinishedAt, SortDirection::Asc);
+    let readings = reading_repo
+        .list(
+            ReadingFilter::for_user_status(user_id, ReadingStatus::Read),
+            &request,
+            Some(&finished_in_year),
+        )
+        .await?;
+
+    Ok(YearInReview::build(year, &stats, &readings.items))
 }
@@ -0,0 +1,36 @@
+use crate::application::services::jobs::{BackgroundJob, JobQueue};
+use crate::application::services::live::LiveUpdates;
+use crate::domain::ids::UserId;
+use crate::domain::timeline::TimelineEvent;
+
+/// Announces timeline events once they're written: to connected pages
+/// through live updates, and to webhooks through the job queue, so no
+/// delivery is lost to a lagging subscriber or a restart.
+#[derive(Clone)]
+pub struct TimelinePublisher {
+    live: LiveUpdates,
+    queue: JobQueue,
+}
+
+impl TimelinePublisher {
+    pub fn new(live: LiveUpdates, queue: JobQueue) -> Self {
+        Self { live, queue }
+    }
+
+    pub async fn publish(&self, event: TimelineEvent, user_id: UserId) {
+        self.queue_webhooks(&event).await;
+        self.live.publish_event(event, user_id);
+    }
+
+    /// Publish an event about a private reading, shown only to its reader.
+    pub async fn publish_private(&self, event: TimelineEvent, user_id: UserId) {
+        self.queue_webhooks(&event).await;
+        self.live.publish_private_event(event, user_id);
+    }
+
+    async fn queue_webhooks(&self, event: &TimelineEvent) {
+        self.queue
+            .enqueue(BackgroundJob::DispatchWebhooks { event_id: event.id })
+            .await;
+    }
+}
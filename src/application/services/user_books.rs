@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use tracing::warn;
+
+use crate::application::services::TimelinePublisher;
+use crate::domain::errors::RepositoryError;
+use crate::domain::repositories::{
+    AuthorRepository, BookRepository, TimelineEventRepository, UserBookRepository,
+};
+use crate::domain::user_books::{Shelf, UserBook, user_book_change_event};
+
+/// Changes to a library entry, each recorded on the timeline with the
+/// before/after values.
+#[derive(Clone)]
+pub struct UserBookService {
+    user_books: Arc<dyn UserBookRepository>,
+    books: Arc<dyn BookRepository>,
+    authors: Arc<dyn AuthorRepository>,
+    timeline: Arc<dyn TimelineEventRepository>,
+    events: TimelinePublisher,
+}
+
+impl UserBookService {
+    pub fn new(
+        user_books: Arc<dyn UserBookRepository>,
+        books: Arc<dyn BookRepository>,
+        authors: Arc<dyn AuthorRepository>,
+        timeline: Arc<dyn TimelineEventRepository>,
+        events: TimelinePublisher,
+    ) -> Self {
+        Self {
+            user_books,
+            books,
+            authors,
+            timeline,
+            events,
+        }
+    }
+
+    #[allow(clippy::similar_names)] // self vs shelf
+    pub async fn move_shelf(
+        &self,
+        existing: &UserBook,
+        shelf: Shelf,
+    ) -> Result<UserBook, RepositoryError> {
+        let user_book = self.user_books.move_shelf(existing.id, shelf).await?;
+        self.record_change_event(existing, &user_book).await;
+        Ok(user_book)
+    }
+
+    pub async fn set_book_club(
+        &self,
+        existing: &UserBook,
+        book_club: bool,
+    ) -> Result<UserBook, RepositoryError> {
+        let user_book = self
+            .user_books
+            .set_book_club(existing.id, book_club)
+            .await?;
+        self.record_change_event(existing, &user_book).await;
+        Ok(user_book)
+    }
+
+    async fn record_change_event(&self, before: &UserBook, after: &UserBook) {
+        if before.shelf == after.shelf && before.book_club == after.book_club {
+            return;
+        }
+        let book = match self.books.get(after.book_id).await {
+            Ok(b) => b,
+            Err(err) => {
+                warn!(error = %err, user_book_id = %after.id, "failed to fetch book for shelf timeline event");
+                return;
+            }
+        };
+
+        let mut authors = Vec::new();
+        if let Ok(enriched) = self.books.get_with_authors(book.id).await {
+            for author_info in &enriched.authors {
+                if let Ok(author) = self.authors.get(author_info.author_id).await {
+                    authors.push(author);
+                }
+            }
+        }
+
+        let Some(event) = user_book_change_event(before, after, &book, &authors, Utc::now()) else {
+            return;
+        };
+        match self.timeline.insert(event).await {
+            Ok(event) => self.events.publish(event, after.user_id).await,
+            Err(err) => {
+                warn!(error = %err, user_book_id = %after.id, "failed to record shelf change timeline event");
+            }
+        }
+    }
+}
@@ -0,0 +1,267 @@
+use std::sync::Arc;
+
+use chrono::{SecondsFormat, Utc};
+use serde_json::{Value, json};
+use tracing::{info, warn};
+
+use crate::application::services::jobs::{BackgroundJob, JobQueue};
+use crate::domain::RepositoryError;
+use crate::domain::analytics::timeline::TimelineEvent;
+use crate::domain::ids::{TimelineEventId, UserId, WebhookDeliveryId, WebhookId};
+use crate::domain::repositories::{TimelineEventRepository, UserRepository, WebhookRepository};
+use crate::domain::webhooks::{
+    DeliveryAttempt, DeliveryStatus, NewWebhook, NewWebhookDelivery, TEST_EVENT, Webhook,
+};
+use crate::infrastructure::auth::generate_session_token;
+use crate::infrastructure::outbound::OutboundClient;
+use crate::infrastructure::webhooks::{self, WebhookRequest};
+
+/// Sends signed timeline events to the URLs users and admins register.
+/// Every POST goes through the job queue, so failing endpoints are retried
+/// with backoff and each attempt lands in the delivery log.
+#[derive(Clone)]
+pub struct WebhookService {
+    webhook_repo: Arc<dyn WebhookRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    timeline_repo: Arc<dyn TimelineEventRepository>,
+    job_queue: JobQueue,
+    outbound: OutboundClient,
+}
+
+impl WebhookService {
+    pub fn new(
+        webhook_repo: Arc<dyn WebhookRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        timeline_repo: Arc<dyn TimelineEventRepository>,
+        job_queue: JobQueue,
+        outbound: OutboundClient,
+    ) -> Self {
+        Self {
+            webhook_repo,
+            user_repo,
+            timeline_repo,
+            job_queue,
+            outbound,
+        }
+    }
+
+    /// Register a webhook with a fresh secret. `user_id: None` creates an
+    /// admin-level webhook receiving every user's events.
+    pub async fn create(
+        &self,
+        user_id: Option<UserId>,
+        url: &str,
+        events: Vec<String>,
+    ) -> Result<Webhook, RepositoryError> {
+        let webhook = self
+            .webhook_repo
+            .insert(NewWebhook {
+                user_id,
+                url: url.to_string(),
+                secret: generate_session_token(),
+                events,
+            })
+            .await?;
+        info!(webhook_id = %webhook.id, global = user_id.is_none(), "webhook created");
+        Ok(webhook)
+    }
+
+    /// Reject a URL on this host or its private network. Only admins may
+    /// point webhooks there, since the delivery log shows the response.
+    pub async fn check_destination(&self, url: &str) -> anyhow::Result<()> {
+        self.outbound.check(url, false).await
+    }
+
+    /// A webhook the user may manage, or `NotFound`.
+    pub async fn get_managed(
+        &self,
+        user_id: UserId,
+        is_admin: bool,
+        id: WebhookId,
+    ) -> Result<Webhook, RepositoryError> {
+        let webhook = self.webhook_repo.get(id).await?;
+        if !webhook.is_managed_by(user_id, is_admin) {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(webhook)
+    }
+
+    pub async fn delete(
+        &self,
+        user_id: UserId,
+        is_admin: bool,
+        id: WebhookId,
+    ) -> Result<(), RepositoryError> {
+        let webhook = self.get_managed(user_id, is_admin, id).await?;
+        self.webhook_repo.delete(webhook.id).await?;
+        info!(webhook_id = %webhook.id, "webhook deleted");
+        Ok(())
+    }
+
+    /// Queue a delivery of a recorded timeline event to every webhook
+    /// subscribed to it. Events about private readings only go to the
+    /// reader's own webhooks. Errors are returned so the job is retried.
+    pub async fn dispatch(&self, event_id: TimelineEventId) -> Result<(), RepositoryError> {
+        let recorded = match self.timeline_repo.get_recorded(event_id).await {
+            Ok(recorded) => recorded,
+            // Deleted along with its entity before it was dispatched
+            Err(RepositoryError::NotFound) => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        let Some(user_id) = recorded.user_id else {
+            return Ok(());
+        };
+        let event = &recorded.event;
+        let webhooks: Vec<Webhook> = self
+            .webhook_repo
+            .list_receiving(user_id)
+            .await?
+            .into_iter()
+            .filter(|webhook| webhook.is_subscribed_to(&event.action))
+            .filter(|webhook| !recorded.private || webhook.user_id == Some(user_id))
+            .collect();
+        if webhooks.is_empty() {
+            return Ok(());
+        }
+
+        let username = self.user_repo.get(user_id).await?.username;
+        let payload = event_payload(event, user_id, &username).to_string();
+        for webhook in webhooks {
+            self.queue(&webhook, &event.action, payload.clone()).await;
+        }
+        Ok(())
+    }
+
+    /// Queue a `test` event so the receiver can check its signature handling.
+    pub async fn send_test(&self, webhook: &Webhook) -> Result<(), RepositoryError> {
+        let payload = json!({
+            "event": TEST_EVENT,
+            "webhook_id": webhook.id,
+            "occurred_at": Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+        })
+        .to_string();
+        self.queue(webhook, TEST_EVENT, payload).await;
+        Ok(())
+    }
+
+    async fn queue(&self, webhook: &Webhook, event: &str, payload: String) {
+        let delivery = self
+            .webhook_repo
+            .insert_delivery(NewWebhookDelivery {
+                webhook_id: webhook.id,
+                event: event.to_string(),
+                payload,
+            })
+            .await;
+        match delivery {
+            Ok(delivery) => {
+                self.job_queue
+                    .enqueue(BackgroundJob::DeliverWebhook {
+                        delivery_id: delivery.id,
+                    })
+                    .await;
+            }
+            Err(err) => {
+                warn!(error = %err, webhook_id = %webhook.id, "failed to record webhook delivery");
+            }
+        }
+    }
+
+    /// Attempt a queued delivery. Errors make the job queue retry it.
+    pub async fn deliver(&self, delivery_id: WebhookDeliveryId) -> Result<(), String> {
+        let delivery = match self.webhook_repo.get_delivery(delivery_id).await {
+            Ok(delivery) => delivery,
+            // The webhook was deleted along with its log
+            Err(RepositoryError::NotFound) => return Ok(()),
+            Err(err) => return Err(err.to_string()),
+        };
+        let webhook = match self.webhook_repo.get(delivery.webhook_id).await {
+            Ok(webhook) => webhook,
+            Err(RepositoryError::NotFound) => return Ok(()),
+            Err(err) => return Err(err.to_string()),
+        };
+
+        // Checked again after resolving, since DNS can change after creation
+        let client = match self.owned_by_admin(&webhook).await {
+            Ok(true) => Ok(self.outbound.unchecked().clone()),
+            Ok(false) => self.outbound.client_for(&webhook.url, false).await,
+            Err(err) => return Err(err.to_string()),
+        };
+        let result = match client {
+            Ok(client) => {
+                webhooks::deliver(
+                    &client,
+                    WebhookRequest {
+                        url: &webhook.url,
+                        secret: &webhook.secret,
+                        event: &delivery.event,
+                        delivery_id: delivery.id.into_inner(),
+                        body: &delivery.payload,
+                    },
+                )
+                .await
+            }
+            Err(err) => Err(err),
+        };
+        let (attempt, outcome) = match result {
+            Ok(status) if (200..300).contains(&status) => (
+                DeliveryAttempt {
+                    status: DeliveryStatus::Delivered,
+                    response_status: Some(status),
+                    error: None,
+                },
+                Ok(()),
+            ),
+            Ok(status) => (
+                DeliveryAttempt {
+                    status: DeliveryStatus::Failed,
+                    response_status: Some(status),
+                    error: None,
+                },
+                Err(format!("webhook {} returned {status}", webhook.url)),
+            ),
+            Err(err) => {
+                let message = format!("{err:#}");
+                (
+                    DeliveryAttempt {
+                        status: DeliveryStatus::Failed,
+                        response_status: None,
+                        error: Some(message.clone()),
+                    },
+                    Err(message),
+                )
+            }
+        };
+        self.webhook_repo
+            .record_attempt(delivery.id, attempt)
+            .await
+            .map_err(|err| err.to_string())?;
+        outcome
+    }
+
+    async fn owned_by_admin(&self, webhook: &Webhook) -> Result<bool, RepositoryError> {
+        match webhook.user_id {
+            None => Ok(true),
+            Some(user_id) => Ok(self.user_repo.get(user_id).await?.is_admin),
+        }
+    }
+}
+
+/// The JSON body sent for a timeline event.
+fn event_payload(event: &TimelineEvent, user_id: UserId, username: &str) -> Value {
+    let details: serde_json::Map<String, Value> = event
+        .details
+        .iter()
+        .map(|detail| (detail.label.clone(), Value::String(detail.value.clone())))
+        .collect();
+    json!({
+        "event": event.action,
+        "entity_type": event.entity_type,
+        "entity_id": event.entity_id,
+        "title": event.title,
+        "occurred_at": event.occurred_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+        "user_id": user_id,
+        "username": username,
+        "details": details,
+    })
+}
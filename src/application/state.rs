@@ -3,24 +3,36 @@ use std::sync::Arc;
 use webauthn_rs::prelude::*;
 
 use crate::application::services::{
-    AuthorService, BookService, GenreService, ReadingService, StatsInvalidator, TimelineInvalidator,
+    AuthorService, BookService, ChallengeService, FederationService, GenreService, GoalService,
+    JobQueue, LiveUpdates, ReadingService, ShelfService, StatsInvalidator, TimelineInvalidator,
+    TimelinePublisher, UserBookService, WebhookService,
 };
 use crate::domain::repositories::{
-    AiUsageRepository, AuthorRepository, BookRepository, CoverSuggestionRepository,
-    GenreRepository, ImageRepository, PasskeyCredentialRepository, ReadingRepository,
-    RegistrationTokenRepository, SessionRepository, StatsRepository, TimelineEventRepository,
-    TokenRepository, UserBookRepository, UserRepository,
+    AiUsageRepository, AuthorRepository, BookRepository, ChallengeRepository,
+    CoverSuggestionRepository, FederationRepository, FeedTokenRepository, GenreRepository,
+    GoalRepository, ImageRepository, JobRepository, PasskeyCredentialRepository, ReadingRepository,
+    RegistrationTokenRepository, SessionRepository, ShelfRepository, StatsRepository,
+    TagRepository, TimelineEventRepository, TokenRepository, UserBookRepository, UserRepository,
+    WebhookRepository,
 };
 use crate::infrastructure::backup::BackupService;
 use crate::infrastructure::database::Database;
+use crate::infrastructure::outbound::OutboundClient;
 use crate::infrastructure::repositories::ai_usage::SqlAiUsageRepository;
 use crate::infrastructure::repositories::books::authors::SqlAuthorRepository;
 use crate::infrastructure::repositories::books::books::SqlBookRepository;
 use crate::infrastructure::repositories::books::genres::SqlGenreRepository;
 use crate::infrastructure::repositories::books::readings::SqlReadingRepository;
+use crate::infrastructure::repositories::books::shelves::SqlShelfRepository;
+use crate::infrastructure::repositories::books::tags::SqlTagRepository;
 use crate::infrastructure::repositories::books::user_books::SqlUserBookRepository;
+use crate::infrastructure::repositories::challenges::SqlChallengeRepository;
 use crate::infrastructure::repositories::cover_suggestions::SqlCoverSuggestionRepository;
+use crate::infrastructure::repositories::federation::SqlFederationRepository;
+use crate::infrastructure::repositories::feed_tokens::SqlFeedTokenRepository;
+use crate::infrastructure::repositories::goals::SqlGoalRepository;
 use crate::infrastructure::repositories::images::SqlImageRepository;
+use crate::infrastructure::repositories::jobs::SqlJobRepository;
 use crate::infrastructure::repositories::passkey_credentials::SqlPasskeyCredentialRepository;
 use crate::infrastructure::repositories::registration_tokens::SqlRegistrationTokenRepository;
 use crate::infrastructure::repositories::sessions::SqlSessionRepository;
@@ -28,6 +40,7 @@ use crate::infrastructure::repositories::stats::SqlStatsRepository;
 use crate::infrastructure::repositories::timeline_events::SqlTimelineEventRepository;
 use crate::infrastructure::repositories::tokens::SqlTokenRepository;
 use crate::infrastructure::repositories::users::SqlUserRepository;
+use crate::infrastructure::repositories::webhooks::SqlWebhookRepository;
 use crate::infrastructure::webauthn::ChallengeStore;
 
 /// Configuration for external services and auth — everything that varies
@@ -39,8 +52,9 @@ pub struct AppStateConfig {
     pub openrouter_url: String,
     pub openrouter_api_key: String,
     pub openrouter_model: String,
-    pub stats_invalidator: StatsInvalidator,
-    pub timeline_invalidator: TimelineInvalidator,
+    /// Let federation and webhooks reach loopback and private addresses,
+    /// and federate over plain http. For development and tests only.
+    pub allow_private_network: bool,
 }
 
 #[derive(Clone)]
@@ -50,9 +64,12 @@ pub struct AppState {
     pub genre_repo: Arc<dyn GenreRepository>,
     pub reading_repo: Arc<dyn ReadingRepository>,
     pub user_book_repo: Arc<dyn UserBookRepository>,
+    pub shelf_repo: Arc<dyn ShelfRepository>,
+    pub tag_repo: Arc<dyn TagRepository>,
     pub timeline_repo: Arc<dyn TimelineEventRepository>,
     pub user_repo: Arc<dyn UserRepository>,
     pub token_repo: Arc<dyn TokenRepository>,
+    pub feed_token_repo: Arc<dyn FeedTokenRepository>,
     pub session_repo: Arc<dyn SessionRepository>,
     pub passkey_repo: Arc<dyn PasskeyCredentialRepository>,
     pub registration_token_repo: Arc<dyn RegistrationTokenRepository>,
@@ -60,6 +77,10 @@ pub struct AppState {
     pub image_repo: Arc<dyn ImageRepository>,
     pub cover_suggestion_repo: Arc<dyn CoverSuggestionRepository>,
     pub stats_repo: Arc<dyn StatsRepository>,
+    pub goal_repo: Arc<dyn GoalRepository>,
+    pub challenge_repo: Arc<dyn ChallengeRepository>,
+    pub federation_repo: Arc<dyn FederationRepository>,
+    pub webhook_repo: Arc<dyn WebhookRepository>,
     pub webauthn: Arc<Webauthn>,
     pub challenge_store: Arc<ChallengeStore>,
     pub http_client: reqwest::Client,
@@ -71,15 +92,25 @@ pub struct AppState {
     pub genre_service: GenreService,
     pub book_service: BookService,
     pub reading_service: ReadingService,
+    pub shelf_service: ShelfService,
+    pub user_book_service: UserBookService,
+    pub goal_service: GoalService,
+    pub challenge_service: ChallengeService,
+    pub federation: FederationService,
+    pub webhooks: WebhookService,
     pub insecure_cookies: bool,
+    pub job_queue: JobQueue,
     pub stats_invalidator: StatsInvalidator,
     pub timeline_invalidator: TimelineInvalidator,
+    pub live_updates: LiveUpdates,
+    pub timeline_publisher: TimelinePublisher,
     pub image_semaphore: Arc<tokio::sync::Semaphore>,
 }
 
 impl AppState {
     /// Build the full application state from a database connection and config.
     /// Creates all repositories and services internally.
+    #[allow(clippy::too_many_lines)] // one binding per repository and service
     pub fn from_database(database: &Database, config: AppStateConfig) -> Self {
         let pool = database.clone_pool();
 
@@ -91,10 +122,14 @@ impl AppState {
             Arc::new(SqlReadingRepository::new(pool.clone()));
         let user_book_repo: Arc<dyn UserBookRepository> =
             Arc::new(SqlUserBookRepository::new(pool.clone()));
+        let shelf_repo: Arc<dyn ShelfRepository> = Arc::new(SqlShelfRepository::new(pool.clone()));
+        let tag_repo: Arc<dyn TagRepository> = Arc::new(SqlTagRepository::new(pool.clone()));
         let timeline_repo: Arc<dyn TimelineEventRepository> =
             Arc::new(SqlTimelineEventRepository::new(pool.clone()));
         let user_repo: Arc<dyn UserRepository> = Arc::new(SqlUserRepository::new(pool.clone()));
         let token_repo: Arc<dyn TokenRepository> = Arc::new(SqlTokenRepository::new(pool.clone()));
+        let feed_token_repo: Arc<dyn FeedTokenRepository> =
+            Arc::new(SqlFeedTokenRepository::new(pool.clone()));
         let session_repo: Arc<dyn SessionRepository> =
             Arc::new(SqlSessionRepository::new(pool.clone()));
         let passkey_repo: Arc<dyn PasskeyCredentialRepository> =
@@ -107,17 +142,42 @@ impl AppState {
         let cover_suggestion_repo: Arc<dyn CoverSuggestionRepository> =
             Arc::new(SqlCoverSuggestionRepository::new(pool.clone()));
         let stats_repo: Arc<dyn StatsRepository> = Arc::new(SqlStatsRepository::new(pool.clone()));
+        let goal_repo: Arc<dyn GoalRepository> = Arc::new(SqlGoalRepository::new(pool.clone()));
+        let challenge_repo: Arc<dyn ChallengeRepository> =
+            Arc::new(SqlChallengeRepository::new(pool.clone()));
+        let federation_repo: Arc<dyn FederationRepository> =
+            Arc::new(SqlFederationRepository::new(pool.clone()));
+        let webhook_repo: Arc<dyn WebhookRepository> =
+            Arc::new(SqlWebhookRepository::new(pool.clone()));
+        let job_repo: Arc<dyn JobRepository> = Arc::new(SqlJobRepository::new(pool.clone()));
+        #[allow(clippy::expect_used)]
+        let http_client = reqwest::ClientBuilder::new()
+            .timeout(std::time::Duration::from_secs(30))
+            .build()
+            .expect("failed to build HTTP client");
+        let outbound = OutboundClient::new(http_client.clone(), config.allow_private_network);
 
         let backup_service = Arc::new(BackupService::new(pool));
+        let live_updates = LiveUpdates::new();
+        let job_queue = JobQueue::new(job_repo);
+        let timeline_publisher = TimelinePublisher::new(live_updates.clone(), job_queue.clone());
 
-        let author_service =
-            AuthorService::new(Arc::clone(&author_repo), Arc::clone(&timeline_repo));
-        let genre_service = GenreService::new(Arc::clone(&genre_repo), Arc::clone(&timeline_repo));
+        let author_service = AuthorService::new(
+            Arc::clone(&author_repo),
+            Arc::clone(&timeline_repo),
+            timeline_publisher.clone(),
+        );
+        let genre_service = GenreService::new(
+            Arc::clone(&genre_repo),
+            Arc::clone(&timeline_repo),
+            timeline_publisher.clone(),
+        );
         let book_service = BookService::new(
             Arc::clone(&book_repo),
             Arc::clone(&author_repo),
             Arc::clone(&genre_repo),
             Arc::clone(&timeline_repo),
+            timeline_publisher.clone(),
         );
         let reading_service = ReadingService::new(
             Arc::clone(&reading_repo),
@@ -125,6 +185,43 @@ impl AppState {
             Arc::clone(&author_repo),
             Arc::clone(&timeline_repo),
             Arc::clone(&user_book_repo),
+            timeline_publisher.clone(),
+        );
+        let shelf_service = ShelfService::new(
+            Arc::clone(&shelf_repo),
+            Arc::clone(&book_repo),
+            Arc::clone(&user_book_repo),
+        );
+        let user_book_service = UserBookService::new(
+            Arc::clone(&user_book_repo),
+            Arc::clone(&book_repo),
+            Arc::clone(&author_repo),
+            Arc::clone(&timeline_repo),
+            timeline_publisher.clone(),
+        );
+        let goal_service = GoalService::new(
+            Arc::clone(&goal_repo),
+            Arc::clone(&timeline_repo),
+            timeline_publisher.clone(),
+        );
+        let challenge_service =
+            ChallengeService::new(Arc::clone(&challenge_repo), Arc::clone(&reading_repo));
+        let stats_invalidator = StatsInvalidator::new(job_queue.clone());
+        let timeline_invalidator = TimelineInvalidator::new(job_queue.clone());
+        let federation = FederationService::new(
+            Arc::clone(&federation_repo),
+            Arc::clone(&user_repo),
+            Arc::clone(&reading_repo),
+            Arc::clone(&image_repo),
+            job_queue.clone(),
+            outbound.clone(),
+        );
+        let webhooks = WebhookService::new(
+            Arc::clone(&webhook_repo),
+            Arc::clone(&user_repo),
+            Arc::clone(&timeline_repo),
+            job_queue.clone(),
+            outbound,
         );
         Self {
             author_repo,
@@ -132,9 +229,12 @@ impl AppState {
             genre_repo,
             reading_repo,
             user_book_repo,
+            shelf_repo,
+            tag_repo,
             timeline_repo,
             user_repo,
             token_repo,
+            feed_token_repo,
             session_repo,
             passkey_repo,
             registration_token_repo,
@@ -142,13 +242,13 @@ impl AppState {
             image_repo,
             cover_suggestion_repo,
             stats_repo,
+            goal_repo,
+            challenge_repo,
+            federation_repo,
+            webhook_repo,
             webauthn: config.webauthn,
             challenge_store: Arc::new(ChallengeStore::new()),
-            #[allow(clippy::expect_used)]
-            http_client: reqwest::ClientBuilder::new()
-                .timeout(std::time::Duration::from_secs(30))
-                .build()
-                .expect("failed to build HTTP client"),
+            http_client,
             openrouter_url: config.openrouter_url,
             openrouter_api_key: config.openrouter_api_key,
             openrouter_model: config.openrouter_model,
@@ -157,9 +257,18 @@ impl AppState {
             genre_service,
             book_service,
             reading_service,
+            shelf_service,
+            user_book_service,
+            goal_service,
+            challenge_service,
+            federation,
+            webhooks,
             insecure_cookies: config.insecure_cookies,
-            stats_invalidator: config.stats_invalidator,
-            timeline_invalidator: config.timeline_invalidator,
+            job_queue,
+            stats_invalidator,
+            timeline_invalidator,
+            live_updates,
+            timeline_publisher,
             image_semaphore: Arc::new(tokio::sync::Semaphore::new(4)),
         }
     }
@@ -0,0 +1,265 @@
+use std::collections::BTreeMap;
+
+use chrono::{Days, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::readings::ReadingStatus;
+
+/// The dates recorded on one reading, enough to tell which days it was on the go.
+#[derive(Debug, Clone)]
+pub struct ReadingSpan {
+    pub status: ReadingStatus,
+    pub started_at: Option<NaiveDate>,
+    pub finished_at: Option<NaiveDate>,
+}
+
+impl ReadingSpan {
+    /// First and last day the reading was in progress. Unfinished readings run
+    /// until `today`; abandoned ones without an end date only count the day
+    /// they were started.
+    fn days(&self, today: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+        match (self.started_at, self.finished_at) {
+            (Some(start), Some(end)) if start <= end => Some((start, end)),
+            // Inconsistent dates: only trust the finish date
+            (Some(_) | None, Some(end)) => Some((end, end)),
+            (Some(start), None) if self.status == ReadingStatus::Reading => {
+                Some((start, today.max(start)))
+            }
+            (Some(start), None) => Some((start, start)),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Reading activity on a single day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityDay {
+    pub date: NaiveDate,
+    /// Readings started on this day.
+    pub started: u32,
+    /// Readings finished on this day.
+    pub finished: u32,
+    /// Readings in progress on this day, including ones started or finished on it.
+    pub in_progress: u32,
+}
+
+impl ActivityDay {
+    fn empty(date: NaiveDate) -> Self {
+        Self {
+            date,
+            started: 0,
+            finished: 0,
+            in_progress: 0,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.in_progress > 0
+    }
+
+    /// Heatmap intensity from 0 (nothing on the go) to 4. Days with a book in
+    /// progress are 1; each start or finish adds a level.
+    pub fn level(&self) -> u8 {
+        if !self.is_active() {
+            return 0;
+        }
+        let events = self.started + self.finished;
+        u8::try_from(events.min(3)).unwrap_or(3) + 1
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadingStreaks {
+    /// Consecutive days with a book on the go, ending today. A streak that
+    /// ran until yesterday still counts until the day is over.
+    pub current: u32,
+    pub longest: u32,
+    /// Last day of the longest streak, the most recent one on ties.
+    pub longest_ended_on: Option<NaiveDate>,
+}
+
+/// Day-by-day reading activity over a date range, with streaks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadingActivity {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    /// One entry per day from `from` to `to`, inclusive.
+    pub days: Vec<ActivityDay>,
+    pub active_days: u32,
+    pub books_started: u32,
+    pub books_finished: u32,
+    /// Streaks are measured over the whole reading history, not just the range.
+    pub streaks: ReadingStreaks,
+}
+
+impl ReadingActivity {
+    pub fn build(spans: &[ReadingSpan], from: NaiveDate, to: NaiveDate, today: NaiveDate) -> Self {
+        let history = daily_activity(spans, today);
+
+        let days: Vec<ActivityDay> = from
+            .iter_days()
+            .take_while(|date| *date <= to)
+            .map(|date| {
+                history
+                    .get(&date)
+                    .copied()
+                    .unwrap_or(ActivityDay::empty(date))
+            })
+            .collect();
+
+        Self {
+            from,
+            to,
+            active_days: count(days.iter().filter(|d| d.is_active())),
+            books_started: days.iter().map(|d| d.started).sum(),
+            books_finished: days.iter().map(|d| d.finished).sum(),
+            days,
+            streaks: streaks(&history, today),
+        }
+    }
+}
+
+fn count<T>(items: impl Iterator<Item = T>) -> u32 {
+    u32::try_from(items.count()).unwrap_or(u32::MAX)
+}
+
+fn daily_activity(spans: &[ReadingSpan], today: NaiveDate) -> BTreeMap<NaiveDate, ActivityDay> {
+    let mut history = BTreeMap::new();
+    for span in spans {
+        let Some((start, end)) = span.days(today) else {
+            continue;
+        };
+        for date in start.iter_days().take_while(|date| *date <= end) {
+            history
+                .entry(date)
+                .or_insert_with(|| ActivityDay::empty(date))
+                .in_progress += 1;
+        }
+        if let Some(date) = span.started_at.filter(|d| *d <= end) {
+            history
+                .entry(date)
+                .or_insert_with(|| ActivityDay::empty(date))
+                .started += 1;
+        }
+        if let Some(date) = span.finished_at {
+            history
+                .entry(date)
+                .or_insert_with(|| ActivityDay::empty(date))
+                .finished += 1;
+        }
+    }
+    history
+}
+
+fn streaks(history: &BTreeMap<NaiveDate, ActivityDay>, today: NaiveDate) -> ReadingStreaks {
+    let mut result = ReadingStreaks::default();
+    let mut run = 0;
+    let mut previous: Option<NaiveDate> = None;
+
+    for day in history
+        .values()
+        .filter(|d| d.is_active() && d.date <= today)
+    {
+        let consecutive = previous.and_then(|p| p.checked_add_days(Days::new(1))) == Some(day.date);
+        run = if consecutive { run + 1 } else { 1 };
+        if run >= result.longest {
+            result.longest = run;
+            result.longest_ended_on = Some(day.date);
+        }
+        previous = Some(day.date);
+    }
+
+    let yesterday = today.pred_opt();
+    if previous == Some(today) || (previous.is_some() && previous == yesterday) {
+        result.current = run;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, m, d).unwrap()
+    }
+
+    fn span(
+        status: ReadingStatus,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> ReadingSpan {
+        ReadingSpan {
+            status,
+            started_at: start,
+            finished_at: end,
+        }
+    }
+
+    #[test]
+    fn days_count_starts_finishes_and_books_in_progress() {
+        let spans = [
+            span(ReadingStatus::Read, Some(date(3, 1)), Some(date(3, 3))),
+            span(ReadingStatus::Read, Some(date(3, 3)), Some(date(3, 4))),
+        ];
+        let activity = ReadingActivity::build(&spans, date(2, 28), date(3, 5), date(6, 1));
+
+        assert_eq!(activity.days.len(), 6);
+        let on = |d| activity.days.iter().find(|a| a.date == d).copied().unwrap();
+        assert_eq!(on(date(2, 28)).level(), 0);
+        assert_eq!(on(date(3, 1)).started, 1);
+        assert_eq!(on(date(3, 2)).level(), 1);
+        let busy = on(date(3, 3));
+        assert_eq!((busy.started, busy.finished, busy.in_progress), (1, 1, 2));
+        assert_eq!(busy.level(), 3);
+        assert_eq!(activity.active_days, 4);
+        assert_eq!(activity.books_started, 2);
+        assert_eq!(activity.books_finished, 2);
+    }
+
+    #[test]
+    fn unfinished_readings_run_until_today() {
+        let spans = [
+            span(ReadingStatus::Reading, Some(date(5, 28)), None),
+            span(ReadingStatus::Abandoned, Some(date(4, 1)), None),
+            span(ReadingStatus::Read, None, None),
+        ];
+        let activity = ReadingActivity::build(&spans, date(4, 1), date(6, 1), date(6, 1));
+
+        assert_eq!(activity.active_days, 6);
+        assert_eq!(activity.streaks.current, 5);
+        assert_eq!(activity.streaks.longest, 5);
+    }
+
+    #[test]
+    fn streaks_span_the_whole_history() {
+        let spans = [
+            span(ReadingStatus::Read, Some(date(1, 1)), Some(date(1, 10))),
+            span(ReadingStatus::Read, Some(date(2, 1)), Some(date(2, 3))),
+            // Overlapping readings don't double-count days
+            span(ReadingStatus::Read, Some(date(2, 2)), Some(date(2, 4))),
+        ];
+
+        let streaks = ReadingActivity::build(&spans, date(2, 1), date(2, 28), date(2, 5)).streaks;
+        assert_eq!(streaks.longest, 10);
+        assert_eq!(streaks.longest_ended_on, Some(date(1, 10)));
+        // Still current the day after the last active day
+        assert_eq!(streaks.current, 4);
+
+        let later = ReadingActivity::build(&spans, date(2, 1), date(2, 28), date(2, 6)).streaks;
+        assert_eq!(later.current, 0);
+    }
+
+    #[test]
+    fn inconsistent_dates_only_count_the_finish() {
+        let spans = [span(
+            ReadingStatus::Read,
+            Some(date(3, 5)),
+            Some(date(3, 1)),
+        )];
+        let activity = ReadingActivity::build(&spans, date(3, 1), date(3, 5), date(6, 1));
+        assert_eq!(activity.active_days, 1);
+        assert_eq!(activity.books_started, 0);
+        assert_eq!(activity.books_finished, 1);
+    }
+}
@@ -0,0 +1,327 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::filters::{FilterQuery, FilterTarget};
+use crate::domain::ids::{ChallengeId, ChallengePromptId, ReadingId, UserId};
+use crate::domain::readings::ReadingWithBook;
+
+/// A named list of reading prompts, e.g. "2026 Reading Challenge".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Challenge {
+    pub id: ChallengeId,
+    /// The user who created the challenge and may edit it.
+    pub user_id: UserId,
+    pub name: String,
+    pub description: Option<String>,
+    /// When set, only readings finished in this year count.
+    pub year: Option<i32>,
+    /// Shared challenges are visible to every user on the instance.
+    pub shared: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Challenge {
+    pub fn is_visible_to(&self, user_id: UserId) -> bool {
+        self.shared || self.user_id == user_id
+    }
+
+    /// Owners can always edit; admins can also look after shared challenges.
+    pub fn is_editable_by(&self, user_id: UserId, is_admin: bool) -> bool {
+        self.user_id == user_id || (self.shared && is_admin)
+    }
+
+    /// First and last day a reading may be finished on to count.
+    pub fn period(&self) -> Option<(NaiveDate, NaiveDate)> {
+        let year = self.year?;
+        Some((
+            NaiveDate::from_ymd_opt(year, 1, 1)?,
+            NaiveDate::from_ymd_opt(year, 12, 31)?,
+        ))
+    }
+
+    /// Whether a reading finished on `finished_at` falls inside the challenge year.
+    pub fn accepts(&self, finished_at: Option<NaiveDate>) -> bool {
+        match (self.period(), finished_at) {
+            (None, _) => true,
+            (Some((start, end)), Some(date)) => (start..=end).contains(&date),
+            (Some(_), None) => false,
+        }
+    }
+}
+
+/// One prompt in a challenge, e.g. "A book over 600 pages".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChallengePrompt {
+    pub id: ChallengePromptId,
+    pub challenge_id: ChallengeId,
+    pub position: i64,
+    pub label: String,
+    /// Filter query that finished readings are matched against, e.g.
+    /// `pages>600` or `-language:english`. Prompts without a rule are only
+    /// filled by hand.
+    pub rule: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChallengeWithPrompts {
+    #[serde(flatten)]
+    pub challenge: Challenge,
+    pub prompts: Vec<ChallengePrompt>,
+}
+
+/// Parses a prompt rule. Rules are limited to book and format fields so that
+/// they mean the same thing for every user, and may not contain free text.
+pub fn parse_rule(rule: &str) -> Result<FilterQuery, String> {
+    let query =
+        FilterQuery::parse(rule, FilterTarget::ChallengePrompts).map_err(|err| err.to_string())?;
+    if let Some(text) = &query.text {
+        return Err(format!(
+            "prompt rules only accept field filters such as pages>600, found \"{text}\""
+        ));
+    }
+    Ok(query)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewChallengePrompt {
+    pub label: String,
+    #[serde(default)]
+    pub rule: Option<String>,
+}
+
+impl NewChallengePrompt {
+    pub fn normalize(mut self) -> Self {
+        self.label = self.label.trim().to_string();
+        self.rule = non_blank(self.rule);
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.label.is_empty() {
+            return Err("prompt label cannot be empty".to_string());
+        }
+        if let Some(rule) = &self.rule {
+            parse_rule(rule)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewChallenge {
+    pub user_id: UserId,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub year: Option<i32>,
+    #[serde(default)]
+    pub shared: bool,
+    #[serde(default)]
+    pub prompts: Vec<NewChallengePrompt>,
+}
+
+impl NewChallenge {
+    pub fn normalize(mut self) -> Self {
+        self.name = self.name.trim().to_string();
+        self.description = non_blank(self.description);
+        self.prompts = self
+            .prompts
+            .into_iter()
+            .map(NewChallengePrompt::normalize)
+            .collect();
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.is_empty() {
+            return Err("challenge name cannot be empty".to_string());
+        }
+        validate_year(self.year)?;
+        self.prompts
+            .iter()
+            .try_for_each(NewChallengePrompt::validate)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateChallenge {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// An empty description clears it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// `None` = don't change, `Some(None)` = clear, `Some(Some(year))` = set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub year: Option<Option<i32>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared: Option<bool>,
+}
+
+impl UpdateChallenge {
+    pub fn normalize(mut self) -> Self {
+        self.name = self.name.map(|n| n.trim().to_string());
+        self.description = self.description.map(|d| d.trim().to_string());
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.as_deref() == Some("") {
+            return Err("challenge name cannot be empty".to_string());
+        }
+        validate_year(self.year.flatten())
+    }
+}
+
+fn validate_year(year: Option<i32>) -> Result<(), String> {
+    match year {
+        Some(year) if !(1..=9999).contains(&year) => {
+            Err("challenge year must be between 1 and 9999".to_string())
+        }
+        _ => Ok(()),
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateChallengePrompt {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// An empty rule turns the prompt into a manual-only one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule: Option<String>,
+}
+
+impl UpdateChallengePrompt {
+    pub fn normalize(mut self) -> Self {
+        self.label = self.label.map(|l| l.trim().to_string());
+        self.rule = self.rule.map(|r| r.trim().to_string());
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.label.as_deref() == Some("") {
+            return Err("prompt label cannot be empty".to_string());
+        }
+        match self.rule.as_deref() {
+            Some(rule) if !rule.is_empty() => parse_rule(rule).map(|_| ()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A user's reading assigned to a prompt.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChallengeEntry {
+    pub challenge_id: ChallengeId,
+    pub prompt_id: ChallengePromptId,
+    pub user_id: UserId,
+    pub reading_id: ReadingId,
+    pub assigned_at: DateTime<Utc>,
+}
+
+/// A prompt from one user's point of view.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptProgress {
+    #[serde(flatten)]
+    pub prompt: ChallengePrompt,
+    /// The reading assigned to this prompt, if any.
+    pub entry: Option<ReadingWithBook>,
+    /// Finished readings matching the prompt's rule that are not yet assigned
+    /// to another prompt. Empty once the prompt is filled.
+    pub suggestions: Vec<ReadingWithBook>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChallengeProgress {
+    #[serde(flatten)]
+    pub challenge: Challenge,
+    pub prompts: Vec<PromptProgress>,
+    pub completed: usize,
+    pub total: usize,
+}
+
+/// A challenge with a user's completion counts, for list views.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChallengeSummary {
+    #[serde(flatten)]
+    pub challenge: Challenge,
+    pub completed: usize,
+    pub total: usize,
+}
+
+fn non_blank(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn challenge(year: Option<i32>) -> Challenge {
+        Challenge {
+            id: ChallengeId::new(1),
+            user_id: UserId::new(1),
+            name: "Challenge".to_string(),
+            description: None,
+            year,
+            shared: false,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn rules_accept_book_and_format_fields() {
+        for rule in [
+            "pages>600",
+            "year<1900",
+            "-language:english",
+            "genre:fantasy format:audiobook",
+        ] {
+            assert!(parse_rule(rule).is_ok(), "{rule}");
+        }
+    }
+
+    #[test]
+    fn rules_reject_personal_fields_and_free_text() {
+        assert!(parse_rule("rating>=4").is_err());
+        assert!(parse_rule("tag:cosy").is_err());
+        let err = parse_rule("pages>600 dragons").unwrap_err();
+        assert!(err.contains("dragons"));
+    }
+
+    #[test]
+    fn normalize_drops_blank_rules() {
+        let prompt = NewChallengePrompt {
+            label: "  A book a friend recommended ".to_string(),
+            rule: Some("  ".to_string()),
+        }
+        .normalize();
+        assert_eq!(prompt.label, "A book a friend recommended");
+        assert_eq!(prompt.rule, None);
+        assert!(prompt.validate().is_ok());
+    }
+
+    #[test]
+    fn challenge_year_limits_finish_dates() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+        let yearly = challenge(Some(2025));
+        assert!(yearly.accepts(date(2025, 12, 31)));
+        assert!(!yearly.accepts(date(2024, 12, 31)));
+        assert!(!yearly.accepts(None));
+        assert!(challenge(None).accepts(None));
+    }
+
+    #[test]
+    fn visibility_follows_sharing() {
+        let mut c = challenge(None);
+        assert!(c.is_visible_to(UserId::new(1)));
+        assert!(!c.is_visible_to(UserId::new(2)));
+        assert!(!c.is_editable_by(UserId::new(2), true));
+        c.shared = true;
+        assert!(c.is_visible_to(UserId::new(2)));
+        assert!(c.is_editable_by(UserId::new(2), true));
+        assert!(!c.is_editable_by(UserId::new(2), false));
+    }
+}
@@ -0,0 +1,445 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::ids::{GenreId, GoalId, UserId};
+use crate::domain::readings::ReadingFormat;
+use crate::domain::timeline::{
+    NewTimelineEvent, TimelineEvent, TimelineEventChange, TimelineEventDetail, TimelineField,
+};
+
+/// Percentages of a goal's target recorded on the timeline as they're reached.
+pub const GOAL_MILESTONES: [u8; 4] = [25, 50, 75, 100];
+
+/// What a reading goal counts.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum GoalMetric {
+    /// Finished readings.
+    #[default]
+    Books,
+    /// Page counts of finished readings.
+    Pages,
+}
+
+impl GoalMetric {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GoalMetric::Books => "books",
+            GoalMetric::Pages => "pages",
+        }
+    }
+
+    /// Unit label for an amount, e.g. "1 book" or "250 pages".
+    pub fn format_amount(&self, amount: i64) -> String {
+        let unit = match (self, amount.abs() == 1) {
+            (GoalMetric::Books, true) => "book",
+            (GoalMetric::Books, false) => "books",
+            (GoalMetric::Pages, true) => "page",
+            (GoalMetric::Pages, false) => "pages",
+        };
+        format!("{amount} {unit}")
+    }
+}
+
+impl FromStr for GoalMetric {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "books" | "book" => Ok(GoalMetric::Books),
+            "pages" | "page" => Ok(GoalMetric::Pages),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadingGoal {
+    pub id: GoalId,
+    pub user_id: UserId,
+    pub metric: GoalMetric,
+    pub target: i64,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    /// Only count books in this genre or one of its subgenres.
+    pub genre_id: Option<GenreId>,
+    /// Only count readings in this format.
+    pub format: Option<ReadingFormat>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ReadingGoal {
+    /// The calendar year this goal covers, if it spans exactly one.
+    pub fn calendar_year(&self) -> Option<i32> {
+        calendar_year(self.start_date, self.end_date)
+    }
+
+    /// "2026" for calendar-year goals, otherwise the date range.
+    pub fn period_label(&self) -> String {
+        if let Some(year) = self.calendar_year() {
+            return year.to_string();
+        }
+        if self.start_date.year() == self.end_date.year() {
+            format!(
+                "{} – {}",
+                self.start_date.format("%b %-d"),
+                self.end_date.format("%b %-d, %Y")
+            )
+        } else {
+            format!(
+                "{} – {}",
+                self.start_date.format("%b %-d, %Y"),
+                self.end_date.format("%b %-d, %Y")
+            )
+        }
+    }
+
+    pub fn is_active(&self, today: NaiveDate) -> bool {
+        (self.start_date..=self.end_date).contains(&today)
+    }
+}
+
+/// First and last day of `year`.
+pub fn year_range(year: i32) -> Option<(NaiveDate, NaiveDate)> {
+    Some((
+        NaiveDate::from_ymd_opt(year, 1, 1)?,
+        NaiveDate::from_ymd_opt(year, 12, 31)?,
+    ))
+}
+
+fn calendar_year(start: NaiveDate, end: NaiveDate) -> Option<i32> {
+    (year_range(start.year()) == Some((start, end))).then_some(start.year())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewReadingGoal {
+    pub user_id: UserId,
+    #[serde(default)]
+    pub metric: GoalMetric,
+    pub target: i64,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    #[serde(default)]
+    pub genre_id: Option<GenreId>,
+    #[serde(default)]
+    pub format: Option<ReadingFormat>,
+}
+
+impl NewReadingGoal {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_goal(self.target, self.start_date, self.end_date)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateReadingGoal {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metric: Option<GoalMetric>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<NaiveDate>,
+    /// `None` = don't change, `Some(None)` = clear, `Some(Some(id))` = set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub genre_id: Option<Option<GenreId>>,
+    /// `None` = don't change, `Some(None)` = clear, `Some(Some(format))` = set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<Option<ReadingFormat>>,
+}
+
+impl UpdateReadingGoal {
+    /// Validates the goal that results from applying these changes to `existing`.
+    pub fn validate_against(&self, existing: &ReadingGoal) -> Result<(), String> {
+        validate_goal(
+            self.target.unwrap_or(existing.target),
+            self.start_date.unwrap_or(existing.start_date),
+            self.end_date.unwrap_or(existing.end_date),
+        )
+    }
+}
+
+fn validate_goal(target: i64, start_date: NaiveDate, end_date: NaiveDate) -> Result<(), String> {
+    if target <= 0 {
+        return Err("goal target must be greater than zero".to_string());
+    }
+    if end_date < start_date {
+        return Err("goal end date cannot be before its start date".to_string());
+    }
+    Ok(())
+}
+
+/// Where progress stands against a straight-line pace from start to end date.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GoalPace {
+    /// The goal period has not started yet.
+    Upcoming,
+    Ahead,
+    OnTrack,
+    Behind,
+    /// The target has been reached.
+    Complete,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoalProgress {
+    #[serde(flatten)]
+    pub goal: ReadingGoal,
+    /// Books or pages counted so far.
+    pub current: i64,
+    /// Where a linear pace would put progress today.
+    pub expected: i64,
+    /// `current - expected`: positive when ahead of schedule, negative when behind.
+    pub ahead_by: i64,
+    /// Percentage of the target reached, capped at 100.
+    pub percent: u8,
+    /// Days left in the goal period, including today.
+    pub days_remaining: i64,
+    pub pace: GoalPace,
+}
+
+impl GoalProgress {
+    pub fn new(goal: ReadingGoal, current: i64, today: NaiveDate) -> Self {
+        let total_days = (goal.end_date - goal.start_date).num_days() + 1;
+        let elapsed_days = if today < goal.start_date {
+            0
+        } else {
+            ((today.min(goal.end_date) - goal.start_date).num_days() + 1).min(total_days)
+        };
+        let expected = (goal.target * elapsed_days + total_days / 2) / total_days;
+        let ahead_by = current - expected;
+        let percent = ((current.max(0) * 100) / goal.target).min(100) as u8;
+        let days_remaining = if today > goal.end_date {
+            0
+        } else {
+            (goal.end_date - today.max(goal.start_date)).num_days() + 1
+        };
+
+        let pace = if current >= goal.target {
+            GoalPace::Complete
+        } else if today < goal.start_date {
+            GoalPace::Upcoming
+        } else if ahead_by > 0 {
+            GoalPace::Ahead
+        } else if ahead_by < 0 {
+            GoalPace::Behind
+        } else {
+            GoalPace::OnTrack
+        };
+
+        Self {
+            goal,
+            current,
+            expected,
+            ahead_by,
+            percent,
+            days_remaining,
+            pace,
+        }
+    }
+
+    /// Human-readable pace, e.g. "Ahead of schedule by 2 books".
+    pub fn pace_label(&self) -> String {
+        let metric = self.goal.metric;
+        match self.pace {
+            GoalPace::Upcoming => format!("Starts {}", self.goal.start_date.format("%b %-d")),
+            GoalPace::Complete => "Goal reached".to_string(),
+            GoalPace::OnTrack => "On schedule".to_string(),
+            GoalPace::Ahead => format!(
+                "Ahead of schedule by {}",
+                metric.format_amount(self.ahead_by)
+            ),
+            GoalPace::Behind => format!(
+                "Behind schedule by {}",
+                metric.format_amount(-self.ahead_by)
+            ),
+        }
+    }
+
+    /// The highest of `GOAL_MILESTONES` reached so far.
+    pub fn milestone(&self) -> Option<u8> {
+        GOAL_MILESTONES
+            .iter()
+            .rev()
+            .copied()
+            .find(|m| self.percent >= *m)
+    }
+}
+
+/// The milestone a `goal_milestone_event` or `reading_milestone_event`
+/// recorded.
+pub fn recorded_milestone(event: &TimelineEvent) -> Option<u8> {
+    event
+        .changes
+        .first()?
+        .after
+        .as_deref()?
+        .trim_end_matches('%')
+        .parse()
+        .ok()
+}
+
+/// An event for a goal passing a milestone beyond `previous`, the last one
+/// recorded. Returns `None` when no new milestone was reached.
+pub fn goal_milestone_event(
+    progress: &GoalProgress,
+    previous: Option<u8>,
+    occurred_at: DateTime<Utc>,
+) -> Option<NewTimelineEvent> {
+    let milestone = progress.milestone().filter(|m| Some(*m) > previous)?;
+    let goal = &progress.goal;
+    Some(NewTimelineEvent {
+        user_id: Some(goal.user_id),
+        entity_type: "goal".to_string(),
+        entity_id: goal.id.into_inner(),
+        action: "milestone".to_string(),
+        occurred_at,
+        title: format!("{} reading goal", goal.period_label()),
+        details: vec![TimelineEventDetail::new(
+            TimelineField::Progress,
+            format!(
+                "{} of {}",
+                progress.current,
+                goal.metric.format_amount(goal.target)
+            ),
+        )],
+        genres: vec![],
+        reading_data: None,
+        changes: vec![TimelineEventChange::new(
+            TimelineField::Milestone,
+            previous.map(|m| format!("{m}%")),
+            Some(format!("{milestone}%")),
+        )],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn goal(metric: GoalMetric, target: i64, start: NaiveDate, end: NaiveDate) -> ReadingGoal {
+        ReadingGoal {
+            id: GoalId::new(1),
+            user_id: UserId::new(1),
+            metric,
+            target,
+            start_date: start,
+            end_date: end,
+            genre_id: None,
+            format: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn progress_compares_against_linear_pace() {
+        let (start, end) = year_range(2025).unwrap();
+        let g = goal(GoalMetric::Books, 365, start, end);
+
+        let progress = GoalProgress::new(g.clone(), 40, date(2025, 2, 9));
+        assert_eq!(progress.expected, 40);
+        assert_eq!(progress.pace, GoalPace::OnTrack);
+
+        let progress = GoalProgress::new(g.clone(), 45, date(2025, 2, 9));
+        assert_eq!(progress.ahead_by, 5);
+        assert_eq!(progress.pace_label(), "Ahead of schedule by 5 books");
+
+        let progress = GoalProgress::new(g, 39, date(2025, 2, 9));
+        assert_eq!(progress.pace_label(), "Behind schedule by 1 book");
+        assert_eq!(progress.percent, 10);
+        assert_eq!(progress.days_remaining, 326);
+    }
+
+    #[test]
+    fn progress_before_and_after_the_period() {
+        let g = goal(GoalMetric::Pages, 1000, date(2025, 6, 1), date(2025, 6, 30));
+
+        let upcoming = GoalProgress::new(g.clone(), 0, date(2025, 5, 20));
+        assert_eq!(upcoming.pace, GoalPace::Upcoming);
+        assert_eq!(upcoming.expected, 0);
+        assert_eq!(upcoming.days_remaining, 30);
+
+        let missed = GoalProgress::new(g.clone(), 600, date(2025, 7, 15));
+        assert_eq!(missed.expected, 1000);
+        assert_eq!(missed.pace_label(), "Behind schedule by 400 pages");
+        assert_eq!(missed.days_remaining, 0);
+
+        let done = GoalProgress::new(g, 1200, date(2025, 6, 10));
+        assert_eq!(done.pace, GoalPace::Complete);
+        assert_eq!(done.percent, 100);
+    }
+
+    #[test]
+    fn milestone_events_only_record_new_milestones() {
+        let (start, end) = year_range(2026).unwrap();
+        let g = goal(GoalMetric::Books, 12, start, end);
+        let today = date(2026, 6, 1);
+
+        assert_eq!(GoalProgress::new(g.clone(), 2, today).milestone(), None);
+        let halfway = GoalProgress::new(g.clone(), 7, today);
+        assert_eq!(halfway.milestone(), Some(50));
+
+        let event = goal_milestone_event(&halfway, Some(25), Utc::now()).unwrap();
+        assert_eq!(event.title, "2026 reading goal");
+        assert_eq!(event.details[0].value, "7 of 12 books");
+        assert_eq!(event.changes[0].before.as_deref(), Some("25%"));
+        assert_eq!(event.changes[0].after.as_deref(), Some("50%"));
+        assert!(goal_milestone_event(&halfway, Some(50), Utc::now()).is_none());
+
+        let recorded = TimelineEvent {
+            id: crate::domain::ids::TimelineEventId::from(1),
+            entity_type: event.entity_type,
+            entity_id: event.entity_id,
+            action: event.action,
+            occurred_at: event.occurred_at,
+            title: event.title,
+            details: event.details,
+            genres: event.genres,
+            reading_data: None,
+            changes: event.changes,
+        };
+        assert_eq!(recorded_milestone(&recorded), Some(50));
+    }
+
+    #[test]
+    fn period_label_prefers_calendar_years() {
+        let (start, end) = year_range(2026).unwrap();
+        assert_eq!(
+            goal(GoalMetric::Books, 1, start, end).period_label(),
+            "2026"
+        );
+        let summer = goal(GoalMetric::Books, 1, date(2026, 6, 1), date(2026, 8, 31));
+        assert_eq!(summer.period_label(), "Jun 1 – Aug 31, 2026");
+    }
+
+    #[test]
+    fn validation_rejects_empty_targets_and_reversed_ranges() {
+        let (start, end) = year_range(2026).unwrap();
+        assert!(validate_goal(0, start, end).is_err());
+        assert!(validate_goal(10, end, start).is_err());
+        assert!(validate_goal(10, start, start).is_ok());
+
+        let existing = goal(GoalMetric::Books, 10, start, end);
+        let update = UpdateReadingGoal {
+            end_date: Some(date(2025, 12, 31)),
+            ..UpdateReadingGoal::default()
+        };
+        assert!(update.validate_against(&existing).is_err());
+    }
+
+    #[test]
+    fn metric_roundtrip() {
+        for metric in [GoalMetric::Books, GoalMetric::Pages] {
+            assert_eq!(metric.as_str().parse::<GoalMetric>(), Ok(metric));
+        }
+        assert!("minutes".parse::<GoalMetric>().is_err());
+    }
+}
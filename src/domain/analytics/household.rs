@@ -0,0 +1,183 @@
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::ids::BookId;
+
+/// Books and authors listed in the household stats.
+pub const TOP_ENTRIES: usize = 10;
+
+/// A book with how many household members have finished it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HouseholdBook {
+    pub book_id: BookId,
+    pub title: String,
+    pub readers: u64,
+}
+
+/// Books one member finished in the current month.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MonthReader {
+    pub username: String,
+    pub titles: Vec<String>,
+}
+
+impl MonthReader {
+    /// Groups `(username, title)` rows, already ordered by username, into
+    /// one entry per member.
+    pub fn group(rows: Vec<(String, String)>) -> Vec<Self> {
+        let mut readers: Vec<Self> = Vec::new();
+        for (username, title) in rows {
+            match readers.last_mut() {
+                Some(reader) if reader.username == username => reader.titles.push(title),
+                _ => readers.push(Self {
+                    username,
+                    titles: vec![title],
+                }),
+            }
+        }
+        readers
+    }
+}
+
+/// One member's totals in a leaderboard year.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    pub username: String,
+    pub books: u64,
+    pub pages: i64,
+}
+
+/// Members ranked by books finished in a year, then by pages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct YearLeaderboard {
+    pub year: i32,
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+impl YearLeaderboard {
+    /// Builds one leaderboard per year from `(year, username, books, pages)`
+    /// rows, most recent year first.
+    pub fn from_totals(rows: Vec<(i32, String, u64, i64)>) -> Vec<Self> {
+        let mut boards: Vec<Self> = Vec::new();
+        for (year, username, books, pages) in rows {
+            let entry = LeaderboardEntry {
+                username,
+                books,
+                pages,
+            };
+            match boards.iter_mut().find(|b| b.year == year) {
+                Some(board) => board.entries.push(entry),
+                None => boards.push(Self {
+                    year,
+                    entries: vec![entry],
+                }),
+            }
+        }
+        boards.sort_by_key(|b| std::cmp::Reverse(b.year));
+        for board in &mut boards {
+            board.entries.sort_by(|a, b| {
+                b.books
+                    .cmp(&a.books)
+                    .then(b.pages.cmp(&a.pages))
+                    .then_with(|| a.username.cmp(&b.username))
+            });
+        }
+        boards
+    }
+}
+
+/// Reading stats across every user who hasn't opted out of them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HouseholdStats {
+    /// Users included in the stats.
+    pub members: u64,
+    pub books_read: u64,
+    pub pages_read: i64,
+    pub top_books: Vec<HouseholdBook>,
+    /// Authors by books of theirs finished across the household.
+    pub top_authors: Vec<(String, u64)>,
+    /// First day of the month `this_month` covers.
+    pub month: NaiveDate,
+    pub this_month: Vec<MonthReader>,
+    pub leaderboards: Vec<YearLeaderboard>,
+    pub computed_at: String,
+}
+
+impl HouseholdStats {
+    /// First day of the month containing `date`.
+    pub fn month_start(date: NaiveDate) -> NaiveDate {
+        date.with_day(1).unwrap_or(date)
+    }
+
+    /// Whether "this month" still means the month containing `today`. A
+    /// cached snapshot from an earlier month needs recomputing.
+    pub fn is_current(&self, today: NaiveDate) -> bool {
+        self.month == Self::month_start(today)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn month_readers_group_consecutive_rows() {
+        let rows = vec![
+            ("alice".to_string(), "Dune".to_string()),
+            ("alice".to_string(), "Emma".to_string()),
+            ("bob".to_string(), "Dune".to_string()),
+        ];
+
+        let readers = MonthReader::group(rows);
+
+        assert_eq!(readers.len(), 2);
+        assert_eq!(readers[0].username, "alice");
+        assert_eq!(readers[0].titles, ["Dune", "Emma"]);
+        assert_eq!(readers[1].titles, ["Dune"]);
+    }
+
+    #[test]
+    fn leaderboards_rank_by_books_then_pages() {
+        let rows = vec![
+            (2024, "alice".to_string(), 3, 900),
+            (2025, "alice".to_string(), 4, 1200),
+            (2025, "bob".to_string(), 4, 1500),
+            (2025, "carol".to_string(), 6, 800),
+        ];
+
+        let boards = YearLeaderboard::from_totals(rows);
+
+        assert_eq!(
+            boards.iter().map(|b| b.year).collect::<Vec<_>>(),
+            [2025, 2024]
+        );
+        let order: Vec<&str> = boards[0]
+            .entries
+            .iter()
+            .map(|e| e.username.as_str())
+            .collect();
+        assert_eq!(order, ["carol", "bob", "alice"]);
+    }
+
+    #[test]
+    fn snapshot_is_stale_after_the_month_ends() {
+        let stats = HouseholdStats {
+            members: 2,
+            books_read: 1,
+            pages_read: 300,
+            top_books: Vec::new(),
+            top_authors: Vec::new(),
+            month: HouseholdStats::month_start(date(2025, 3, 17)),
+            this_month: Vec::new(),
+            leaderboards: Vec::new(),
+            computed_at: String::new(),
+        };
+
+        assert!(stats.is_current(date(2025, 3, 31)));
+        assert!(!stats.is_current(date(2025, 4, 1)));
+    }
+}
@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+
+/// Pages an hour of listening counts as in the effort total, roughly what
+/// an hour of reading covers.
+pub const PAGES_PER_LISTENING_HOUR: i64 = 40;
+
+/// Listening time for finished audiobook readings with a known running time.
+/// Audiobooks are left out of page totals, so this is where they count.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ListeningStats {
+    pub audiobooks: u64,
+    pub minutes: i64,
+    /// Running time and days taken over the audiobooks with both dates, for
+    /// the average pace.
+    pub paced_minutes: i64,
+    pub paced_days: f64,
+    /// Minutes listened per month, bucketed like `ReadingStats::monthly_books`.
+    pub monthly_minutes: Vec<(String, i64)>,
+    /// Minutes listened per year (all-time view).
+    pub yearly_minutes: Vec<(String, i64)>,
+}
+
+impl ListeningStats {
+    pub fn is_empty(&self) -> bool {
+        self.audiobooks == 0
+    }
+
+    /// Average minutes listened per day between starting and finishing.
+    pub fn minutes_per_day(&self) -> Option<f64> {
+        // Minute totals stay far below where f64 loses precision
+        #[allow(clippy::cast_precision_loss)]
+        (self.paced_days > 0.0).then(|| self.paced_minutes as f64 / self.paced_days)
+    }
+
+    /// Sums the totals of stats computed over separate sets of readings.
+    /// Monthly and yearly breakdowns are left for the caller to fill in.
+    pub fn merge<'a>(stats: impl IntoIterator<Item = &'a Self>) -> Self {
+        stats
+            .into_iter()
+            .fold(Self::default(), |mut merged, stats| {
+                merged.audiobooks += stats.audiobooks;
+                merged.minutes += stats.minutes;
+                merged.paced_minutes += stats.paced_minutes;
+                merged.paced_days += stats.paced_days;
+                merged
+            })
+    }
+}
+
+/// Pages read plus time listened, in page-equivalents, so readers who mix
+/// formats can compare years.
+pub fn effort(pages: i64, listening_minutes: i64) -> i64 {
+    pages + listening_minutes * PAGES_PER_LISTENING_HOUR / 60
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn listened(audiobooks: u64, minutes: i64, paced_days: f64) -> ListeningStats {
+        ListeningStats {
+            audiobooks,
+            minutes,
+            paced_minutes: minutes,
+            paced_days,
+            ..ListeningStats::default()
+        }
+    }
+
+    #[test]
+    fn merged_totals_average_pace_over_all_readings() {
+        let merged = ListeningStats::merge(&[listened(1, 600, 10.0), listened(2, 900, 5.0)]);
+
+        assert_eq!(merged.audiobooks, 3);
+        assert_eq!(merged.minutes, 1500);
+        assert_eq!(merged.minutes_per_day(), Some(100.0));
+        assert_eq!(ListeningStats::default().minutes_per_day(), None);
+    }
+
+    #[test]
+    fn effort_converts_listening_hours_to_pages() {
+        assert_eq!(effort(300, 0), 300);
+        assert_eq!(effort(300, 90), 300 + PAGES_PER_LISTENING_HOUR * 3 / 2);
+    }
+}
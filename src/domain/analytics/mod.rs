@@ -1,3 +1,11 @@
+pub mod activity;
 pub mod ai_usage;
+pub mod challenges;
+pub mod goals;
+pub mod household;
+pub mod listening;
+pub mod review;
+pub mod sentiment;
 pub mod stats;
+pub mod stats_cache;
 pub mod timeline;
@@ -0,0 +1,213 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::ids::{BookId, ReadingId};
+use crate::domain::readings::{QuickReview, ReadingWithBook};
+use crate::domain::stats::PeriodStats;
+
+/// Highest-rated books listed in a year in review.
+pub const TOP_BOOKS: usize = 5;
+/// Quick-review chips listed in a year in review.
+pub const TOP_QUICK_REVIEWS: usize = 5;
+
+/// A finished book as it appears in a year in review.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReviewBook {
+    pub reading_id: ReadingId,
+    pub book_id: BookId,
+    pub title: String,
+    pub author_names: String,
+    pub rating: Option<f64>,
+    pub finished_at: NaiveDate,
+}
+
+impl ReviewBook {
+    fn from_reading(rwb: &ReadingWithBook) -> Option<Self> {
+        Some(Self {
+            reading_id: rwb.reading.id,
+            book_id: rwb.reading.book_id,
+            title: rwb.book_title.clone(),
+            author_names: rwb.author_names.clone(),
+            rating: rwb.reading.rating,
+            finished_at: rwb.reading.finished_at?,
+        })
+    }
+}
+
+/// A "wrapped"-style summary of one calendar year of reading.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct YearInReview {
+    pub year: i32,
+    pub books_read: u64,
+    pub pages_read: i64,
+    pub average_rating: Option<f64>,
+    pub top_author: Option<String>,
+    pub top_genre: Option<String>,
+    /// Longest book finished in the year: (title, `page_count`).
+    pub longest_book: Option<(String, i32)>,
+    /// Books finished per format, most used first.
+    pub format_split: Vec<(String, u64)>,
+    /// Highest-rated books, ties going to the one finished first.
+    pub top_books: Vec<ReviewBook>,
+    pub first_finished: Option<ReviewBook>,
+    pub last_finished: Option<ReviewBook>,
+    /// Most-used quick-review chips, with how many readings used each.
+    pub top_quick_reviews: Vec<(QuickReview, u64)>,
+}
+
+impl YearInReview {
+    /// Builds the review from the year's stats and the readings finished in it.
+    pub fn build(year: i32, stats: &PeriodStats, readings: &[ReadingWithBook]) -> Self {
+        let mut finished: Vec<ReviewBook> = readings
+            .iter()
+            .filter_map(ReviewBook::from_reading)
+            .collect();
+        finished.sort_by_key(|b| (b.finished_at, b.reading_id.into_inner()));
+
+        let mut top_books: Vec<ReviewBook> = finished
+            .iter()
+            .filter(|b| b.rating.is_some())
+            .cloned()
+            .collect();
+        top_books.sort_by(|a, b| b.rating.unwrap_or(0.0).total_cmp(&a.rating.unwrap_or(0.0)));
+        top_books.truncate(TOP_BOOKS);
+
+        Self {
+            year,
+            books_read: stats.reading.books_all_time,
+            pages_read: stats.reading.pages_all_time,
+            average_rating: stats.reading.average_rating,
+            top_author: stats.book_summary.top_author.clone(),
+            top_genre: stats.book_summary.top_genre.clone(),
+            longest_book: stats.book_summary.longest_book.clone(),
+            format_split: stats.reading.format_counts.clone(),
+            top_books,
+            first_finished: finished.first().cloned(),
+            last_finished: finished.last().cloned(),
+            top_quick_reviews: top_quick_reviews(readings),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.books_read == 0
+    }
+
+    /// Books to show covers for: the top-rated ones, or the first and last
+    /// finished when nothing was rated.
+    pub fn featured_books(&self) -> Vec<&ReviewBook> {
+        if !self.top_books.is_empty() {
+            return self.top_books.iter().collect();
+        }
+        let mut books: Vec<&ReviewBook> = self.first_finished.iter().collect();
+        if let Some(last) = &self.last_finished
+            && books
+                .first()
+                .is_none_or(|first| first.reading_id != last.reading_id)
+        {
+            books.push(last);
+        }
+        books
+    }
+}
+
+/// Counts quick-review chips across readings, most used first, then in the
+/// order chips are offered.
+fn top_quick_reviews(readings: &[ReadingWithBook]) -> Vec<(QuickReview, u64)> {
+    let mut top: Vec<(QuickReview, u64)> = QuickReview::all()
+        .iter()
+        .map(|&review| {
+            let count = readings
+                .iter()
+                .filter(|r| r.reading.quick_reviews.contains(&review))
+                .count();
+            (review, count as u64)
+        })
+        .filter(|&(_, count)| count > 0)
+        .collect();
+    top.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+    top.truncate(TOP_QUICK_REVIEWS);
+    top
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ids::UserId;
+    use crate::domain::readings::{Reading, ReadingStatus};
+    use crate::domain::stats::StatsPeriod;
+
+    fn reading(id: i64, title: &str, finished: (u32, u32), rating: Option<f64>) -> ReadingWithBook {
+        ReadingWithBook {
+            reading: Reading {
+                id: ReadingId::new(id),
+                user_id: UserId::new(1),
+                book_id: BookId::new(id),
+                status: ReadingStatus::Read,
+                format: None,
+                started_at: None,
+                finished_at: NaiveDate::from_ymd_opt(2025, finished.0, finished.1),
+                rating,
+                quick_reviews: Vec::new(),
+                current_page: None,
+                private: false,
+                created_at: chrono::Utc::now(),
+                updated_at: chrono::Utc::now(),
+            },
+            book_title: title.to_string(),
+            author_names: String::new(),
+            page_count: None,
+            year_published: None,
+            primary_genre: None,
+            secondary_genre: None,
+        }
+    }
+
+    fn stats() -> PeriodStats {
+        PeriodStats {
+            period: StatsPeriod::year(2025).unwrap(),
+            book_summary: crate::domain::stats::BookSummaryStats::default(),
+            reading: crate::domain::stats::ReadingStats::default(),
+        }
+    }
+
+    #[test]
+    fn top_books_rank_by_rating_then_finish_date() {
+        let readings = vec![
+            reading(1, "Early", (2, 1), Some(4.0)),
+            reading(2, "Best", (5, 1), Some(5.0)),
+            reading(3, "Unrated", (6, 1), None),
+            reading(4, "Late", (9, 1), Some(4.0)),
+        ];
+        let review = YearInReview::build(2025, &stats(), &readings);
+
+        let titles: Vec<&str> = review.top_books.iter().map(|b| b.title.as_str()).collect();
+        assert_eq!(titles, ["Best", "Early", "Late"]);
+    }
+
+    #[test]
+    fn first_and_last_finished_follow_finish_dates() {
+        let readings = vec![
+            reading(1, "Middle", (5, 1), None),
+            reading(2, "Last", (12, 30), None),
+            reading(3, "First", (1, 2), None),
+        ];
+        let review = YearInReview::build(2025, &stats(), &readings);
+
+        assert_eq!(review.first_finished.unwrap().title, "First");
+        assert_eq!(review.last_finished.unwrap().title, "Last");
+    }
+
+    #[test]
+    fn quick_reviews_are_counted_most_used_first() {
+        let mut first = reading(1, "One", (1, 1), None);
+        first.reading.quick_reviews = vec![QuickReview::Funny, QuickReview::PageTurner];
+        let mut second = reading(2, "Two", (2, 1), None);
+        second.reading.quick_reviews = vec![QuickReview::Funny];
+        let review = YearInReview::build(2025, &stats(), &[first, second]);
+
+        assert_eq!(
+            review.top_quick_reviews,
+            [(QuickReview::Funny, 2), (QuickReview::PageTurner, 1)]
+        );
+    }
+}
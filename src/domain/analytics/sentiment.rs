@@ -0,0 +1,351 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::readings::{QuickReview, Sentiment};
+use crate::domain::stats::{pace_bucket, pace_rank};
+
+/// Genres and authors listed in the sentiment balances.
+pub const SENTIMENT_GROUPS: usize = 8;
+
+/// Quick-review chips tallied by sentiment. Each chip on a reading counts
+/// once, so a reading tagged "Funny" and "Moving" adds two positives.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SentimentCounts {
+    pub positive: u64,
+    pub neutral: u64,
+    pub negative: u64,
+}
+
+impl SentimentCounts {
+    pub fn tally(reviews: &[QuickReview]) -> Self {
+        let mut counts = Self::default();
+        for review in reviews {
+            match review.sentiment() {
+                Sentiment::Positive => counts.positive += 1,
+                Sentiment::Neutral => counts.neutral += 1,
+                Sentiment::Negative => counts.negative += 1,
+            }
+        }
+        counts
+    }
+
+    pub fn add(&mut self, other: Self) {
+        self.positive += other.positive;
+        self.neutral += other.neutral;
+        self.negative += other.negative;
+    }
+
+    pub fn total(&self) -> u64 {
+        self.positive + self.neutral + self.negative
+    }
+
+    /// Positive minus negative chips over all chips, from -1.0 (all
+    /// negative) to 1.0 (all positive). `None` when no chips were used.
+    // Chip counts are nowhere near where f64 loses precision
+    #[allow(clippy::cast_precision_loss)]
+    pub fn balance(&self) -> Option<f64> {
+        let total = self.total();
+        (total > 0).then(|| (self.positive as f64 - self.negative as f64) / total as f64)
+    }
+}
+
+/// How often one quick-review chip was used, with the ratings and pace of
+/// the readings it was used on. Totals rather than averages, so usage from
+/// several years can be summed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuickReviewUsage {
+    pub review: QuickReview,
+    pub readings: u64,
+    /// Sum of ratings over `rated` readings.
+    pub rating_total: f64,
+    pub rated: u64,
+    /// Sum of pages per day over `paced` readings.
+    pub pages_per_day_total: f64,
+    pub paced: u64,
+}
+
+impl QuickReviewUsage {
+    fn new(review: QuickReview) -> Self {
+        Self {
+            review,
+            readings: 0,
+            rating_total: 0.0,
+            rated: 0,
+            pages_per_day_total: 0.0,
+            paced: 0,
+        }
+    }
+
+    fn add(&mut self, other: &Self) {
+        self.readings += other.readings;
+        self.rating_total += other.rating_total;
+        self.rated += other.rated;
+        self.pages_per_day_total += other.pages_per_day_total;
+        self.paced += other.paced;
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub fn average_rating(&self) -> Option<f64> {
+        (self.rated > 0).then(|| self.rating_total / self.rated as f64)
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub fn average_pages_per_day(&self) -> Option<f64> {
+        (self.paced > 0).then(|| self.pages_per_day_total / self.paced as f64)
+    }
+}
+
+/// A finished reading's quick reviews alongside its rating and pace.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReviewedReading {
+    pub rating: Option<f64>,
+    /// `None` without a page count or both dates.
+    pub pages_per_day: Option<f64>,
+    pub quick_reviews: Vec<QuickReview>,
+}
+
+impl ReviewedReading {
+    /// Works out pages per day the way the pace distribution does: readings
+    /// finished the day they were started count as one day.
+    pub fn new(
+        rating: Option<f64>,
+        page_count: Option<i32>,
+        started_at: Option<NaiveDate>,
+        finished_at: Option<NaiveDate>,
+        quick_reviews: Vec<QuickReview>,
+    ) -> Self {
+        let pages_per_day = match (page_count, started_at, finished_at) {
+            (Some(pages), Some(start), Some(finish)) if finish >= start => {
+                let days = (finish - start).num_days().max(1);
+                #[allow(clippy::cast_precision_loss)]
+                Some(f64::from(pages) / days as f64)
+            }
+            _ => None,
+        };
+        Self {
+            rating,
+            pages_per_day,
+            quick_reviews,
+        }
+    }
+}
+
+/// Quick-review usage and how sentiment lines up with rating and pace.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct QuickReviewStats {
+    /// Chips used at least once, most used first.
+    pub usage: Vec<QuickReviewUsage>,
+    /// Sentiment per half-star rating, lowest rating first.
+    pub by_rating: Vec<(f64, SentimentCounts)>,
+    /// Sentiment per pace bucket, slowest first.
+    pub by_pace: Vec<(String, SentimentCounts)>,
+}
+
+impl QuickReviewStats {
+    pub fn from_readings(readings: &[ReviewedReading]) -> Self {
+        let mut usage: Vec<QuickReviewUsage> = QuickReview::all()
+            .iter()
+            .map(|&review| QuickReviewUsage::new(review))
+            .collect();
+        let mut by_rating: Vec<(f64, SentimentCounts)> = Vec::new();
+        let mut by_pace: Vec<(String, SentimentCounts)> = Vec::new();
+
+        for reading in readings {
+            if reading.quick_reviews.is_empty() {
+                continue;
+            }
+            for entry in usage
+                .iter_mut()
+                .filter(|u| reading.quick_reviews.contains(&u.review))
+            {
+                entry.readings += 1;
+                if let Some(rating) = reading.rating {
+                    entry.rating_total += rating;
+                    entry.rated += 1;
+                }
+                if let Some(pace) = reading.pages_per_day {
+                    entry.pages_per_day_total += pace;
+                    entry.paced += 1;
+                }
+            }
+
+            let sentiment = SentimentCounts::tally(&reading.quick_reviews);
+            if let Some(rating) = reading.rating {
+                add_to(&mut by_rating, rating, sentiment);
+            }
+            if let Some(pace) = reading.pages_per_day {
+                add_to(&mut by_pace, pace_bucket(pace).to_string(), sentiment);
+            }
+        }
+
+        Self::sorted(usage, by_rating, by_pace)
+    }
+
+    /// Sums stats computed over separate sets of readings.
+    pub fn merge<'a>(parts: impl IntoIterator<Item = &'a Self>) -> Self {
+        let mut usage: Vec<QuickReviewUsage> = QuickReview::all()
+            .iter()
+            .map(|&review| QuickReviewUsage::new(review))
+            .collect();
+        let mut by_rating = Vec::new();
+        let mut by_pace = Vec::new();
+        for part in parts {
+            for used in &part.usage {
+                if let Some(entry) = usage.iter_mut().find(|u| u.review == used.review) {
+                    entry.add(used);
+                }
+            }
+            for &(rating, counts) in &part.by_rating {
+                add_to(&mut by_rating, rating, counts);
+            }
+            for (pace, counts) in &part.by_pace {
+                add_to(&mut by_pace, pace.clone(), *counts);
+            }
+        }
+        Self::sorted(usage, by_rating, by_pace)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.usage.is_empty()
+    }
+
+    /// Drops unused chips and puts everything in display order. Chips used
+    /// equally often keep the order they're offered in.
+    fn sorted(
+        mut usage: Vec<QuickReviewUsage>,
+        mut by_rating: Vec<(f64, SentimentCounts)>,
+        mut by_pace: Vec<(String, SentimentCounts)>,
+    ) -> Self {
+        usage.retain(|u| u.readings > 0);
+        usage.sort_by_key(|u| std::cmp::Reverse(u.readings));
+        by_rating.sort_by(|a, b| a.0.total_cmp(&b.0));
+        by_pace.sort_by_key(|(pace, _)| pace_rank(pace));
+        Self {
+            usage,
+            by_rating,
+            by_pace,
+        }
+    }
+}
+
+fn add_to<K: PartialEq>(groups: &mut Vec<(K, SentimentCounts)>, key: K, counts: SentimentCounts) {
+    match groups.iter_mut().find(|(k, _)| *k == key) {
+        Some((_, total)) => total.add(counts),
+        None => groups.push((key, counts)),
+    }
+}
+
+/// Sentiment balance per group (a genre or an author), given each group's
+/// ID, name and the quick reviews of one reading. Returns the groups with
+/// the most chips, busiest first.
+pub fn group_sentiment(
+    rows: impl IntoIterator<Item = (i64, String, Vec<QuickReview>)>,
+) -> Vec<(String, SentimentCounts)> {
+    let mut groups: Vec<(i64, String, SentimentCounts)> = Vec::new();
+    for (id, name, reviews) in rows {
+        let counts = SentimentCounts::tally(&reviews);
+        match groups.iter_mut().find(|(group, _, _)| *group == id) {
+            Some((_, _, total)) => total.add(counts),
+            None => groups.push((id, name, counts)),
+        }
+    }
+    groups.retain(|(_, _, counts)| counts.total() > 0);
+    groups.sort_by(|a, b| b.2.total().cmp(&a.2.total()).then_with(|| a.1.cmp(&b.1)));
+    groups.truncate(SENTIMENT_GROUPS);
+    groups
+        .into_iter()
+        .map(|(_, name, counts)| (name, counts))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(m: u32, d: u32) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(2025, m, d)
+    }
+
+    fn reviewed(rating: f64, pages: i32, days: u32, reviews: &[QuickReview]) -> ReviewedReading {
+        ReviewedReading::new(
+            Some(rating),
+            Some(pages),
+            date(1, 1),
+            date(1, 1 + days),
+            reviews.to_vec(),
+        )
+    }
+
+    #[test]
+    fn balance_runs_from_negative_to_positive() {
+        let mixed = SentimentCounts::tally(&[
+            QuickReview::Funny,
+            QuickReview::Moving,
+            QuickReview::Dense,
+            QuickReview::Overrated,
+        ]);
+        assert_eq!((mixed.positive, mixed.neutral, mixed.negative), (2, 1, 1));
+        assert_eq!(mixed.balance(), Some(0.25));
+        assert_eq!(SentimentCounts::default().balance(), None);
+    }
+
+    #[test]
+    fn usage_tracks_rating_and_pace_per_chip() {
+        let stats = QuickReviewStats::from_readings(&[
+            reviewed(2.0, 100, 10, &[QuickReview::SlowBurn]),
+            reviewed(3.0, 300, 10, &[QuickReview::SlowBurn, QuickReview::Moving]),
+            reviewed(5.0, 500, 5, &[QuickReview::PageTurner]),
+        ]);
+
+        let slow_burn = &stats.usage[0];
+        assert_eq!(slow_burn.review, QuickReview::SlowBurn);
+        assert_eq!(slow_burn.readings, 2);
+        assert_eq!(slow_burn.average_rating(), Some(2.5));
+        assert_eq!(slow_burn.average_pages_per_day(), Some(20.0));
+        // Ties keep the order chips are offered in
+        assert_eq!(stats.usage[1].review, QuickReview::PageTurner);
+
+        assert_eq!(stats.by_rating[0].0, 2.0);
+        assert_eq!(stats.by_rating[2].1.positive, 1);
+        let paces: Vec<&str> = stats.by_pace.iter().map(|(p, _)| p.as_str()).collect();
+        assert_eq!(paces, ["Slow", "Medium", "Fast"]);
+    }
+
+    #[test]
+    fn merged_stats_match_stats_over_all_readings() {
+        let first = [reviewed(4.0, 200, 4, &[QuickReview::Funny])];
+        let second = [
+            reviewed(4.0, 200, 20, &[QuickReview::Funny, QuickReview::TooLong]),
+            ReviewedReading::new(None, None, None, None, vec![QuickReview::Dense]),
+        ];
+        let all: Vec<ReviewedReading> = first.iter().chain(&second).cloned().collect();
+
+        let merged = QuickReviewStats::merge(&[
+            QuickReviewStats::from_readings(&first),
+            QuickReviewStats::from_readings(&second),
+        ]);
+        assert_eq!(merged, QuickReviewStats::from_readings(&all));
+    }
+
+    #[test]
+    fn groups_are_keyed_by_id_and_ranked_by_chips() {
+        let groups = group_sentiment([
+            (1, "Fantasy".to_string(), vec![QuickReview::Funny]),
+            (
+                2,
+                "History".to_string(),
+                vec![QuickReview::Dense, QuickReview::TooLong],
+            ),
+            (
+                1,
+                "Fantasy".to_string(),
+                vec![QuickReview::Moving, QuickReview::LovedIt],
+            ),
+            (3, "Poetry".to_string(), vec![]),
+        ]);
+        let names: Vec<&str> = groups.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, ["Fantasy", "History"]);
+        assert_eq!(groups[0].1.positive, 3);
+        assert_eq!(groups[1].1.balance(), Some(-0.5));
+    }
+}
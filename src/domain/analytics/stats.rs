@@ -1,5 +1,116 @@
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, Months, NaiveDate};
 use serde::{Deserialize, Serialize};
 
+use crate::domain::listening::{ListeningStats, effort};
+use crate::domain::sentiment::{QuickReviewStats, SentimentCounts};
+
+/// Longest date range stats can be computed over in one go: about ten years.
+pub const MAX_PERIOD_DAYS: i64 = 3660;
+
+/// An inclusive date range that stats are computed over, matched against the
+/// date a reading was finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatsPeriod {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+}
+
+impl StatsPeriod {
+    pub fn new(from: NaiveDate, to: NaiveDate) -> Result<Self, String> {
+        if from > to {
+            return Err("from must be on or before to".to_string());
+        }
+        if (to - from).num_days() >= MAX_PERIOD_DAYS {
+            return Err(format!(
+                "date range cannot be longer than {MAX_PERIOD_DAYS} days"
+            ));
+        }
+        Ok(Self { from, to })
+    }
+
+    /// January 1st to December 31st of `year`.
+    pub fn year(year: i32) -> Option<Self> {
+        Some(Self {
+            from: NaiveDate::from_ymd_opt(year, 1, 1)?,
+            to: NaiveDate::from_ymd_opt(year, 12, 31)?,
+        })
+    }
+
+    /// The twelve months up to and including `to`.
+    pub fn last_twelve_months(to: NaiveDate) -> Option<Self> {
+        let from = to.checked_sub_months(Months::new(12))?.succ_opt()?;
+        Some(Self { from, to })
+    }
+
+    /// The period of the same length that ends the day before this one starts.
+    pub fn preceding(&self) -> Option<Self> {
+        let to = self.from.pred_opt()?;
+        let from = to.checked_sub_signed(self.to - self.from)?;
+        Some(Self { from, to })
+    }
+
+    pub fn is_calendar_year(&self) -> bool {
+        Self::year(self.from.year()).as_ref() == Some(self)
+    }
+
+    /// "2025" for calendar years, otherwise the date range.
+    pub fn label(&self) -> String {
+        if self.is_calendar_year() {
+            return self.from.year().to_string();
+        }
+        if self.from.year() == self.to.year() {
+            format!(
+                "{} – {}",
+                self.from.format("%b %-d"),
+                self.to.format("%b %-d, %Y")
+            )
+        } else {
+            format!(
+                "{} – {}",
+                self.from.format("%b %-d, %Y"),
+                self.to.format("%b %-d, %Y")
+            )
+        }
+    }
+
+    /// The first day of each calendar month the period touches, in order.
+    pub fn months(&self) -> Vec<NaiveDate> {
+        let mut month = self.from.with_day(1).unwrap_or(self.from);
+        let mut months = Vec::new();
+        while month <= self.to {
+            months.push(month);
+            let Some(next) = month.checked_add_months(Months::new(1)) else {
+                break;
+            };
+            month = next;
+        }
+        months
+    }
+}
+
+/// Pace bucket for a reading's pages per day, matching the thresholds of the
+/// pace distribution queries.
+pub fn pace_bucket(pages_per_day: f64) -> &'static str {
+    if pages_per_day < 15.0 {
+        "Slow"
+    } else if pages_per_day <= 40.0 {
+        "Medium"
+    } else {
+        "Fast"
+    }
+}
+
+/// Orders pace buckets slowest first, matching the per-period queries.
+pub fn pace_rank(pace: &str) -> u8 {
+    match pace {
+        "Slow" => 1,
+        "Medium" => 2,
+        _ => 3,
+    }
+}
+
 /// Summary statistics for books: genres, authors, page counts, publication years.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct BookSummaryStats {
@@ -14,8 +125,14 @@ pub struct BookSummaryStats {
     /// Genre with the highest total sum of star ratings.
     #[serde(default)]
     pub most_rated_genre: Option<String>,
+    /// Books per top-level genre, with books in subgenres rolled up into
+    /// their parents (each book counted once per genre).
     pub genre_counts: Vec<(String, u64)>,
     pub max_genre_count: u64,
+    /// Drill-down into `genre_counts`: one entry per genre whose subgenres
+    /// have books, parents before their children.
+    #[serde(default)]
+    pub subgenre_counts: Vec<GenreBreakdown>,
     /// Page count distribution buckets (e.g. "< 200", "200 – 350", "350 – 500", "500+").
     #[serde(default)]
     pub page_count_distribution: Vec<(String, u64)>,
@@ -35,6 +152,21 @@ pub struct BookSummaryStats {
     /// Shortest book by page count: (title, `page_count`).
     #[serde(default)]
     pub shortest_book: Option<(String, i32)>,
+    /// Quick-review sentiment for the genres with the most chips.
+    #[serde(default)]
+    pub genre_sentiment: Vec<(String, SentimentCounts)>,
+    /// Quick-review sentiment for the authors with the most chips.
+    #[serde(default)]
+    pub author_sentiment: Vec<(String, SentimentCounts)>,
+}
+
+/// Rolled-up book counts for the direct subgenres of one genre.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GenreBreakdown {
+    /// Full name of the parent genre, e.g. "Science Fiction › Space Opera".
+    pub genre: String,
+    pub counts: Vec<(String, u64)>,
+    pub max_count: u64,
 }
 
 /// Reading activity totals and distributions.
@@ -60,10 +192,11 @@ pub struct ReadingStats {
     pub rating_distribution: Vec<(f64, u64)>,
     #[serde(default)]
     pub max_rating_count: u64,
-    /// Books finished per month in the current year.
+    /// Books finished per month of the period, or of the current year in the
+    /// all-time view.
     #[serde(default)]
     pub monthly_books: Vec<(String, u64)>,
-    /// Pages read per month in the current year.
+    /// Pages read per month, bucketed like `monthly_books`.
     #[serde(default)]
     pub monthly_pages: Vec<(String, i64)>,
     #[serde(default)]
@@ -86,6 +219,19 @@ pub struct ReadingStats {
     /// Format distribution: Physical / eReader / Audiobook.
     #[serde(default)]
     pub format_counts: Vec<(String, u64)>,
+    /// Most-used quick reviews, and how sentiment lines up with rating and pace.
+    #[serde(default)]
+    pub quick_reviews: QuickReviewStats,
+    /// Audiobook listening time, kept out of the page totals above.
+    #[serde(default)]
+    pub listening: ListeningStats,
+}
+
+impl ReadingStats {
+    /// Pages read plus listening time, in page-equivalents.
+    pub fn effort(&self) -> i64 {
+        effort(self.pages_all_time, self.listening.minutes)
+    }
 }
 
 /// Pre-computed snapshot of all statistics, stored as JSON in the cache table.
@@ -95,3 +241,195 @@ pub struct CachedStats {
     pub reading: ReadingStats,
     pub computed_at: String,
 }
+
+/// Stats for a single period.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PeriodStats {
+    pub period: StatsPeriod,
+    pub book_summary: BookSummaryStats,
+    pub reading: ReadingStats,
+}
+
+/// How one top-level genre's share of the books read moved between periods.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GenreShareChange {
+    pub genre: String,
+    pub books: u64,
+    pub compare_books: u64,
+    /// Percentage of the period's books in this genre.
+    pub share: f64,
+    pub compare_share: f64,
+    /// `share - compare_share`, in percentage points.
+    pub share_change: f64,
+}
+
+/// Differences between a period and the one it is compared against: positive
+/// values mean the period is ahead.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatsDelta {
+    pub books: i64,
+    pub pages: i64,
+    /// `None` unless both periods have rated readings.
+    pub average_rating: Option<f64>,
+    /// Genres read in either period, most read first.
+    pub genres: Vec<GenreShareChange>,
+}
+
+impl StatsDelta {
+    pub fn between(current: &PeriodStats, compare: &PeriodStats) -> Self {
+        let books = current.reading.books_all_time.cast_signed()
+            - compare.reading.books_all_time.cast_signed();
+        let pages = current.reading.pages_all_time - compare.reading.pages_all_time;
+        let average_rating = current
+            .reading
+            .average_rating
+            .zip(compare.reading.average_rating)
+            .map(|(a, b)| a - b);
+
+        let mut counts: BTreeMap<&str, (u64, u64)> = BTreeMap::new();
+        for (genre, count) in &current.book_summary.genre_counts {
+            counts.entry(genre).or_default().0 = *count;
+        }
+        for (genre, count) in &compare.book_summary.genre_counts {
+            counts.entry(genre).or_default().1 = *count;
+        }
+        // Book counts are nowhere near where f64 loses precision
+        #[allow(clippy::cast_precision_loss)]
+        let percent = |count: u64, total: u64| {
+            if total == 0 {
+                0.0
+            } else {
+                count as f64 * 100.0 / total as f64
+            }
+        };
+        let mut genres: Vec<GenreShareChange> = counts
+            .into_iter()
+            .map(|(genre, (books, compare_books))| {
+                let share = percent(books, current.book_summary.total_books);
+                let compare_share = percent(compare_books, compare.book_summary.total_books);
+                GenreShareChange {
+                    genre: genre.to_string(),
+                    books,
+                    compare_books,
+                    share,
+                    compare_share,
+                    share_change: share - compare_share,
+                }
+            })
+            .collect();
+        genres.sort_by(|a, b| {
+            (b.books + b.compare_books)
+                .cmp(&(a.books + a.compare_books))
+                .then_with(|| a.genre.cmp(&b.genre))
+        });
+
+        Self {
+            books,
+            pages,
+            average_rating,
+            genres,
+        }
+    }
+}
+
+/// Stats for a period, optionally side by side with a second period.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatsReport {
+    pub current: PeriodStats,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compare: Option<PeriodStats>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delta: Option<StatsDelta>,
+}
+
+impl StatsReport {
+    pub fn new(current: PeriodStats, compare: Option<PeriodStats>) -> Self {
+        let delta = compare.as_ref().map(|c| StatsDelta::between(&current, c));
+        Self {
+            current,
+            compare,
+            delta,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn period_stats(
+        books: u64,
+        pages: i64,
+        rating: Option<f64>,
+        genres: &[(&str, u64)],
+    ) -> PeriodStats {
+        PeriodStats {
+            period: StatsPeriod::year(2025).unwrap(),
+            book_summary: BookSummaryStats {
+                total_books: books,
+                genre_counts: genres.iter().map(|(g, c)| ((*g).to_string(), *c)).collect(),
+                ..BookSummaryStats::default()
+            },
+            reading: ReadingStats {
+                books_all_time: books,
+                pages_all_time: pages,
+                average_rating: rating,
+                ..ReadingStats::default()
+            },
+        }
+    }
+
+    #[test]
+    fn periods_validate_their_bounds() {
+        assert!(StatsPeriod::new(date(2025, 3, 1), date(2025, 2, 1)).is_err());
+        assert!(StatsPeriod::new(date(2010, 1, 1), date(2025, 1, 1)).is_err());
+        let q1 = StatsPeriod::new(date(2025, 1, 1), date(2025, 3, 31)).unwrap();
+        assert_eq!(q1.label(), "Jan 1 – Mar 31, 2025");
+        assert_eq!(StatsPeriod::year(2024).unwrap().label(), "2024");
+    }
+
+    #[test]
+    fn preceding_and_trailing_periods() {
+        let q1 = StatsPeriod::new(date(2025, 1, 1), date(2025, 1, 31)).unwrap();
+        let before = q1.preceding().unwrap();
+        assert_eq!(
+            (before.from, before.to),
+            (date(2024, 12, 1), date(2024, 12, 31))
+        );
+
+        let trailing = StatsPeriod::last_twelve_months(date(2025, 6, 15)).unwrap();
+        assert_eq!(trailing.from, date(2024, 6, 16));
+        assert_eq!(trailing.months().len(), 13);
+        assert_eq!(trailing.months()[0], date(2024, 6, 1));
+    }
+
+    #[test]
+    fn deltas_cover_totals_rating_and_genre_mix() {
+        let current = period_stats(4, 1200, Some(4.0), &[("Fantasy", 3), ("History", 1)]);
+        let compare = period_stats(2, 900, Some(3.5), &[("History", 2)]);
+        let report = StatsReport::new(current, Some(compare));
+        let delta = report.delta.unwrap();
+
+        assert_eq!((delta.books, delta.pages), (2, 300));
+        assert_eq!(delta.average_rating, Some(0.5));
+        assert_eq!(delta.genres[0].genre, "Fantasy");
+        assert!((delta.genres[0].share_change - 75.0).abs() < f64::EPSILON);
+        let history = &delta.genres[1];
+        assert_eq!((history.books, history.compare_books), (1, 2));
+        assert!((history.share_change + 75.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn rating_delta_needs_both_periods_rated() {
+        let delta = StatsDelta::between(
+            &period_stats(1, 100, Some(4.0), &[]),
+            &period_stats(0, 0, None, &[]),
+        );
+        assert_eq!(delta.average_rating, None);
+        assert!(delta.genres.is_empty());
+    }
+}
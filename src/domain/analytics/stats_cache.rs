@@ -0,0 +1,318 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::listening::ListeningStats;
+use crate::domain::readings::Reading;
+use crate::domain::sentiment::QuickReviewStats;
+use crate::domain::stats::{ReadingStats, pace_rank};
+
+/// Reading aggregates for one year, kept so the all-time stats can be
+/// refreshed a year at a time. Read readings count towards the year they
+/// were finished, abandoned ones towards the year they were started.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct YearStatsPartial {
+    /// `None` holds readings without the date that would place them in a year.
+    pub year: Option<i32>,
+    pub books_read: u64,
+    pub pages_read: i64,
+    pub rating_distribution: Vec<(f64, u64)>,
+    /// Sum of days from start to finish, over `finish_time_count` readings.
+    pub finish_days_total: f64,
+    pub finish_time_count: u64,
+    pub books_abandoned: u64,
+    /// Books finished in each month, January first. Empty for undated readings.
+    pub monthly_books: Vec<u64>,
+    pub monthly_pages: Vec<i64>,
+    pub pace_distribution: Vec<(String, u64)>,
+    pub format_counts: Vec<(String, u64)>,
+    #[serde(default)]
+    pub quick_reviews: QuickReviewStats,
+    /// Listening totals, with minutes per month of the year.
+    #[serde(default)]
+    pub listening: ListeningStats,
+}
+
+impl YearStatsPartial {
+    /// True when no reading falls in the year, so the partial needn't be kept.
+    pub fn is_empty(&self) -> bool {
+        self.books_read == 0 && self.books_abandoned == 0
+    }
+}
+
+/// Reading stats that describe the library right now rather than any year.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadingCounters {
+    pub books_last_30_days: u64,
+    pub pages_last_30_days: i64,
+    pub books_in_progress: u64,
+    pub books_on_shelf: u64,
+    pub books_on_wishlist: u64,
+}
+
+/// The partials a reading contributes to: the years it was started and
+/// finished in, either of which may be undated.
+pub fn reading_stats_years(reading: &Reading) -> [Option<i32>; 2] {
+    use chrono::Datelike;
+    [
+        reading.started_at.map(|d| d.year()),
+        reading.finished_at.map(|d| d.year()),
+    ]
+}
+
+/// Sums `(key, count)` pairs from several partials.
+fn merge_counts<K: Ord + Clone>(lists: impl Iterator<Item = Vec<(K, u64)>>) -> BTreeMap<K, u64> {
+    let mut merged = BTreeMap::new();
+    for list in lists {
+        for (key, count) in list {
+            *merged.entry(key).or_insert(0) += count;
+        }
+    }
+    merged
+}
+
+/// Pairs month names with a partial's monthly counts, zero where missing.
+fn label_months<T: Copy + Default>(names: &[String], counts: Option<&[T]>) -> Vec<(String, T)> {
+    names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let count = counts.and_then(|c| c.get(i)).copied();
+            (name.clone(), count.unwrap_or_default())
+        })
+        .collect()
+}
+
+impl ReadingStats {
+    /// Assembles all-time reading stats from per-year partials. Monthly
+    /// activity covers `current_year`, as in the all-time view.
+    pub fn from_partials(
+        partials: &[YearStatsPartial],
+        counters: ReadingCounters,
+        current_year: i32,
+    ) -> Self {
+        let books_all_time = partials.iter().map(|p| p.books_read).sum();
+        let pages_all_time = partials.iter().map(|p| p.pages_read).sum();
+
+        // Ratings are positive half stars, so their bit patterns are exact
+        // keys that sort in numeric order
+        let rating_distribution: Vec<(f64, u64)> = merge_counts(partials.iter().map(|p| {
+            p.rating_distribution
+                .iter()
+                .map(|(r, c)| (r.to_bits(), *c))
+                .collect()
+        }))
+        .into_iter()
+        .map(|(bits, c)| (f64::from_bits(bits), c))
+        .collect();
+        let rated: u64 = rating_distribution.iter().map(|(_, c)| c).sum();
+        // Counts stay far below where f64 loses precision
+        #[allow(clippy::cast_precision_loss)]
+        let average_rating = (rated > 0).then(|| {
+            rating_distribution
+                .iter()
+                .map(|(r, c)| r * *c as f64)
+                .sum::<f64>()
+                / rated as f64
+        });
+
+        let finish_count: u64 = partials.iter().map(|p| p.finish_time_count).sum();
+        #[allow(clippy::cast_precision_loss)]
+        let average_days_to_finish = (finish_count > 0).then(|| {
+            partials.iter().map(|p| p.finish_days_total).sum::<f64>() / finish_count as f64
+        });
+
+        let current = partials.iter().find(|p| p.year == Some(current_year));
+        let month_names: Vec<String> = (1..=12)
+            .filter_map(|m| {
+                NaiveDate::from_ymd_opt(current_year, m, 1).map(|d| d.format("%b").to_string())
+            })
+            .collect();
+        let monthly_books = label_months(&month_names, current.map(|p| &p.monthly_books[..]));
+        let monthly_pages = label_months(&month_names, current.map(|p| &p.monthly_pages[..]));
+
+        let mut dated: Vec<&YearStatsPartial> = partials
+            .iter()
+            .filter(|p| p.year.is_some() && p.books_read > 0)
+            .collect();
+        dated.sort_by_key(|p| p.year);
+        let yearly_books: Vec<(String, u64)> = dated
+            .iter()
+            .filter_map(|p| p.year.map(|y| (y.to_string(), p.books_read)))
+            .collect();
+        let yearly_pages: Vec<(String, i64)> = dated
+            .iter()
+            .filter_map(|p| p.year.map(|y| (y.to_string(), p.pages_read)))
+            .collect();
+
+        let mut listening = ListeningStats::merge(partials.iter().map(|p| &p.listening));
+        let current_minutes: Option<Vec<i64>> = current.map(|p| {
+            p.listening
+                .monthly_minutes
+                .iter()
+                .map(|(_, m)| *m)
+                .collect()
+        });
+        listening.monthly_minutes = label_months(&month_names, current_minutes.as_deref());
+        listening.yearly_minutes = dated
+            .iter()
+            .filter(|p| p.listening.minutes > 0)
+            .filter_map(|p| p.year.map(|y| (y.to_string(), p.listening.minutes)))
+            .collect();
+
+        let mut pace_distribution: Vec<(String, u64)> =
+            merge_counts(partials.iter().map(|p| p.pace_distribution.clone()))
+                .into_iter()
+                .collect();
+        pace_distribution.sort_by_key(|(pace, _)| pace_rank(pace));
+
+        let mut format_counts: Vec<(String, u64)> =
+            merge_counts(partials.iter().map(|p| p.format_counts.clone()))
+                .into_iter()
+                .collect();
+        format_counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        Self {
+            books_last_30_days: counters.books_last_30_days,
+            books_all_time,
+            pages_last_30_days: counters.pages_last_30_days,
+            pages_all_time,
+            books_in_progress: counters.books_in_progress,
+            books_on_shelf: counters.books_on_shelf,
+            books_on_wishlist: counters.books_on_wishlist,
+            average_rating,
+            books_abandoned: partials.iter().map(|p| p.books_abandoned).sum(),
+            average_days_to_finish,
+            max_rating_count: rating_distribution
+                .iter()
+                .map(|(_, c)| *c)
+                .max()
+                .unwrap_or(0),
+            rating_distribution,
+            max_monthly_books: monthly_books.iter().map(|(_, c)| *c).max().unwrap_or(0),
+            max_monthly_pages: monthly_pages.iter().map(|(_, p)| *p).max().unwrap_or(0),
+            monthly_books,
+            monthly_pages,
+            max_yearly_books: yearly_books.iter().map(|(_, c)| *c).max().unwrap_or(0),
+            max_yearly_pages: yearly_pages.iter().map(|(_, p)| *p).max().unwrap_or(0),
+            yearly_books,
+            yearly_pages,
+            pace_distribution,
+            format_counts,
+            quick_reviews: QuickReviewStats::merge(partials.iter().map(|p| &p.quick_reviews)),
+            listening,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn partial(year: Option<i32>, books: u64, pages: i64) -> YearStatsPartial {
+        YearStatsPartial {
+            year,
+            books_read: books,
+            pages_read: pages,
+            ..YearStatsPartial::default()
+        }
+    }
+
+    #[test]
+    fn totals_and_averages_combine_across_years() {
+        let partials = [
+            YearStatsPartial {
+                rating_distribution: vec![(4.0, 1), (5.0, 1)],
+                finish_days_total: 10.0,
+                finish_time_count: 2,
+                format_counts: vec![("Physical".to_string(), 2)],
+                ..partial(Some(2024), 2, 600)
+            },
+            YearStatsPartial {
+                rating_distribution: vec![(3.0, 1), (4.0, 1)],
+                finish_days_total: 20.0,
+                finish_time_count: 1,
+                format_counts: vec![("Audiobook".to_string(), 1), ("Physical".to_string(), 1)],
+                books_abandoned: 1,
+                ..partial(Some(2025), 2, 300)
+            },
+            partial(None, 1, 0),
+        ];
+        let stats = ReadingStats::from_partials(&partials, ReadingCounters::default(), 2025);
+
+        assert_eq!(stats.books_all_time, 5);
+        assert_eq!(stats.pages_all_time, 900);
+        assert_eq!(stats.books_abandoned, 1);
+        assert_eq!(stats.rating_distribution, [(3.0, 1), (4.0, 2), (5.0, 1)]);
+        assert_eq!(stats.max_rating_count, 2);
+        assert_eq!(stats.average_rating, Some(4.0));
+        assert_eq!(stats.average_days_to_finish, Some(10.0));
+        assert_eq!(
+            stats.format_counts,
+            [("Physical".to_string(), 3), ("Audiobook".to_string(), 1)]
+        );
+        // Undated readings count, but have no year of their own
+        assert_eq!(
+            stats.yearly_books,
+            [("2024".to_string(), 2), ("2025".to_string(), 2)]
+        );
+    }
+
+    #[test]
+    fn monthly_activity_covers_the_current_year() {
+        let mut this_year = partial(Some(2025), 3, 900);
+        this_year.monthly_books = vec![0, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        this_year.monthly_pages = vec![0, 600, 300, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let partials = [partial(Some(2024), 4, 1000), this_year];
+
+        let stats = ReadingStats::from_partials(&partials, ReadingCounters::default(), 2025);
+        assert_eq!(stats.monthly_books.len(), 12);
+        assert_eq!(stats.monthly_books[1], ("Feb".to_string(), 2));
+        assert_eq!(stats.max_monthly_pages, 600);
+
+        let next_year = ReadingStats::from_partials(&partials, ReadingCounters::default(), 2026);
+        assert_eq!(next_year.max_monthly_books, 0);
+        assert_eq!(next_year.monthly_books[0], ("Jan".to_string(), 0));
+    }
+
+    #[test]
+    fn pace_buckets_keep_their_order() {
+        let partials = [YearStatsPartial {
+            pace_distribution: vec![("Fast".to_string(), 1), ("Slow".to_string(), 2)],
+            ..partial(Some(2025), 3, 0)
+        }];
+        let stats = ReadingStats::from_partials(&partials, ReadingCounters::default(), 2025);
+        assert_eq!(
+            stats.pace_distribution,
+            [("Slow".to_string(), 2), ("Fast".to_string(), 1)]
+        );
+    }
+
+    #[test]
+    fn listening_time_combines_across_years() {
+        let mut this_year = partial(Some(2025), 1, 0);
+        this_year.listening = ListeningStats {
+            audiobooks: 1,
+            minutes: 600,
+            monthly_minutes: vec![("Jan".to_string(), 0), ("Feb".to_string(), 600)],
+            ..ListeningStats::default()
+        };
+        let mut last_year = partial(Some(2024), 2, 300);
+        last_year.listening = ListeningStats {
+            audiobooks: 1,
+            minutes: 300,
+            ..ListeningStats::default()
+        };
+
+        let stats =
+            ReadingStats::from_partials(&[last_year, this_year], ReadingCounters::default(), 2025);
+        assert_eq!(stats.listening.audiobooks, 2);
+        assert_eq!(stats.listening.minutes, 900);
+        assert_eq!(stats.listening.monthly_minutes[1], ("Feb".to_string(), 600));
+        assert_eq!(
+            stats.listening.yearly_minutes,
+            [("2024".to_string(), 300), ("2025".to_string(), 600)]
+        );
+    }
+}
@@ -1,24 +1,90 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::domain::ids::{TimelineEventId, UserId};
+use crate::domain::ids::{GenreId, TimelineEventId, UserId};
 use crate::domain::listing::{SortDirection, SortKey};
+use crate::domain::users::ProfilePrivacy;
+
+/// What a detail or change holds, so code can find one without matching
+/// its display label. Stored with the event; older events may lack it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimelineField {
+    Author,
+    Format,
+    Rating,
+    QuickReviews,
+    Genres,
+    Pages,
+    Progress,
+    Status,
+    Shelf,
+    BookClub,
+    Milestone,
+}
+
+impl TimelineField {
+    pub fn label(self) -> &'static str {
+        match self {
+            TimelineField::Author => "Author",
+            TimelineField::Format => "Format",
+            TimelineField::Rating => "Rating",
+            TimelineField::QuickReviews => "Notes",
+            TimelineField::Genres => "Genres",
+            TimelineField::Pages => "Pages",
+            TimelineField::Progress => "Progress",
+            TimelineField::Status => "Status",
+            TimelineField::Shelf => "Shelf",
+            TimelineField::BookClub => "Book club",
+            TimelineField::Milestone => "Reached",
+        }
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimelineEventDetail {
+    #[serde(default)]
+    pub key: Option<TimelineField>,
     pub label: String,
     pub value: String,
 }
 
 impl TimelineEventDetail {
+    pub fn new(key: TimelineField, value: String) -> Self {
+        Self {
+            key: Some(key),
+            label: key.label().to_string(),
+            value,
+        }
+    }
+
     pub fn author_detail(author_names: &[&str]) -> Self {
+        let value = if author_names.is_empty() {
+            "Unknown".to_string()
+        } else {
+            author_names.join(", ")
+        };
+        Self::new(TimelineField::Author, value)
+    }
+}
+
+/// A value an event changed, e.g. a rating going from 3 to 4.5.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimelineEventChange {
+    #[serde(default)]
+    pub key: Option<TimelineField>,
+    pub label: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+impl TimelineEventChange {
+    pub fn new(key: TimelineField, before: Option<String>, after: Option<String>) -> Self {
         Self {
-            label: "Author".to_string(),
-            value: if author_names.is_empty() {
-                "Unknown".to_string()
-            } else {
-                author_names.join(", ")
-            },
+            key: Some(key),
+            label: key.label().to_string(),
+            before,
+            after,
         }
     }
 }
@@ -42,6 +108,39 @@ pub struct TimelineEvent {
     pub details: Vec<TimelineEventDetail>,
     pub genres: Vec<String>,
     pub reading_data: Option<TimelineReadingData>,
+    /// Before/after values for events that record a change. Unlike the
+    /// snapshot fields above, these are never refreshed.
+    #[serde(default)]
+    pub changes: Vec<TimelineEventChange>,
+}
+
+impl TimelineEvent {
+    /// Drop the rating and quick reviews a reader keeps off their public
+    /// profile, for output shown beyond the app.
+    pub fn redact(&mut self, privacy: ProfilePrivacy) {
+        if !privacy.show_ratings {
+            self.details
+                .retain(|detail| detail.key != Some(TimelineField::Rating));
+            self.changes
+                .retain(|change| change.key != Some(TimelineField::Rating));
+            if let Some(data) = &mut self.reading_data {
+                data.rating = None;
+            }
+        }
+        if !privacy.show_quick_reviews {
+            self.details
+                .retain(|detail| detail.key != Some(TimelineField::QuickReviews));
+        }
+    }
+}
+
+/// A stored event with who recorded it, for work done after it was written.
+#[derive(Debug, Clone)]
+pub struct RecordedTimelineEvent {
+    pub event: TimelineEvent,
+    pub user_id: Option<UserId>,
+    /// About a private reading, so only `user_id` may see it.
+    pub private: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,6 +154,70 @@ pub struct NewTimelineEvent {
     pub details: Vec<TimelineEventDetail>,
     pub genres: Vec<String>,
     pub reading_data: Option<TimelineReadingData>,
+    #[serde(default)]
+    pub changes: Vec<TimelineEventChange>,
+}
+
+/// Entity types events are recorded for. `user_book` events carry the
+/// book's id, `goal` events the goal's.
+pub const TIMELINE_ENTITY_TYPES: [&str; 6] =
+    ["book", "reading", "user_book", "author", "genre", "goal"];
+
+/// Actions events are recorded with. A `milestone` is a reading goal, or a
+/// reading in progress, passing one of its milestones.
+pub const TIMELINE_ACTIONS: [&str; 9] = [
+    "added",
+    "shelved",
+    "moved",
+    "book_club",
+    "started",
+    "finished",
+    "abandoned",
+    "rated",
+    "milestone",
+];
+
+/// Filter criteria for timeline queries.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TimelineFilter {
+    pub user_id: Option<UserId>,
+    pub entity_type: Option<String>,
+    pub action: Option<String>,
+    /// An action to leave out, applied before any page limit.
+    pub exclude_action: Option<String>,
+    /// Events for books in the genre, and for readings of them.
+    pub genre_id: Option<GenreId>,
+    /// First day to include.
+    pub since: Option<NaiveDate>,
+    /// Last day to include.
+    pub until: Option<NaiveDate>,
+    /// Who is looking. Events for private readings are only shown to their
+    /// reader, and to nobody when this is `None`.
+    pub viewer: Option<UserId>,
+}
+
+impl TimelineFilter {
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    pub fn for_user(user_id: UserId) -> Self {
+        Self {
+            user_id: Some(user_id),
+            ..Default::default()
+        }
+    }
+
+    /// Shown to this viewer, private readings included if they are theirs.
+    #[must_use]
+    pub fn seen_by(self, viewer: Option<UserId>) -> Self {
+        Self { viewer, ..self }
+    }
+
+    /// Whether no criteria narrow the events; the viewer doesn't count.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default().seen_by(self.viewer)
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -0,0 +1,87 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::ids::{FeedTokenId, UserId};
+
+/// Which of a user's timeline feeds a token unlocks.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedKind {
+    /// Every timeline event the user recorded.
+    All,
+    /// Only finished readings.
+    Finished,
+}
+
+impl FeedKind {
+    pub const ALL: [FeedKind; 2] = [FeedKind::All, FeedKind::Finished];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FeedKind::All => "all",
+            FeedKind::Finished => "finished",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FeedKind::All => "All activity",
+            FeedKind::Finished => "Finished books",
+        }
+    }
+}
+
+impl FromStr for FeedKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(FeedKind::All),
+            "finished" => Ok(FeedKind::Finished),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Secret that lets a feed reader fetch one feed of a private profile.
+/// Only the hash is stored; the token itself is shown once, in the feed URL.
+#[derive(Debug, Clone)]
+pub struct FeedToken {
+    pub id: FeedTokenId,
+    pub user_id: UserId,
+    pub feed: FeedKind,
+    pub token_hash: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewFeedToken {
+    pub user_id: UserId,
+    pub feed: FeedKind,
+    pub token_hash: String,
+}
+
+impl NewFeedToken {
+    pub fn new(user_id: UserId, feed: FeedKind, token_hash: String) -> Self {
+        Self {
+            user_id,
+            feed,
+            token_hash,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kind_round_trips_through_strings() {
+        for kind in FeedKind::ALL {
+            assert_eq!(kind.as_str().parse::<FeedKind>(), Ok(kind));
+        }
+        assert!("reviews".parse::<FeedKind>().is_err());
+    }
+}
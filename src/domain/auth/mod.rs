@@ -1,3 +1,4 @@
+pub mod feed_tokens;
 pub mod passkey_credentials;
 pub mod registration_tokens;
 pub mod sessions;
@@ -10,6 +10,29 @@ pub struct User {
     pub uuid: String,
     pub is_admin: bool,
     pub created_at: DateTime<Utc>,
+    /// Leave this user's readings out of the household stats.
+    #[serde(default)]
+    pub household_opt_out: bool,
+    /// Show this user's feeds to anyone, without a feed token.
+    #[serde(default)]
+    pub public_profile: bool,
+}
+
+/// What a public profile, feed or federated note shows alongside the
+/// reader's books.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProfilePrivacy {
+    pub show_ratings: bool,
+    pub show_quick_reviews: bool,
+}
+
+impl Default for ProfilePrivacy {
+    fn default() -> Self {
+        Self {
+            show_ratings: true,
+            show_quick_reviews: true,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -25,6 +48,8 @@ impl User {
         uuid: String,
         is_admin: bool,
         created_at: DateTime<Utc>,
+        household_opt_out: bool,
+        public_profile: bool,
     ) -> Self {
         Self {
             id,
@@ -32,6 +57,8 @@ impl User {
             uuid,
             is_admin,
             created_at,
+            household_opt_out,
+            public_profile,
         }
     }
 }
@@ -9,12 +9,53 @@ use crate::domain::timeline::NewTimelineEvent;
 pub struct Author {
     pub id: AuthorId,
     pub name: String,
+    #[serde(flatten)]
+    pub details: AuthorDetails,
     pub created_at: DateTime<Utc>,
 }
 
+/// Biographical details, all optional.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthorDetails {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bio: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub birth_year: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub death_year: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nationality: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+    /// Pen names and other names the author publishes under.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+}
+
+impl AuthorDetails {
+    pub fn normalize(mut self, name: &str) -> Self {
+        self.bio = normalize_optional_field(self.bio);
+        self.nationality = normalize_optional_field(self.nationality);
+        self.website = normalize_optional_field(self.website);
+        self.aliases = normalize_aliases(self.aliases, name);
+        self
+    }
+
+    /// Checks that the years are in order and the website is an http(s) URL.
+    pub fn validate(&self) -> Result<(), String> {
+        validate_years(self.birth_year, self.death_year)?;
+        match &self.website {
+            Some(website) => validate_website(website),
+            None => Ok(()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NewAuthor {
     pub name: String,
+    #[serde(flatten)]
+    pub details: AuthorDetails,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub created_at: Option<DateTime<Utc>>,
 }
@@ -22,6 +63,7 @@ pub struct NewAuthor {
 impl NewAuthor {
     pub fn normalize(mut self) -> Self {
         self.name = self.name.trim().to_string();
+        self.details = self.details.normalize(&self.name);
         self
     }
 }
@@ -38,17 +80,96 @@ impl Author {
             details: vec![],
             genres: vec![],
             reading_data: None,
+            changes: vec![],
         }
     }
 }
 
+/// Text fields set to an empty string are cleared.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct UpdateAuthor {
     pub name: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bio: Option<String>,
+    /// `None` = don't change, `Some(None)` = clear, `Some(Some(year))` = set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub birth_year: Option<Option<i32>>,
+    /// `None` = don't change, `Some(None)` = clear, `Some(Some(year))` = set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub death_year: Option<Option<i32>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nationality: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+    /// Replaces the full alias list when present.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aliases: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub created_at: Option<DateTime<Utc>>,
 }
 
+impl UpdateAuthor {
+    pub fn normalize(mut self) -> Self {
+        let trim = |value: Option<String>| value.map(|v| v.trim().to_string());
+        self.bio = trim(self.bio);
+        self.nationality = trim(self.nationality);
+        self.website = trim(self.website);
+        self
+    }
+
+    /// Applies the changes to `existing` and validates the result, so that a
+    /// death year can't be moved before a birth year set earlier.
+    pub fn validate_against(&self, existing: &Author) -> Result<(), String> {
+        let birth_year = self.birth_year.unwrap_or(existing.details.birth_year);
+        let death_year = self.death_year.unwrap_or(existing.details.death_year);
+        validate_years(birth_year, death_year)?;
+        match self.website.as_deref() {
+            Some(website) if !website.is_empty() => validate_website(website),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn normalize_optional_field(value: Option<String>) -> Option<String> {
+    value
+        .map(|raw| raw.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// Trims aliases and drops blanks, case-insensitive duplicates and the
+/// author's own name.
+pub fn normalize_aliases(aliases: Vec<String>, name: &str) -> Vec<String> {
+    let mut seen = vec![name.trim().to_lowercase()];
+    aliases
+        .into_iter()
+        .map(|alias| alias.trim().to_string())
+        .filter(|alias| {
+            let key = alias.to_lowercase();
+            if alias.is_empty() || seen.contains(&key) {
+                return false;
+            }
+            seen.push(key);
+            true
+        })
+        .collect()
+}
+
+fn validate_years(birth_year: Option<i32>, death_year: Option<i32>) -> Result<(), String> {
+    match (birth_year, death_year) {
+        (Some(birth), Some(death)) if death < birth => {
+            Err("death year cannot be before birth year".to_string())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn validate_website(website: &str) -> Result<(), String> {
+    match url::Url::parse(website) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") => Ok(()),
+        _ => Err("website must be an http(s) URL".to_string()),
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum AuthorSortKey {
     CreatedAt,
@@ -91,6 +212,7 @@ mod tests {
     fn normalize_trims_name() {
         let author = NewAuthor {
             name: "  J.R.R. Tolkien  ".to_string(),
+            details: AuthorDetails::default(),
             created_at: None,
         }
         .normalize();
@@ -101,9 +223,82 @@ mod tests {
     fn normalize_whitespace_only_name() {
         let author = NewAuthor {
             name: "   ".to_string(),
+            details: AuthorDetails::default(),
             created_at: None,
         }
         .normalize();
         assert_eq!(author.name, "");
     }
+
+    #[test]
+    fn normalize_cleans_details() {
+        let author = NewAuthor {
+            name: "J.K. Rowling".to_string(),
+            details: AuthorDetails {
+                bio: Some("  ".to_string()),
+                nationality: Some(" British ".to_string()),
+                aliases: vec![
+                    " Robert Galbraith ".to_string(),
+                    "robert galbraith".to_string(),
+                    "j.k. rowling".to_string(),
+                    String::new(),
+                ],
+                ..AuthorDetails::default()
+            },
+            created_at: None,
+        }
+        .normalize();
+        assert_eq!(author.details.bio, None);
+        assert_eq!(author.details.nationality.as_deref(), Some("British"));
+        assert_eq!(author.details.aliases, ["Robert Galbraith"]);
+    }
+
+    #[test]
+    fn validate_rejects_bad_years_and_websites() {
+        let details = AuthorDetails {
+            birth_year: Some(1950),
+            death_year: Some(1900),
+            ..AuthorDetails::default()
+        };
+        assert!(details.validate().is_err());
+
+        let details = AuthorDetails {
+            website: Some("ftp://example.com".to_string()),
+            ..AuthorDetails::default()
+        };
+        assert!(details.validate().is_err());
+
+        let details = AuthorDetails {
+            birth_year: Some(1903),
+            death_year: Some(1950),
+            website: Some("https://example.com".to_string()),
+            ..AuthorDetails::default()
+        };
+        assert!(details.validate().is_ok());
+    }
+
+    #[test]
+    fn update_validation_considers_existing_years() {
+        let existing = Author {
+            id: AuthorId::from(1),
+            name: "George Orwell".to_string(),
+            details: AuthorDetails {
+                birth_year: Some(1903),
+                ..AuthorDetails::default()
+            },
+            created_at: Utc::now(),
+        };
+        let update = UpdateAuthor {
+            death_year: Some(Some(1850)),
+            ..UpdateAuthor::default()
+        };
+        assert!(update.validate_against(&existing).is_err());
+
+        let update = UpdateAuthor {
+            birth_year: Some(None),
+            death_year: Some(Some(1850)),
+            ..UpdateAuthor::default()
+        };
+        assert!(update.validate_against(&existing).is_ok());
+    }
 }
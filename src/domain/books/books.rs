@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use crate::domain::books::authors::Author;
 use crate::domain::ids::{AuthorId, BookId, GenreId, UserId};
 use crate::domain::listing::{SortDirection, SortKey};
-use crate::domain::timeline::{NewTimelineEvent, TimelineEventDetail};
+use crate::domain::timeline::{NewTimelineEvent, TimelineEventDetail, TimelineField};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Book {
@@ -15,6 +15,8 @@ pub struct Book {
     pub isbn: Option<String>,
     pub description: Option<String>,
     pub page_count: Option<i32>,
+    /// Audiobook running time.
+    pub duration_minutes: Option<i32>,
     pub year_published: Option<i32>,
     pub publisher: Option<String>,
     pub language: Option<String>,
@@ -61,6 +63,7 @@ pub enum AuthorRole {
     Author,
     Editor,
     Translator,
+    Narrator,
 }
 
 impl AuthorRole {
@@ -69,6 +72,7 @@ impl AuthorRole {
             AuthorRole::Author => "author",
             AuthorRole::Editor => "editor",
             AuthorRole::Translator => "translator",
+            AuthorRole::Narrator => "narrator",
         }
     }
 
@@ -77,6 +81,7 @@ impl AuthorRole {
             AuthorRole::Author => "Author",
             AuthorRole::Editor => "Editor",
             AuthorRole::Translator => "Translator",
+            AuthorRole::Narrator => "Narrator",
         }
     }
 }
@@ -89,6 +94,7 @@ impl FromStr for AuthorRole {
             "author" => Ok(AuthorRole::Author),
             "editor" => Ok(AuthorRole::Editor),
             "translator" => Ok(AuthorRole::Translator),
+            "narrator" => Ok(AuthorRole::Narrator),
             _ => Err(()),
         }
     }
@@ -108,6 +114,7 @@ pub struct NewBook {
     pub isbn: Option<String>,
     pub description: Option<String>,
     pub page_count: Option<i32>,
+    pub duration_minutes: Option<i32>,
     pub year_published: Option<i32>,
     pub publisher: Option<String>,
     pub language: Option<String>,
@@ -126,6 +133,7 @@ impl NewBook {
         self.publisher = normalize_optional_field(self.publisher);
         self.language = normalize_optional_field(self.language);
         self.page_count = self.page_count.filter(|&p| p > 0);
+        self.duration_minutes = self.duration_minutes.filter(|&m| m > 0);
         self
     }
 }
@@ -136,6 +144,7 @@ pub struct UpdateBook {
     pub isbn: Option<String>,
     pub description: Option<String>,
     pub page_count: Option<i32>,
+    pub duration_minutes: Option<i32>,
     pub year_published: Option<i32>,
     pub publisher: Option<String>,
     pub language: Option<String>,
@@ -155,6 +164,7 @@ impl UpdateBook {
         self.publisher = normalize_optional_field(self.publisher);
         self.language = normalize_optional_field(self.language);
         self.page_count = self.page_count.filter(|&p| p > 0);
+        self.duration_minutes = self.duration_minutes.filter(|&m| m > 0);
         self
     }
 }
@@ -227,16 +237,16 @@ pub fn book_timeline_event(
         .filter_map(|g| g.map(String::from))
         .collect();
     if !genres.is_empty() {
-        details.push(TimelineEventDetail {
-            label: "Genres".to_string(),
-            value: genres.join(", "),
-        });
+        details.push(TimelineEventDetail::new(
+            TimelineField::Genres,
+            genres.join(", "),
+        ));
     }
     if let Some(pages) = book.page_count {
-        details.push(TimelineEventDetail {
-            label: "Pages".to_string(),
-            value: format!("{pages}"),
-        });
+        details.push(TimelineEventDetail::new(
+            TimelineField::Pages,
+            format!("{pages}"),
+        ));
     }
     NewTimelineEvent {
         user_id: Some(user_id),
@@ -248,6 +258,7 @@ pub fn book_timeline_event(
         details,
         genres,
         reading_data: None,
+        changes: vec![],
     }
 }
 
@@ -299,6 +310,7 @@ mod tests {
             isbn: None,
             description: None,
             page_count: None,
+            duration_minutes: None,
             year_published: None,
             publisher: None,
             language: None,
@@ -318,6 +330,7 @@ mod tests {
             isbn: Some("  ".to_string()),
             description: Some("".to_string()),
             page_count: None,
+            duration_minutes: None,
             year_published: None,
             publisher: Some("   ".to_string()),
             language: None,
@@ -339,6 +352,7 @@ mod tests {
             isbn: Some("  978-0-13-468599-1  ".to_string()),
             description: None,
             page_count: None,
+            duration_minutes: None,
             year_published: None,
             publisher: Some("  O'Reilly  ".to_string()),
             language: None,
@@ -359,6 +373,7 @@ mod tests {
             isbn: None,
             description: None,
             page_count: Some(0),
+            duration_minutes: None,
             year_published: None,
             publisher: None,
             language: None,
@@ -378,6 +393,7 @@ mod tests {
             isbn: None,
             description: None,
             page_count: Some(-5),
+            duration_minutes: None,
             year_published: None,
             publisher: None,
             language: None,
@@ -397,6 +413,7 @@ mod tests {
             isbn: None,
             description: None,
             page_count: Some(300),
+            duration_minutes: None,
             year_published: None,
             publisher: None,
             language: None,
@@ -1,7 +1,9 @@
+use std::collections::{HashMap, HashSet};
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::domain::ids::{GenreId, UserId};
+use crate::domain::ids::{BookId, GenreId, UserId};
 use crate::domain::listing::{SortDirection, SortKey};
 use crate::domain::timeline::NewTimelineEvent;
 
@@ -9,6 +11,9 @@ use crate::domain::timeline::NewTimelineEvent;
 pub struct Genre {
     pub id: GenreId,
     pub name: String,
+    /// Broader genre this one sits under, e.g. "Science Fiction" for "Space Opera".
+    #[serde(default)]
+    pub parent_id: Option<GenreId>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -16,6 +21,8 @@ pub struct Genre {
 pub struct NewGenre {
     pub name: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<GenreId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub created_at: Option<DateTime<Utc>>,
 }
 
@@ -38,6 +45,7 @@ impl Genre {
             details: vec![],
             genres: vec![],
             reading_data: None,
+            changes: vec![],
         }
     }
 }
@@ -45,10 +53,156 @@ impl Genre {
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct UpdateGenre {
     pub name: Option<String>,
+    /// `None` = don't change, `Some(None)` = clear, `Some(Some(id))` = set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<Option<GenreId>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub created_at: Option<DateTime<Utc>>,
 }
 
+/// The genre hierarchy built from a flat genre list, for walking parents and
+/// subgenres without further queries.
+#[derive(Debug, Clone, Default)]
+pub struct GenreTree {
+    genres: Vec<Genre>,
+    index: HashMap<GenreId, usize>,
+    children: HashMap<GenreId, Vec<usize>>,
+}
+
+impl GenreTree {
+    pub fn new(mut genres: Vec<Genre>) -> Self {
+        genres.sort_by_key(|genre| genre.name.to_lowercase());
+        let index: HashMap<GenreId, usize> = genres
+            .iter()
+            .enumerate()
+            .map(|(position, genre)| (genre.id, position))
+            .collect();
+        let mut children: HashMap<GenreId, Vec<usize>> = HashMap::new();
+        for (position, genre) in genres.iter().enumerate() {
+            if let Some(parent_id) = genre.parent_id.filter(|id| index.contains_key(id)) {
+                children.entry(parent_id).or_default().push(position);
+            }
+        }
+        Self {
+            genres,
+            index,
+            children,
+        }
+    }
+
+    pub fn get(&self, id: GenreId) -> Option<&Genre> {
+        self.index.get(&id).map(|&position| &self.genres[position])
+    }
+
+    /// Genres without a (known) parent, sorted by name.
+    pub fn roots(&self) -> Vec<&Genre> {
+        self.genres
+            .iter()
+            .filter(|genre| self.parent(genre.id).is_none())
+            .collect()
+    }
+
+    /// Direct subgenres of `id`, sorted by name.
+    pub fn children(&self, id: GenreId) -> Vec<&Genre> {
+        self.children
+            .get(&id)
+            .map(|positions| positions.iter().map(|&p| &self.genres[p]).collect())
+            .unwrap_or_default()
+    }
+
+    fn parent(&self, id: GenreId) -> Option<&Genre> {
+        self.get(id)
+            .and_then(|genre| genre.parent_id)
+            .and_then(|parent_id| self.get(parent_id))
+    }
+
+    /// Ancestors of `id`, from the root down to its direct parent.
+    pub fn ancestors(&self, id: GenreId) -> Vec<&Genre> {
+        let mut ancestors = Vec::new();
+        let mut seen = HashSet::from([id]);
+        let mut current = self.parent(id);
+        while let Some(genre) = current {
+            if !seen.insert(genre.id) {
+                break;
+            }
+            ancestors.push(genre);
+            current = self.parent(genre.id);
+        }
+        ancestors.reverse();
+        ancestors
+    }
+
+    /// `id` plus every genre below it, at any depth.
+    pub fn descendant_ids(&self, id: GenreId) -> Vec<GenreId> {
+        let mut ids = vec![id];
+        let mut seen = HashSet::from([id]);
+        let mut next = 0;
+        while next < ids.len() {
+            for child in self.children(ids[next]) {
+                if seen.insert(child.id) {
+                    ids.push(child.id);
+                }
+            }
+            next += 1;
+        }
+        ids
+    }
+
+    /// Whether making `parent_id` the parent of `id` would loop back on itself.
+    pub fn would_create_cycle(&self, id: GenreId, parent_id: GenreId) -> bool {
+        parent_id == id || self.ancestors(parent_id).iter().any(|g| g.id == id)
+    }
+
+    /// Full name of a genre including its ancestors, e.g. "Science Fiction › Space Opera".
+    pub fn path_name(&self, id: GenreId) -> Option<String> {
+        let genre = self.get(id)?;
+        let mut names: Vec<&str> = self.ancestors(id).iter().map(|g| g.name.as_str()).collect();
+        names.push(&genre.name);
+        Some(names.join(" › "))
+    }
+
+    /// Counts books per genre, counting each book once under every genre it
+    /// has and under each of their ancestors.
+    pub fn rolled_up_counts<I>(&self, book_genres: I) -> HashMap<GenreId, u64>
+    where
+        I: IntoIterator<Item = (BookId, GenreId)>,
+    {
+        let mut per_book: HashMap<BookId, HashSet<GenreId>> = HashMap::new();
+        for (book_id, genre_id) in book_genres {
+            let genres = per_book.entry(book_id).or_default();
+            genres.insert(genre_id);
+            genres.extend(self.ancestors(genre_id).iter().map(|g| g.id));
+        }
+
+        let mut counts: HashMap<GenreId, u64> = HashMap::new();
+        for genre_id in per_book.into_values().flatten() {
+            *counts.entry(genre_id).or_default() += 1;
+        }
+        counts
+    }
+
+    /// Indented bullet outline of the hierarchy, one genre per line.
+    pub fn outline(&self) -> String {
+        let mut lines = Vec::new();
+        let mut stack: Vec<(&Genre, usize)> =
+            self.roots().into_iter().rev().map(|g| (g, 0)).collect();
+        let mut seen = HashSet::new();
+        while let Some((genre, depth)) = stack.pop() {
+            if !seen.insert(genre.id) {
+                continue;
+            }
+            lines.push(format!("{}- {}", "  ".repeat(depth), genre.name));
+            stack.extend(
+                self.children(genre.id)
+                    .into_iter()
+                    .rev()
+                    .map(|child| (child, depth + 1)),
+            );
+        }
+        lines.join("\n")
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum GenreSortKey {
     CreatedAt,
@@ -91,6 +245,7 @@ mod tests {
     fn normalize_trims_name() {
         let genre = NewGenre {
             name: "  Science Fiction  ".to_string(),
+            parent_id: None,
             created_at: None,
         }
         .normalize();
@@ -101,9 +256,81 @@ mod tests {
     fn normalize_whitespace_only_name() {
         let genre = NewGenre {
             name: "   ".to_string(),
+            parent_id: None,
             created_at: None,
         }
         .normalize();
         assert_eq!(genre.name, "");
     }
+
+    fn genre(id: i64, name: &str, parent_id: Option<i64>) -> Genre {
+        Genre {
+            id: GenreId::from(id),
+            name: name.to_string(),
+            parent_id: parent_id.map(GenreId::from),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn sample_tree() -> GenreTree {
+        GenreTree::new(vec![
+            genre(1, "Science Fiction", None),
+            genre(2, "Space Opera", Some(1)),
+            genre(3, "Military Space Opera", Some(2)),
+            genre(4, "Cyberpunk", Some(1)),
+            genre(5, "Fantasy", None),
+        ])
+    }
+
+    #[test]
+    fn tree_walks_ancestors_and_descendants() {
+        let tree = sample_tree();
+        let names: Vec<&str> = tree
+            .ancestors(GenreId::from(3))
+            .iter()
+            .map(|g| g.name.as_str())
+            .collect();
+        assert_eq!(names, ["Science Fiction", "Space Opera"]);
+
+        let mut ids = tree.descendant_ids(GenreId::from(1));
+        ids.sort_by_key(|id| id.into_inner());
+        assert_eq!(ids, [1, 2, 3, 4].map(GenreId::from));
+        assert_eq!(
+            tree.path_name(GenreId::from(3)).as_deref(),
+            Some("Science Fiction › Space Opera › Military Space Opera")
+        );
+    }
+
+    #[test]
+    fn tree_detects_cycles() {
+        let tree = sample_tree();
+        assert!(tree.would_create_cycle(GenreId::from(1), GenreId::from(1)));
+        assert!(tree.would_create_cycle(GenreId::from(1), GenreId::from(3)));
+        assert!(!tree.would_create_cycle(GenreId::from(3), GenreId::from(4)));
+        assert!(!tree.would_create_cycle(GenreId::from(5), GenreId::from(2)));
+    }
+
+    #[test]
+    fn rolled_up_counts_count_each_book_once_per_genre() {
+        let tree = sample_tree();
+        let counts = tree.rolled_up_counts([
+            (BookId::from(10), GenreId::from(3)),
+            (BookId::from(10), GenreId::from(1)),
+            (BookId::from(11), GenreId::from(4)),
+            (BookId::from(12), GenreId::from(5)),
+        ]);
+        assert_eq!(counts[&GenreId::from(1)], 2);
+        assert_eq!(counts[&GenreId::from(2)], 1);
+        assert_eq!(counts[&GenreId::from(3)], 1);
+        assert_eq!(counts[&GenreId::from(4)], 1);
+        assert_eq!(counts[&GenreId::from(5)], 1);
+    }
+
+    #[test]
+    fn outline_indents_subgenres() {
+        assert_eq!(
+            sample_tree().outline(),
+            "- Fantasy\n- Science Fiction\n  - Cyberpunk\n  - Space Opera\n    - Military Space Opera"
+        );
+    }
 }
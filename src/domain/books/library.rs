@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::books::authors::Author;
+use crate::domain::books::books::BookWithAuthors;
+use crate::domain::books::genres::Genre;
+use crate::domain::books::readings::Reading;
+use crate::domain::books::user_books::Shelf;
+
+/// Everything in one user's library, in a single document the CLI turns
+/// into static sites and notes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryExport {
+    pub username: String,
+    pub exported_at: DateTime<Utc>,
+    /// Oldest library entry first.
+    pub books: Vec<LibraryBook>,
+    /// Authors of the books above, by name.
+    pub authors: Vec<Author>,
+    pub genres: Vec<Genre>,
+}
+
+/// A book on the user's library or wishlist shelf, with their own readings
+/// and tags.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryBook {
+    #[serde(flatten)]
+    pub book: BookWithAuthors,
+    pub shelf: Shelf,
+    /// When the book went on the user's shelf.
+    pub added_at: DateTime<Utc>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Oldest first, including private readings.
+    #[serde(default)]
+    pub readings: Vec<Reading>,
+    /// Whether `/api/v1/book/{id}/image` has a cover to fetch.
+    #[serde(default)]
+    pub has_cover: bool,
+}
+
+impl LibraryBook {
+    /// Readings that may be published, leaving out private ones.
+    pub fn public_readings(&self) -> impl DoubleEndedIterator<Item = &Reading> {
+        self.readings.iter().filter(|reading| !reading.private)
+    }
+}
@@ -2,6 +2,9 @@
 pub mod authors;
 pub mod books;
 pub mod genres;
+pub mod library;
 pub mod quick_reviews;
 pub mod readings;
+pub mod shelves;
+pub mod tags;
 pub mod user_books;
@@ -7,7 +7,9 @@ use crate::domain::books::authors::Author;
 use crate::domain::books::books::Book;
 use crate::domain::ids::{BookId, ReadingId, UserId};
 use crate::domain::listing::{SortDirection, SortKey};
-use crate::domain::timeline::{NewTimelineEvent, TimelineEventDetail};
+use crate::domain::timeline::{
+    NewTimelineEvent, TimelineEventChange, TimelineEventDetail, TimelineField,
+};
 
 pub use super::quick_reviews::{QuickReview, Sentiment};
 
@@ -102,6 +104,12 @@ pub struct Reading {
     pub finished_at: Option<NaiveDate>,
     pub rating: Option<f64>,
     pub quick_reviews: Vec<QuickReview>,
+    /// Hidden from public profiles and from other users' timelines.
+    #[serde(default)]
+    pub private: bool,
+    /// Page reached so far, for progress milestones.
+    #[serde(default)]
+    pub current_page: Option<i32>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -131,6 +139,8 @@ pub struct NewReading {
     pub rating: Option<f64>,
     #[serde(default)]
     pub quick_reviews: Vec<QuickReview>,
+    #[serde(default)]
+    pub private: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub created_at: Option<DateTime<Utc>>,
 }
@@ -146,6 +156,10 @@ pub struct UpdateReading {
     pub rating: Option<f64>,
     pub quick_reviews: Option<Vec<QuickReview>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_page: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub created_at: Option<DateTime<Utc>>,
 }
 
@@ -264,25 +278,25 @@ pub fn reading_timeline_event(
     let mut details = vec![TimelineEventDetail::author_detail(&author_names)];
 
     if let Some(fmt) = reading.format {
-        details.push(TimelineEventDetail {
-            label: "Format".to_string(),
-            value: fmt.display_label().to_string(),
-        });
+        details.push(TimelineEventDetail::new(
+            TimelineField::Format,
+            fmt.display_label().to_string(),
+        ));
     }
 
     if let Some(rating) = reading.rating {
-        details.push(TimelineEventDetail {
-            label: "Rating".to_string(),
-            value: crate::domain::formatting::format_rating(rating),
-        });
+        details.push(TimelineEventDetail::new(
+            TimelineField::Rating,
+            crate::domain::formatting::format_rating(rating),
+        ));
     }
 
     if !reading.quick_reviews.is_empty() {
         let labels: Vec<&str> = reading.quick_reviews.iter().map(|r| r.label()).collect();
-        details.push(TimelineEventDetail {
-            label: "Notes".to_string(),
-            value: labels.join(", "),
-        });
+        details.push(TimelineEventDetail::new(
+            TimelineField::QuickReviews,
+            labels.join(", "),
+        ));
     }
 
     let occurred_at = match reading.status {
@@ -300,9 +314,103 @@ pub fn reading_timeline_event(
         details,
         genres: vec![],
         reading_data: None,
+        changes: vec![],
     }
 }
 
+/// An event for an update that changed a reading's status or rating, with
+/// the before/after values. Status transitions take the new status's
+/// action; a rating change on its own is recorded as `rated`. Returns
+/// `None` when neither changed.
+pub fn reading_change_event(
+    before: &Reading,
+    after: &Reading,
+    book: &Book,
+    authors: &[Author],
+) -> Option<NewTimelineEvent> {
+    let mut changes = Vec::new();
+    if before.status != after.status {
+        changes.push(TimelineEventChange::new(
+            TimelineField::Status,
+            Some(before.status.display_label().to_string()),
+            Some(after.status.display_label().to_string()),
+        ));
+    }
+    if before.rating != after.rating {
+        changes.push(TimelineEventChange::new(
+            TimelineField::Rating,
+            before.rating.map(crate::domain::formatting::format_rating),
+            after.rating.map(crate::domain::formatting::format_rating),
+        ));
+    }
+    if changes.is_empty() {
+        return None;
+    }
+
+    let mut event = reading_timeline_event(after, book, authors);
+    if before.status == after.status {
+        event.action = "rated".to_string();
+    }
+    event.occurred_at = after.updated_at;
+    event.changes = changes;
+    Some(event)
+}
+
+/// Shares of a book at which reading progress records a milestone.
+/// Finishing is recorded as `finished` rather than a 100% milestone.
+pub const READING_MILESTONES: [u8; 3] = [25, 50, 75];
+
+/// The highest of `READING_MILESTONES` that `page` reaches in a book of
+/// `page_count` pages.
+pub fn reading_milestone(page: Option<i32>, page_count: Option<i32>) -> Option<u8> {
+    let page = i64::from(page?);
+    let page_count = i64::from(page_count.filter(|&count| count > 0)?);
+    let percent = page * 100 / page_count;
+    READING_MILESTONES
+        .iter()
+        .rev()
+        .copied()
+        .find(|m| percent >= i64::from(*m))
+}
+
+/// An event for a progress update that took a reading in progress past a
+/// milestone beyond `previous`, the last one recorded. Returns `None` when
+/// no new milestone was reached, and for books without a page count.
+pub fn reading_milestone_event(
+    before: &Reading,
+    after: &Reading,
+    previous: Option<u8>,
+    book: &Book,
+    authors: &[Author],
+) -> Option<NewTimelineEvent> {
+    if after.status != ReadingStatus::Reading {
+        return None;
+    }
+    let milestone =
+        reading_milestone(after.current_page, book.page_count).filter(|m| Some(*m) > previous)?;
+    let pages = |page: Option<i32>| {
+        page.zip(book.page_count)
+            .map(|(page, count)| format!("{page} of {count} pages"))
+    };
+
+    let mut event = reading_timeline_event(after, book, authors);
+    event.action = "milestone".to_string();
+    event.occurred_at = after.updated_at;
+    event.changes = vec![
+        TimelineEventChange::new(
+            TimelineField::Milestone,
+            previous.map(|m| format!("{m}%")),
+            Some(format!("{milestone}%")),
+        ),
+        TimelineEventChange::new(
+            TimelineField::Progress,
+            pages(before.current_page),
+            pages(after.current_page),
+        ),
+    ];
+    Some(event)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -412,6 +520,23 @@ mod tests {
         }
     }
 
+    // --- Progress milestones ---
+
+    #[test]
+    fn reading_milestone_is_the_highest_share_reached() {
+        assert_eq!(reading_milestone(Some(99), Some(400)), None);
+        assert_eq!(reading_milestone(Some(100), Some(400)), Some(25));
+        assert_eq!(reading_milestone(Some(250), Some(400)), Some(50));
+        assert_eq!(reading_milestone(Some(400), Some(400)), Some(75));
+    }
+
+    #[test]
+    fn reading_milestone_needs_a_page_and_page_count() {
+        assert_eq!(reading_milestone(None, Some(400)), None);
+        assert_eq!(reading_milestone(Some(300), None), None);
+        assert_eq!(reading_milestone(Some(300), Some(0)), None);
+    }
+
     // --- QuickReview ---
 
     #[test]
@@ -0,0 +1,163 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::book_items::BookWithAuthors;
+use crate::domain::ids::{ShelfId, UserId};
+
+/// How a saved shelf decides which books it holds.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ShelfKind {
+    /// Hand-picked books in a user-chosen order.
+    #[default]
+    Manual,
+    /// Books in the user's library matching a saved filter query.
+    Smart,
+}
+
+impl ShelfKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ShelfKind::Manual => "manual",
+            ShelfKind::Smart => "smart",
+        }
+    }
+
+    pub fn display_label(&self) -> &'static str {
+        match self {
+            ShelfKind::Manual => "Manual",
+            ShelfKind::Smart => "Smart",
+        }
+    }
+}
+
+impl FromStr for ShelfKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "manual" => Ok(ShelfKind::Manual),
+            "smart" => Ok(ShelfKind::Smart),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A user-defined shelf, distinct from the fixed library/wishlist [`Shelf`](super::user_books::Shelf).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedShelf {
+    pub id: ShelfId,
+    pub user_id: UserId,
+    pub name: String,
+    pub description: Option<String>,
+    pub kind: ShelfKind,
+    /// Filter query for smart shelves, e.g. `shelf:library -status:read pages<300`.
+    pub query: Option<String>,
+    pub pinned: bool,
+    /// Listed on the owner's public profile.
+    #[serde(default)]
+    pub public: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedShelfWithBooks {
+    #[serde(flatten)]
+    pub shelf: SavedShelf,
+    pub books: Vec<BookWithAuthors>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewSavedShelf {
+    pub user_id: UserId,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub kind: ShelfKind,
+    #[serde(default)]
+    pub query: Option<String>,
+    #[serde(default)]
+    pub pinned: bool,
+    #[serde(default)]
+    pub public: bool,
+}
+
+impl NewSavedShelf {
+    /// Trims text fields and drops blank optional values. Manual shelves never
+    /// carry a query.
+    pub fn normalize(mut self) -> Self {
+        self.name = self.name.trim().to_string();
+        self.description = non_blank(self.description);
+        self.query = match self.kind {
+            ShelfKind::Manual => None,
+            ShelfKind::Smart => non_blank(self.query),
+        };
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateSavedShelf {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub query: Option<String>,
+    pub pinned: Option<bool>,
+    pub public: Option<bool>,
+}
+
+impl UpdateSavedShelf {
+    pub fn normalize(mut self) -> Self {
+        self.name = self.name.map(|n| n.trim().to_string());
+        self.description = self.description.map(|d| d.trim().to_string());
+        self.query = self.query.map(|q| q.trim().to_string());
+        self
+    }
+}
+
+fn non_blank(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_shelf(kind: ShelfKind, query: Option<&str>) -> NewSavedShelf {
+        NewSavedShelf {
+            user_id: UserId::new(1),
+            name: "  Short reads ".to_string(),
+            description: Some("   ".to_string()),
+            kind,
+            query: query.map(String::from),
+            pinned: false,
+            public: false,
+        }
+    }
+
+    #[test]
+    fn normalize_trims_and_drops_blanks() {
+        let shelf = new_shelf(ShelfKind::Smart, Some(" pages<300 ")).normalize();
+        assert_eq!(shelf.name, "Short reads");
+        assert_eq!(shelf.description, None);
+        assert_eq!(shelf.query.as_deref(), Some("pages<300"));
+    }
+
+    #[test]
+    fn normalize_clears_query_on_manual_shelves() {
+        let shelf = new_shelf(ShelfKind::Manual, Some("pages<300")).normalize();
+        assert_eq!(shelf.query, None);
+    }
+
+    #[test]
+    fn shelf_kind_roundtrip() {
+        for kind in [ShelfKind::Manual, ShelfKind::Smart] {
+            assert_eq!(kind.as_str().parse::<ShelfKind>(), Ok(kind));
+        }
+        assert!("dynamic".parse::<ShelfKind>().is_err());
+    }
+}
@@ -0,0 +1,77 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::ids::{BookId, UserId};
+
+/// Longest tag accepted after normalisation.
+pub const MAX_TAG_LENGTH: usize = 50;
+
+/// Normalises a tag to its stored form: trimmed, lowercase, without a leading
+/// `#`, and with inner whitespace collapsed to single hyphens. Returns `None`
+/// for tags that are empty or too long.
+pub fn normalize_tag(raw: &str) -> Option<String> {
+    let trimmed = raw.trim().trim_start_matches('#');
+    let tag = trimmed
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase();
+    if tag.is_empty() || tag.chars().count() > MAX_TAG_LENGTH {
+        return None;
+    }
+    Some(tag)
+}
+
+/// Normalises a list of tags, dropping invalid entries and duplicates.
+pub fn normalize_tags<I, S>(raw: I) -> Vec<String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut tags: Vec<String> = raw
+        .into_iter()
+        .filter_map(|tag| normalize_tag(tag.as_ref()))
+        .collect();
+    tags.sort();
+    tags.dedup();
+    tags
+}
+
+/// A tag a user has attached to a book.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BookTag {
+    pub user_id: UserId,
+    pub book_id: BookId,
+    pub tag: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// How many of a user's books carry a tag.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct TagCount {
+    pub tag: String,
+    pub count: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_tag_lowercases_and_hyphenates() {
+        assert_eq!(
+            normalize_tag("  #Comfort   Read "),
+            Some("comfort-read".to_string())
+        );
+        assert_eq!(normalize_tag("#"), None);
+        assert_eq!(normalize_tag(&"x".repeat(MAX_TAG_LENGTH + 1)), None);
+    }
+
+    #[test]
+    fn normalize_tags_dedupes_and_sorts() {
+        assert_eq!(
+            normalize_tags(["Sci Fi", "cosy", "sci-fi", ""]),
+            vec!["cosy".to_string(), "sci-fi".to_string()]
+        );
+    }
+}
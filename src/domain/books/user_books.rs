@@ -7,7 +7,9 @@ use crate::domain::books::authors::Author;
 use crate::domain::books::books::Book;
 use crate::domain::ids::{BookId, ReadingId, UserBookId, UserId};
 use crate::domain::listing::{SortDirection, SortKey};
-use crate::domain::timeline::{NewTimelineEvent, TimelineEventDetail};
+use crate::domain::timeline::{
+    NewTimelineEvent, TimelineEventChange, TimelineEventDetail, TimelineField,
+};
 
 use super::books::BookWithAuthors;
 use super::readings::ReadingStatus;
@@ -64,6 +66,19 @@ pub struct UserBookWithDetails {
     pub user_book: UserBook,
     pub book: BookWithAuthors,
     pub reading_summary: Option<ReadingSummary>,
+    /// The user's personal tags on this book.
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// A library entry with the user's tags on the book, as returned by the
+/// user-books list endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserBookWithTags {
+    #[serde(flatten)]
+    pub user_book: UserBook,
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 /// Summary of the most recent reading for a user-book pair.
@@ -149,9 +164,57 @@ pub fn user_book_timeline_event(
         details,
         genres: vec![],
         reading_data: None,
+        changes: vec![],
     }
 }
 
+/// An event for a shelf move or book club change, with the before/after
+/// values. Returns `None` when neither changed.
+pub fn user_book_change_event(
+    before: &UserBook,
+    after: &UserBook,
+    book: &Book,
+    authors: &[Author],
+    occurred_at: DateTime<Utc>,
+) -> Option<NewTimelineEvent> {
+    let yes_no = |b: bool| if b { "Yes" } else { "No" }.to_string();
+    let (action, change) = if before.shelf != after.shelf {
+        (
+            "moved",
+            TimelineEventChange::new(
+                TimelineField::Shelf,
+                Some(before.shelf.display_label().to_string()),
+                Some(after.shelf.display_label().to_string()),
+            ),
+        )
+    } else if before.book_club != after.book_club {
+        (
+            "book_club",
+            TimelineEventChange::new(
+                TimelineField::BookClub,
+                Some(yes_no(before.book_club)),
+                Some(yes_no(after.book_club)),
+            ),
+        )
+    } else {
+        return None;
+    };
+
+    let author_names: Vec<&str> = authors.iter().map(|a| a.name.as_str()).collect();
+    Some(NewTimelineEvent {
+        user_id: Some(after.user_id),
+        entity_type: "user_book".to_string(),
+        entity_id: book.id.into_inner(),
+        action: action.to_string(),
+        occurred_at,
+        title: book.title.clone(),
+        details: vec![TimelineEventDetail::author_detail(&author_names)],
+        genres: vec![],
+        reading_data: None,
+        changes: vec![change],
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,136 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::ids::{FollowerId, ReadingId, UserId};
+
+/// A user who has opted in to federation, with the key pair their
+/// outgoing `ActivityPub` requests are signed with.
+#[derive(Debug, Clone)]
+pub struct Actor {
+    pub user_id: UserId,
+    /// Scheme and host the actor's URIs live under, e.g. `https://books.example`.
+    pub origin: String,
+    pub public_key_pem: String,
+    pub private_key_pem: String,
+    /// Hold new followers for approval instead of accepting them.
+    pub manually_approves_followers: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewActor {
+    pub user_id: UserId,
+    pub origin: String,
+    pub public_key_pem: String,
+    pub private_key_pem: String,
+    pub manually_approves_followers: bool,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FollowerStatus {
+    /// Waiting for the user to approve the follow.
+    Pending,
+    /// Receives the user's activities.
+    Accepted,
+}
+
+impl FollowerStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FollowerStatus::Pending => "pending",
+            FollowerStatus::Accepted => "accepted",
+        }
+    }
+}
+
+impl FromStr for FollowerStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(FollowerStatus::Pending),
+            "accepted" => Ok(FollowerStatus::Accepted),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A remote actor following one of our users.
+#[derive(Debug, Clone)]
+pub struct Follower {
+    pub id: FollowerId,
+    pub user_id: UserId,
+    pub actor_uri: String,
+    pub inbox: String,
+    pub shared_inbox: Option<String>,
+    /// The remote `Follow` activity as JSON, echoed back when answering it.
+    pub follow_activity: String,
+    pub status: FollowerStatus,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Follower {
+    pub fn is_accepted(&self) -> bool {
+        self.status == FollowerStatus::Accepted
+    }
+
+    /// Where activities for this follower are delivered. Followers on the
+    /// same server share an inbox, so one delivery reaches them all.
+    pub fn delivery_inbox(&self) -> &str {
+        self.shared_inbox.as_deref().unwrap_or(&self.inbox)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewFollower {
+    pub user_id: UserId,
+    pub actor_uri: String,
+    pub inbox: String,
+    pub shared_inbox: Option<String>,
+    pub follow_activity: String,
+    pub status: FollowerStatus,
+}
+
+/// A `Create` activity published for a finished reading.
+#[derive(Debug, Clone)]
+pub struct OutboxActivity {
+    pub user_id: UserId,
+    pub reading_id: ReadingId,
+    /// The activity as JSON, exactly as delivered.
+    pub activity: String,
+    pub published_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewOutboxActivity {
+    pub user_id: UserId,
+    pub reading_id: ReadingId,
+    pub activity: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn followers_deliver_to_shared_inbox_when_available() {
+        let mut follower = Follower {
+            id: FollowerId::new(1),
+            user_id: UserId::new(1),
+            actor_uri: "https://example.social/users/ana".to_string(),
+            inbox: "https://example.social/users/ana/inbox".to_string(),
+            shared_inbox: None,
+            follow_activity: "{}".to_string(),
+            status: FollowerStatus::Accepted,
+            created_at: Utc::now(),
+        };
+        assert_eq!(
+            follower.delivery_inbox(),
+            "https://example.social/users/ana/inbox"
+        );
+
+        follower.shared_inbox = Some("https://example.social/inbox".to_string());
+        assert_eq!(follower.delivery_inbox(), "https://example.social/inbox");
+    }
+}
@@ -0,0 +1,824 @@
+//! Structured filter queries for list searches.
+//!
+//! A query mixes `field:value` terms with free text, for example
+//! `genre:fantasy rating>=4 finished:2024 format:audiobook author:"Le Guin" dragons`.
+//! Terms are parsed into typed conditions here; the repository layer compiles
+//! them to SQL. Anything that is not a term is matched as plain text against
+//! the list's usual search columns.
+
+use std::fmt;
+
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::domain::readings::{ReadingFormat, ReadingStatus};
+use crate::domain::tags::normalize_tag;
+use crate::domain::user_books::Shelf;
+
+/// The list a query is evaluated against. Determines which fields are allowed.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FilterTarget {
+    Books,
+    Readings,
+    UserBooks,
+    /// Challenge prompt rules: shared across users, so limited to fields that
+    /// describe the book itself or the format it was read in.
+    ChallengePrompts,
+}
+
+impl FilterTarget {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FilterTarget::Books => "books",
+            FilterTarget::Readings => "readings",
+            FilterTarget::UserBooks => "library",
+            FilterTarget::ChallengePrompts => "challenge prompts",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FilterField {
+    Title,
+    Author,
+    Genre,
+    Publisher,
+    Language,
+    Isbn,
+    Pages,
+    Year,
+    Status,
+    Format,
+    Rating,
+    Started,
+    Finished,
+    Club,
+    Shelf,
+    Tag,
+}
+
+impl FilterField {
+    pub const ALL: [FilterField; 16] = [
+        FilterField::Title,
+        FilterField::Author,
+        FilterField::Genre,
+        FilterField::Publisher,
+        FilterField::Language,
+        FilterField::Isbn,
+        FilterField::Pages,
+        FilterField::Year,
+        FilterField::Status,
+        FilterField::Format,
+        FilterField::Rating,
+        FilterField::Started,
+        FilterField::Finished,
+        FilterField::Club,
+        FilterField::Shelf,
+        FilterField::Tag,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FilterField::Title => "title",
+            FilterField::Author => "author",
+            FilterField::Genre => "genre",
+            FilterField::Publisher => "publisher",
+            FilterField::Language => "language",
+            FilterField::Isbn => "isbn",
+            FilterField::Pages => "pages",
+            FilterField::Year => "year",
+            FilterField::Status => "status",
+            FilterField::Format => "format",
+            FilterField::Rating => "rating",
+            FilterField::Started => "started",
+            FilterField::Finished => "finished",
+            FilterField::Club => "club",
+            FilterField::Shelf => "shelf",
+            FilterField::Tag => "tag",
+        }
+    }
+
+    /// Resolves a field name, accepting a few common aliases.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "title" => Some(FilterField::Title),
+            "author" | "by" => Some(FilterField::Author),
+            "genre" => Some(FilterField::Genre),
+            "publisher" => Some(FilterField::Publisher),
+            "language" | "lang" => Some(FilterField::Language),
+            "isbn" => Some(FilterField::Isbn),
+            "pages" | "page_count" => Some(FilterField::Pages),
+            "year" | "published" => Some(FilterField::Year),
+            "status" => Some(FilterField::Status),
+            "format" => Some(FilterField::Format),
+            "rating" => Some(FilterField::Rating),
+            "started" => Some(FilterField::Started),
+            "finished" => Some(FilterField::Finished),
+            "club" | "book_club" => Some(FilterField::Club),
+            "shelf" => Some(FilterField::Shelf),
+            "tag" | "tags" => Some(FilterField::Tag),
+            _ => None,
+        }
+    }
+
+    /// Whether this field describes a reading rather than the book itself.
+    pub fn is_reading_field(&self) -> bool {
+        matches!(
+            self,
+            FilterField::Status
+                | FilterField::Format
+                | FilterField::Rating
+                | FilterField::Started
+                | FilterField::Finished
+        )
+    }
+
+    /// Whether this field describes a user's copy of a book (shelf, club pick).
+    pub fn is_user_book_field(&self) -> bool {
+        matches!(self, FilterField::Club | FilterField::Shelf)
+    }
+
+    /// Whether this field is personal to a user (tags) and so needs a user
+    /// in scope to mean anything.
+    pub fn is_personal_field(&self) -> bool {
+        matches!(self, FilterField::Tag)
+    }
+
+    pub fn supported_by(&self, target: FilterTarget) -> bool {
+        match target {
+            FilterTarget::Books => {
+                !self.is_reading_field() && !self.is_user_book_field() && !self.is_personal_field()
+            }
+            FilterTarget::Readings => !self.is_user_book_field(),
+            FilterTarget::UserBooks => true,
+            FilterTarget::ChallengePrompts => matches!(
+                self,
+                FilterField::Genre
+                    | FilterField::Language
+                    | FilterField::Pages
+                    | FilterField::Year
+                    | FilterField::Format
+            ),
+        }
+    }
+
+    fn kind(self) -> ValueKind {
+        match self {
+            FilterField::Title
+            | FilterField::Author
+            | FilterField::Genre
+            | FilterField::Publisher
+            | FilterField::Language
+            | FilterField::Isbn
+            | FilterField::Tag => ValueKind::Text,
+            FilterField::Pages | FilterField::Year | FilterField::Rating => ValueKind::Number,
+            FilterField::Started | FilterField::Finished => ValueKind::Date,
+            FilterField::Status => ValueKind::Status,
+            FilterField::Format => ValueKind::Format,
+            FilterField::Club => ValueKind::Bool,
+            FilterField::Shelf => ValueKind::Shelf,
+        }
+    }
+}
+
+impl fmt::Display for FilterField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum ValueKind {
+    Text,
+    Number,
+    Date,
+    Status,
+    Format,
+    Bool,
+    Shelf,
+}
+
+/// Comparison operator between a field and its value.
+///
+/// `:` means "matches": substring for text, equality for numbers and enums,
+/// and "falls within" for dates.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FilterOp {
+    Matches,
+    Eq,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
+
+impl FilterOp {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FilterOp::Matches => ":",
+            FilterOp::Eq => "=",
+            FilterOp::Gt => ">",
+            FilterOp::Gte => ">=",
+            FilterOp::Lt => "<",
+            FilterOp::Lte => "<=",
+        }
+    }
+
+    pub fn is_ordering(&self) -> bool {
+        matches!(
+            self,
+            FilterOp::Gt | FilterOp::Gte | FilterOp::Lt | FilterOp::Lte
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterValue {
+    /// Lowercased text.
+    Text(String),
+    Number(f64),
+    /// Inclusive date range covered by a year, month or day.
+    Period {
+        start: NaiveDate,
+        end: NaiveDate,
+    },
+    Status(ReadingStatus),
+    Format(ReadingFormat),
+    Bool(bool),
+    Shelf(Shelf),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterTerm {
+    pub field: FilterField,
+    pub op: FilterOp,
+    pub value: FilterValue,
+    pub negated: bool,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+pub enum FilterError {
+    #[error("unknown filter field \"{0}\" (known fields: {known}; quote text to search for it literally)", known = known_fields())]
+    UnknownField(String),
+    #[error("the \"{field}\" filter is not available for {target}")]
+    UnsupportedField {
+        field: FilterField,
+        target: &'static str,
+    },
+    #[error("the \"{field}\" filter does not support the \"{op}\" operator")]
+    UnsupportedOperator {
+        field: FilterField,
+        op: &'static str,
+    },
+    #[error("the \"{0}\" filter needs a value")]
+    MissingValue(FilterField),
+    #[error("invalid value \"{value}\" for \"{field}\": expected {expected}")]
+    InvalidValue {
+        field: FilterField,
+        value: String,
+        expected: &'static str,
+    },
+    #[error("unterminated quote in search query")]
+    UnterminatedQuote,
+}
+
+fn known_fields() -> String {
+    FilterField::ALL
+        .iter()
+        .map(FilterField::as_str)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// A parsed search query: structured terms plus any remaining free text.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FilterQuery {
+    pub text: Option<String>,
+    pub terms: Vec<FilterTerm>,
+}
+
+impl FilterQuery {
+    /// Builds a query that only performs a free-text search.
+    pub fn text(text: &str) -> Self {
+        let trimmed = text.trim();
+        Self {
+            text: (!trimmed.is_empty()).then(|| trimmed.to_string()),
+            terms: Vec::new(),
+        }
+    }
+
+    pub fn parse(input: &str, target: FilterTarget) -> Result<Self, FilterError> {
+        let mut words = Vec::new();
+        let mut terms = Vec::new();
+
+        for token in tokenize(input)? {
+            match token {
+                Token::Word(word) => words.push(word),
+                Token::Term {
+                    negated,
+                    field,
+                    op,
+                    value,
+                } => {
+                    let field = FilterField::from_name(&field)
+                        .ok_or_else(|| FilterError::UnknownField(field.clone()))?;
+                    if !field.supported_by(target) {
+                        return Err(FilterError::UnsupportedField {
+                            field,
+                            target: target.as_str(),
+                        });
+                    }
+                    terms.push(FilterTerm {
+                        field,
+                        op,
+                        value: parse_value(field, op, &value)?,
+                        negated,
+                    });
+                }
+            }
+        }
+
+        let text = words.join(" ");
+        Ok(Self {
+            text: (!text.is_empty()).then_some(text),
+            terms,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_none() && self.terms.is_empty()
+    }
+
+    pub fn has_terms(&self) -> bool {
+        !self.terms.is_empty()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Word(String),
+    Term {
+        negated: bool,
+        field: String,
+        op: FilterOp,
+        value: String,
+    },
+}
+
+/// Splits the input on whitespace, honouring double quotes, and recognises
+/// `[-]field<op>value` terms. A field name is a run of ASCII letters or `_`;
+/// quoted text such as `"Dune: Messiah"` is never a term.
+fn tokenize(input: &str) -> Result<Vec<Token>, FilterError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i].is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        if chars[i] == '"' {
+            let (phrase, next) = read_quoted(&chars, i)?;
+            if !phrase.is_empty() {
+                tokens.push(Token::Word(phrase));
+            }
+            i = next;
+            continue;
+        }
+
+        let start = i;
+        let negated = chars[i] == '-';
+        let name_start = if negated { i + 1 } else { i };
+        let mut j = name_start;
+        while j < chars.len() && (chars[j].is_ascii_alphabetic() || chars[j] == '_') {
+            j += 1;
+        }
+
+        if j > name_start
+            && let Some((op, op_len)) = read_operator(&chars, j)
+        {
+            let field: String = chars[name_start..j].iter().collect();
+            let (value, next) = read_value(&chars, j + op_len)?;
+            tokens.push(Token::Term {
+                negated,
+                field,
+                op,
+                value,
+            });
+            i = next;
+            continue;
+        }
+
+        while i < chars.len() && !chars[i].is_whitespace() {
+            i += 1;
+        }
+        tokens.push(Token::Word(chars[start..i].iter().collect()));
+    }
+
+    Ok(tokens)
+}
+
+fn read_operator(chars: &[char], at: usize) -> Option<(FilterOp, usize)> {
+    let next = chars.get(at + 1).copied();
+    match (chars.get(at).copied()?, next) {
+        (':', _) => Some((FilterOp::Matches, 1)),
+        ('>', Some('=')) => Some((FilterOp::Gte, 2)),
+        ('<', Some('=')) => Some((FilterOp::Lte, 2)),
+        ('>', _) => Some((FilterOp::Gt, 1)),
+        ('<', _) => Some((FilterOp::Lt, 1)),
+        ('=', _) => Some((FilterOp::Eq, 1)),
+        _ => None,
+    }
+}
+
+fn read_value(chars: &[char], at: usize) -> Result<(String, usize), FilterError> {
+    if chars.get(at) == Some(&'"') {
+        return read_quoted(chars, at);
+    }
+    let mut end = at;
+    while end < chars.len() && !chars[end].is_whitespace() {
+        end += 1;
+    }
+    Ok((chars[at..end].iter().collect(), end))
+}
+
+/// Reads a quoted string starting at the opening quote; returns its contents
+/// and the index just past the closing quote.
+fn read_quoted(chars: &[char], at: usize) -> Result<(String, usize), FilterError> {
+    let close = chars[at + 1..]
+        .iter()
+        .position(|c| *c == '"')
+        .ok_or(FilterError::UnterminatedQuote)?;
+    let end = at + 1 + close;
+    Ok((chars[at + 1..end].iter().collect::<String>(), end + 1))
+}
+
+fn parse_value(field: FilterField, op: FilterOp, raw: &str) -> Result<FilterValue, FilterError> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return Err(FilterError::MissingValue(field));
+    }
+    let invalid = |expected: &'static str| FilterError::InvalidValue {
+        field,
+        value: raw.to_string(),
+        expected,
+    };
+    let unsupported_op = || FilterError::UnsupportedOperator {
+        field,
+        op: op.as_str(),
+    };
+
+    match field.kind() {
+        ValueKind::Text => {
+            if op.is_ordering() {
+                return Err(unsupported_op());
+            }
+            if field == FilterField::Tag {
+                return normalize_tag(raw)
+                    .map(FilterValue::Text)
+                    .ok_or_else(|| invalid("a tag"));
+            }
+            Ok(FilterValue::Text(raw.to_lowercase()))
+        }
+        ValueKind::Number => raw
+            .parse::<f64>()
+            .ok()
+            .filter(|n| n.is_finite())
+            .map(FilterValue::Number)
+            .ok_or_else(|| invalid("a number")),
+        ValueKind::Date => {
+            parse_period(raw).ok_or_else(|| invalid("a date as YYYY, YYYY-MM or YYYY-MM-DD"))
+        }
+        ValueKind::Status => {
+            if op.is_ordering() {
+                return Err(unsupported_op());
+            }
+            raw.parse::<ReadingStatus>()
+                .map(FilterValue::Status)
+                .map_err(|()| invalid("reading, read or abandoned"))
+        }
+        ValueKind::Format => {
+            if op.is_ordering() {
+                return Err(unsupported_op());
+            }
+            raw.parse::<ReadingFormat>()
+                .map(FilterValue::Format)
+                .map_err(|()| invalid("physical, ereader or audiobook"))
+        }
+        ValueKind::Bool => {
+            if op.is_ordering() {
+                return Err(unsupported_op());
+            }
+            match raw.to_lowercase().as_str() {
+                "yes" | "true" | "1" => Ok(FilterValue::Bool(true)),
+                "no" | "false" | "0" => Ok(FilterValue::Bool(false)),
+                _ => Err(invalid("yes or no")),
+            }
+        }
+        ValueKind::Shelf => {
+            if op.is_ordering() {
+                return Err(unsupported_op());
+            }
+            raw.parse::<Shelf>()
+                .map(FilterValue::Shelf)
+                .map_err(|()| invalid("library or wishlist"))
+        }
+    }
+}
+
+/// Parses `YYYY`, `YYYY-MM` or `YYYY-MM-DD` into the inclusive range it covers.
+fn parse_period(raw: &str) -> Option<FilterValue> {
+    let parts: Vec<&str> = raw.split('-').collect();
+    let year: i32 = parts.first()?.parse().ok()?;
+    let (start, end) = match parts.as_slice() {
+        [_] => (
+            NaiveDate::from_ymd_opt(year, 1, 1)?,
+            NaiveDate::from_ymd_opt(year, 12, 31)?,
+        ),
+        [_, month] => {
+            let month: u32 = month.parse().ok()?;
+            let start = NaiveDate::from_ymd_opt(year, month, 1)?;
+            let next = if month == 12 {
+                NaiveDate::from_ymd_opt(year + 1, 1, 1)?
+            } else {
+                NaiveDate::from_ymd_opt(year, month + 1, 1)?
+            };
+            (start, next.pred_opt()?)
+        }
+        [_, month, day] => {
+            let date = NaiveDate::from_ymd_opt(year, month.parse().ok()?, day.parse().ok()?)?;
+            (date, date)
+        }
+        _ => return None,
+    };
+    Some(FilterValue::Period { start, end })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn parse(input: &str) -> Result<FilterQuery, FilterError> {
+        FilterQuery::parse(input, FilterTarget::UserBooks)
+    }
+
+    #[test]
+    fn plain_text_has_no_terms() {
+        let query = parse("the left hand").unwrap();
+        assert_eq!(query.text.as_deref(), Some("the left hand"));
+        assert!(query.terms.is_empty());
+    }
+
+    #[test]
+    fn empty_input_is_empty() {
+        assert!(parse("   ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn parses_mixed_terms_and_text() {
+        let query = parse(
+            r#"genre:fantasy rating>=4 finished:2024 format:audiobook author:"Le Guin" dragons"#,
+        )
+        .unwrap();
+
+        assert_eq!(query.text.as_deref(), Some("dragons"));
+        assert_eq!(
+            query.terms,
+            vec![
+                FilterTerm {
+                    field: FilterField::Genre,
+                    op: FilterOp::Matches,
+                    value: FilterValue::Text("fantasy".to_string()),
+                    negated: false,
+                },
+                FilterTerm {
+                    field: FilterField::Rating,
+                    op: FilterOp::Gte,
+                    value: FilterValue::Number(4.0),
+                    negated: false,
+                },
+                FilterTerm {
+                    field: FilterField::Finished,
+                    op: FilterOp::Matches,
+                    value: FilterValue::Period {
+                        start: date(2024, 1, 1),
+                        end: date(2024, 12, 31),
+                    },
+                    negated: false,
+                },
+                FilterTerm {
+                    field: FilterField::Format,
+                    op: FilterOp::Matches,
+                    value: FilterValue::Format(ReadingFormat::Audiobook),
+                    negated: false,
+                },
+                FilterTerm {
+                    field: FilterField::Author,
+                    op: FilterOp::Matches,
+                    value: FilterValue::Text("le guin".to_string()),
+                    negated: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_operators() {
+        for (input, op) in [
+            ("pages:300", FilterOp::Matches),
+            ("pages=300", FilterOp::Eq),
+            ("pages>300", FilterOp::Gt),
+            ("pages>=300", FilterOp::Gte),
+            ("pages<300", FilterOp::Lt),
+            ("pages<=300", FilterOp::Lte),
+        ] {
+            assert_eq!(parse(input).unwrap().terms[0].op, op, "{input}");
+        }
+    }
+
+    #[test]
+    fn leading_dash_negates_term() {
+        let query = parse("-genre:horror").unwrap();
+        assert!(query.terms[0].negated);
+        assert_eq!(query.terms[0].field, FilterField::Genre);
+    }
+
+    #[test]
+    fn hyphenated_words_stay_text() {
+        let query = parse("sci-fi -").unwrap();
+        assert_eq!(query.text.as_deref(), Some("sci-fi -"));
+    }
+
+    #[test]
+    fn quoted_phrase_is_text() {
+        let query = parse(r#""Dune: Messiah""#).unwrap();
+        assert_eq!(query.text.as_deref(), Some("Dune: Messiah"));
+        assert!(query.terms.is_empty());
+    }
+
+    #[test]
+    fn field_aliases_resolve() {
+        assert_eq!(
+            parse("lang:en").unwrap().terms[0].field,
+            FilterField::Language
+        );
+        assert_eq!(
+            parse("by:tolkien").unwrap().terms[0].field,
+            FilterField::Author
+        );
+    }
+
+    #[test]
+    fn unknown_field_is_error() {
+        let err = parse("colour:blue").unwrap_err();
+        assert_eq!(err, FilterError::UnknownField("colour".to_string()));
+        assert!(err.to_string().contains("known fields"));
+        assert!(err.to_string().contains("genre"));
+    }
+
+    #[test]
+    fn quoted_text_with_colon_is_text() {
+        assert_eq!(
+            parse("Dune: Messiah").unwrap_err(),
+            FilterError::UnknownField("Dune".to_string())
+        );
+        let query = parse("\"Dune: Messiah\"").unwrap();
+        assert_eq!(query.text.as_deref(), Some("Dune: Messiah"));
+        assert!(query.terms.is_empty());
+    }
+
+    #[test]
+    fn reading_fields_unsupported_for_books() {
+        let err = FilterQuery::parse("rating>=4", FilterTarget::Books).unwrap_err();
+        assert!(matches!(
+            err,
+            FilterError::UnsupportedField {
+                field: FilterField::Rating,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn club_unsupported_for_readings() {
+        assert!(FilterQuery::parse("club:yes", FilterTarget::Readings).is_err());
+        assert!(FilterQuery::parse("club:yes", FilterTarget::UserBooks).is_ok());
+    }
+
+    #[test]
+    fn missing_value_is_error() {
+        assert_eq!(
+            parse("genre:").unwrap_err(),
+            FilterError::MissingValue(FilterField::Genre)
+        );
+    }
+
+    #[test]
+    fn invalid_number_is_error() {
+        assert!(matches!(
+            parse("pages>lots").unwrap_err(),
+            FilterError::InvalidValue {
+                field: FilterField::Pages,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn ordering_on_text_is_error() {
+        assert!(matches!(
+            parse("title>a").unwrap_err(),
+            FilterError::UnsupportedOperator {
+                field: FilterField::Title,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn invalid_status_is_error() {
+        assert!(parse("status:finished").is_err());
+        assert_eq!(
+            parse("status:read").unwrap().terms[0].value,
+            FilterValue::Status(ReadingStatus::Read)
+        );
+    }
+
+    #[test]
+    fn club_accepts_yes_and_no() {
+        assert_eq!(
+            parse("club:no").unwrap().terms[0].value,
+            FilterValue::Bool(false)
+        );
+        assert!(parse("club:maybe").is_err());
+    }
+
+    #[test]
+    fn shelf_is_only_for_user_books() {
+        assert_eq!(
+            parse("shelf:wishlist").unwrap().terms[0].value,
+            FilterValue::Shelf(Shelf::Wishlist)
+        );
+        assert!(FilterQuery::parse("shelf:library", FilterTarget::Readings).is_err());
+        assert!(parse("shelf:attic").is_err());
+    }
+
+    #[test]
+    fn tag_is_normalized_and_personal() {
+        assert_eq!(
+            parse("tag:\"Comfort Read\"").unwrap().terms[0].value,
+            FilterValue::Text("comfort-read".to_string())
+        );
+        assert!(FilterQuery::parse("tag:cosy", FilterTarget::Readings).is_ok());
+        assert!(FilterQuery::parse("tag:cosy", FilterTarget::Books).is_err());
+    }
+
+    #[test]
+    fn unterminated_quote_is_error() {
+        assert_eq!(
+            parse(r#"author:"Le Guin"#).unwrap_err(),
+            FilterError::UnterminatedQuote
+        );
+    }
+
+    #[test]
+    fn month_period_covers_whole_month() {
+        assert_eq!(
+            parse("started:2024-02").unwrap().terms[0].value,
+            FilterValue::Period {
+                start: date(2024, 2, 1),
+                end: date(2024, 2, 29),
+            }
+        );
+        assert_eq!(
+            parse("started:2024-12").unwrap().terms[0].value,
+            FilterValue::Period {
+                start: date(2024, 12, 1),
+                end: date(2024, 12, 31),
+            }
+        );
+    }
+
+    #[test]
+    fn day_period_is_single_day() {
+        assert_eq!(
+            parse("finished:2024-03-15").unwrap().terms[0].value,
+            FilterValue::Period {
+                start: date(2024, 3, 15),
+                end: date(2024, 3, 15),
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_date_is_error() {
+        assert!(parse("finished:2024-13").is_err());
+        assert!(parse("finished:last-year").is_err());
+    }
+}
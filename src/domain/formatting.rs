@@ -51,6 +51,31 @@ pub fn format_pages(pages: i32) -> String {
     }
 }
 
+/// Format a running time in minutes for display, e.g. "11h 45m".
+pub fn format_duration(minutes: i32) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{m}m"),
+        (h, 0) => format!("{h}h"),
+        (h, m) => format!("{h}h {m}m"),
+    }
+}
+
+/// Format a number with thousand separators.
+pub fn format_number(n: i64) -> String {
+    if n < 1_000 {
+        return n.to_string();
+    }
+    let s = n.to_string();
+    let mut result = String::with_capacity(s.len() + s.len() / 3);
+    for (i, c) in s.chars().rev().enumerate() {
+        if i > 0 && i % 3 == 0 {
+            result.push(',');
+        }
+        result.push(c);
+    }
+    result.chars().rev().collect()
+}
+
 /// Format a star rating for display (0.5-5.0 in half-star increments).
 pub fn format_rating(rating: f64) -> String {
     if rating.fract() == 0.0 {
@@ -233,4 +258,11 @@ mod tests {
         assert!(!is_valid_rating(-1.0));
         assert!(!is_valid_rating(1.7));
     }
+
+    #[test]
+    fn duration_in_hours_and_minutes() {
+        assert_eq!(format_duration(45), "45m");
+        assert_eq!(format_duration(120), "2h");
+        assert_eq!(format_duration(705), "11h 45m");
+    }
 }
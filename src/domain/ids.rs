@@ -54,9 +54,19 @@ define_id!(ReadingId);
 define_id!(TimelineEventId);
 define_id!(UserId);
 define_id!(TokenId);
+define_id!(FeedTokenId);
 define_id!(SessionId);
 define_id!(PasskeyCredentialId);
 define_id!(RegistrationTokenId);
 define_id!(AiUsageId);
 define_id!(UserBookId);
 define_id!(GenreId);
+define_id!(ShelfId);
+define_id!(GoalId);
+define_id!(ChallengeId);
+define_id!(ChallengePromptId);
+define_id!(JobId);
+define_id!(FollowerId);
+define_id!(WebhookId);
+define_id!(WebhookDeliveryId);
+define_id!(ImageUploadId);
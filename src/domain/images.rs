@@ -11,6 +11,12 @@ pub struct EntityImage {
     pub thumbnail_data: Vec<u8>,
 }
 
+/// The type and size of a stored thumbnail, without its bytes.
+pub struct ThumbnailInfo {
+    pub content_type: String,
+    pub length: usize,
+}
+
 /// Wrapper for image data URLs that redacts content in `Debug` output,
 /// allowing payloads to be traced without logging raw base64 image data.
 #[derive(Default, Deserialize)]
@@ -0,0 +1,140 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::ids::JobId;
+
+/// Attempts before a job is marked failed and left for inspection.
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 5;
+
+const RETRY_BASE_SECONDS: i64 = 10;
+const RETRY_MAX_SECONDS: i64 = 60 * 60;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum JobStatus {
+    /// Waiting for its `run_at` time.
+    Pending,
+    /// Claimed by the worker.
+    Running,
+    /// Out of attempts.
+    Failed,
+}
+
+impl JobStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JobStatus::Pending => "pending",
+            JobStatus::Running => "running",
+            JobStatus::Failed => "failed",
+        }
+    }
+}
+
+impl FromStr for JobStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(JobStatus::Pending),
+            "running" => Ok(JobStatus::Running),
+            "failed" => Ok(JobStatus::Failed),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Job {
+    pub id: JobId,
+    pub kind: String,
+    /// JSON describing the work, interpreted by the worker per `kind`.
+    pub payload: String,
+    pub dedupe_key: Option<String>,
+    pub status: JobStatus,
+    /// Attempts started so far, including the current one while running.
+    pub attempts: u32,
+    pub max_attempts: u32,
+    pub run_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Job {
+    /// Whether a failed attempt should be retried rather than marked failed.
+    pub fn can_retry(&self) -> bool {
+        self.attempts < self.max_attempts
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewJob {
+    pub kind: String,
+    pub payload: String,
+    /// Enqueueing is a no-op while a pending job with the same key exists.
+    pub dedupe_key: Option<String>,
+    pub run_at: DateTime<Utc>,
+    pub max_attempts: u32,
+}
+
+impl NewJob {
+    pub fn new(
+        kind: impl Into<String>,
+        payload: impl Into<String>,
+        dedupe_key: Option<String>,
+        run_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            kind: kind.into(),
+            payload: payload.into(),
+            dedupe_key,
+            run_at,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+        }
+    }
+}
+
+/// Delay before retrying a job whose `attempts`th attempt failed: doubling
+/// from ten seconds, capped at an hour.
+pub fn retry_delay(attempts: u32) -> Duration {
+    let exponent = attempts.saturating_sub(1).min(16);
+    let seconds = RETRY_BASE_SECONDS
+        .saturating_mul(1 << exponent)
+        .min(RETRY_MAX_SECONDS);
+    Duration::seconds(seconds)
+}
+
+/// Queue counts and recent failures, shown on the admin page.
+#[derive(Debug, Clone, Default)]
+pub struct JobQueueHealth {
+    pub pending: i64,
+    pub running: i64,
+    pub failed: i64,
+    /// Pending jobs whose `run_at` has passed.
+    pub overdue: i64,
+    pub oldest_pending_at: Option<DateTime<Utc>>,
+    /// Most recently failed jobs, newest first.
+    pub recent_failures: Vec<Job>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_delay_doubles_up_to_an_hour() {
+        assert_eq!(retry_delay(1), Duration::seconds(10));
+        assert_eq!(retry_delay(2), Duration::seconds(20));
+        assert_eq!(retry_delay(4), Duration::seconds(80));
+        assert_eq!(retry_delay(10), Duration::hours(1));
+        assert_eq!(retry_delay(u32::MAX), Duration::hours(1));
+    }
+
+    #[test]
+    fn status_round_trips_through_strings() {
+        for status in [JobStatus::Pending, JobStatus::Running, JobStatus::Failed] {
+            assert_eq!(status.as_str().parse::<JobStatus>(), Ok(status));
+        }
+        assert!("done".parse::<JobStatus>().is_err());
+    }
+}
@@ -203,6 +203,11 @@ impl<T> Page<T> {
         }
     }
 
+    /// An empty first page, for lists whose query could not be run.
+    pub fn empty() -> Self {
+        Self::new(Vec::new(), 1, DEFAULT_PAGE_SIZE, 0, false)
+    }
+
     pub fn total_pages(&self) -> u32 {
         if self.total == 0 || self.showing_all {
             1
@@ -3,15 +3,22 @@ pub mod auth;
 pub mod books;
 pub mod cover_suggestions;
 pub mod errors;
+pub mod federation;
+pub mod filters;
 pub mod formatting;
 pub mod ids;
 pub mod images;
+pub mod jobs;
 pub mod listing;
 pub mod repositories;
+pub mod webhooks;
 
 // Re-exports
-pub use analytics::{ai_usage, stats, timeline};
-pub use auth::{passkey_credentials, registration_tokens, sessions, tokens, users};
+pub use analytics::{
+    activity, ai_usage, challenges, goals, household, listening, review, sentiment, stats,
+    stats_cache, timeline,
+};
+pub use auth::{feed_tokens, passkey_credentials, registration_tokens, sessions, tokens, users};
 pub use books::books as book_items;
-pub use books::{authors, genres, readings, user_books};
+pub use books::{authors, genres, library, readings, shelves, tags, user_books};
 pub use errors::RepositoryError;
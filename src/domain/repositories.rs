@@ -1,33 +1,53 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use std::time::Duration;
 
 use super::RepositoryError;
 use crate::domain::ai_usage::{AiUsage, AiUsageSummary, NewAiUsage};
 use crate::domain::cover_suggestions::CoverSuggestion;
+use crate::domain::federation::{
+    Actor, Follower, FollowerStatus, NewActor, NewFollower, NewOutboxActivity, OutboxActivity,
+};
+use crate::domain::feed_tokens::{FeedKind, FeedToken, NewFeedToken};
+use crate::domain::filters::FilterQuery;
 use crate::domain::listing::{ListRequest, Page, SortDirection, SortKey};
+use crate::domain::webhooks::{
+    DeliveryAttempt, NewWebhook, NewWebhookDelivery, Webhook, WebhookDelivery,
+};
 
 use crate::domain::authors::{Author, AuthorSortKey, NewAuthor, UpdateAuthor};
 use crate::domain::book_items::{Book, BookSortKey, BookWithAuthors, NewBook, UpdateBook};
-use crate::domain::genres::{Genre, GenreSortKey, NewGenre, UpdateGenre};
+use crate::domain::challenges::{
+    Challenge, ChallengeEntry, ChallengePrompt, ChallengeWithPrompts, NewChallenge,
+    NewChallengePrompt, UpdateChallenge, UpdateChallengePrompt,
+};
+use crate::domain::genres::{Genre, GenreSortKey, GenreTree, NewGenre, UpdateGenre};
+use crate::domain::goals::{NewReadingGoal, ReadingGoal, UpdateReadingGoal};
 use crate::domain::ids::{
-    AuthorId, BookId, GenreId, PasskeyCredentialId, ReadingId, RegistrationTokenId, SessionId,
-    TokenId, UserBookId, UserId,
+    AuthorId, BookId, ChallengeId, ChallengePromptId, FollowerId, GenreId, GoalId, ImageUploadId,
+    JobId, PasskeyCredentialId, ReadingId, RegistrationTokenId, SessionId, ShelfId,
+    TimelineEventId, TokenId, UserBookId, UserId, WebhookDeliveryId, WebhookId,
 };
-use crate::domain::images::EntityImage;
+use crate::domain::images::{EntityImage, ThumbnailInfo};
+use crate::domain::jobs::{Job, JobQueueHealth, NewJob};
 use crate::domain::passkey_credentials::{NewPasskeyCredential, PasskeyCredential};
 use crate::domain::readings::{
     NewReading, Reading, ReadingFilter, ReadingSortKey, ReadingWithBook, UpdateReading,
 };
 use crate::domain::registration_tokens::{NewRegistrationToken, RegistrationToken};
 use crate::domain::sessions::{NewSession, Session};
-use crate::domain::timeline::{NewTimelineEvent, TimelineEvent, TimelineSortKey};
+use crate::domain::shelves::{NewSavedShelf, SavedShelf, UpdateSavedShelf};
+use crate::domain::tags::TagCount;
+use crate::domain::timeline::{
+    NewTimelineEvent, RecordedTimelineEvent, TimelineEvent, TimelineFilter, TimelineSortKey,
+};
 use crate::domain::tokens::{NewToken, Token};
 use crate::domain::user_books::{
     NewUserBook, Shelf, UserBook, UserBookSortKey, UserBookWithDetails,
 };
-use crate::domain::users::{NewUser, User};
+use crate::domain::users::{NewUser, ProfilePrivacy, User};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 #[async_trait]
 pub trait AuthorRepository: Send + Sync {
@@ -88,6 +108,10 @@ pub trait GenreRepository: Send + Sync {
         Ok(page.items)
     }
 
+    async fn tree(&self) -> Result<GenreTree, RepositoryError> {
+        Ok(GenreTree::new(self.list_all().await?))
+    }
+
     async fn list_all_sorted(
         &self,
         sort_key: GenreSortKey,
@@ -109,7 +133,7 @@ pub trait BookRepository: Send + Sync {
     async fn list(
         &self,
         request: &ListRequest<BookSortKey>,
-        search: Option<&str>,
+        query: Option<&FilterQuery>,
     ) -> Result<Page<BookWithAuthors>, RepositoryError>;
     async fn list_by_author(
         &self,
@@ -119,6 +143,11 @@ pub trait BookRepository: Send + Sync {
         &self,
         genre_id: GenreId,
     ) -> Result<Vec<BookWithAuthors>, RepositoryError>;
+    /// List the books on a manual shelf in shelf order.
+    async fn list_by_shelf(
+        &self,
+        shelf_id: ShelfId,
+    ) -> Result<Vec<BookWithAuthors>, RepositoryError>;
     async fn update(&self, id: BookId, changes: UpdateBook) -> Result<Book, RepositoryError>;
     async fn delete(&self, id: BookId) -> Result<(), RepositoryError>;
 
@@ -144,7 +173,7 @@ pub trait UserBookRepository: Send + Sync {
         user_id: UserId,
         shelf: Option<Shelf>,
         request: &ListRequest<UserBookSortKey>,
-        search: Option<&str>,
+        query: Option<&FilterQuery>,
     ) -> Result<Page<UserBookWithDetails>, RepositoryError>;
     async fn move_shelf(&self, id: UserBookId, shelf: Shelf) -> Result<UserBook, RepositoryError>;
     async fn set_book_club(
@@ -153,6 +182,13 @@ pub trait UserBookRepository: Send + Sync {
         book_club: bool,
     ) -> Result<UserBook, RepositoryError>;
     async fn delete(&self, id: UserBookId) -> Result<(), RepositoryError>;
+    /// Whether the book is in the user's library and matches the filter query.
+    async fn matches_query(
+        &self,
+        user_id: UserId,
+        book_id: BookId,
+        query: &FilterQuery,
+    ) -> Result<bool, RepositoryError>;
     async fn book_ids_for_user(
         &self,
         user_id: UserId,
@@ -160,6 +196,70 @@ pub trait UserBookRepository: Send + Sync {
     ) -> Result<HashSet<BookId>, RepositoryError>;
 }
 
+#[async_trait]
+pub trait ShelfRepository: Send + Sync {
+    async fn insert(&self, shelf: NewSavedShelf) -> Result<SavedShelf, RepositoryError>;
+    async fn get(&self, id: ShelfId) -> Result<SavedShelf, RepositoryError>;
+    /// List a user's shelves, pinned first, then by name.
+    async fn list_by_user(&self, user_id: UserId) -> Result<Vec<SavedShelf>, RepositoryError>;
+    /// List a user's manual shelves that hold the given book.
+    async fn list_containing_book(
+        &self,
+        user_id: UserId,
+        book_id: BookId,
+    ) -> Result<Vec<SavedShelf>, RepositoryError>;
+    async fn update(
+        &self,
+        id: ShelfId,
+        changes: UpdateSavedShelf,
+    ) -> Result<SavedShelf, RepositoryError>;
+    async fn delete(&self, id: ShelfId) -> Result<(), RepositoryError>;
+    /// Append a book to the end of a manual shelf.
+    async fn add_book(&self, id: ShelfId, book_id: BookId) -> Result<(), RepositoryError>;
+    async fn remove_book(&self, id: ShelfId, book_id: BookId) -> Result<(), RepositoryError>;
+    /// Move a book to a zero-based position, shifting the others along.
+    async fn move_book(
+        &self,
+        id: ShelfId,
+        book_id: BookId,
+        position: usize,
+    ) -> Result<(), RepositoryError>;
+}
+
+#[async_trait]
+pub trait TagRepository: Send + Sync {
+    /// List the tags a user has put on a book, alphabetically.
+    async fn list_for_book(
+        &self,
+        user_id: UserId,
+        book_id: BookId,
+    ) -> Result<Vec<String>, RepositoryError>;
+    /// Replace a user's tags on a book. Tags must already be normalised.
+    async fn set_for_book(
+        &self,
+        user_id: UserId,
+        book_id: BookId,
+        tags: &[String],
+    ) -> Result<(), RepositoryError>;
+    /// Add a single normalised tag; adding an existing tag is a no-op.
+    async fn add(&self, user_id: UserId, book_id: BookId, tag: &str)
+    -> Result<(), RepositoryError>;
+    async fn remove(
+        &self,
+        user_id: UserId,
+        book_id: BookId,
+        tag: &str,
+    ) -> Result<(), RepositoryError>;
+    /// Count a user's books per tag, most used first. `prefix` narrows the
+    /// result to tags starting with it (for autocomplete).
+    async fn counts_for_user(
+        &self,
+        user_id: UserId,
+        prefix: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<Vec<TagCount>, RepositoryError>;
+}
+
 #[async_trait]
 pub trait ReadingRepository: Send + Sync {
     async fn insert(&self, reading: NewReading) -> Result<Reading, RepositoryError>;
@@ -169,7 +269,7 @@ pub trait ReadingRepository: Send + Sync {
         &self,
         filter: ReadingFilter,
         request: &ListRequest<ReadingSortKey>,
-        search: Option<&str>,
+        query: Option<&FilterQuery>,
     ) -> Result<Page<ReadingWithBook>, RepositoryError>;
     async fn update(
         &self,
@@ -177,16 +277,31 @@ pub trait ReadingRepository: Send + Sync {
         changes: UpdateReading,
     ) -> Result<Reading, RepositoryError>;
     async fn delete(&self, id: ReadingId) -> Result<(), RepositoryError>;
+    /// The book each of the readings is of; missing readings are left out.
+    async fn book_ids(
+        &self,
+        ids: &[ReadingId],
+    ) -> Result<HashMap<ReadingId, BookId>, RepositoryError>;
 }
 
 #[async_trait]
 pub trait TimelineEventRepository: Send + Sync {
     async fn insert(&self, event: NewTimelineEvent) -> Result<TimelineEvent, RepositoryError>;
+    async fn get_recorded(
+        &self,
+        id: TimelineEventId,
+    ) -> Result<RecordedTimelineEvent, RepositoryError>;
     async fn list(
         &self,
-        user_id: Option<UserId>,
+        filter: &TimelineFilter,
         request: &ListRequest<TimelineSortKey>,
     ) -> Result<Page<TimelineEvent>, RepositoryError>;
+    /// Whether the event would be listed with this filter.
+    async fn matches(
+        &self,
+        id: TimelineEventId,
+        filter: &TimelineFilter,
+    ) -> Result<bool, RepositoryError>;
 
     async fn update_by_entity(
         &self,
@@ -210,7 +325,7 @@ pub trait TimelineEventRepository: Send + Sync {
         let sort_key = <TimelineSortKey as SortKey>::default();
         let request =
             ListRequest::<TimelineSortKey>::show_all(sort_key, sort_key.default_direction());
-        let page = self.list(None, &request).await?;
+        let page = self.list(&TimelineFilter::all(), &request).await?;
         Ok(page.items)
     }
 }
@@ -223,6 +338,15 @@ pub trait UserRepository: Send + Sync {
     async fn get_by_uuid(&self, uuid: &str) -> Result<User, RepositoryError>;
     async fn exists(&self) -> Result<bool, RepositoryError>;
     async fn list_all(&self) -> Result<Vec<User>, RepositoryError>;
+    async fn set_household_opt_out(&self, id: UserId, opt_out: bool)
+    -> Result<(), RepositoryError>;
+    async fn set_public_profile(&self, id: UserId, public: bool) -> Result<(), RepositoryError>;
+    async fn get_profile_privacy(&self, id: UserId) -> Result<ProfilePrivacy, RepositoryError>;
+    async fn set_profile_privacy(
+        &self,
+        id: UserId,
+        privacy: ProfilePrivacy,
+    ) -> Result<(), RepositoryError>;
 }
 
 #[async_trait]
@@ -235,6 +359,92 @@ pub trait TokenRepository: Send + Sync {
     async fn update_last_used(&self, id: TokenId) -> Result<(), RepositoryError>;
 }
 
+#[async_trait]
+pub trait FeedTokenRepository: Send + Sync {
+    /// Store a feed's token, replacing any earlier one for the same feed.
+    async fn replace(&self, token: NewFeedToken) -> Result<FeedToken, RepositoryError>;
+    async fn get_by_token_hash(&self, token_hash: &str) -> Result<FeedToken, RepositoryError>;
+    async fn list_by_user(&self, user_id: UserId) -> Result<Vec<FeedToken>, RepositoryError>;
+    async fn delete(&self, user_id: UserId, feed: FeedKind) -> Result<(), RepositoryError>;
+}
+
+#[async_trait]
+pub trait FederationRepository: Send + Sync {
+    async fn insert_actor(&self, actor: NewActor) -> Result<Actor, RepositoryError>;
+    async fn get_actor(&self, user_id: UserId) -> Result<Actor, RepositoryError>;
+    async fn set_manually_approves_followers(
+        &self,
+        user_id: UserId,
+        manual: bool,
+    ) -> Result<(), RepositoryError>;
+    /// Stop federating; followers and published activities go with the actor.
+    async fn delete_actor(&self, user_id: UserId) -> Result<(), RepositoryError>;
+
+    /// Record a follow, replacing an earlier one from the same actor.
+    async fn upsert_follower(&self, follower: NewFollower) -> Result<Follower, RepositoryError>;
+    async fn get_follower(&self, id: FollowerId) -> Result<Follower, RepositoryError>;
+    async fn list_followers(&self, user_id: UserId) -> Result<Vec<Follower>, RepositoryError>;
+    async fn set_follower_status(
+        &self,
+        id: FollowerId,
+        status: FollowerStatus,
+    ) -> Result<(), RepositoryError>;
+    async fn delete_follower(&self, id: FollowerId) -> Result<(), RepositoryError>;
+    async fn delete_follower_by_actor(
+        &self,
+        user_id: UserId,
+        actor_uri: &str,
+    ) -> Result<(), RepositoryError>;
+
+    /// Store a published activity. Returns `None` when the reading was
+    /// already published.
+    async fn insert_activity(
+        &self,
+        activity: NewOutboxActivity,
+    ) -> Result<Option<OutboxActivity>, RepositoryError>;
+    /// A user's most recent activities, newest first.
+    async fn list_activities(
+        &self,
+        user_id: UserId,
+        limit: i64,
+    ) -> Result<Vec<OutboxActivity>, RepositoryError>;
+    async fn count_activities(&self, user_id: UserId) -> Result<i64, RepositoryError>;
+}
+
+#[async_trait]
+pub trait WebhookRepository: Send + Sync {
+    async fn insert(&self, webhook: NewWebhook) -> Result<Webhook, RepositoryError>;
+    async fn get(&self, id: WebhookId) -> Result<Webhook, RepositoryError>;
+    /// A user's own webhooks, plus the admin-level ones when `include_global`.
+    async fn list_for_user(
+        &self,
+        user_id: UserId,
+        include_global: bool,
+    ) -> Result<Vec<Webhook>, RepositoryError>;
+    /// Webhooks that receive `user_id`'s events: their own and admin-level ones.
+    async fn list_receiving(&self, user_id: UserId) -> Result<Vec<Webhook>, RepositoryError>;
+    async fn delete(&self, id: WebhookId) -> Result<(), RepositoryError>;
+
+    async fn insert_delivery(
+        &self,
+        delivery: NewWebhookDelivery,
+    ) -> Result<WebhookDelivery, RepositoryError>;
+    async fn get_delivery(&self, id: WebhookDeliveryId)
+    -> Result<WebhookDelivery, RepositoryError>;
+    /// Record an attempt, counting it towards the delivery's attempts.
+    async fn record_attempt(
+        &self,
+        id: WebhookDeliveryId,
+        attempt: DeliveryAttempt,
+    ) -> Result<(), RepositoryError>;
+    /// Most recent deliveries to any of `webhook_ids`, newest first.
+    async fn list_recent_deliveries(
+        &self,
+        webhook_ids: &[WebhookId],
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError>;
+}
+
 #[async_trait]
 pub trait SessionRepository: Send + Sync {
     async fn insert(&self, session: NewSession) -> Result<Session, RepositoryError>;
@@ -309,6 +519,84 @@ pub trait ImageRepository: Send + Sync {
         entity_type: &str,
         entity_ids: &[i64],
     ) -> Result<HashSet<i64>, RepositoryError>;
+    async fn thumbnail_info(
+        &self,
+        entity_type: &str,
+        entity_ids: &[i64],
+    ) -> Result<HashMap<i64, ThumbnailInfo>, RepositoryError>;
+    /// Hold an uploaded data URL until the background worker processes it.
+    async fn stage_upload(&self, data_url: &str) -> Result<ImageUploadId, RepositoryError>;
+    async fn get_upload(&self, id: ImageUploadId) -> Result<String, RepositoryError>;
+    async fn delete_upload(&self, id: ImageUploadId) -> Result<(), RepositoryError>;
+}
+
+#[async_trait]
+pub trait GoalRepository: Send + Sync {
+    async fn insert(&self, goal: NewReadingGoal) -> Result<ReadingGoal, RepositoryError>;
+    async fn get(&self, id: GoalId) -> Result<ReadingGoal, RepositoryError>;
+    /// List a user's goals, most recent period first.
+    async fn list_by_user(&self, user_id: UserId) -> Result<Vec<ReadingGoal>, RepositoryError>;
+    async fn update(
+        &self,
+        id: GoalId,
+        changes: UpdateReadingGoal,
+    ) -> Result<ReadingGoal, RepositoryError>;
+    async fn delete(&self, id: GoalId) -> Result<(), RepositoryError>;
+    /// Books or pages from the user's readings finished within the goal
+    /// period that match its genre and format constraints.
+    async fn progress(&self, goal: &ReadingGoal) -> Result<i64, RepositoryError>;
+}
+
+#[async_trait]
+pub trait ChallengeRepository: Send + Sync {
+    /// Create a challenge together with its initial prompts.
+    async fn insert(
+        &self,
+        challenge: NewChallenge,
+    ) -> Result<ChallengeWithPrompts, RepositoryError>;
+    async fn get(&self, id: ChallengeId) -> Result<ChallengeWithPrompts, RepositoryError>;
+    /// List the user's own challenges and every shared one, newest year first.
+    async fn list_visible(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<ChallengeWithPrompts>, RepositoryError>;
+    async fn update(
+        &self,
+        id: ChallengeId,
+        changes: UpdateChallenge,
+    ) -> Result<Challenge, RepositoryError>;
+    async fn delete(&self, id: ChallengeId) -> Result<(), RepositoryError>;
+    /// Append a prompt to the end of a challenge.
+    async fn add_prompt(
+        &self,
+        id: ChallengeId,
+        prompt: NewChallengePrompt,
+    ) -> Result<ChallengePrompt, RepositoryError>;
+    async fn update_prompt(
+        &self,
+        prompt_id: ChallengePromptId,
+        changes: UpdateChallengePrompt,
+    ) -> Result<ChallengePrompt, RepositoryError>;
+    async fn delete_prompt(&self, prompt_id: ChallengePromptId) -> Result<(), RepositoryError>;
+    /// All of a user's entries, across every challenge.
+    async fn entries_for_user(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<ChallengeEntry>, RepositoryError>;
+    /// Assign a reading to a prompt, replacing any earlier assignment. Fails
+    /// with a conflict if the reading already fills another prompt in the
+    /// same challenge.
+    async fn assign(
+        &self,
+        prompt: &ChallengePrompt,
+        user_id: UserId,
+        reading_id: ReadingId,
+    ) -> Result<ChallengeEntry, RepositoryError>;
+    async fn unassign(
+        &self,
+        prompt_id: ChallengePromptId,
+        user_id: UserId,
+    ) -> Result<(), RepositoryError>;
 }
 
 #[async_trait]
@@ -332,18 +620,66 @@ pub trait StatsRepository: Send + Sync {
     ) -> Result<(), RepositoryError>;
     /// Return distinct years (descending) that have at least one completed reading.
     async fn available_years(&self, user_id: UserId) -> Result<Vec<i32>, RepositoryError>;
-    /// Book summary stats filtered to books with a completed reading in the given year.
-    async fn book_summary_for_year(
+    /// Book summary stats filtered to books with a reading completed in the period.
+    async fn book_summary_for_period(
         &self,
         user_id: UserId,
-        year: i32,
+        period: &crate::domain::stats::StatsPeriod,
     ) -> Result<crate::domain::stats::BookSummaryStats, RepositoryError>;
-    /// Reading stats filtered to readings completed in the given year.
-    async fn reading_summary_for_year(
+    /// Reading stats filtered to readings completed in the period.
+    async fn reading_summary_for_period(
         &self,
         user_id: UserId,
-        year: i32,
+        period: &crate::domain::stats::StatsPeriod,
     ) -> Result<crate::domain::stats::ReadingStats, RepositoryError>;
+    /// Reading aggregates for one year, computed from the readings table.
+    /// `None` covers readings without the relevant date.
+    async fn year_partial(
+        &self,
+        user_id: UserId,
+        year: Option<i32>,
+    ) -> Result<crate::domain::stats_cache::YearStatsPartial, RepositoryError>;
+    /// Years that currently have read or abandoned readings, `None` for undated ones.
+    async fn partial_years(&self, user_id: UserId) -> Result<Vec<Option<i32>>, RepositoryError>;
+    async fn get_partials(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<crate::domain::stats_cache::YearStatsPartial>, RepositoryError>;
+    async fn store_partial(
+        &self,
+        user_id: UserId,
+        partial: &crate::domain::stats_cache::YearStatsPartial,
+    ) -> Result<(), RepositoryError>;
+    async fn delete_partial(
+        &self,
+        user_id: UserId,
+        year: Option<i32>,
+    ) -> Result<(), RepositoryError>;
+    /// Counters describing the library right now: in progress, on shelf, last 30 days.
+    async fn reading_counters(
+        &self,
+        user_id: UserId,
+    ) -> Result<crate::domain::stats_cache::ReadingCounters, RepositoryError>;
+    /// Users with a stats cache entry, for periodic reconciliation.
+    async fn cached_user_ids(&self) -> Result<Vec<UserId>, RepositoryError>;
+    /// Start and finish dates of all the user's readings, for day-level activity.
+    async fn reading_spans(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<crate::domain::activity::ReadingSpan>, RepositoryError>;
+    /// Stats across all users who haven't opted out, with "this month"
+    /// being the month starting on `month`.
+    async fn household_stats(
+        &self,
+        month: chrono::NaiveDate,
+    ) -> Result<crate::domain::household::HouseholdStats, RepositoryError>;
+    async fn get_cached_household(
+        &self,
+    ) -> Result<Option<crate::domain::household::HouseholdStats>, RepositoryError>;
+    async fn store_cached_household(
+        &self,
+        stats: &crate::domain::household::HouseholdStats,
+    ) -> Result<(), RepositoryError>;
 }
 
 #[async_trait]
@@ -354,3 +690,34 @@ pub trait CoverSuggestionRepository: Send + Sync {
     async fn delete(&self, id: &str) -> Result<(), RepositoryError>;
     async fn delete_older_than(&self, max_age: Duration) -> Result<u64, RepositoryError>;
 }
+
+#[async_trait]
+pub trait JobRepository: Send + Sync {
+    /// Queue a job. Returns `false` when a pending job with the same dedupe
+    /// key already exists and nothing was inserted.
+    async fn enqueue(&self, job: NewJob) -> Result<bool, RepositoryError>;
+    /// Mark up to `limit` pending jobs due by `now` as running, counting an
+    /// attempt for each, and return them oldest first. A job waits while
+    /// one with the same dedupe key is still running.
+    async fn claim_due(&self, now: DateTime<Utc>, limit: i64) -> Result<Vec<Job>, RepositoryError>;
+    /// Remove a job that finished successfully.
+    async fn complete(&self, id: JobId) -> Result<(), RepositoryError>;
+    /// Return a running job to pending to run again at `run_at`. Dropped
+    /// instead when a pending duplicate was queued in the meantime.
+    async fn retry(
+        &self,
+        job: &Job,
+        run_at: DateTime<Utc>,
+        error: &str,
+    ) -> Result<(), RepositoryError>;
+    /// Mark a job failed, keeping it for inspection.
+    async fn fail(&self, id: JobId, error: &str) -> Result<(), RepositoryError>;
+    /// Return jobs left running by a previous process to pending.
+    async fn requeue_running(&self) -> Result<u64, RepositoryError>;
+    /// Queue counts and the `failure_limit` most recent failures.
+    async fn health(
+        &self,
+        now: DateTime<Utc>,
+        failure_limit: i64,
+    ) -> Result<JobQueueHealth, RepositoryError>;
+}
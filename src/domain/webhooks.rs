@@ -0,0 +1,155 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::ids::{UserId, WebhookDeliveryId, WebhookId};
+use crate::domain::timeline::TIMELINE_ACTIONS;
+
+/// Event sent by the "send test event" button.
+pub const TEST_EVENT: &str = "test";
+
+/// Whether `event` is a timeline action webhooks can subscribe to.
+pub fn is_webhook_event(event: &str) -> bool {
+    TIMELINE_ACTIONS.contains(&event)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Webhook {
+    pub id: WebhookId,
+    /// `None` for admin-level webhooks, which receive every user's events.
+    pub user_id: Option<UserId>,
+    pub url: String,
+    #[serde(skip_serializing)]
+    pub secret: String,
+    /// Timeline actions to send; empty sends them all.
+    pub events: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Webhook {
+    pub fn is_subscribed_to(&self, event: &str) -> bool {
+        event == TEST_EVENT || self.events.is_empty() || self.events.iter().any(|e| e == event)
+    }
+
+    /// Admins manage every webhook; other users only their own.
+    pub fn is_managed_by(&self, user_id: UserId, is_admin: bool) -> bool {
+        match self.user_id {
+            Some(owner) => owner == user_id || is_admin,
+            None => is_admin,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewWebhook {
+    pub user_id: Option<UserId>,
+    pub url: String,
+    pub secret: String,
+    pub events: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryStatus {
+    /// Not attempted yet.
+    Pending,
+    /// The endpoint answered with a 2xx status.
+    Delivered,
+    /// The last attempt failed; it is retried until out of attempts.
+    Failed,
+}
+
+impl DeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeliveryStatus::Pending => "pending",
+            DeliveryStatus::Delivered => "delivered",
+            DeliveryStatus::Failed => "failed",
+        }
+    }
+}
+
+impl FromStr for DeliveryStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(DeliveryStatus::Pending),
+            "delivered" => Ok(DeliveryStatus::Delivered),
+            "failed" => Ok(DeliveryStatus::Failed),
+            _ => Err(()),
+        }
+    }
+}
+
+/// One event sent, or to be sent, to a webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    pub id: WebhookDeliveryId,
+    pub webhook_id: WebhookId,
+    pub event: String,
+    /// The JSON body, exactly as signed and sent.
+    pub payload: String,
+    pub status: DeliveryStatus,
+    pub attempts: u32,
+    pub response_status: Option<u16>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewWebhookDelivery {
+    pub webhook_id: WebhookId,
+    pub event: String,
+    pub payload: String,
+}
+
+/// The outcome of one delivery attempt.
+#[derive(Debug, Clone)]
+pub struct DeliveryAttempt {
+    pub status: DeliveryStatus,
+    pub response_status: Option<u16>,
+    pub error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn webhook(user_id: Option<i64>, events: &[&str]) -> Webhook {
+        Webhook {
+            id: WebhookId::new(1),
+            user_id: user_id.map(UserId::new),
+            url: "https://hooks.example/booklog".to_string(),
+            secret: "secret".to_string(),
+            events: events.iter().map(ToString::to_string).collect(),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn webhooks_without_events_receive_everything() {
+        let all = webhook(Some(1), &[]);
+        assert!(all.is_subscribed_to("started"));
+        assert!(all.is_subscribed_to("milestone"));
+
+        let some = webhook(Some(1), &["started", "finished"]);
+        assert!(some.is_subscribed_to("finished"));
+        assert!(!some.is_subscribed_to("rated"));
+        assert!(some.is_subscribed_to(TEST_EVENT));
+    }
+
+    #[test]
+    fn admin_level_webhooks_are_admin_only() {
+        let own = webhook(Some(1), &[]);
+        assert!(own.is_managed_by(UserId::new(1), false));
+        assert!(!own.is_managed_by(UserId::new(2), false));
+        assert!(own.is_managed_by(UserId::new(2), true));
+
+        let global = webhook(None, &[]);
+        assert!(!global.is_managed_by(UserId::new(1), false));
+        assert!(global.is_managed_by(UserId::new(1), true));
+    }
+}
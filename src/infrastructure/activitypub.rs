@@ -0,0 +1,119 @@
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow, bail};
+use serde::Deserialize;
+use url::Url;
+
+use crate::infrastructure::http_signatures::sign_post;
+use crate::infrastructure::outbound::OutboundClient;
+
+pub const ACTIVITY_JSON: &str = "application/activity+json";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The parts of a remote actor document we need to verify and answer it.
+#[derive(Debug, Clone)]
+pub struct RemoteActor {
+    pub id: String,
+    pub inbox: String,
+    pub shared_inbox: Option<String>,
+    pub key_id: String,
+    pub public_key_pem: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ActorDocument {
+    id: String,
+    inbox: String,
+    #[serde(default)]
+    endpoints: Option<Endpoints>,
+    public_key: PublicKey,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Endpoints {
+    #[serde(default)]
+    shared_inbox: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PublicKey {
+    id: String,
+    public_key_pem: String,
+}
+
+/// Fetch the actor owning `key_id`, a key URL like `https://host/users/ana#main-key`.
+/// The document must describe the actor at that URL, so a server can only
+/// vouch for its own actors.
+pub async fn fetch_actor(client: &OutboundClient, key_id: &str) -> Result<RemoteActor> {
+    let key_url = Url::parse(key_id).with_context(|| format!("invalid key id {key_id}"))?;
+    let url = key_id.split('#').next().unwrap_or(key_id);
+    let response = client
+        .client_for(url, true)
+        .await?
+        .get(url)
+        .header(reqwest::header::ACCEPT, ACTIVITY_JSON)
+        .timeout(REQUEST_TIMEOUT)
+        .send()
+        .await
+        .with_context(|| format!("failed to fetch actor {url}"))?;
+    if !response.status().is_success() {
+        bail!("actor {url} returned {}", response.status());
+    }
+
+    let document: ActorDocument = response
+        .json()
+        .await
+        .with_context(|| format!("actor {url} is not an actor document"))?;
+    if document.public_key.id != key_id {
+        return Err(anyhow!("actor {url} does not own key {key_id}"));
+    }
+    let same_origin =
+        Url::parse(&document.id).is_ok_and(|actor| actor.origin() == key_url.origin());
+    if !same_origin || document.id != url {
+        return Err(anyhow!("actor {url} claims to be {}", document.id));
+    }
+
+    Ok(RemoteActor {
+        id: document.id,
+        inbox: document.inbox,
+        shared_inbox: document
+            .endpoints
+            .and_then(|endpoints| endpoints.shared_inbox),
+        key_id: document.public_key.id,
+        public_key_pem: document.public_key.public_key_pem,
+    })
+}
+
+/// POST an activity to a remote inbox, signed with the sender's key.
+/// Anything but a 2xx response is an error so the delivery is retried.
+pub async fn deliver(
+    client: &OutboundClient,
+    inbox: &str,
+    activity: &str,
+    key_id: &str,
+    private_key_pem: &str,
+) -> Result<()> {
+    let signed = sign_post(private_key_pem, key_id, inbox, activity.as_bytes())?;
+    let response = client
+        .client_for(inbox, true)
+        .await?
+        .post(inbox)
+        .header(reqwest::header::CONTENT_TYPE, ACTIVITY_JSON)
+        .header(reqwest::header::HOST, signed.host)
+        .header(reqwest::header::DATE, signed.date)
+        .header("digest", signed.digest)
+        .header("signature", signed.signature)
+        .timeout(REQUEST_TIMEOUT)
+        .body(activity.to_string())
+        .send()
+        .await
+        .with_context(|| format!("failed to deliver to {inbox}"))?;
+
+    if !response.status().is_success() {
+        bail!("inbox {inbox} returned {}", response.status());
+    }
+    Ok(())
+}
@@ -6,10 +6,16 @@ use crate::application::errors::AppError;
 
 pub const OPENROUTER_URL: &str = "https://openrouter.ai/api/v1/chat/completions";
 const USER_AGENT: &str = "Booklog/1.0";
-const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+const REQUEST_TIMEOUT: Duration = Duration::from_mins(1);
 
 const AUTHOR_PROMPT: &str = r#"Extract author information from this input. Use web search to look up any details you cannot determine from the input alone. Return a JSON object with these fields (only include fields you can identify with confidence):
 - "name": the author's name
+- "bio": a short biography of two or three sentences
+- "birth_year": the year the author was born
+- "death_year": the year the author died, if they have died
+- "nationality": the author's nationality (e.g. "British")
+- "website": the author's official website URL
+- "aliases": an array of pen names or other names the author has published under
 
 Return ONLY the JSON object, no other text."#;
 
@@ -18,7 +24,7 @@ const COVER_PROMPT: &str = r#"Find book cover images for the book described belo
 
 Return ONLY the JSON object, no other text."#;
 
-fn book_prompt(available_genres: &[String]) -> String {
+fn book_prompt(genre_outline: &str) -> String {
     let mut prompt = String::from(
         r#"Extract book information from this input. Use web search to look up any details you cannot determine from the input alone (e.g. author, ISBN, page count, publisher, genres). Return a JSON object with these fields (only include fields you can identify with confidence):
 - "title": the book's title
@@ -26,22 +32,23 @@ fn book_prompt(available_genres: &[String]) -> String {
 - "isbn": the ISBN (10 or 13 digit)
 - "description": a brief description or summary of the book
 - "page_count": the number of pages
+- "narrator": for audiobooks, the narrator's name
+- "duration_minutes": for audiobooks, the total running time in minutes
 - "year_published": the year the book was first published
 - "publisher": the publisher's name
 - "language": the language the book is written in
-- "primary_genre": the single best-matching genre for this book
+- "primary_genre": the single best-matching genre for this book, as specific as possible
 - "secondary_genre": an optional secondary genre for this book
 - "cover_image_urls": an array of up to 5 URLs to book cover images. Search for cover images on OpenLibrary (e.g. https://covers.openlibrary.org/b/isbn/{isbn}-L.jpg), Google Books, Amazon, and other web properties. Include only direct image URLs that point to actual cover art.
 
 Return ONLY the JSON object, no other text."#,
     );
 
-    if !available_genres.is_empty() {
+    if !genre_outline.is_empty() {
         use std::fmt::Write;
         let _ = write!(
             prompt,
-            "\n\nHere are the available genres to choose from (prefer these, but suggest new ones if none fit well): {}",
-            available_genres.join(", ")
+            "\n\nHere are the available genres to choose from, as a tree where indented genres are subgenres of the one above them. Prefer these, picking the most specific genre that fits (a subgenre over its parent), but suggest new ones if none fit well. Use the genre name alone, without its parents:\n{genre_outline}"
         );
     }
 
@@ -67,6 +74,13 @@ pub struct ExtractionInput {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExtractedAuthor {
     pub name: Option<String>,
+    pub bio: Option<String>,
+    pub birth_year: Option<i32>,
+    pub death_year: Option<i32>,
+    pub nationality: Option<String>,
+    pub website: Option<String>,
+    #[serde(default)]
+    pub aliases: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -76,6 +90,8 @@ pub struct ExtractedBook {
     pub isbn: Option<String>,
     pub description: Option<String>,
     pub page_count: Option<i32>,
+    pub narrator: Option<String>,
+    pub duration_minutes: Option<i32>,
     pub year_published: Option<i32>,
     pub publisher: Option<String>,
     pub language: Option<String>,
@@ -114,9 +130,9 @@ pub async fn extract_book(
     api_key: &str,
     model: &str,
     input: &ExtractionInput,
-    available_genres: &[String],
+    genre_outline: &str,
 ) -> Result<(ExtractedBook, Option<Usage>), AppError> {
-    let prompt = book_prompt(available_genres);
+    let prompt = book_prompt(genre_outline);
     let (content, usage) = call_openrouter(client, url, api_key, model, &prompt, input).await?;
     let json = extract_json(&content);
 
@@ -321,6 +337,26 @@ struct ResponseMessage {
 mod tests {
     use super::*;
 
+    #[test]
+    fn book_prompt_includes_genre_tree() {
+        let prompt = book_prompt("- Science Fiction\n  - Space Opera");
+        assert!(prompt.contains("most specific genre"));
+        assert!(prompt.ends_with("- Science Fiction\n  - Space Opera"));
+        assert!(!book_prompt("").contains("available genres"));
+    }
+
+    #[test]
+    fn parse_extracted_author_details() {
+        let author: ExtractedAuthor = serde_json::from_str(
+            r#"{"name": "J.K. Rowling", "birth_year": 1965, "nationality": "British", "aliases": ["Robert Galbraith"]}"#,
+        )
+        .unwrap();
+        assert_eq!(author.birth_year, Some(1965));
+        assert_eq!(author.death_year, None);
+        assert_eq!(author.nationality.as_deref(), Some("British"));
+        assert_eq!(author.aliases, ["Robert Galbraith"]);
+    }
+
     #[test]
     fn parse_chat_response() {
         let json = r#"{
@@ -404,6 +440,21 @@ mod tests {
         assert_eq!(book.secondary_genre.as_deref(), Some("Literary Fiction"));
     }
 
+    #[test]
+    fn parse_audiobook_extraction() {
+        let json = r#"{
+            "title": "Project Hail Mary",
+            "author_name": "Andy Weir",
+            "narrator": "Ray Porter",
+            "duration_minutes": 970
+        }"#;
+
+        let book: ExtractedBook = serde_json::from_str(json).unwrap();
+        assert_eq!(book.narrator.as_deref(), Some("Ray Porter"));
+        assert_eq!(book.duration_minutes, Some(970));
+        assert!(book.page_count.is_none());
+    }
+
     #[test]
     fn parse_partial_book_extraction() {
         let json = r#"{"title": "1984", "author_name": "George Orwell"}"#;
@@ -413,6 +464,8 @@ mod tests {
         assert_eq!(book.author_name.as_deref(), Some("George Orwell"));
         assert!(book.isbn.is_none());
         assert!(book.page_count.is_none());
+        assert!(book.narrator.is_none());
+        assert!(book.duration_minutes.is_none());
         assert!(book.primary_genre.is_none());
         assert!(book.secondary_genre.is_none());
     }
@@ -6,11 +6,12 @@ use serde::{Deserialize, Serialize};
 use serde_json::{from_str, to_string};
 use sqlx::AssertSqlSafe;
 
-use crate::domain::authors::Author;
+use crate::domain::authors::{Author, AuthorDetails};
 use crate::domain::book_items::Book;
 use crate::domain::genres::Genre;
 use crate::domain::ids::{AuthorId, BookId, GenreId, ReadingId, TimelineEventId, UserId};
 use crate::domain::readings::{QuickReview, Reading, ReadingFormat, ReadingStatus};
+use crate::domain::tags::BookTag;
 
 fn encode_quick_reviews(reviews: &[QuickReview]) -> Option<String> {
     if reviews.is_empty() {
@@ -93,6 +94,8 @@ pub struct BackupData {
     pub timeline_events: Vec<TimelineEvent>,
     #[serde(default)]
     pub images: Vec<BackupImage>,
+    #[serde(default)]
+    pub tags: Vec<BookTag>,
 }
 
 pub struct BackupService {
@@ -119,6 +122,7 @@ impl BackupService {
         let readings = self.export_readings(&mut tx).await?;
         let timeline_events = self.export_timeline_events(&mut tx).await?;
         let images = self.export_images(&mut tx).await?;
+        let tags = self.export_tags(&mut tx).await?;
 
         tx.commit()
             .await
@@ -134,6 +138,7 @@ impl BackupService {
             readings,
             timeline_events,
             images,
+            tags,
         })
     }
 
@@ -155,6 +160,7 @@ impl BackupService {
         self.restore_timeline_events(&mut tx, &data.timeline_events)
             .await?;
         self.restore_images(&mut tx, &data.images).await?;
+        self.restore_tags(&mut tx, &data.tags).await?;
 
         tx.commit().await.context("failed to commit transaction")?;
 
@@ -171,6 +177,7 @@ impl BackupService {
 
         let tables = [
             "entity_images",
+            "book_tags",
             "readings",
             "book_authors",
             "books",
@@ -178,6 +185,8 @@ impl BackupService {
             "timeline_events",
             "authors",
             "stats_cache",
+            "stats_year_partials",
+            "household_stats_cache",
         ];
 
         for table in tables {
@@ -200,7 +209,7 @@ impl BackupService {
         tx: &mut DatabaseTransaction<'_>,
     ) -> anyhow::Result<Vec<Author>> {
         let records = sqlx::query_as::<_, AuthorRecord>(
-            "SELECT id, name, created_at FROM authors ORDER BY id",
+            "SELECT id, name, bio, birth_year, death_year, nationality, website, aliases_json, created_at FROM authors ORDER BY id",
         )
         .fetch_all(&mut **tx)
         .await
@@ -210,18 +219,19 @@ impl BackupService {
     }
 
     async fn export_genres(&self, tx: &mut DatabaseTransaction<'_>) -> anyhow::Result<Vec<Genre>> {
-        let records =
-            sqlx::query_as::<_, GenreRecord>("SELECT id, name, created_at FROM genres ORDER BY id")
-                .fetch_all(&mut **tx)
-                .await
-                .context("failed to export genres")?;
+        let records = sqlx::query_as::<_, GenreRecord>(
+            "SELECT id, name, parent_id, created_at FROM genres ORDER BY id",
+        )
+        .fetch_all(&mut **tx)
+        .await
+        .context("failed to export genres")?;
 
         Ok(records.into_iter().map(GenreRecord::into_domain).collect())
     }
 
     async fn export_books(&self, tx: &mut DatabaseTransaction<'_>) -> anyhow::Result<Vec<Book>> {
         let records = sqlx::query_as::<_, BookRecord>(
-            "SELECT id, title, isbn, description, page_count, year_published, publisher, language, primary_genre_id, secondary_genre_id, created_at FROM books ORDER BY id",
+            "SELECT id, title, isbn, description, page_count, duration_minutes, year_published, publisher, language, primary_genre_id, secondary_genre_id, created_at FROM books ORDER BY id",
         )
         .fetch_all(&mut **tx)
         .await
@@ -256,7 +266,7 @@ impl BackupService {
         tx: &mut DatabaseTransaction<'_>,
     ) -> anyhow::Result<Vec<Reading>> {
         let records = sqlx::query_as::<_, ReadingRecord>(
-            "SELECT id, user_id, book_id, status, format, started_at, finished_at, rating, review, created_at, updated_at FROM readings ORDER BY id",
+            "SELECT id, user_id, book_id, status, format, started_at, finished_at, rating, review, private, current_page, created_at, updated_at FROM readings ORDER BY id",
         )
         .fetch_all(&mut **tx)
         .await
@@ -273,7 +283,7 @@ impl BackupService {
         tx: &mut DatabaseTransaction<'_>,
     ) -> anyhow::Result<Vec<TimelineEvent>> {
         let records = sqlx::query_as::<_, TimelineEventRecord>(
-            "SELECT id, entity_type, entity_id, action, occurred_at, title, details_json, genres_json, reading_data_json FROM timeline_events ORDER BY id",
+            "SELECT id, entity_type, entity_id, action, occurred_at, title, details_json, genres_json, reading_data_json, changes_json FROM timeline_events ORDER BY id",
         )
         .fetch_all(&mut **tx)
         .await
@@ -299,6 +309,20 @@ impl BackupService {
         Ok(records.into_iter().map(ImageRecord::into_backup).collect())
     }
 
+    async fn export_tags(&self, tx: &mut DatabaseTransaction<'_>) -> anyhow::Result<Vec<BookTag>> {
+        let records = sqlx::query_as::<_, BookTagRecord>(
+            "SELECT user_id, book_id, tag, created_at FROM book_tags ORDER BY user_id, book_id, tag",
+        )
+        .fetch_all(&mut **tx)
+        .await
+        .context("failed to export tags")?;
+
+        Ok(records
+            .into_iter()
+            .map(BookTagRecord::into_domain)
+            .collect())
+    }
+
     // --- Restore methods ---
 
     async fn verify_empty_database(&self) -> anyhow::Result<()> {
@@ -310,6 +334,7 @@ impl BackupService {
             "readings",
             "timeline_events",
             "entity_images",
+            "book_tags",
         ];
 
         for table in tables {
@@ -336,13 +361,24 @@ impl BackupService {
         authors: &[Author],
     ) -> anyhow::Result<()> {
         for author in authors {
-            sqlx::query("INSERT INTO authors (id, name, created_at) VALUES (?, ?, ?)")
-                .bind(i64::from(author.id))
-                .bind(&author.name)
-                .bind(author.created_at)
-                .execute(&mut **tx)
-                .await
-                .context("failed to restore author")?;
+            let details = &author.details;
+            let aliases_json = to_string(&details.aliases)
+                .context("failed to encode author aliases for restore")?;
+            sqlx::query(
+                "INSERT INTO authors (id, name, bio, birth_year, death_year, nationality, website, aliases_json, created_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(i64::from(author.id))
+            .bind(&author.name)
+            .bind(details.bio.as_deref())
+            .bind(details.birth_year)
+            .bind(details.death_year)
+            .bind(details.nationality.as_deref())
+            .bind(details.website.as_deref())
+            .bind(&aliases_json)
+            .bind(author.created_at)
+            .execute(&mut **tx)
+            .await
+            .context("failed to restore author")?;
         }
 
         Ok(())
@@ -363,6 +399,20 @@ impl BackupService {
                 .context("failed to restore genre")?;
         }
 
+        // Parents can have higher IDs than their subgenres, so link them once
+        // every genre exists.
+        for genre in genres {
+            let Some(parent_id) = genre.parent_id else {
+                continue;
+            };
+            sqlx::query("UPDATE genres SET parent_id = ? WHERE id = ?")
+                .bind(i64::from(parent_id))
+                .bind(i64::from(genre.id))
+                .execute(&mut **tx)
+                .await
+                .context("failed to restore genre parent")?;
+        }
+
         Ok(())
     }
 
@@ -373,13 +423,14 @@ impl BackupService {
     ) -> anyhow::Result<()> {
         for book in books {
             sqlx::query(
-                "INSERT INTO books (id, title, isbn, description, page_count, year_published, publisher, language, primary_genre_id, secondary_genre_id, created_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                "INSERT INTO books (id, title, isbn, description, page_count, duration_minutes, year_published, publisher, language, primary_genre_id, secondary_genre_id, created_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             )
             .bind(i64::from(book.id))
             .bind(&book.title)
             .bind(book.isbn.as_deref())
             .bind(book.description.as_deref())
             .bind(book.page_count)
+            .bind(book.duration_minutes)
             .bind(book.year_published)
             .bind(book.publisher.as_deref())
             .bind(book.language.as_deref())
@@ -419,7 +470,7 @@ impl BackupService {
     ) -> anyhow::Result<()> {
         for reading in readings {
             sqlx::query(
-                "INSERT INTO readings (id, user_id, book_id, status, format, started_at, finished_at, rating, review, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                "INSERT INTO readings (id, user_id, book_id, status, format, started_at, finished_at, rating, review, private, current_page, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             )
             .bind(i64::from(reading.id))
             .bind(i64::from(reading.user_id))
@@ -430,6 +481,8 @@ impl BackupService {
             .bind(reading.finished_at)
             .bind(reading.rating)
             .bind(encode_quick_reviews(&reading.quick_reviews))
+            .bind(reading.private)
+            .bind(reading.current_page)
             .bind(reading.created_at)
             .bind(reading.updated_at)
             .execute(&mut **tx)
@@ -459,8 +512,13 @@ impl BackupService {
                 .transpose()
                 .context("failed to encode timeline reading data for restore")?;
 
+            let changes_json = (!event.changes.is_empty())
+                .then(|| to_string(&event.changes))
+                .transpose()
+                .context("failed to encode timeline event changes for restore")?;
+
             sqlx::query(
-                "INSERT INTO timeline_events (id, entity_type, entity_id, action, occurred_at, title, details_json, genres_json, reading_data_json) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                "INSERT INTO timeline_events (id, entity_type, entity_id, action, occurred_at, title, details_json, genres_json, reading_data_json, changes_json) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             )
             .bind(i64::from(event.id))
             .bind(&event.entity_type)
@@ -471,6 +529,7 @@ impl BackupService {
             .bind(&details_json)
             .bind(&genres_json)
             .bind(reading_data_json.as_deref())
+            .bind(changes_json.as_deref())
             .execute(&mut **tx)
             .await
             .context("failed to restore timeline event")?;
@@ -500,6 +559,27 @@ impl BackupService {
 
         Ok(())
     }
+
+    async fn restore_tags(
+        &self,
+        tx: &mut DatabaseTransaction<'_>,
+        tags: &[BookTag],
+    ) -> anyhow::Result<()> {
+        for tag in tags {
+            sqlx::query(
+                "INSERT INTO book_tags (user_id, book_id, tag, created_at) VALUES (?, ?, ?, ?)",
+            )
+            .bind(i64::from(tag.user_id))
+            .bind(i64::from(tag.book_id))
+            .bind(&tag.tag)
+            .bind(tag.created_at)
+            .execute(&mut **tx)
+            .await
+            .context("failed to restore tag")?;
+        }
+
+        Ok(())
+    }
 }
 
 // --- Record types for export queries ---
@@ -508,6 +588,12 @@ impl BackupService {
 struct AuthorRecord {
     id: i64,
     name: String,
+    bio: Option<String>,
+    birth_year: Option<i32>,
+    death_year: Option<i32>,
+    nationality: Option<String>,
+    website: Option<String>,
+    aliases_json: String,
     created_at: DateTime<Utc>,
 }
 
@@ -516,6 +602,14 @@ impl AuthorRecord {
         Author {
             id: AuthorId::from(self.id),
             name: self.name,
+            details: AuthorDetails {
+                bio: self.bio,
+                birth_year: self.birth_year,
+                death_year: self.death_year,
+                nationality: self.nationality,
+                website: self.website,
+                aliases: serde_json::from_str(&self.aliases_json).unwrap_or_default(),
+            },
             created_at: self.created_at,
         }
     }
@@ -525,6 +619,7 @@ impl AuthorRecord {
 struct GenreRecord {
     id: i64,
     name: String,
+    parent_id: Option<i64>,
     created_at: DateTime<Utc>,
 }
 
@@ -533,6 +628,7 @@ impl GenreRecord {
         Genre {
             id: GenreId::from(self.id),
             name: self.name,
+            parent_id: self.parent_id.map(GenreId::from),
             created_at: self.created_at,
         }
     }
@@ -545,6 +641,7 @@ struct BookRecord {
     isbn: Option<String>,
     description: Option<String>,
     page_count: Option<i32>,
+    duration_minutes: Option<i32>,
     year_published: Option<i32>,
     publisher: Option<String>,
     language: Option<String>,
@@ -561,6 +658,7 @@ impl BookRecord {
             isbn: self.isbn,
             description: self.description,
             page_count: self.page_count,
+            duration_minutes: self.duration_minutes,
             year_published: self.year_published,
             publisher: self.publisher,
             language: self.language,
@@ -589,6 +687,8 @@ struct ReadingRecord {
     finished_at: Option<NaiveDate>,
     rating: Option<f64>,
     review: Option<String>,
+    private: bool,
+    current_page: Option<i32>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -616,6 +716,8 @@ impl ReadingRecord {
             finished_at: self.finished_at,
             rating: self.rating,
             quick_reviews: crate::infrastructure::repositories::books::readings::SqlReadingRepository::decode_quick_reviews(self.review),
+            private: self.private,
+            current_page: self.current_page,
             created_at: self.created_at,
             updated_at: self.updated_at,
         })
@@ -633,6 +735,7 @@ struct TimelineEventRecord {
     details_json: Option<String>,
     genres_json: Option<String>,
     reading_data_json: Option<String>,
+    changes_json: Option<String>,
 }
 
 impl TimelineEventRecord {
@@ -640,6 +743,7 @@ impl TimelineEventRecord {
         let details = decode_json_vec(self.details_json, "timeline event details")?;
         let genres = decode_json_vec(self.genres_json, "timeline genres")?;
         let reading_data = decode_json_opt(self.reading_data_json, "timeline reading data")?;
+        let changes = decode_json_vec(self.changes_json, "timeline event changes")?;
 
         Ok(TimelineEvent {
             id: TimelineEventId::from(self.id),
@@ -651,6 +755,7 @@ impl TimelineEventRecord {
             details,
             genres,
             reading_data,
+            changes,
         })
     }
 }
@@ -675,3 +780,22 @@ impl ImageRecord {
         }
     }
 }
+
+#[derive(sqlx::FromRow)]
+struct BookTagRecord {
+    user_id: i64,
+    book_id: i64,
+    tag: String,
+    created_at: DateTime<Utc>,
+}
+
+impl BookTagRecord {
+    fn into_domain(self) -> BookTag {
+        BookTag {
+            user_id: UserId::new(self.user_id),
+            book_id: BookId::new(self.book_id),
+            tag: self.tag,
+            created_at: self.created_at,
+        }
+    }
+}
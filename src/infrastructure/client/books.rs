@@ -22,12 +22,19 @@ impl<'a> BooksClient<'a> {
         update_type: UpdateBook
     );
 
-    pub async fn list(&self, author_id: Option<AuthorId>) -> Result<Vec<BookWithAuthors>> {
+    pub async fn list(
+        &self,
+        author_id: Option<AuthorId>,
+        query: Option<&str>,
+    ) -> Result<Vec<BookWithAuthors>> {
         let mut url = self.client.endpoint("api/v1/books")?;
         if let Some(id) = author_id {
             url.query_pairs_mut()
                 .append_pair("author_id", &id.to_string());
         }
+        if let Some(q) = query {
+            url.query_pairs_mut().append_pair("q", q);
+        }
         let response = self
             .client
             .request(reqwest::Method::GET, url)
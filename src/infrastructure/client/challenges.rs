@@ -0,0 +1,152 @@
+use anyhow::Result;
+
+use super::BooklogClient;
+use crate::domain::challenges::{
+    Challenge, ChallengeEntry, ChallengeProgress, ChallengePrompt, ChallengeSummary,
+    ChallengeWithPrompts, NewChallengePrompt, UpdateChallenge,
+};
+use crate::domain::ids::{ChallengeId, ChallengePromptId, ReadingId};
+
+pub struct ChallengesClient<'a> {
+    client: &'a BooklogClient,
+}
+
+impl<'a> ChallengesClient<'a> {
+    pub fn new(client: &'a BooklogClient) -> Self {
+        Self { client }
+    }
+
+    pub async fn create(
+        &self,
+        name: &str,
+        description: Option<&str>,
+        year: Option<i32>,
+        shared: bool,
+        prompts: &[NewChallengePrompt],
+    ) -> Result<ChallengeWithPrompts> {
+        let url = self.client.endpoint("api/v1/challenges")?;
+        let payload = serde_json::json!({
+            "name": name,
+            "description": description,
+            "year": year,
+            "shared": shared,
+            "prompts": prompts,
+        });
+        let response = self
+            .client
+            .request(reqwest::Method::POST, url)
+            .json(&payload)
+            .send()
+            .await?;
+        self.client.handle_response(response).await
+    }
+
+    pub async fn list(&self) -> Result<Vec<ChallengeSummary>> {
+        let url = self.client.endpoint("api/v1/challenges")?;
+        let response = self
+            .client
+            .request(reqwest::Method::GET, url)
+            .send()
+            .await?;
+        self.client.handle_response(response).await
+    }
+
+    pub async fn get(&self, id: ChallengeId) -> Result<ChallengeProgress> {
+        let url = self.client.endpoint(&format!("api/v1/challenges/{id}"))?;
+        let response = self
+            .client
+            .request(reqwest::Method::GET, url)
+            .send()
+            .await?;
+        self.client.handle_response(response).await
+    }
+
+    pub async fn update(&self, id: ChallengeId, payload: &UpdateChallenge) -> Result<Challenge> {
+        let url = self.client.endpoint(&format!("api/v1/challenges/{id}"))?;
+        let response = self
+            .client
+            .request(reqwest::Method::PUT, url)
+            .json(payload)
+            .send()
+            .await?;
+        self.client.handle_response(response).await
+    }
+
+    pub async fn delete(&self, id: ChallengeId) -> Result<()> {
+        let url = self.client.endpoint(&format!("api/v1/challenges/{id}"))?;
+        let response = self
+            .client
+            .request(reqwest::Method::DELETE, url)
+            .send()
+            .await?;
+        self.expect_success(response).await
+    }
+
+    pub async fn add_prompt(
+        &self,
+        id: ChallengeId,
+        prompt: &NewChallengePrompt,
+    ) -> Result<ChallengePrompt> {
+        let url = self
+            .client
+            .endpoint(&format!("api/v1/challenges/{id}/prompts"))?;
+        let response = self
+            .client
+            .request(reqwest::Method::POST, url)
+            .json(prompt)
+            .send()
+            .await?;
+        self.client.handle_response(response).await
+    }
+
+    pub async fn delete_prompt(&self, id: ChallengeId, prompt_id: ChallengePromptId) -> Result<()> {
+        let url = self
+            .client
+            .endpoint(&format!("api/v1/challenges/{id}/prompts/{prompt_id}"))?;
+        let response = self
+            .client
+            .request(reqwest::Method::DELETE, url)
+            .send()
+            .await?;
+        self.expect_success(response).await
+    }
+
+    pub async fn assign(
+        &self,
+        id: ChallengeId,
+        prompt_id: ChallengePromptId,
+        reading_id: ReadingId,
+    ) -> Result<ChallengeEntry> {
+        let url = self
+            .client
+            .endpoint(&format!("api/v1/challenges/{id}/prompts/{prompt_id}/entry"))?;
+        let payload = serde_json::json!({ "reading_id": reading_id });
+        let response = self
+            .client
+            .request(reqwest::Method::PUT, url)
+            .json(&payload)
+            .send()
+            .await?;
+        self.client.handle_response(response).await
+    }
+
+    pub async fn unassign(&self, id: ChallengeId, prompt_id: ChallengePromptId) -> Result<()> {
+        let url = self
+            .client
+            .endpoint(&format!("api/v1/challenges/{id}/prompts/{prompt_id}/entry"))?;
+        let response = self
+            .client
+            .request(reqwest::Method::DELETE, url)
+            .send()
+            .await?;
+        self.expect_success(response).await
+    }
+
+    async fn expect_success(&self, response: reqwest::Response) -> Result<()> {
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(self.client.response_error(response).await)
+        }
+    }
+}
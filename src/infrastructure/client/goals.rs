@@ -0,0 +1,92 @@
+use anyhow::Result;
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use super::BooklogClient;
+use crate::domain::goals::{GoalMetric, GoalProgress, UpdateReadingGoal};
+use crate::domain::ids::{GenreId, GoalId};
+use crate::domain::readings::ReadingFormat;
+
+/// Request body for creating a goal. Give either `year` or both dates; with
+/// neither the server uses the current year.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct NewGoalRequest {
+    pub metric: GoalMetric,
+    pub target: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub year: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genre_id: Option<GenreId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<ReadingFormat>,
+}
+
+pub struct GoalsClient<'a> {
+    client: &'a BooklogClient,
+}
+
+impl<'a> GoalsClient<'a> {
+    pub fn new(client: &'a BooklogClient) -> Self {
+        Self { client }
+    }
+
+    pub async fn create(&self, payload: &NewGoalRequest) -> Result<GoalProgress> {
+        let url = self.client.endpoint("api/v1/goals")?;
+        let response = self
+            .client
+            .request(reqwest::Method::POST, url)
+            .json(payload)
+            .send()
+            .await?;
+        self.client.handle_response(response).await
+    }
+
+    pub async fn list(&self) -> Result<Vec<GoalProgress>> {
+        let url = self.client.endpoint("api/v1/goals")?;
+        let response = self
+            .client
+            .request(reqwest::Method::GET, url)
+            .send()
+            .await?;
+        self.client.handle_response(response).await
+    }
+
+    pub async fn get(&self, id: GoalId) -> Result<GoalProgress> {
+        let url = self.client.endpoint(&format!("api/v1/goals/{id}"))?;
+        let response = self
+            .client
+            .request(reqwest::Method::GET, url)
+            .send()
+            .await?;
+        self.client.handle_response(response).await
+    }
+
+    pub async fn update(&self, id: GoalId, payload: &UpdateReadingGoal) -> Result<GoalProgress> {
+        let url = self.client.endpoint(&format!("api/v1/goals/{id}"))?;
+        let response = self
+            .client
+            .request(reqwest::Method::PUT, url)
+            .json(payload)
+            .send()
+            .await?;
+        self.client.handle_response(response).await
+    }
+
+    pub async fn delete(&self, id: GoalId) -> Result<()> {
+        let url = self.client.endpoint(&format!("api/v1/goals/{id}"))?;
+        let response = self
+            .client
+            .request(reqwest::Method::DELETE, url)
+            .send()
+            .await?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(self.client.response_error(response).await)
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use anyhow::{Context, Result};
+use reqwest::StatusCode;
+
+use super::BooklogClient;
+use crate::domain::ids::BookId;
+use crate::domain::library::LibraryExport;
+
+pub struct LibraryClient<'a> {
+    client: &'a BooklogClient,
+}
+
+impl<'a> LibraryClient<'a> {
+    pub fn new(client: &'a BooklogClient) -> Self {
+        Self { client }
+    }
+
+    pub async fn export(&self) -> Result<LibraryExport> {
+        let url = self.client.endpoint("api/v1/export/library")?;
+        let response = self
+            .client
+            .request(reqwest::Method::GET, url)
+            .send()
+            .await
+            .context("failed to issue library export request")?;
+        self.client.handle_response(response).await
+    }
+
+    /// A book's JPEG cover, full size or as its thumbnail; `None` if it has
+    /// none.
+    pub async fn cover(&self, book_id: BookId, thumbnail: bool) -> Result<Option<Vec<u8>>> {
+        let kind = if thumbnail { "thumbnail" } else { "image" };
+        let url = self
+            .client
+            .endpoint(&format!("api/v1/book/{book_id}/{kind}"))?;
+        let response = self
+            .client
+            .request(reqwest::Method::GET, url)
+            .send()
+            .await
+            .with_context(|| format!("failed to fetch cover for book {book_id}"))?;
+
+        match response.status() {
+            StatusCode::NOT_FOUND => Ok(None),
+            status if status.is_success() => {
+                let data = response
+                    .bytes()
+                    .await
+                    .with_context(|| format!("failed to read cover for book {book_id}"))?;
+                Ok(Some(data.to_vec()))
+            }
+            _ => Err(self.client.response_error(response).await),
+        }
+    }
+}
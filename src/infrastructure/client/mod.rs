@@ -1,11 +1,17 @@
 pub mod authors;
 pub mod backup;
 pub mod books;
+pub mod challenges;
 pub mod genres;
+pub mod goals;
+pub mod library;
 pub mod readings;
+pub mod shelves;
+pub mod tags;
 pub mod timeline;
 pub mod tokens;
 pub mod user_books;
+pub mod webhooks;
 
 /// Generates `create`, `get`, `update`, and `delete` methods for an entity client.
 ///
@@ -136,10 +142,30 @@ impl BooklogClient {
         readings::ReadingsClient::new(self)
     }
 
+    pub fn challenges(&self) -> challenges::ChallengesClient<'_> {
+        challenges::ChallengesClient::new(self)
+    }
+
     pub fn genres(&self) -> genres::GenresClient<'_> {
         genres::GenresClient::new(self)
     }
 
+    pub fn goals(&self) -> goals::GoalsClient<'_> {
+        goals::GoalsClient::new(self)
+    }
+
+    pub fn library(&self) -> library::LibraryClient<'_> {
+        library::LibraryClient::new(self)
+    }
+
+    pub fn shelves(&self) -> shelves::ShelvesClient<'_> {
+        shelves::ShelvesClient::new(self)
+    }
+
+    pub fn tags(&self) -> tags::TagsClient<'_> {
+        tags::TagsClient::new(self)
+    }
+
     pub fn timeline(&self) -> timeline::TimelineClient<'_> {
         timeline::TimelineClient::new(self)
     }
@@ -148,6 +174,10 @@ impl BooklogClient {
         user_books::UserBooksClient::new(self)
     }
 
+    pub fn webhooks(&self) -> webhooks::WebhooksClient<'_> {
+        webhooks::WebhooksClient::new(self)
+    }
+
     pub(crate) fn endpoint(&self, path: &str) -> Result<Url> {
         self.base_url
             .join(path)
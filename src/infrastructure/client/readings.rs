@@ -22,12 +22,19 @@ impl<'a> ReadingsClient<'a> {
         update_type: UpdateReading
     );
 
-    pub async fn list(&self, book_id: Option<BookId>) -> Result<Vec<ReadingWithBook>> {
+    pub async fn list(
+        &self,
+        book_id: Option<BookId>,
+        query: Option<&str>,
+    ) -> Result<Vec<ReadingWithBook>> {
         let mut url = self.client.endpoint("api/v1/readings")?;
         if let Some(id) = book_id {
             url.query_pairs_mut()
                 .append_pair("book_id", &id.to_string());
         }
+        if let Some(q) = query {
+            url.query_pairs_mut().append_pair("q", q);
+        }
         let response = self
             .client
             .request(reqwest::Method::GET, url)
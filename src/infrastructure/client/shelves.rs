@@ -0,0 +1,131 @@
+use anyhow::Result;
+
+use super::BooklogClient;
+use crate::domain::ids::{BookId, ShelfId};
+use crate::domain::shelves::{SavedShelf, SavedShelfWithBooks, ShelfKind, UpdateSavedShelf};
+
+pub struct ShelvesClient<'a> {
+    client: &'a BooklogClient,
+}
+
+impl<'a> ShelvesClient<'a> {
+    pub fn new(client: &'a BooklogClient) -> Self {
+        Self { client }
+    }
+
+    pub async fn create(
+        &self,
+        name: &str,
+        description: Option<&str>,
+        kind: ShelfKind,
+        query: Option<&str>,
+        pinned: bool,
+        public: bool,
+    ) -> Result<SavedShelf> {
+        let url = self.client.endpoint("api/v1/shelves")?;
+        let payload = serde_json::json!({
+            "name": name,
+            "description": description,
+            "kind": kind.as_str(),
+            "query": query,
+            "pinned": pinned,
+            "public": public,
+        });
+        let response = self
+            .client
+            .request(reqwest::Method::POST, url)
+            .json(&payload)
+            .send()
+            .await?;
+        self.client.handle_response(response).await
+    }
+
+    pub async fn list(&self) -> Result<Vec<SavedShelf>> {
+        let url = self.client.endpoint("api/v1/shelves")?;
+        let response = self
+            .client
+            .request(reqwest::Method::GET, url)
+            .send()
+            .await?;
+        self.client.handle_response(response).await
+    }
+
+    pub async fn get(&self, id: ShelfId) -> Result<SavedShelfWithBooks> {
+        let url = self.client.endpoint(&format!("api/v1/shelves/{id}"))?;
+        let response = self
+            .client
+            .request(reqwest::Method::GET, url)
+            .send()
+            .await?;
+        self.client.handle_response(response).await
+    }
+
+    pub async fn update(&self, id: ShelfId, payload: &UpdateSavedShelf) -> Result<SavedShelf> {
+        let url = self.client.endpoint(&format!("api/v1/shelves/{id}"))?;
+        let response = self
+            .client
+            .request(reqwest::Method::PUT, url)
+            .json(payload)
+            .send()
+            .await?;
+        self.client.handle_response(response).await
+    }
+
+    pub async fn delete(&self, id: ShelfId) -> Result<()> {
+        let url = self.client.endpoint(&format!("api/v1/shelves/{id}"))?;
+        let response = self
+            .client
+            .request(reqwest::Method::DELETE, url)
+            .send()
+            .await?;
+        self.expect_success(response).await
+    }
+
+    pub async fn add_book(&self, id: ShelfId, book_id: BookId) -> Result<()> {
+        let url = self
+            .client
+            .endpoint(&format!("api/v1/shelves/{id}/books"))?;
+        let payload = serde_json::json!({ "book_id": book_id });
+        let response = self
+            .client
+            .request(reqwest::Method::POST, url)
+            .json(&payload)
+            .send()
+            .await?;
+        self.expect_success(response).await
+    }
+
+    pub async fn remove_book(&self, id: ShelfId, book_id: BookId) -> Result<()> {
+        let url = self
+            .client
+            .endpoint(&format!("api/v1/shelves/{id}/books/{book_id}"))?;
+        let response = self
+            .client
+            .request(reqwest::Method::DELETE, url)
+            .send()
+            .await?;
+        self.expect_success(response).await
+    }
+
+    pub async fn move_book(&self, id: ShelfId, book_id: BookId, position: usize) -> Result<()> {
+        let url = self
+            .client
+            .endpoint(&format!("api/v1/shelves/{id}/books/{book_id}"))?;
+        let payload = serde_json::json!({ "position": position });
+        let response = self
+            .client
+            .request(reqwest::Method::PUT, url)
+            .json(&payload)
+            .send()
+            .await?;
+        self.expect_success(response).await
+    }
+
+    async fn expect_success(&self, response: reqwest::Response) -> Result<()> {
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(self.client.response_error(response).await)
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use anyhow::Result;
+
+use super::BooklogClient;
+use crate::domain::ids::BookId;
+use crate::domain::tags::TagCount;
+
+pub struct TagsClient<'a> {
+    client: &'a BooklogClient,
+}
+
+impl<'a> TagsClient<'a> {
+    pub fn new(client: &'a BooklogClient) -> Self {
+        Self { client }
+    }
+
+    pub async fn list(&self, prefix: Option<&str>, limit: Option<u32>) -> Result<Vec<TagCount>> {
+        let mut url = self.client.endpoint("api/v1/tags")?;
+        if let Some(q) = prefix {
+            url.query_pairs_mut().append_pair("q", q);
+        }
+        if let Some(limit) = limit {
+            url.query_pairs_mut()
+                .append_pair("limit", &limit.to_string());
+        }
+        let response = self
+            .client
+            .request(reqwest::Method::GET, url)
+            .send()
+            .await?;
+        self.client.handle_response(response).await
+    }
+
+    pub async fn for_book(&self, book_id: BookId) -> Result<Vec<String>> {
+        let url = self
+            .client
+            .endpoint(&format!("api/v1/books/{book_id}/tags"))?;
+        let response = self
+            .client
+            .request(reqwest::Method::GET, url)
+            .send()
+            .await?;
+        self.client.handle_response(response).await
+    }
+
+    pub async fn set(&self, book_id: BookId, tags: &[String]) -> Result<Vec<String>> {
+        let url = self
+            .client
+            .endpoint(&format!("api/v1/books/{book_id}/tags"))?;
+        let payload = serde_json::json!({ "tags": tags });
+        let response = self
+            .client
+            .request(reqwest::Method::PUT, url)
+            .json(&payload)
+            .send()
+            .await?;
+        self.client.handle_response(response).await
+    }
+
+    pub async fn add(&self, book_id: BookId, tag: &str) -> Result<()> {
+        let url = self
+            .client
+            .endpoint(&format!("api/v1/books/{book_id}/tags"))?;
+        let payload = serde_json::json!({ "tag": tag });
+        let response = self
+            .client
+            .request(reqwest::Method::POST, url)
+            .json(&payload)
+            .send()
+            .await?;
+        self.expect_success(response).await
+    }
+
+    pub async fn remove(&self, book_id: BookId, tag: &str) -> Result<()> {
+        let mut url = self
+            .client
+            .endpoint(&format!("api/v1/books/{book_id}/tags/"))?;
+        url.path_segments_mut()
+            .map_err(|()| anyhow::anyhow!("invalid API URL"))?
+            .pop_if_empty()
+            .push(tag);
+        let response = self
+            .client
+            .request(reqwest::Method::DELETE, url)
+            .send()
+            .await?;
+        self.expect_success(response).await
+    }
+
+    async fn expect_success(&self, response: reqwest::Response) -> Result<()> {
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(self.client.response_error(response).await)
+        }
+    }
+}
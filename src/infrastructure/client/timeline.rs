@@ -2,6 +2,7 @@ use anyhow::{Context, Result};
 use reqwest::StatusCode;
 
 use super::BooklogClient;
+use crate::domain::timeline::{TimelineEvent, TimelineFilter};
 
 pub struct TimelineClient<'a> {
     inner: &'a BooklogClient,
@@ -12,6 +13,33 @@ impl<'a> TimelineClient<'a> {
         Self { inner }
     }
 
+    pub async fn list(&self, filter: &TimelineFilter) -> Result<Vec<TimelineEvent>> {
+        let mut url = self.inner.endpoint("api/v1/timeline")?;
+        {
+            let mut pairs = url.query_pairs_mut();
+            if let Some(entity_type) = &filter.entity_type {
+                pairs.append_pair("type", entity_type);
+            }
+            if let Some(action) = &filter.action {
+                pairs.append_pair("action", action);
+            }
+            if let Some(genre_id) = filter.genre_id {
+                pairs.append_pair("genre", &genre_id.to_string());
+            }
+            if let Some(user_id) = filter.user_id {
+                pairs.append_pair("user", &user_id.to_string());
+            }
+            if let Some(since) = filter.since {
+                pairs.append_pair("since", &since.to_string());
+            }
+            if let Some(until) = filter.until {
+                pairs.append_pair("until", &until.to_string());
+            }
+        }
+        let response = self.inner.request(reqwest::Method::GET, url).send().await?;
+        self.inner.handle_response(response).await
+    }
+
     pub async fn rebuild(&self) -> Result<()> {
         let url = self.inner.endpoint("api/v1/timeline/rebuild")?;
         let response = self
@@ -2,7 +2,7 @@ use anyhow::Result;
 
 use super::BooklogClient;
 use crate::domain::ids::{BookId, UserBookId};
-use crate::domain::user_books::{Shelf, UserBook};
+use crate::domain::user_books::{Shelf, UserBook, UserBookWithTags};
 
 pub struct UserBooksClient<'a> {
     client: &'a BooklogClient,
@@ -19,12 +19,14 @@ impl<'a> UserBooksClient<'a> {
         book_id: BookId,
         shelf: Shelf,
         is_book_club: bool,
+        tags: &[String],
     ) -> Result<UserBook> {
         let url = self.client.endpoint("api/v1/user-books")?;
         let payload = serde_json::json!({
             "book_id": book_id,
             "shelf": shelf.as_str(),
             "book_club": is_book_club,
+            "tags": tags,
         });
         let response = self
             .client
@@ -78,8 +80,11 @@ impl<'a> UserBooksClient<'a> {
         }
     }
 
-    pub async fn list(&self) -> Result<Vec<UserBook>> {
-        let url = self.client.endpoint("api/v1/user-books")?;
+    pub async fn list(&self, query: Option<&str>) -> Result<Vec<UserBookWithTags>> {
+        let mut url = self.client.endpoint("api/v1/user-books")?;
+        if let Some(q) = query {
+            url.query_pairs_mut().append_pair("q", q);
+        }
         let response = self
             .client
             .request(reqwest::Method::GET, url)
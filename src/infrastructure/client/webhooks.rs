@@ -0,0 +1,77 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::BooklogClient;
+use crate::domain::ids::{UserId, WebhookId};
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct NewWebhookRequest {
+    pub url: String,
+    /// Timeline actions to send; empty sends them all.
+    pub events: Vec<String>,
+    /// Admins only: receive every user's events.
+    pub all_users: bool,
+}
+
+pub struct WebhooksClient<'a> {
+    client: &'a BooklogClient,
+}
+
+impl<'a> WebhooksClient<'a> {
+    pub fn new(client: &'a BooklogClient) -> Self {
+        Self { client }
+    }
+
+    pub async fn create(&self, payload: &NewWebhookRequest) -> Result<CreatedWebhook> {
+        let url = self.client.endpoint("api/v1/webhooks")?;
+        let response = self
+            .client
+            .request(reqwest::Method::POST, url)
+            .json(payload)
+            .send()
+            .await?;
+        self.client.handle_response(response).await
+    }
+
+    pub async fn list(&self) -> Result<Vec<WebhookInfo>> {
+        let url = self.client.endpoint("api/v1/webhooks")?;
+        let response = self
+            .client
+            .request(reqwest::Method::GET, url)
+            .send()
+            .await?;
+        self.client.handle_response(response).await
+    }
+
+    pub async fn delete(&self, id: WebhookId) -> Result<()> {
+        let url = self.client.endpoint(&format!("api/v1/webhooks/{id}"))?;
+        let response = self
+            .client
+            .request(reqwest::Method::DELETE, url)
+            .send()
+            .await?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(self.client.response_error(response).await)
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WebhookInfo {
+    pub id: WebhookId,
+    pub user_id: Option<UserId>,
+    pub url: String,
+    pub events: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A new webhook along with its signing secret, which is only returned once.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreatedWebhook {
+    #[serde(flatten)]
+    pub webhook: WebhookInfo,
+    pub secret: String,
+}
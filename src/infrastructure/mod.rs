@@ -1,9 +1,14 @@
+pub mod activitypub;
 pub mod ai;
 pub mod auth;
 pub mod backup;
 pub mod client;
 pub mod cover_fetch;
 pub mod database;
+pub mod http_signatures;
 pub mod image_processing;
+pub mod outbound;
 pub mod repositories;
+pub mod review_card;
 pub mod webauthn;
+pub mod webhooks;
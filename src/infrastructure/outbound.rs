@@ -0,0 +1,192 @@
+//! Requests to URLs supplied by users or remote servers. Unless private
+//! networks are allowed (development and tests), destinations must resolve
+//! to public addresses, and the request is pinned to the addresses that
+//! were checked so a second DNS lookup can't point it somewhere else.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow, bail};
+use url::{Host, Url};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Clone)]
+pub struct OutboundClient {
+    client: reqwest::Client,
+    allow_private_network: bool,
+}
+
+impl OutboundClient {
+    pub fn new(client: reqwest::Client, allow_private_network: bool) -> Self {
+        Self {
+            client,
+            allow_private_network,
+        }
+    }
+
+    /// The shared client, for destinations that don't need checking.
+    pub fn unchecked(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    /// Reject `url` unless it is http(s) (https only if `require_https`)
+    /// and every address its host resolves to is public.
+    pub async fn check(&self, url: &str, require_https: bool) -> Result<()> {
+        self.resolve(url, require_https).await.map(|_| ())
+    }
+
+    /// A client for a single request to `url`, after checking it as in
+    /// [`Self::check`]. Redirects aren't followed, since their targets
+    /// haven't been checked.
+    pub async fn client_for(&self, url: &str, require_https: bool) -> Result<reqwest::Client> {
+        let Some((url, addrs)) = self.resolve(url, require_https).await? else {
+            return Ok(self.client.clone());
+        };
+        let mut builder = reqwest::ClientBuilder::new()
+            .timeout(REQUEST_TIMEOUT)
+            .redirect(reqwest::redirect::Policy::none());
+        if let Some(Host::Domain(domain)) = url.host() {
+            builder = builder.resolve_to_addrs(domain, &addrs);
+        }
+        builder.build().context("failed to build HTTP client")
+    }
+
+    /// The parsed URL and its checked addresses, or `None` when private
+    /// networks are allowed and nothing was checked.
+    async fn resolve(
+        &self,
+        url: &str,
+        require_https: bool,
+    ) -> Result<Option<(Url, Vec<SocketAddr>)>> {
+        let parsed = Url::parse(url).with_context(|| format!("invalid URL {url}"))?;
+        if self.allow_private_network {
+            return Ok(None);
+        }
+        match parsed.scheme() {
+            "https" => {}
+            "http" if !require_https => {}
+            _ if require_https => bail!("{url} is not an https URL"),
+            _ => bail!("{url} is not an http(s) URL"),
+        }
+
+        let port = parsed
+            .port_or_known_default()
+            .ok_or_else(|| anyhow!("{url} has no port"))?;
+        let addrs: Vec<SocketAddr> = match parsed.host() {
+            Some(Host::Ipv4(ip)) => vec![SocketAddr::new(IpAddr::V4(ip), port)],
+            Some(Host::Ipv6(ip)) => vec![SocketAddr::new(IpAddr::V6(ip), port)],
+            Some(Host::Domain(domain)) => tokio::net::lookup_host((domain, port))
+                .await
+                .with_context(|| format!("failed to resolve {domain}"))?
+                .collect(),
+            None => bail!("{url} has no host"),
+        };
+        if addrs.is_empty() {
+            bail!("{url} does not resolve to any address");
+        }
+        if let Some(addr) = addrs.iter().find(|addr| !is_public(addr.ip())) {
+            bail!("{url} resolves to non-public address {}", addr.ip());
+        }
+        Ok(Some((parsed, addrs)))
+    }
+}
+
+/// Whether `ip` is reachable on the public internet, rather than this host,
+/// a private or link-local network, or a special-purpose range.
+pub fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_v4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_v4(ip),
+            None => is_public_v6(ip),
+        },
+    }
+}
+
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    let shared = a == 100 && (64..128).contains(&b); // 100.64.0.0/10, carrier-grade NAT
+    !(ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || ip.is_documentation()
+        || shared
+        || a == 0)
+}
+
+fn is_public_v6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+    let unique_local = first & 0xfe00 == 0xfc00; // fc00::/7
+    let link_local = first & 0xffc0 == 0xfe80; // fe80::/10
+    !(ip.is_loopback() || ip.is_unspecified() || ip.is_multicast() || unique_local || link_local)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn guarded() -> OutboundClient {
+        OutboundClient::new(reqwest::Client::new(), false)
+    }
+
+    #[test]
+    fn private_and_special_addresses_are_not_public() {
+        for ip in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+        ] {
+            assert!(!is_public(ip.parse().unwrap()), "{ip} should not be public");
+        }
+        for ip in ["93.184.216.34", "1.1.1.1", "2606:4700:4700::1111"] {
+            assert!(is_public(ip.parse().unwrap()), "{ip} should be public");
+        }
+    }
+
+    #[tokio::test]
+    async fn private_destinations_are_rejected() {
+        let client = guarded();
+        for url in [
+            "http://127.0.0.1:8080/hook",
+            "http://169.254.169.254/latest/meta-data",
+            "https://[::1]/inbox",
+            "http://localhost/hook",
+        ] {
+            assert!(client.check(url, false).await.is_err(), "{url} accepted");
+        }
+        assert!(
+            client
+                .check("https://93.184.216.34/hook", false)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn https_can_be_required() {
+        let client = guarded();
+        assert!(client.check("http://93.184.216.34/", true).await.is_err());
+        assert!(client.check("ftp://93.184.216.34/", false).await.is_err());
+        assert!(client.check("https://93.184.216.34/", true).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn private_networks_can_be_allowed() {
+        let client = OutboundClient::new(reqwest::Client::new(), true);
+        assert!(client.check("http://127.0.0.1:8080/", true).await.is_ok());
+        assert!(client.check("not a url", false).await.is_err());
+    }
+}
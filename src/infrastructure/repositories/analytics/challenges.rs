@@ -0,0 +1,445 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{QueryBuilder, query, query_as};
+
+use crate::domain::RepositoryError;
+use crate::domain::challenges::{
+    Challenge, ChallengeEntry, ChallengePrompt, ChallengeWithPrompts, NewChallenge,
+    NewChallengePrompt, UpdateChallenge, UpdateChallengePrompt,
+};
+use crate::domain::ids::{ChallengeId, ChallengePromptId, ReadingId, UserId};
+use crate::domain::repositories::ChallengeRepository;
+use crate::infrastructure::database::DatabasePool;
+use crate::infrastructure::repositories::macros::push_update_field;
+
+const CHALLENGE_COLUMNS: &str = "id, user_id, name, description, year, shared, created_at";
+const PROMPT_COLUMNS: &str = "id, challenge_id, position, label, rule";
+const ENTRY_COLUMNS: &str = "challenge_id, prompt_id, user_id, reading_id, assigned_at";
+
+#[derive(Clone)]
+pub struct SqlChallengeRepository {
+    pool: DatabasePool,
+}
+
+impl SqlChallengeRepository {
+    pub fn new(pool: DatabasePool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_challenge(&self, id: ChallengeId) -> Result<Challenge, RepositoryError> {
+        let record = query_as::<_, ChallengeRecord>(sqlx::AssertSqlSafe(format!(
+            "SELECT {CHALLENGE_COLUMNS} FROM challenges WHERE id = ?"
+        )))
+        .bind(id.into_inner())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| RepositoryError::unexpected(err.to_string()))?
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(record.into())
+    }
+
+    async fn get_prompt(
+        &self,
+        prompt_id: ChallengePromptId,
+    ) -> Result<ChallengePrompt, RepositoryError> {
+        let record = query_as::<_, PromptRecord>(sqlx::AssertSqlSafe(format!(
+            "SELECT {PROMPT_COLUMNS} FROM challenge_prompts WHERE id = ?"
+        )))
+        .bind(prompt_id.into_inner())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| RepositoryError::unexpected(err.to_string()))?
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(record.into())
+    }
+}
+
+#[async_trait]
+impl ChallengeRepository for SqlChallengeRepository {
+    async fn insert(
+        &self,
+        challenge: NewChallenge,
+    ) -> Result<ChallengeWithPrompts, RepositoryError> {
+        let challenge = challenge.normalize();
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        let record = query_as::<_, ChallengeRecord>(sqlx::AssertSqlSafe(format!(
+            "INSERT INTO challenges (user_id, name, description, year, shared) \
+             VALUES (?, ?, ?, ?, ?) RETURNING {CHALLENGE_COLUMNS}"
+        )))
+        .bind(challenge.user_id.into_inner())
+        .bind(&challenge.name)
+        .bind(challenge.description.as_deref())
+        .bind(challenge.year)
+        .bind(challenge.shared)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        let mut prompts = Vec::with_capacity(challenge.prompts.len());
+        for (position, prompt) in (0_i64..).zip(challenge.prompts) {
+            let prompt_record = query_as::<_, PromptRecord>(sqlx::AssertSqlSafe(format!(
+                "INSERT INTO challenge_prompts (challenge_id, position, label, rule) \
+                 VALUES (?, ?, ?, ?) RETURNING {PROMPT_COLUMNS}"
+            )))
+            .bind(record.id)
+            .bind(position)
+            .bind(&prompt.label)
+            .bind(prompt.rule.as_deref())
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+            prompts.push(prompt_record.into());
+        }
+
+        tx.commit()
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        Ok(ChallengeWithPrompts {
+            challenge: record.into(),
+            prompts,
+        })
+    }
+
+    async fn get(&self, id: ChallengeId) -> Result<ChallengeWithPrompts, RepositoryError> {
+        let challenge = self.get_challenge(id).await?;
+        let prompts = query_as::<_, PromptRecord>(sqlx::AssertSqlSafe(format!(
+            "SELECT {PROMPT_COLUMNS} FROM challenge_prompts WHERE challenge_id = ? \
+             ORDER BY position ASC, id ASC"
+        )))
+        .bind(id.into_inner())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        Ok(ChallengeWithPrompts {
+            challenge,
+            prompts: prompts.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    async fn list_visible(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<ChallengeWithPrompts>, RepositoryError> {
+        let records = query_as::<_, ChallengeRecord>(sqlx::AssertSqlSafe(format!(
+            "SELECT {CHALLENGE_COLUMNS} FROM challenges WHERE shared = 1 OR user_id = ? \
+             ORDER BY year IS NULL, year DESC, LOWER(name) ASC"
+        )))
+        .bind(user_id.into_inner())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        let prompt_records = query_as::<_, PromptRecord>(
+            r"SELECT p.id, p.challenge_id, p.position, p.label, p.rule
+              FROM challenge_prompts p
+              JOIN challenges c ON c.id = p.challenge_id
+              WHERE c.shared = 1 OR c.user_id = ?
+              ORDER BY p.position ASC, p.id ASC",
+        )
+        .bind(user_id.into_inner())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        let mut prompts: HashMap<i64, Vec<ChallengePrompt>> = HashMap::new();
+        for record in prompt_records {
+            prompts
+                .entry(record.challenge_id)
+                .or_default()
+                .push(record.into());
+        }
+
+        Ok(records
+            .into_iter()
+            .map(|record| ChallengeWithPrompts {
+                prompts: prompts.remove(&record.id).unwrap_or_default(),
+                challenge: record.into(),
+            })
+            .collect())
+    }
+
+    async fn update(
+        &self,
+        id: ChallengeId,
+        changes: UpdateChallenge,
+    ) -> Result<Challenge, RepositoryError> {
+        let changes = changes.normalize();
+        let mut builder = QueryBuilder::new("UPDATE challenges SET ");
+        let mut sep = false;
+
+        push_update_field!(builder, sep, "name", changes.name);
+        push_update_field!(
+            builder,
+            sep,
+            "description",
+            changes.description.map(|d| (!d.is_empty()).then_some(d))
+        );
+        push_update_field!(builder, sep, "year", changes.year);
+        push_update_field!(builder, sep, "shared", changes.shared);
+
+        if !sep {
+            return Err(RepositoryError::unexpected(
+                "No fields provided for update".to_string(),
+            ));
+        }
+
+        builder.push(" WHERE id = ");
+        builder.push_bind(id.into_inner());
+
+        let result = builder
+            .build()
+            .execute(&self.pool)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        self.get_challenge(id).await
+    }
+
+    async fn delete(&self, id: ChallengeId) -> Result<(), RepositoryError> {
+        let result = query("DELETE FROM challenges WHERE id = ?")
+            .bind(id.into_inner())
+            .execute(&self.pool)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn add_prompt(
+        &self,
+        id: ChallengeId,
+        prompt: NewChallengePrompt,
+    ) -> Result<ChallengePrompt, RepositoryError> {
+        let prompt = prompt.normalize();
+        let record = query_as::<_, PromptRecord>(sqlx::AssertSqlSafe(format!(
+            "INSERT INTO challenge_prompts (challenge_id, position, label, rule) \
+             VALUES (?, (SELECT COALESCE(MAX(position) + 1, 0) FROM challenge_prompts WHERE challenge_id = ?), ?, ?) \
+             RETURNING {PROMPT_COLUMNS}"
+        )))
+        .bind(id.into_inner())
+        .bind(id.into_inner())
+        .bind(&prompt.label)
+        .bind(prompt.rule.as_deref())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|err| {
+            if let sqlx::Error::Database(db_err) = &err
+                && db_err.is_foreign_key_violation()
+            {
+                return RepositoryError::NotFound;
+            }
+            RepositoryError::unexpected(err.to_string())
+        })?;
+
+        Ok(record.into())
+    }
+
+    async fn update_prompt(
+        &self,
+        prompt_id: ChallengePromptId,
+        changes: UpdateChallengePrompt,
+    ) -> Result<ChallengePrompt, RepositoryError> {
+        let changes = changes.normalize();
+        let mut builder = QueryBuilder::new("UPDATE challenge_prompts SET ");
+        let mut sep = false;
+
+        push_update_field!(builder, sep, "label", changes.label);
+        push_update_field!(
+            builder,
+            sep,
+            "rule",
+            changes.rule.map(|r| (!r.is_empty()).then_some(r))
+        );
+
+        if !sep {
+            return Err(RepositoryError::unexpected(
+                "No fields provided for update".to_string(),
+            ));
+        }
+
+        builder.push(" WHERE id = ");
+        builder.push_bind(prompt_id.into_inner());
+
+        let result = builder
+            .build()
+            .execute(&self.pool)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        self.get_prompt(prompt_id).await
+    }
+
+    async fn delete_prompt(&self, prompt_id: ChallengePromptId) -> Result<(), RepositoryError> {
+        let result = query("DELETE FROM challenge_prompts WHERE id = ?")
+            .bind(prompt_id.into_inner())
+            .execute(&self.pool)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn entries_for_user(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<ChallengeEntry>, RepositoryError> {
+        let records = query_as::<_, EntryRecord>(sqlx::AssertSqlSafe(format!(
+            "SELECT {ENTRY_COLUMNS} FROM challenge_entries WHERE user_id = ?"
+        )))
+        .bind(user_id.into_inner())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        Ok(records.into_iter().map(Into::into).collect())
+    }
+
+    async fn assign(
+        &self,
+        prompt: &ChallengePrompt,
+        user_id: UserId,
+        reading_id: ReadingId,
+    ) -> Result<ChallengeEntry, RepositoryError> {
+        let record = query_as::<_, EntryRecord>(sqlx::AssertSqlSafe(format!(
+            "INSERT INTO challenge_entries (challenge_id, prompt_id, user_id, reading_id) \
+             VALUES (?, ?, ?, ?) \
+             ON CONFLICT (prompt_id, user_id) DO UPDATE SET \
+                reading_id = excluded.reading_id, assigned_at = excluded.assigned_at \
+             RETURNING {ENTRY_COLUMNS}"
+        )))
+        .bind(prompt.challenge_id.into_inner())
+        .bind(prompt.id.into_inner())
+        .bind(user_id.into_inner())
+        .bind(reading_id.into_inner())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|err| {
+            if let sqlx::Error::Database(db_err) = &err {
+                if db_err.is_unique_violation() {
+                    return RepositoryError::conflict(
+                        "This reading already fills another prompt in the challenge",
+                    );
+                }
+                if db_err.is_foreign_key_violation() {
+                    return RepositoryError::NotFound;
+                }
+            }
+            RepositoryError::unexpected(err.to_string())
+        })?;
+
+        Ok(record.into())
+    }
+
+    async fn unassign(
+        &self,
+        prompt_id: ChallengePromptId,
+        user_id: UserId,
+    ) -> Result<(), RepositoryError> {
+        let result = query("DELETE FROM challenge_entries WHERE prompt_id = ? AND user_id = ?")
+            .bind(prompt_id.into_inner())
+            .bind(user_id.into_inner())
+            .execute(&self.pool)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct ChallengeRecord {
+    id: i64,
+    user_id: i64,
+    name: String,
+    description: Option<String>,
+    year: Option<i32>,
+    shared: bool,
+    created_at: DateTime<Utc>,
+}
+
+impl From<ChallengeRecord> for Challenge {
+    fn from(record: ChallengeRecord) -> Self {
+        Self {
+            id: ChallengeId::new(record.id),
+            user_id: UserId::new(record.user_id),
+            name: record.name,
+            description: record.description,
+            year: record.year,
+            shared: record.shared,
+            created_at: record.created_at,
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct PromptRecord {
+    id: i64,
+    challenge_id: i64,
+    position: i64,
+    label: String,
+    rule: Option<String>,
+}
+
+impl From<PromptRecord> for ChallengePrompt {
+    fn from(record: PromptRecord) -> Self {
+        Self {
+            id: ChallengePromptId::new(record.id),
+            challenge_id: ChallengeId::new(record.challenge_id),
+            position: record.position,
+            label: record.label,
+            rule: record.rule,
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct EntryRecord {
+    challenge_id: i64,
+    prompt_id: i64,
+    user_id: i64,
+    reading_id: i64,
+    assigned_at: DateTime<Utc>,
+}
+
+impl From<EntryRecord> for ChallengeEntry {
+    fn from(record: EntryRecord) -> Self {
+        Self {
+            challenge_id: ChallengeId::new(record.challenge_id),
+            prompt_id: ChallengePromptId::new(record.prompt_id),
+            user_id: UserId::new(record.user_id),
+            reading_id: ReadingId::new(record.reading_id),
+            assigned_at: record.assigned_at,
+        }
+    }
+}
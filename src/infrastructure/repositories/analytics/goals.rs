@@ -0,0 +1,224 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{QueryBuilder, query, query_as};
+
+use crate::domain::RepositoryError;
+use crate::domain::goals::{GoalMetric, NewReadingGoal, ReadingGoal, UpdateReadingGoal};
+use crate::domain::ids::{GenreId, GoalId, UserId};
+use crate::domain::readings::ReadingFormat;
+use crate::domain::repositories::GoalRepository;
+use crate::infrastructure::database::DatabasePool;
+use crate::infrastructure::repositories::macros::push_update_field;
+
+const GOAL_COLUMNS: &str =
+    "id, user_id, metric, target, start_date, end_date, genre_id, format, created_at";
+
+#[derive(Clone)]
+pub struct SqlGoalRepository {
+    pool: DatabasePool,
+}
+
+impl SqlGoalRepository {
+    pub fn new(pool: DatabasePool) -> Self {
+        Self { pool }
+    }
+
+    fn to_domain(record: GoalRecord) -> Result<ReadingGoal, RepositoryError> {
+        let metric = GoalMetric::from_str(&record.metric).map_err(|()| {
+            RepositoryError::unexpected(format!("invalid goal metric: {}", record.metric))
+        })?;
+        let format = record
+            .format
+            .as_deref()
+            .map(|f| {
+                ReadingFormat::from_str(f).map_err(|()| {
+                    RepositoryError::unexpected(format!("invalid reading format: {f}"))
+                })
+            })
+            .transpose()?;
+
+        Ok(ReadingGoal {
+            id: GoalId::new(record.id),
+            user_id: UserId::new(record.user_id),
+            metric,
+            target: record.target,
+            start_date: record.start_date,
+            end_date: record.end_date,
+            genre_id: record.genre_id.map(GenreId::new),
+            format,
+            created_at: record.created_at,
+        })
+    }
+
+    fn write_error(err: sqlx::Error) -> RepositoryError {
+        if let sqlx::Error::Database(db_err) = &err
+            && db_err.is_foreign_key_violation()
+        {
+            return RepositoryError::conflict("The goal's genre does not exist");
+        }
+        RepositoryError::unexpected(err.to_string())
+    }
+}
+
+#[async_trait]
+impl GoalRepository for SqlGoalRepository {
+    async fn insert(&self, goal: NewReadingGoal) -> Result<ReadingGoal, RepositoryError> {
+        let record = query_as::<_, GoalRecord>(sqlx::AssertSqlSafe(format!(
+            "INSERT INTO reading_goals (user_id, metric, target, start_date, end_date, genre_id, format) \
+             VALUES (?, ?, ?, ?, ?, ?, ?) RETURNING {GOAL_COLUMNS}"
+        )))
+        .bind(goal.user_id.into_inner())
+        .bind(goal.metric.as_str())
+        .bind(goal.target)
+        .bind(goal.start_date)
+        .bind(goal.end_date)
+        .bind(goal.genre_id.map(GenreId::into_inner))
+        .bind(goal.format.map(|f| f.as_str()))
+        .fetch_one(&self.pool)
+        .await
+        .map_err(Self::write_error)?;
+
+        Self::to_domain(record)
+    }
+
+    async fn get(&self, id: GoalId) -> Result<ReadingGoal, RepositoryError> {
+        let record = query_as::<_, GoalRecord>(sqlx::AssertSqlSafe(format!(
+            "SELECT {GOAL_COLUMNS} FROM reading_goals WHERE id = ?"
+        )))
+        .bind(id.into_inner())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| RepositoryError::unexpected(err.to_string()))?
+        .ok_or(RepositoryError::NotFound)?;
+
+        Self::to_domain(record)
+    }
+
+    async fn list_by_user(&self, user_id: UserId) -> Result<Vec<ReadingGoal>, RepositoryError> {
+        let records = query_as::<_, GoalRecord>(sqlx::AssertSqlSafe(format!(
+            "SELECT {GOAL_COLUMNS} FROM reading_goals WHERE user_id = ? \
+             ORDER BY start_date DESC, end_date ASC, id ASC"
+        )))
+        .bind(user_id.into_inner())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        records.into_iter().map(Self::to_domain).collect()
+    }
+
+    async fn update(
+        &self,
+        id: GoalId,
+        changes: UpdateReadingGoal,
+    ) -> Result<ReadingGoal, RepositoryError> {
+        let mut builder = QueryBuilder::new("UPDATE reading_goals SET ");
+        let mut sep = false;
+
+        push_update_field!(builder, sep, "metric", changes.metric.map(|m| m.as_str()));
+        push_update_field!(builder, sep, "target", changes.target);
+        push_update_field!(builder, sep, "start_date", changes.start_date);
+        push_update_field!(builder, sep, "end_date", changes.end_date);
+        push_update_field!(
+            builder,
+            sep,
+            "genre_id",
+            changes.genre_id.map(|g| g.map(GenreId::into_inner))
+        );
+        push_update_field!(
+            builder,
+            sep,
+            "format",
+            changes.format.map(|f| f.map(|f| f.as_str()))
+        );
+
+        if !sep {
+            return Err(RepositoryError::unexpected(
+                "No fields provided for update".to_string(),
+            ));
+        }
+
+        builder.push(" WHERE id = ");
+        builder.push_bind(id.into_inner());
+
+        let result = builder
+            .build()
+            .execute(&self.pool)
+            .await
+            .map_err(Self::write_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        self.get(id).await
+    }
+
+    async fn delete(&self, id: GoalId) -> Result<(), RepositoryError> {
+        let result = query("DELETE FROM reading_goals WHERE id = ?")
+            .bind(id.into_inner())
+            .execute(&self.pool)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn progress(&self, goal: &ReadingGoal) -> Result<i64, RepositoryError> {
+        let mut qb = QueryBuilder::new("");
+        if let Some(genre_id) = goal.genre_id {
+            qb.push("WITH RECURSIVE genre_tree(id) AS (SELECT ");
+            qb.push_bind(genre_id.into_inner());
+            qb.push(" UNION SELECT g.id FROM genres g JOIN genre_tree t ON g.parent_id = t.id) ");
+        }
+
+        qb.push(match goal.metric {
+            GoalMetric::Books => "SELECT COUNT(*)",
+            GoalMetric::Pages => "SELECT COALESCE(SUM(b.page_count), 0)",
+        });
+        qb.push(
+            " FROM readings r JOIN books b ON b.id = r.book_id \
+             WHERE r.status = 'read' AND r.user_id = ",
+        );
+        qb.push_bind(goal.user_id.into_inner());
+        qb.push(" AND r.finished_at >= ");
+        qb.push_bind(goal.start_date);
+        qb.push(" AND r.finished_at <= ");
+        qb.push_bind(goal.end_date);
+
+        if let Some(format) = goal.format {
+            qb.push(" AND r.format = ");
+            qb.push_bind(format.as_str());
+        }
+        if goal.genre_id.is_some() {
+            qb.push(
+                " AND (b.primary_genre_id IN (SELECT id FROM genre_tree) \
+                 OR b.secondary_genre_id IN (SELECT id FROM genre_tree))",
+            );
+        }
+
+        qb.build_query_scalar::<i64>()
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct GoalRecord {
+    id: i64,
+    user_id: i64,
+    metric: String,
+    target: i64,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    genre_id: Option<i64>,
+    format: Option<String>,
+    created_at: DateTime<Utc>,
+}
@@ -1,3 +1,5 @@
 pub mod ai_usage;
+pub mod challenges;
+pub mod goals;
 pub mod stats;
 pub mod timeline_events;
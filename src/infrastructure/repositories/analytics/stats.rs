@@ -1,11 +1,29 @@
 use async_trait::async_trait;
 use sqlx::{AssertSqlSafe, QueryBuilder, Row, query_as, query_scalar};
 
+use std::collections::HashSet;
+
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc};
+
 use crate::domain::RepositoryError;
-use crate::domain::ids::UserId;
+use crate::domain::activity::ReadingSpan;
+use crate::domain::genres::{Genre, GenreTree};
+use crate::domain::household::{
+    HouseholdBook, HouseholdStats, MonthReader, TOP_ENTRIES, YearLeaderboard,
+};
+use crate::domain::ids::{BookId, GenreId, UserId};
+use crate::domain::listening::ListeningStats;
+use crate::domain::readings::ReadingStatus;
 use crate::domain::repositories::StatsRepository;
-use crate::domain::stats::{BookSummaryStats, CachedStats, ReadingStats};
+use crate::domain::sentiment::{
+    QuickReviewStats, ReviewedReading, SentimentCounts, group_sentiment,
+};
+use crate::domain::stats::{
+    BookSummaryStats, CachedStats, GenreBreakdown, ReadingStats, StatsPeriod,
+};
+use crate::domain::stats_cache::{ReadingCounters, YearStatsPartial};
 use crate::infrastructure::database::{DatabaseDriver, DatabasePool};
+use crate::infrastructure::repositories::books::readings::SqlReadingRepository;
 
 // --- Internal record types ---
 
@@ -16,6 +34,20 @@ struct NameCount {
     count: i64,
 }
 
+#[derive(sqlx::FromRow)]
+struct BookGenre {
+    book_id: i64,
+    genre_id: i64,
+}
+
+#[derive(sqlx::FromRow)]
+struct GenreRecord {
+    id: i64,
+    name: String,
+    parent_id: Option<i64>,
+    created_at: DateTime<Utc>,
+}
+
 #[derive(sqlx::FromRow)]
 #[allow(dead_code)]
 struct RatingCount {
@@ -23,6 +55,22 @@ struct RatingCount {
     count: i64,
 }
 
+/// Books, pages and listening minutes for each month of a period.
+#[derive(Default)]
+struct MonthlySeries {
+    books: Vec<(String, u64)>,
+    pages: Vec<(String, i64)>,
+    minutes: Vec<(String, i64)>,
+}
+
+#[derive(sqlx::FromRow)]
+struct MonthActivity {
+    month: String,
+    count: i64,
+    pages: i64,
+    minutes: i64,
+}
+
 #[derive(sqlx::FromRow)]
 #[allow(dead_code)]
 struct MonthPages {
@@ -30,6 +78,50 @@ struct MonthPages {
     pages: i64,
 }
 
+#[derive(sqlx::FromRow)]
+struct SpanRecord {
+    status: String,
+    started_at: Option<NaiveDate>,
+    finished_at: Option<NaiveDate>,
+}
+
+#[derive(sqlx::FromRow)]
+struct ReviewRecord {
+    rating: Option<f64>,
+    page_count: Option<i32>,
+    started_at: Option<NaiveDate>,
+    finished_at: Option<NaiveDate>,
+    review: Option<String>,
+}
+
+#[derive(sqlx::FromRow)]
+struct GroupReview {
+    id: i64,
+    name: String,
+    review: Option<String>,
+}
+
+#[derive(sqlx::FromRow)]
+struct HouseholdBookRecord {
+    id: i64,
+    title: String,
+    readers: i64,
+}
+
+#[derive(sqlx::FromRow)]
+struct MonthFinishRecord {
+    username: String,
+    title: String,
+}
+
+#[derive(sqlx::FromRow)]
+struct LeaderboardRecord {
+    year: i32,
+    username: String,
+    books: i64,
+    pages: i64,
+}
+
 #[derive(sqlx::FromRow)]
 #[allow(dead_code)]
 struct TitlePages {
@@ -50,17 +142,107 @@ fn name_counts(records: Vec<NameCount>) -> Vec<(String, u64)> {
         .collect()
 }
 
-fn push_year_filter(qb: &mut QueryBuilder<DatabaseDriver>, year: Option<i32>, column: &str) {
-    if let Some(y) = year {
-        qb.push(format!(" AND CAST(strftime('%Y', {column}) AS INTEGER) = "));
-        qb.push_bind(y);
+/// Which readings a reading stat query covers, by the date column it filters on.
+#[derive(Clone, Copy)]
+enum Scope<'a> {
+    AllTime,
+    Period(&'a StatsPeriod),
+    /// Readings with no date in the filtered column.
+    Undated,
+}
+
+impl<'a> From<Option<&'a StatsPeriod>> for Scope<'a> {
+    fn from(period: Option<&'a StatsPeriod>) -> Self {
+        period.map_or(Self::AllTime, Self::Period)
     }
 }
 
+fn push_scope_filter(qb: &mut QueryBuilder<DatabaseDriver>, scope: Scope<'_>, column: &str) {
+    match scope {
+        Scope::AllTime => {}
+        Scope::Period(p) => {
+            qb.push(format!(" AND {column} BETWEEN "));
+            qb.push_bind(p.from);
+            qb.push(" AND ");
+            qb.push_bind(p.to);
+        }
+        Scope::Undated => {
+            qb.push(format!(" AND {column} IS NULL"));
+        }
+    }
+}
+
+/// Year 0 in `stats_year_partials` stands for undated readings.
+fn partial_year_key(year: Option<i32>) -> i32 {
+    year.unwrap_or(0)
+}
+
+/// Month labels for a period's bar chart: just the month within a single
+/// year, otherwise with the year too.
+fn month_label(month: NaiveDate, period: StatsPeriod) -> String {
+    if period.from.year() == period.to.year() {
+        month.format("%b").to_string()
+    } else {
+        month.format("%b %Y").to_string()
+    }
+}
+
+/// Rolls book/genre links up the genre hierarchy into top-level counts plus
+/// per-genre subgenre breakdowns. Returns the number of distinct genres used.
+fn genre_summary(
+    tree: &GenreTree,
+    links: Vec<BookGenre>,
+) -> (Vec<(String, u64)>, Vec<GenreBreakdown>, u64) {
+    let unique_genres = links
+        .iter()
+        .map(|link| link.genre_id)
+        .collect::<HashSet<_>>()
+        .len() as u64;
+    let counts = tree.rolled_up_counts(
+        links
+            .into_iter()
+            .map(|link| (BookId::from(link.book_id), GenreId::from(link.genre_id))),
+    );
+
+    let ranked = |genres: Vec<&Genre>| -> Vec<(GenreId, String, u64)> {
+        let mut ranked: Vec<_> = genres
+            .into_iter()
+            .filter_map(|g| counts.get(&g.id).map(|&c| (g.id, g.name.clone(), c)))
+            .collect();
+        ranked.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| a.1.cmp(&b.1)));
+        ranked
+    };
+    let without_ids = |ranked: &[(GenreId, String, u64)]| {
+        ranked.iter().map(|(_, n, c)| (n.clone(), *c)).collect()
+    };
+
+    let roots = ranked(tree.roots());
+    let genre_counts: Vec<(String, u64)> = without_ids(&roots);
+
+    let mut breakdowns = Vec::new();
+    let mut stack: Vec<GenreId> = roots.iter().rev().map(|(id, _, _)| *id).collect();
+    while let Some(id) = stack.pop() {
+        let children = ranked(tree.children(id));
+        if children.is_empty() {
+            continue;
+        }
+        let counts: Vec<(String, u64)> = without_ids(&children);
+        breakdowns.push(GenreBreakdown {
+            genre: tree.path_name(id).unwrap_or_default(),
+            max_count: counts.iter().map(|(_, c)| *c).max().unwrap_or(0),
+            counts,
+        });
+        stack.extend(children.iter().rev().map(|(id, _, _)| *id));
+    }
+
+    (genre_counts, breakdowns, unique_genres)
+}
+
 struct RawBookStats {
     total_books: i64,
     total_authors: i64,
-    genre_records: Vec<NameCount>,
+    genre_tree: GenreTree,
+    genre_links: Vec<BookGenre>,
     top_author: Option<String>,
     most_rated_author: Option<String>,
     most_rated_genre: Option<String>,
@@ -69,6 +251,8 @@ struct RawBookStats {
     top_authors_records: Vec<NameCount>,
     longest: Option<TitlePages>,
     shortest: Option<TitlePages>,
+    genre_sentiment: Vec<(String, SentimentCounts)>,
+    author_sentiment: Vec<(String, SentimentCounts)>,
 }
 
 /// Processes raw book query results into the final stats struct.
@@ -76,7 +260,8 @@ fn build_book_summary(raw: RawBookStats) -> BookSummaryStats {
     let RawBookStats {
         total_books,
         total_authors,
-        genre_records,
+        genre_tree,
+        genre_links,
         top_author,
         most_rated_author,
         most_rated_genre,
@@ -85,11 +270,11 @@ fn build_book_summary(raw: RawBookStats) -> BookSummaryStats {
         top_authors_records,
         longest,
         shortest,
+        genre_sentiment,
+        author_sentiment,
     } = raw;
-    let genre_counts = name_counts(genre_records);
-    let unique_genres = genre_counts.len() as u64;
+    let (genre_counts, subgenre_counts, unique_genres) = genre_summary(&genre_tree, genre_links);
     let top_genre = genre_counts.first().map(|(name, _)| name.clone());
-    let genre_counts: Vec<(String, u64)> = genre_counts.into_iter().collect();
     let max_genre_count = genre_counts.iter().map(|(_, c)| *c).max().unwrap_or(0);
 
     let page_count_distribution = name_counts(page_distribution);
@@ -113,6 +298,7 @@ fn build_book_summary(raw: RawBookStats) -> BookSummaryStats {
         most_rated_genre,
         genre_counts,
         max_genre_count,
+        subgenre_counts,
         page_count_distribution,
         year_published_distribution,
         max_year_published_count,
@@ -120,6 +306,8 @@ fn build_book_summary(raw: RawBookStats) -> BookSummaryStats {
         max_top_author_count,
         longest_book: longest.map(|r| (r.title, r.page_count as i32)),
         shortest_book: shortest.map(|r| (r.title, r.page_count as i32)),
+        genre_sentiment,
+        author_sentiment,
     }
 }
 
@@ -137,6 +325,65 @@ impl SqlStatsRepository {
 
     // --- Book stat helpers ---
 
+    /// Most-read books and authors across users who haven't opted out of
+    /// the household stats. Private readings are never counted.
+    async fn fetch_household_top(
+        &self,
+    ) -> Result<(Vec<HouseholdBookRecord>, Vec<NameCount>), RepositoryError> {
+        let limit = i64::try_from(TOP_ENTRIES).unwrap_or(i64::MAX);
+        let top_books: Vec<HouseholdBookRecord> = query_as(
+            r"SELECT b.id, b.title, COUNT(DISTINCT r.user_id) AS readers
+               FROM readings r
+               JOIN users u ON u.id = r.user_id AND u.household_opt_out = 0
+               JOIN books b ON b.id = r.book_id
+               WHERE r.status = 'read' AND r.private = 0
+               GROUP BY b.id
+               ORDER BY readers DESC, b.title
+               LIMIT ?",
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        let top_authors: Vec<NameCount> = query_as(
+            r"SELECT a.name, COUNT(*) AS count
+               FROM readings r
+               JOIN users u ON u.id = r.user_id AND u.household_opt_out = 0
+               JOIN book_authors ba ON r.book_id = ba.book_id AND ba.role = 'author'
+               JOIN authors a ON a.id = ba.author_id
+               WHERE r.status = 'read' AND r.private = 0
+               GROUP BY a.id
+               ORDER BY count DESC, a.name
+               LIMIT ?",
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        Ok((top_books, top_authors))
+    }
+
+    async fn fetch_genre_tree(&self) -> Result<GenreTree, RepositoryError> {
+        let records: Vec<GenreRecord> =
+            query_as("SELECT id, name, parent_id, created_at FROM genres")
+                .fetch_all(&self.pool)
+                .await
+                .map_err(db_err)?;
+        Ok(GenreTree::new(
+            records
+                .into_iter()
+                .map(|r| Genre {
+                    id: GenreId::from(r.id),
+                    name: r.name,
+                    parent_id: r.parent_id.map(GenreId::from),
+                    created_at: r.created_at,
+                })
+                .collect(),
+        ))
+    }
+
     async fn fetch_library_extremes(
         &self,
         uid: i64,
@@ -170,32 +417,34 @@ impl SqlStatsRepository {
         Ok((longest, shortest))
     }
 
-    async fn fetch_year_extremes(
+    async fn fetch_period_extremes(
         &self,
         uid: i64,
-        year: i32,
+        period: &StatsPeriod,
         cte: &str,
     ) -> Result<(Option<TitlePages>, Option<TitlePages>), RepositoryError> {
         let longest = query_as::<_, TitlePages>(AssertSqlSafe(format!(
             "{cte} SELECT b.title, b.page_count \
-             FROM year_books yb JOIN books b ON b.id = yb.book_id \
+             FROM period_books yb JOIN books b ON b.id = yb.book_id \
              WHERE b.page_count IS NOT NULL \
              ORDER BY b.page_count DESC LIMIT 1"
         )))
         .bind(uid)
-        .bind(year)
+        .bind(period.from)
+        .bind(period.to)
         .fetch_optional(&self.pool)
         .await
         .map_err(db_err)?;
 
         let shortest = query_as::<_, TitlePages>(AssertSqlSafe(format!(
             "{cte} SELECT b.title, b.page_count \
-             FROM year_books yb JOIN books b ON b.id = yb.book_id \
+             FROM period_books yb JOIN books b ON b.id = yb.book_id \
              WHERE b.page_count IS NOT NULL \
              ORDER BY b.page_count ASC LIMIT 1"
         )))
         .bind(uid)
-        .bind(year)
+        .bind(period.from)
+        .bind(period.to)
         .fetch_optional(&self.pool)
         .await
         .map_err(db_err)?;
@@ -247,10 +496,10 @@ impl SqlStatsRepository {
         Ok((page_distribution, year_distribution))
     }
 
-    async fn fetch_year_distributions(
+    async fn fetch_period_distributions(
         &self,
         uid: i64,
-        year: i32,
+        period: &StatsPeriod,
         cte: &str,
     ) -> Result<(Vec<NameCount>, Vec<NameCount>), RepositoryError> {
         let page_distribution: Vec<NameCount> = query_as(AssertSqlSafe(format!(
@@ -262,25 +511,27 @@ impl SqlStatsRepository {
                  ELSE '500+' \
                END AS name, \
                COUNT(*) AS count \
-             FROM year_books yb \
+             FROM period_books yb \
              JOIN books b ON b.id = yb.book_id \
              WHERE b.page_count IS NOT NULL \
              GROUP BY name ORDER BY MIN(b.page_count)"
         )))
         .bind(uid)
-        .bind(year)
+        .bind(period.from)
+        .bind(period.to)
         .fetch_all(&self.pool)
         .await
         .map_err(db_err)?;
 
         let year_distribution: Vec<NameCount> = query_as(AssertSqlSafe(format!(
             "{cte} SELECT (b.year_published / 10 * 10) || 's' AS name, COUNT(*) AS count \
-             FROM year_books yb JOIN books b ON b.id = yb.book_id \
+             FROM period_books yb JOIN books b ON b.id = yb.book_id \
              WHERE b.year_published IS NOT NULL \
              GROUP BY b.year_published / 10 ORDER BY count DESC"
         )))
         .bind(uid)
-        .bind(year)
+        .bind(period.from)
+        .bind(period.to)
         .fetch_all(&self.pool)
         .await
         .map_err(db_err)?;
@@ -293,7 +544,7 @@ impl SqlStatsRepository {
     async fn fetch_most_rated_author(
         &self,
         uid: i64,
-        year: Option<i32>,
+        scope: Scope<'_>,
     ) -> Result<Option<String>, RepositoryError> {
         let mut qb = QueryBuilder::new(
             r"SELECT a.name AS name, CAST(SUM(r.rating) AS INTEGER) AS count
@@ -304,7 +555,7 @@ impl SqlStatsRepository {
         );
         qb.push_bind(uid);
         qb.push(" AND r.status = 'read' AND r.rating IS NOT NULL");
-        push_year_filter(&mut qb, year, "r.finished_at");
+        push_scope_filter(&mut qb, scope, "r.finished_at");
         qb.push(" GROUP BY a.id ORDER BY SUM(r.rating) DESC LIMIT 1");
         let record: Option<NameCount> = qb
             .build_query_as()
@@ -317,7 +568,7 @@ impl SqlStatsRepository {
     async fn fetch_most_rated_genre(
         &self,
         uid: i64,
-        year: Option<i32>,
+        scope: Scope<'_>,
     ) -> Result<Option<String>, RepositoryError> {
         let mut qb = QueryBuilder::new(
             r"SELECT g.name AS name, CAST(SUM(r.rating) AS INTEGER) AS count
@@ -328,7 +579,7 @@ impl SqlStatsRepository {
         );
         qb.push_bind(uid);
         qb.push(" AND r.status = 'read' AND r.rating IS NOT NULL AND g.id IS NOT NULL");
-        push_year_filter(&mut qb, year, "r.finished_at");
+        push_scope_filter(&mut qb, scope, "r.finished_at");
         qb.push(" GROUP BY g.id ORDER BY SUM(r.rating) DESC LIMIT 1");
         let record: Option<NameCount> = qb
             .build_query_as()
@@ -338,13 +589,105 @@ impl SqlStatsRepository {
         Ok(record.map(|r| r.name))
     }
 
-    // --- Reading stat queries (shared between all-time and per-year) ---
+    // --- Quick-review sentiment queries ---
 
-    async fn fetch_books_read(&self, uid: i64, year: Option<i32>) -> Result<i64, RepositoryError> {
+    async fn fetch_group_sentiment(
+        &self,
+        uid: i64,
+        scope: Scope<'_>,
+        from: &str,
+    ) -> Result<Vec<(String, SentimentCounts)>, RepositoryError> {
+        let mut qb = QueryBuilder::new(format!("{from} WHERE r.user_id = "));
+        qb.push_bind(uid);
+        qb.push(" AND r.status = 'read' AND r.review IS NOT NULL");
+        push_scope_filter(&mut qb, scope, "r.finished_at");
+        let records: Vec<GroupReview> = qb
+            .build_query_as()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_err)?;
+        Ok(group_sentiment(records.into_iter().map(|r| {
+            (
+                r.id,
+                r.name,
+                SqlReadingRepository::decode_quick_reviews(r.review),
+            )
+        })))
+    }
+
+    async fn fetch_genre_sentiment(
+        &self,
+        uid: i64,
+        scope: Scope<'_>,
+    ) -> Result<Vec<(String, SentimentCounts)>, RepositoryError> {
+        self.fetch_group_sentiment(
+            uid,
+            scope,
+            r"SELECT g.id AS id, g.name AS name, r.review AS review
+               FROM readings r
+               JOIN books b ON r.book_id = b.id
+               JOIN genres g ON g.id IN (b.primary_genre_id, b.secondary_genre_id)",
+        )
+        .await
+    }
+
+    async fn fetch_author_sentiment(
+        &self,
+        uid: i64,
+        scope: Scope<'_>,
+    ) -> Result<Vec<(String, SentimentCounts)>, RepositoryError> {
+        self.fetch_group_sentiment(
+            uid,
+            scope,
+            r"SELECT a.id AS id, a.name AS name, r.review AS review
+               FROM readings r
+               JOIN book_authors ba ON r.book_id = ba.book_id AND ba.role = 'author'
+               JOIN authors a ON ba.author_id = a.id",
+        )
+        .await
+    }
+
+    async fn fetch_quick_review_stats(
+        &self,
+        uid: i64,
+        scope: Scope<'_>,
+    ) -> Result<QuickReviewStats, RepositoryError> {
+        let mut qb = QueryBuilder::new(
+            "SELECT r.rating, \
+                    CASE WHEN r.format IS NOT 'audiobook' THEN bk.page_count END AS page_count, \
+                    r.started_at, r.finished_at, r.review \
+             FROM readings r JOIN books bk ON r.book_id = bk.id WHERE r.user_id = ",
+        );
+        qb.push_bind(uid);
+        qb.push(" AND r.status = 'read' AND r.review IS NOT NULL");
+        push_scope_filter(&mut qb, scope, "r.finished_at");
+        let records: Vec<ReviewRecord> = qb
+            .build_query_as()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_err)?;
+        let readings: Vec<ReviewedReading> = records
+            .into_iter()
+            .map(|r| {
+                ReviewedReading::new(
+                    r.rating,
+                    r.page_count,
+                    r.started_at,
+                    r.finished_at,
+                    SqlReadingRepository::decode_quick_reviews(r.review),
+                )
+            })
+            .collect();
+        Ok(QuickReviewStats::from_readings(&readings))
+    }
+
+    // --- Reading stat queries (shared between all-time and per-period) ---
+
+    async fn fetch_books_read(&self, uid: i64, scope: Scope<'_>) -> Result<i64, RepositoryError> {
         let mut qb = QueryBuilder::new("SELECT COUNT(*) FROM readings WHERE user_id = ");
         qb.push_bind(uid);
         qb.push(" AND status = 'read'");
-        push_year_filter(&mut qb, year, "finished_at");
+        push_scope_filter(&mut qb, scope, "finished_at");
         let (count,): (i64,) = qb
             .build_query_as()
             .fetch_one(&self.pool)
@@ -353,14 +696,17 @@ impl SqlStatsRepository {
         Ok(count)
     }
 
-    async fn fetch_pages_read(&self, uid: i64, year: Option<i32>) -> Result<i64, RepositoryError> {
+    async fn fetch_pages_read(&self, uid: i64, scope: Scope<'_>) -> Result<i64, RepositoryError> {
         let mut qb = QueryBuilder::new(
             "SELECT COALESCE(SUM(bk.page_count), 0) FROM readings r \
              JOIN books bk ON r.book_id = bk.id WHERE r.user_id = ",
         );
         qb.push_bind(uid);
-        qb.push(" AND r.status = 'read' AND bk.page_count IS NOT NULL");
-        push_year_filter(&mut qb, year, "r.finished_at");
+        qb.push(
+            " AND r.status = 'read' AND bk.page_count IS NOT NULL \
+             AND r.format IS NOT 'audiobook'",
+        );
+        push_scope_filter(&mut qb, scope, "r.finished_at");
         let (pages,): (i64,) = qb
             .build_query_as()
             .fetch_one(&self.pool)
@@ -372,12 +718,12 @@ impl SqlStatsRepository {
     async fn fetch_average_rating(
         &self,
         uid: i64,
-        year: Option<i32>,
+        scope: Scope<'_>,
     ) -> Result<Option<f64>, RepositoryError> {
         let mut qb = QueryBuilder::new("SELECT AVG(rating) FROM readings WHERE user_id = ");
         qb.push_bind(uid);
         qb.push(" AND rating IS NOT NULL AND status = 'read'");
-        push_year_filter(&mut qb, year, "finished_at");
+        push_scope_filter(&mut qb, scope, "finished_at");
         let (avg,): (Option<f64>,) = qb
             .build_query_as()
             .fetch_one(&self.pool)
@@ -389,7 +735,7 @@ impl SqlStatsRepository {
     async fn fetch_average_days_to_finish(
         &self,
         uid: i64,
-        year: Option<i32>,
+        scope: Scope<'_>,
     ) -> Result<Option<f64>, RepositoryError> {
         let mut qb = QueryBuilder::new(
             "SELECT AVG(julianday(finished_at) - julianday(started_at)) \
@@ -397,7 +743,7 @@ impl SqlStatsRepository {
         );
         qb.push_bind(uid);
         qb.push(" AND status = 'read' AND started_at IS NOT NULL AND finished_at IS NOT NULL");
-        push_year_filter(&mut qb, year, "finished_at");
+        push_scope_filter(&mut qb, scope, "finished_at");
         let (avg,): (Option<f64>,) = qb
             .build_query_as()
             .fetch_one(&self.pool)
@@ -406,16 +752,37 @@ impl SqlStatsRepository {
         Ok(avg)
     }
 
+    /// Total and count of start-to-finish times, for averaging across partials.
+    async fn fetch_finish_days(
+        &self,
+        uid: i64,
+        scope: Scope<'_>,
+    ) -> Result<(f64, u64), RepositoryError> {
+        let mut qb = QueryBuilder::new(
+            "SELECT COALESCE(SUM(julianday(finished_at) - julianday(started_at)), 0.0), COUNT(*) \
+             FROM readings WHERE user_id = ",
+        );
+        qb.push_bind(uid);
+        qb.push(" AND status = 'read' AND started_at IS NOT NULL AND finished_at IS NOT NULL");
+        push_scope_filter(&mut qb, scope, "finished_at");
+        let (total, count): (f64, i64) = qb
+            .build_query_as()
+            .fetch_one(&self.pool)
+            .await
+            .map_err(db_err)?;
+        Ok((total, count as u64))
+    }
+
     async fn fetch_rating_distribution(
         &self,
         uid: i64,
-        year: Option<i32>,
+        scope: Scope<'_>,
     ) -> Result<Vec<(f64, u64)>, RepositoryError> {
         let mut qb =
             QueryBuilder::new("SELECT rating, COUNT(*) AS count FROM readings WHERE user_id = ");
         qb.push_bind(uid);
         qb.push(" AND rating IS NOT NULL AND status = 'read'");
-        push_year_filter(&mut qb, year, "finished_at");
+        push_scope_filter(&mut qb, scope, "finished_at");
         qb.push(" GROUP BY rating ORDER BY rating");
         let records: Vec<RatingCount> = qb
             .build_query_as()
@@ -428,97 +795,54 @@ impl SqlStatsRepository {
             .collect())
     }
 
-    async fn fetch_monthly_books(
+    async fn fetch_monthly_activity(
         &self,
         uid: i64,
-        year: Option<i32>,
-    ) -> Result<Vec<(String, u64)>, RepositoryError> {
-        let mut qb = QueryBuilder::new(
-            r"WITH months(m) AS (
-                 VALUES (1),(2),(3),(4),(5),(6),(7),(8),(9),(10),(11),(12)
-               )
-               SELECT
-                 CASE m
-                   WHEN 1 THEN 'Jan' WHEN 2 THEN 'Feb' WHEN 3 THEN 'Mar'
-                   WHEN 4 THEN 'Apr' WHEN 5 THEN 'May' WHEN 6 THEN 'Jun'
-                   WHEN 7 THEN 'Jul' WHEN 8 THEN 'Aug' WHEN 9 THEN 'Sep'
-                   WHEN 10 THEN 'Oct' WHEN 11 THEN 'Nov' WHEN 12 THEN 'Dec'
-                 END AS name,
-                 COUNT(r.id) AS count
-               FROM months
-               LEFT JOIN readings r
-                 ON CAST(strftime('%m', r.finished_at) AS INTEGER) = m
-                 AND ",
-        );
-        match year {
-            Some(y) => {
-                qb.push("strftime('%Y', r.finished_at) = ");
-                qb.push_bind(y.to_string());
-            }
-            None => {
-                qb.push("strftime('%Y', r.finished_at) = strftime('%Y', 'now')");
-            }
-        }
-        qb.push(" AND r.status = 'read' AND r.user_id = ");
-        qb.push_bind(uid);
-        qb.push(" GROUP BY m ORDER BY m");
-        let records: Vec<NameCount> = qb
-            .build_query_as()
-            .fetch_all(&self.pool)
-            .await
-            .map_err(db_err)?;
-        Ok(name_counts(records))
-    }
+        period: &StatsPeriod,
+    ) -> Result<MonthlySeries, RepositoryError> {
+        let records: Vec<MonthActivity> = query_as(
+            r"SELECT strftime('%Y-%m', r.finished_at) AS month,
+                     COUNT(*) AS count,
+                     COALESCE(SUM(CASE WHEN r.format IS NOT 'audiobook' THEN bk.page_count END), 0)
+                       AS pages,
+                     COALESCE(SUM(CASE WHEN r.format = 'audiobook' THEN bk.duration_minutes END), 0)
+                       AS minutes
+               FROM readings r
+               JOIN books bk ON r.book_id = bk.id
+               WHERE r.user_id = ? AND r.status = 'read'
+                 AND r.finished_at BETWEEN ? AND ?
+               GROUP BY month",
+        )
+        .bind(uid)
+        .bind(period.from)
+        .bind(period.to)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_err)?;
 
-    async fn fetch_monthly_pages(
-        &self,
-        uid: i64,
-        year: Option<i32>,
-    ) -> Result<Vec<(String, i64)>, RepositoryError> {
-        let mut qb = QueryBuilder::new(
-            r"WITH months(m) AS (
-                 VALUES (1),(2),(3),(4),(5),(6),(7),(8),(9),(10),(11),(12)
-               )
-               SELECT
-                 CASE m
-                   WHEN 1 THEN 'Jan' WHEN 2 THEN 'Feb' WHEN 3 THEN 'Mar'
-                   WHEN 4 THEN 'Apr' WHEN 5 THEN 'May' WHEN 6 THEN 'Jun'
-                   WHEN 7 THEN 'Jul' WHEN 8 THEN 'Aug' WHEN 9 THEN 'Sep'
-                   WHEN 10 THEN 'Oct' WHEN 11 THEN 'Nov' WHEN 12 THEN 'Dec'
-                 END AS name,
-                 COALESCE(SUM(bk.page_count), 0) AS pages
-               FROM months
-               LEFT JOIN readings r
-                 ON CAST(strftime('%m', r.finished_at) AS INTEGER) = m
-                 AND ",
-        );
-        match year {
-            Some(y) => {
-                qb.push("strftime('%Y', r.finished_at) = ");
-                qb.push_bind(y.to_string());
-            }
-            None => {
-                qb.push("strftime('%Y', r.finished_at) = strftime('%Y', 'now')");
-            }
+        // Every month in the period gets a bar, read or not
+        let mut series = MonthlySeries::default();
+        for month in period.months() {
+            let key = month.format("%Y-%m").to_string();
+            let record = records.iter().find(|r| r.month == key);
+            let label = month_label(month, *period);
+            series
+                .books
+                .push((label.clone(), record.map_or(0, |r| r.count as u64)));
+            series
+                .pages
+                .push((label.clone(), record.map_or(0, |r| r.pages)));
+            series
+                .minutes
+                .push((label, record.map_or(0, |r| r.minutes)));
         }
-        qb.push(" AND r.status = 'read' AND r.user_id = ");
-        qb.push_bind(uid);
-        qb.push(
-            " LEFT JOIN books bk ON r.book_id = bk.id AND bk.page_count IS NOT NULL \
-             GROUP BY m ORDER BY m",
-        );
-        let records: Vec<MonthPages> = qb
-            .build_query_as()
-            .fetch_all(&self.pool)
-            .await
-            .map_err(db_err)?;
-        Ok(records.into_iter().map(|r| (r.name, r.pages)).collect())
+        Ok(series)
     }
 
     async fn fetch_pace_distribution(
         &self,
         uid: i64,
-        year: Option<i32>,
+        scope: Scope<'_>,
     ) -> Result<Vec<(String, u64)>, RepositoryError> {
         let mut qb = QueryBuilder::new(
             r"SELECT pace AS name, COUNT(*) AS count
@@ -537,10 +861,10 @@ impl SqlStatsRepository {
         qb.push(
             " AND r.status = 'read' \
              AND r.started_at IS NOT NULL AND r.finished_at IS NOT NULL \
-             AND bk.page_count IS NOT NULL \
+             AND bk.page_count IS NOT NULL AND r.format IS NOT 'audiobook' \
              AND julianday(r.finished_at) >= julianday(r.started_at)",
         );
-        push_year_filter(&mut qb, year, "r.finished_at");
+        push_scope_filter(&mut qb, scope, "r.finished_at");
         qb.push(
             ") GROUP BY pace \
              ORDER BY CASE pace WHEN 'Slow' THEN 1 WHEN 'Medium' THEN 2 ELSE 3 END",
@@ -556,7 +880,7 @@ impl SqlStatsRepository {
     async fn fetch_format_counts(
         &self,
         uid: i64,
-        year: Option<i32>,
+        scope: Scope<'_>,
     ) -> Result<Vec<(String, u64)>, RepositoryError> {
         let mut qb = QueryBuilder::new(
             r"SELECT
@@ -571,7 +895,7 @@ impl SqlStatsRepository {
         );
         qb.push_bind(uid);
         qb.push(" AND format IS NOT NULL AND status = 'read'");
-        push_year_filter(&mut qb, year, "finished_at");
+        push_scope_filter(&mut qb, scope, "finished_at");
         qb.push(" GROUP BY format ORDER BY count DESC");
         let records: Vec<NameCount> = qb
             .build_query_as()
@@ -581,15 +905,56 @@ impl SqlStatsRepository {
         Ok(name_counts(records))
     }
 
+    /// Listening totals for finished audiobooks with a running time.
+    async fn fetch_listening_stats(
+        &self,
+        uid: i64,
+        scope: Scope<'_>,
+    ) -> Result<ListeningStats, RepositoryError> {
+        let mut qb = QueryBuilder::new(
+            r"SELECT COUNT(*),
+                     COALESCE(SUM(bk.duration_minutes), 0),
+                     COALESCE(SUM(CASE
+                       WHEN julianday(r.finished_at) >= julianday(r.started_at)
+                       THEN bk.duration_minutes
+                     END), 0),
+                     COALESCE(SUM(CASE
+                       WHEN julianday(r.finished_at) >= julianday(r.started_at)
+                       THEN MAX(1, julianday(r.finished_at) - julianday(r.started_at))
+                     END), 0.0)
+               FROM readings r
+               JOIN books bk ON r.book_id = bk.id
+               WHERE r.user_id = ",
+        );
+        qb.push_bind(uid);
+        qb.push(
+            " AND r.status = 'read' AND r.format = 'audiobook' \
+             AND bk.duration_minutes IS NOT NULL",
+        );
+        push_scope_filter(&mut qb, scope, "r.finished_at");
+        let (audiobooks, minutes, paced_minutes, paced_days): (i64, i64, i64, f64) = qb
+            .build_query_as()
+            .fetch_one(&self.pool)
+            .await
+            .map_err(db_err)?;
+        Ok(ListeningStats {
+            audiobooks: audiobooks as u64,
+            minutes,
+            paced_minutes,
+            paced_days,
+            ..ListeningStats::default()
+        })
+    }
+
     async fn fetch_books_abandoned(
         &self,
         uid: i64,
-        year: Option<i32>,
+        scope: Scope<'_>,
     ) -> Result<i64, RepositoryError> {
         let mut qb = QueryBuilder::new("SELECT COUNT(*) FROM readings WHERE user_id = ");
         qb.push_bind(uid);
         qb.push(" AND status = 'abandoned'");
-        push_year_filter(&mut qb, year, "started_at");
+        push_scope_filter(&mut qb, scope, "started_at");
         let (count,): (i64,) = qb
             .build_query_as()
             .fetch_one(&self.pool)
@@ -621,7 +986,24 @@ impl SqlStatsRepository {
                FROM readings r
                JOIN books bk ON r.book_id = bk.id
                WHERE r.user_id = ? AND r.status = 'read' AND r.finished_at IS NOT NULL
-                 AND bk.page_count IS NOT NULL
+                 AND bk.page_count IS NOT NULL AND r.format IS NOT 'audiobook'
+               GROUP BY name ORDER BY name",
+        )
+        .bind(uid)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_err)?;
+        Ok(records.into_iter().map(|r| (r.name, r.pages)).collect())
+    }
+
+    async fn fetch_yearly_minutes(&self, uid: i64) -> Result<Vec<(String, i64)>, RepositoryError> {
+        let records: Vec<MonthPages> = query_as(
+            r"SELECT strftime('%Y', r.finished_at) AS name,
+                     COALESCE(SUM(bk.duration_minutes), 0) AS pages
+               FROM readings r
+               JOIN books bk ON r.book_id = bk.id
+               WHERE r.user_id = ? AND r.status = 'read' AND r.finished_at IS NOT NULL
+                 AND r.format = 'audiobook' AND bk.duration_minutes IS NOT NULL
                GROUP BY name ORDER BY name",
         )
         .bind(uid)
@@ -636,32 +1018,46 @@ impl SqlStatsRepository {
     async fn build_reading_stats(
         &self,
         uid: i64,
-        year: Option<i32>,
+        period: Option<&StatsPeriod>,
     ) -> Result<ReadingStats, RepositoryError> {
-        let books_all_time = self.fetch_books_read(uid, year).await? as u64;
-        let pages_all_time = self.fetch_pages_read(uid, year).await?;
-        let average_rating = self.fetch_average_rating(uid, year).await?;
-        let average_days_to_finish = self.fetch_average_days_to_finish(uid, year).await?;
-        let rating_distribution = self.fetch_rating_distribution(uid, year).await?;
+        let scope = Scope::from(period);
+        let books_all_time = self.fetch_books_read(uid, scope).await? as u64;
+        let pages_all_time = self.fetch_pages_read(uid, scope).await?;
+        let average_rating = self.fetch_average_rating(uid, scope).await?;
+        let average_days_to_finish = self.fetch_average_days_to_finish(uid, scope).await?;
+        let rating_distribution = self.fetch_rating_distribution(uid, scope).await?;
         let max_rating_count = rating_distribution
             .iter()
             .map(|(_, c)| *c)
             .max()
             .unwrap_or(0);
-        let monthly_books = self.fetch_monthly_books(uid, year).await?;
+        // The all-time view charts the current year month by month
+        let months = match period {
+            Some(p) => *p,
+            None => StatsPeriod::year(Utc::now().year())
+                .ok_or_else(|| RepositoryError::unexpected("current year out of range"))?,
+        };
+        let MonthlySeries {
+            books: monthly_books,
+            pages: monthly_pages,
+            minutes: monthly_minutes,
+        } = self.fetch_monthly_activity(uid, &months).await?;
         let max_monthly_books = monthly_books.iter().map(|(_, c)| *c).max().unwrap_or(0);
-        let monthly_pages = self.fetch_monthly_pages(uid, year).await?;
         let max_monthly_pages = monthly_pages.iter().map(|(_, p)| *p).max().unwrap_or(0);
-        let pace_distribution = self.fetch_pace_distribution(uid, year).await?;
-        let format_counts = self.fetch_format_counts(uid, year).await?;
-        let books_abandoned = self.fetch_books_abandoned(uid, year).await? as u64;
+        let pace_distribution = self.fetch_pace_distribution(uid, scope).await?;
+        let format_counts = self.fetch_format_counts(uid, scope).await?;
+        let books_abandoned = self.fetch_books_abandoned(uid, scope).await? as u64;
+        let quick_reviews = self.fetch_quick_review_stats(uid, scope).await?;
+        let mut listening = self.fetch_listening_stats(uid, scope).await?;
+        listening.monthly_minutes = monthly_minutes;
 
         // Yearly aggregation only for the all-time view
-        let (yearly_books, yearly_pages, max_yearly_books, max_yearly_pages) = if year.is_none() {
+        let (yearly_books, yearly_pages, max_yearly_books, max_yearly_pages) = if period.is_none() {
             let yb = self.fetch_yearly_books(uid).await?;
             let yp = self.fetch_yearly_pages(uid).await?;
             let mb = yb.iter().map(|(_, c)| *c).max().unwrap_or(0);
             let mp = yp.iter().map(|(_, p)| *p).max().unwrap_or(0);
+            listening.yearly_minutes = self.fetch_yearly_minutes(uid).await?;
             (yb, yp, mb, mp)
         } else {
             (vec![], vec![], 0, 0)
@@ -690,6 +1086,8 @@ impl SqlStatsRepository {
             max_yearly_pages,
             pace_distribution,
             format_counts,
+            quick_reviews,
+            listening,
         })
     }
 }
@@ -718,15 +1116,12 @@ impl StatsRepository for SqlStatsRepository {
         .await
         .map_err(db_err)?;
 
-        let genre_records: Vec<NameCount> = query_as(
-            r"SELECT g.name AS name, COUNT(*) AS count
+        let genre_links: Vec<BookGenre> = query_as(
+            r"SELECT DISTINCT b.id AS book_id, g.id AS genre_id
               FROM user_books ub
               JOIN books b ON b.id = ub.book_id
               JOIN genres g ON g.id IN (b.primary_genre_id, b.secondary_genre_id)
-              WHERE ub.user_id = ? AND ub.shelf = 'library'
-                AND g.id IS NOT NULL
-              GROUP BY g.id
-              ORDER BY count DESC",
+              WHERE ub.user_id = ? AND ub.shelf = 'library'",
         )
         .bind(uid)
         .fetch_all(&self.pool)
@@ -747,8 +1142,8 @@ impl StatsRepository for SqlStatsRepository {
         .map_err(db_err)?
         .map(|r| r.name);
 
-        let most_rated_author = self.fetch_most_rated_author(uid, None).await?;
-        let most_rated_genre = self.fetch_most_rated_genre(uid, None).await?;
+        let most_rated_author = self.fetch_most_rated_author(uid, Scope::AllTime).await?;
+        let most_rated_genre = self.fetch_most_rated_genre(uid, Scope::AllTime).await?;
 
         let (page_distribution, year_distribution) = self.fetch_library_distributions(uid).await?;
 
@@ -768,11 +1163,14 @@ impl StatsRepository for SqlStatsRepository {
         .map_err(db_err)?;
 
         let (longest, shortest) = self.fetch_library_extremes(uid).await?;
+        let genre_sentiment = self.fetch_genre_sentiment(uid, Scope::AllTime).await?;
+        let author_sentiment = self.fetch_author_sentiment(uid, Scope::AllTime).await?;
 
         Ok(build_book_summary(RawBookStats {
             total_books,
             total_authors,
-            genre_records,
+            genre_tree: self.fetch_genre_tree().await?,
+            genre_links,
             top_author,
             most_rated_author,
             most_rated_genre,
@@ -781,6 +1179,8 @@ impl StatsRepository for SqlStatsRepository {
             top_authors_records,
             longest,
             shortest,
+            genre_sentiment,
+            author_sentiment,
         }))
     }
 
@@ -789,58 +1189,13 @@ impl StatsRepository for SqlStatsRepository {
 
         let mut stats = self.build_reading_stats(uid, None).await?;
 
-        // All-time summary includes current-state counters not relevant to per-year view
-        stats.books_last_30_days = query_scalar(
-            r"SELECT COUNT(*) FROM readings
-               WHERE user_id = ? AND status = 'read' AND finished_at >= date('now', '-30 days')",
-        )
-        .bind(uid)
-        .fetch_one(&self.pool)
-        .await
-        .map_err(db_err)
-        .map(|v: i64| v as u64)?;
-
-        stats.pages_last_30_days = query_scalar(
-            r"SELECT COALESCE(SUM(bk.page_count), 0) FROM readings r
-               JOIN books bk ON r.book_id = bk.id
-               WHERE r.user_id = ? AND r.status = 'read' AND r.finished_at >= date('now', '-30 days')
-               AND bk.page_count IS NOT NULL",
-        )
-        .bind(uid)
-        .fetch_one(&self.pool)
-        .await
-        .map_err(db_err)?;
-
-        stats.books_in_progress =
-            query_scalar(r"SELECT COUNT(*) FROM readings WHERE user_id = ? AND status = 'reading'")
-                .bind(uid)
-                .fetch_one(&self.pool)
-                .await
-                .map_err(db_err)
-                .map(|v: i64| v as u64)?;
-
-        stats.books_on_shelf = query_scalar(
-            r"SELECT COUNT(*) FROM user_books ub
-               WHERE ub.user_id = ? AND ub.shelf = 'library'
-               AND NOT EXISTS (
-                   SELECT 1 FROM readings r
-                   WHERE r.book_id = ub.book_id AND r.user_id = ub.user_id
-               )",
-        )
-        .bind(uid)
-        .fetch_one(&self.pool)
-        .await
-        .map_err(db_err)
-        .map(|v: i64| v as u64)?;
-
-        stats.books_on_wishlist = query_scalar(
-            r"SELECT COUNT(*) FROM user_books WHERE user_id = ? AND shelf = 'wishlist'",
-        )
-        .bind(uid)
-        .fetch_one(&self.pool)
-        .await
-        .map_err(db_err)
-        .map(|v: i64| v as u64)?;
+        // All-time summary includes current-state counters not relevant to per-period views
+        let counters = self.reading_counters(user_id).await?;
+        stats.books_last_30_days = counters.books_last_30_days;
+        stats.pages_last_30_days = counters.pages_last_30_days;
+        stats.books_in_progress = counters.books_in_progress;
+        stats.books_on_shelf = counters.books_on_shelf;
+        stats.books_on_wishlist = counters.books_on_wishlist;
 
         Ok(stats)
     }
@@ -899,72 +1254,78 @@ impl StatsRepository for SqlStatsRepository {
         Ok(years)
     }
 
-    async fn book_summary_for_year(
+    async fn book_summary_for_period(
         &self,
         user_id: UserId,
-        year: i32,
+        period: &StatsPeriod,
     ) -> Result<BookSummaryStats, RepositoryError> {
         let uid = user_id.into_inner();
 
-        // All queries for year scope use a CTE that defines the set of books read that year.
-        let cte = r"WITH year_books AS (
+        // All queries for a period use a CTE that defines the set of books read in it.
+        let cte = r"WITH period_books AS (
                    SELECT DISTINCT r.book_id FROM readings r
                    WHERE r.user_id = ? AND r.status = 'read'
-                     AND CAST(strftime('%Y', r.finished_at) AS INTEGER) = ?
+                     AND r.finished_at BETWEEN ? AND ?
                )";
 
         let total_books: i64 = query_scalar(AssertSqlSafe(format!(
-            "{cte} SELECT COUNT(*) FROM year_books"
+            "{cte} SELECT COUNT(*) FROM period_books"
         )))
         .bind(uid)
-        .bind(year)
+        .bind(period.from)
+        .bind(period.to)
         .fetch_one(&self.pool)
         .await
         .map_err(db_err)?;
 
         let total_authors: i64 = query_scalar(AssertSqlSafe(format!(
             "{cte} SELECT COUNT(DISTINCT ba.author_id) \
-             FROM year_books yb JOIN book_authors ba ON ba.book_id = yb.book_id"
+             FROM period_books yb JOIN book_authors ba ON ba.book_id = yb.book_id"
         )))
         .bind(uid)
-        .bind(year)
+        .bind(period.from)
+        .bind(period.to)
         .fetch_one(&self.pool)
         .await
         .map_err(db_err)?;
 
-        let genre_records: Vec<NameCount> = query_as(AssertSqlSafe(format!(
-            "{cte} SELECT g.name AS name, COUNT(*) AS count \
-             FROM year_books yb \
+        let genre_links: Vec<BookGenre> = query_as(AssertSqlSafe(format!(
+            "{cte} SELECT DISTINCT b.id AS book_id, g.id AS genre_id \
+             FROM period_books yb \
              JOIN books b ON b.id = yb.book_id \
-             JOIN genres g ON g.id IN (b.primary_genre_id, b.secondary_genre_id) \
-             WHERE g.id IS NOT NULL \
-             GROUP BY g.id ORDER BY count DESC"
+             JOIN genres g ON g.id IN (b.primary_genre_id, b.secondary_genre_id)"
         )))
         .bind(uid)
-        .bind(year)
+        .bind(period.from)
+        .bind(period.to)
         .fetch_all(&self.pool)
         .await
         .map_err(db_err)?;
 
         let top_author = query_as::<_, NameCount>(AssertSqlSafe(format!(
             "{cte} SELECT a.name AS name, COUNT(*) AS count \
-             FROM year_books yb \
+             FROM period_books yb \
              JOIN book_authors ba ON ba.book_id = yb.book_id \
              JOIN authors a ON ba.author_id = a.id \
              GROUP BY a.id ORDER BY count DESC LIMIT 1"
         )))
         .bind(uid)
-        .bind(year)
+        .bind(period.from)
+        .bind(period.to)
         .fetch_optional(&self.pool)
         .await
         .map_err(db_err)?
         .map(|r| r.name);
 
-        let most_rated_author = self.fetch_most_rated_author(uid, Some(year)).await?;
-        let most_rated_genre = self.fetch_most_rated_genre(uid, Some(year)).await?;
+        let most_rated_author = self
+            .fetch_most_rated_author(uid, Scope::Period(period))
+            .await?;
+        let most_rated_genre = self
+            .fetch_most_rated_genre(uid, Scope::Period(period))
+            .await?;
 
         let (page_distribution, year_distribution) =
-            self.fetch_year_distributions(uid, year, cte).await?;
+            self.fetch_period_distributions(uid, period, cte).await?;
 
         let top_authors_records: Vec<NameCount> = query_as(
             r"SELECT a.name AS name, COUNT(*) AS count
@@ -972,23 +1333,31 @@ impl StatsRepository for SqlStatsRepository {
                JOIN book_authors ba ON r.book_id = ba.book_id AND ba.role = 'author'
                JOIN authors a ON ba.author_id = a.id
                WHERE r.user_id = ? AND r.status = 'read'
-                 AND CAST(strftime('%Y', r.finished_at) AS INTEGER) = ?
+                 AND r.finished_at BETWEEN ? AND ?
                GROUP BY a.id
                ORDER BY count DESC
                LIMIT 13",
         )
         .bind(uid)
-        .bind(year)
+        .bind(period.from)
+        .bind(period.to)
         .fetch_all(&self.pool)
         .await
         .map_err(db_err)?;
 
-        let (longest, shortest) = self.fetch_year_extremes(uid, year, cte).await?;
+        let (longest, shortest) = self.fetch_period_extremes(uid, period, cte).await?;
+        let genre_sentiment = self
+            .fetch_genre_sentiment(uid, Scope::Period(period))
+            .await?;
+        let author_sentiment = self
+            .fetch_author_sentiment(uid, Scope::Period(period))
+            .await?;
 
         Ok(build_book_summary(RawBookStats {
             total_books,
             total_authors,
-            genre_records,
+            genre_tree: self.fetch_genre_tree().await?,
+            genre_links,
             top_author,
             most_rated_author,
             most_rated_genre,
@@ -997,15 +1366,339 @@ impl StatsRepository for SqlStatsRepository {
             top_authors_records,
             longest,
             shortest,
+            genre_sentiment,
+            author_sentiment,
         }))
     }
 
-    async fn reading_summary_for_year(
+    async fn reading_summary_for_period(
         &self,
         user_id: UserId,
-        year: i32,
+        period: &StatsPeriod,
     ) -> Result<ReadingStats, RepositoryError> {
-        self.build_reading_stats(user_id.into_inner(), Some(year))
+        self.build_reading_stats(user_id.into_inner(), Some(period))
             .await
     }
+
+    async fn year_partial(
+        &self,
+        user_id: UserId,
+        year: Option<i32>,
+    ) -> Result<YearStatsPartial, RepositoryError> {
+        let uid = user_id.into_inner();
+        let period = match year {
+            Some(y) => Some(
+                StatsPeriod::year(y)
+                    .ok_or_else(|| RepositoryError::unexpected(format!("year {y} out of range")))?,
+            ),
+            None => None,
+        };
+        let scope = period.as_ref().map_or(Scope::Undated, Scope::Period);
+
+        let (finish_days_total, finish_time_count) = self.fetch_finish_days(uid, scope).await?;
+        let mut listening = self.fetch_listening_stats(uid, scope).await?;
+        let (monthly_books, monthly_pages) = match &period {
+            Some(p) => {
+                let series = self.fetch_monthly_activity(uid, p).await?;
+                listening.monthly_minutes = series.minutes;
+                (
+                    series.books.into_iter().map(|(_, c)| c).collect(),
+                    series.pages.into_iter().map(|(_, p)| p).collect(),
+                )
+            }
+            None => (Vec::new(), Vec::new()),
+        };
+
+        Ok(YearStatsPartial {
+            year,
+            books_read: self.fetch_books_read(uid, scope).await? as u64,
+            pages_read: self.fetch_pages_read(uid, scope).await?,
+            rating_distribution: self.fetch_rating_distribution(uid, scope).await?,
+            finish_days_total,
+            finish_time_count,
+            books_abandoned: self.fetch_books_abandoned(uid, scope).await? as u64,
+            monthly_books,
+            monthly_pages,
+            pace_distribution: self.fetch_pace_distribution(uid, scope).await?,
+            format_counts: self.fetch_format_counts(uid, scope).await?,
+            quick_reviews: self.fetch_quick_review_stats(uid, scope).await?,
+            listening,
+        })
+    }
+
+    async fn partial_years(&self, user_id: UserId) -> Result<Vec<Option<i32>>, RepositoryError> {
+        query_scalar(
+            r"SELECT CAST(strftime('%Y', finished_at) AS INTEGER) FROM readings
+               WHERE user_id = ? AND status = 'read'
+              UNION
+              SELECT CAST(strftime('%Y', started_at) AS INTEGER) FROM readings
+               WHERE user_id = ? AND status = 'abandoned'",
+        )
+        .bind(user_id.into_inner())
+        .bind(user_id.into_inner())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_err)
+    }
+
+    async fn get_partials(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<YearStatsPartial>, RepositoryError> {
+        let rows: Vec<String> =
+            query_scalar(r"SELECT data FROM stats_year_partials WHERE user_id = ? ORDER BY year")
+                .bind(user_id.into_inner())
+                .fetch_all(&self.pool)
+                .await
+                .map_err(db_err)?;
+
+        rows.iter()
+            .map(|json| {
+                serde_json::from_str(json)
+                    .map_err(|err| RepositoryError::unexpected(err.to_string()))
+            })
+            .collect()
+    }
+
+    async fn store_partial(
+        &self,
+        user_id: UserId,
+        partial: &YearStatsPartial,
+    ) -> Result<(), RepositoryError> {
+        let json = serde_json::to_string(partial)
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        sqlx::query(
+            r"INSERT OR REPLACE INTO stats_year_partials (user_id, year, data, computed_at)
+              VALUES (?, ?, ?, datetime('now'))",
+        )
+        .bind(user_id.into_inner())
+        .bind(partial_year_key(partial.year))
+        .bind(&json)
+        .execute(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        Ok(())
+    }
+
+    async fn delete_partial(
+        &self,
+        user_id: UserId,
+        year: Option<i32>,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query(r"DELETE FROM stats_year_partials WHERE user_id = ? AND year = ?")
+            .bind(user_id.into_inner())
+            .bind(partial_year_key(year))
+            .execute(&self.pool)
+            .await
+            .map_err(db_err)?;
+        Ok(())
+    }
+
+    async fn reading_counters(&self, user_id: UserId) -> Result<ReadingCounters, RepositoryError> {
+        let uid = user_id.into_inner();
+
+        let books_last_30_days: i64 = query_scalar(
+            r"SELECT COUNT(*) FROM readings
+               WHERE user_id = ? AND status = 'read' AND finished_at >= date('now', '-30 days')",
+        )
+        .bind(uid)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        let pages_last_30_days: i64 = query_scalar(
+            r"SELECT COALESCE(SUM(bk.page_count), 0) FROM readings r
+               JOIN books bk ON r.book_id = bk.id
+               WHERE r.user_id = ? AND r.status = 'read' AND r.finished_at >= date('now', '-30 days')
+               AND bk.page_count IS NOT NULL AND r.format IS NOT 'audiobook'",
+        )
+        .bind(uid)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        let books_in_progress: i64 =
+            query_scalar(r"SELECT COUNT(*) FROM readings WHERE user_id = ? AND status = 'reading'")
+                .bind(uid)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(db_err)?;
+
+        let books_on_shelf: i64 = query_scalar(
+            r"SELECT COUNT(*) FROM user_books ub
+               WHERE ub.user_id = ? AND ub.shelf = 'library'
+               AND NOT EXISTS (
+                   SELECT 1 FROM readings r
+                   WHERE r.book_id = ub.book_id AND r.user_id = ub.user_id
+               )",
+        )
+        .bind(uid)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        let books_on_wishlist: i64 = query_scalar(
+            r"SELECT COUNT(*) FROM user_books WHERE user_id = ? AND shelf = 'wishlist'",
+        )
+        .bind(uid)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        Ok(ReadingCounters {
+            books_last_30_days: books_last_30_days as u64,
+            pages_last_30_days,
+            books_in_progress: books_in_progress as u64,
+            books_on_shelf: books_on_shelf as u64,
+            books_on_wishlist: books_on_wishlist as u64,
+        })
+    }
+
+    async fn cached_user_ids(&self) -> Result<Vec<UserId>, RepositoryError> {
+        let ids: Vec<i64> = query_scalar(r"SELECT user_id FROM stats_cache ORDER BY user_id")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_err)?;
+        Ok(ids.into_iter().map(UserId::new).collect())
+    }
+
+    async fn reading_spans(&self, user_id: UserId) -> Result<Vec<ReadingSpan>, RepositoryError> {
+        let records: Vec<SpanRecord> = query_as(
+            r"SELECT status, started_at, finished_at
+               FROM readings
+               WHERE user_id = ? AND (started_at IS NOT NULL OR finished_at IS NOT NULL)",
+        )
+        .bind(user_id.into_inner())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        records
+            .into_iter()
+            .map(|r| {
+                let status = r.status.parse::<ReadingStatus>().map_err(|()| {
+                    RepositoryError::unexpected(format!("invalid reading status: {}", r.status))
+                })?;
+                Ok(ReadingSpan {
+                    status,
+                    started_at: r.started_at,
+                    finished_at: r.finished_at,
+                })
+            })
+            .collect()
+    }
+    async fn household_stats(&self, month: NaiveDate) -> Result<HouseholdStats, RepositoryError> {
+        let next_month = month
+            .checked_add_months(Months::new(1))
+            .ok_or_else(|| RepositoryError::unexpected("month out of range"))?;
+
+        let members: i64 = query_scalar(r"SELECT COUNT(*) FROM users WHERE household_opt_out = 0")
+            .fetch_one(&self.pool)
+            .await
+            .map_err(db_err)?;
+
+        let (books_read, pages_read): (i64, i64) = query_as(
+            r"SELECT COUNT(*),
+                     COALESCE(SUM(CASE WHEN r.format IS NOT 'audiobook' THEN b.page_count END), 0)
+               FROM readings r
+               JOIN users u ON u.id = r.user_id AND u.household_opt_out = 0
+               JOIN books b ON b.id = r.book_id
+               WHERE r.status = 'read' AND r.private = 0",
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        let (top_books, top_authors) = self.fetch_household_top().await?;
+
+        let this_month: Vec<MonthFinishRecord> = query_as(
+            r"SELECT u.username, b.title
+               FROM readings r
+               JOIN users u ON u.id = r.user_id AND u.household_opt_out = 0
+               JOIN books b ON b.id = r.book_id
+               WHERE r.status = 'read' AND r.private = 0
+                 AND r.finished_at >= ? AND r.finished_at < ?
+               ORDER BY u.username, r.finished_at, b.title",
+        )
+        .bind(month)
+        .bind(next_month)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        let leaderboard: Vec<LeaderboardRecord> = query_as(
+            r"SELECT CAST(strftime('%Y', r.finished_at) AS INTEGER) AS year,
+                      u.username,
+                      COUNT(*) AS books,
+                      COALESCE(SUM(CASE WHEN r.format IS NOT 'audiobook' THEN b.page_count END), 0) AS pages
+               FROM readings r
+               JOIN users u ON u.id = r.user_id AND u.household_opt_out = 0
+               JOIN books b ON b.id = r.book_id
+               WHERE r.status = 'read' AND r.private = 0 AND r.finished_at IS NOT NULL
+               GROUP BY year, u.id",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        Ok(HouseholdStats {
+            members: members as u64,
+            books_read: books_read as u64,
+            pages_read,
+            top_books: top_books
+                .into_iter()
+                .map(|r| HouseholdBook {
+                    book_id: BookId::from(r.id),
+                    title: r.title,
+                    readers: r.readers as u64,
+                })
+                .collect(),
+            top_authors: name_counts(top_authors),
+            month,
+            this_month: MonthReader::group(
+                this_month
+                    .into_iter()
+                    .map(|r| (r.username, r.title))
+                    .collect(),
+            ),
+            leaderboards: YearLeaderboard::from_totals(
+                leaderboard
+                    .into_iter()
+                    .map(|r| (r.year, r.username, r.books as u64, r.pages))
+                    .collect(),
+            ),
+            computed_at: Utc::now().to_rfc3339(),
+        })
+    }
+
+    async fn get_cached_household(&self) -> Result<Option<HouseholdStats>, RepositoryError> {
+        let json: Option<String> =
+            query_scalar(r"SELECT data FROM household_stats_cache WHERE id = 1")
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(db_err)?;
+
+        json.map(|json| {
+            serde_json::from_str(&json).map_err(|err| RepositoryError::unexpected(err.to_string()))
+        })
+        .transpose()
+    }
+
+    async fn store_cached_household(&self, stats: &HouseholdStats) -> Result<(), RepositoryError> {
+        let json = serde_json::to_string(stats)
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        sqlx::query(
+            r"INSERT OR REPLACE INTO household_stats_cache (id, data, computed_at)
+              VALUES (1, ?, datetime('now'))",
+        )
+        .bind(&json)
+        .execute(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        Ok(())
+    }
 }
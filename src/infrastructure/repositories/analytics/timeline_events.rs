@@ -1,14 +1,16 @@
 use crate::domain::RepositoryError;
-use crate::domain::ids::{TimelineEventId, UserId};
+use crate::domain::ids::{GenreId, TimelineEventId, UserId};
 use crate::domain::listing::{ListRequest, Page};
 use crate::domain::repositories::TimelineEventRepository;
 use crate::domain::timeline::{
-    NewTimelineEvent, TimelineEvent, TimelineEventDetail, TimelineReadingData, TimelineSortKey,
+    NewTimelineEvent, RecordedTimelineEvent, TimelineEvent, TimelineEventChange,
+    TimelineEventDetail, TimelineFilter, TimelineReadingData, TimelineSortKey,
 };
-use crate::infrastructure::database::DatabasePool;
+use crate::infrastructure::database::{DatabaseDriver, DatabasePool};
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Days, Utc};
 use serde_json::from_str;
+use sqlx::QueryBuilder;
 
 #[derive(Clone)]
 pub struct SqlTimelineEventRepository {
@@ -21,13 +23,66 @@ impl SqlTimelineEventRepository {
     }
 }
 
+/// Appends a `WHERE` clause matching the filter.
+fn push_filter(qb: &mut QueryBuilder<DatabaseDriver>, filter: &TimelineFilter) {
+    qb.push(" WHERE 1 = 1");
+    if let Some(uid) = filter.user_id {
+        qb.push(" AND user_id = ");
+        qb.push_bind(uid.into_inner());
+    }
+    if let Some(entity_type) = &filter.entity_type {
+        qb.push(" AND entity_type = ");
+        qb.push_bind(entity_type.clone());
+    }
+    if let Some(action) = &filter.action {
+        qb.push(" AND action = ");
+        qb.push_bind(action.clone());
+    }
+    if let Some(action) = &filter.exclude_action {
+        qb.push(" AND action != ");
+        qb.push_bind(action.clone());
+    }
+    if let Some(genre_id) = filter.genre_id.map(GenreId::into_inner) {
+        // Book and shelf events carry the book's id, reading events the reading's
+        qb.push(
+            " AND EXISTS (SELECT 1 FROM books b WHERE b.id = CASE entity_type \
+               WHEN 'book' THEN entity_id \
+               WHEN 'user_book' THEN entity_id \
+               WHEN 'reading' THEN (SELECT r.book_id FROM readings r WHERE r.id = entity_id) \
+             END AND ",
+        );
+        qb.push_bind(genre_id);
+        qb.push(" IN (b.primary_genre_id, b.secondary_genre_id))");
+    }
+    // Timestamps are stored as RFC 3339 text, so a bare date sorts before
+    // every time on that day
+    if let Some(since) = filter.since {
+        qb.push(" AND occurred_at >= ");
+        qb.push_bind(since.to_string());
+    }
+    if let Some(until) = filter.until.and_then(|d| d.checked_add_days(Days::new(1))) {
+        qb.push(" AND occurred_at < ");
+        qb.push_bind(until.to_string());
+    }
+    // Private readings stay on their reader's own timeline
+    qb.push(
+        " AND NOT EXISTS (SELECT 1 FROM readings pr WHERE entity_type = 'reading' \
+           AND pr.id = entity_id AND pr.private = 1",
+    );
+    if let Some(viewer) = filter.viewer {
+        qb.push(" AND pr.user_id != ");
+        qb.push_bind(viewer.into_inner());
+    }
+    qb.push(")");
+}
+
 #[async_trait]
 impl TimelineEventRepository for SqlTimelineEventRepository {
     async fn insert(&self, event: NewTimelineEvent) -> Result<TimelineEvent, RepositoryError> {
         let query = r"
-            INSERT INTO timeline_events (user_id, entity_type, entity_id, action, occurred_at, title, details_json, genres_json, reading_data_json)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
-            RETURNING id, entity_type, entity_id, action, occurred_at, title, details_json, genres_json, reading_data_json
+            INSERT INTO timeline_events (user_id, entity_type, entity_id, action, occurred_at, title, details_json, genres_json, reading_data_json, changes_json)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            RETURNING id, entity_type, entity_id, action, occurred_at, title, details_json, genres_json, reading_data_json, changes_json
         ";
 
         let details_json = serde_json::to_string(&event.details).map_err(|err| {
@@ -47,8 +102,19 @@ impl TimelineEventRepository for SqlTimelineEventRepository {
                 RepositoryError::unexpected(format!("failed to encode reading data: {err}"))
             })?;
 
+        // Snapshot-only events store no changes
+        let changes_json = if event.changes.is_empty() {
+            None
+        } else {
+            Some(serde_json::to_string(&event.changes).map_err(|err| {
+                RepositoryError::unexpected(format!(
+                    "failed to encode timeline event changes: {err}"
+                ))
+            })?)
+        };
+
         let record = sqlx::query_as::<_, TimelineEventRecord>(query)
-            .bind(event.user_id.map(crate::domain::ids::UserId::into_inner))
+            .bind(event.user_id.map(UserId::into_inner))
             .bind(event.entity_type)
             .bind(event.entity_id)
             .bind(event.action)
@@ -57,6 +123,7 @@ impl TimelineEventRepository for SqlTimelineEventRepository {
             .bind(details_json)
             .bind(genres_json)
             .bind(reading_data_json)
+            .bind(changes_json)
             .fetch_one(&self.pool)
             .await
             .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
@@ -64,30 +131,52 @@ impl TimelineEventRepository for SqlTimelineEventRepository {
         record.into_domain()
     }
 
+    async fn get_recorded(
+        &self,
+        id: TimelineEventId,
+    ) -> Result<RecordedTimelineEvent, RepositoryError> {
+        let query = r"
+            SELECT id, entity_type, entity_id, action, occurred_at, title, details_json,
+                genres_json, reading_data_json, changes_json, user_id,
+                EXISTS (SELECT 1 FROM readings pr WHERE entity_type = 'reading'
+                    AND pr.id = entity_id AND pr.private = 1) AS private
+            FROM timeline_events
+            WHERE id = ?
+        ";
+
+        let record = sqlx::query_as::<_, RecordedTimelineEventRecord>(query)
+            .bind(id.into_inner())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?
+            .ok_or(RepositoryError::NotFound)?;
+
+        Ok(RecordedTimelineEvent {
+            event: record.event.into_domain()?,
+            user_id: record.user_id.map(UserId::new),
+            private: record.private,
+        })
+    }
+
     async fn list(
         &self,
-        user_id: Option<UserId>,
+        filter: &TimelineFilter,
         request: &ListRequest<TimelineSortKey>,
     ) -> Result<Page<TimelineEvent>, RepositoryError> {
         use crate::domain::listing::PageSize;
-        use sqlx::QueryBuilder;
 
         let direction_sql = request.sort_direction().as_sql();
         let order_clause = format!("occurred_at {direction_sql}, id DESC");
-        let uid_val = user_id.map(UserId::into_inner);
 
         let base_select = r"SELECT
             id, entity_type, entity_id, action, occurred_at, title,
-            details_json, genres_json, reading_data_json
+            details_json, genres_json, reading_data_json, changes_json
         FROM timeline_events";
 
         match request.page_size() {
             PageSize::All => {
                 let mut qb = QueryBuilder::new(base_select);
-                if let Some(uid) = uid_val {
-                    qb.push(" WHERE user_id = ");
-                    qb.push_bind(uid);
-                }
+                push_filter(&mut qb, filter);
                 qb.push(" ORDER BY ");
                 qb.push(&order_clause);
 
@@ -107,10 +196,7 @@ impl TimelineEventRepository for SqlTimelineEventRepository {
             }
             PageSize::Limited(page_size) => {
                 let mut count_qb = QueryBuilder::new("SELECT COUNT(*) FROM timeline_events");
-                if let Some(uid) = uid_val {
-                    count_qb.push(" WHERE user_id = ");
-                    count_qb.push_bind(uid);
-                }
+                push_filter(&mut count_qb, filter);
                 let (total,): (i64,) = count_qb
                     .build_query_as()
                     .fetch_one(&self.pool)
@@ -123,10 +209,7 @@ impl TimelineEventRepository for SqlTimelineEventRepository {
                 let offset = i64::from(page - 1).saturating_mul(limit);
 
                 let mut qb = QueryBuilder::new(base_select);
-                if let Some(uid) = uid_val {
-                    qb.push(" WHERE user_id = ");
-                    qb.push_bind(uid);
-                }
+                push_filter(&mut qb, filter);
                 qb.push(" ORDER BY ");
                 qb.push(&order_clause);
                 qb.push(" LIMIT ");
@@ -150,6 +233,25 @@ impl TimelineEventRepository for SqlTimelineEventRepository {
         }
     }
 
+    async fn matches(
+        &self,
+        id: TimelineEventId,
+        filter: &TimelineFilter,
+    ) -> Result<bool, RepositoryError> {
+        let mut qb = QueryBuilder::new("SELECT EXISTS (SELECT 1 FROM timeline_events");
+        push_filter(&mut qb, filter);
+        qb.push(" AND id = ");
+        qb.push_bind(id.into_inner());
+        qb.push(")");
+
+        let (matches,): (bool,) = qb
+            .build_query_as()
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+        Ok(matches)
+    }
+
     async fn update_by_entity(
         &self,
         entity_type: &str,
@@ -197,12 +299,17 @@ impl TimelineEventRepository for SqlTimelineEventRepository {
         entity_type: &str,
         entity_id: i64,
     ) -> Result<(), RepositoryError> {
-        sqlx::query("DELETE FROM timeline_events WHERE entity_type = ? AND entity_id = ?")
-            .bind(entity_type)
-            .bind(entity_id)
-            .execute(&self.pool)
-            .await
-            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+        // Shelf events carry the book's id, so they go with the book
+        sqlx::query(
+            "DELETE FROM timeline_events WHERE entity_id = ? \
+             AND (entity_type = ? OR (? = 'book' AND entity_type = 'user_book'))",
+        )
+        .bind(entity_id)
+        .bind(entity_type)
+        .bind(entity_type)
+        .execute(&self.pool)
+        .await
+        .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
 
         Ok(())
     }
@@ -228,6 +335,15 @@ struct TimelineEventRecord {
     details_json: Option<String>,
     genres_json: Option<String>,
     reading_data_json: Option<String>,
+    changes_json: Option<String>,
+}
+
+#[derive(sqlx::FromRow)]
+struct RecordedTimelineEventRecord {
+    #[sqlx(flatten)]
+    event: TimelineEventRecord,
+    user_id: Option<i64>,
+    private: bool,
 }
 
 impl TimelineEventRecord {
@@ -261,6 +377,17 @@ impl TimelineEventRecord {
             _ => None,
         };
 
+        let changes = match self.changes_json {
+            Some(raw) if !raw.is_empty() => {
+                from_str::<Vec<TimelineEventChange>>(&raw).map_err(|err| {
+                    RepositoryError::unexpected(format!(
+                        "failed to decode timeline event changes: {err}"
+                    ))
+                })?
+            }
+            _ => Vec::new(),
+        };
+
         Ok(TimelineEvent {
             id: TimelineEventId::from(self.id),
             entity_type: self.entity_type,
@@ -271,6 +398,7 @@ impl TimelineEventRecord {
             details,
             genres,
             reading_data,
+            changes,
         })
     }
 }
@@ -0,0 +1,118 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::query_as;
+
+use crate::domain::RepositoryError;
+use crate::domain::feed_tokens::{FeedKind, FeedToken, NewFeedToken};
+use crate::domain::ids::{FeedTokenId, UserId};
+use crate::domain::repositories::FeedTokenRepository;
+use crate::infrastructure::database::DatabasePool;
+
+#[derive(Clone)]
+pub struct SqlFeedTokenRepository {
+    pool: DatabasePool,
+}
+
+impl SqlFeedTokenRepository {
+    pub fn new(pool: DatabasePool) -> Self {
+        Self { pool }
+    }
+
+    fn to_domain(record: FeedTokenRecord) -> Result<FeedToken, RepositoryError> {
+        let FeedTokenRecord {
+            id,
+            user_id,
+            feed,
+            token_hash,
+            created_at,
+        } = record;
+
+        let feed = feed
+            .parse::<FeedKind>()
+            .map_err(|()| RepositoryError::unexpected(format!("unknown feed: {feed}")))?;
+
+        Ok(FeedToken {
+            id: FeedTokenId::from(id),
+            user_id: UserId::from(user_id),
+            feed,
+            token_hash,
+            created_at,
+        })
+    }
+}
+
+#[async_trait]
+impl FeedTokenRepository for SqlFeedTokenRepository {
+    async fn replace(&self, token: NewFeedToken) -> Result<FeedToken, RepositoryError> {
+        let query = r"INSERT INTO feed_tokens (user_id, feed, token_hash) VALUES (?, ?, ?)
+            ON CONFLICT(user_id, feed) DO UPDATE SET
+                token_hash = excluded.token_hash,
+                created_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+            RETURNING id, user_id, feed, token_hash, created_at";
+
+        let record = query_as::<_, FeedTokenRecord>(query)
+            .bind(i64::from(token.user_id))
+            .bind(token.feed.as_str())
+            .bind(&token.token_hash)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|err| {
+                if let sqlx::Error::Database(db_err) = &err
+                    && db_err.is_unique_violation()
+                {
+                    return RepositoryError::conflict("feed token already exists");
+                }
+                RepositoryError::unexpected(err.to_string())
+            })?;
+
+        Self::to_domain(record)
+    }
+
+    async fn get_by_token_hash(&self, token_hash: &str) -> Result<FeedToken, RepositoryError> {
+        let query = "SELECT id, user_id, feed, token_hash, created_at FROM feed_tokens WHERE token_hash = ?";
+
+        let record = query_as::<_, FeedTokenRecord>(query)
+            .bind(token_hash)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?
+            .ok_or(RepositoryError::NotFound)?;
+
+        Self::to_domain(record)
+    }
+
+    async fn list_by_user(&self, user_id: UserId) -> Result<Vec<FeedToken>, RepositoryError> {
+        let query = "SELECT id, user_id, feed, token_hash, created_at FROM feed_tokens WHERE user_id = ? ORDER BY feed";
+
+        let records = query_as::<_, FeedTokenRecord>(query)
+            .bind(i64::from(user_id))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        records.into_iter().map(Self::to_domain).collect()
+    }
+
+    async fn delete(&self, user_id: UserId, feed: FeedKind) -> Result<(), RepositoryError> {
+        let result = sqlx::query("DELETE FROM feed_tokens WHERE user_id = ? AND feed = ?")
+            .bind(i64::from(user_id))
+            .bind(feed.as_str())
+            .execute(&self.pool)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct FeedTokenRecord {
+    id: i64,
+    user_id: i64,
+    feed: String,
+    token_hash: String,
+    created_at: DateTime<Utc>,
+}
@@ -5,7 +5,7 @@ use sqlx::query_as;
 use crate::domain::RepositoryError;
 use crate::domain::ids::UserId;
 use crate::domain::repositories::UserRepository;
-use crate::domain::users::{NewUser, User};
+use crate::domain::users::{NewUser, ProfilePrivacy, User};
 use crate::infrastructure::database::DatabasePool;
 
 #[derive(Clone)]
@@ -25,16 +25,26 @@ impl SqlUserRepository {
             uuid,
             is_admin,
             created_at,
+            household_opt_out,
+            public_profile,
         } = record;
 
-        User::new(UserId::from(id), username, uuid, is_admin, created_at)
+        User::new(
+            UserId::from(id),
+            username,
+            uuid,
+            is_admin,
+            created_at,
+            household_opt_out,
+            public_profile,
+        )
     }
 }
 
 #[async_trait]
 impl UserRepository for SqlUserRepository {
     async fn insert(&self, user: NewUser) -> Result<User, RepositoryError> {
-        let query = r"INSERT INTO users (username, uuid, is_admin) VALUES (?, ?, (SELECT CASE WHEN COUNT(*) = 0 THEN 1 ELSE 0 END FROM users)) RETURNING id, username, uuid, is_admin, created_at";
+        let query = r"INSERT INTO users (username, uuid, is_admin) VALUES (?, ?, (SELECT CASE WHEN COUNT(*) = 0 THEN 1 ELSE 0 END FROM users)) RETURNING id, username, uuid, is_admin, created_at, household_opt_out, public_profile";
 
         let record = sqlx::query_as::<_, UserRecord>(query)
             .bind(&user.username)
@@ -54,7 +64,7 @@ impl UserRepository for SqlUserRepository {
     }
 
     async fn get(&self, id: UserId) -> Result<User, RepositoryError> {
-        let query = "SELECT id, username, uuid, is_admin, created_at FROM users WHERE id = ?";
+        let query = "SELECT id, username, uuid, is_admin, created_at, household_opt_out, public_profile FROM users WHERE id = ?";
 
         let record = query_as::<_, UserRecord>(query)
             .bind(i64::from(id))
@@ -67,7 +77,7 @@ impl UserRepository for SqlUserRepository {
     }
 
     async fn get_by_username(&self, username: &str) -> Result<User, RepositoryError> {
-        let query = "SELECT id, username, uuid, is_admin, created_at FROM users WHERE username = ?";
+        let query = "SELECT id, username, uuid, is_admin, created_at, household_opt_out, public_profile FROM users WHERE username = ?";
 
         let record = query_as::<_, UserRecord>(query)
             .bind(username)
@@ -80,7 +90,7 @@ impl UserRepository for SqlUserRepository {
     }
 
     async fn get_by_uuid(&self, uuid: &str) -> Result<User, RepositoryError> {
-        let query = "SELECT id, username, uuid, is_admin, created_at FROM users WHERE uuid = ?";
+        let query = "SELECT id, username, uuid, is_admin, created_at, household_opt_out, public_profile FROM users WHERE uuid = ?";
 
         let record = query_as::<_, UserRecord>(query)
             .bind(uuid)
@@ -104,8 +114,7 @@ impl UserRepository for SqlUserRepository {
     }
 
     async fn list_all(&self) -> Result<Vec<User>, RepositoryError> {
-        let query =
-            "SELECT id, username, uuid, is_admin, created_at FROM users ORDER BY created_at ASC";
+        let query = "SELECT id, username, uuid, is_admin, created_at, household_opt_out, public_profile FROM users ORDER BY created_at ASC";
 
         let records = query_as::<_, UserRecord>(query)
             .fetch_all(&self.pool)
@@ -114,6 +123,75 @@ impl UserRepository for SqlUserRepository {
 
         Ok(records.into_iter().map(Self::to_domain).collect())
     }
+
+    async fn set_household_opt_out(
+        &self,
+        id: UserId,
+        opt_out: bool,
+    ) -> Result<(), RepositoryError> {
+        let result = sqlx::query("UPDATE users SET household_opt_out = ? WHERE id = ?")
+            .bind(opt_out)
+            .bind(i64::from(id))
+            .execute(&self.pool)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn set_public_profile(&self, id: UserId, public: bool) -> Result<(), RepositoryError> {
+        let result = sqlx::query("UPDATE users SET public_profile = ? WHERE id = ?")
+            .bind(public)
+            .bind(i64::from(id))
+            .execute(&self.pool)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn get_profile_privacy(&self, id: UserId) -> Result<ProfilePrivacy, RepositoryError> {
+        let (show_ratings, show_quick_reviews): (bool, bool) = sqlx::query_as(
+            "SELECT profile_show_ratings, profile_show_quick_reviews FROM users WHERE id = ?",
+        )
+        .bind(i64::from(id))
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| RepositoryError::unexpected(err.to_string()))?
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(ProfilePrivacy {
+            show_ratings,
+            show_quick_reviews,
+        })
+    }
+
+    async fn set_profile_privacy(
+        &self,
+        id: UserId,
+        privacy: ProfilePrivacy,
+    ) -> Result<(), RepositoryError> {
+        let result = sqlx::query(
+            "UPDATE users SET profile_show_ratings = ?, profile_show_quick_reviews = ? WHERE id = ?",
+        )
+        .bind(privacy.show_ratings)
+        .bind(privacy.show_quick_reviews)
+        .bind(i64::from(id))
+        .execute(&self.pool)
+        .await
+        .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
 }
 
 #[derive(sqlx::FromRow)]
@@ -123,4 +201,6 @@ struct UserRecord {
     uuid: String,
     is_admin: bool,
     created_at: DateTime<Utc>,
+    household_opt_out: bool,
+    public_profile: bool,
 }
@@ -3,7 +3,7 @@ use chrono::{DateTime, Utc};
 use sqlx::{QueryBuilder, query, query_as};
 
 use crate::domain::RepositoryError;
-use crate::domain::authors::{Author, AuthorSortKey, NewAuthor, UpdateAuthor};
+use crate::domain::authors::{Author, AuthorDetails, AuthorSortKey, NewAuthor, UpdateAuthor};
 use crate::domain::ids::{AuthorId, UserId};
 use crate::domain::listing::{ListRequest, Page};
 use crate::domain::repositories::AuthorRepository;
@@ -33,9 +33,21 @@ impl SqlAuthorRepository {
         Author {
             id: AuthorId::from(record.id),
             name: record.name,
+            details: AuthorDetails {
+                bio: record.bio,
+                birth_year: record.birth_year,
+                death_year: record.death_year,
+                nationality: record.nationality,
+                website: record.website,
+                aliases: serde_json::from_str(&record.aliases_json).unwrap_or_default(),
+            },
             created_at: record.created_at,
         }
     }
+
+    fn encode_aliases(aliases: &[String]) -> Result<String, RepositoryError> {
+        serde_json::to_string(aliases).map_err(|err| RepositoryError::unexpected(err.to_string()))
+    }
 }
 
 #[async_trait]
@@ -44,11 +56,20 @@ impl AuthorRepository for SqlAuthorRepository {
         let new_author = new_author.normalize();
         let created_at = new_author.created_at.unwrap_or_else(Utc::now);
 
+        let details = &new_author.details;
+
         let record = query_as::<_, AuthorRecord>(
-            "INSERT INTO authors (name, created_at) VALUES (?, ?)\
-             RETURNING id, name, created_at",
+            "INSERT INTO authors (name, bio, birth_year, death_year, nationality, website, aliases_json, created_at) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?) \
+             RETURNING id, name, bio, birth_year, death_year, nationality, website, aliases_json, created_at",
         )
         .bind(&new_author.name)
+        .bind(details.bio.as_deref())
+        .bind(details.birth_year)
+        .bind(details.death_year)
+        .bind(details.nationality.as_deref())
+        .bind(details.website.as_deref())
+        .bind(Self::encode_aliases(&details.aliases)?)
         .bind(created_at)
         .fetch_one(&self.pool)
         .await
@@ -65,12 +86,13 @@ impl AuthorRepository for SqlAuthorRepository {
     }
 
     async fn get(&self, id: AuthorId) -> Result<Author, RepositoryError> {
-        let record =
-            query_as::<_, AuthorRecord>("SELECT id, name, created_at FROM authors WHERE id = ?")
-                .bind(i64::from(id))
-                .fetch_optional(&self.pool)
-                .await
-                .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+        let record = query_as::<_, AuthorRecord>(
+            "SELECT id, name, bio, birth_year, death_year, nationality, website, aliases_json, created_at FROM authors WHERE id = ?",
+        )
+        .bind(i64::from(id))
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
 
         match record {
             Some(record) => Ok(Self::into_domain(record)),
@@ -79,8 +101,16 @@ impl AuthorRepository for SqlAuthorRepository {
     }
 
     async fn get_by_name(&self, name: &str) -> Result<Author, RepositoryError> {
+        // Pen names match too, but an author's own name wins over another's alias.
         let record = query_as::<_, AuthorRecord>(
-            "SELECT id, name, created_at FROM authors WHERE LOWER(TRIM(name)) = LOWER(TRIM(?))",
+            r"SELECT id, name, bio, birth_year, death_year, nationality, website, aliases_json, created_at FROM authors
+              WHERE LOWER(TRIM(name)) = LOWER(TRIM(?1))
+                 OR EXISTS (
+                     SELECT 1 FROM json_each(authors.aliases_json) alias
+                     WHERE LOWER(TRIM(alias.value)) = LOWER(TRIM(?1))
+                 )
+              ORDER BY LOWER(TRIM(name)) = LOWER(TRIM(?1)) DESC, id ASC
+              LIMIT 1",
         )
         .bind(name)
         .fetch_optional(&self.pool)
@@ -98,12 +128,14 @@ impl AuthorRepository for SqlAuthorRepository {
         request: &ListRequest<AuthorSortKey>,
         search: Option<&str>,
     ) -> Result<Page<Author>, RepositoryError> {
-        use crate::infrastructure::repositories::pagination::SearchFilter;
+        use crate::infrastructure::repositories::pagination::{ListFilters, SearchFilter};
 
         let order_clause = Self::order_clause(request);
-        let base_query = "SELECT id, name, created_at FROM authors";
+        let base_query = "SELECT id, name, bio, birth_year, death_year, nationality, website, aliases_json, created_at FROM authors";
         let count_query = "SELECT COUNT(*) FROM authors";
-        let sf = search.and_then(|t| SearchFilter::new(t, vec!["name"]));
+        let filters = ListFilters::search(search.and_then(|t| {
+            SearchFilter::new(t, vec!["name"]).map(|f| f.with_json_array("aliases_json"))
+        }));
 
         crate::infrastructure::repositories::pagination::paginate(
             &self.pool,
@@ -111,7 +143,7 @@ impl AuthorRepository for SqlAuthorRepository {
             base_query,
             count_query,
             &order_clause,
-            sf.as_ref(),
+            &filters,
             |record| Ok(Self::into_domain(record)),
         )
         .await
@@ -123,12 +155,12 @@ impl AuthorRepository for SqlAuthorRepository {
         request: &ListRequest<AuthorSortKey>,
         search: Option<&str>,
     ) -> Result<Page<Author>, RepositoryError> {
-        use crate::infrastructure::repositories::pagination::SearchFilter;
+        use crate::infrastructure::repositories::pagination::{ListFilters, SearchFilter};
 
         let order_clause = Self::order_clause(request);
 
         let base_query = format!(
-            r"SELECT DISTINCT a.id, a.name, a.created_at
+            r"SELECT DISTINCT a.id, a.name, a.bio, a.birth_year, a.death_year, a.nationality, a.website, a.aliases_json, a.created_at
                FROM authors a
                JOIN book_authors ba ON ba.author_id = a.id
                JOIN user_books ub ON ub.book_id = ba.book_id
@@ -145,7 +177,9 @@ impl AuthorRepository for SqlAuthorRepository {
             i64::from(user_id)
         );
 
-        let sf = search.and_then(|t| SearchFilter::new(t, vec!["a.name"]));
+        let filters = ListFilters::search(search.and_then(|t| {
+            SearchFilter::new(t, vec!["a.name"]).map(|f| f.with_json_array("a.aliases_json"))
+        }));
 
         crate::infrastructure::repositories::pagination::paginate(
             &self.pool,
@@ -153,7 +187,7 @@ impl AuthorRepository for SqlAuthorRepository {
             &base_query,
             &count_query,
             &order_clause,
-            sf.as_ref(),
+            &filters,
             |record| Ok(Self::into_domain(record)),
         )
         .await
@@ -166,6 +200,20 @@ impl AuthorRepository for SqlAuthorRepository {
         push_update_field!(builder, sep, "name", changes.name);
         push_update_field!(builder, sep, "created_at", changes.created_at);
 
+        // Empty text clears a field; `Some(None)` clears a year.
+        let cleared = |value: Option<String>| value.map(|v| Some(v).filter(|v| !v.is_empty()));
+        push_update_field!(builder, sep, "bio", cleared(changes.bio));
+        push_update_field!(builder, sep, "birth_year", changes.birth_year);
+        push_update_field!(builder, sep, "death_year", changes.death_year);
+        push_update_field!(builder, sep, "nationality", cleared(changes.nationality));
+        push_update_field!(builder, sep, "website", cleared(changes.website));
+        let aliases_json = changes
+            .aliases
+            .as_deref()
+            .map(Self::encode_aliases)
+            .transpose()?;
+        push_update_field!(builder, sep, "aliases_json", aliases_json);
+
         if !sep {
             return Err(RepositoryError::unexpected(
                 "No fields provided for update".to_string(),
@@ -207,5 +255,11 @@ impl AuthorRepository for SqlAuthorRepository {
 struct AuthorRecord {
     id: i64,
     name: String,
+    bio: Option<String>,
+    birth_year: Option<i32>,
+    death_year: Option<i32>,
+    nationality: Option<String>,
+    website: Option<String>,
+    aliases_json: String,
     created_at: DateTime<Utc>,
 }
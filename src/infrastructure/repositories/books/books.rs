@@ -6,7 +6,8 @@ use sqlx::{QueryBuilder, query, query_as};
 
 use crate::domain::RepositoryError;
 use crate::domain::book_items::{Book, BookSortKey, BookWithAuthors, NewBook, UpdateBook};
-use crate::domain::ids::{AuthorId, BookId, GenreId};
+use crate::domain::filters::FilterQuery;
+use crate::domain::ids::{AuthorId, BookId, GenreId, ShelfId};
 use crate::domain::listing::{ListRequest, Page};
 use crate::domain::repositories::BookRepository;
 use crate::infrastructure::database::DatabasePool;
@@ -47,6 +48,7 @@ impl SqlBookRepository {
             isbn: record.isbn,
             description: record.description,
             page_count: record.page_count,
+            duration_minutes: record.duration_minutes,
             year_published: record.year_published,
             publisher: record.publisher,
             language: record.language,
@@ -178,14 +180,15 @@ impl BookRepository for SqlBookRepository {
             .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
 
         let record = query_as::<_, BookRecord>(
-            r"INSERT INTO books (title, isbn, description, page_count, year_published, publisher, language, primary_genre_id, secondary_genre_id, created_at)
-              VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-              RETURNING id, title, isbn, description, page_count, year_published, publisher, language, primary_genre_id, secondary_genre_id, created_at",
+            r"INSERT INTO books (title, isbn, description, page_count, duration_minutes, year_published, publisher, language, primary_genre_id, secondary_genre_id, created_at)
+              VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+              RETURNING id, title, isbn, description, page_count, duration_minutes, year_published, publisher, language, primary_genre_id, secondary_genre_id, created_at",
         )
         .bind(&new_book.title)
         .bind(new_book.isbn.as_deref())
         .bind(new_book.description.as_deref())
         .bind(new_book.page_count)
+        .bind(new_book.duration_minutes)
         .bind(new_book.year_published)
         .bind(new_book.publisher.as_deref())
         .bind(new_book.language.as_deref())
@@ -229,7 +232,7 @@ impl BookRepository for SqlBookRepository {
 
     async fn get(&self, id: BookId) -> Result<Book, RepositoryError> {
         let record = query_as::<_, BookRecord>(
-            r"SELECT id, title, isbn, description, page_count, year_published, publisher, language, primary_genre_id, secondary_genre_id, created_at
+            r"SELECT id, title, isbn, description, page_count, duration_minutes, year_published, publisher, language, primary_genre_id, secondary_genre_id, created_at
               FROM books WHERE id = ?",
         )
         .bind(i64::from(id))
@@ -255,7 +258,7 @@ impl BookRepository for SqlBookRepository {
 
     async fn get_by_title(&self, title: &str) -> Result<Book, RepositoryError> {
         let record = query_as::<_, BookRecord>(
-            r"SELECT id, title, isbn, description, page_count, year_published, publisher, language, primary_genre_id, secondary_genre_id, created_at
+            r"SELECT id, title, isbn, description, page_count, duration_minutes, year_published, publisher, language, primary_genre_id, secondary_genre_id, created_at
               FROM books WHERE LOWER(TRIM(title)) = LOWER(TRIM(?))",
         )
         .bind(title)
@@ -269,7 +272,7 @@ impl BookRepository for SqlBookRepository {
 
     async fn get_by_isbn(&self, isbn: &str) -> Result<Book, RepositoryError> {
         let record = query_as::<_, BookRecord>(
-            r"SELECT id, title, isbn, description, page_count, year_published, publisher, language, primary_genre_id, secondary_genre_id, created_at
+            r"SELECT id, title, isbn, description, page_count, duration_minutes, year_published, publisher, language, primary_genre_id, secondary_genre_id, created_at
               FROM books WHERE isbn = ?",
         )
         .bind(isbn)
@@ -284,14 +287,15 @@ impl BookRepository for SqlBookRepository {
     async fn list(
         &self,
         request: &ListRequest<BookSortKey>,
-        search: Option<&str>,
+        query: Option<&FilterQuery>,
     ) -> Result<Page<BookWithAuthors>, RepositoryError> {
-        use crate::infrastructure::repositories::pagination::SearchFilter;
+        use crate::infrastructure::repositories::filters::{FilterScope, TermFilter};
+        use crate::infrastructure::repositories::pagination::{ListFilters, SearchFilter};
 
         let order_clause = Self::order_clause(request);
 
         // Use LEFT JOINs to get the first author name for sorting and genre names for search
-        let base_query = r"SELECT b.id, b.title, b.isbn, b.description, b.page_count, b.year_published, b.publisher, b.language, b.primary_genre_id, b.secondary_genre_id, b.created_at
+        let base_query = r"SELECT b.id, b.title, b.isbn, b.description, b.page_count, b.duration_minutes, b.year_published, b.publisher, b.language, b.primary_genre_id, b.secondary_genre_id, b.created_at
               FROM books b
               LEFT JOIN (
                   SELECT book_id, MIN(rowid) AS min_rowid, author_id
@@ -301,9 +305,11 @@ impl BookRepository for SqlBookRepository {
               LEFT JOIN authors ba_sort ON ba_sort.id = ba_first.author_id
               LEFT JOIN genres pg ON pg.id = b.primary_genre_id
               LEFT JOIN genres sg ON sg.id = b.secondary_genre_id";
-        let count_query = "SELECT COUNT(*) FROM books b";
+        let count_query = r"SELECT COUNT(*) FROM books b
+              LEFT JOIN genres pg ON pg.id = b.primary_genre_id
+              LEFT JOIN genres sg ON sg.id = b.secondary_genre_id";
 
-        let sf = search.and_then(|t| {
+        let sf = query.and_then(|q| q.text.as_deref()).and_then(|t| {
             SearchFilter::new(
                 t,
                 vec![
@@ -315,6 +321,8 @@ impl BookRepository for SqlBookRepository {
                 ],
             )
         });
+        let terms = query.and_then(|q| TermFilter::new(&q.terms, FilterScope::books("b")));
+        let filters = ListFilters::new(sf, terms);
 
         let page: Page<Book> = crate::infrastructure::repositories::pagination::paginate(
             &self.pool,
@@ -322,7 +330,7 @@ impl BookRepository for SqlBookRepository {
             base_query,
             count_query,
             &order_clause,
-            sf.as_ref(),
+            &filters,
             |r| Ok(Self::into_book(r)),
         )
         .await?;
@@ -343,7 +351,7 @@ impl BookRepository for SqlBookRepository {
         author_id: AuthorId,
     ) -> Result<Vec<BookWithAuthors>, RepositoryError> {
         let records = query_as::<_, BookRecord>(
-            r"SELECT b.id, b.title, b.isbn, b.description, b.page_count, b.year_published, b.publisher, b.language, b.primary_genre_id, b.secondary_genre_id, b.created_at
+            r"SELECT b.id, b.title, b.isbn, b.description, b.page_count, b.duration_minutes, b.year_published, b.publisher, b.language, b.primary_genre_id, b.secondary_genre_id, b.created_at
               FROM books b
               JOIN book_authors ba ON ba.book_id = b.id
               WHERE ba.author_id = ?
@@ -363,14 +371,41 @@ impl BookRepository for SqlBookRepository {
         &self,
         genre_id: GenreId,
     ) -> Result<Vec<BookWithAuthors>, RepositoryError> {
+        // Includes books filed under any subgenre, at any depth.
         let records = query_as::<_, BookRecord>(
-            r"SELECT b.id, b.title, b.isbn, b.description, b.page_count, b.year_published, b.publisher, b.language, b.primary_genre_id, b.secondary_genre_id, b.created_at
+            r"WITH RECURSIVE genre_tree(id) AS (
+                  SELECT ?
+                  UNION
+                  SELECT g.id FROM genres g JOIN genre_tree t ON g.parent_id = t.id
+              )
+              SELECT b.id, b.title, b.isbn, b.description, b.page_count, b.duration_minutes, b.year_published, b.publisher, b.language, b.primary_genre_id, b.secondary_genre_id, b.created_at
               FROM books b
-              WHERE b.primary_genre_id = ? OR b.secondary_genre_id = ?
+              WHERE b.primary_genre_id IN (SELECT id FROM genre_tree)
+                 OR b.secondary_genre_id IN (SELECT id FROM genre_tree)
               ORDER BY b.created_at DESC",
         )
         .bind(i64::from(genre_id))
-        .bind(i64::from(genre_id))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        let books: Vec<Book> = records.into_iter().map(Self::into_book).collect();
+
+        self.enrich_books(books).await
+    }
+
+    async fn list_by_shelf(
+        &self,
+        shelf_id: ShelfId,
+    ) -> Result<Vec<BookWithAuthors>, RepositoryError> {
+        let records = query_as::<_, BookRecord>(
+            r"SELECT b.id, b.title, b.isbn, b.description, b.page_count, b.duration_minutes, b.year_published, b.publisher, b.language, b.primary_genre_id, b.secondary_genre_id, b.created_at
+              FROM books b
+              JOIN shelf_books sb ON sb.book_id = b.id
+              WHERE sb.shelf_id = ?
+              ORDER BY sb.position ASC, sb.added_at ASC",
+        )
+        .bind(i64::from(shelf_id))
         .fetch_all(&self.pool)
         .await
         .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
@@ -394,6 +429,7 @@ impl BookRepository for SqlBookRepository {
         push_update_field!(builder, sep, "isbn", changes.isbn);
         push_update_field!(builder, sep, "description", changes.description);
         push_update_field!(builder, sep, "page_count", changes.page_count);
+        push_update_field!(builder, sep, "duration_minutes", changes.duration_minutes);
         push_update_field!(builder, sep, "year_published", changes.year_published);
         push_update_field!(builder, sep, "publisher", changes.publisher);
         push_update_field!(builder, sep, "language", changes.language);
@@ -486,6 +522,7 @@ struct BookRecord {
     isbn: Option<String>,
     description: Option<String>,
     page_count: Option<i32>,
+    duration_minutes: Option<i32>,
     year_published: Option<i32>,
     publisher: Option<String>,
     language: Option<String>,
@@ -33,6 +33,7 @@ impl SqlGenreRepository {
         Genre {
             id: GenreId::from(record.id),
             name: record.name,
+            parent_id: record.parent_id.map(GenreId::from),
             created_at: record.created_at,
         }
     }
@@ -45,10 +46,11 @@ impl GenreRepository for SqlGenreRepository {
         let created_at = new_genre.created_at.unwrap_or_else(Utc::now);
 
         let record = query_as::<_, GenreRecord>(
-            "INSERT INTO genres (name, created_at) VALUES (?, ?)\
-             RETURNING id, name, created_at",
+            "INSERT INTO genres (name, parent_id, created_at) VALUES (?, ?, ?)\
+             RETURNING id, name, parent_id, created_at",
         )
         .bind(&new_genre.name)
+        .bind(new_genre.parent_id.map(GenreId::into_inner))
         .bind(created_at)
         .fetch_one(&self.pool)
         .await
@@ -65,12 +67,13 @@ impl GenreRepository for SqlGenreRepository {
     }
 
     async fn get(&self, id: GenreId) -> Result<Genre, RepositoryError> {
-        let record =
-            query_as::<_, GenreRecord>("SELECT id, name, created_at FROM genres WHERE id = ?")
-                .bind(i64::from(id))
-                .fetch_optional(&self.pool)
-                .await
-                .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+        let record = query_as::<_, GenreRecord>(
+            "SELECT id, name, parent_id, created_at FROM genres WHERE id = ?",
+        )
+        .bind(i64::from(id))
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
 
         match record {
             Some(record) => Ok(Self::into_domain(record)),
@@ -80,7 +83,7 @@ impl GenreRepository for SqlGenreRepository {
 
     async fn get_by_name(&self, name: &str) -> Result<Genre, RepositoryError> {
         let record = query_as::<_, GenreRecord>(
-            "SELECT id, name, created_at FROM genres WHERE LOWER(TRIM(name)) = LOWER(TRIM(?))",
+            "SELECT id, name, parent_id, created_at FROM genres WHERE LOWER(TRIM(name)) = LOWER(TRIM(?))",
         )
         .bind(name)
         .fetch_optional(&self.pool)
@@ -98,12 +101,12 @@ impl GenreRepository for SqlGenreRepository {
         request: &ListRequest<GenreSortKey>,
         search: Option<&str>,
     ) -> Result<Page<Genre>, RepositoryError> {
-        use crate::infrastructure::repositories::pagination::SearchFilter;
+        use crate::infrastructure::repositories::pagination::{ListFilters, SearchFilter};
 
         let order_clause = Self::order_clause(request);
-        let base_query = "SELECT id, name, created_at FROM genres";
+        let base_query = "SELECT id, name, parent_id, created_at FROM genres";
         let count_query = "SELECT COUNT(*) FROM genres";
-        let sf = search.and_then(|t| SearchFilter::new(t, vec!["name"]));
+        let filters = ListFilters::search(search.and_then(|t| SearchFilter::new(t, vec!["name"])));
 
         crate::infrastructure::repositories::pagination::paginate(
             &self.pool,
@@ -111,7 +114,7 @@ impl GenreRepository for SqlGenreRepository {
             base_query,
             count_query,
             &order_clause,
-            sf.as_ref(),
+            &filters,
             |record| Ok(Self::into_domain(record)),
         )
         .await
@@ -124,6 +127,15 @@ impl GenreRepository for SqlGenreRepository {
         push_update_field!(builder, sep, "name", changes.name);
         push_update_field!(builder, sep, "created_at", changes.created_at);
 
+        if let Some(parent_id) = &changes.parent_id {
+            if sep {
+                builder.push(", ");
+            }
+            sep = true;
+            builder.push("parent_id = ");
+            builder.push_bind(parent_id.map(i64::from));
+        }
+
         if !sep {
             return Err(RepositoryError::unexpected(
                 "No fields provided for update".to_string(),
@@ -165,5 +177,6 @@ impl GenreRepository for SqlGenreRepository {
 struct GenreRecord {
     id: i64,
     name: String,
+    parent_id: Option<i64>,
     created_at: DateTime<Utc>,
 }
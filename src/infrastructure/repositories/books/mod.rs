@@ -4,4 +4,6 @@ pub(crate) mod book_authors;
 pub mod books;
 pub mod genres;
 pub mod readings;
+pub mod shelves;
+pub mod tags;
 pub mod user_books;
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::str::FromStr;
 
 use async_trait::async_trait;
@@ -5,6 +6,7 @@ use chrono::{DateTime, NaiveDate, Utc};
 use sqlx::{AssertSqlSafe, QueryBuilder, query_as};
 
 use crate::domain::RepositoryError;
+use crate::domain::filters::FilterQuery;
 use crate::domain::ids::{BookId, ReadingId, UserId};
 use crate::domain::listing::{ListRequest, Page};
 use crate::domain::readings::{
@@ -17,7 +19,7 @@ use crate::infrastructure::repositories::macros::push_update_field;
 
 const BASE_SELECT: &str = r"
     SELECT
-        r.id, r.user_id, r.book_id, r.status, r.format, r.started_at, r.finished_at, r.rating, r.review, r.created_at, r.updated_at,
+        r.id, r.user_id, r.book_id, r.status, r.format, r.started_at, r.finished_at, r.rating, r.review, r.private, r.current_page, r.created_at, r.updated_at,
         bk.title AS book_title,
         bk.page_count, bk.year_published,
         pg.name AS primary_genre, sg.name AS secondary_genre,
@@ -110,6 +112,8 @@ impl SqlReadingRepository {
             finished_at: record.finished_at,
             rating: record.rating,
             quick_reviews: Self::decode_quick_reviews(record.review),
+            private: record.private,
+            current_page: record.current_page,
             created_at: record.created_at,
             updated_at: record.updated_at,
         })
@@ -141,6 +145,8 @@ impl SqlReadingRepository {
                 finished_at: record.finished_at,
                 rating: record.rating,
                 quick_reviews: Self::decode_quick_reviews(record.review),
+                private: record.private,
+                current_page: record.current_page,
                 created_at: record.created_at,
                 updated_at: record.updated_at,
             },
@@ -194,9 +200,9 @@ impl ReadingRepository for SqlReadingRepository {
     async fn insert(&self, reading: NewReading) -> Result<Reading, RepositoryError> {
         let created_at = reading.created_at.unwrap_or_else(Utc::now);
         let query = r"
-            INSERT INTO readings (user_id, book_id, status, format, started_at, finished_at, rating, review, created_at, updated_at)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-            RETURNING id, user_id, book_id, status, format, started_at, finished_at, rating, review, created_at, updated_at
+            INSERT INTO readings (user_id, book_id, status, format, started_at, finished_at, rating, review, private, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            RETURNING id, user_id, book_id, status, format, started_at, finished_at, rating, review, private, current_page, created_at, updated_at
         ";
 
         let record = query_as::<_, ReadingRecord>(query)
@@ -208,6 +214,7 @@ impl ReadingRepository for SqlReadingRepository {
             .bind(reading.finished_at)
             .bind(reading.rating)
             .bind(Self::encode_quick_reviews(&reading.quick_reviews))
+            .bind(reading.private)
             .bind(created_at)
             .bind(created_at)
             .fetch_one(&self.pool)
@@ -219,7 +226,7 @@ impl ReadingRepository for SqlReadingRepository {
 
     async fn get(&self, id: ReadingId) -> Result<Reading, RepositoryError> {
         let query = r"
-            SELECT id, user_id, book_id, status, format, started_at, finished_at, rating, review, created_at, updated_at
+            SELECT id, user_id, book_id, status, format, started_at, finished_at, rating, review, private, current_page, created_at, updated_at
             FROM readings
             WHERE id = ?
         ";
@@ -251,23 +258,24 @@ impl ReadingRepository for SqlReadingRepository {
         &self,
         filter: ReadingFilter,
         request: &ListRequest<ReadingSortKey>,
-        search: Option<&str>,
+        query: Option<&FilterQuery>,
     ) -> Result<Page<ReadingWithBook>, RepositoryError> {
         use crate::domain::listing::PageSize;
-        use crate::infrastructure::repositories::pagination::{
-            SearchFilter, push_search_condition,
-        };
+        use crate::infrastructure::repositories::filters::{FilterScope, TermFilter};
+        use crate::infrastructure::repositories::pagination::{ListFilters, SearchFilter};
 
         let order_clause = Self::order_clause(request);
-        let sf = search.and_then(|t| SearchFilter::new(t, vec!["bk.title", "a.name"]));
+        let sf = query
+            .and_then(|q| q.text.as_deref())
+            .and_then(|t| SearchFilter::new(t, vec!["bk.title", "a.name"]));
+        let terms = query.and_then(|q| TermFilter::new(&q.terms, FilterScope::readings("bk", "r")));
+        let filters = ListFilters::new(sf, terms);
 
         match request.page_size() {
             PageSize::All => {
                 let mut qb = QueryBuilder::new(BASE_SELECT);
                 let has_where = Self::push_filter(&mut qb, &filter);
-                if let Some(sf) = &sf {
-                    push_search_condition(&mut qb, sf, has_where);
-                }
+                filters.push_conditions(&mut qb, has_where);
                 qb.push(BASE_GROUP_BY);
                 qb.push(" ORDER BY ");
                 qb.push(&order_clause);
@@ -287,19 +295,19 @@ impl ReadingRepository for SqlReadingRepository {
                 Ok(Page::new(items, 1, page_size.max(1), total, true))
             }
             PageSize::Limited(page_size) => {
-                let count_base = if sf.is_some() {
+                let count_base = if filters.search.is_some() {
                     r"SELECT COUNT(DISTINCT r.id) FROM readings r
                         JOIN books bk ON r.book_id = bk.id
                         LEFT JOIN book_authors ba ON ba.book_id = bk.id
                         LEFT JOIN authors a ON a.id = ba.author_id"
+                } else if filters.terms.is_some() {
+                    "SELECT COUNT(*) FROM readings r JOIN books bk ON r.book_id = bk.id"
                 } else {
                     "SELECT COUNT(*) FROM readings r"
                 };
                 let mut count_qb = QueryBuilder::new(count_base);
                 let has_where = Self::push_filter(&mut count_qb, &filter);
-                if let Some(sf) = &sf {
-                    push_search_condition(&mut count_qb, sf, has_where);
-                }
+                filters.push_conditions(&mut count_qb, has_where);
                 let (total,): (i64,) = count_qb
                     .build_query_as()
                     .fetch_one(&self.pool)
@@ -313,9 +321,7 @@ impl ReadingRepository for SqlReadingRepository {
 
                 let mut qb = QueryBuilder::new(BASE_SELECT);
                 let has_where = Self::push_filter(&mut qb, &filter);
-                if let Some(sf) = &sf {
-                    push_search_condition(&mut qb, sf, has_where);
-                }
+                filters.push_conditions(&mut qb, has_where);
                 qb.push(BASE_GROUP_BY);
                 qb.push(" ORDER BY ");
                 qb.push(&order_clause);
@@ -373,12 +379,14 @@ impl ReadingRepository for SqlReadingRepository {
             builder.push(", review = ");
             builder.push_bind(Self::encode_quick_reviews(reviews));
         }
+        push_update_field!(builder, sep, "private", changes.private);
+        push_update_field!(builder, sep, "current_page", changes.current_page);
         push_update_field!(builder, sep, "created_at", changes.created_at);
         let _ = sep; // Suppress unused_assignments warning from macro
 
         builder.push(" WHERE id = ");
         builder.push_bind(id.into_inner());
-        builder.push(" RETURNING id, user_id, book_id, status, format, started_at, finished_at, rating, review, created_at, updated_at");
+        builder.push(" RETURNING id, user_id, book_id, status, format, started_at, finished_at, rating, review, private, current_page, created_at, updated_at");
 
         let record = builder
             .build_query_as::<ReadingRecord>()
@@ -405,6 +413,33 @@ impl ReadingRepository for SqlReadingRepository {
 
         Ok(())
     }
+
+    async fn book_ids(
+        &self,
+        ids: &[ReadingId],
+    ) -> Result<HashMap<ReadingId, BookId>, RepositoryError> {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut qb = QueryBuilder::new("SELECT id, book_id FROM readings WHERE id IN (");
+        let mut sep = qb.separated(", ");
+        for id in ids {
+            sep.push_bind(id.into_inner());
+        }
+        sep.push_unseparated(")");
+
+        let rows: Vec<(i64, i64)> = qb
+            .build_query_as()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, book_id)| (ReadingId::new(id), BookId::new(book_id)))
+            .collect())
+    }
 }
 
 #[derive(sqlx::FromRow)]
@@ -418,6 +453,8 @@ struct ReadingRecord {
     finished_at: Option<NaiveDate>,
     rating: Option<f64>,
     review: Option<String>,
+    private: bool,
+    current_page: Option<i32>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -433,6 +470,8 @@ struct ReadingWithBookRecord {
     finished_at: Option<NaiveDate>,
     rating: Option<f64>,
     review: Option<String>,
+    private: bool,
+    current_page: Option<i32>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
     book_title: String,
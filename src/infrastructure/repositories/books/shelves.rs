@@ -0,0 +1,277 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{QueryBuilder, query, query_as, query_scalar};
+
+use crate::domain::RepositoryError;
+use crate::domain::ids::{BookId, ShelfId, UserId};
+use crate::domain::repositories::ShelfRepository;
+use crate::domain::shelves::{NewSavedShelf, SavedShelf, ShelfKind, UpdateSavedShelf};
+use crate::infrastructure::database::DatabasePool;
+use crate::infrastructure::repositories::macros::push_update_field;
+
+#[derive(Clone)]
+pub struct SqlShelfRepository {
+    pool: DatabasePool,
+}
+
+impl SqlShelfRepository {
+    pub fn new(pool: DatabasePool) -> Self {
+        Self { pool }
+    }
+
+    fn to_domain(record: ShelfRecord) -> Result<SavedShelf, RepositoryError> {
+        let kind = ShelfKind::from_str(&record.kind).map_err(|()| {
+            RepositoryError::unexpected(format!("invalid shelf kind: {}", record.kind))
+        })?;
+
+        Ok(SavedShelf {
+            id: ShelfId::new(record.id),
+            user_id: UserId::new(record.user_id),
+            name: record.name,
+            description: record.description,
+            kind,
+            query: record.query,
+            pinned: record.pinned,
+            public: record.public,
+            created_at: record.created_at,
+        })
+    }
+
+    fn unique_name_error(err: sqlx::Error) -> RepositoryError {
+        if let sqlx::Error::Database(db_err) = &err
+            && db_err.is_unique_violation()
+        {
+            return RepositoryError::conflict("A shelf with this name already exists");
+        }
+        RepositoryError::unexpected(err.to_string())
+    }
+}
+
+#[async_trait]
+impl ShelfRepository for SqlShelfRepository {
+    #[allow(clippy::similar_names)] // self vs shelf
+    async fn insert(&self, shelf: NewSavedShelf) -> Result<SavedShelf, RepositoryError> {
+        let shelf = shelf.normalize();
+
+        let record = query_as::<_, ShelfRecord>(
+            "INSERT INTO shelves (user_id, name, description, kind, query, pinned, public) \
+             VALUES (?, ?, ?, ?, ?, ?, ?) \
+             RETURNING id, user_id, name, description, kind, query, pinned, public, created_at",
+        )
+        .bind(shelf.user_id.into_inner())
+        .bind(&shelf.name)
+        .bind(shelf.description.as_deref())
+        .bind(shelf.kind.as_str())
+        .bind(shelf.query.as_deref())
+        .bind(shelf.pinned)
+        .bind(shelf.public)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(Self::unique_name_error)?;
+
+        Self::to_domain(record)
+    }
+
+    async fn get(&self, id: ShelfId) -> Result<SavedShelf, RepositoryError> {
+        let record = query_as::<_, ShelfRecord>(
+            "SELECT id, user_id, name, description, kind, query, pinned, public, created_at \
+             FROM shelves WHERE id = ?",
+        )
+        .bind(id.into_inner())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| RepositoryError::unexpected(err.to_string()))?
+        .ok_or(RepositoryError::NotFound)?;
+
+        Self::to_domain(record)
+    }
+
+    async fn list_by_user(&self, user_id: UserId) -> Result<Vec<SavedShelf>, RepositoryError> {
+        let records = query_as::<_, ShelfRecord>(
+            "SELECT id, user_id, name, description, kind, query, pinned, public, created_at \
+             FROM shelves WHERE user_id = ? ORDER BY pinned DESC, LOWER(name) ASC",
+        )
+        .bind(user_id.into_inner())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        records.into_iter().map(Self::to_domain).collect()
+    }
+
+    async fn list_containing_book(
+        &self,
+        user_id: UserId,
+        book_id: BookId,
+    ) -> Result<Vec<SavedShelf>, RepositoryError> {
+        let records = query_as::<_, ShelfRecord>(
+            r"SELECT s.id, s.user_id, s.name, s.description, s.kind, s.query, s.pinned, s.public, s.created_at
+              FROM shelves s
+              JOIN shelf_books sb ON sb.shelf_id = s.id
+              WHERE s.user_id = ? AND sb.book_id = ?
+              ORDER BY LOWER(s.name) ASC",
+        )
+        .bind(user_id.into_inner())
+        .bind(book_id.into_inner())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        records.into_iter().map(Self::to_domain).collect()
+    }
+
+    async fn update(
+        &self,
+        id: ShelfId,
+        changes: UpdateSavedShelf,
+    ) -> Result<SavedShelf, RepositoryError> {
+        let changes = changes.normalize();
+        let mut builder = QueryBuilder::new("UPDATE shelves SET ");
+        let mut sep = false;
+
+        push_update_field!(builder, sep, "name", changes.name);
+        push_update_field!(
+            builder,
+            sep,
+            "description",
+            changes.description.map(|d| (!d.is_empty()).then_some(d))
+        );
+        push_update_field!(builder, sep, "query", changes.query);
+        push_update_field!(builder, sep, "pinned", changes.pinned);
+        push_update_field!(builder, sep, "public", changes.public);
+
+        if !sep {
+            return Err(RepositoryError::unexpected(
+                "No fields provided for update".to_string(),
+            ));
+        }
+
+        builder.push(" WHERE id = ");
+        builder.push_bind(id.into_inner());
+
+        let result = builder
+            .build()
+            .execute(&self.pool)
+            .await
+            .map_err(Self::unique_name_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        self.get(id).await
+    }
+
+    async fn delete(&self, id: ShelfId) -> Result<(), RepositoryError> {
+        let result = query("DELETE FROM shelves WHERE id = ?")
+            .bind(id.into_inner())
+            .execute(&self.pool)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn add_book(&self, id: ShelfId, book_id: BookId) -> Result<(), RepositoryError> {
+        query(
+            r"INSERT INTO shelf_books (shelf_id, book_id, position)
+              VALUES (?, ?, (SELECT COALESCE(MAX(position) + 1, 0) FROM shelf_books WHERE shelf_id = ?))",
+        )
+        .bind(id.into_inner())
+        .bind(book_id.into_inner())
+        .bind(id.into_inner())
+        .execute(&self.pool)
+        .await
+        .map_err(|err| {
+            if let sqlx::Error::Database(db_err) = &err {
+                if db_err.is_unique_violation() {
+                    return RepositoryError::conflict("This book is already on the shelf");
+                }
+                if db_err.is_foreign_key_violation() {
+                    return RepositoryError::NotFound;
+                }
+            }
+            RepositoryError::unexpected(err.to_string())
+        })?;
+
+        Ok(())
+    }
+
+    async fn remove_book(&self, id: ShelfId, book_id: BookId) -> Result<(), RepositoryError> {
+        let result = query("DELETE FROM shelf_books WHERE shelf_id = ? AND book_id = ?")
+            .bind(id.into_inner())
+            .bind(book_id.into_inner())
+            .execute(&self.pool)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn move_book(
+        &self,
+        id: ShelfId,
+        book_id: BookId,
+        position: usize,
+    ) -> Result<(), RepositoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        let mut order: Vec<i64> = query_scalar(
+            "SELECT book_id FROM shelf_books WHERE shelf_id = ? ORDER BY position ASC, added_at ASC",
+        )
+        .bind(id.into_inner())
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        let current = order
+            .iter()
+            .position(|b| *b == book_id.into_inner())
+            .ok_or(RepositoryError::NotFound)?;
+        let moved = order.remove(current);
+        order.insert(position.min(order.len()), moved);
+
+        for (index, book) in (0_i64..).zip(order.iter()) {
+            query("UPDATE shelf_books SET position = ? WHERE shelf_id = ? AND book_id = ?")
+                .bind(index)
+                .bind(id.into_inner())
+                .bind(*book)
+                .execute(&mut *tx)
+                .await
+                .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct ShelfRecord {
+    id: i64,
+    user_id: i64,
+    name: String,
+    description: Option<String>,
+    kind: String,
+    query: Option<String>,
+    pinned: bool,
+    public: bool,
+    created_at: DateTime<Utc>,
+}
@@ -0,0 +1,185 @@
+use async_trait::async_trait;
+use sqlx::{QueryBuilder, query, query_as, query_scalar};
+
+use crate::domain::RepositoryError;
+use crate::domain::ids::{BookId, UserId};
+use crate::domain::repositories::TagRepository;
+use crate::domain::tags::TagCount;
+use crate::infrastructure::database::DatabasePool;
+
+#[derive(Clone)]
+pub struct SqlTagRepository {
+    pool: DatabasePool,
+}
+
+impl SqlTagRepository {
+    pub fn new(pool: DatabasePool) -> Self {
+        Self { pool }
+    }
+
+    fn book_error(err: sqlx::Error) -> RepositoryError {
+        if let sqlx::Error::Database(db_err) = &err
+            && db_err.is_foreign_key_violation()
+        {
+            return RepositoryError::NotFound;
+        }
+        RepositoryError::unexpected(err.to_string())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+pub(crate) struct BookTagRecord {
+    pub book_id: i64,
+    pub tag: String,
+}
+
+/// Loads one user's tags for a batch of books, ordered by book then tag.
+pub(crate) async fn fetch_tags_for_books(
+    pool: &DatabasePool,
+    user_id: UserId,
+    book_ids: &[i64],
+) -> Result<Vec<BookTagRecord>, RepositoryError> {
+    if book_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut qb = QueryBuilder::new("SELECT book_id, tag FROM book_tags WHERE user_id = ");
+    qb.push_bind(user_id.into_inner());
+    qb.push(" AND book_id IN (");
+    let mut sep = qb.separated(", ");
+    for id in book_ids {
+        sep.push_bind(*id);
+    }
+    sep.push_unseparated(") ORDER BY book_id, tag");
+
+    qb.build_query_as::<BookTagRecord>()
+        .fetch_all(pool)
+        .await
+        .map_err(|err| RepositoryError::unexpected(err.to_string()))
+}
+
+#[async_trait]
+impl TagRepository for SqlTagRepository {
+    async fn list_for_book(
+        &self,
+        user_id: UserId,
+        book_id: BookId,
+    ) -> Result<Vec<String>, RepositoryError> {
+        query_scalar("SELECT tag FROM book_tags WHERE user_id = ? AND book_id = ? ORDER BY tag")
+            .bind(user_id.into_inner())
+            .bind(book_id.into_inner())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))
+    }
+
+    async fn set_for_book(
+        &self,
+        user_id: UserId,
+        book_id: BookId,
+        tags: &[String],
+    ) -> Result<(), RepositoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        query("DELETE FROM book_tags WHERE user_id = ? AND book_id = ?")
+            .bind(user_id.into_inner())
+            .bind(book_id.into_inner())
+            .execute(&mut *tx)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        for tag in tags {
+            query("INSERT OR IGNORE INTO book_tags (user_id, book_id, tag) VALUES (?, ?, ?)")
+                .bind(user_id.into_inner())
+                .bind(book_id.into_inner())
+                .bind(tag)
+                .execute(&mut *tx)
+                .await
+                .map_err(Self::book_error)?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))
+    }
+
+    async fn add(
+        &self,
+        user_id: UserId,
+        book_id: BookId,
+        tag: &str,
+    ) -> Result<(), RepositoryError> {
+        query("INSERT OR IGNORE INTO book_tags (user_id, book_id, tag) VALUES (?, ?, ?)")
+            .bind(user_id.into_inner())
+            .bind(book_id.into_inner())
+            .bind(tag)
+            .execute(&self.pool)
+            .await
+            .map_err(Self::book_error)?;
+
+        Ok(())
+    }
+
+    async fn remove(
+        &self,
+        user_id: UserId,
+        book_id: BookId,
+        tag: &str,
+    ) -> Result<(), RepositoryError> {
+        let result = query("DELETE FROM book_tags WHERE user_id = ? AND book_id = ? AND tag = ?")
+            .bind(user_id.into_inner())
+            .bind(book_id.into_inner())
+            .bind(tag)
+            .execute(&self.pool)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn counts_for_user(
+        &self,
+        user_id: UserId,
+        prefix: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<Vec<TagCount>, RepositoryError> {
+        let records = query_as::<_, TagCountRecord>(
+            r"SELECT tag, COUNT(*) AS count
+              FROM book_tags
+              WHERE user_id = ? AND (? IS NULL OR substr(tag, 1, length(?)) = ?)
+              GROUP BY tag
+              ORDER BY count DESC, tag ASC
+              LIMIT ?",
+        )
+        .bind(user_id.into_inner())
+        .bind(prefix)
+        .bind(prefix)
+        .bind(prefix)
+        .bind(limit.map_or(-1, i64::from))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+
+        Ok(records
+            .into_iter()
+            .map(|record| TagCount {
+                tag: record.tag,
+                count: record.count,
+            })
+            .collect())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct TagCountRecord {
+    tag: String,
+    count: i64,
+}
@@ -8,6 +8,7 @@ use sqlx::{QueryBuilder, query_as};
 use crate::domain::RepositoryError;
 use crate::domain::book_items::{Book, BookWithAuthors};
 use crate::domain::books::readings::ReadingStatus;
+use crate::domain::filters::FilterQuery;
 use crate::domain::ids::{BookId, GenreId, UserBookId, UserId};
 use crate::domain::listing::{ListRequest, Page};
 use crate::domain::repositories::UserBookRepository;
@@ -15,6 +16,8 @@ use crate::domain::user_books::{
     NewUserBook, ReadingSummary, Shelf, UserBook, UserBookSortKey, UserBookWithDetails,
 };
 use crate::infrastructure::database::DatabasePool;
+use crate::infrastructure::repositories::filters::{FilterScope, TermFilter};
+use crate::infrastructure::repositories::pagination::{ListFilters, SearchFilter};
 
 #[derive(Clone)]
 pub struct SqlUserBookRepository {
@@ -26,6 +29,24 @@ impl SqlUserBookRepository {
         Self { pool }
     }
 
+    fn query_filters(query: Option<&FilterQuery>) -> ListFilters<'_> {
+        let search_filter = query.and_then(|q| q.text.as_deref()).and_then(|t| {
+            SearchFilter::new(
+                t,
+                vec![
+                    "b.title",
+                    "COALESCE(b.publisher,'')",
+                    "COALESCE(pg.name,'')",
+                    "COALESCE(sg.name,'')",
+                    "COALESCE(b.isbn,'')",
+                ],
+            )
+        });
+        let terms =
+            query.and_then(|q| TermFilter::new(&q.terms, FilterScope::user_books("b", "ub")));
+        ListFilters::new(search_filter, terms)
+    }
+
     fn order_clause(request: &ListRequest<UserBookSortKey>) -> String {
         let dir_sql = request.sort_direction().as_sql();
 
@@ -65,6 +86,7 @@ impl SqlUserBookRepository {
     fn to_domain_with_details(
         record: UserBookWithDetailsRecord,
         author_records: &[super::book_authors::BookAuthorRecord],
+        tag_records: &[super::tags::BookTagRecord],
     ) -> Result<UserBookWithDetails, RepositoryError> {
         let shelf = Shelf::from_str(&record.shelf).map_err(|()| {
             RepositoryError::unexpected(format!("invalid shelf value: {}", record.shelf))
@@ -74,6 +96,11 @@ impl SqlUserBookRepository {
             .filter(|r| r.book_id == record.book_id)
             .map(super::book_authors::BookAuthorRecord::to_info)
             .collect();
+        let tags = tag_records
+            .iter()
+            .filter(|r| r.book_id == record.book_id)
+            .map(|r| r.tag.clone())
+            .collect();
 
         let reading_summary = match (record.reading_id, record.reading_status) {
             (Some(rid), Some(status_str)) => {
@@ -106,6 +133,7 @@ impl SqlUserBookRepository {
                     isbn: record.isbn,
                     description: record.description,
                     page_count: record.page_count,
+                    duration_minutes: record.duration_minutes,
                     year_published: record.year_published,
                     publisher: record.publisher,
                     language: record.language,
@@ -118,19 +146,26 @@ impl SqlUserBookRepository {
                 secondary_genre: record.secondary_genre,
             },
             reading_summary,
+            tags,
         })
     }
 
     async fn enrich_user_book_records(
         &self,
+        user_id: UserId,
         records: Vec<UserBookWithDetailsRecord>,
     ) -> Result<Vec<UserBookWithDetails>, RepositoryError> {
         let book_ids: Vec<i64> = records.iter().map(|r| r.book_id).collect();
         let author_records =
             super::book_authors::fetch_authors_for_books(&self.pool, &book_ids).await?;
+        let tag_records = super::tags::fetch_tags_for_books(&self.pool, user_id, &book_ids).await?;
         let mut items = Vec::with_capacity(records.len());
         for record in records {
-            items.push(Self::to_domain_with_details(record, &author_records)?);
+            items.push(Self::to_domain_with_details(
+                record,
+                &author_records,
+                &tag_records,
+            )?);
         }
         Ok(items)
     }
@@ -140,18 +175,16 @@ impl SqlUserBookRepository {
         &self,
         user_id: UserId,
         shelf: Option<&Shelf>,
-        search_filter: Option<&crate::infrastructure::repositories::pagination::SearchFilter>,
+        filters: &ListFilters<'_>,
     ) -> Result<i64, RepositoryError> {
-        use crate::infrastructure::repositories::pagination::push_search_condition;
-
-        let count_base = if search_filter.is_some() {
+        let count_base = if filters.is_empty() {
+            "SELECT COUNT(*) FROM user_books ub WHERE ub.user_id = "
+        } else {
             r"SELECT COUNT(*) FROM user_books ub
                JOIN books b ON b.id = ub.book_id
                LEFT JOIN genres pg ON pg.id = b.primary_genre_id
                LEFT JOIN genres sg ON sg.id = b.secondary_genre_id
                WHERE ub.user_id = "
-        } else {
-            "SELECT COUNT(*) FROM user_books ub WHERE ub.user_id = "
         };
         let mut builder = QueryBuilder::new(count_base);
         builder.push_bind(user_id.into_inner());
@@ -159,9 +192,7 @@ impl SqlUserBookRepository {
             builder.push(" AND ub.shelf = ");
             builder.push_bind(s.as_str().to_string());
         }
-        if let Some(sf) = search_filter {
-            push_search_condition(&mut builder, sf, true);
-        }
+        filters.push_conditions(&mut builder, true);
         let (total,): (i64,) = builder
             .build_query_as()
             .fetch_one(&self.pool)
@@ -172,7 +203,7 @@ impl SqlUserBookRepository {
 }
 
 const USER_BOOKS_BASE_SELECT: &str = r"SELECT ub.id, ub.user_id, ub.book_id, ub.shelf, ub.book_club, ub.created_at AS ub_created_at,
-                      b.title, b.isbn, b.description, b.page_count, b.year_published,
+                      b.title, b.isbn, b.description, b.page_count, b.duration_minutes, b.year_published,
                       b.publisher, b.language, b.primary_genre_id, b.secondary_genre_id,
                       pg.name AS primary_genre, sg.name AS secondary_genre,
                       b.created_at AS book_created_at,
@@ -264,26 +295,12 @@ impl UserBookRepository for SqlUserBookRepository {
         user_id: UserId,
         shelf: Option<Shelf>,
         request: &ListRequest<UserBookSortKey>,
-        search: Option<&str>,
+        query: Option<&FilterQuery>,
     ) -> Result<Page<UserBookWithDetails>, RepositoryError> {
         use crate::domain::listing::PageSize;
-        use crate::infrastructure::repositories::pagination::{
-            SearchFilter, push_search_condition,
-        };
 
         let order_clause = Self::order_clause(request);
-        let search_filter = search.and_then(|t| {
-            SearchFilter::new(
-                t,
-                vec![
-                    "b.title",
-                    "COALESCE(b.publisher,'')",
-                    "COALESCE(pg.name,'')",
-                    "COALESCE(sg.name,'')",
-                    "COALESCE(b.isbn,'')",
-                ],
-            )
-        });
+        let filters = Self::query_filters(query);
 
         let push_filters =
             |builder: &mut QueryBuilder<crate::infrastructure::database::DatabaseDriver>| {
@@ -297,9 +314,7 @@ impl UserBookRepository for SqlUserBookRepository {
         let build_data_query = |limit_offset: Option<(i64, i64)>| {
             let mut builder = QueryBuilder::new(USER_BOOKS_BASE_SELECT);
             push_filters(&mut builder);
-            if let Some(sf) = &search_filter {
-                push_search_condition(&mut builder, sf, true);
-            }
+            filters.push_conditions(&mut builder, true);
             builder.push(" ORDER BY ");
             builder.push(&order_clause);
             if let Some((limit, offset)) = limit_offset {
@@ -319,14 +334,14 @@ impl UserBookRepository for SqlUserBookRepository {
                     .await
                     .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
 
-                let items = self.enrich_user_book_records(records).await?;
+                let items = self.enrich_user_book_records(user_id, records).await?;
                 let total = items.len() as u64;
                 let size = total.min(u64::from(u32::MAX)) as u32;
                 Ok(Page::new(items, 1, size.max(1), total, true))
             }
             PageSize::Limited(page_size) => {
                 let total = self
-                    .count_user_books(user_id, shelf.as_ref(), search_filter.as_ref())
+                    .count_user_books(user_id, shelf.as_ref(), &filters)
                     .await?;
 
                 let limit = i64::from(page_size);
@@ -341,7 +356,7 @@ impl UserBookRepository for SqlUserBookRepository {
                         .await
                         .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
 
-                let items = self.enrich_user_book_records(records).await?;
+                let items = self.enrich_user_book_records(user_id, records).await?;
                 Ok(Page::new(
                     items,
                     current_page,
@@ -400,6 +415,33 @@ impl UserBookRepository for SqlUserBookRepository {
         Ok(())
     }
 
+    async fn matches_query(
+        &self,
+        user_id: UserId,
+        book_id: BookId,
+        query: &FilterQuery,
+    ) -> Result<bool, RepositoryError> {
+        let filters = Self::query_filters(Some(query));
+        let mut builder = QueryBuilder::new(
+            r"SELECT EXISTS(SELECT 1 FROM user_books ub
+               JOIN books b ON b.id = ub.book_id
+               LEFT JOIN genres pg ON pg.id = b.primary_genre_id
+               LEFT JOIN genres sg ON sg.id = b.secondary_genre_id
+               WHERE ub.user_id = ",
+        );
+        builder.push_bind(user_id.into_inner());
+        builder.push(" AND ub.book_id = ");
+        builder.push_bind(book_id.into_inner());
+        filters.push_conditions(&mut builder, true);
+        builder.push(")");
+        let (matches,): (bool,) = builder
+            .build_query_as()
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
+        Ok(matches)
+    }
+
     #[allow(clippy::similar_names)] // self vs shelf
     async fn book_ids_for_user(
         &self,
@@ -449,6 +491,7 @@ struct UserBookWithDetailsRecord {
     isbn: Option<String>,
     description: Option<String>,
     page_count: Option<i32>,
+    duration_minutes: Option<i32>,
     year_published: Option<i32>,
     publisher: Option<String>,
     language: Option<String>,
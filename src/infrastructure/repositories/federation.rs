@@ -0,0 +1,324 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{AssertSqlSafe, query_as};
+
+use crate::domain::RepositoryError;
+use crate::domain::federation::{
+    Actor, Follower, FollowerStatus, NewActor, NewFollower, NewOutboxActivity, OutboxActivity,
+};
+use crate::domain::ids::{FollowerId, ReadingId, UserId};
+use crate::domain::repositories::FederationRepository;
+use crate::infrastructure::database::DatabasePool;
+
+const ACTOR_COLUMNS: &str =
+    "user_id, origin, public_key_pem, private_key_pem, manually_approves_followers, created_at";
+const FOLLOWER_COLUMNS: &str =
+    "id, user_id, actor_uri, inbox, shared_inbox, follow_activity, status, created_at";
+const ACTIVITY_COLUMNS: &str = "user_id, reading_id, activity, published_at";
+
+#[derive(Clone)]
+pub struct SqlFederationRepository {
+    pool: DatabasePool,
+}
+
+impl SqlFederationRepository {
+    pub fn new(pool: DatabasePool) -> Self {
+        Self { pool }
+    }
+
+    fn follower_to_domain(record: FollowerRecord) -> Result<Follower, RepositoryError> {
+        let status = record.status.parse::<FollowerStatus>().map_err(|()| {
+            RepositoryError::unexpected(format!("unknown follower status: {}", record.status))
+        })?;
+
+        Ok(Follower {
+            id: FollowerId::from(record.id),
+            user_id: UserId::from(record.user_id),
+            actor_uri: record.actor_uri,
+            inbox: record.inbox,
+            shared_inbox: record.shared_inbox,
+            follow_activity: record.follow_activity,
+            status,
+            created_at: record.created_at,
+        })
+    }
+}
+
+fn unexpected(err: sqlx::Error) -> RepositoryError {
+    RepositoryError::unexpected(err.to_string())
+}
+
+fn not_found_unless_affected(
+    result: sqlx::sqlite::SqliteQueryResult,
+) -> Result<(), RepositoryError> {
+    if result.rows_affected() == 0 {
+        return Err(RepositoryError::NotFound);
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl FederationRepository for SqlFederationRepository {
+    async fn insert_actor(&self, actor: NewActor) -> Result<Actor, RepositoryError> {
+        let query = format!(
+            "INSERT INTO activitypub_actors (user_id, origin, public_key_pem, private_key_pem, manually_approves_followers)
+             VALUES (?, ?, ?, ?, ?) RETURNING {ACTOR_COLUMNS}"
+        );
+
+        let record = query_as::<_, ActorRecord>(AssertSqlSafe(query))
+            .bind(i64::from(actor.user_id))
+            .bind(&actor.origin)
+            .bind(&actor.public_key_pem)
+            .bind(&actor.private_key_pem)
+            .bind(actor.manually_approves_followers)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|err| {
+                if let sqlx::Error::Database(db_err) = &err
+                    && db_err.is_unique_violation()
+                {
+                    return RepositoryError::conflict("user already federates");
+                }
+                unexpected(err)
+            })?;
+
+        Ok(record.into())
+    }
+
+    async fn get_actor(&self, user_id: UserId) -> Result<Actor, RepositoryError> {
+        let query = format!("SELECT {ACTOR_COLUMNS} FROM activitypub_actors WHERE user_id = ?");
+
+        let record = query_as::<_, ActorRecord>(AssertSqlSafe(query))
+            .bind(i64::from(user_id))
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(unexpected)?
+            .ok_or(RepositoryError::NotFound)?;
+
+        Ok(record.into())
+    }
+
+    async fn set_manually_approves_followers(
+        &self,
+        user_id: UserId,
+        manual: bool,
+    ) -> Result<(), RepositoryError> {
+        let result = sqlx::query(
+            "UPDATE activitypub_actors SET manually_approves_followers = ? WHERE user_id = ?",
+        )
+        .bind(manual)
+        .bind(i64::from(user_id))
+        .execute(&self.pool)
+        .await
+        .map_err(unexpected)?;
+
+        not_found_unless_affected(result)
+    }
+
+    async fn delete_actor(&self, user_id: UserId) -> Result<(), RepositoryError> {
+        let result = sqlx::query("DELETE FROM activitypub_actors WHERE user_id = ?")
+            .bind(i64::from(user_id))
+            .execute(&self.pool)
+            .await
+            .map_err(unexpected)?;
+
+        not_found_unless_affected(result)
+    }
+
+    async fn upsert_follower(&self, follower: NewFollower) -> Result<Follower, RepositoryError> {
+        let query = format!(
+            "INSERT INTO activitypub_followers (user_id, actor_uri, inbox, shared_inbox, follow_activity, status)
+             VALUES (?, ?, ?, ?, ?, ?)
+             ON CONFLICT(user_id, actor_uri) DO UPDATE SET
+                inbox = excluded.inbox,
+                shared_inbox = excluded.shared_inbox,
+                follow_activity = excluded.follow_activity,
+                status = excluded.status
+             RETURNING {FOLLOWER_COLUMNS}"
+        );
+
+        let record = query_as::<_, FollowerRecord>(AssertSqlSafe(query))
+            .bind(i64::from(follower.user_id))
+            .bind(&follower.actor_uri)
+            .bind(&follower.inbox)
+            .bind(&follower.shared_inbox)
+            .bind(&follower.follow_activity)
+            .bind(follower.status.as_str())
+            .fetch_one(&self.pool)
+            .await
+            .map_err(unexpected)?;
+
+        Self::follower_to_domain(record)
+    }
+
+    async fn get_follower(&self, id: FollowerId) -> Result<Follower, RepositoryError> {
+        let query = format!("SELECT {FOLLOWER_COLUMNS} FROM activitypub_followers WHERE id = ?");
+
+        let record = query_as::<_, FollowerRecord>(AssertSqlSafe(query))
+            .bind(i64::from(id))
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(unexpected)?
+            .ok_or(RepositoryError::NotFound)?;
+
+        Self::follower_to_domain(record)
+    }
+
+    async fn list_followers(&self, user_id: UserId) -> Result<Vec<Follower>, RepositoryError> {
+        let query = format!(
+            "SELECT {FOLLOWER_COLUMNS} FROM activitypub_followers WHERE user_id = ? ORDER BY created_at, id"
+        );
+
+        let records = query_as::<_, FollowerRecord>(AssertSqlSafe(query))
+            .bind(i64::from(user_id))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(unexpected)?;
+
+        records.into_iter().map(Self::follower_to_domain).collect()
+    }
+
+    async fn set_follower_status(
+        &self,
+        id: FollowerId,
+        status: FollowerStatus,
+    ) -> Result<(), RepositoryError> {
+        let result = sqlx::query("UPDATE activitypub_followers SET status = ? WHERE id = ?")
+            .bind(status.as_str())
+            .bind(i64::from(id))
+            .execute(&self.pool)
+            .await
+            .map_err(unexpected)?;
+
+        not_found_unless_affected(result)
+    }
+
+    async fn delete_follower(&self, id: FollowerId) -> Result<(), RepositoryError> {
+        let result = sqlx::query("DELETE FROM activitypub_followers WHERE id = ?")
+            .bind(i64::from(id))
+            .execute(&self.pool)
+            .await
+            .map_err(unexpected)?;
+
+        not_found_unless_affected(result)
+    }
+
+    async fn delete_follower_by_actor(
+        &self,
+        user_id: UserId,
+        actor_uri: &str,
+    ) -> Result<(), RepositoryError> {
+        let result =
+            sqlx::query("DELETE FROM activitypub_followers WHERE user_id = ? AND actor_uri = ?")
+                .bind(i64::from(user_id))
+                .bind(actor_uri)
+                .execute(&self.pool)
+                .await
+                .map_err(unexpected)?;
+
+        not_found_unless_affected(result)
+    }
+
+    async fn insert_activity(
+        &self,
+        activity: NewOutboxActivity,
+    ) -> Result<Option<OutboxActivity>, RepositoryError> {
+        let query = format!(
+            "INSERT INTO activitypub_outbox (user_id, reading_id, activity) VALUES (?, ?, ?)
+             ON CONFLICT(user_id, reading_id) DO NOTHING
+             RETURNING {ACTIVITY_COLUMNS}"
+        );
+
+        let record = query_as::<_, ActivityRecord>(AssertSqlSafe(query))
+            .bind(i64::from(activity.user_id))
+            .bind(i64::from(activity.reading_id))
+            .bind(&activity.activity)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(unexpected)?;
+
+        Ok(record.map(Into::into))
+    }
+
+    async fn list_activities(
+        &self,
+        user_id: UserId,
+        limit: i64,
+    ) -> Result<Vec<OutboxActivity>, RepositoryError> {
+        let query = format!(
+            "SELECT {ACTIVITY_COLUMNS} FROM activitypub_outbox WHERE user_id = ?
+             ORDER BY published_at DESC, id DESC LIMIT ?"
+        );
+
+        let records = query_as::<_, ActivityRecord>(AssertSqlSafe(query))
+            .bind(i64::from(user_id))
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(unexpected)?;
+
+        Ok(records.into_iter().map(Into::into).collect())
+    }
+
+    async fn count_activities(&self, user_id: UserId) -> Result<i64, RepositoryError> {
+        sqlx::query_scalar("SELECT COUNT(*) FROM activitypub_outbox WHERE user_id = ?")
+            .bind(i64::from(user_id))
+            .fetch_one(&self.pool)
+            .await
+            .map_err(unexpected)
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct ActorRecord {
+    user_id: i64,
+    origin: String,
+    public_key_pem: String,
+    private_key_pem: String,
+    manually_approves_followers: bool,
+    created_at: DateTime<Utc>,
+}
+
+impl From<ActorRecord> for Actor {
+    fn from(record: ActorRecord) -> Self {
+        Actor {
+            user_id: UserId::from(record.user_id),
+            origin: record.origin,
+            public_key_pem: record.public_key_pem,
+            private_key_pem: record.private_key_pem,
+            manually_approves_followers: record.manually_approves_followers,
+            created_at: record.created_at,
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct FollowerRecord {
+    id: i64,
+    user_id: i64,
+    actor_uri: String,
+    inbox: String,
+    shared_inbox: Option<String>,
+    follow_activity: String,
+    status: String,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(sqlx::FromRow)]
+struct ActivityRecord {
+    user_id: i64,
+    reading_id: i64,
+    activity: String,
+    published_at: DateTime<Utc>,
+}
+
+impl From<ActivityRecord> for OutboxActivity {
+    fn from(record: ActivityRecord) -> Self {
+        OutboxActivity {
+            user_id: UserId::from(record.user_id),
+            reading_id: ReadingId::from(record.reading_id),
+            activity: record.activity,
+            published_at: record.published_at,
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use sqlx::QueryBuilder;
+
+use crate::domain::filters::{FilterField, FilterOp, FilterTerm, FilterValue};
+use crate::infrastructure::database::DatabaseDriver;
+
+/// Where reading-level fields (status, rating, dates, …) are found for a query.
+#[derive(Debug, Clone, Copy)]
+pub enum ReadingScope {
+    /// The query has no reading rows; reading fields are rejected at parse time.
+    None,
+    /// The query selects readings directly under this alias.
+    Direct(&'static str),
+    /// The query selects user books under this alias; a term matches when any
+    /// of the user's readings of that book matches.
+    UserBook(&'static str),
+}
+
+/// Table aliases used when compiling filter terms against a base query.
+#[derive(Debug, Clone, Copy)]
+pub struct FilterScope {
+    pub book: &'static str,
+    pub readings: ReadingScope,
+}
+
+impl FilterScope {
+    pub const fn books(book: &'static str) -> Self {
+        Self {
+            book,
+            readings: ReadingScope::None,
+        }
+    }
+
+    pub const fn readings(book: &'static str, reading: &'static str) -> Self {
+        Self {
+            book,
+            readings: ReadingScope::Direct(reading),
+        }
+    }
+
+    pub const fn user_books(book: &'static str, user_book: &'static str) -> Self {
+        Self {
+            book,
+            readings: ReadingScope::UserBook(user_book),
+        }
+    }
+}
+
+/// Structured filter terms paired with the aliases they compile against.
+pub struct TermFilter<'a> {
+    pub terms: &'a [FilterTerm],
+    pub scope: FilterScope,
+}
+
+impl<'a> TermFilter<'a> {
+    pub fn new(terms: &'a [FilterTerm], scope: FilterScope) -> Option<Self> {
+        if terms.is_empty() {
+            None
+        } else {
+            Some(Self { terms, scope })
+        }
+    }
+}
+
+/// Appends one `AND`-joined condition per term. Returns whether the builder
+/// now has a `WHERE` clause.
+pub(crate) fn push_term_conditions(
+    qb: &mut QueryBuilder<DatabaseDriver>,
+    filter: &TermFilter<'_>,
+    has_where: bool,
+) -> bool {
+    let mut has_where = has_where;
+    for term in filter.terms {
+        qb.push(if has_where { " AND " } else { " WHERE " });
+        has_where = true;
+        if term.negated {
+            qb.push("NOT COALESCE((");
+            push_term(qb, term, filter.scope);
+            qb.push("), 0)");
+        } else {
+            push_term(qb, term, filter.scope);
+        }
+    }
+    has_where
+}
+
+fn push_term(qb: &mut QueryBuilder<DatabaseDriver>, term: &FilterTerm, scope: FilterScope) {
+    let b = scope.book;
+    match term.field {
+        FilterField::Title => push_text(qb, &format!("{b}.title"), term),
+        FilterField::Publisher => push_text(qb, &format!("COALESCE({b}.publisher, '')"), term),
+        FilterField::Language => push_text(qb, &format!("COALESCE({b}.language, '')"), term),
+        FilterField::Isbn => push_text(qb, &format!("COALESCE({b}.isbn, '')"), term),
+        FilterField::Author => {
+            qb.push(format!(
+                "EXISTS (SELECT 1 FROM book_authors fba JOIN authors fa ON fa.id = fba.author_id WHERE fba.book_id = {b}.id AND "
+            ));
+            push_text(qb, "fa.name", term);
+            qb.push(")");
+        }
+        FilterField::Genre => {
+            qb.push(format!(
+                "EXISTS (SELECT 1 FROM genres fg WHERE fg.id IN ({b}.primary_genre_id, {b}.secondary_genre_id) AND "
+            ));
+            push_text(qb, "fg.name", term);
+            qb.push(")");
+        }
+        FilterField::Pages => push_number(qb, &format!("{b}.page_count"), term),
+        FilterField::Year => push_number(qb, &format!("{b}.year_published"), term),
+        FilterField::Club => match scope.readings {
+            ReadingScope::UserBook(ub) => push_bool(qb, &format!("{ub}.book_club"), term),
+            _ => {
+                qb.push("0");
+            }
+        },
+        FilterField::Shelf => match scope.readings {
+            ReadingScope::UserBook(ub) => push_enum(qb, &format!("{ub}.shelf"), term),
+            _ => {
+                qb.push("0");
+            }
+        },
+        FilterField::Tag => match scope.readings {
+            ReadingScope::Direct(owner) | ReadingScope::UserBook(owner) => {
+                qb.push(format!(
+                    "EXISTS (SELECT 1 FROM book_tags ft WHERE ft.book_id = {b}.id AND ft.user_id = {owner}.user_id AND "
+                ));
+                push_text(qb, "ft.tag", term);
+                qb.push(")");
+            }
+            ReadingScope::None => {
+                qb.push("0");
+            }
+        },
+        FilterField::Status
+        | FilterField::Format
+        | FilterField::Rating
+        | FilterField::Started
+        | FilterField::Finished => match scope.readings {
+            ReadingScope::Direct(r) => push_reading_term(qb, r, term),
+            ReadingScope::UserBook(ub) => {
+                qb.push(format!(
+                    "EXISTS (SELECT 1 FROM readings fr WHERE fr.book_id = {ub}.book_id AND fr.user_id = {ub}.user_id AND "
+                ));
+                push_reading_term(qb, "fr", term);
+                qb.push(")");
+            }
+            ReadingScope::None => {
+                qb.push("0");
+            }
+        },
+    }
+}
+
+fn push_reading_term(qb: &mut QueryBuilder<DatabaseDriver>, r: &str, term: &FilterTerm) {
+    match term.field {
+        FilterField::Status => push_enum(qb, &format!("{r}.status"), term),
+        FilterField::Format => push_enum(qb, &format!("{r}.format"), term),
+        FilterField::Rating => push_number(qb, &format!("{r}.rating"), term),
+        FilterField::Started => push_period(qb, &format!("{r}.started_at"), term),
+        FilterField::Finished => push_period(qb, &format!("{r}.finished_at"), term),
+        _ => {
+            qb.push("0");
+        }
+    }
+}
+
+fn comparison(op: FilterOp) -> &'static str {
+    match op {
+        FilterOp::Matches | FilterOp::Eq => " = ",
+        FilterOp::Gt => " > ",
+        FilterOp::Gte => " >= ",
+        FilterOp::Lt => " < ",
+        FilterOp::Lte => " <= ",
+    }
+}
+
+fn push_text(qb: &mut QueryBuilder<DatabaseDriver>, column: &str, term: &FilterTerm) {
+    let FilterValue::Text(value) = &term.value else {
+        qb.push("0");
+        return;
+    };
+    qb.push("LOWER(");
+    qb.push(column);
+    if term.op == FilterOp::Eq {
+        qb.push(") = ");
+        qb.push_bind(value.clone());
+    } else {
+        qb.push(") LIKE ");
+        qb.push_bind(format!("%{}%", escape_like(value)));
+        qb.push(" ESCAPE '\\'");
+    }
+}
+
+/// Escapes LIKE wildcards so `%` and `_` in a value match literally.
+pub(crate) fn escape_like(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn push_number(qb: &mut QueryBuilder<DatabaseDriver>, column: &str, term: &FilterTerm) {
+    let FilterValue::Number(value) = term.value else {
+        qb.push("0");
+        return;
+    };
+    qb.push(column);
+    qb.push(comparison(term.op));
+    qb.push_bind(value);
+}
+
+fn push_enum(qb: &mut QueryBuilder<DatabaseDriver>, column: &str, term: &FilterTerm) {
+    let value = match &term.value {
+        FilterValue::Status(status) => status.as_str(),
+        FilterValue::Format(format) => format.as_str(),
+        FilterValue::Shelf(shelf) => shelf.as_str(),
+        _ => {
+            qb.push("0");
+            return;
+        }
+    };
+    qb.push(column);
+    qb.push(" = ");
+    qb.push_bind(value.to_string());
+}
+
+fn push_bool(qb: &mut QueryBuilder<DatabaseDriver>, column: &str, term: &FilterTerm) {
+    let FilterValue::Bool(value) = term.value else {
+        qb.push("0");
+        return;
+    };
+    qb.push(column);
+    qb.push(" = ");
+    qb.push_bind(value);
+}
+
+/// Compares a date column against the period a term covers: `:`/`=` match
+/// anything inside it, `>` anything after it, `>=` anything from its start,
+/// and so on.
+fn push_period(qb: &mut QueryBuilder<DatabaseDriver>, column: &str, term: &FilterTerm) {
+    let FilterValue::Period { start, end } = term.value else {
+        qb.push("0");
+        return;
+    };
+    qb.push(column);
+    match term.op {
+        FilterOp::Matches | FilterOp::Eq => {
+            qb.push(" BETWEEN ");
+            qb.push_bind(start);
+            qb.push(" AND ");
+            qb.push_bind(end);
+        }
+        FilterOp::Gt => {
+            qb.push(" > ");
+            qb.push_bind(end);
+        }
+        FilterOp::Gte => {
+            qb.push(" >= ");
+            qb.push_bind(start);
+        }
+        FilterOp::Lt => {
+            qb.push(" < ");
+            qb.push_bind(start);
+        }
+        FilterOp::Lte => {
+            qb.push(" <= ");
+            qb.push_bind(end);
+        }
+    }
+}
@@ -1,10 +1,11 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use async_trait::async_trait;
-use sqlx::{QueryBuilder, query, query_as};
+use sqlx::{QueryBuilder, query, query_as, query_scalar};
 
 use crate::domain::RepositoryError;
-use crate::domain::images::EntityImage;
+use crate::domain::ids::ImageUploadId;
+use crate::domain::images::{EntityImage, ThumbnailInfo};
 use crate::domain::repositories::ImageRepository;
 use crate::infrastructure::database::DatabasePool;
 
@@ -165,4 +166,74 @@ impl ImageRepository for SqlImageRepository {
 
         Ok(rows.into_iter().map(|(id,)| id).collect())
     }
+
+    async fn thumbnail_info(
+        &self,
+        entity_type: &str,
+        entity_ids: &[i64],
+    ) -> Result<HashMap<i64, ThumbnailInfo>, RepositoryError> {
+        if entity_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut qb = QueryBuilder::new(
+            r"SELECT entity_id, content_type, length(thumbnail_data) FROM entity_images WHERE entity_type = ",
+        );
+        qb.push_bind(entity_type);
+        qb.push(" AND entity_id IN (");
+        let mut sep = qb.separated(", ");
+        for id in entity_ids {
+            sep.push_bind(*id);
+        }
+        sep.push_unseparated(")");
+
+        let rows: Vec<(i64, String, i64)> = qb
+            .build_query_as()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::unexpected(e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, content_type, length)| {
+                let length = usize::try_from(length).unwrap_or_default();
+                (
+                    id,
+                    ThumbnailInfo {
+                        content_type,
+                        length,
+                    },
+                )
+            })
+            .collect())
+    }
+
+    async fn stage_upload(&self, data_url: &str) -> Result<ImageUploadId, RepositoryError> {
+        let id: i64 = query_scalar("INSERT INTO image_uploads (data_url) VALUES (?) RETURNING id")
+            .bind(data_url)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::unexpected(e.to_string()))?;
+
+        Ok(ImageUploadId::new(id))
+    }
+
+    async fn get_upload(&self, id: ImageUploadId) -> Result<String, RepositoryError> {
+        query_scalar("SELECT data_url FROM image_uploads WHERE id = ?")
+            .bind(id.into_inner())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::unexpected(e.to_string()))?
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    async fn delete_upload(&self, id: ImageUploadId) -> Result<(), RepositoryError> {
+        query("DELETE FROM image_uploads WHERE id = ?")
+            .bind(id.into_inner())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::unexpected(e.to_string()))?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,256 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{query, query_as, query_scalar};
+
+use crate::domain::RepositoryError;
+use crate::domain::ids::JobId;
+use crate::domain::jobs::{Job, JobQueueHealth, JobStatus, NewJob};
+use crate::domain::repositories::JobRepository;
+use crate::infrastructure::database::DatabasePool;
+
+#[derive(Clone)]
+pub struct SqlJobRepository {
+    pool: DatabasePool,
+}
+
+impl SqlJobRepository {
+    pub fn new(pool: DatabasePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct JobRecord {
+    id: i64,
+    kind: String,
+    payload: String,
+    dedupe_key: Option<String>,
+    status: String,
+    attempts: i64,
+    max_attempts: i64,
+    run_at: DateTime<Utc>,
+    last_error: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl JobRecord {
+    fn into_domain(self) -> Result<Job, RepositoryError> {
+        let status = self.status.parse::<JobStatus>().map_err(|()| {
+            RepositoryError::unexpected(format!("unknown job status: {}", self.status))
+        })?;
+        Ok(Job {
+            id: JobId::new(self.id),
+            kind: self.kind,
+            payload: self.payload,
+            dedupe_key: self.dedupe_key,
+            status,
+            attempts: u32::try_from(self.attempts).unwrap_or(0),
+            max_attempts: u32::try_from(self.max_attempts).unwrap_or(0),
+            run_at: self.run_at,
+            last_error: self.last_error,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        })
+    }
+}
+
+/// Timestamps are stored in the same format as the `strftime` column
+/// defaults so they compare correctly as text.
+fn format_timestamp(at: DateTime<Utc>) -> String {
+    at.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+}
+
+fn now_timestamp() -> String {
+    format_timestamp(Utc::now())
+}
+
+fn db_err(err: sqlx::Error) -> RepositoryError {
+    RepositoryError::unexpected(err.to_string())
+}
+
+#[async_trait]
+impl JobRepository for SqlJobRepository {
+    async fn enqueue(&self, job: NewJob) -> Result<bool, RepositoryError> {
+        let result = query(
+            r"INSERT INTO jobs (kind, payload, dedupe_key, max_attempts, run_at)
+               VALUES (?, ?, ?, ?, ?)
+               ON CONFLICT(dedupe_key) WHERE status = 'pending' AND dedupe_key IS NOT NULL
+               DO NOTHING",
+        )
+        .bind(&job.kind)
+        .bind(&job.payload)
+        .bind(&job.dedupe_key)
+        .bind(i64::from(job.max_attempts))
+        .bind(format_timestamp(job.run_at))
+        .execute(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn claim_due(&self, now: DateTime<Utc>, limit: i64) -> Result<Vec<Job>, RepositoryError> {
+        let records = query_as::<_, JobRecord>(
+            r"UPDATE jobs SET status = 'running', attempts = attempts + 1, updated_at = ?
+               WHERE id IN (
+                   SELECT id FROM jobs WHERE status = 'pending' AND run_at <= ?
+                   AND (dedupe_key IS NULL OR dedupe_key NOT IN (
+                       SELECT dedupe_key FROM jobs
+                       WHERE status = 'running' AND dedupe_key IS NOT NULL
+                   ))
+                   ORDER BY run_at, id LIMIT ?
+               )
+               RETURNING id, kind, payload, dedupe_key, status, attempts, max_attempts,
+                   run_at, last_error, created_at, updated_at",
+        )
+        .bind(now_timestamp())
+        .bind(format_timestamp(now))
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        let mut jobs = records
+            .into_iter()
+            .map(JobRecord::into_domain)
+            .collect::<Result<Vec<_>, _>>()?;
+        jobs.sort_by_key(|job| (job.run_at, job.id.into_inner()));
+        Ok(jobs)
+    }
+
+    async fn complete(&self, id: JobId) -> Result<(), RepositoryError> {
+        query("DELETE FROM jobs WHERE id = ?")
+            .bind(id.into_inner())
+            .execute(&self.pool)
+            .await
+            .map_err(db_err)?;
+        Ok(())
+    }
+
+    async fn retry(
+        &self,
+        job: &Job,
+        run_at: DateTime<Utc>,
+        error: &str,
+    ) -> Result<(), RepositoryError> {
+        let mut tx = self.pool.begin().await.map_err(db_err)?;
+
+        let duplicate_pending = match &job.dedupe_key {
+            Some(key) => {
+                query_scalar::<_, i64>(
+                    "SELECT COUNT(*) FROM jobs WHERE dedupe_key = ? AND status = 'pending'",
+                )
+                .bind(key)
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(db_err)?
+                    > 0
+            }
+            None => false,
+        };
+
+        if duplicate_pending {
+            query("DELETE FROM jobs WHERE id = ?")
+                .bind(job.id.into_inner())
+                .execute(&mut *tx)
+                .await
+                .map_err(db_err)?;
+        } else {
+            query(
+                r"UPDATE jobs SET status = 'pending', run_at = ?, last_error = ?, updated_at = ?
+                   WHERE id = ?",
+            )
+            .bind(format_timestamp(run_at))
+            .bind(error)
+            .bind(now_timestamp())
+            .bind(job.id.into_inner())
+            .execute(&mut *tx)
+            .await
+            .map_err(db_err)?;
+        }
+
+        tx.commit().await.map_err(db_err)
+    }
+
+    async fn fail(&self, id: JobId, error: &str) -> Result<(), RepositoryError> {
+        query("UPDATE jobs SET status = 'failed', last_error = ?, updated_at = ? WHERE id = ?")
+            .bind(error)
+            .bind(now_timestamp())
+            .bind(id.into_inner())
+            .execute(&self.pool)
+            .await
+            .map_err(db_err)?;
+        Ok(())
+    }
+
+    async fn requeue_running(&self) -> Result<u64, RepositoryError> {
+        let mut tx = self.pool.begin().await.map_err(db_err)?;
+
+        // A pending duplicate already covers the interrupted work
+        query(
+            r"DELETE FROM jobs WHERE status = 'running' AND dedupe_key IN (
+                   SELECT dedupe_key FROM jobs WHERE status = 'pending'
+               )",
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(db_err)?;
+
+        let result = query(
+            r"UPDATE jobs SET status = 'pending', updated_at = ?
+               WHERE status = 'running'",
+        )
+        .bind(now_timestamp())
+        .execute(&mut *tx)
+        .await
+        .map_err(db_err)?;
+
+        tx.commit().await.map_err(db_err)?;
+        Ok(result.rows_affected())
+    }
+
+    async fn health(
+        &self,
+        now: DateTime<Utc>,
+        failure_limit: i64,
+    ) -> Result<JobQueueHealth, RepositoryError> {
+        let (pending, running, failed, overdue, oldest_pending_at) =
+            query_as::<_, (i64, i64, i64, i64, Option<DateTime<Utc>>)>(
+                r"SELECT
+                       COALESCE(SUM(status = 'pending'), 0),
+                       COALESCE(SUM(status = 'running'), 0),
+                       COALESCE(SUM(status = 'failed'), 0),
+                       COALESCE(SUM(status = 'pending' AND run_at <= ?), 0),
+                       MIN(CASE WHEN status = 'pending' THEN created_at END)
+                   FROM jobs",
+            )
+            .bind(format_timestamp(now))
+            .fetch_one(&self.pool)
+            .await
+            .map_err(db_err)?;
+
+        let recent_failures = query_as::<_, JobRecord>(
+            r"SELECT id, kind, payload, dedupe_key, status, attempts, max_attempts,
+                   run_at, last_error, created_at, updated_at
+               FROM jobs WHERE status = 'failed'
+               ORDER BY updated_at DESC, id DESC LIMIT ?",
+        )
+        .bind(failure_limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_err)?
+        .into_iter()
+        .map(JobRecord::into_domain)
+        .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(JobQueueHealth {
+            pending,
+            running,
+            failed,
+            overdue,
+            oldest_pending_at,
+            recent_failures,
+        })
+    }
+}
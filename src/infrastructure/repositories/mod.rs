@@ -2,11 +2,15 @@ pub mod analytics;
 pub mod auth;
 pub mod books;
 pub mod cover_suggestions;
+pub mod federation;
+pub mod filters;
 pub mod images;
+pub mod jobs;
 pub(crate) mod macros;
 pub mod pagination;
+pub mod webhooks;
 
 // Re-exports for backward compatibility
-pub use analytics::{ai_usage, stats, timeline_events};
-pub use auth::{passkey_credentials, registration_tokens, sessions, tokens, users};
-pub use books::{authors, books as book_repos, genres, readings, user_books};
+pub use analytics::{ai_usage, challenges, goals, stats, timeline_events};
+pub use auth::{feed_tokens, passkey_credentials, registration_tokens, sessions, tokens, users};
+pub use books::{authors, books as book_repos, genres, readings, shelves, tags, user_books};
@@ -3,11 +3,14 @@ use sqlx::{AssertSqlSafe, FromRow, QueryBuilder, query_scalar};
 use crate::domain::RepositoryError;
 use crate::domain::listing::{ListRequest, Page, PageSize, SortKey};
 use crate::infrastructure::database::{DatabaseDriver, DatabasePool, DatabaseRow};
+use crate::infrastructure::repositories::filters::{TermFilter, escape_like, push_term_conditions};
 
 /// Describes which columns to search and the term to match.
 pub struct SearchFilter {
     pub term: String,
     pub columns: Vec<&'static str>,
+    /// Columns holding JSON arrays of strings, matched element by element.
+    pub json_array_columns: Vec<&'static str>,
 }
 
 impl SearchFilter {
@@ -16,12 +19,64 @@ impl SearchFilter {
         if term.is_empty() {
             None
         } else {
-            Some(Self { term, columns })
+            Some(Self {
+                term,
+                columns,
+                json_array_columns: Vec::new(),
+            })
         }
     }
 
+    /// Also match any element of a JSON array column.
+    #[must_use]
+    pub fn with_json_array(mut self, column: &'static str) -> Self {
+        self.json_array_columns.push(column);
+        self
+    }
+
     fn like_pattern(&self) -> String {
-        format!("%{}%", self.term)
+        format!("%{}%", escape_like(&self.term))
+    }
+}
+
+/// Conditions applied to both the data and count queries of a paginated list.
+#[derive(Default)]
+pub struct ListFilters<'a> {
+    pub search: Option<SearchFilter>,
+    pub terms: Option<TermFilter<'a>>,
+}
+
+impl<'a> ListFilters<'a> {
+    pub fn new(search: Option<SearchFilter>, terms: Option<TermFilter<'a>>) -> Self {
+        Self { search, terms }
+    }
+
+    pub fn search(search: Option<SearchFilter>) -> Self {
+        Self {
+            search,
+            terms: None,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.search.is_none() && self.terms.is_none()
+    }
+
+    /// Appends all conditions to the builder, returning whether it now has a `WHERE` clause.
+    pub(crate) fn push_conditions(
+        &self,
+        qb: &mut QueryBuilder<DatabaseDriver>,
+        has_where: bool,
+    ) -> bool {
+        let mut has_where = has_where;
+        if let Some(terms) = &self.terms {
+            has_where = push_term_conditions(qb, terms, has_where);
+        }
+        if let Some(sf) = &self.search {
+            push_search_condition(qb, sf, has_where);
+            has_where = true;
+        }
+        has_where
     }
 }
 
@@ -31,7 +86,7 @@ pub async fn paginate<K, R, T, MapFn>(
     base_query: &str,
     count_query: &str,
     order_clause: &str,
-    search: Option<&SearchFilter>,
+    filters: &ListFilters<'_>,
     map_fn: MapFn,
 ) -> Result<Page<T>, RepositoryError>
 where
@@ -42,7 +97,7 @@ where
 {
     match request.page_size() {
         PageSize::All => {
-            let records = fetch_records::<R>(pool, base_query, order_clause, search, None).await?;
+            let records = fetch_records::<R>(pool, base_query, order_clause, filters, None).await?;
 
             let mut items = Vec::with_capacity(records.len());
             for record in records {
@@ -54,7 +109,7 @@ where
         }
         PageSize::Limited(page_size) => {
             let limit = i64::from(page_size);
-            let total = fetch_count(pool, count_query, search).await?;
+            let total = fetch_count(pool, count_query, filters).await?;
 
             // Clamp page to valid range before fetching
             let adjusted = (*request).ensure_page_within(total as u64);
@@ -65,7 +120,7 @@ where
                 pool,
                 base_query,
                 order_clause,
-                search,
+                filters,
                 Some((limit, offset)),
             )
             .await?;
@@ -84,16 +139,14 @@ async fn fetch_records<R>(
     pool: &DatabasePool,
     base_query: &str,
     order_clause: &str,
-    search: Option<&SearchFilter>,
+    filters: &ListFilters<'_>,
     limit_offset: Option<(i64, i64)>,
 ) -> Result<Vec<R>, RepositoryError>
 where
     R: for<'r> FromRow<'r, DatabaseRow> + Send + Unpin,
 {
     let mut qb = QueryBuilder::new(base_query);
-    if let Some(sf) = search {
-        append_search_condition(&mut qb, base_query, sf);
-    }
+    filters.push_conditions(&mut qb, has_where_clause(base_query));
     qb.push(" ORDER BY ");
     qb.push(order_clause);
     if let Some((limit, offset)) = limit_offset {
@@ -111,22 +164,22 @@ where
 async fn fetch_count(
     pool: &DatabasePool,
     count_query: &str,
-    search: Option<&SearchFilter>,
+    filters: &ListFilters<'_>,
 ) -> Result<i64, RepositoryError> {
-    if let Some(sf) = search {
+    if filters.is_empty() {
+        query_scalar(AssertSqlSafe(count_query))
+            .fetch_one(pool)
+            .await
+            .map_err(|err| RepositoryError::unexpected(err.to_string()))
+    } else {
         let mut qb = QueryBuilder::new(count_query);
-        append_search_condition(&mut qb, count_query, sf);
+        filters.push_conditions(&mut qb, has_where_clause(count_query));
         let row: (i64,) = qb
             .build_query_as()
             .fetch_one(pool)
             .await
             .map_err(|err| RepositoryError::unexpected(err.to_string()))?;
         Ok(row.0)
-    } else {
-        query_scalar(AssertSqlSafe(count_query))
-            .fetch_one(pool)
-            .await
-            .map_err(|err| RepositoryError::unexpected(err.to_string()))
     }
 }
 
@@ -147,15 +200,18 @@ pub(crate) fn push_search_condition(
         qb.push(*col);
         qb.push(") LIKE ");
         qb.push_bind(pattern.clone());
+        qb.push(" ESCAPE '\\'");
+    }
+    for col in &search.json_array_columns {
+        qb.push(" OR EXISTS (SELECT 1 FROM json_each(");
+        qb.push(*col);
+        qb.push(") element WHERE LOWER(element.value) LIKE ");
+        qb.push_bind(pattern.clone());
+        qb.push(" ESCAPE '\\')");
     }
     qb.push(")");
 }
 
-fn append_search_condition(
-    qb: &mut QueryBuilder<DatabaseDriver>,
-    base_sql: &str,
-    search: &SearchFilter,
-) {
-    let has_where = base_sql.to_uppercase().contains("WHERE");
-    push_search_condition(qb, search, has_where);
+fn has_where_clause(base_sql: &str) -> bool {
+    base_sql.to_uppercase().contains("WHERE")
 }
@@ -0,0 +1,256 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{AssertSqlSafe, QueryBuilder, query_as};
+
+use crate::domain::RepositoryError;
+use crate::domain::ids::{UserId, WebhookDeliveryId, WebhookId};
+use crate::domain::repositories::WebhookRepository;
+use crate::domain::webhooks::{
+    DeliveryAttempt, DeliveryStatus, NewWebhook, NewWebhookDelivery, Webhook, WebhookDelivery,
+};
+use crate::infrastructure::database::DatabasePool;
+
+const WEBHOOK_COLUMNS: &str = "id, user_id, url, secret, events, created_at";
+const DELIVERY_COLUMNS: &str = "id, webhook_id, event, payload, status, attempts, response_status, error, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct SqlWebhookRepository {
+    pool: DatabasePool,
+}
+
+impl SqlWebhookRepository {
+    pub fn new(pool: DatabasePool) -> Self {
+        Self { pool }
+    }
+
+    fn delivery_to_domain(record: DeliveryRecord) -> Result<WebhookDelivery, RepositoryError> {
+        let status = record.status.parse::<DeliveryStatus>().map_err(|()| {
+            RepositoryError::unexpected(format!("unknown delivery status: {}", record.status))
+        })?;
+
+        Ok(WebhookDelivery {
+            id: WebhookDeliveryId::from(record.id),
+            webhook_id: WebhookId::from(record.webhook_id),
+            event: record.event,
+            payload: record.payload,
+            status,
+            attempts: u32::try_from(record.attempts).unwrap_or(0),
+            response_status: record
+                .response_status
+                .and_then(|status| u16::try_from(status).ok()),
+            error: record.error,
+            created_at: record.created_at,
+            updated_at: record.updated_at,
+        })
+    }
+}
+
+fn unexpected(err: sqlx::Error) -> RepositoryError {
+    RepositoryError::unexpected(err.to_string())
+}
+
+#[async_trait]
+impl WebhookRepository for SqlWebhookRepository {
+    async fn insert(&self, webhook: NewWebhook) -> Result<Webhook, RepositoryError> {
+        let query = format!(
+            "INSERT INTO webhooks (user_id, url, secret, events) VALUES (?, ?, ?, ?)
+             RETURNING {WEBHOOK_COLUMNS}"
+        );
+
+        let record = query_as::<_, WebhookRecord>(AssertSqlSafe(query))
+            .bind(webhook.user_id.map(i64::from))
+            .bind(&webhook.url)
+            .bind(&webhook.secret)
+            .bind(webhook.events.join(","))
+            .fetch_one(&self.pool)
+            .await
+            .map_err(unexpected)?;
+
+        Ok(record.into())
+    }
+
+    async fn get(&self, id: WebhookId) -> Result<Webhook, RepositoryError> {
+        let query = format!("SELECT {WEBHOOK_COLUMNS} FROM webhooks WHERE id = ?");
+
+        let record = query_as::<_, WebhookRecord>(AssertSqlSafe(query))
+            .bind(i64::from(id))
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(unexpected)?
+            .ok_or(RepositoryError::NotFound)?;
+
+        Ok(record.into())
+    }
+
+    async fn list_for_user(
+        &self,
+        user_id: UserId,
+        include_global: bool,
+    ) -> Result<Vec<Webhook>, RepositoryError> {
+        let query = format!(
+            "SELECT {WEBHOOK_COLUMNS} FROM webhooks
+             WHERE user_id = ? OR (? AND user_id IS NULL)
+             ORDER BY id"
+        );
+
+        let records = query_as::<_, WebhookRecord>(AssertSqlSafe(query))
+            .bind(i64::from(user_id))
+            .bind(include_global)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(unexpected)?;
+
+        Ok(records.into_iter().map(Into::into).collect())
+    }
+
+    async fn list_receiving(&self, user_id: UserId) -> Result<Vec<Webhook>, RepositoryError> {
+        self.list_for_user(user_id, true).await
+    }
+
+    async fn delete(&self, id: WebhookId) -> Result<(), RepositoryError> {
+        let result = sqlx::query("DELETE FROM webhooks WHERE id = ?")
+            .bind(i64::from(id))
+            .execute(&self.pool)
+            .await
+            .map_err(unexpected)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn insert_delivery(
+        &self,
+        delivery: NewWebhookDelivery,
+    ) -> Result<WebhookDelivery, RepositoryError> {
+        let query = format!(
+            "INSERT INTO webhook_deliveries (webhook_id, event, payload) VALUES (?, ?, ?)
+             RETURNING {DELIVERY_COLUMNS}"
+        );
+
+        let record = query_as::<_, DeliveryRecord>(AssertSqlSafe(query))
+            .bind(i64::from(delivery.webhook_id))
+            .bind(&delivery.event)
+            .bind(&delivery.payload)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(unexpected)?;
+
+        Self::delivery_to_domain(record)
+    }
+
+    async fn get_delivery(
+        &self,
+        id: WebhookDeliveryId,
+    ) -> Result<WebhookDelivery, RepositoryError> {
+        let query = format!("SELECT {DELIVERY_COLUMNS} FROM webhook_deliveries WHERE id = ?");
+
+        let record = query_as::<_, DeliveryRecord>(AssertSqlSafe(query))
+            .bind(i64::from(id))
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(unexpected)?
+            .ok_or(RepositoryError::NotFound)?;
+
+        Self::delivery_to_domain(record)
+    }
+
+    async fn record_attempt(
+        &self,
+        id: WebhookDeliveryId,
+        attempt: DeliveryAttempt,
+    ) -> Result<(), RepositoryError> {
+        let result = sqlx::query(
+            "UPDATE webhook_deliveries SET
+                status = ?,
+                attempts = attempts + 1,
+                response_status = ?,
+                error = ?,
+                updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+             WHERE id = ?",
+        )
+        .bind(attempt.status.as_str())
+        .bind(attempt.response_status.map(i64::from))
+        .bind(&attempt.error)
+        .bind(i64::from(id))
+        .execute(&self.pool)
+        .await
+        .map_err(unexpected)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn list_recent_deliveries(
+        &self,
+        webhook_ids: &[WebhookId],
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        if webhook_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut builder = QueryBuilder::new(format!(
+            "SELECT {DELIVERY_COLUMNS} FROM webhook_deliveries WHERE webhook_id IN ("
+        ));
+        let mut separated = builder.separated(", ");
+        for id in webhook_ids {
+            separated.push_bind(i64::from(*id));
+        }
+        builder.push(") ORDER BY created_at DESC, id DESC LIMIT ");
+        builder.push_bind(limit);
+
+        let records = builder
+            .build_query_as::<DeliveryRecord>()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(unexpected)?;
+
+        records.into_iter().map(Self::delivery_to_domain).collect()
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct WebhookRecord {
+    id: i64,
+    user_id: Option<i64>,
+    url: String,
+    secret: String,
+    events: String,
+    created_at: DateTime<Utc>,
+}
+
+impl From<WebhookRecord> for Webhook {
+    fn from(record: WebhookRecord) -> Self {
+        Webhook {
+            id: WebhookId::from(record.id),
+            user_id: record.user_id.map(UserId::from),
+            url: record.url,
+            secret: record.secret,
+            events: record
+                .events
+                .split(',')
+                .filter(|event| !event.is_empty())
+                .map(str::to_string)
+                .collect(),
+            created_at: record.created_at,
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct DeliveryRecord {
+    id: i64,
+    webhook_id: i64,
+    event: String,
+    payload: String,
+    status: String,
+    attempts: i64,
+    response_status: Option<i64>,
+    error: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
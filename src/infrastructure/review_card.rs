@@ -0,0 +1,364 @@
+//! Renders a year in review as a shareable PNG card.
+//!
+//! Text is drawn with a built-in 5×7 bitmap font, scaled up, so the card
+//! needs nothing beyond the `image` crate. The font covers uppercase ASCII
+//! letters, digits and common punctuation; lowercase is drawn as uppercase
+//! and common accented letters lose their accents.
+
+use std::io::Cursor;
+
+use anyhow::Context;
+use image::imageops::FilterType;
+use image::{ImageFormat, Rgba, RgbaImage};
+
+use crate::domain::formatting::{format_number, format_rating};
+use crate::domain::review::YearInReview;
+
+/// Card width and height, square to suit most social feeds.
+pub const CARD_SIZE: u32 = 1080;
+
+const PADDING: u32 = 72;
+const COVER_WIDTH: u32 = 160;
+const COVER_HEIGHT: u32 = 240;
+const COVER_GAP: u32 = 24;
+const GLYPH_WIDTH: u32 = 5;
+const GLYPH_HEIGHT: u32 = 7;
+
+const BACKGROUND: Rgba<u8> = Rgba([28, 25, 23, 255]);
+const PLACEHOLDER: Rgba<u8> = Rgba([68, 64, 60, 255]);
+const ACCENT: Rgba<u8> = Rgba([245, 158, 11, 255]);
+const TEXT: Rgba<u8> = Rgba([250, 250, 249, 255]);
+const MUTED: Rgba<u8> = Rgba([168, 162, 158, 255]);
+
+/// A book cover for the card, in the order of `YearInReview::featured_books`.
+/// `thumbnail` holds encoded image bytes; books without one get a placeholder.
+pub struct CardCover {
+    pub title: String,
+    pub thumbnail: Option<Vec<u8>>,
+}
+
+/// Draws the card and encodes it as PNG.
+pub fn render_review_card(review: &YearInReview, covers: &[CardCover]) -> anyhow::Result<Vec<u8>> {
+    let mut card = RgbaImage::from_pixel(CARD_SIZE, CARD_SIZE, BACKGROUND);
+    let content_width = CARD_SIZE - 2 * PADDING;
+
+    draw_text(
+        &mut card,
+        &format!("My {} in books", review.year),
+        PADDING,
+        PADDING,
+        7,
+        ACCENT,
+    );
+    fill_rect(&mut card, PADDING, 160, 120, 8, ACCENT);
+
+    let half = CARD_SIZE / 2;
+    draw_text(
+        &mut card,
+        &format_number(review.books_read.cast_signed()),
+        PADDING,
+        210,
+        12,
+        TEXT,
+    );
+    draw_text(&mut card, "Books", PADDING, 310, 4, MUTED);
+    draw_text(
+        &mut card,
+        &format_number(review.pages_read),
+        half,
+        210,
+        12,
+        TEXT,
+    );
+    draw_text(&mut card, "Pages", half, 310, 4, MUTED);
+
+    for (i, cover) in covers.iter().take(5).enumerate() {
+        let x = PADDING + i as u32 * (COVER_WIDTH + COVER_GAP);
+        draw_cover(&mut card, cover, x, 380);
+    }
+
+    let mut y = 660;
+    for (label, value) in detail_lines(review) {
+        draw_text(&mut card, label, PADDING, y, 4, MUTED);
+        let value_x = PADDING + 12 * char_advance(4);
+        let value = fit_text(&value, 4, content_width - (value_x - PADDING));
+        draw_text(&mut card, &value, value_x, y, 4, TEXT);
+        y += 50;
+    }
+
+    let footer = "Booklog";
+    let footer_x = CARD_SIZE - PADDING - text_width(footer, 3);
+    draw_text(&mut card, footer, footer_x, CARD_SIZE - 60, 3, MUTED);
+
+    let mut png = Vec::new();
+    card.write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .context("failed to encode review card")?;
+    Ok(png)
+}
+
+/// Label and value pairs listed under the covers, skipping anything unknown.
+fn detail_lines(review: &YearInReview) -> Vec<(&'static str, String)> {
+    let chips = review
+        .top_quick_reviews
+        .iter()
+        .take(3)
+        .map(|(chip, _)| chip.label())
+        .collect::<Vec<_>>()
+        .join(", ");
+    [
+        ("Top author", review.top_author.clone()),
+        ("Top genre", review.top_genre.clone()),
+        (
+            "Longest",
+            review
+                .longest_book
+                .as_ref()
+                .map(|(title, pages)| format!("{title} ({pages} pages)")),
+        ),
+        (
+            "Avg rating",
+            review.average_rating.map(format_rating_tenths),
+        ),
+        (
+            "First",
+            review.first_finished.as_ref().map(|b| b.title.clone()),
+        ),
+        (
+            "Last",
+            review.last_finished.as_ref().map(|b| b.title.clone()),
+        ),
+        ("Felt", (!chips.is_empty()).then_some(chips)),
+    ]
+    .into_iter()
+    .filter_map(|(label, value)| value.map(|v| (label, v)))
+    .collect()
+}
+
+/// Averages aren't half-star values, so round them before formatting.
+fn format_rating_tenths(rating: f64) -> String {
+    format_rating((rating * 10.0).round() / 10.0)
+}
+
+fn draw_cover(card: &mut RgbaImage, cover: &CardCover, x: u32, y: u32) {
+    let decoded = cover
+        .thumbnail
+        .as_deref()
+        .and_then(|bytes| image::load_from_memory(bytes).ok());
+    if let Some(image) = decoded {
+        let resized = image
+            .resize_to_fill(COVER_WIDTH, COVER_HEIGHT, FilterType::Triangle)
+            .to_rgba8();
+        image::imageops::overlay(card, &resized, i64::from(x), i64::from(y));
+        return;
+    }
+
+    fill_rect(card, x, y, COVER_WIDTH, COVER_HEIGHT, PLACEHOLDER);
+    let initial: String = cover.title.chars().take(1).collect();
+    let initial_x = x + (COVER_WIDTH - text_width(&initial, 8)) / 2;
+    let initial_y = y + (COVER_HEIGHT - GLYPH_HEIGHT * 8) / 2;
+    draw_text(card, &initial, initial_x, initial_y, 8, TEXT);
+}
+
+fn fill_rect(card: &mut RgbaImage, x: u32, y: u32, width: u32, height: u32, color: Rgba<u8>) {
+    for py in y..(y + height).min(card.height()) {
+        for px in x..(x + width).min(card.width()) {
+            card.put_pixel(px, py, color);
+        }
+    }
+}
+
+fn char_advance(scale: u32) -> u32 {
+    (GLYPH_WIDTH + 1) * scale
+}
+
+fn text_width(text: &str, scale: u32) -> u32 {
+    let chars = text.chars().count() as u32;
+    (chars * char_advance(scale)).saturating_sub(scale)
+}
+
+/// Shortens `text` with a trailing "..." so it fits in `max_width` pixels.
+fn fit_text(text: &str, scale: u32, max_width: u32) -> String {
+    if text_width(text, scale) <= max_width {
+        return text.to_string();
+    }
+    let fits = ((max_width + scale) / char_advance(scale)) as usize;
+    let kept: String = text.chars().take(fits.saturating_sub(3)).collect();
+    format!("{}...", kept.trim_end())
+}
+
+fn draw_text(card: &mut RgbaImage, text: &str, x: u32, y: u32, scale: u32, color: Rgba<u8>) {
+    let mut cursor = x;
+    for c in text.chars() {
+        let rows = glyph(c);
+        for (row, bits) in rows.iter().enumerate() {
+            for col in 0..GLYPH_WIDTH {
+                if bits & (1 << (GLYPH_WIDTH - 1 - col)) != 0 {
+                    let px = cursor + col * scale;
+                    let py = y + row as u32 * scale;
+                    fill_rect(card, px, py, scale, scale, color);
+                }
+            }
+        }
+        cursor += char_advance(scale);
+    }
+}
+
+/// Folds a character onto one the font can draw.
+fn fold(c: char) -> char {
+    match c.to_ascii_uppercase() {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' => 'A',
+        'ç' | 'Ç' => 'C',
+        'è' | 'é' | 'ê' | 'ë' | 'È' | 'É' | 'Ê' | 'Ë' => 'E',
+        'ì' | 'í' | 'î' | 'ï' | 'Ì' | 'Í' | 'Î' | 'Ï' => 'I',
+        'ñ' | 'Ñ' => 'N',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' => 'O',
+        'ù' | 'ú' | 'û' | 'ü' | 'Ù' | 'Ú' | 'Û' | 'Ü' => 'U',
+        '‘' | '’' => '\'',
+        '–' | '—' => '-',
+        other => other,
+    }
+}
+
+/// Rows of a 5×7 glyph, top first, leftmost pixel in the high bit.
+#[allow(clippy::unreadable_literal, clippy::too_many_lines)] // glyph table
+fn glyph(c: char) -> [u8; 7] {
+    match fold(c) {
+        'A' => [
+            0b01110, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001,
+        ],
+        'B' => [
+            0b11110, 0b10001, 0b10001, 0b11110, 0b10001, 0b10001, 0b11110,
+        ],
+        'C' => [
+            0b01110, 0b10001, 0b10000, 0b10000, 0b10000, 0b10001, 0b01110,
+        ],
+        'D' => [
+            0b11110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b11110,
+        ],
+        'E' => [
+            0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b11111,
+        ],
+        'F' => [
+            0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000,
+        ],
+        'G' => [
+            0b01110, 0b10001, 0b10000, 0b10111, 0b10001, 0b10001, 0b01111,
+        ],
+        'H' => [
+            0b10001, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001,
+        ],
+        'I' => [
+            0b01110, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110,
+        ],
+        'J' => [
+            0b00111, 0b00010, 0b00010, 0b00010, 0b00010, 0b10010, 0b01100,
+        ],
+        'K' => [
+            0b10001, 0b10010, 0b10100, 0b11000, 0b10100, 0b10010, 0b10001,
+        ],
+        'L' => [
+            0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b11111,
+        ],
+        'M' => [
+            0b10001, 0b11011, 0b10101, 0b10101, 0b10001, 0b10001, 0b10001,
+        ],
+        'N' => [
+            0b10001, 0b10001, 0b11001, 0b10101, 0b10011, 0b10001, 0b10001,
+        ],
+        'O' => [
+            0b01110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110,
+        ],
+        'P' => [
+            0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000, 0b10000,
+        ],
+        'Q' => [
+            0b01110, 0b10001, 0b10001, 0b10001, 0b10101, 0b10010, 0b01101,
+        ],
+        'R' => [
+            0b11110, 0b10001, 0b10001, 0b11110, 0b10100, 0b10010, 0b10001,
+        ],
+        'S' => [
+            0b01111, 0b10000, 0b10000, 0b01110, 0b00001, 0b00001, 0b11110,
+        ],
+        'T' => [
+            0b11111, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100,
+        ],
+        'U' => [
+            0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110,
+        ],
+        'V' => [
+            0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100,
+        ],
+        'W' => [
+            0b10001, 0b10001, 0b10001, 0b10101, 0b10101, 0b10101, 0b01010,
+        ],
+        'X' => [
+            0b10001, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001, 0b10001,
+        ],
+        'Y' => [
+            0b10001, 0b10001, 0b01010, 0b00100, 0b00100, 0b00100, 0b00100,
+        ],
+        'Z' => [
+            0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b11111,
+        ],
+        '0' => [
+            0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110,
+        ],
+        '1' => [
+            0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110,
+        ],
+        '2' => [
+            0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111,
+        ],
+        '3' => [
+            0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110,
+        ],
+        '4' => [
+            0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010,
+        ],
+        '5' => [
+            0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110,
+        ],
+        '6' => [
+            0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110,
+        ],
+        '7' => [
+            0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000,
+        ],
+        '8' => [
+            0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110,
+        ],
+        '9' => [
+            0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100,
+        ],
+        '.' => [0, 0, 0, 0, 0, 0b01100, 0b01100],
+        ',' => [0, 0, 0, 0, 0b01100, 0b00100, 0b01000],
+        ':' => [0, 0b01100, 0b01100, 0, 0b01100, 0b01100, 0],
+        ';' => [0, 0b01100, 0b01100, 0, 0b01100, 0b00100, 0b01000],
+        '-' => [0, 0, 0, 0b11111, 0, 0, 0],
+        '\'' => [0b01100, 0b00100, 0b01000, 0, 0, 0, 0],
+        '"' => [0b01010, 0b01010, 0, 0, 0, 0, 0],
+        '!' => [0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0, 0b00100],
+        '?' => [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0, 0b00100],
+        '/' => [0, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0],
+        '&' => [
+            0b01100, 0b10010, 0b10100, 0b01000, 0b10101, 0b10010, 0b01101,
+        ],
+        '(' => [
+            0b00010, 0b00100, 0b01000, 0b01000, 0b01000, 0b00100, 0b00010,
+        ],
+        ')' => [
+            0b01000, 0b00100, 0b00010, 0b00010, 0b00010, 0b00100, 0b01000,
+        ],
+        '#' => [
+            0b01010, 0b01010, 0b11111, 0b01010, 0b11111, 0b01010, 0b01010,
+        ],
+        '+' => [0, 0b00100, 0b00100, 0b11111, 0b00100, 0b00100, 0],
+        '%' => [
+            0b11000, 0b11001, 0b00010, 0b00100, 0b01000, 0b10011, 0b00011,
+        ],
+        ' ' => [0; 7],
+        // Anything else is drawn as a small box rather than dropped.
+        _ => [0, 0b11111, 0b10001, 0b10001, 0b10001, 0b11111, 0],
+    }
+}
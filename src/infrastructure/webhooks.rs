@@ -0,0 +1,69 @@
+use std::fmt::Write as _;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use openssl::hash::MessageDigest;
+use openssl::pkey::PKey;
+use openssl::sign::Signer;
+
+pub const EVENT_HEADER: &str = "X-Booklog-Event";
+pub const DELIVERY_HEADER: &str = "X-Booklog-Delivery";
+pub const SIGNATURE_HEADER: &str = "X-Booklog-Signature";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// HMAC-SHA256 of `body` keyed with the webhook secret, as `sha256=<hex>`.
+pub fn signature(secret: &str, body: &[u8]) -> Result<String> {
+    let key = PKey::hmac(secret.as_bytes())?;
+    let mut signer = Signer::new(MessageDigest::sha256(), &key)?;
+    signer.update(body)?;
+    let digest = signer.sign_to_vec()?;
+
+    let mut signature = String::with_capacity(7 + digest.len() * 2);
+    signature.push_str("sha256=");
+    for byte in digest {
+        let _ = write!(signature, "{byte:02x}");
+    }
+    Ok(signature)
+}
+
+/// A webhook request ready to send.
+pub struct WebhookRequest<'a> {
+    pub url: &'a str,
+    pub secret: &'a str,
+    pub event: &'a str,
+    pub delivery_id: i64,
+    pub body: &'a str,
+}
+
+/// POST a signed JSON payload and return the receiver's status code.
+/// Only transport failures are errors; the caller judges the status.
+pub async fn deliver(client: &reqwest::Client, request: WebhookRequest<'_>) -> Result<u16> {
+    let signature = signature(request.secret, request.body.as_bytes())?;
+    let response = client
+        .post(request.url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header(EVENT_HEADER, request.event)
+        .header(DELIVERY_HEADER, request.delivery_id.to_string())
+        .header(SIGNATURE_HEADER, signature)
+        .body(request.body.to_string())
+        .timeout(REQUEST_TIMEOUT)
+        .send()
+        .await
+        .with_context(|| format!("failed to reach {}", request.url))?;
+    Ok(response.status().as_u16())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signature_matches_known_hmac() {
+        // RFC 4231 test case 2
+        let signature = signature("Jefe", b"what do ya want for nothing?").unwrap();
+        assert_eq!(
+            signature,
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+}
@@ -3,7 +3,8 @@ use booklog::application::{ServerConfig, serve};
 use booklog::infrastructure::backup::BackupData;
 use booklog::infrastructure::client::BooklogClient;
 use booklog::presentation::cli::{
-    Cli, Commands, ServeCommand, authors, books, genres, readings, timeline, tokens, user_books,
+    Cli, Commands, ServeCommand, authors, books, challenges, export, genres, goals, readings,
+    shelves, tags, timeline, tokens, user_books, webhooks,
 };
 use clap::Parser;
 use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
@@ -27,14 +28,34 @@ async fn main() -> Result<()> {
             let client = BooklogClient::from_base_url(&cli.api_url)?;
             books::run(&client, command).await
         }
+        Commands::Challenge { command } => {
+            let client = BooklogClient::from_base_url(&cli.api_url)?;
+            challenges::run(&client, command).await
+        }
+        Commands::Export { command } => {
+            let client = BooklogClient::from_base_url(&cli.api_url)?;
+            export::run(&client, command).await
+        }
         Commands::Genre { command } => {
             let client = BooklogClient::from_base_url(&cli.api_url)?;
             genres::run(&client, command).await
         }
+        Commands::Goal { command } => {
+            let client = BooklogClient::from_base_url(&cli.api_url)?;
+            goals::run(&client, command).await
+        }
         Commands::Reading { command } => {
             let client = BooklogClient::from_base_url(&cli.api_url)?;
             readings::run(&client, command).await
         }
+        Commands::Shelf { command } => {
+            let client = BooklogClient::from_base_url(&cli.api_url)?;
+            shelves::run(&client, command).await
+        }
+        Commands::Tag { command } => {
+            let client = BooklogClient::from_base_url(&cli.api_url)?;
+            tags::run(&client, command).await
+        }
         Commands::UserBook { command } => {
             let client = BooklogClient::from_base_url(&cli.api_url)?;
             user_books::run(&client, command).await
@@ -47,6 +68,10 @@ async fn main() -> Result<()> {
             let client = BooklogClient::from_base_url(&cli.api_url)?;
             timeline::run(&client, command).await
         }
+        Commands::Webhook { command } => {
+            let client = BooklogClient::from_base_url(&cli.api_url)?;
+            webhooks::run(&client, command).await
+        }
         Commands::Backup(_cmd) => {
             let client = BooklogClient::from_base_url(&cli.api_url)?;
             let data = client.backup().export().await?;
@@ -77,6 +102,10 @@ async fn run_server(command: ServeCommand) -> Result<()> {
         );
     }
 
+    if command.allow_private_network {
+        tracing::warn!("outgoing requests may reach private addresses - do not use in production");
+    }
+
     let openrouter_api_key = command.openrouter_api_key.unwrap_or_default();
 
     booklog::set_base_url(rp_origin.clone());
@@ -89,6 +118,7 @@ async fn run_server(command: ServeCommand) -> Result<()> {
         insecure_cookies,
         openrouter_api_key,
         openrouter_model: command.openrouter_model,
+        allow_private_network: command.allow_private_network,
     };
 
     serve(config).await
@@ -4,7 +4,7 @@ use clap::{Args, Subcommand};
 use super::macros::{define_delete_command, define_get_command};
 use super::parse_created_at;
 use super::print_json;
-use crate::domain::books::authors::{NewAuthor, UpdateAuthor};
+use crate::domain::books::authors::{AuthorDetails, NewAuthor, UpdateAuthor};
 use crate::domain::ids::AuthorId;
 use crate::infrastructure::client::BooklogClient;
 
@@ -36,6 +36,21 @@ pub async fn run(client: &BooklogClient, cmd: AuthorCommands) -> Result<()> {
 pub struct AddAuthorCommand {
     #[arg(long)]
     pub name: String,
+    /// Short biography
+    #[arg(long)]
+    pub bio: Option<String>,
+    #[arg(long)]
+    pub birth_year: Option<i32>,
+    #[arg(long)]
+    pub death_year: Option<i32>,
+    #[arg(long)]
+    pub nationality: Option<String>,
+    /// Official website (http or https)
+    #[arg(long)]
+    pub website: Option<String>,
+    /// Pen name or other name the author publishes under (repeatable)
+    #[arg(long = "alias")]
+    pub aliases: Vec<String>,
     /// Override creation timestamp (e.g. 2025-08-05T10:00:00Z or 2025-08-05)
     #[arg(long)]
     pub created_at: Option<String>,
@@ -48,6 +63,14 @@ pub async fn add_author(client: &BooklogClient, command: AddAuthorCommand) -> Re
         .transpose()?;
     let payload = NewAuthor {
         name: command.name,
+        details: AuthorDetails {
+            bio: command.bio,
+            birth_year: command.birth_year,
+            death_year: command.death_year,
+            nationality: command.nationality,
+            website: command.website,
+            aliases: command.aliases,
+        },
         created_at,
     };
 
@@ -68,6 +91,31 @@ pub struct UpdateAuthorCommand {
     pub id: i64,
     #[arg(long)]
     pub name: Option<String>,
+    /// Short biography (an empty value clears it)
+    #[arg(long)]
+    pub bio: Option<String>,
+    #[arg(long, conflicts_with = "clear_birth_year")]
+    pub birth_year: Option<i32>,
+    /// Remove the recorded birth year
+    #[arg(long)]
+    pub clear_birth_year: bool,
+    #[arg(long, conflicts_with = "clear_death_year")]
+    pub death_year: Option<i32>,
+    /// Remove the recorded death year
+    #[arg(long)]
+    pub clear_death_year: bool,
+    /// Nationality (an empty value clears it)
+    #[arg(long)]
+    pub nationality: Option<String>,
+    /// Official website (an empty value clears it)
+    #[arg(long)]
+    pub website: Option<String>,
+    /// Replace the author's aliases (repeatable)
+    #[arg(long = "alias", conflicts_with = "clear_aliases")]
+    pub aliases: Vec<String>,
+    /// Remove all aliases
+    #[arg(long)]
+    pub clear_aliases: bool,
     /// Override creation timestamp (e.g. 2025-08-05T10:00:00Z or 2025-08-05)
     #[arg(long)]
     pub created_at: Option<String>,
@@ -78,8 +126,20 @@ pub async fn update_author(client: &BooklogClient, command: UpdateAuthorCommand)
         .created_at
         .map(|s| parse_created_at(&s))
         .transpose()?;
+    let year = |value: Option<i32>, clear: bool| if clear { Some(None) } else { value.map(Some) };
+    let aliases = if command.clear_aliases {
+        Some(Vec::new())
+    } else {
+        Some(command.aliases).filter(|aliases| !aliases.is_empty())
+    };
     let payload = UpdateAuthor {
         name: command.name,
+        bio: command.bio,
+        birth_year: year(command.birth_year, command.clear_birth_year),
+        death_year: year(command.death_year, command.clear_death_year),
+        nationality: command.nationality,
+        website: command.website,
+        aliases,
         created_at,
     };
 
@@ -42,6 +42,9 @@ pub struct AddBookCommand {
     pub description: Option<String>,
     #[arg(long)]
     pub page_count: Option<i32>,
+    /// Audiobook running time in minutes
+    #[arg(long)]
+    pub duration_minutes: Option<i32>,
     #[arg(long)]
     pub year_published: Option<i32>,
     #[arg(long)]
@@ -77,6 +80,7 @@ pub async fn add_book(client: &BooklogClient, command: AddBookCommand) -> Result
         isbn: command.isbn,
         description: command.description,
         page_count: command.page_count,
+        duration_minutes: command.duration_minutes,
         year_published: command.year_published,
         publisher: command.publisher,
         language: command.language,
@@ -94,12 +98,18 @@ pub async fn add_book(client: &BooklogClient, command: AddBookCommand) -> Result
 pub struct ListBooksCommand {
     #[arg(long)]
     pub author_id: Option<i64>,
+    /// Filter query, e.g. 'genre:fantasy year>=2000 author:"Le Guin"'
+    #[arg(long)]
+    pub query: Option<String>,
 }
 
 pub async fn list_books(client: &BooklogClient, command: ListBooksCommand) -> Result<()> {
     let books = client
         .books()
-        .list(command.author_id.map(AuthorId::new))
+        .list(
+            command.author_id.map(AuthorId::new),
+            command.query.as_deref(),
+        )
         .await?;
     print_json(&books)
 }
@@ -118,6 +128,9 @@ pub struct UpdateBookCommand {
     pub description: Option<String>,
     #[arg(long)]
     pub page_count: Option<i32>,
+    /// Audiobook running time in minutes
+    #[arg(long)]
+    pub duration_minutes: Option<i32>,
     #[arg(long)]
     pub year_published: Option<i32>,
     #[arg(long)]
@@ -153,6 +166,7 @@ pub async fn update_book(client: &BooklogClient, command: UpdateBookCommand) ->
         isbn: command.isbn,
         description: command.description,
         page_count: command.page_count,
+        duration_minutes: command.duration_minutes,
         year_published: command.year_published,
         publisher: command.publisher,
         language: command.language,
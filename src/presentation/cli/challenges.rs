@@ -0,0 +1,226 @@
+use anyhow::{Result, anyhow};
+use clap::{Args, Subcommand};
+
+use super::macros::{define_delete_command, define_get_command};
+use super::print_json;
+use crate::domain::challenges::{NewChallengePrompt, UpdateChallenge};
+use crate::domain::ids::{ChallengeId, ChallengePromptId, ReadingId};
+use crate::infrastructure::client::BooklogClient;
+
+#[derive(Debug, Subcommand)]
+pub enum ChallengeCommands {
+    /// Add a new reading challenge
+    Add(AddChallengeCommand),
+    /// List challenges you can see, with your progress
+    List,
+    /// Get a challenge with your progress and suggested readings
+    Get(GetChallengeCommand),
+    /// Update a challenge
+    Update(UpdateChallengeCommand),
+    /// Delete a challenge
+    Delete(DeleteChallengeCommand),
+    /// Add a prompt to the end of a challenge
+    AddPrompt(AddPromptCommand),
+    /// Delete a prompt from a challenge
+    DeletePrompt(PromptCommand),
+    /// Fill a prompt with one of your finished readings
+    Assign(AssignCommand),
+    /// Clear the reading assigned to a prompt
+    Unassign(PromptCommand),
+}
+
+pub async fn run(client: &BooklogClient, cmd: ChallengeCommands) -> Result<()> {
+    match cmd {
+        ChallengeCommands::Add(c) => add_challenge(client, c).await,
+        ChallengeCommands::List => list_challenges(client).await,
+        ChallengeCommands::Get(c) => get_challenge(client, c).await,
+        ChallengeCommands::Update(c) => update_challenge(client, c).await,
+        ChallengeCommands::Delete(c) => delete_challenge(client, c).await,
+        ChallengeCommands::AddPrompt(c) => add_prompt(client, c).await,
+        ChallengeCommands::DeletePrompt(c) => delete_prompt(client, c).await,
+        ChallengeCommands::Assign(c) => assign(client, c).await,
+        ChallengeCommands::Unassign(c) => unassign(client, c).await,
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct AddChallengeCommand {
+    #[arg(long)]
+    pub name: String,
+    #[arg(long)]
+    pub description: Option<String>,
+    /// Only count readings finished in this year
+    #[arg(long)]
+    pub year: Option<i32>,
+    /// Make the challenge visible to every user on the instance
+    #[arg(long)]
+    pub shared: bool,
+    /// Prompt as "LABEL" or "LABEL=RULE" (repeatable), e.g. "Over 600 pages=pages>600"
+    #[arg(long = "prompt")]
+    pub prompts: Vec<String>,
+}
+
+/// Splits "LABEL=RULE" on the first `=`; rules may contain `>=` and `<=`.
+fn parse_prompt(value: &str) -> Result<NewChallengePrompt> {
+    let (label, rule) = match value.split_once('=') {
+        Some((label, rule)) => (label, Some(rule.to_string())),
+        None => (value, None),
+    };
+    if label.trim().is_empty() {
+        return Err(anyhow!(
+            "invalid prompt '{value}': expected LABEL or LABEL=RULE"
+        ));
+    }
+    Ok(NewChallengePrompt {
+        label: label.to_string(),
+        rule,
+    })
+}
+
+pub async fn add_challenge(client: &BooklogClient, command: AddChallengeCommand) -> Result<()> {
+    let prompts = command
+        .prompts
+        .iter()
+        .map(|p| parse_prompt(p))
+        .collect::<Result<Vec<_>>>()?;
+
+    let challenge = client
+        .challenges()
+        .create(
+            &command.name,
+            command.description.as_deref(),
+            command.year,
+            command.shared,
+            &prompts,
+        )
+        .await?;
+    print_json(&challenge)
+}
+
+pub async fn list_challenges(client: &BooklogClient) -> Result<()> {
+    let challenges = client.challenges().list().await?;
+    print_json(&challenges)
+}
+
+define_get_command!(GetChallengeCommand, get_challenge, ChallengeId, challenges);
+
+#[derive(Debug, Args)]
+pub struct UpdateChallengeCommand {
+    #[arg(long)]
+    pub id: i64,
+    #[arg(long)]
+    pub name: Option<String>,
+    /// New description (an empty value clears it)
+    #[arg(long)]
+    pub description: Option<String>,
+    #[arg(long, conflicts_with = "any_year")]
+    pub year: Option<i32>,
+    /// Count readings finished in any year
+    #[arg(long)]
+    pub any_year: bool,
+    #[arg(long)]
+    pub shared: Option<bool>,
+}
+
+pub async fn update_challenge(
+    client: &BooklogClient,
+    command: UpdateChallengeCommand,
+) -> Result<()> {
+    let payload = UpdateChallenge {
+        name: command.name,
+        description: command.description,
+        year: if command.any_year {
+            Some(None)
+        } else {
+            command.year.map(Some)
+        },
+        shared: command.shared,
+    };
+
+    let challenge = client
+        .challenges()
+        .update(ChallengeId::new(command.id), &payload)
+        .await?;
+    print_json(&challenge)
+}
+
+define_delete_command!(
+    DeleteChallengeCommand,
+    delete_challenge,
+    ChallengeId,
+    challenges,
+    "challenge"
+);
+
+#[derive(Debug, Args)]
+pub struct AddPromptCommand {
+    #[arg(long)]
+    pub id: i64,
+    #[arg(long)]
+    pub label: String,
+    /// Filter rule matched against finished readings, e.g. "year<1900"
+    #[arg(long)]
+    pub rule: Option<String>,
+}
+
+pub async fn add_prompt(client: &BooklogClient, command: AddPromptCommand) -> Result<()> {
+    let prompt = NewChallengePrompt {
+        label: command.label,
+        rule: command.rule,
+    };
+    let prompt = client
+        .challenges()
+        .add_prompt(ChallengeId::new(command.id), &prompt)
+        .await?;
+    print_json(&prompt)
+}
+
+#[derive(Debug, Args)]
+pub struct PromptCommand {
+    #[arg(long)]
+    pub id: i64,
+    #[arg(long)]
+    pub prompt_id: i64,
+}
+
+pub async fn delete_prompt(client: &BooklogClient, command: PromptCommand) -> Result<()> {
+    let id = ChallengeId::new(command.id);
+    client
+        .challenges()
+        .delete_prompt(id, ChallengePromptId::new(command.prompt_id))
+        .await?;
+    let challenge = client.challenges().get(id).await?;
+    print_json(&challenge)
+}
+
+#[derive(Debug, Args)]
+pub struct AssignCommand {
+    #[arg(long)]
+    pub id: i64,
+    #[arg(long)]
+    pub prompt_id: i64,
+    #[arg(long)]
+    pub reading_id: i64,
+}
+
+pub async fn assign(client: &BooklogClient, command: AssignCommand) -> Result<()> {
+    let entry = client
+        .challenges()
+        .assign(
+            ChallengeId::new(command.id),
+            ChallengePromptId::new(command.prompt_id),
+            ReadingId::new(command.reading_id),
+        )
+        .await?;
+    print_json(&entry)
+}
+
+pub async fn unassign(client: &BooklogClient, command: PromptCommand) -> Result<()> {
+    let id = ChallengeId::new(command.id);
+    client
+        .challenges()
+        .unassign(id, ChallengePromptId::new(command.prompt_id))
+        .await?;
+    let challenge = client.challenges().get(id).await?;
+    print_json(&challenge)
+}
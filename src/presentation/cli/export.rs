@@ -0,0 +1,185 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use clap::{Args, Subcommand};
+
+use crate::infrastructure::client::BooklogClient;
+use crate::presentation::export::obsidian::{self, NoteUpdate, VaultFile};
+use crate::presentation::export::site::{self, SiteFile};
+
+#[derive(Debug, Subcommand)]
+pub enum ExportCommands {
+    /// Render your library as a static HTML site
+    Site(ExportSiteCommand),
+    /// Write or update an Obsidian vault with a Markdown note per book and author
+    Obsidian(ExportObsidianCommand),
+}
+
+pub async fn run(client: &BooklogClient, cmd: ExportCommands) -> Result<()> {
+    match cmd {
+        ExportCommands::Site(c) => export_site(client, c).await,
+        ExportCommands::Obsidian(c) => export_obsidian(client, c).await,
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct ExportSiteCommand {
+    /// Directory to write the site to (created if missing)
+    pub dir: PathBuf,
+}
+
+pub async fn export_site(client: &BooklogClient, command: ExportSiteCommand) -> Result<()> {
+    let export = client.library().export().await?;
+
+    let mut covers = HashSet::new();
+    for entry in export.books.iter().filter(|entry| entry.has_cover) {
+        let book_id = entry.book.book.id;
+        if let Some(data) = client.library().cover(book_id, true).await? {
+            write_file(&command.dir, &site::cover_path(book_id), &data)?;
+            covers.insert(book_id);
+        }
+    }
+
+    let files = site::render_site(&export, &covers)?;
+    for SiteFile { path, contents } in &files {
+        write_file(&command.dir, path, contents.as_bytes())?;
+    }
+
+    eprintln!(
+        "Exported {} books and {} covers to {}",
+        export.books.len(),
+        covers.len(),
+        command.dir.display()
+    );
+    Ok(())
+}
+
+#[derive(Debug, Args)]
+pub struct ExportObsidianCommand {
+    /// Vault directory to write notes to (created if missing)
+    pub dir: PathBuf,
+}
+
+pub async fn export_obsidian(client: &BooklogClient, command: ExportObsidianCommand) -> Result<()> {
+    let export = client.library().export().await?;
+
+    let mut covers = HashSet::new();
+    for entry in export.books.iter().filter(|entry| entry.has_cover) {
+        let book_id = entry.book.book.id;
+        if let Some(data) = client.library().cover(book_id, false).await? {
+            let path = obsidian::cover_path(book_id);
+            if read_existing(&command.dir.join(&path))?.as_deref() != Some(data.as_slice()) {
+                write_file(&command.dir, &path, &data)?;
+            }
+            covers.insert(book_id);
+        }
+    }
+
+    // Read every note up front, since renamed notes can take each other's paths
+    let files = read_notes(&command.dir)?;
+    let notes = obsidian::render_vault(&export, &covers);
+    let mut written = HashSet::new();
+    let mut moved_from = Vec::new();
+    let (mut created, mut updated, mut unchanged) = (0, 0, 0);
+    for (note, file) in notes.iter().zip(obsidian::match_files(&notes, &files)) {
+        let moved = file.filter(|file| file.path != note.path);
+        let contents = match (note.merge(file.map(|file| file.contents.as_str())), moved) {
+            (NoteUpdate::Create(contents), _) => {
+                created += 1;
+                contents
+            }
+            (NoteUpdate::Update(contents), _) => {
+                updated += 1;
+                contents
+            }
+            // A note renamed without other changes still moves
+            (NoteUpdate::Unchanged, Some(file)) => {
+                updated += 1;
+                file.contents.clone()
+            }
+            (NoteUpdate::Unchanged, None) => {
+                unchanged += 1;
+                continue;
+            }
+            (NoteUpdate::MissingMarker, _) => {
+                eprintln!(
+                    "Skipped {}: notes marker not found, so it was left untouched",
+                    note.path
+                );
+                continue;
+            }
+        };
+        write_file(&command.dir, &note.path, contents.as_bytes())?;
+        written.insert(note.path.as_str());
+        if let Some(file) = moved {
+            moved_from.push(file.path.as_str());
+        }
+    }
+    // Notes that moved leave their old file behind unless another took it
+    let renamed = moved_from.len();
+    for path in moved_from
+        .into_iter()
+        .filter(|path| !written.contains(path))
+    {
+        let target = command.dir.join(path);
+        fs::remove_file(&target)
+            .with_context(|| format!("failed to remove {}", target.display()))?;
+    }
+
+    eprintln!(
+        "Vault {}: {created} created, {updated} updated ({renamed} renamed), {unchanged} unchanged",
+        command.dir.display()
+    );
+    Ok(())
+}
+
+/// The Markdown files in the vault's note directories.
+fn read_notes(dir: &Path) -> Result<Vec<VaultFile>> {
+    let mut files = Vec::new();
+    for note_dir in obsidian::NOTE_DIRS {
+        let entries = match fs::read_dir(dir.join(note_dir)) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(err) => {
+                return Err(err).with_context(|| format!("failed to read {note_dir}"));
+            }
+        };
+        for entry in entries {
+            let entry = entry.with_context(|| format!("failed to read {note_dir}"))?;
+            let file_path = entry.path();
+            if file_path.extension().is_none_or(|ext| ext != "md") {
+                continue;
+            }
+            let name = entry.file_name();
+            let Some(name) = name.to_str() else {
+                continue;
+            };
+            let path = format!("{note_dir}/{name}");
+            let contents = fs::read_to_string(&file_path)
+                .with_context(|| format!("failed to read {path} as UTF-8"))?;
+            files.push(VaultFile { path, contents });
+        }
+    }
+    Ok(files)
+}
+
+/// The file's bytes, or `None` if it does not exist yet.
+fn read_existing(path: &Path) -> Result<Option<Vec<u8>>> {
+    match fs::read(path) {
+        Ok(data) => Ok(Some(data)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display())),
+    }
+}
+
+/// Write `contents` to `path` under `dir`, creating parent directories.
+fn write_file(dir: &Path, path: &str, contents: &[u8]) -> Result<()> {
+    let target = dir.join(path);
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    fs::write(&target, contents).with_context(|| format!("failed to write {}", target.display()))
+}
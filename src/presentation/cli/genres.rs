@@ -36,6 +36,9 @@ pub async fn run(client: &BooklogClient, cmd: GenreCommands) -> Result<()> {
 pub struct AddGenreCommand {
     #[arg(long)]
     pub name: String,
+    /// Broader genre to file this one under
+    #[arg(long)]
+    pub parent_id: Option<i64>,
     /// Override creation timestamp (e.g. 2025-08-05T10:00:00Z or 2025-08-05)
     #[arg(long)]
     pub created_at: Option<String>,
@@ -48,6 +51,7 @@ pub async fn add_genre(client: &BooklogClient, command: AddGenreCommand) -> Resu
         .transpose()?;
     let payload = NewGenre {
         name: command.name,
+        parent_id: command.parent_id.map(GenreId::new),
         created_at,
     };
 
@@ -68,6 +72,12 @@ pub struct UpdateGenreCommand {
     pub id: i64,
     #[arg(long)]
     pub name: Option<String>,
+    /// Broader genre to file this one under
+    #[arg(long, conflicts_with = "clear_parent")]
+    pub parent_id: Option<i64>,
+    /// Move the genre back to the top level
+    #[arg(long)]
+    pub clear_parent: bool,
     /// Override creation timestamp (e.g. 2025-08-05T10:00:00Z or 2025-08-05)
     #[arg(long)]
     pub created_at: Option<String>,
@@ -78,8 +88,14 @@ pub async fn update_genre(client: &BooklogClient, command: UpdateGenreCommand) -
         .created_at
         .map(|s| parse_created_at(&s))
         .transpose()?;
+    let parent_id = if command.clear_parent {
+        Some(None)
+    } else {
+        command.parent_id.map(|id| Some(GenreId::new(id)))
+    };
     let payload = UpdateGenre {
         name: command.name,
+        parent_id,
         created_at,
     };
 
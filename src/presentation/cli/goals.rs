@@ -0,0 +1,174 @@
+use anyhow::{Result, anyhow};
+use chrono::NaiveDate;
+use clap::{Args, Subcommand};
+
+use super::macros::{define_delete_command, define_get_command};
+use super::print_json;
+use crate::domain::goals::{GoalMetric, UpdateReadingGoal, year_range};
+use crate::domain::ids::{GenreId, GoalId};
+use crate::domain::readings::ReadingFormat;
+use crate::infrastructure::client::BooklogClient;
+use crate::infrastructure::client::goals::NewGoalRequest;
+
+#[derive(Debug, Subcommand)]
+pub enum GoalCommands {
+    /// Set a reading goal for a year or a custom date range
+    Add(AddGoalCommand),
+    /// List your goals with progress
+    List,
+    /// Get a goal and its progress
+    Get(GetGoalCommand),
+    /// Update a goal
+    Update(UpdateGoalCommand),
+    /// Delete a goal
+    Delete(DeleteGoalCommand),
+}
+
+pub async fn run(client: &BooklogClient, cmd: GoalCommands) -> Result<()> {
+    match cmd {
+        GoalCommands::Add(c) => add_goal(client, c).await,
+        GoalCommands::List => list_goals(client).await,
+        GoalCommands::Get(c) => get_goal(client, c).await,
+        GoalCommands::Update(c) => update_goal(client, c).await,
+        GoalCommands::Delete(c) => delete_goal(client, c).await,
+    }
+}
+
+fn parse_metric(value: &str) -> Result<GoalMetric> {
+    value
+        .parse()
+        .map_err(|()| anyhow!("invalid metric '{value}': use books or pages"))
+}
+
+fn parse_format(value: &str) -> Result<ReadingFormat> {
+    value
+        .parse()
+        .map_err(|()| anyhow!("invalid format '{value}': use physical, ereader or audiobook"))
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| anyhow!("invalid date '{value}': use YYYY-MM-DD"))
+}
+
+#[derive(Debug, Args)]
+pub struct AddGoalCommand {
+    /// Number of books or pages to read
+    #[arg(long)]
+    pub target: i64,
+    /// What to count: books or pages
+    #[arg(long, default_value = "books")]
+    pub metric: String,
+    /// Calendar year the goal covers (defaults to the current year)
+    #[arg(long, conflicts_with_all = ["start", "end"])]
+    pub year: Option<i32>,
+    /// First day of a custom goal period (YYYY-MM-DD)
+    #[arg(long, requires = "end")]
+    pub start: Option<String>,
+    /// Last day of a custom goal period (YYYY-MM-DD)
+    #[arg(long, requires = "start")]
+    pub end: Option<String>,
+    /// Only count books in this genre or its subgenres
+    #[arg(long)]
+    pub genre_id: Option<i64>,
+    /// Only count readings in this format: physical, ereader or audiobook
+    #[arg(long)]
+    pub format: Option<String>,
+}
+
+pub async fn add_goal(client: &BooklogClient, command: AddGoalCommand) -> Result<()> {
+    let payload = NewGoalRequest {
+        metric: parse_metric(&command.metric)?,
+        target: command.target,
+        year: command.year,
+        start_date: command.start.as_deref().map(parse_date).transpose()?,
+        end_date: command.end.as_deref().map(parse_date).transpose()?,
+        genre_id: command.genre_id.map(GenreId::new),
+        format: command.format.as_deref().map(parse_format).transpose()?,
+    };
+
+    let goal = client.goals().create(&payload).await?;
+    print_json(&goal)
+}
+
+pub async fn list_goals(client: &BooklogClient) -> Result<()> {
+    let goals = client.goals().list().await?;
+    print_json(&goals)
+}
+
+define_get_command!(GetGoalCommand, get_goal, GoalId, goals);
+
+#[derive(Debug, Args)]
+pub struct UpdateGoalCommand {
+    #[arg(long)]
+    pub id: i64,
+    #[arg(long)]
+    pub target: Option<i64>,
+    /// What to count: books or pages
+    #[arg(long)]
+    pub metric: Option<String>,
+    /// Move the goal to cover this calendar year
+    #[arg(long, conflicts_with_all = ["start", "end"])]
+    pub year: Option<i32>,
+    /// New first day of the goal period (YYYY-MM-DD)
+    #[arg(long)]
+    pub start: Option<String>,
+    /// New last day of the goal period (YYYY-MM-DD)
+    #[arg(long)]
+    pub end: Option<String>,
+    #[arg(long, conflicts_with = "any_genre")]
+    pub genre_id: Option<i64>,
+    /// Count books from every genre
+    #[arg(long)]
+    pub any_genre: bool,
+    #[arg(long, conflicts_with = "any_format")]
+    pub format: Option<String>,
+    /// Count readings in any format
+    #[arg(long)]
+    pub any_format: bool,
+}
+
+pub async fn update_goal(client: &BooklogClient, command: UpdateGoalCommand) -> Result<()> {
+    let (start_date, end_date) = match command.year {
+        Some(year) => {
+            let (start, end) = year_range(year).ok_or_else(|| anyhow!("invalid year {year}"))?;
+            (Some(start), Some(end))
+        }
+        None => (
+            command.start.as_deref().map(parse_date).transpose()?,
+            command.end.as_deref().map(parse_date).transpose()?,
+        ),
+    };
+    let genre_id = if command.any_genre {
+        Some(None)
+    } else {
+        command.genre_id.map(|id| Some(GenreId::new(id)))
+    };
+    let format = if command.any_format {
+        Some(None)
+    } else {
+        command
+            .format
+            .as_deref()
+            .map(parse_format)
+            .transpose()?
+            .map(Some)
+    };
+
+    let payload = UpdateReadingGoal {
+        metric: command.metric.as_deref().map(parse_metric).transpose()?,
+        target: command.target,
+        start_date,
+        end_date,
+        genre_id,
+        format,
+    };
+
+    let goal = client
+        .goals()
+        .update(GoalId::new(command.id), &payload)
+        .await?;
+    print_json(&goal)
+}
+
+define_delete_command!(DeleteGoalCommand, delete_goal, GoalId, goals, "goal");
@@ -1,12 +1,18 @@
 pub mod authors;
 pub mod backup;
 pub mod books;
+pub mod challenges;
+pub mod export;
 pub mod genres;
+pub mod goals;
 mod macros;
 pub mod readings;
+pub mod shelves;
+pub mod tags;
 pub mod timeline;
 pub mod tokens;
 pub mod user_books;
+pub mod webhooks;
 
 use std::net::SocketAddr;
 
@@ -15,12 +21,18 @@ use chrono::{DateTime, NaiveDate, Utc};
 use authors::AuthorCommands;
 use backup::{BackupCommand, RestoreCommand};
 use books::BookCommands;
+use challenges::ChallengeCommands;
 use clap::{Args, Parser, Subcommand};
+use export::ExportCommands;
 use genres::GenreCommands;
+use goals::GoalCommands;
 use readings::ReadingCommands;
+use shelves::ShelfCommands;
+use tags::TagCommands;
 use timeline::TimelineCommands;
 use tokens::TokenCommands;
 use user_books::UserBookCommands;
+use webhooks::WebhookCommands;
 
 #[derive(Debug, Parser)]
 #[command(author, version, about = "Track books and build a personal library", long_about = None)]
@@ -54,18 +66,48 @@ pub enum Commands {
         command: BookCommands,
     },
 
+    /// Manage reading challenges and fill their prompts
+    Challenge {
+        #[command(subcommand)]
+        command: ChallengeCommands,
+    },
+
+    /// Export your library as a static site or Markdown notes
+    Export {
+        #[command(subcommand)]
+        command: ExportCommands,
+    },
+
     /// Manage genres
     Genre {
         #[command(subcommand)]
         command: GenreCommands,
     },
 
+    /// Manage reading goals and check progress
+    Goal {
+        #[command(subcommand)]
+        command: GoalCommands,
+    },
+
     /// Manage library entries
     Reading {
         #[command(subcommand)]
         command: ReadingCommands,
     },
 
+    /// Manage saved manual and smart shelves
+    Shelf {
+        #[command(subcommand)]
+        command: ShelfCommands,
+    },
+
+    /// Manage personal book tags
+    Tag {
+        #[command(subcommand)]
+        command: TagCommands,
+    },
+
     /// Manage library and wishlist
     #[command(name = "user-book")]
     UserBook {
@@ -85,6 +127,12 @@ pub enum Commands {
         command: TimelineCommands,
     },
 
+    /// Manage outgoing webhooks
+    Webhook {
+        #[command(subcommand)]
+        command: WebhookCommands,
+    },
+
     /// Back up all book data to JSON (stdout)
     Backup(BackupCommand),
 
@@ -126,6 +174,9 @@ pub struct ServeCommand {
         default_value = "openrouter/free"
     )]
     pub openrouter_model: String,
+
+    #[arg(long, env = "BOOKLOG_ALLOW_PRIVATE_NETWORK")]
+    pub allow_private_network: bool,
 }
 
 pub fn parse_created_at(value: &str) -> anyhow::Result<DateTime<Utc>> {
@@ -140,6 +191,20 @@ pub fn parse_created_at(value: &str) -> anyhow::Result<DateTime<Utc>> {
     )
 }
 
+/// Appends a `tag:` filter term per tag to an optional filter query.
+pub(crate) fn with_tag_terms(query: Option<String>, tags: &[String]) -> Option<String> {
+    let terms = tags
+        .iter()
+        .map(|tag| format!("tag=\"{tag}\""))
+        .collect::<Vec<_>>();
+    let parts: Vec<String> = query.into_iter().chain(terms).collect();
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join(" "))
+    }
+}
+
 pub(crate) fn print_json<T>(value: &T) -> anyhow::Result<()>
 where
     T: serde::Serialize,
@@ -3,7 +3,7 @@ use clap::{Args, Subcommand};
 
 use super::macros::{define_delete_command, define_get_command};
 use super::parse_created_at;
-use super::print_json;
+use super::{print_json, with_tag_terms};
 use crate::domain::books::readings::{
     NewReading, QuickReview, ReadingFormat, ReadingStatus, UpdateReading,
 };
@@ -52,6 +52,9 @@ pub struct AddReadingCommand {
     /// Comma-separated quick review labels (e.g. "loved-it,page-turner")
     #[arg(long)]
     pub quick_reviews: Option<String>,
+    /// Hide this reading from your public profile and other users' timelines
+    #[arg(long)]
+    pub private: bool,
     /// Override creation timestamp (e.g. 2025-08-05T10:00:00Z or 2025-08-05)
     #[arg(long)]
     pub created_at: Option<String>,
@@ -99,6 +102,7 @@ pub async fn add_reading(client: &BooklogClient, command: AddReadingCommand) ->
                     .collect()
             })
             .unwrap_or_default(),
+        private: command.private,
         created_at,
     };
 
@@ -110,12 +114,19 @@ pub async fn add_reading(client: &BooklogClient, command: AddReadingCommand) ->
 pub struct ListReadingsCommand {
     #[arg(long)]
     pub book_id: Option<i64>,
+    /// Filter query, e.g. 'status:read rating>=4 finished:2024 format:audiobook'
+    #[arg(long)]
+    pub query: Option<String>,
+    /// Only readings of books with this personal tag (repeatable; all must match)
+    #[arg(long = "tag")]
+    pub tags: Vec<String>,
 }
 
 pub async fn list_readings(client: &BooklogClient, command: ListReadingsCommand) -> Result<()> {
+    let query = with_tag_terms(command.query, &command.tags);
     let readings = client
         .readings()
-        .list(command.book_id.map(BookId::new))
+        .list(command.book_id.map(BookId::new), query.as_deref())
         .await?;
     print_json(&readings)
 }
@@ -140,6 +151,12 @@ pub struct UpdateReadingCommand {
     /// Comma-separated quick review labels (e.g. "loved-it,page-turner")
     #[arg(long)]
     pub quick_reviews: Option<String>,
+    /// Hide this reading from your public profile and other users' timelines (true/false)
+    #[arg(long)]
+    pub private: Option<bool>,
+    /// Page reached so far; passing 25%, 50% or 75% of the book records a milestone
+    #[arg(long)]
+    pub current_page: Option<i32>,
     /// Override creation timestamp (e.g. 2025-08-05T10:00:00Z or 2025-08-05)
     #[arg(long)]
     pub created_at: Option<String>,
@@ -185,6 +202,8 @@ pub async fn update_reading(client: &BooklogClient, command: UpdateReadingComman
                 .filter_map(|v| QuickReview::from_str_value(v.trim()))
                 .collect()
         }),
+        private: command.private,
+        current_page: command.current_page,
         created_at,
     };
 
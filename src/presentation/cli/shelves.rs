@@ -0,0 +1,182 @@
+use anyhow::{Result, anyhow};
+use clap::{Args, Subcommand};
+
+use super::macros::define_delete_command;
+use super::print_json;
+use crate::domain::ids::{BookId, ShelfId};
+use crate::domain::shelves::{ShelfKind, UpdateSavedShelf};
+use crate::infrastructure::client::BooklogClient;
+
+#[derive(Debug, Subcommand)]
+pub enum ShelfCommands {
+    /// Add a new manual or smart shelf
+    Add(AddShelfCommand),
+    /// List your shelves
+    List,
+    /// Get a shelf and the books on it
+    Get(GetShelfCommand),
+    /// Update a shelf
+    Update(UpdateShelfCommand),
+    /// Delete a shelf
+    Delete(DeleteShelfCommand),
+    /// Add a book to the end of a manual shelf
+    AddBook(ShelfBookCommand),
+    /// Remove a book from a manual shelf
+    RemoveBook(ShelfBookCommand),
+    /// Move a book to a new position on a manual shelf
+    MoveBook(MoveShelfBookCommand),
+}
+
+pub async fn run(client: &BooklogClient, cmd: ShelfCommands) -> Result<()> {
+    match cmd {
+        ShelfCommands::Add(c) => add_shelf(client, c).await,
+        ShelfCommands::List => list_shelves(client).await,
+        ShelfCommands::Get(c) => get_shelf(client, c).await,
+        ShelfCommands::Update(c) => update_shelf(client, c).await,
+        ShelfCommands::Delete(c) => delete_shelf(client, c).await,
+        ShelfCommands::AddBook(c) => add_book(client, c).await,
+        ShelfCommands::RemoveBook(c) => remove_book(client, c).await,
+        ShelfCommands::MoveBook(c) => move_book(client, c).await,
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct AddShelfCommand {
+    #[arg(long)]
+    pub name: String,
+    #[arg(long)]
+    pub description: Option<String>,
+    /// Shelf kind: manual or smart
+    #[arg(long, default_value = "manual")]
+    pub kind: String,
+    /// Filter query for smart shelves (e.g. "shelf:library -status:read pages<300")
+    #[arg(long)]
+    pub query: Option<String>,
+    /// Pin the shelf to the home page
+    #[arg(long)]
+    pub pinned: bool,
+    /// List the shelf on your public profile
+    #[arg(long)]
+    pub public: bool,
+}
+
+pub async fn add_shelf(client: &BooklogClient, command: AddShelfCommand) -> Result<()> {
+    let kind: ShelfKind = command
+        .kind
+        .parse()
+        .map_err(|()| anyhow!("invalid kind '{}': use manual or smart", command.kind))?;
+
+    let shelf = client
+        .shelves()
+        .create(
+            &command.name,
+            command.description.as_deref(),
+            kind,
+            command.query.as_deref(),
+            command.pinned,
+            command.public,
+        )
+        .await?;
+    print_json(&shelf)
+}
+
+pub async fn list_shelves(client: &BooklogClient) -> Result<()> {
+    let shelves = client.shelves().list().await?;
+    print_json(&shelves)
+}
+
+#[derive(Debug, Args)]
+pub struct GetShelfCommand {
+    #[arg(long)]
+    pub id: i64,
+}
+
+pub async fn get_shelf(client: &BooklogClient, command: GetShelfCommand) -> Result<()> {
+    let shelf = client.shelves().get(ShelfId::new(command.id)).await?;
+    print_json(&shelf)
+}
+
+#[derive(Debug, Args)]
+pub struct UpdateShelfCommand {
+    #[arg(long)]
+    pub id: i64,
+    #[arg(long)]
+    pub name: Option<String>,
+    /// New description (pass an empty string to clear)
+    #[arg(long)]
+    pub description: Option<String>,
+    /// New filter query (smart shelves only)
+    #[arg(long)]
+    pub query: Option<String>,
+    #[arg(long)]
+    pub pinned: Option<bool>,
+    #[arg(long)]
+    pub public: Option<bool>,
+}
+
+pub async fn update_shelf(client: &BooklogClient, command: UpdateShelfCommand) -> Result<()> {
+    let payload = UpdateSavedShelf {
+        name: command.name,
+        description: command.description,
+        query: command.query,
+        pinned: command.pinned,
+        public: command.public,
+    };
+
+    let shelf = client
+        .shelves()
+        .update(ShelfId::new(command.id), &payload)
+        .await?;
+    print_json(&shelf)
+}
+
+define_delete_command!(DeleteShelfCommand, delete_shelf, ShelfId, shelves, "shelf");
+
+#[derive(Debug, Args)]
+pub struct ShelfBookCommand {
+    #[arg(long)]
+    pub id: i64,
+    #[arg(long)]
+    pub book_id: i64,
+}
+
+pub async fn add_book(client: &BooklogClient, command: ShelfBookCommand) -> Result<()> {
+    let id = ShelfId::new(command.id);
+    client
+        .shelves()
+        .add_book(id, BookId::new(command.book_id))
+        .await?;
+    let shelf = client.shelves().get(id).await?;
+    print_json(&shelf)
+}
+
+pub async fn remove_book(client: &BooklogClient, command: ShelfBookCommand) -> Result<()> {
+    let id = ShelfId::new(command.id);
+    client
+        .shelves()
+        .remove_book(id, BookId::new(command.book_id))
+        .await?;
+    let shelf = client.shelves().get(id).await?;
+    print_json(&shelf)
+}
+
+#[derive(Debug, Args)]
+pub struct MoveShelfBookCommand {
+    #[arg(long)]
+    pub id: i64,
+    #[arg(long)]
+    pub book_id: i64,
+    /// Zero-based position on the shelf
+    #[arg(long)]
+    pub position: usize,
+}
+
+pub async fn move_book(client: &BooklogClient, command: MoveShelfBookCommand) -> Result<()> {
+    let id = ShelfId::new(command.id);
+    client
+        .shelves()
+        .move_book(id, BookId::new(command.book_id), command.position)
+        .await?;
+    let shelf = client.shelves().get(id).await?;
+    print_json(&shelf)
+}
@@ -0,0 +1,102 @@
+use anyhow::Result;
+use clap::{Args, Subcommand};
+
+use super::print_json;
+use crate::domain::ids::BookId;
+use crate::infrastructure::client::BooklogClient;
+
+#[derive(Debug, Subcommand)]
+pub enum TagCommands {
+    /// List your tags with the number of books carrying each
+    List(ListTagsCommand),
+    /// Show your tags on a book
+    Get(GetBookTagsCommand),
+    /// Replace your tags on a book
+    Set(SetBookTagsCommand),
+    /// Add a tag to a book
+    Add(BookTagCommand),
+    /// Remove a tag from a book
+    Remove(BookTagCommand),
+}
+
+pub async fn run(client: &BooklogClient, cmd: TagCommands) -> Result<()> {
+    match cmd {
+        TagCommands::List(c) => list_tags(client, c).await,
+        TagCommands::Get(c) => get_book_tags(client, c).await,
+        TagCommands::Set(c) => set_book_tags(client, c).await,
+        TagCommands::Add(c) => add_book_tag(client, c).await,
+        TagCommands::Remove(c) => remove_book_tag(client, c).await,
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct ListTagsCommand {
+    /// Only tags starting with this prefix
+    #[arg(long)]
+    pub prefix: Option<String>,
+    /// Maximum number of tags to return
+    #[arg(long)]
+    pub limit: Option<u32>,
+}
+
+pub async fn list_tags(client: &BooklogClient, command: ListTagsCommand) -> Result<()> {
+    let tags = client
+        .tags()
+        .list(command.prefix.as_deref(), command.limit)
+        .await?;
+    print_json(&tags)
+}
+
+#[derive(Debug, Args)]
+pub struct GetBookTagsCommand {
+    #[arg(long)]
+    pub book_id: i64,
+}
+
+pub async fn get_book_tags(client: &BooklogClient, command: GetBookTagsCommand) -> Result<()> {
+    let tags = client.tags().for_book(BookId::new(command.book_id)).await?;
+    print_json(&tags)
+}
+
+#[derive(Debug, Args)]
+pub struct SetBookTagsCommand {
+    #[arg(long)]
+    pub book_id: i64,
+    /// Tag to keep on the book (repeatable); omit to clear all tags
+    #[arg(long = "tag")]
+    pub tags: Vec<String>,
+}
+
+pub async fn set_book_tags(client: &BooklogClient, command: SetBookTagsCommand) -> Result<()> {
+    let tags = client
+        .tags()
+        .set(BookId::new(command.book_id), &command.tags)
+        .await?;
+    print_json(&tags)
+}
+
+#[derive(Debug, Args)]
+pub struct BookTagCommand {
+    #[arg(long)]
+    pub book_id: i64,
+    #[arg(long)]
+    pub tag: String,
+}
+
+pub async fn add_book_tag(client: &BooklogClient, command: BookTagCommand) -> Result<()> {
+    client
+        .tags()
+        .add(BookId::new(command.book_id), &command.tag)
+        .await?;
+    println!("Tagged book {} with {}", command.book_id, command.tag);
+    Ok(())
+}
+
+pub async fn remove_book_tag(client: &BooklogClient, command: BookTagCommand) -> Result<()> {
+    client
+        .tags()
+        .remove(BookId::new(command.book_id), &command.tag)
+        .await?;
+    println!("Removed tag {} from book {}", command.tag, command.book_id);
+    Ok(())
+}
@@ -1,15 +1,41 @@
-use clap::Subcommand;
+use clap::{Args, Subcommand};
 
+use super::print_json;
+use crate::domain::ids::{GenreId, UserId};
+use crate::domain::timeline::TimelineFilter;
 use crate::infrastructure::client::BooklogClient;
 
 #[derive(Debug, Subcommand)]
 pub enum TimelineCommands {
+    /// List timeline events, newest first
+    List(ListTimelineCommand),
     /// Rebuild all timeline event snapshots
     Rebuild,
 }
 
+#[derive(Debug, Args)]
+pub struct ListTimelineCommand {
+    /// Entity type: book, reading, `user_book`, author, genre, or goal
+    #[arg(long = "type")]
+    pub entity_type: Option<String>,
+    /// Action, e.g. added, moved, started, finished, abandoned, rated, or milestone
+    #[arg(long)]
+    pub action: Option<String>,
+    #[arg(long)]
+    pub genre_id: Option<i64>,
+    #[arg(long)]
+    pub user_id: Option<i64>,
+    /// Only events on or after this date (YYYY-MM-DD)
+    #[arg(long)]
+    pub since: Option<String>,
+    /// Only events on or before this date (YYYY-MM-DD)
+    #[arg(long)]
+    pub until: Option<String>,
+}
+
 pub async fn run(client: &BooklogClient, command: TimelineCommands) -> anyhow::Result<()> {
     match command {
+        TimelineCommands::List(c) => list_timeline(client, c).await,
         TimelineCommands::Rebuild => {
             client.timeline().rebuild().await?;
             eprintln!("Timeline rebuild triggered.");
@@ -17,3 +43,23 @@ pub async fn run(client: &BooklogClient, command: TimelineCommands) -> anyhow::R
         }
     }
 }
+
+pub async fn list_timeline(
+    client: &BooklogClient,
+    command: ListTimelineCommand,
+) -> anyhow::Result<()> {
+    let parse_date = |d: String| chrono::NaiveDate::parse_from_str(&d, "%Y-%m-%d");
+    let filter = TimelineFilter {
+        user_id: command.user_id.map(UserId::new),
+        entity_type: command.entity_type,
+        action: command.action,
+        exclude_action: None,
+        genre_id: command.genre_id.map(GenreId::new),
+        since: command.since.map(parse_date).transpose()?,
+        until: command.until.map(parse_date).transpose()?,
+        // The server shows private readings to the token's owner
+        viewer: None,
+    };
+    let events = client.timeline().list(&filter).await?;
+    print_json(&events)
+}
@@ -1,7 +1,7 @@
 use anyhow::Result;
 use clap::{Args, Subcommand};
 
-use super::print_json;
+use super::{print_json, with_tag_terms};
 use crate::domain::ids::BookId;
 use crate::domain::user_books::Shelf;
 use crate::infrastructure::client::BooklogClient;
@@ -40,6 +40,9 @@ pub struct AddUserBookCommand {
     /// Mark as a book club pick
     #[arg(long, default_value_t = false)]
     pub book_club: bool,
+    /// Personal tag to attach (repeatable)
+    #[arg(long = "tag")]
+    pub tags: Vec<String>,
 }
 
 pub async fn add_user_book(client: &BooklogClient, command: AddUserBookCommand) -> Result<()> {
@@ -50,16 +53,29 @@ pub async fn add_user_book(client: &BooklogClient, command: AddUserBookCommand)
 
     let user_book = client
         .user_books()
-        .create(BookId::new(command.book_id), shelf, command.book_club)
+        .create(
+            BookId::new(command.book_id),
+            shelf,
+            command.book_club,
+            &command.tags,
+        )
         .await?;
     print_json(&user_book)
 }
 
 #[derive(Debug, Args)]
-pub struct ListUserBooksCommand {}
+pub struct ListUserBooksCommand {
+    /// Filter query, e.g. 'club:yes status:reading genre:fantasy'
+    #[arg(long)]
+    pub query: Option<String>,
+    /// Only books with this personal tag (repeatable; all must match)
+    #[arg(long = "tag")]
+    pub tags: Vec<String>,
+}
 
-pub async fn list_user_books(client: &BooklogClient, _command: ListUserBooksCommand) -> Result<()> {
-    let user_books = client.user_books().list().await?;
+pub async fn list_user_books(client: &BooklogClient, command: ListUserBooksCommand) -> Result<()> {
+    let query = with_tag_terms(command.query, &command.tags);
+    let user_books = client.user_books().list(query.as_deref()).await?;
     print_json(&user_books)
 }
 
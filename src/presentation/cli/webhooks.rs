@@ -0,0 +1,63 @@
+use anyhow::Result;
+use clap::{Args, Subcommand};
+
+use super::macros::define_delete_command;
+use super::print_json;
+use crate::domain::ids::WebhookId;
+use crate::infrastructure::client::BooklogClient;
+use crate::infrastructure::client::webhooks::NewWebhookRequest;
+
+#[derive(Debug, Subcommand)]
+pub enum WebhookCommands {
+    /// Register a URL to receive signed timeline events
+    Add(AddWebhookCommand),
+    /// List your webhooks
+    List,
+    /// Delete a webhook and its delivery log
+    Delete(DeleteWebhookCommand),
+}
+
+pub async fn run(client: &BooklogClient, cmd: WebhookCommands) -> Result<()> {
+    match cmd {
+        WebhookCommands::Add(c) => add_webhook(client, c).await,
+        WebhookCommands::List => list_webhooks(client).await,
+        WebhookCommands::Delete(c) => delete_webhook(client, c).await,
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct AddWebhookCommand {
+    /// The URL events are sent to
+    #[arg(long)]
+    pub url: String,
+    /// Timeline action to send, e.g. finished (repeatable; defaults to all)
+    #[arg(long = "event")]
+    pub events: Vec<String>,
+    /// Send every user's events (admins only)
+    #[arg(long)]
+    pub all_users: bool,
+}
+
+pub async fn add_webhook(client: &BooklogClient, command: AddWebhookCommand) -> Result<()> {
+    let payload = NewWebhookRequest {
+        url: command.url,
+        events: command.events,
+        all_users: command.all_users,
+    };
+    let webhook = client.webhooks().create(&payload).await?;
+    eprintln!("Save the signing secret - it will not be shown again.");
+    print_json(&webhook)
+}
+
+pub async fn list_webhooks(client: &BooklogClient) -> Result<()> {
+    let webhooks = client.webhooks().list().await?;
+    print_json(&webhooks)
+}
+
+define_delete_command!(
+    DeleteWebhookCommand,
+    delete_webhook,
+    WebhookId,
+    webhooks,
+    "webhook"
+);
@@ -0,0 +1,2 @@
+pub mod obsidian;
+pub mod site;
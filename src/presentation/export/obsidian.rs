@@ -0,0 +1,547 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+use std::hash::BuildHasher;
+
+use crate::domain::authors::Author;
+use crate::domain::formatting::{format_pages, format_rating};
+use crate::domain::ids::{AuthorId, BookId};
+use crate::domain::library::{LibraryBook, LibraryExport};
+use crate::domain::readings::Reading;
+
+/// Separates the generated part of a note from the reader's own notes.
+/// Everything below it survives a re-export.
+pub const NOTES_MARKER: &str =
+    "%% booklog: write your notes below this line; it is kept on re-export %%";
+
+const BOOKS_DIR: &str = "Books";
+const AUTHORS_DIR: &str = "Authors";
+
+/// The directories notes are written to, relative to the vault root.
+pub const NOTE_DIRS: [&str; 2] = [BOOKS_DIR, AUTHORS_DIR];
+
+/// Characters Obsidian does not allow in note names or wikilinks.
+const FORBIDDEN_CHARS: &[char] = &[
+    '[', ']', '#', '^', '|', '\\', '/', ':', '*', '?', '"', '<', '>',
+];
+
+/// Where a book's cover goes, relative to the vault root.
+pub fn cover_path(book_id: BookId) -> String {
+    format!("attachments/cover-{book_id}.jpg")
+}
+
+/// A Markdown note of the vault, at a path relative to its root.
+pub struct VaultNote {
+    pub path: String,
+    /// The book's or author's id, also written to the front matter so the
+    /// note is found again after a rename changes its path.
+    pub booklog_id: i64,
+    /// The note down to and including [`NOTES_MARKER`].
+    pub generated: String,
+}
+
+/// A Markdown file already in the vault, at a path relative to its root.
+pub struct VaultFile {
+    pub path: String,
+    pub contents: String,
+}
+
+impl VaultFile {
+    /// The `booklog_id` in the file's front matter, if it has one.
+    pub fn booklog_id(&self) -> Option<i64> {
+        let mut lines = self.contents.lines();
+        if lines.next()?.trim_end() != "---" {
+            return None;
+        }
+        lines
+            .take_while(|line| line.trim_end() != "---")
+            .find_map(|line| line.strip_prefix("booklog_id:")?.trim().parse().ok())
+    }
+}
+
+/// What writing a note over the existing file would do.
+#[derive(Debug, PartialEq, Eq)]
+pub enum NoteUpdate {
+    Create(String),
+    Update(String),
+    Unchanged,
+    /// The existing file has no marker, so it cannot be updated without
+    /// losing the reader's edits.
+    MissingMarker,
+}
+
+impl VaultNote {
+    /// Merge with the file already at [`VaultNote::path`], replacing the
+    /// generated part and keeping everything below the marker.
+    pub fn merge(&self, existing: Option<&str>) -> NoteUpdate {
+        let Some(existing) = existing else {
+            return NoteUpdate::Create(format!("{}\n", self.generated));
+        };
+        let Some(index) = existing.find(NOTES_MARKER) else {
+            return NoteUpdate::MissingMarker;
+        };
+        let notes = &existing[index + NOTES_MARKER.len()..];
+        let contents = format!("{}{notes}", self.generated);
+        if contents == existing {
+            NoteUpdate::Unchanged
+        } else {
+            NoteUpdate::Update(contents)
+        }
+    }
+}
+
+/// Render a note per book and per author. Private readings are included,
+/// since the vault is the reader's own. `covers` are the books whose cover
+/// was saved at [`cover_path`].
+pub fn render_vault<S: BuildHasher>(
+    export: &LibraryExport,
+    covers: &HashSet<BookId, S>,
+) -> Vec<VaultNote> {
+    let names = NoteNames::new(export);
+
+    let mut notes: Vec<VaultNote> = export
+        .books
+        .iter()
+        .map(|book| {
+            let name = names.book(book.book.book.id);
+            VaultNote {
+                path: format!("{BOOKS_DIR}/{name}.md"),
+                booklog_id: book.book.book.id.into_inner(),
+                generated: book_note(&names, book, covers.contains(&book.book.book.id)),
+            }
+        })
+        .collect();
+    notes.extend(export.authors.iter().filter_map(|author| {
+        let name = names.author(author.id)?;
+        Some(VaultNote {
+            path: format!("{AUTHORS_DIR}/{name}.md"),
+            booklog_id: author.id.into_inner(),
+            generated: author_note(&names, export, author),
+        })
+    }));
+    notes
+}
+
+/// The existing file each note updates: the one in the note's directory
+/// with its `booklog_id`, so books and authors keep their notes when
+/// renamed. Otherwise it's the file at the note's path, unless that file
+/// belongs to another note. `files` must all be read before any note is
+/// written, since notes can take over each other's paths.
+pub fn match_files<'a>(notes: &[VaultNote], files: &'a [VaultFile]) -> Vec<Option<&'a VaultFile>> {
+    let by_id: HashMap<(&str, i64), &VaultFile> = files
+        .iter()
+        .filter_map(|file| Some(((note_dir(&file.path), file.booklog_id()?), file)))
+        .collect();
+    let by_path: HashMap<&str, &VaultFile> = files
+        .iter()
+        .map(|file| (file.path.as_str(), file))
+        .collect();
+    let claimed: HashSet<(&str, i64)> = notes
+        .iter()
+        .map(|note| (note_dir(&note.path), note.booklog_id))
+        .collect();
+
+    notes
+        .iter()
+        .map(|note| {
+            let dir = note_dir(&note.path);
+            by_id.get(&(dir, note.booklog_id)).copied().or_else(|| {
+                let file = *by_path.get(note.path.as_str())?;
+                match file.booklog_id() {
+                    Some(id) if claimed.contains(&(dir, id)) => None,
+                    _ => Some(file),
+                }
+            })
+        })
+        .collect()
+}
+
+fn note_dir(path: &str) -> &str {
+    path.split_once('/').map_or("", |(dir, _)| dir)
+}
+
+/// Note names, unique across books and authors so that a bare `[[name]]`
+/// always resolves to the right note.
+struct NoteNames {
+    books: HashMap<BookId, String>,
+    authors: HashMap<AuthorId, String>,
+}
+
+impl NoteNames {
+    fn new(export: &LibraryExport) -> Self {
+        let mut taken = HashSet::new();
+        let mut unique = |base: &str, id: i64| {
+            let base = note_name(base);
+            let name = if taken.contains(&base.to_lowercase()) {
+                format!("{base} ({id})")
+            } else {
+                base
+            };
+            taken.insert(name.to_lowercase());
+            name
+        };
+
+        // Authors first, so they keep their plain names when a book shares one
+        let authors = export
+            .authors
+            .iter()
+            .map(|author| (author.id, unique(&author.name, author.id.into_inner())))
+            .collect();
+        let books = export
+            .books
+            .iter()
+            .map(|entry| {
+                let book = &entry.book.book;
+                (book.id, unique(&book.title, book.id.into_inner()))
+            })
+            .collect();
+        Self { books, authors }
+    }
+
+    fn book(&self, id: BookId) -> &str {
+        self.books.get(&id).map_or("Untitled", String::as_str)
+    }
+
+    fn author(&self, id: AuthorId) -> Option<&str> {
+        self.authors.get(&id).map(String::as_str)
+    }
+}
+
+fn book_note(names: &NoteNames, entry: &LibraryBook, has_cover: bool) -> String {
+    let book = &entry.book.book;
+    let authors: Vec<String> = entry
+        .book
+        .authors
+        .iter()
+        .map(|author| match names.author(author.author_id) {
+            Some(name) => wikilink(name, &author.author_name),
+            None => author.author_name.clone(),
+        })
+        .collect();
+    let cover = has_cover.then(|| cover_path(book.id));
+
+    let mut note = String::from("---\n");
+    yaml_field(&mut note, "title", &quote(&book.title));
+    yaml_list(&mut note, "authors", authors.iter().map(|a| quote(a)));
+    if let Some(isbn) = &book.isbn {
+        yaml_field(&mut note, "isbn", &quote(isbn));
+    }
+    yaml_list(
+        &mut note,
+        "genres",
+        entry.book.genre_names().iter().map(|g| quote(g)),
+    );
+    yaml_field(&mut note, "shelf", entry.shelf.as_str());
+    yaml_list(&mut note, "tags", entry.tags.iter().map(|t| quote(t)));
+    if let Some(cover) = &cover {
+        yaml_field(&mut note, "cover", &quote(&format!("[[{cover}]]")));
+    }
+    if !entry.readings.is_empty() {
+        note.push_str("readings:\n");
+        for reading in &entry.readings {
+            reading_yaml(&mut note, reading);
+        }
+    }
+    yaml_field(&mut note, "booklog_id", &book.id.to_string());
+    note.push_str("---\n\n");
+
+    let _ = writeln!(note, "# {}\n", book.title);
+    let mut byline = Vec::new();
+    if !authors.is_empty() {
+        byline.push(format!("By {}", authors.join(", ")));
+    }
+    if let Some(year) = book.year_published {
+        byline.push(year.to_string());
+    }
+    if let Some(pages) = book.page_count {
+        byline.push(format_pages(pages));
+    }
+    if !byline.is_empty() {
+        let _ = writeln!(note, "{}\n", byline.join(" · "));
+    }
+    if let Some(cover) = &cover {
+        let _ = writeln!(note, "![[{cover}]]\n");
+    }
+    if let Some(description) = &book.description {
+        let _ = writeln!(note, "{}\n", description.trim());
+    }
+    if !entry.readings.is_empty() {
+        note.push_str("## Readings\n\n");
+        for reading in entry.readings.iter().rev() {
+            let _ = writeln!(note, "- {}", reading_summary(reading));
+        }
+        note.push('\n');
+    }
+    note.push_str(NOTES_MARKER);
+    note
+}
+
+fn author_note(names: &NoteNames, export: &LibraryExport, author: &Author) -> String {
+    let details = &author.details;
+
+    let mut note = String::from("---\n");
+    yaml_field(&mut note, "name", &quote(&author.name));
+    yaml_list(
+        &mut note,
+        "aliases",
+        details.aliases.iter().map(|a| quote(a)),
+    );
+    if let Some(born) = details.birth_year {
+        yaml_field(&mut note, "born", &born.to_string());
+    }
+    if let Some(died) = details.death_year {
+        yaml_field(&mut note, "died", &died.to_string());
+    }
+    if let Some(nationality) = &details.nationality {
+        yaml_field(&mut note, "nationality", &quote(nationality));
+    }
+    if let Some(website) = &details.website {
+        yaml_field(&mut note, "website", &quote(website));
+    }
+    yaml_field(&mut note, "booklog_id", &author.id.to_string());
+    note.push_str("---\n\n");
+
+    let _ = writeln!(note, "# {}\n", author.name);
+    if let Some(bio) = &details.bio {
+        let _ = writeln!(note, "{}\n", bio.trim());
+    }
+    note.push_str("## Books\n\n");
+    for entry in export
+        .books
+        .iter()
+        .filter(|entry| entry.book.authors.iter().any(|a| a.author_id == author.id))
+    {
+        let book = &entry.book.book;
+        let _ = write!(note, "- {}", wikilink(names.book(book.id), &book.title));
+        if let Some(year) = book.year_published {
+            let _ = write!(note, " ({year})");
+        }
+        note.push('\n');
+    }
+    note.push('\n');
+    note.push_str(NOTES_MARKER);
+    note
+}
+
+fn reading_yaml(note: &mut String, reading: &Reading) {
+    let _ = writeln!(note, "  - status: {}", reading.status.as_str());
+    if let Some(format) = reading.format {
+        let _ = writeln!(note, "    format: {}", format.as_str());
+    }
+    if let Some(started) = reading.started_at {
+        let _ = writeln!(note, "    started: {started}");
+    }
+    if let Some(finished) = reading.finished_at {
+        let _ = writeln!(note, "    finished: {finished}");
+    }
+    if let Some(rating) = reading.rating {
+        let _ = writeln!(note, "    rating: {rating}");
+    }
+    if !reading.quick_reviews.is_empty() {
+        note.push_str("    quick_reviews:\n");
+        for review in &reading.quick_reviews {
+            let _ = writeln!(note, "      - {}", quote(review.label()));
+        }
+    }
+    if reading.private {
+        note.push_str("    private: true\n");
+    }
+}
+
+/// One line per reading for the note body, e.g.
+/// "Read · Ebook · 2026-01-05 → 2026-02-01 · 4.5/5 · Loved it".
+fn reading_summary(reading: &Reading) -> String {
+    let mut parts = vec![reading.status.display_label().to_string()];
+    if let Some(format) = reading.format {
+        parts.push(format.display_label().to_string());
+    }
+    match (reading.started_at, reading.finished_at) {
+        (Some(started), Some(finished)) => parts.push(format!("{started} → {finished}")),
+        (Some(started), None) => parts.push(format!("since {started}")),
+        (None, Some(finished)) => parts.push(format!("finished {finished}")),
+        (None, None) => {}
+    }
+    if let Some(rating) = reading.rating {
+        parts.push(format_rating(rating));
+    }
+    parts.extend(
+        reading
+            .quick_reviews
+            .iter()
+            .map(|review| review.label().to_string()),
+    );
+    parts.join(" · ")
+}
+
+fn yaml_field(note: &mut String, key: &str, value: &str) {
+    let _ = writeln!(note, "{key}: {value}");
+}
+
+fn yaml_list(note: &mut String, key: &str, values: impl Iterator<Item = String>) {
+    let mut values = values.peekable();
+    if values.peek().is_none() {
+        return;
+    }
+    let _ = writeln!(note, "{key}:");
+    for value in values {
+        let _ = writeln!(note, "  - {value}");
+    }
+}
+
+/// A YAML double-quoted scalar.
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(quoted, "\\u{:04x}", u32::from(c));
+            }
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn wikilink(name: &str, label: &str) -> String {
+    if name == label {
+        format!("[[{name}]]")
+    } else {
+        format!("[[{name}|{label}]]")
+    }
+}
+
+/// A file name Obsidian can link to, from a title or author name.
+fn note_name(value: &str) -> String {
+    let cleaned: String = value
+        .chars()
+        .map(|c| if FORBIDDEN_CHARS.contains(&c) { ' ' } else { c })
+        .collect();
+    let name = cleaned.split_whitespace().collect::<Vec<_>>().join(" ");
+    let name = name.trim_start_matches('.');
+    if name.is_empty() {
+        "Untitled".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn note() -> VaultNote {
+        VaultNote {
+            path: "Books/Dune.md".to_string(),
+            booklog_id: 1,
+            generated: format!("# Dune\n\n{NOTES_MARKER}"),
+        }
+    }
+
+    #[test]
+    fn new_notes_end_with_an_empty_notes_section() {
+        assert_eq!(
+            note().merge(None),
+            NoteUpdate::Create(format!("# Dune\n\n{NOTES_MARKER}\n"))
+        );
+    }
+
+    #[test]
+    fn merge_keeps_notes_below_the_marker() {
+        let existing = format!("# Dun\n\n{NOTES_MARKER}\nLoved the worms.\n");
+        assert_eq!(
+            note().merge(Some(&existing)),
+            NoteUpdate::Update(format!("# Dune\n\n{NOTES_MARKER}\nLoved the worms.\n"))
+        );
+    }
+
+    #[test]
+    fn merge_leaves_identical_notes_alone() {
+        let existing = format!("# Dune\n\n{NOTES_MARKER}\nLoved the worms.\n");
+        assert_eq!(note().merge(Some(&existing)), NoteUpdate::Unchanged);
+    }
+
+    #[test]
+    fn merge_refuses_files_without_a_marker() {
+        assert_eq!(
+            note().merge(Some("# Dune\n\nMy own note")),
+            NoteUpdate::MissingMarker
+        );
+    }
+
+    fn file(path: &str, booklog_id: i64) -> VaultFile {
+        VaultFile {
+            path: path.to_string(),
+            contents: format!(
+                "---\ntitle: \"x\"\nbooklog_id: {booklog_id}\n---\n\n{NOTES_MARKER}\n"
+            ),
+        }
+    }
+
+    #[test]
+    fn booklog_id_is_read_from_front_matter() {
+        assert_eq!(file("Books/Dune.md", 12).booklog_id(), Some(12));
+        let body_only = VaultFile {
+            path: "Books/Dune.md".to_string(),
+            contents: "# Dune\n\nbooklog_id: 12\n".to_string(),
+        };
+        assert_eq!(body_only.booklog_id(), None);
+    }
+
+    #[test]
+    fn notes_are_matched_by_id_before_path() {
+        let renamed = VaultNote {
+            path: "Books/Dune Messiah.md".to_string(),
+            ..note()
+        };
+        let files = [file("Books/Dune.md", 1), file("Authors/Dune.md", 1)];
+        let matched = match_files(&[renamed], &files);
+        assert_eq!(matched[0].map(|f| f.path.as_str()), Some("Books/Dune.md"));
+    }
+
+    #[test]
+    fn notes_do_not_take_over_files_claimed_by_other_notes() {
+        // Two books swapped titles
+        let dune = note();
+        let other = VaultNote {
+            path: "Books/Other.md".to_string(),
+            booklog_id: 2,
+            ..note()
+        };
+        let files = [file("Books/Dune.md", 2), file("Books/Other.md", 1)];
+        let matched = match_files(&[dune, other], &files);
+        assert_eq!(matched[0].map(|f| f.path.as_str()), Some("Books/Other.md"));
+        assert_eq!(matched[1].map(|f| f.path.as_str()), Some("Books/Dune.md"));
+
+        // A new book named like one that was renamed gets a fresh note
+        let newcomer = VaultNote {
+            booklog_id: 3,
+            ..note()
+        };
+        let renamed = VaultNote {
+            path: "Books/Other.md".to_string(),
+            booklog_id: 2,
+            ..note()
+        };
+        let matched = match_files(&[newcomer, renamed], &files[..1]);
+        assert!(matched[0].is_none());
+        assert_eq!(matched[1].map(|f| f.path.as_str()), Some("Books/Dune.md"));
+    }
+
+    #[test]
+    fn note_names_drop_characters_obsidian_rejects() {
+        assert_eq!(note_name("Dune: Messiah"), "Dune Messiah");
+        assert_eq!(note_name("What If? #2 [Annotated]"), "What If 2 Annotated");
+        assert_eq!(note_name(" / "), "Untitled");
+    }
+
+    #[test]
+    fn quoted_strings_escape_yaml_specials() {
+        assert_eq!(quote("Say \"hi\"\n"), "\"Say \\\"hi\\\"\\n\"");
+        assert_eq!(quote("[[Frank Herbert]]"), "\"[[Frank Herbert]]\"");
+    }
+}
@@ -0,0 +1,476 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::BuildHasher;
+
+use askama::Template;
+use serde::Serialize;
+
+use crate::domain::authors::Author;
+use crate::domain::formatting::{EM_DASH, format_number};
+use crate::domain::genres::{Genre, GenreTree};
+use crate::domain::ids::{BookId, GenreId, TimelineEventId};
+use crate::domain::library::{LibraryBook, LibraryExport};
+use crate::domain::readings::{ReadingStatus, ReadingWithBook, reading_timeline_event};
+use crate::domain::timeline::TimelineEvent;
+use crate::domain::user_books::Shelf;
+use crate::presentation::web::views::{
+    AuthorBookCardView, AuthorDetailView, BookDetailView, BookLibraryInfo, BookReadingCardView,
+    GenreDetailView, GoalView, StatCard, StatsView, TimelineMonthView,
+};
+
+/// The app's own stylesheet, written next to the pages.
+const STYLESHEET: &str = include_str!("../../../static/css/styles.css");
+
+/// A file of the generated site, at a path relative to its root.
+pub struct SiteFile {
+    pub path: String,
+    pub contents: String,
+}
+
+/// Where a book's cover thumbnail goes, relative to the site root.
+pub fn cover_path(book_id: BookId) -> String {
+    format!("covers/{book_id}.jpg")
+}
+
+/// Render every page of the site, plus its stylesheet and search index.
+/// Pages use the app's own templates in static mode; private readings are
+/// left out. `covers` are the books whose cover was saved at
+/// [`cover_path`].
+pub fn render_site<S: BuildHasher>(
+    export: &LibraryExport,
+    covers: &HashSet<BookId, S>,
+) -> anyhow::Result<Vec<SiteFile>> {
+    let site = SiteLibrary::new(export, covers);
+    let generated = export.exported_at.format("%b %-d, %Y").to_string();
+    let top = StaticSite {
+        root: "",
+        generated: generated.clone(),
+    };
+    let nested = || StaticSite {
+        root: "../",
+        generated: generated.clone(),
+    };
+
+    let mut files = vec![
+        SiteFile {
+            path: "style.css".to_string(),
+            contents: STYLESHEET.to_string(),
+        },
+        SiteFile {
+            path: "search-index.json".to_string(),
+            contents: serde_json::to_string(&site.search_index())?,
+        },
+        SiteFile {
+            path: "index.html".to_string(),
+            contents: site.index_page(top).render()?,
+        },
+    ];
+
+    for book in &export.books {
+        files.push(SiteFile {
+            path: book_href(book.book.book.id),
+            contents: site.book_page(nested(), book).render()?,
+        });
+    }
+    for author in &export.authors {
+        files.push(SiteFile {
+            path: format!("authors/{}.html", author.id),
+            contents: site.author_page(nested(), author).render()?,
+        });
+    }
+    for genre in &export.genres {
+        files.push(SiteFile {
+            path: genre_href(genre.id),
+            contents: site.genre_page(nested(), genre).render()?,
+        });
+    }
+
+    Ok(files)
+}
+
+fn book_href(id: BookId) -> String {
+    format!("books/{id}.html")
+}
+
+fn genre_href(id: GenreId) -> String {
+    format!("genres/{id}.html")
+}
+
+fn book_genre_ids(book: &LibraryBook) -> Vec<GenreId> {
+    [
+        book.book.book.primary_genre_id,
+        book.book.book.secondary_genre_id,
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn author_names(book: &LibraryBook) -> String {
+    if book.book.authors.is_empty() {
+        "Unknown".to_string()
+    } else {
+        book.book
+            .authors
+            .iter()
+            .map(|a| a.author_name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// The export, indexed for rendering.
+struct SiteLibrary<'a, S> {
+    export: &'a LibraryExport,
+    covers: &'a HashSet<BookId, S>,
+    genres: HashMap<GenreId, &'a Genre>,
+    genre_tree: GenreTree,
+}
+
+impl<'a, S: BuildHasher> SiteLibrary<'a, S> {
+    fn new(export: &'a LibraryExport, covers: &'a HashSet<BookId, S>) -> Self {
+        Self {
+            export,
+            covers,
+            genres: export.genres.iter().map(|g| (g.id, g)).collect(),
+            genre_tree: GenreTree::new(export.genres.clone()),
+        }
+    }
+
+    fn cover(&self, site: &StaticSite, id: BookId) -> Option<String> {
+        self.covers
+            .contains(&id)
+            .then(|| format!("{}{}", site.root, cover_path(id)))
+    }
+
+    fn book_authors(&self, book: &LibraryBook) -> Vec<Author> {
+        self.export
+            .authors
+            .iter()
+            .filter(|author| book.book.authors.iter().any(|a| a.author_id == author.id))
+            .cloned()
+            .collect()
+    }
+
+    /// Every public reading as a timeline, newest first, each linking to
+    /// its book's page.
+    fn index_page(&self, site: StaticSite) -> IndexPage {
+        let mut events: Vec<(BookId, TimelineEvent)> = Vec::new();
+        for book in &self.export.books {
+            let authors = self.book_authors(book);
+            for reading in book.public_readings() {
+                let event = reading_timeline_event(reading, &book.book.book, &authors);
+                events.push((
+                    book.book.book.id,
+                    TimelineEvent {
+                        id: TimelineEventId::from(reading.id.into_inner()),
+                        entity_type: event.entity_type,
+                        entity_id: event.entity_id,
+                        action: event.action,
+                        occurred_at: event.occurred_at,
+                        title: event.title,
+                        details: event.details,
+                        genres: event.genres,
+                        reading_data: event.reading_data,
+                        changes: event.changes,
+                    },
+                ));
+            }
+        }
+        events.sort_by_key(|(_, event)| Reverse(event.occurred_at));
+
+        let (book_ids, events): (Vec<BookId>, Vec<TimelineEvent>) = events.into_iter().unzip();
+        let mut months = TimelineMonthView::group(events);
+        for (view, book_id) in months
+            .iter_mut()
+            .flat_map(|month| month.events.iter_mut())
+            .zip(book_ids)
+        {
+            view.link = site.page("books", book_id);
+        }
+
+        IndexPage {
+            stats: self.stats(),
+            stat_cards: self.stat_cards(),
+            goals: Vec::new(),
+            months,
+            static_site: site,
+        }
+    }
+
+    fn stats(&self) -> StatsView {
+        let count = |keep: fn(&LibraryBook) -> bool| {
+            self.export.books.iter().filter(|b| keep(b)).count() as u64
+        };
+        StatsView {
+            library: count(|b| b.shelf == Shelf::Library),
+            wishlist: count(|b| b.shelf == Shelf::Wishlist),
+            currently_reading: count(|b| {
+                b.public_readings()
+                    .any(|r| r.status == ReadingStatus::Reading)
+            }),
+        }
+    }
+
+    fn stat_cards(&self) -> Vec<StatCard> {
+        let finished: Vec<&LibraryBook> = self
+            .export
+            .books
+            .iter()
+            .flat_map(|book| {
+                book.public_readings()
+                    .filter(|r| r.status == ReadingStatus::Read)
+                    .map(move |_| book)
+            })
+            .collect();
+        let pages: i64 = finished
+            .iter()
+            .filter_map(|book| book.book.book.page_count)
+            .map(i64::from)
+            .sum();
+
+        let mut by_genre: HashMap<GenreId, usize> = HashMap::new();
+        let mut by_author: HashMap<&str, usize> = HashMap::new();
+        for book in &finished {
+            if let Some(id) = book.book.book.primary_genre_id {
+                *by_genre.entry(id).or_default() += 1;
+            }
+            for author in &book.book.authors {
+                *by_author.entry(author.author_name.as_str()).or_default() += 1;
+            }
+        }
+        let top_genre = by_genre
+            .into_iter()
+            .filter_map(|(id, count)| self.genres.get(&id).map(|g| (g.name.as_str(), count)))
+            .max_by_key(|&(name, count)| (count, Reverse(name)))
+            .map(|(name, _)| name);
+        let top_author = by_author
+            .into_iter()
+            .max_by_key(|&(name, count)| (count, Reverse(name)))
+            .map(|(name, _)| name);
+        let unique_genres: HashSet<GenreId> =
+            self.export.books.iter().flat_map(book_genre_ids).collect();
+
+        vec![
+            StatCard {
+                icon: "book",
+                value: finished.len().to_string(),
+                label: "All Time",
+            },
+            StatCard {
+                icon: "book",
+                value: format_number(pages),
+                label: "Pages Read",
+            },
+            StatCard {
+                icon: "map",
+                value: unique_genres.len().to_string(),
+                label: "Genres",
+            },
+            StatCard {
+                icon: "book",
+                value: top_genre.unwrap_or(EM_DASH).to_string(),
+                label: "Top Genre",
+            },
+            StatCard {
+                icon: "book",
+                value: top_author.unwrap_or(EM_DASH).to_string(),
+                label: "Top Author",
+            },
+        ]
+    }
+
+    fn book_page(&self, site: StaticSite, book: &LibraryBook) -> BookPage {
+        let details = &book.book.book;
+        let mut view = BookDetailView::from_domain(book.book.clone());
+        for (_, path) in &mut view.genre_links {
+            *path = site.link(path);
+        }
+
+        let readings: Vec<ReadingWithBook> = book
+            .public_readings()
+            .rev()
+            .map(|reading| ReadingWithBook {
+                reading: reading.clone(),
+                book_title: details.title.clone(),
+                author_names: author_names(book),
+                page_count: details.page_count,
+                year_published: details.year_published,
+                primary_genre: None,
+                secondary_genre: None,
+            })
+            .collect();
+        let latest = readings.first();
+        let is_wishlist = book.shelf == Shelf::Wishlist;
+        let library_info = BookLibraryInfo {
+            date_added: book.added_at.format("%Y-%m-%d").to_string(),
+            status_label: match latest {
+                Some(rwb) => rwb.reading.status.display_label().to_string(),
+                None if is_wishlist => "Wishlist".to_string(),
+                None => "On Shelf".to_string(),
+            },
+            format_label: latest
+                .and_then(|rwb| rwb.reading.format)
+                .map_or_else(|| EM_DASH.to_string(), |f| f.display_label().to_string()),
+            book_club: false,
+            shelf_heading: book.shelf.display_label(),
+            is_wishlist,
+        };
+
+        BookPage {
+            is_authenticated: false,
+            image_url: self.cover(&site, details.id),
+            book: view,
+            library_info: Some(library_info),
+            readings: readings
+                .into_iter()
+                .map(BookReadingCardView::from_domain)
+                .collect(),
+            static_site: site,
+        }
+    }
+
+    fn book_cards<'b>(
+        &self,
+        site: &StaticSite,
+        books: impl Iterator<Item = &'b LibraryBook>,
+    ) -> Vec<AuthorBookCardView> {
+        let mut cards: Vec<AuthorBookCardView> = books
+            .map(|book| {
+                let mut card = AuthorBookCardView::from_domain(book.book.clone());
+                card.detail_path = site.page("books", book.book.book.id);
+                card.thumbnail_url = self.cover(site, book.book.book.id);
+                card
+            })
+            .collect();
+        cards.sort_by_key(|card| card.title.to_lowercase());
+        cards
+    }
+
+    fn author_page(&self, site: StaticSite, author: &Author) -> AuthorPage {
+        AuthorPage {
+            is_authenticated: false,
+            author: AuthorDetailView::from_domain(author.clone()),
+            image_url: None,
+            library_books: self.book_cards(
+                &site,
+                self.export
+                    .books
+                    .iter()
+                    .filter(|b| b.book.authors.iter().any(|a| a.author_id == author.id)),
+            ),
+            static_site: site,
+        }
+    }
+
+    fn genre_page(&self, site: StaticSite, genre: &Genre) -> GenrePage {
+        let mut view = GenreDetailView::from_domain(genre.clone(), &self.genre_tree);
+        for link in view.ancestors.iter_mut().chain(&mut view.subgenres) {
+            link.detail_path = site.link(&link.detail_path);
+        }
+
+        GenrePage {
+            genre: view,
+            library_books: self.book_cards(
+                &site,
+                self.export
+                    .books
+                    .iter()
+                    .filter(|b| book_genre_ids(b).contains(&genre.id)),
+            ),
+            static_site: site,
+        }
+    }
+
+    fn search_index(&self) -> Vec<SearchEntry> {
+        let books = self.export.books.iter().map(|book| SearchEntry {
+            kind: "book",
+            title: book.book.book.title.clone(),
+            subtitle: author_names(book),
+            url: book_href(book.book.book.id),
+        });
+        let authors = self.export.authors.iter().map(|author| SearchEntry {
+            kind: "author",
+            title: author.name.clone(),
+            subtitle: author.details.aliases.join(", "),
+            url: format!("authors/{}.html", author.id),
+        });
+        let genres = self.export.genres.iter().map(|genre| SearchEntry {
+            kind: "genre",
+            title: genre.name.clone(),
+            subtitle: String::new(),
+            url: genre_href(genre.id),
+        });
+        books.chain(authors).chain(genres).collect()
+    }
+}
+
+/// Marks a page as written into the static site rather than served: the
+/// templates link relative to `root` and leave out anything that needs the
+/// app behind them.
+struct StaticSite {
+    /// Prefix from the page back to the site root, e.g. `../`.
+    root: &'static str,
+    generated: String,
+}
+
+impl StaticSite {
+    /// The exported page of an entity, e.g. `../authors/3.html`.
+    fn page(&self, kind: &str, id: impl Display) -> String {
+        format!("{}{kind}/{id}.html", self.root)
+    }
+
+    /// The exported page for one of the app's paths, e.g. `/genres/3`.
+    fn link(&self, path: &str) -> String {
+        format!("{}{}.html", self.root, path.trim_start_matches('/'))
+    }
+}
+
+/// One entry of `search-index.json`, read by the search box in the nav.
+#[derive(Serialize)]
+struct SearchEntry {
+    kind: &'static str,
+    title: String,
+    subtitle: String,
+    url: String,
+}
+
+#[derive(Template)]
+#[template(path = "pages/timeline.html")]
+struct IndexPage {
+    static_site: StaticSite,
+    stats: StatsView,
+    stat_cards: Vec<StatCard>,
+    goals: Vec<GoalView>,
+    months: Vec<TimelineMonthView>,
+}
+
+#[derive(Template)]
+#[template(path = "pages/book.html")]
+struct BookPage {
+    static_site: StaticSite,
+    is_authenticated: bool,
+    book: BookDetailView,
+    image_url: Option<String>,
+    library_info: Option<BookLibraryInfo>,
+    readings: Vec<BookReadingCardView>,
+}
+
+#[derive(Template)]
+#[template(path = "pages/author.html")]
+struct AuthorPage {
+    static_site: StaticSite,
+    is_authenticated: bool,
+    author: AuthorDetailView,
+    image_url: Option<String>,
+    library_books: Vec<AuthorBookCardView>,
+}
+
+#[derive(Template)]
+#[template(path = "pages/genre.html")]
+struct GenrePage {
+    static_site: StaticSite,
+    genre: GenreDetailView,
+    library_books: Vec<AuthorBookCardView>,
+}
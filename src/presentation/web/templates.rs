@@ -2,11 +2,15 @@ use askama::Template;
 
 use super::views::{
     AuthorBookCardView, AuthorDetailView, AuthorOptionView, AuthorView, BookDetailView,
-    BookLibraryInfo, BookOptionView, BookReadingCardView, BookView, GenreDetailView,
-    GenreOptionView, GenreView, ListNavigator, Paginated, ReadingDetailView, ReadingView, StatCard,
-    StatsView, TimelineEventView, TimelineMonthView, UserBookView,
+    BookLibraryInfo, BookOptionView, BookReadingCardView, BookView, ChallengeDetailView,
+    ChallengeHeaderView, FeedView, GenreDetailView, GenreOptionView, GenreView, GoalView,
+    HeatmapView, HouseholdStatsView, ListNavigator, ListeningStatsView, Paginated,
+    ProfileReadingView, ProfileStatsView, PromptReadingView, ReadingDetailView, ReadingView,
+    SentimentStatsView, ShelfLinkView, ShelfOptionView, ShelfView, StatCard, StatsComparisonView,
+    StatsView, TagCloudView, TagView, TimelineEventView, TimelineFiltersView, TimelineMonthView,
+    UserBookView, WidgetView, YearReviewView,
 };
-use crate::domain::analytics::stats::{BookSummaryStats, ReadingStats};
+use crate::domain::analytics::stats::{BookSummaryStats, ReadingStats, StatsPeriod};
 use crate::domain::analytics::timeline::TimelineSortKey;
 use crate::domain::books::authors::AuthorSortKey;
 use crate::domain::books::books::BookSortKey;
@@ -67,6 +71,9 @@ pub struct TimelineTemplate {
     pub events: Paginated<TimelineEventView>,
     pub navigator: ListNavigator<TimelineSortKey>,
     pub months: Vec<TimelineMonthView>,
+    pub filters: TimelineFiltersView,
+    /// Where new events are streamed from to the top of the list, if they are.
+    pub live_events_url: Option<String>,
 }
 
 #[derive(Template)]
@@ -78,6 +85,22 @@ pub struct TimelineChunkTemplate {
     pub months: Vec<TimelineMonthView>,
 }
 
+/// A single timeline card, streamed to the timeline page as it happens.
+#[derive(Template)]
+#[template(path = "partials/timeline_event.html")]
+pub struct TimelineEventTemplate {
+    pub event: TimelineEventView,
+    /// Groups the card with others for "Expand all".
+    pub month_anchor: &'static str,
+}
+
+/// Shown above the timeline when streamed events can't be patched in.
+#[derive(Template)]
+#[template(path = "partials/timeline_live_notice.html")]
+pub struct TimelineLiveNoticeTemplate {
+    pub message: &'static str,
+}
+
 #[derive(Template)]
 #[template(path = "pages/home.html")]
 pub struct HomeTemplate {
@@ -91,9 +114,28 @@ pub struct HomeTemplate {
     pub currently_reading: Vec<ReadingView>,
     pub recently_added: Vec<UserBookView>,
     pub wishlist: Vec<UserBookView>,
+    pub pinned_shelves: Vec<ShelfView>,
+    pub recent_events: Vec<TimelineEventView>,
+    pub stats: StatsView,
+    pub stat_cards: Vec<StatCard>,
+    /// Goals whose period includes today, shown ahead of the stat cards.
+    pub goals: Vec<GoalView>,
+}
+
+/// The home page's "Recent Activity" section, re-rendered on live updates.
+#[derive(Template)]
+#[template(path = "partials/home_activity.html")]
+pub struct HomeActivityTemplate {
     pub recent_events: Vec<TimelineEventView>,
+}
+
+/// The home page's "Stats" section, re-rendered on live updates.
+#[derive(Template)]
+#[template(path = "partials/home_stats.html")]
+pub struct HomeStatsTemplate {
     pub stats: StatsView,
     pub stat_cards: Vec<StatCard>,
+    pub goals: Vec<GoalView>,
 }
 
 #[derive(Template)]
@@ -148,6 +190,36 @@ pub struct YearTab {
     pub label: String,
 }
 
+/// A quick link to a stats range, optionally compared with another.
+pub struct StatsRangePreset {
+    pub label: String,
+    pub href: String,
+}
+
+impl StatsRangePreset {
+    pub fn new(label: &str, period: &StatsPeriod, compare: Option<&StatsPeriod>) -> Self {
+        let mut href = format!("/stats?from={}&to={}", period.from, period.to);
+        if let Some(compare) = compare {
+            href = format!(
+                "{href}&compare_from={}&compare_to={}",
+                compare.from, compare.to
+            );
+        }
+        Self {
+            label: label.to_string(),
+            href,
+        }
+    }
+}
+
+/// The stats page's custom range form, filled in with the current query.
+pub struct StatsRangeForm {
+    pub from: String,
+    pub to: String,
+    pub compare_from: String,
+    pub compare_to: String,
+}
+
 #[derive(Template)]
 #[template(path = "pages/stats.html")]
 pub struct StatsPageTemplate {
@@ -161,6 +233,11 @@ pub struct StatsPageTemplate {
     pub content: String,
     pub year_tabs: Vec<YearTab>,
     pub active_year: String,
+    pub range_form: StatsRangeForm,
+    pub range_presets: Vec<StatsRangePreset>,
+    pub goals: Vec<GoalView>,
+    /// Year linked to from "Year in review", when any year has data.
+    pub review_year: Option<i32>,
 }
 
 #[derive(Template)]
@@ -180,6 +257,18 @@ pub struct StatsContentTemplate {
     pub avg_days_formatted: String,
     /// Pre-formatted pipe-separated data for the rating donut chart.
     pub rating_chart_data: String,
+    /// Set when showing a custom date range, e.g. "Jan 1 – Mar 31, 2025".
+    pub period_label: Option<String>,
+    /// Deltas against a second period, when one was asked for.
+    pub comparison: Option<StatsComparisonView>,
+    /// The user's personal tags, sized by use. Empty in year views.
+    pub tag_cloud: Vec<TagCloudView>,
+    /// Day-by-day reading calendar for the past year, or the selected year.
+    pub heatmap: Option<HeatmapView>,
+    /// Quick-review usage and sentiment, when any quick reviews were used.
+    pub sentiment: Option<SentimentStatsView>,
+    /// Audiobook listening time, when any audiobooks were finished.
+    pub listening: Option<ListeningStatsView>,
 }
 
 #[derive(Template)]
@@ -212,6 +301,11 @@ pub struct BookDetailTemplate {
     pub library_info: Option<BookLibraryInfo>,
     pub readings: Vec<BookReadingCardView>,
     pub active_reading_id: Option<String>,
+    pub shelves: Vec<ShelfLinkView>,
+    pub shelf_options: Vec<ShelfOptionView>,
+    pub tags: Vec<TagView>,
+    /// The user's existing tags, offered as autocomplete suggestions.
+    pub tag_suggestions: Vec<String>,
 }
 
 #[derive(Template)]
@@ -241,6 +335,100 @@ pub struct GenreDetailTemplate {
     pub library_books: Vec<AuthorBookCardView>,
 }
 
+#[derive(Template)]
+#[template(path = "pages/shelves.html")]
+pub struct ShelvesTemplate {
+    pub nav_active: &'static str,
+    pub is_authenticated: bool,
+    pub version_info: &'static crate::VersionInfo,
+    pub is_impersonating: bool,
+    pub impersonated_username: String,
+    pub shelves: Vec<ShelfView>,
+}
+
+#[derive(Template)]
+#[template(path = "pages/shelf.html")]
+pub struct ShelfDetailTemplate {
+    pub nav_active: &'static str,
+    pub is_authenticated: bool,
+    pub version_info: &'static crate::VersionInfo,
+    pub is_impersonating: bool,
+    pub impersonated_username: String,
+    pub shelf: ShelfView,
+    pub edit_url: String,
+}
+
+/// A reader's public profile at `/u/{username}`.
+#[derive(Template)]
+#[template(path = "pages/profile.html")]
+pub struct ProfileTemplate {
+    pub nav_active: &'static str,
+    pub is_authenticated: bool,
+    pub version_info: &'static crate::VersionInfo,
+    pub is_impersonating: bool,
+    pub impersonated_username: String,
+    pub username: String,
+    pub feed_url: String,
+    pub currently_reading: Vec<ProfileReadingView>,
+    pub recent_finishes: Vec<ProfileReadingView>,
+    pub stats: ProfileStatsView,
+    pub shelves: Vec<ShelfView>,
+}
+
+#[derive(Template)]
+#[template(path = "pages/challenges.html")]
+pub struct ChallengesTemplate {
+    pub nav_active: &'static str,
+    pub is_authenticated: bool,
+    pub version_info: &'static crate::VersionInfo,
+    pub is_impersonating: bool,
+    pub impersonated_username: String,
+    pub challenges: Vec<ChallengeHeaderView>,
+}
+
+#[derive(Template)]
+#[template(path = "pages/review.html")]
+pub struct YearReviewTemplate {
+    pub nav_active: &'static str,
+    pub is_authenticated: bool,
+    pub version_info: &'static crate::VersionInfo,
+    pub is_impersonating: bool,
+    pub impersonated_username: String,
+    pub review: YearReviewView,
+    pub has_data: bool,
+    /// Years with finished books, for switching between reviews.
+    pub year_tabs: Vec<YearTab>,
+}
+
+#[derive(Template)]
+#[template(path = "pages/household.html")]
+pub struct HouseholdTemplate {
+    pub nav_active: &'static str,
+    pub is_authenticated: bool,
+    pub version_info: &'static crate::VersionInfo,
+    pub is_impersonating: bool,
+    pub impersonated_username: String,
+    pub stats: HouseholdStatsView,
+    /// Whether the viewing user's readings are left out.
+    pub opted_out: bool,
+}
+
+#[derive(Template)]
+#[template(path = "pages/challenge.html")]
+pub struct ChallengeDetailTemplate {
+    pub nav_active: &'static str,
+    pub is_authenticated: bool,
+    pub version_info: &'static crate::VersionInfo,
+    pub is_impersonating: bool,
+    pub impersonated_username: String,
+    pub challenge: ChallengeDetailView,
+    /// Whether the user may edit the challenge's details and prompts.
+    pub can_edit: bool,
+    pub edit_url: String,
+    /// The user's finished readings that can be assigned to a prompt by hand.
+    pub reading_options: Vec<PromptReadingView>,
+}
+
 // ── Edit page templates ──
 
 #[derive(Template)]
@@ -253,6 +441,13 @@ pub struct AuthorEditTemplate {
     pub impersonated_username: String,
     pub id: String,
     pub name: String,
+    pub bio: String,
+    pub birth_year: String,
+    pub death_year: String,
+    pub nationality: String,
+    pub website: String,
+    /// Comma-separated, as edited in the form.
+    pub aliases: String,
     pub image_url: Option<String>,
 }
 
@@ -266,6 +461,8 @@ pub struct GenreEditTemplate {
     pub impersonated_username: String,
     pub id: String,
     pub name: String,
+    pub parent_id: String,
+    pub parent_options: Vec<GenreOptionView>,
 }
 
 #[derive(Template)]
@@ -281,6 +478,8 @@ pub struct BookEditTemplate {
     pub isbn: String,
     pub description: String,
     pub page_count: String,
+    pub duration_hours: String,
+    pub duration_minutes: String,
     pub year_published: String,
     pub publisher: String,
     pub language: String,
@@ -352,6 +551,7 @@ pub struct FinishReadingTemplate {
 
 #[derive(Template)]
 #[template(path = "pages/edit_reading.html")]
+#[allow(clippy::struct_excessive_bools)] // checkbox values for the form
 pub struct ReadingEditTemplate {
     pub nav_active: &'static str,
     pub is_authenticated: bool,
@@ -365,12 +565,36 @@ pub struct ReadingEditTemplate {
     pub format: String,
     pub started_at: String,
     pub finished_at: String,
+    pub current_page: String,
     pub rating: String,
     pub quick_reviews: String,
     pub book_club: bool,
+    pub private: bool,
     pub book_options: Vec<BookOptionView>,
 }
 
+#[derive(Template)]
+#[template(path = "pages/edit_shelf.html")]
+pub struct ShelfEditTemplate {
+    pub nav_active: &'static str,
+    pub is_authenticated: bool,
+    pub version_info: &'static crate::VersionInfo,
+    pub is_impersonating: bool,
+    pub impersonated_username: String,
+    pub shelf: ShelfView,
+}
+
+#[derive(Template)]
+#[template(path = "pages/edit_challenge.html")]
+pub struct ChallengeEditTemplate {
+    pub nav_active: &'static str,
+    pub is_authenticated: bool,
+    pub version_info: &'static crate::VersionInfo,
+    pub is_impersonating: bool,
+    pub impersonated_username: String,
+    pub challenge: ChallengeHeaderView,
+}
+
 #[derive(Template)]
 #[template(path = "partials/image_upload.html")]
 pub struct ImageUploadTemplate<'a> {
@@ -380,6 +604,30 @@ pub struct ImageUploadTemplate<'a> {
     pub is_authenticated: bool,
 }
 
+#[derive(Template)]
+#[template(path = "feeds/atom.xml")]
+pub struct AtomFeedTemplate {
+    pub feed: FeedView,
+}
+
+#[derive(Template)]
+#[template(path = "feeds/rss.xml")]
+pub struct RssFeedTemplate {
+    pub feed: FeedView,
+}
+
+#[derive(Template)]
+#[template(path = "widgets/reading.html")]
+pub struct ReadingWidgetTemplate {
+    pub widget: WidgetView,
+}
+
+#[derive(Template)]
+#[template(path = "widgets/reading.svg")]
+pub struct ReadingBadgeTemplate {
+    pub widget: WidgetView,
+}
+
 pub fn render_template<T: Template>(template: T) -> Result<String, askama::Error> {
     template.render()
 }
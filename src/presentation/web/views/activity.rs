@@ -0,0 +1,125 @@
+use chrono::{Datelike, Days, NaiveDate};
+
+use crate::domain::activity::{ActivityDay, ReadingActivity};
+
+fn days_label(days: u32) -> String {
+    match days {
+        1 => "1 day".to_string(),
+        n => format!("{n} days"),
+    }
+}
+
+/// One square in the heatmap.
+pub struct HeatmapCell {
+    /// 0 (no reading) to 4; padding cells outside the range are always 0.
+    pub level: u8,
+    /// Tooltip, e.g. "Mar 3, 2025 · 2 in progress, 1 finished".
+    pub title: String,
+    pub in_range: bool,
+}
+
+impl HeatmapCell {
+    fn new(day: &ActivityDay) -> Self {
+        let mut parts = Vec::new();
+        if day.in_progress > 0 {
+            parts.push(format!("{} in progress", day.in_progress));
+        }
+        if day.started > 0 {
+            parts.push(format!("{} started", day.started));
+        }
+        if day.finished > 0 {
+            parts.push(format!("{} finished", day.finished));
+        }
+        let summary = if parts.is_empty() {
+            "No reading".to_string()
+        } else {
+            parts.join(", ")
+        };
+        Self {
+            level: day.level(),
+            title: format!("{} · {summary}", day.date.format("%b %-d, %Y")),
+            in_range: true,
+        }
+    }
+
+    fn padding() -> Self {
+        Self {
+            level: 0,
+            title: String::new(),
+            in_range: false,
+        }
+    }
+}
+
+/// A heatmap column: Monday to Sunday.
+pub struct HeatmapWeek {
+    /// Short month name on the first week of each month, otherwise empty.
+    pub month_label: String,
+    pub cells: Vec<HeatmapCell>,
+}
+
+/// GitHub-style calendar of reading activity.
+pub struct HeatmapView {
+    pub weeks: Vec<HeatmapWeek>,
+    /// e.g. "142 active days".
+    pub active_days_label: String,
+    pub current_streak_label: String,
+    pub longest_streak_label: String,
+}
+
+impl From<ReadingActivity> for HeatmapView {
+    fn from(activity: ReadingActivity) -> Self {
+        let mut weeks: Vec<HeatmapWeek> = Vec::new();
+        let leading = activity.from.weekday().num_days_from_monday() as usize;
+        let mut cells: Vec<HeatmapCell> = (0..leading).map(|_| HeatmapCell::padding()).collect();
+        let mut week_start = activity
+            .from
+            .checked_sub_days(Days::new(leading as u64))
+            .unwrap_or(activity.from);
+        let mut last_month = None;
+
+        for day in &activity.days {
+            cells.push(HeatmapCell::new(day));
+            if cells.len() == 7 {
+                weeks.push(week(&mut cells, week_start, &mut last_month, activity.from));
+                week_start = day.date.succ_opt().unwrap_or(day.date);
+            }
+        }
+        if !cells.is_empty() {
+            cells.resize_with(7, HeatmapCell::padding);
+            weeks.push(week(&mut cells, week_start, &mut last_month, activity.from));
+        }
+
+        let active = activity.active_days;
+        Self {
+            weeks,
+            active_days_label: match active {
+                1 => "1 active day".to_string(),
+                n => format!("{n} active days"),
+            },
+            current_streak_label: days_label(activity.streaks.current),
+            longest_streak_label: days_label(activity.streaks.longest),
+        }
+    }
+}
+
+/// Closes off a week's cells, labelling it when it holds the first shown day of a month.
+fn week(
+    cells: &mut Vec<HeatmapCell>,
+    week_start: NaiveDate,
+    last_month: &mut Option<u32>,
+    from: NaiveDate,
+) -> HeatmapWeek {
+    let first_shown = week_start.max(from);
+    let month = first_shown.month();
+    let month_label = if *last_month == Some(month) {
+        String::new()
+    } else {
+        *last_month = Some(month);
+        first_shown.format("%b").to_string()
+    };
+    HeatmapWeek {
+        month_label,
+        cells: std::mem::take(cells),
+    }
+}
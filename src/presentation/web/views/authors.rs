@@ -5,19 +5,44 @@ use super::author_path;
 pub struct AuthorDetailView {
     pub id: String,
     pub name: String,
+    pub bio: Option<String>,
+    /// e.g. "1903 – 1950", "b. 1965" or "d. 1616"; `None` if neither year is known.
+    pub lifespan: Option<String>,
+    pub nationality: Option<String>,
+    pub website: Option<String>,
+    pub aliases: Vec<String>,
     pub created_date: String,
     pub created_time: String,
 }
 
 impl AuthorDetailView {
     pub fn from_domain(author: Author) -> Self {
+        let details = author.details;
+        let lifespan = match (details.birth_year, details.death_year) {
+            (Some(birth), Some(death)) => Some(format!("{birth} – {death}")),
+            (Some(birth), None) => Some(format!("b. {birth}")),
+            (None, Some(death)) => Some(format!("d. {death}")),
+            (None, None) => None,
+        };
         Self {
             id: author.id.to_string(),
             name: author.name,
+            bio: details.bio,
+            lifespan,
+            nationality: details.nationality,
+            website: details.website,
+            aliases: details.aliases,
             created_date: author.created_at.format("%Y-%m-%d").to_string(),
             created_time: author.created_at.format("%H:%M").to_string(),
         }
     }
+
+    pub fn has_details(&self) -> bool {
+        self.lifespan.is_some()
+            || self.nationality.is_some()
+            || self.website.is_some()
+            || !self.aliases.is_empty()
+    }
 }
 
 pub struct AuthorOptionView {
@@ -58,6 +83,7 @@ impl From<Author> for AuthorView {
             id,
             name,
             created_at,
+            ..
         } = author;
 
         let detail_path = author_path(id);
@@ -143,6 +143,8 @@ pub struct BookDetailView {
     pub isbn: String,
     pub description: Option<String>,
     pub page_count: String,
+    /// Audiobook length, e.g. "11h 45m".
+    pub duration: Option<String>,
     pub year_published: String,
     pub publisher: String,
     pub language: String,
@@ -179,6 +181,9 @@ impl BookDetailView {
             isbn: or_em_dash(book.isbn.as_deref()),
             description: book.description,
             page_count: or_em_dash(book.page_count),
+            duration: book
+                .duration_minutes
+                .map(crate::domain::formatting::format_duration),
             year_published: or_em_dash(book.year_published),
             publisher: or_em_dash(book.publisher.as_deref()),
             language: or_em_dash(book.language.as_deref()),
@@ -0,0 +1,121 @@
+use crate::domain::challenges::{Challenge, ChallengeProgress, ChallengeSummary, PromptProgress};
+use crate::domain::readings::ReadingWithBook;
+
+use super::reading_path;
+
+fn challenge_path(id: impl std::fmt::Display) -> String {
+    format!("/challenges/{id}")
+}
+
+fn percent(completed: usize, total: usize) -> usize {
+    (completed * 100).checked_div(total).unwrap_or(0)
+}
+
+fn progress_label(completed: usize, total: usize) -> String {
+    match total {
+        1 => format!("{completed} / 1 prompt"),
+        n => format!("{completed} / {n} prompts"),
+    }
+}
+
+/// Shared fields for challenge list cards and detail pages.
+pub struct ChallengeHeaderView {
+    pub id: String,
+    pub detail_path: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// e.g. "2026", empty when readings from any year count.
+    pub year: String,
+    pub shared: bool,
+    /// e.g. "3 / 10 prompts".
+    pub progress_label: String,
+    pub percent: usize,
+}
+
+impl ChallengeHeaderView {
+    fn new(challenge: Challenge, completed: usize, total: usize) -> Self {
+        Self {
+            id: challenge.id.to_string(),
+            detail_path: challenge_path(challenge.id),
+            name: challenge.name,
+            description: challenge.description,
+            year: challenge.year.map(|y| y.to_string()).unwrap_or_default(),
+            shared: challenge.shared,
+            progress_label: progress_label(completed, total),
+            percent: percent(completed, total),
+        }
+    }
+}
+
+impl From<ChallengeSummary> for ChallengeHeaderView {
+    fn from(summary: ChallengeSummary) -> Self {
+        Self::new(summary.challenge, summary.completed, summary.total)
+    }
+}
+
+/// A finished reading shown against a prompt, either assigned or suggested.
+pub struct PromptReadingView {
+    pub reading_id: String,
+    pub detail_path: String,
+    pub title: String,
+    pub author_names: String,
+    pub finished_label: String,
+}
+
+impl From<ReadingWithBook> for PromptReadingView {
+    fn from(rwb: ReadingWithBook) -> Self {
+        Self {
+            reading_id: rwb.reading.id.to_string(),
+            detail_path: reading_path(rwb.reading.id),
+            title: rwb.book_title,
+            author_names: rwb.author_names,
+            finished_label: rwb
+                .reading
+                .finished_at
+                .map(|d| d.format("%b %-d, %Y").to_string())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+pub struct PromptView {
+    pub id: String,
+    pub label: String,
+    pub rule: String,
+    pub entry: Option<PromptReadingView>,
+    pub suggestions: Vec<PromptReadingView>,
+}
+
+impl From<PromptProgress> for PromptView {
+    fn from(progress: PromptProgress) -> Self {
+        Self {
+            id: progress.prompt.id.to_string(),
+            label: progress.prompt.label,
+            rule: progress.prompt.rule.unwrap_or_default(),
+            entry: progress.entry.map(PromptReadingView::from),
+            suggestions: progress
+                .suggestions
+                .into_iter()
+                .map(PromptReadingView::from)
+                .collect(),
+        }
+    }
+}
+
+pub struct ChallengeDetailView {
+    pub header: ChallengeHeaderView,
+    pub prompts: Vec<PromptView>,
+}
+
+impl From<ChallengeProgress> for ChallengeDetailView {
+    fn from(progress: ChallengeProgress) -> Self {
+        Self {
+            header: ChallengeHeaderView::new(
+                progress.challenge,
+                progress.completed,
+                progress.total,
+            ),
+            prompts: progress.prompts.into_iter().map(PromptView::from).collect(),
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use std::cmp::Ordering;
+
+use crate::domain::formatting::EM_DASH;
+use crate::domain::stats::{PeriodStats, StatsReport};
+
+/// Genres listed in the comparison's genre mix.
+const COMPARISON_GENRES: usize = 8;
+
+/// Direction of a change, for colouring deltas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Up,
+    Down,
+    Flat,
+}
+
+impl Trend {
+    fn of_count(delta: i64) -> Self {
+        match delta.cmp(&0) {
+            Ordering::Greater => Self::Up,
+            Ordering::Less => Self::Down,
+            Ordering::Equal => Self::Flat,
+        }
+    }
+
+    fn of(delta: f64) -> Self {
+        if delta > f64::EPSILON {
+            Self::Up
+        } else if delta < -f64::EPSILON {
+            Self::Down
+        } else {
+            Self::Flat
+        }
+    }
+
+    pub fn is_up(&self) -> bool {
+        *self == Self::Up
+    }
+
+    pub fn is_down(&self) -> bool {
+        *self == Self::Down
+    }
+}
+
+/// One headline figure for both periods, e.g. books read.
+pub struct ComparisonMetricView {
+    pub label: &'static str,
+    pub current: String,
+    pub compare: String,
+    /// Signed change, e.g. "+3" or "−0.4"; an em dash when it can't be worked out.
+    pub delta: String,
+    pub trend: Trend,
+}
+
+/// One genre's share of the books read in each period.
+pub struct GenreShareView {
+    pub genre: String,
+    /// e.g. "3 books · 30%".
+    pub current: String,
+    pub compare: String,
+    /// Change in percentage points, e.g. "+12 pts".
+    pub delta: String,
+    pub trend: Trend,
+}
+
+/// Two periods side by side, with the differences between them.
+pub struct StatsComparisonView {
+    pub period_label: String,
+    pub compare_label: String,
+    pub metrics: Vec<ComparisonMetricView>,
+    pub genres: Vec<GenreShareView>,
+}
+
+impl StatsComparisonView {
+    /// `None` unless the report compares two periods.
+    pub fn from_report(report: &StatsReport) -> Option<Self> {
+        let compare = report.compare.as_ref()?;
+        let delta = report.delta.as_ref()?;
+        let current = &report.current;
+
+        let rating = |stats: &PeriodStats| {
+            stats
+                .reading
+                .average_rating
+                .map_or_else(|| EM_DASH.to_string(), |r| format!("{r:.1}"))
+        };
+        let metrics = vec![
+            ComparisonMetricView {
+                label: "Books Read",
+                current: current.reading.books_all_time.to_string(),
+                compare: compare.reading.books_all_time.to_string(),
+                delta: signed_count(delta.books),
+                trend: Trend::of_count(delta.books),
+            },
+            ComparisonMetricView {
+                label: "Pages Read",
+                current: current.reading.pages_all_time.to_string(),
+                compare: compare.reading.pages_all_time.to_string(),
+                delta: signed_count(delta.pages),
+                trend: Trend::of_count(delta.pages),
+            },
+            ComparisonMetricView {
+                label: "Average Rating",
+                current: rating(current),
+                compare: rating(compare),
+                delta: delta
+                    .average_rating
+                    .map_or_else(|| EM_DASH.to_string(), |d| signed(d, 1)),
+                trend: Trend::of(delta.average_rating.unwrap_or(0.0)),
+            },
+        ];
+
+        let share = |books: u64, share: f64| {
+            let noun = if books == 1 { "book" } else { "books" };
+            format!("{books} {noun} · {share:.0}%")
+        };
+        let genres = delta
+            .genres
+            .iter()
+            .take(COMPARISON_GENRES)
+            .map(|g| GenreShareView {
+                genre: g.genre.clone(),
+                current: share(g.books, g.share),
+                compare: share(g.compare_books, g.compare_share),
+                delta: format!("{} pts", signed(g.share_change, 0)),
+                trend: Trend::of(g.share_change.round()),
+            })
+            .collect();
+
+        Some(Self {
+            period_label: current.period.label(),
+            compare_label: compare.period.label(),
+            metrics,
+            genres,
+        })
+    }
+}
+
+/// Formats a change with an explicit sign, using a proper minus for losses.
+fn signed(value: f64, decimals: usize) -> String {
+    let formatted = format!("{:.decimals$}", value.abs());
+    if formatted.trim_start_matches(['0', '.']).is_empty() {
+        return "±0".to_string();
+    }
+    let sign = if value > 0.0 { "+" } else { "\u{2212}" };
+    format!("{sign}{formatted}")
+}
+
+fn signed_count(value: i64) -> String {
+    match value.cmp(&0) {
+        Ordering::Greater => format!("+{value}"),
+        Ordering::Less => format!("\u{2212}{}", value.unsigned_abs()),
+        Ordering::Equal => "±0".to_string(),
+    }
+}
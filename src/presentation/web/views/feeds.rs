@@ -0,0 +1,108 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::analytics::timeline::{TimelineEvent, TimelineEventDetail, TimelineField};
+use crate::domain::feed_tokens::FeedKind;
+
+use super::TimelineEventView;
+
+/// A cover thumbnail attached to a feed entry.
+pub struct FeedEnclosureView {
+    pub url: String,
+    pub length: usize,
+    pub content_type: String,
+}
+
+pub struct FeedEntryView {
+    pub id: String,
+    pub title: String,
+    pub link: String,
+    /// RFC 3339, for Atom.
+    pub updated: String,
+    /// RFC 2822, for RSS.
+    pub pub_date: String,
+    /// Title, authors and rating, then any quick reviews.
+    pub summary: String,
+    pub rating: Option<String>,
+    pub enclosure: Option<FeedEnclosureView>,
+}
+
+pub struct FeedView {
+    pub title: String,
+    pub author: String,
+    /// Absolute URL of the feed itself, without its token.
+    pub self_url: String,
+    /// Absolute URL of the page the feed mirrors.
+    pub home_url: String,
+    pub updated: String,
+    pub pub_date: String,
+    pub entries: Vec<FeedEntryView>,
+}
+
+fn detail_value(details: &[TimelineEventDetail], key: TimelineField) -> Option<String> {
+    details
+        .iter()
+        .find(|d| d.key == Some(key))
+        .map(|d| d.value.trim().to_string())
+        .filter(|v| !v.is_empty() && v != crate::domain::formatting::EM_DASH)
+}
+
+impl FeedEntryView {
+    pub fn from_domain(
+        event: TimelineEvent,
+        base_url: &str,
+        enclosure: Option<FeedEnclosureView>,
+    ) -> Self {
+        let rating = detail_value(&event.details, TimelineField::Rating);
+        let notes = detail_value(&event.details, TimelineField::QuickReviews);
+        let occurred_at = event.occurred_at;
+        let view = TimelineEventView::from_domain(event);
+
+        let mut summary = view.title.clone();
+        if let Some(subtitle) = &view.subtitle {
+            summary.push_str(" \u{2014} ");
+            summary.push_str(subtitle);
+        }
+        if let Some(notes) = &notes {
+            summary.push_str(". ");
+            summary.push_str(notes);
+        }
+
+        Self {
+            id: format!("{base_url}{}#event-{}", view.link, view.id),
+            title: format!("{}: {}", view.kind_label, view.title),
+            link: format!("{base_url}{}", view.link),
+            updated: occurred_at.to_rfc3339(),
+            pub_date: occurred_at.to_rfc2822(),
+            summary,
+            rating,
+            enclosure,
+        }
+    }
+}
+
+impl FeedView {
+    pub fn new(
+        username: &str,
+        kind: FeedKind,
+        self_url: String,
+        base_url: &str,
+        updated: Option<DateTime<Utc>>,
+        entries: Vec<FeedEntryView>,
+    ) -> Self {
+        let title = match kind {
+            FeedKind::All => format!("{username}'s reading activity"),
+            FeedKind::Finished => format!("Books {username} finished"),
+        };
+        // Feeds need an updated date even when empty
+        let updated = updated.unwrap_or(DateTime::UNIX_EPOCH);
+        Self {
+            title,
+            author: username.to_string(),
+            self_url,
+            home_url: format!("{base_url}/timeline"),
+            updated: updated.to_rfc3339(),
+            pub_date: updated.to_rfc2822(),
+            entries,
+        }
+    }
+}
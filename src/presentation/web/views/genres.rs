@@ -1,18 +1,45 @@
-use crate::domain::books::genres::Genre;
+use crate::domain::books::genres::{Genre, GenreTree};
 
 use super::genre_path;
 
+pub struct GenreLinkView {
+    pub name: String,
+    pub detail_path: String,
+}
+
+impl From<&Genre> for GenreLinkView {
+    fn from(genre: &Genre) -> Self {
+        Self {
+            name: genre.name.clone(),
+            detail_path: genre_path(genre.id),
+        }
+    }
+}
+
 pub struct GenreDetailView {
     pub id: String,
     pub name: String,
     pub created_date: String,
     pub created_time: String,
+    /// Broader genres, from the top level down to the direct parent.
+    pub ancestors: Vec<GenreLinkView>,
+    pub subgenres: Vec<GenreLinkView>,
 }
 
 impl GenreDetailView {
-    pub fn from_domain(genre: Genre) -> Self {
+    pub fn from_domain(genre: Genre, tree: &GenreTree) -> Self {
         Self {
             id: genre.id.to_string(),
+            ancestors: tree
+                .ancestors(genre.id)
+                .into_iter()
+                .map(GenreLinkView::from)
+                .collect(),
+            subgenres: tree
+                .children(genre.id)
+                .into_iter()
+                .map(GenreLinkView::from)
+                .collect(),
             name: genre.name,
             created_date: genre.created_at.format("%Y-%m-%d").to_string(),
             created_time: genre.created_at.format("%H:%M").to_string(),
@@ -58,6 +85,7 @@ impl From<Genre> for GenreView {
             id,
             name,
             created_at,
+            ..
         } = genre;
 
         let detail_path = genre_path(id);
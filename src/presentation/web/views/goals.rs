@@ -0,0 +1,62 @@
+use crate::domain::goals::{GoalMetric, GoalPace, GoalProgress};
+use crate::domain::readings::ReadingFormat;
+
+pub struct GoalView {
+    pub id: String,
+    /// e.g. "Books in 2026" or "Pages · Jun 1 – Aug 31, 2026".
+    pub title: String,
+    /// Genre and format constraints, e.g. "Science Fiction · Audiobook".
+    pub constraint: Option<String>,
+    /// e.g. "12 / 30 books".
+    pub progress_label: String,
+    pub percent: u8,
+    pub pace_label: String,
+    pub is_behind: bool,
+    pub is_complete: bool,
+    pub days_remaining_label: String,
+}
+
+impl GoalView {
+    /// `genre_name` is the display name of the goal's genre constraint, if any.
+    pub fn from_domain(progress: GoalProgress, genre_name: Option<String>) -> Self {
+        let goal = &progress.goal;
+        let metric_label = match goal.metric {
+            GoalMetric::Books => "Books",
+            GoalMetric::Pages => "Pages",
+        };
+        let title = match goal.calendar_year() {
+            Some(year) => format!("{metric_label} in {year}"),
+            None => format!("{metric_label} · {}", goal.period_label()),
+        };
+
+        let constraint_parts: Vec<&str> = genre_name
+            .as_deref()
+            .into_iter()
+            .chain(goal.format.as_ref().map(ReadingFormat::display_label))
+            .collect();
+        let constraint = (!constraint_parts.is_empty()).then(|| constraint_parts.join(" · "));
+
+        let progress_label = format!(
+            "{} / {}",
+            progress.current,
+            goal.metric.format_amount(goal.target)
+        );
+        let days_remaining_label = match progress.days_remaining {
+            0 => "Ended".to_string(),
+            1 => "1 day left".to_string(),
+            n => format!("{n} days left"),
+        };
+
+        Self {
+            id: goal.id.to_string(),
+            title,
+            constraint,
+            progress_label,
+            percent: progress.percent,
+            pace_label: progress.pace_label(),
+            is_behind: progress.pace == GoalPace::Behind,
+            is_complete: progress.pace == GoalPace::Complete,
+            days_remaining_label,
+        }
+    }
+}
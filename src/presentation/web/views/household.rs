@@ -0,0 +1,99 @@
+use crate::domain::formatting::format_number;
+use crate::domain::household::{HouseholdBook, HouseholdStats, YearLeaderboard};
+
+use super::book_path;
+
+pub struct HouseholdBookView {
+    pub title: String,
+    pub book_path: String,
+    /// e.g. "3 readers".
+    pub readers: String,
+}
+
+impl HouseholdBookView {
+    fn new(book: &HouseholdBook) -> Self {
+        Self {
+            title: book.title.clone(),
+            book_path: book_path(book.book_id),
+            readers: match book.readers {
+                1 => "1 reader".to_string(),
+                n => format!("{n} readers"),
+            },
+        }
+    }
+}
+
+pub struct LeaderboardRowView {
+    pub rank: usize,
+    pub username: String,
+    pub books: u64,
+    pub pages: String,
+}
+
+pub struct LeaderboardView {
+    pub year: i32,
+    pub rows: Vec<LeaderboardRowView>,
+}
+
+impl LeaderboardView {
+    fn new(board: &YearLeaderboard) -> Self {
+        Self {
+            year: board.year,
+            rows: board
+                .entries
+                .iter()
+                .enumerate()
+                .map(|(i, entry)| LeaderboardRowView {
+                    rank: i + 1,
+                    username: entry.username.clone(),
+                    books: entry.books,
+                    pages: format_number(entry.pages),
+                })
+                .collect(),
+        }
+    }
+}
+
+pub struct MonthReaderView {
+    pub username: String,
+    /// e.g. "Dune · Emma".
+    pub titles: String,
+}
+
+pub struct HouseholdStatsView {
+    pub members: u64,
+    pub books_read: u64,
+    pub pages_read: String,
+    pub top_books: Vec<HouseholdBookView>,
+    pub top_authors: Vec<(String, u64)>,
+    /// e.g. "October 2026".
+    pub month_label: String,
+    pub this_month: Vec<MonthReaderView>,
+    pub leaderboards: Vec<LeaderboardView>,
+}
+
+impl From<&HouseholdStats> for HouseholdStatsView {
+    fn from(stats: &HouseholdStats) -> Self {
+        Self {
+            members: stats.members,
+            books_read: stats.books_read,
+            pages_read: format_number(stats.pages_read),
+            top_books: stats.top_books.iter().map(HouseholdBookView::new).collect(),
+            top_authors: stats.top_authors.clone(),
+            month_label: stats.month.format("%B %Y").to_string(),
+            this_month: stats
+                .this_month
+                .iter()
+                .map(|reader| MonthReaderView {
+                    username: reader.username.clone(),
+                    titles: reader.titles.join(" \u{b7} "),
+                })
+                .collect(),
+            leaderboards: stats
+                .leaderboards
+                .iter()
+                .map(LeaderboardView::new)
+                .collect(),
+        }
+    }
+}
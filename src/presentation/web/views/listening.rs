@@ -0,0 +1,62 @@
+use crate::domain::formatting::{EM_DASH, format_duration, format_number};
+use crate::domain::listening::PAGES_PER_LISTENING_HOUR;
+use crate::domain::stats::ReadingStats;
+
+/// One bar of listening time: label, hours for the bar width, and the
+/// formatted duration.
+pub type ListeningBar = (String, f64, String);
+
+pub struct ListeningStatsView {
+    pub audiobooks: u64,
+    /// e.g. "42h 10m".
+    pub time_listened: String,
+    /// e.g. "1h 5m/day".
+    pub pace: String,
+    /// Pages plus listening time in page-equivalents, e.g. "12,480".
+    pub effort: String,
+    pub pages_per_hour: i64,
+    /// Hours per month in year views, per year in the all-time view.
+    pub bars: Vec<ListeningBar>,
+    pub max_hours: f64,
+}
+
+impl ListeningStatsView {
+    /// `None` when no audiobooks with a running time were finished.
+    pub fn from_stats(reading: &ReadingStats, is_year_view: bool) -> Option<Self> {
+        let stats = &reading.listening;
+        if stats.is_empty() {
+            return None;
+        }
+        let series = if is_year_view {
+            &stats.monthly_minutes
+        } else {
+            &stats.yearly_minutes
+        };
+        // Minute totals stay far below where f64 loses precision
+        #[allow(clippy::cast_precision_loss)]
+        let bars: Vec<ListeningBar> = series
+            .iter()
+            .map(|(label, minutes)| {
+                (
+                    label.clone(),
+                    *minutes as f64 / 60.0,
+                    i32::try_from(*minutes).map_or_else(|_| EM_DASH.to_string(), format_duration),
+                )
+            })
+            .collect();
+        Some(Self {
+            audiobooks: stats.audiobooks,
+            time_listened: i32::try_from(stats.minutes)
+                .map_or_else(|_| EM_DASH.to_string(), format_duration),
+            #[allow(clippy::cast_possible_truncation)]
+            pace: stats.minutes_per_day().map_or_else(
+                || EM_DASH.to_string(),
+                |m| format!("{}/day", format_duration(m.round() as i32)),
+            ),
+            effort: format_number(reading.effort()),
+            pages_per_hour: PAGES_PER_LISTENING_HOUR,
+            max_hours: bars.iter().map(|b| b.1).fold(0.0, f64::max),
+            bars,
+        })
+    }
+}
@@ -1,19 +1,49 @@
+mod activity;
 mod authors;
 mod books;
+mod challenges;
+mod comparison;
+mod feeds;
 mod genres;
+mod goals;
+mod household;
+mod listening;
+mod profile;
 mod readings;
+mod review;
+mod sentiment;
+mod shelves;
+mod tags;
 mod timeline;
+mod widgets;
 
+pub use activity::{HeatmapCell, HeatmapView, HeatmapWeek};
 pub use authors::{AuthorDetailView, AuthorOptionView, AuthorView};
 pub use books::{
     AuthorBookCardView, BookDetailView, BookLibraryInfo, BookOptionView, BookReadingCardView,
     BookView, UserBookView,
 };
-pub use genres::{GenreDetailView, GenreOptionView, GenreView};
+pub use challenges::{ChallengeDetailView, ChallengeHeaderView, PromptReadingView, PromptView};
+pub use comparison::{ComparisonMetricView, GenreShareView, StatsComparisonView, Trend};
+pub use feeds::{FeedEnclosureView, FeedEntryView, FeedView};
+pub use genres::{GenreDetailView, GenreLinkView, GenreOptionView, GenreView};
+pub use goals::GoalView;
+pub use household::{
+    HouseholdBookView, HouseholdStatsView, LeaderboardRowView, LeaderboardView, MonthReaderView,
+};
+pub use listening::ListeningStatsView;
+pub use profile::{ProfileReadingView, ProfileStatsView};
 pub use readings::{QuickReviewView, ReadingDetailView, ReadingView};
+pub use review::{FormatShareView, ReviewBookView, ReviewChipView, YearReviewView};
+pub use sentiment::{ChipUsageView, SentimentBarView, SentimentStatsView};
+pub use shelves::{ShelfLinkView, ShelfOptionView, ShelfView};
+pub use tags::{TagCloudView, TagView};
 pub use timeline::{
-    TimelineEventDetailView, TimelineEventView, TimelineMonthView, TimelineReadingDataView,
+    TimelineEventChangeView, TimelineEventDetailView, TimelineEventView, TimelineFilterChip,
+    TimelineFilterOption, TimelineFiltersView, TimelineMonthView, TimelineReadingDataView,
+    timeline_filter_path, timeline_filter_query,
 };
+pub use widgets::{WidgetPalette, WidgetReadingView, WidgetView};
 
 pub struct StatsView {
     pub library: u64,
@@ -189,6 +219,7 @@ pub struct ListNavigator<K: SortKey> {
     fragment_path: String,
     request: ListRequest<K>,
     search: Option<String>,
+    search_error: Option<String>,
 }
 
 impl<K: SortKey> ListNavigator<K> {
@@ -203,9 +234,16 @@ impl<K: SortKey> ListNavigator<K> {
             fragment_path: fragment_path.into(),
             request,
             search,
+            search_error: None,
         }
     }
 
+    /// Attaches a message explaining why the search query could not be run.
+    pub fn with_search_error(mut self, error: Option<String>) -> Self {
+        self.search_error = error;
+        self
+    }
+
     pub const fn request(&self) -> ListRequest<K> {
         self.request
     }
@@ -298,6 +336,10 @@ impl<K: SortKey> ListNavigator<K> {
         self.search.is_some()
     }
 
+    pub fn search_error(&self) -> Option<&str> {
+        self.search_error.as_deref()
+    }
+
     /// Returns the base path without query or fragment.
     pub fn path(&self) -> &str {
         &self.base_path
@@ -0,0 +1,105 @@
+use chrono::{Datelike, NaiveDate};
+
+use crate::domain::books::readings::ReadingWithBook;
+use crate::domain::formatting::{format_number, format_rating};
+use crate::domain::users::ProfilePrivacy;
+
+use super::QuickReviewView;
+
+/// A book on a public profile. Ratings and quick reviews are left out
+/// unless the reader chose to show them.
+pub struct ProfileReadingView {
+    pub book_id: String,
+    pub book_title: String,
+    pub author_names: String,
+    /// e.g. "Started Mar 3, 2026" or "Finished Apr 12, 2026".
+    pub date_label: Option<String>,
+    pub rating: Option<String>,
+    pub quick_reviews: Vec<QuickReviewView>,
+    pub thumbnail_url: Option<String>,
+}
+
+impl ProfileReadingView {
+    pub fn from_domain(rwb: ReadingWithBook, privacy: ProfilePrivacy) -> Self {
+        let reading = rwb.reading;
+        let date_label = match (reading.finished_at, reading.started_at) {
+            (Some(finished), _) => Some(format!("Finished {}", format_day(finished))),
+            (None, Some(started)) => Some(format!("Started {}", format_day(started))),
+            (None, None) => None,
+        };
+        let quick_reviews = if privacy.show_quick_reviews {
+            reading
+                .quick_reviews
+                .into_iter()
+                .map(QuickReviewView::from)
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Self {
+            book_id: reading.book_id.to_string(),
+            book_title: rwb.book_title,
+            author_names: if rwb.author_names.is_empty() {
+                "Unknown".to_string()
+            } else {
+                rwb.author_names
+            },
+            date_label,
+            rating: reading
+                .rating
+                .filter(|_| privacy.show_ratings)
+                .map(format_rating),
+            quick_reviews,
+            thumbnail_url: None,
+        }
+    }
+}
+
+/// Headline numbers for a public profile, from the reader's non-private
+/// finished readings.
+pub struct ProfileStatsView {
+    pub year: i32,
+    pub finished_this_year: usize,
+    pub finished_total: usize,
+    pub pages_this_year: String,
+    /// Only when ratings are shown.
+    pub average_rating: Option<String>,
+}
+
+impl ProfileStatsView {
+    pub fn new(finished: &[ReadingWithBook], today: NaiveDate, privacy: ProfilePrivacy) -> Self {
+        let year = today.year();
+        let this_year: Vec<&ReadingWithBook> = finished
+            .iter()
+            .filter(|rwb| rwb.reading.finished_at.is_some_and(|d| d.year() == year))
+            .collect();
+        let pages: i64 = this_year
+            .iter()
+            .filter_map(|rwb| rwb.page_count)
+            .map(i64::from)
+            .sum();
+
+        let ratings: Vec<f64> = finished
+            .iter()
+            .filter_map(|rwb| rwb.reading.rating)
+            .collect();
+        let average_rating = (privacy.show_ratings && !ratings.is_empty()).then(|| {
+            #[allow(clippy::cast_precision_loss)]
+            let average = ratings.iter().sum::<f64>() / ratings.len() as f64;
+            format!("{average:.1}/5")
+        });
+
+        Self {
+            year,
+            finished_this_year: this_year.len(),
+            finished_total: finished.len(),
+            pages_this_year: format_number(pages),
+            average_rating,
+        }
+    }
+}
+
+fn format_day(date: NaiveDate) -> String {
+    date.format("%b %-d, %Y").to_string()
+}
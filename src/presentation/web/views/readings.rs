@@ -97,6 +97,7 @@ pub struct ReadingDetailView {
     pub format_label: String,
     pub rating: String,
     pub quick_reviews: Vec<QuickReviewView>,
+    pub private: bool,
     pub started_date: String,
     pub finished_date: String,
     pub created_date: String,
@@ -124,6 +125,7 @@ impl ReadingDetailView {
                 .copied()
                 .map(QuickReviewView::from)
                 .collect(),
+            private: rwb.reading.private,
             started_date: or_em_dash(rwb.reading.started_at),
             finished_date: or_em_dash(rwb.reading.finished_at),
             created_date: rwb.reading.created_at.format("%Y-%m-%d").to_string(),
@@ -0,0 +1,111 @@
+use std::collections::HashSet;
+
+use crate::domain::formatting::{format_number, format_pages, format_rating};
+use crate::domain::review::{ReviewBook, YearInReview};
+
+use super::{QuickReviewView, book_path, reading_path};
+
+pub struct ReviewBookView {
+    pub title: String,
+    pub author_names: String,
+    pub detail_path: String,
+    pub book_path: String,
+    pub rating: Option<String>,
+    pub finished_date: String,
+    pub thumbnail_url: Option<String>,
+}
+
+impl ReviewBookView {
+    fn new(book: &ReviewBook, books_with_images: &HashSet<i64>) -> Self {
+        let book_id = i64::from(book.book_id);
+        Self {
+            title: book.title.clone(),
+            author_names: if book.author_names.is_empty() {
+                "Unknown".to_string()
+            } else {
+                book.author_names.clone()
+            },
+            detail_path: reading_path(book.reading_id),
+            book_path: book_path(book.book_id),
+            rating: book.rating.map(format_rating),
+            finished_date: book.finished_at.format("%b %-d").to_string(),
+            thumbnail_url: books_with_images
+                .contains(&book_id)
+                .then(|| format!("/api/v1/book/{book_id}/thumbnail")),
+        }
+    }
+}
+
+/// One share of the format split, e.g. "eReader · 12 · 40%".
+pub struct FormatShareView {
+    pub label: String,
+    pub count: u64,
+    pub percent: u64,
+}
+
+pub struct ReviewChipView {
+    pub chip: QuickReviewView,
+    pub count: u64,
+}
+
+pub struct YearReviewView {
+    pub year: i32,
+    pub books_read: u64,
+    pub pages_read: String,
+    pub average_rating: Option<String>,
+    pub top_author: Option<String>,
+    pub top_genre: Option<String>,
+    /// e.g. "The Stand · 1,152 pages".
+    pub longest_book: Option<String>,
+    pub formats: Vec<FormatShareView>,
+    pub top_books: Vec<ReviewBookView>,
+    pub first_finished: Option<ReviewBookView>,
+    pub last_finished: Option<ReviewBookView>,
+    pub chips: Vec<ReviewChipView>,
+    pub card_url: String,
+}
+
+impl YearReviewView {
+    /// `books_with_images` holds the IDs of books with a cover thumbnail.
+    pub fn from_domain(review: &YearInReview, books_with_images: &HashSet<i64>) -> Self {
+        let format_total: u64 = review.format_split.iter().map(|(_, count)| count).sum();
+        let formats = review
+            .format_split
+            .iter()
+            .map(|(label, count)| FormatShareView {
+                label: label.clone(),
+                count: *count,
+                percent: (count * 100).checked_div(format_total).unwrap_or(0),
+            })
+            .collect();
+        let book = |b: &ReviewBook| ReviewBookView::new(b, books_with_images);
+
+        Self {
+            year: review.year,
+            books_read: review.books_read,
+            pages_read: format_number(review.pages_read),
+            average_rating: review
+                .average_rating
+                .map(|r| format!("{:.1}/5", (r * 10.0).round() / 10.0)),
+            top_author: review.top_author.clone(),
+            top_genre: review.top_genre.clone(),
+            longest_book: review
+                .longest_book
+                .as_ref()
+                .map(|(title, pages)| format!("{title} · {}", format_pages(*pages))),
+            formats,
+            top_books: review.top_books.iter().map(book).collect(),
+            first_finished: review.first_finished.as_ref().map(book),
+            last_finished: review.last_finished.as_ref().map(book),
+            chips: review
+                .top_quick_reviews
+                .iter()
+                .map(|&(chip, count)| ReviewChipView {
+                    chip: chip.into(),
+                    count,
+                })
+                .collect(),
+            card_url: format!("/api/v1/review/{}/card.png", review.year),
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::domain::formatting::EM_DASH;
+use crate::domain::sentiment::{QuickReviewUsage, SentimentCounts};
+use crate::domain::stats::{BookSummaryStats, ReadingStats};
+
+use super::QuickReviewView;
+
+/// Chips listed in the most-used quick reviews.
+const TOP_CHIPS: usize = 10;
+
+/// One quick-review chip with the average rating and pace of the readings
+/// it was used on.
+pub struct ChipUsageView {
+    pub chip: QuickReviewView,
+    pub readings: u64,
+    /// e.g. "3.8/5".
+    pub average_rating: String,
+    /// e.g. "24 pages/day".
+    pub pace: String,
+}
+
+impl ChipUsageView {
+    fn new(usage: &QuickReviewUsage) -> Self {
+        Self {
+            chip: usage.review.into(),
+            readings: usage.readings,
+            average_rating: usage
+                .average_rating()
+                .map_or_else(|| EM_DASH.to_string(), |r| format!("{r:.1}/5")),
+            pace: usage.average_pages_per_day().map_or_else(
+                || EM_DASH.to_string(),
+                |p| format!("{} pages/day", p.round() as i64),
+            ),
+        }
+    }
+}
+
+/// Sentiment of one group of readings as a stacked positive / neutral /
+/// negative bar.
+pub struct SentimentBarView {
+    pub label: String,
+    pub chips: u64,
+    pub positive_percent: u64,
+    pub neutral_percent: u64,
+    pub negative_percent: u64,
+    /// Net positive share, e.g. "+40%" or "−25%".
+    pub balance: String,
+    pub balance_is_negative: bool,
+}
+
+impl SentimentBarView {
+    fn new(label: String, counts: SentimentCounts) -> Self {
+        let total = counts.total();
+        let percent = |n: u64| (n * 100).checked_div(total).unwrap_or(0);
+        let positive_percent = percent(counts.positive);
+        let negative_percent = percent(counts.negative);
+        let balance = counts.balance().unwrap_or(0.0);
+        Self {
+            label,
+            chips: total,
+            positive_percent,
+            negative_percent,
+            // Whatever rounding leaves over, so the bar is always full
+            neutral_percent: 100 - positive_percent - negative_percent,
+            balance: if balance < 0.0 {
+                format!("\u{2212}{:.0}%", -balance * 100.0)
+            } else {
+                format!("+{:.0}%", balance * 100.0)
+            },
+            balance_is_negative: balance < 0.0,
+        }
+    }
+
+    fn list<K>(groups: &[(K, SentimentCounts)], label: impl Fn(&K) -> String) -> Vec<Self> {
+        groups
+            .iter()
+            .map(|(key, counts)| Self::new(label(key), *counts))
+            .collect()
+    }
+}
+
+/// The quick reviews section of the stats page.
+pub struct SentimentStatsView {
+    pub chips: Vec<ChipUsageView>,
+    pub by_rating: Vec<SentimentBarView>,
+    pub by_pace: Vec<SentimentBarView>,
+    pub genres: Vec<SentimentBarView>,
+    pub authors: Vec<SentimentBarView>,
+}
+
+impl SentimentStatsView {
+    /// `None` when no quick reviews were used.
+    pub fn from_stats(reading: &ReadingStats, book_summary: &BookSummaryStats) -> Option<Self> {
+        let stats = &reading.quick_reviews;
+        if stats.is_empty() {
+            return None;
+        }
+        Some(Self {
+            chips: stats
+                .usage
+                .iter()
+                .take(TOP_CHIPS)
+                .map(ChipUsageView::new)
+                .collect(),
+            by_rating: SentimentBarView::list(&stats.by_rating, |rating| {
+                format!("{rating}\u{2605}")
+            }),
+            by_pace: SentimentBarView::list(&stats.by_pace, Clone::clone),
+            genres: SentimentBarView::list(&book_summary.genre_sentiment, Clone::clone),
+            authors: SentimentBarView::list(&book_summary.author_sentiment, Clone::clone),
+        })
+    }
+}
@@ -0,0 +1,80 @@
+use crate::domain::book_items::BookWithAuthors;
+use crate::domain::shelves::{SavedShelf, ShelfKind};
+
+use super::AuthorBookCardView;
+
+pub(super) fn shelf_path(id: impl std::fmt::Display) -> String {
+    format!("/shelves/{id}")
+}
+
+pub struct ShelfView {
+    pub id: String,
+    pub detail_path: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub kind_label: &'static str,
+    pub is_smart: bool,
+    pub query: String,
+    pub pinned: bool,
+    pub public: bool,
+    pub book_count_label: String,
+    pub books: Vec<AuthorBookCardView>,
+}
+
+impl ShelfView {
+    pub fn from_domain(shelf: SavedShelf, books: Vec<BookWithAuthors>) -> Self {
+        let book_count_label = match books.len() {
+            1 => "1 book".to_string(),
+            n => format!("{n} books"),
+        };
+
+        Self {
+            id: shelf.id.to_string(),
+            detail_path: shelf_path(shelf.id),
+            name: shelf.name,
+            description: shelf.description,
+            kind_label: shelf.kind.display_label(),
+            is_smart: shelf.kind == ShelfKind::Smart,
+            query: shelf.query.unwrap_or_default(),
+            pinned: shelf.pinned,
+            public: shelf.public,
+            book_count_label,
+            books: books
+                .into_iter()
+                .map(AuthorBookCardView::from_domain)
+                .collect(),
+        }
+    }
+}
+
+/// A manual shelf the current book can be added to.
+pub struct ShelfOptionView {
+    pub id: String,
+    pub name: String,
+}
+
+impl From<SavedShelf> for ShelfOptionView {
+    fn from(shelf: SavedShelf) -> Self {
+        Self {
+            id: shelf.id.to_string(),
+            name: shelf.name,
+        }
+    }
+}
+
+/// A shelf shown as a link on the book detail page.
+pub struct ShelfLinkView {
+    pub detail_path: String,
+    pub name: String,
+    pub is_smart: bool,
+}
+
+impl From<SavedShelf> for ShelfLinkView {
+    fn from(shelf: SavedShelf) -> Self {
+        Self {
+            detail_path: shelf_path(shelf.id),
+            name: shelf.name,
+            is_smart: shelf.kind == ShelfKind::Smart,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use std::fmt::Display;
+
+use crate::domain::tags::TagCount;
+
+fn encode(value: &str) -> String {
+    url::form_urlencoded::byte_serialize(value.as_bytes()).collect()
+}
+
+/// Path to the user's library filtered to a tag.
+fn tag_library_path(tag: &str) -> String {
+    format!("/data?type=library&q={}", encode(&format!("tag:{tag}")))
+}
+
+/// A personal tag shown as a pill on the book detail page.
+pub struct TagView {
+    pub name: String,
+    pub library_path: String,
+    /// API path that removes this tag from the book.
+    pub remove_path: String,
+}
+
+impl TagView {
+    pub fn for_book(book_id: impl Display, name: String) -> Self {
+        Self {
+            library_path: tag_library_path(&name),
+            remove_path: format!("/api/v1/books/{book_id}/tags/{}", encode(&name)),
+            name,
+        }
+    }
+}
+
+/// A tag in the stats page tag cloud. `weight` runs from 1 (least used) to 4
+/// (most used) and picks the font size.
+pub struct TagCloudView {
+    pub name: String,
+    pub library_path: String,
+    pub count: i64,
+    pub weight: u8,
+}
+
+impl TagCloudView {
+    /// Builds cloud entries from tag counts, sorted alphabetically.
+    pub fn from_counts(counts: Vec<TagCount>) -> Vec<Self> {
+        let max = counts.iter().map(|c| c.count).max().unwrap_or(1).max(1);
+        let mut cloud: Vec<Self> = counts
+            .into_iter()
+            .map(|c| Self {
+                library_path: tag_library_path(&c.tag),
+                weight: (1 + (c.count * 3) / max).clamp(1, 4) as u8,
+                count: c.count,
+                name: c.tag,
+            })
+            .collect();
+        cloud.sort_by(|a, b| a.name.cmp(&b.name));
+        cloud
+    }
+}
@@ -1,5 +1,10 @@
-use crate::domain::analytics::timeline::{TimelineEvent, TimelineEventDetail};
+use crate::domain::analytics::timeline::{
+    TIMELINE_ACTIONS, TIMELINE_ENTITY_TYPES, TimelineEvent, TimelineEventDetail, TimelineFilter,
+};
+use crate::domain::books::genres::Genre;
 use crate::domain::books::quick_reviews::QuickReview;
+use crate::domain::ids::{GenreId, UserId};
+use crate::domain::users::User;
 
 use super::{author_path, book_path, genre_path, reading_path, relative_date};
 
@@ -23,6 +28,14 @@ pub struct TimelineReadingDataView {
     pub status: String,
 }
 
+/// A before/after pair, with blanks shown as an em-dash.
+#[derive(Clone)]
+pub struct TimelineEventChangeView {
+    pub label: String,
+    pub before: String,
+    pub after: String,
+}
+
 #[derive(Clone)]
 pub struct QuickNoteView {
     pub label: String,
@@ -46,6 +59,7 @@ pub struct TimelineEventView {
     pub genres: Option<Vec<String>>,
     pub quick_notes: Option<Vec<QuickNoteView>>,
     pub reading_data: Option<TimelineReadingDataView>,
+    pub changes: Vec<TimelineEventChangeView>,
 }
 
 pub struct TimelineMonthView {
@@ -54,6 +68,31 @@ pub struct TimelineMonthView {
     pub events: Vec<TimelineEventView>,
 }
 
+impl TimelineMonthView {
+    /// Groups events, in the order given, under a heading for each month.
+    pub fn group(events: Vec<TimelineEvent>) -> Vec<Self> {
+        let mut months: Vec<Self> = Vec::new();
+
+        for event in events {
+            let anchor = event.occurred_at.format("%Y-%m").to_string();
+            if let Some(last) = months.last_mut()
+                && last.anchor == anchor
+            {
+                last.events.push(TimelineEventView::from_domain(event));
+                continue;
+            }
+
+            months.push(Self {
+                anchor,
+                heading: event.occurred_at.format("%B %Y").to_string(),
+                events: vec![TimelineEventView::from_domain(event)],
+            });
+        }
+
+        months
+    }
+}
+
 impl TimelineEventView {
     pub fn from_domain(event: TimelineEvent) -> Self {
         let TimelineEvent {
@@ -66,25 +105,32 @@ impl TimelineEventView {
             details,
             genres,
             reading_data,
+            changes,
         } = event;
 
         let kind_label = match (entity_type.as_str(), action.as_str()) {
             ("author", "added") => "Author Added",
             ("book", "added") => "Book Added",
             ("book", "shelved") => "Shelved",
+            ("user_book", "moved") => "Moved",
+            ("user_book", "book_club") => "Book Club",
             ("reading", "want to read") => "Want to Read",
             ("reading", "started") => "Started",
             ("reading", "finished") => "Finished",
             ("reading", "abandoned") => "Abandoned",
+            ("reading", "rated") => "Rated",
+            ("reading", "milestone") => "Reading Milestone",
+            ("goal", "milestone") => "Goal Milestone",
             ("genre", "added") => "Genre Added",
             _ => "Event",
         };
 
         let link = match entity_type.as_str() {
             "author" => author_path(entity_id),
-            "book" => book_path(entity_id),
+            "book" | "user_book" => book_path(entity_id),
             "reading" => reading_path(entity_id),
             "genre" => genre_path(entity_id),
+            "goal" => String::from("/stats"),
             _ => String::from("#"),
         };
 
@@ -120,6 +166,18 @@ impl TimelineEventView {
             genres,
             quick_notes,
             reading_data: reading_data_view,
+            changes: changes
+                .into_iter()
+                .map(|c| TimelineEventChangeView {
+                    label: c.label,
+                    before: c
+                        .before
+                        .unwrap_or_else(|| crate::domain::formatting::EM_DASH.to_string()),
+                    after: c
+                        .after
+                        .unwrap_or_else(|| crate::domain::formatting::EM_DASH.to_string()),
+                })
+                .collect(),
         }
     }
 
@@ -133,7 +191,7 @@ impl TimelineEventView {
         };
 
         let picks: &[&str] = match entity_type {
-            "book" => &["Author"],
+            "book" | "user_book" => &["Author"],
             "reading" => &["Author", "Rating"],
             "author" => &["Nationality"],
             _ => &[],
@@ -218,3 +276,179 @@ impl TimelineEventView {
         (mapped, external_link, quick_notes)
     }
 }
+
+/// A choice in one of the timeline filter selects.
+pub struct TimelineFilterOption {
+    pub value: String,
+    pub label: String,
+    pub selected: bool,
+}
+
+/// An active timeline filter, with the link that drops it.
+pub struct TimelineFilterChip {
+    pub label: String,
+    pub remove_href: String,
+}
+
+/// The timeline filter form and the chips for the filters in effect.
+pub struct TimelineFiltersView {
+    pub types: Vec<TimelineFilterOption>,
+    pub actions: Vec<TimelineFilterOption>,
+    pub genres: Vec<TimelineFilterOption>,
+    /// Empty when the viewer isn't signed in.
+    pub users: Vec<TimelineFilterOption>,
+    pub since: String,
+    pub until: String,
+    pub chips: Vec<TimelineFilterChip>,
+}
+
+fn entity_type_label(entity_type: &str) -> String {
+    match entity_type {
+        "book" => "Books".to_string(),
+        "reading" => "Readings".to_string(),
+        "author" => "Authors".to_string(),
+        "genre" => "Genres".to_string(),
+        "user_book" => "Shelf changes".to_string(),
+        "goal" => "Goals".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn action_label(action: &str) -> String {
+    let action = action.replace('_', " ");
+    let mut chars = action.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+/// Query string for a timeline filter, e.g. `type=reading&since=2025-01-01`.
+pub fn timeline_filter_query(filter: &TimelineFilter) -> String {
+    let mut query = url::form_urlencoded::Serializer::new(String::new());
+    if let Some(entity_type) = &filter.entity_type {
+        query.append_pair("type", entity_type);
+    }
+    if let Some(action) = &filter.action {
+        query.append_pair("action", action);
+    }
+    if let Some(genre_id) = filter.genre_id {
+        query.append_pair("genre", &genre_id.to_string());
+    }
+    if let Some(user_id) = filter.user_id {
+        query.append_pair("user", &user_id.to_string());
+    }
+    if let Some(since) = filter.since {
+        query.append_pair("since", &since.to_string());
+    }
+    if let Some(until) = filter.until {
+        query.append_pair("until", &until.to_string());
+    }
+    query.finish()
+}
+
+/// Timeline page path showing events that match the filter.
+pub fn timeline_filter_path(filter: &TimelineFilter) -> String {
+    let query = timeline_filter_query(filter);
+    if query.is_empty() {
+        "/timeline".to_string()
+    } else {
+        format!("/timeline?{query}")
+    }
+}
+
+impl TimelineFiltersView {
+    pub fn new(filter: &TimelineFilter, genres: &[Genre], users: &[User]) -> Self {
+        let option = |value: String, label: String, selected: bool| TimelineFilterOption {
+            value,
+            label,
+            selected,
+        };
+        let genre_name = |id: GenreId| genres.iter().find(|g| g.id == id).map(|g| g.name.clone());
+        let username = |id: UserId| {
+            users
+                .iter()
+                .find(|u| u.id == id)
+                .map(|u| u.username.clone())
+        };
+
+        let mut chips = Vec::new();
+        let mut chip = |label: String, clear: fn(&mut TimelineFilter)| {
+            let mut remaining = filter.clone();
+            clear(&mut remaining);
+            chips.push(TimelineFilterChip {
+                label,
+                remove_href: timeline_filter_path(&remaining),
+            });
+        };
+        if let Some(entity_type) = &filter.entity_type {
+            chip(format!("Type: {}", entity_type_label(entity_type)), |f| {
+                f.entity_type = None;
+            });
+        }
+        if let Some(action) = &filter.action {
+            chip(format!("Action: {}", action_label(action)), |f| {
+                f.action = None;
+            });
+        }
+        if let Some(genre_id) = filter.genre_id {
+            let name = genre_name(genre_id).unwrap_or_else(|| genre_id.to_string());
+            chip(format!("Genre: {name}"), |f| f.genre_id = None);
+        }
+        if let Some(user_id) = filter.user_id {
+            let name = username(user_id).unwrap_or_else(|| user_id.to_string());
+            chip(format!("User: {name}"), |f| f.user_id = None);
+        }
+        if let Some(since) = filter.since {
+            chip(format!("Since {since}"), |f| f.since = None);
+        }
+        if let Some(until) = filter.until {
+            chip(format!("Until {until}"), |f| f.until = None);
+        }
+
+        Self {
+            types: TIMELINE_ENTITY_TYPES
+                .iter()
+                .map(|t| {
+                    option(
+                        (*t).to_string(),
+                        entity_type_label(t),
+                        filter.entity_type.as_deref() == Some(*t),
+                    )
+                })
+                .collect(),
+            actions: TIMELINE_ACTIONS
+                .iter()
+                .map(|a| {
+                    option(
+                        (*a).to_string(),
+                        action_label(a),
+                        filter.action.as_deref() == Some(*a),
+                    )
+                })
+                .collect(),
+            genres: genres
+                .iter()
+                .map(|g| {
+                    option(
+                        g.id.to_string(),
+                        g.name.clone(),
+                        filter.genre_id == Some(g.id),
+                    )
+                })
+                .collect(),
+            users: users
+                .iter()
+                .map(|u| {
+                    option(
+                        u.id.to_string(),
+                        u.username.clone(),
+                        filter.user_id == Some(u.id),
+                    )
+                })
+                .collect(),
+            since: filter.since.map(|d| d.to_string()).unwrap_or_default(),
+            until: filter.until.map(|d| d.to_string()).unwrap_or_default(),
+            chips,
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use chrono::NaiveDate;
+
+use crate::domain::books::readings::ReadingWithBook;
+
+/// Longest title the SVG badge shows before cutting it short; SVG text
+/// doesn't wrap or truncate by itself.
+const SVG_TITLE_CHARS: usize = 36;
+const SVG_AUTHOR_CHARS: usize = 44;
+
+/// Top of the first row in the SVG badge, below the heading.
+const SVG_HEADER_HEIGHT: u32 = 40;
+const SVG_ROW_HEIGHT: u32 = 64;
+/// Height of the SVG badge's "nothing on the go" line.
+const SVG_EMPTY_HEIGHT: u32 = 28;
+const SVG_FOOTER_HEIGHT: u32 = 12;
+
+/// Colours for a widget, matching the app's light and dark themes.
+#[derive(Clone, Copy)]
+pub struct WidgetPalette {
+    pub background: &'static str,
+    pub surface: &'static str,
+    pub border: &'static str,
+    pub text: &'static str,
+    pub muted: &'static str,
+    pub accent: &'static str,
+}
+
+impl WidgetPalette {
+    pub const LIGHT: Self = Self {
+        background: "#ffffff",
+        surface: "#f5f5f4",
+        border: "#e7e5e4",
+        text: "#1c1917",
+        muted: "#78716c",
+        accent: "#2563eb",
+    };
+
+    pub const DARK: Self = Self {
+        background: "#292524",
+        surface: "#44403c",
+        border: "#57534e",
+        text: "#e7e5e4",
+        muted: "#a8a29e",
+        accent: "#60a5fa",
+    };
+}
+
+/// A book in the "currently reading" widget.
+pub struct WidgetReadingView {
+    pub title: String,
+    pub author_names: String,
+    /// e.g. "Since Mar 3".
+    pub started_label: Option<String>,
+    /// Absolute thumbnail URL, for the HTML widget.
+    pub cover_url: Option<String>,
+    /// Inline thumbnail for the SVG badge, which can't load other URLs
+    /// when embedded as an image.
+    pub cover_data_url: Option<String>,
+    pub short_title: String,
+    pub short_author_names: String,
+    /// Top of this row in the SVG badge.
+    pub y: u32,
+}
+
+impl WidgetReadingView {
+    pub fn from_domain(
+        rwb: ReadingWithBook,
+        index: usize,
+        cover_url: Option<String>,
+        cover_data_url: Option<String>,
+    ) -> Self {
+        let author_names = if rwb.author_names.is_empty() {
+            "Unknown".to_string()
+        } else {
+            rwb.author_names
+        };
+        let row = u32::try_from(index).unwrap_or(u32::MAX);
+        Self {
+            short_title: truncate(&rwb.book_title, SVG_TITLE_CHARS),
+            short_author_names: truncate(&author_names, SVG_AUTHOR_CHARS),
+            title: rwb.book_title,
+            author_names,
+            started_label: rwb.reading.started_at.map(format_started),
+            cover_url,
+            cover_data_url,
+            y: SVG_HEADER_HEIGHT.saturating_add(row.saturating_mul(SVG_ROW_HEIGHT)),
+        }
+    }
+}
+
+/// An embeddable list of what someone is reading right now.
+pub struct WidgetView {
+    pub username: String,
+    /// Absolute URL of the reader's public profile.
+    pub profile_url: String,
+    pub palette: WidgetPalette,
+    pub readings: Vec<WidgetReadingView>,
+}
+
+impl WidgetView {
+    /// Total height of the SVG badge.
+    pub fn svg_height(&self) -> u32 {
+        let body = match u32::try_from(self.readings.len()) {
+            Ok(0) => SVG_EMPTY_HEIGHT,
+            Ok(rows) => rows.saturating_mul(SVG_ROW_HEIGHT),
+            Err(_) => u32::MAX,
+        };
+        SVG_HEADER_HEIGHT
+            .saturating_add(body)
+            .saturating_add(SVG_FOOTER_HEIGHT)
+    }
+}
+
+fn format_started(date: NaiveDate) -> String {
+    format!("Since {}", date.format("%b %-d"))
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let mut short: String = text.chars().take(max_chars.saturating_sub(1)).collect();
+    short.truncate(short.trim_end().len());
+    short.push('…');
+    short
+}
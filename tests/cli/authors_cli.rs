@@ -71,3 +71,55 @@ fn test_list_authors_shows_added_author() {
         .any(|a| a["id"].as_i64().unwrap().to_string() == author_id);
     assert!(found, "Should find the added author in the list");
 }
+
+#[test]
+fn test_add_and_update_author_details() {
+    let token = create_token("test-author-details");
+
+    let output = run_booklog(
+        &[
+            "author",
+            "add",
+            "--name",
+            "George Orwell",
+            "--nationality",
+            "British",
+            "--birth-year",
+            "1903",
+            "--alias",
+            "Eric Arthur Blair",
+        ],
+        &[("BOOKLOG_TOKEN", &token)],
+    );
+    assert!(
+        output.status.success(),
+        "author add with details should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let author: Value = serde_json::from_slice(&output.stdout).expect("Should output valid JSON");
+    assert_eq!(author["nationality"], "British");
+    assert_eq!(author["birth_year"], 1903);
+    assert_eq!(author["aliases"][0], "Eric Arthur Blair");
+
+    let id = author["id"].as_i64().unwrap().to_string();
+    let output = run_booklog(
+        &[
+            "author",
+            "update",
+            "--id",
+            &id,
+            "--death-year",
+            "1950",
+            "--clear-birth-year",
+        ],
+        &[("BOOKLOG_TOKEN", &token)],
+    );
+    assert!(
+        output.status.success(),
+        "author update with details should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let author: Value = serde_json::from_slice(&output.stdout).expect("Should output valid JSON");
+    assert_eq!(author["death_year"], 1950);
+    assert!(author.get("birth_year").is_none());
+}
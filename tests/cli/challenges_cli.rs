@@ -0,0 +1,84 @@
+use crate::helpers::{create_token, run_booklog, run_json};
+use crate::test_macros::define_cli_auth_test;
+use serde_json::Value;
+
+define_cli_auth_test!(
+    test_add_challenge_requires_authentication,
+    &["challenge", "add", "--name", "Challenge"]
+);
+define_cli_auth_test!(
+    test_list_challenges_requires_authentication,
+    &["challenge", "list"]
+);
+
+#[test]
+fn test_challenge_add_prompts_and_delete() {
+    let token = create_token("test-challenge-lifecycle");
+
+    let challenge = run_json(
+        &[
+            "challenge",
+            "add",
+            "--name",
+            "CLI Challenge",
+            "--year",
+            "2025",
+            "--shared",
+            "--prompt",
+            "Over 600 pages=pages>=600",
+            "--prompt",
+            "A friend's pick",
+        ],
+        &token,
+    );
+    assert_eq!(challenge["shared"], true);
+    assert_eq!(challenge["prompts"][0]["label"], "Over 600 pages");
+    assert_eq!(challenge["prompts"][0]["rule"], "pages>=600");
+    assert_eq!(challenge["prompts"][1]["rule"], Value::Null);
+    let id = challenge["id"].to_string();
+
+    let prompt = run_json(
+        &[
+            "challenge",
+            "add-prompt",
+            "--id",
+            &id,
+            "--label",
+            "Before 1900",
+            "--rule",
+            "year<1900",
+        ],
+        &token,
+    );
+    assert_eq!(prompt["position"], 2);
+
+    let challenge = run_json(&["challenge", "update", "--id", &id, "--any-year"], &token);
+    assert_eq!(challenge["year"], Value::Null);
+
+    let progress = run_json(&["challenge", "get", "--id", &id], &token);
+    assert_eq!(progress["total"], 3);
+    assert_eq!(progress["completed"], 0);
+
+    let output = run_booklog(
+        &["challenge", "delete", "--id", &id],
+        &[("BOOKLOG_TOKEN", &token)],
+    );
+    assert!(output.status.success());
+}
+
+#[test]
+fn test_challenge_add_rejects_invalid_rule() {
+    let token = create_token("test-challenge-invalid-rule");
+    let output = run_booklog(
+        &[
+            "challenge",
+            "add",
+            "--name",
+            "Bad",
+            "--prompt",
+            "Loved it=rating>=4",
+        ],
+        &[("BOOKLOG_TOKEN", &token)],
+    );
+    assert!(!output.status.success());
+}
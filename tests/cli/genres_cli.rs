@@ -70,3 +70,41 @@ fn test_list_genres_shows_added_genre() {
         .any(|g| g["id"].as_i64().unwrap().to_string() == genre_id);
     assert!(found, "Should find the added genre in the list");
 }
+
+#[test]
+fn test_genre_parent_can_be_set_and_cleared() {
+    let token = create_token("test-genre-parent");
+    let parent_id = create_genre("CLI Parent Genre", &token);
+
+    let output = run_booklog(
+        &[
+            "genre",
+            "add",
+            "--name",
+            "CLI Child Genre",
+            "--parent-id",
+            &parent_id,
+        ],
+        &[("BOOKLOG_TOKEN", &token)],
+    );
+    assert!(
+        output.status.success(),
+        "genre add with parent should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let child: Value = serde_json::from_slice(&output.stdout).expect("Should output valid JSON");
+    assert_eq!(child["parent_id"].as_i64().unwrap().to_string(), parent_id);
+
+    let child_id = child["id"].as_i64().unwrap().to_string();
+    let output = run_booklog(
+        &["genre", "update", "--id", &child_id, "--clear-parent"],
+        &[("BOOKLOG_TOKEN", &token)],
+    );
+    assert!(
+        output.status.success(),
+        "genre update --clear-parent should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let child: Value = serde_json::from_slice(&output.stdout).expect("Should output valid JSON");
+    assert!(child["parent_id"].is_null());
+}
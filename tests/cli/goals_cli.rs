@@ -0,0 +1,63 @@
+use crate::helpers::{create_token, run_booklog, run_json};
+use crate::test_macros::define_cli_auth_test;
+
+define_cli_auth_test!(
+    test_add_goal_requires_authentication,
+    &["goal", "add", "--target", "10"]
+);
+define_cli_auth_test!(test_list_goals_requires_authentication, &["goal", "list"]);
+
+#[test]
+fn test_goal_add_update_and_delete() {
+    let token = create_token("test-goal-lifecycle");
+
+    let goal = run_json(&["goal", "add", "--target", "12", "--year", "2024"], &token);
+    assert_eq!(goal["metric"], "books");
+    assert_eq!(goal["start_date"], "2024-01-01");
+    assert_eq!(goal["end_date"], "2024-12-31");
+    let id = goal["id"].to_string();
+
+    let goals = run_json(&["goal", "list"], &token);
+    assert!(
+        goals
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|g| g["id"] == goal["id"])
+    );
+
+    let goal = run_json(
+        &[
+            "goal",
+            "update",
+            "--id",
+            &id,
+            "--metric",
+            "pages",
+            "--target",
+            "3000",
+            "--start",
+            "2024-06-01",
+        ],
+        &token,
+    );
+    assert_eq!(goal["metric"], "pages");
+    assert_eq!(goal["target"], 3000);
+    assert_eq!(goal["start_date"], "2024-06-01");
+
+    let output = run_booklog(
+        &["goal", "delete", "--id", &id],
+        &[("BOOKLOG_TOKEN", &token)],
+    );
+    assert!(output.status.success());
+}
+
+#[test]
+fn test_goal_add_rejects_invalid_metric() {
+    let output = run_booklog(
+        &["goal", "add", "--target", "10", "--metric", "minutes"],
+        &[("BOOKLOG_TOKEN", "unused")],
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid metric"));
+}
@@ -85,8 +85,6 @@ fn ensure_server_started() -> Result<(String, String), String> {
                     .await
                     .expect("Failed to connect to test database");
 
-                let (stats_tx, _stats_rx) = tokio::sync::mpsc::channel(1);
-                let (timeline_tx, timeline_rx) = tokio::sync::mpsc::channel(32);
                 let state = AppState::from_database(
                     &database,
                     AppStateConfig {
@@ -95,25 +93,17 @@ fn ensure_server_started() -> Result<(String, String), String> {
                         openrouter_url: booklog::infrastructure::ai::OPENROUTER_URL.to_string(),
                         openrouter_api_key: String::new(),
                         openrouter_model: "openrouter/free".to_string(),
-                        stats_invalidator: booklog::application::services::StatsInvalidator::new(
-                            stats_tx,
-                        ),
-                        timeline_invalidator:
-                            booklog::application::services::TimelineInvalidator::new(timeline_tx),
+                        allow_private_network: true,
                     },
                 );
 
-                // Spawn timeline rebuild task with 100ms debounce for tests
+                // Spawn the job worker with a 100ms poll interval for tests
                 tokio::spawn(
-                    booklog::application::services::timeline_refresh::timeline_rebuild_task(
-                        timeline_rx,
-                        std::sync::Arc::clone(&state.author_repo),
-                        std::sync::Arc::clone(&state.book_repo),
-                        std::sync::Arc::clone(&state.genre_repo),
-                        std::sync::Arc::clone(&state.reading_repo),
-                        std::sync::Arc::clone(&state.timeline_repo),
+                    booklog::application::services::JobWorker::new(
+                        &state,
                         std::time::Duration::from_millis(100),
-                    ),
+                    )
+                    .run(),
                 );
 
                 let app = app_router(state);
@@ -235,6 +225,19 @@ pub fn run_booklog(args: &[&str], env: &[(&str, &str)]) -> std::process::Output
     cmd.output().expect("Failed to run booklog command")
 }
 
+/// Run a booklog CLI command with auth, assert success and parse its JSON output
+pub fn run_json(args: &[&str], token: &str) -> serde_json::Value {
+    let output = run_booklog(args, &[("BOOKLOG_TOKEN", token)]);
+    assert!(
+        output.status.success(),
+        "{args:?} should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    serde_json::from_str(&stdout)
+        .unwrap_or_else(|_| panic!("Should output valid JSON, got: {}", stdout))
+}
+
 /// Generic helper: run a CLI command with auth, assert success, parse JSON, extract ID.
 fn create_entity_cli(args: &[&str], token: &str, label: &str) -> String {
     let output = run_booklog(args, &[("BOOKLOG_TOKEN", token)]);
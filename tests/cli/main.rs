@@ -1,10 +1,15 @@
 pub mod authors_cli;
 pub mod backup_cli;
 pub mod books_cli;
+pub mod challenges_cli;
 pub mod genres_cli;
+pub mod goals_cli;
 pub mod helpers;
 pub mod readings_cli;
+pub mod shelves_cli;
+pub mod tags_cli;
 pub mod test_macros;
 pub mod timeline_cli;
 pub mod tokens_cli;
 pub mod user_books_cli;
+pub mod webhooks_cli;
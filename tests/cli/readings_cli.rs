@@ -131,3 +131,62 @@ fn test_delete_reading_with_authentication() {
     let get_output = run_booklog(&["reading", "get", "--id", &reading_id], &[]);
     assert!(!get_output.status.success());
 }
+
+#[test]
+fn test_list_readings_with_query() {
+    let token = create_token("test-list-readings-query");
+
+    let author_id = create_author("Reading Query Author", &token);
+    let book_id = create_book("Reading Query Book", &author_id, &token);
+    let reading_id = create_reading(&book_id, "abandoned", &token);
+
+    let output = run_booklog(
+        &[
+            "reading",
+            "list",
+            "--query",
+            "status:abandoned title:\"reading query\"",
+        ],
+        &[("BOOKLOG_TOKEN", &token)],
+    );
+
+    assert!(
+        output.status.success(),
+        "reading list --query should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let readings: Value = serde_json::from_str(&stdout).expect("Should output valid JSON");
+    let ids: Vec<String> = readings
+        .as_array()
+        .expect("Should output an array")
+        .iter()
+        .filter_map(|r| r["id"].as_i64().map(|id| id.to_string()))
+        .collect();
+    assert_eq!(ids, vec![reading_id]);
+}
+
+#[test]
+fn test_list_readings_with_unknown_query_field_fails() {
+    let output = run_booklog(&["reading", "list", "--query", "colour:blue"], &[]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("unknown filter field"),
+        "stderr should explain the error: {stderr}"
+    );
+}
+
+#[test]
+fn test_list_readings_with_invalid_query_value_fails() {
+    let output = run_booklog(&["reading", "list", "--query", "finished:yesterday"], &[]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("expected a date"),
+        "stderr should explain the error: {stderr}"
+    );
+}
@@ -0,0 +1,91 @@
+use crate::helpers::{create_author, create_book, create_token, run_booklog, run_json};
+use crate::test_macros::define_cli_auth_test;
+
+define_cli_auth_test!(
+    test_add_shelf_requires_authentication,
+    &["shelf", "add", "--name", "Unauthorised"]
+);
+define_cli_auth_test!(
+    test_list_shelves_requires_authentication,
+    &["shelf", "list"]
+);
+define_cli_auth_test!(
+    test_add_shelf_book_requires_authentication,
+    &["shelf", "add-book", "--id", "1", "--book-id", "1"]
+);
+
+#[test]
+fn test_manual_shelf_add_and_order_books() {
+    let token = create_token("test-manual-shelf");
+    let author_id = create_author("Shelf CLI Author", &token);
+    let first = create_book("Shelf CLI First", &author_id, &token);
+    let second = create_book("Shelf CLI Second", &author_id, &token);
+
+    let shelf = run_json(&["shelf", "add", "--name", "CLI Manual Shelf"], &token);
+    assert_eq!(shelf["kind"], "manual");
+    let id = shelf["id"].to_string();
+
+    run_json(
+        &["shelf", "add-book", "--id", &id, "--book-id", &first],
+        &token,
+    );
+    let shelf = run_json(
+        &["shelf", "add-book", "--id", &id, "--book-id", &second],
+        &token,
+    );
+    assert_eq!(shelf["books"][0]["title"], "Shelf CLI First");
+
+    let shelf = run_json(
+        &[
+            "shelf",
+            "move-book",
+            "--id",
+            &id,
+            "--book-id",
+            &second,
+            "--position",
+            "0",
+        ],
+        &token,
+    );
+    assert_eq!(shelf["books"][0]["title"], "Shelf CLI Second");
+    assert_eq!(shelf["books"][1]["title"], "Shelf CLI First");
+}
+
+#[test]
+fn test_smart_shelf_requires_valid_query() {
+    let token = create_token("test-smart-shelf");
+
+    let output = run_booklog(
+        &[
+            "shelf",
+            "add",
+            "--name",
+            "CLI Broken Smart Shelf",
+            "--kind",
+            "smart",
+            "--query",
+            "pages>=lots",
+        ],
+        &[("BOOKLOG_TOKEN", &token)],
+    );
+    assert!(!output.status.success(), "invalid smart query should fail");
+
+    let shelf = run_json(
+        &[
+            "shelf",
+            "add",
+            "--name",
+            "CLI Smart Shelf",
+            "--kind",
+            "smart",
+            "--query",
+            "pages<300",
+            "--pinned",
+        ],
+        &token,
+    );
+    assert_eq!(shelf["kind"], "smart");
+    assert_eq!(shelf["query"], "pages<300");
+    assert_eq!(shelf["pinned"], true);
+}
@@ -0,0 +1,77 @@
+use crate::helpers::{create_author, create_book, create_token, run_booklog, run_json};
+use crate::test_macros::define_cli_auth_test;
+
+define_cli_auth_test!(test_list_tags_requires_authentication, &["tag", "list"]);
+define_cli_auth_test!(
+    test_set_tags_requires_authentication,
+    &["tag", "set", "--book-id", "1", "--tag", "nope"]
+);
+
+#[test]
+fn test_user_book_add_and_list_by_tag() {
+    let token = create_token("test-tag-user-books");
+    let author_id = create_author("Tag CLI Author", &token);
+    let tagged = create_book("Tag CLI Tagged", &author_id, &token);
+    let untagged = create_book("Tag CLI Untagged", &author_id, &token);
+
+    run_json(
+        &[
+            "user-book",
+            "add",
+            "--book-id",
+            &tagged,
+            "--tag",
+            "CLI Holiday",
+        ],
+        &token,
+    );
+    run_json(&["user-book", "add", "--book-id", &untagged], &token);
+
+    let listed = run_json(&["user-book", "list", "--tag", "cli-holiday"], &token);
+    let items = listed.as_array().expect("Should be an array");
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["book_id"].to_string(), tagged);
+    assert_eq!(items[0]["tags"][0], "cli-holiday");
+}
+
+#[test]
+fn test_tag_set_add_and_remove() {
+    let token = create_token("test-tag-commands");
+    let author_id = create_author("Tag Command Author", &token);
+    let book_id = create_book("Tag Command Book", &author_id, &token);
+
+    let tags = run_json(
+        &[
+            "tag",
+            "set",
+            "--book-id",
+            &book_id,
+            "--tag",
+            "cli-set-one",
+            "--tag",
+            "cli-set-two",
+        ],
+        &token,
+    );
+    assert_eq!(tags, serde_json::json!(["cli-set-one", "cli-set-two"]));
+
+    let output = run_booklog(
+        &[
+            "tag",
+            "remove",
+            "--book-id",
+            &book_id,
+            "--tag",
+            "cli-set-one",
+        ],
+        &[("BOOKLOG_TOKEN", &token)],
+    );
+    assert!(output.status.success());
+
+    let tags = run_json(&["tag", "get", "--book-id", &book_id], &token);
+    assert_eq!(tags, serde_json::json!(["cli-set-two"]));
+
+    let counts = run_json(&["tag", "list", "--prefix", "cli-set"], &token);
+    assert_eq!(counts[0]["tag"], "cli-set-two");
+    assert_eq!(counts[0]["count"], 1);
+}
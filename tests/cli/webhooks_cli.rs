@@ -0,0 +1,70 @@
+use crate::helpers::{create_token, run_booklog, run_json};
+use crate::test_macros::define_cli_auth_test;
+
+define_cli_auth_test!(
+    test_add_webhook_requires_authentication,
+    &["webhook", "add", "--url", "https://example.com/hook"]
+);
+define_cli_auth_test!(
+    test_list_webhooks_requires_authentication,
+    &["webhook", "list"]
+);
+
+#[test]
+fn test_webhook_add_list_and_delete() {
+    let token = create_token("test-webhook-lifecycle");
+
+    let webhook = run_json(
+        &[
+            "webhook",
+            "add",
+            "--url",
+            "https://example.com/hooks/booklog",
+            "--event",
+            "finished",
+            "--event",
+            "rated",
+        ],
+        &token,
+    );
+    assert_eq!(webhook["url"], "https://example.com/hooks/booklog");
+    assert_eq!(webhook["events"], serde_json::json!(["finished", "rated"]));
+    assert!(!webhook["secret"].as_str().unwrap().is_empty());
+    let id = webhook["id"].as_i64().unwrap();
+
+    let webhooks = run_json(&["webhook", "list"], &token);
+    let listed = webhooks
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|w| w["id"] == id)
+        .expect("new webhook should be listed");
+    assert!(listed.get("secret").is_none(), "secrets are never listed");
+
+    let output = run_booklog(
+        &["webhook", "delete", "--id", &id.to_string()],
+        &[("BOOKLOG_TOKEN", &token)],
+    );
+    assert!(output.status.success());
+
+    let webhooks = run_json(&["webhook", "list"], &token);
+    assert!(!webhooks.as_array().unwrap().iter().any(|w| w["id"] == id));
+}
+
+#[test]
+fn test_webhook_add_rejects_unknown_events() {
+    let token = create_token("test-webhook-unknown-event");
+    let output = run_booklog(
+        &[
+            "webhook",
+            "add",
+            "--url",
+            "https://example.com/hook",
+            "--event",
+            "exploded",
+        ],
+        &[("BOOKLOG_TOKEN", &token)],
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown webhook event"));
+}
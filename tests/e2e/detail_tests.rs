@@ -17,6 +17,7 @@ async fn author_detail_shows_all_fields() {
         &app,
         NewAuthor {
             name: "Detail Author".to_string(),
+            details: Default::default(),
             created_at: None,
         },
     )
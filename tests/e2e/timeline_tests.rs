@@ -17,6 +17,7 @@ async fn editing_an_author_updates_timeline_in_browser() {
         &app,
         NewAuthor {
             name: original_name.to_string(),
+            details: Default::default(),
             created_at: None,
         },
     )
@@ -17,6 +17,7 @@ async fn creating_an_author_returns_a_201_for_valid_data() {
 
     let new_author = NewAuthor {
         name: "Ursula K. Le Guin".to_string(),
+        details: Default::default(),
         created_at: None,
     };
 
@@ -41,6 +42,7 @@ async fn creating_an_author_persists_the_data() {
 
     let new_author = NewAuthor {
         name: "Persistent Author".to_string(),
+        details: Default::default(),
         created_at: None,
     };
 
@@ -70,6 +72,7 @@ async fn getting_an_author_returns_a_200_for_valid_id() {
 
     let new_author = NewAuthor {
         name: "Fetchable Author".to_string(),
+        details: Default::default(),
         created_at: None,
     };
 
@@ -106,11 +109,13 @@ async fn listing_authors_returns_a_200_with_multiple_authors() {
 
     let author1 = NewAuthor {
         name: "First Author".to_string(),
+        details: Default::default(),
         created_at: None,
     };
 
     let author2 = NewAuthor {
         name: "Second Author".to_string(),
+        details: Default::default(),
         created_at: None,
     };
 
@@ -149,6 +154,7 @@ async fn updating_an_author_returns_a_200_for_valid_data() {
 
     let new_author = NewAuthor {
         name: "Original Name".to_string(),
+        details: Default::default(),
         created_at: None,
     };
 
@@ -167,7 +173,7 @@ async fn updating_an_author_returns_a_200_for_valid_data() {
 
     let update = UpdateAuthor {
         name: Some("Updated Name".to_string()),
-        created_at: None,
+        ..Default::default()
     };
 
     let response = client
@@ -193,7 +199,7 @@ async fn updating_an_author_with_no_changes_returns_a_400() {
 
     let update = UpdateAuthor {
         name: None,
-        created_at: None,
+        ..Default::default()
     };
 
     let response = client
@@ -214,7 +220,7 @@ async fn updating_a_nonexistent_author_returns_a_404() {
 
     let update = UpdateAuthor {
         name: Some("New Name".to_string()),
-        created_at: None,
+        ..Default::default()
     };
 
     let response = client
@@ -252,3 +258,109 @@ async fn deleting_an_author_returns_a_204_for_valid_id() {
 
     assert_eq!(get_response.status(), 404);
 }
+
+#[tokio::test]
+async fn creating_an_author_stores_biographical_details() {
+    let app = spawn_app_with_auth().await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(app.api_url("/authors"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&serde_json::json!({
+            "name": "George Orwell",
+            "bio": "English novelist and essayist.",
+            "birth_year": 1903,
+            "death_year": 1950,
+            "nationality": "British",
+            "website": "https://orwellfoundation.com",
+            "aliases": ["Eric Arthur Blair", " eric arthur blair ", "George Orwell"],
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), 201);
+    let author: Author = response.json().await.expect("Failed to parse response");
+
+    let fetched: Author = client
+        .get(app.api_url(&format!("/authors/{}", author.id)))
+        .send()
+        .await
+        .expect("Failed to fetch author")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(fetched.details.birth_year, Some(1903));
+    assert_eq!(fetched.details.death_year, Some(1950));
+    assert_eq!(fetched.details.nationality.as_deref(), Some("British"));
+    assert_eq!(fetched.details.aliases, ["Eric Arthur Blair"]);
+}
+
+#[tokio::test]
+async fn creating_an_author_with_invalid_details_returns_a_400() {
+    let app = spawn_app_with_auth().await;
+    let client = reqwest::Client::new();
+
+    for details in [
+        serde_json::json!({"name": "A", "birth_year": 1950, "death_year": 1900}),
+        serde_json::json!({"name": "B", "website": "not a url"}),
+    ] {
+        let response = client
+            .post(app.api_url("/authors"))
+            .bearer_auth(app.auth_token.as_ref().unwrap())
+            .json(&details)
+            .send()
+            .await
+            .expect("Failed to execute request");
+        assert_eq!(response.status(), 400, "{details}");
+    }
+}
+
+#[tokio::test]
+async fn updating_an_author_sets_and_clears_details() {
+    let app = spawn_app_with_auth().await;
+    let client = reqwest::Client::new();
+
+    let author = create_author_with_name(&app, "Terry Pratchett").await;
+    let url = app.api_url(&format!("/authors/{}", author.id));
+
+    let update = UpdateAuthor {
+        birth_year: Some(Some(1948)),
+        nationality: Some("British".to_string()),
+        aliases: Some(vec!["Patrick Kearns".to_string()]),
+        ..Default::default()
+    };
+    let response = client
+        .put(&url)
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&update)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), 200);
+    let updated: Author = response.json().await.expect("Failed to parse response");
+    assert_eq!(updated.details.birth_year, Some(1948));
+    assert_eq!(updated.details.aliases, ["Patrick Kearns"]);
+
+    // A death year before the stored birth year is rejected.
+    let response = client
+        .put(&url)
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&serde_json::json!({"death_year": 1900}))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), 400);
+
+    let response = client
+        .put(&url)
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&serde_json::json!({"birth_year": "", "nationality": "", "aliases": []}))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), 200);
+    let cleared: Author = response.json().await.expect("Failed to parse response");
+    assert_eq!(cleared.details, Default::default());
+}
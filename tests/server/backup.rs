@@ -1,7 +1,9 @@
 use std::sync::Arc;
 
-use booklog::application::services::{AuthorService, BookService};
-use booklog::domain::authors::{Author, AuthorSortKey, NewAuthor};
+use booklog::application::services::{
+    AuthorService, BookService, JobQueue, LiveUpdates, TimelinePublisher,
+};
+use booklog::domain::authors::{Author, AuthorDetails, AuthorSortKey, NewAuthor};
 use booklog::domain::book_items::{AuthorRole, Book, BookAuthor, BookSortKey, NewBook};
 use booklog::domain::genres::{Genre, NewGenre};
 use booklog::domain::ids::{AuthorId, BookId, GenreId, UserId};
@@ -10,7 +12,8 @@ use booklog::domain::readings::{
     NewReading, Reading, ReadingFilter, ReadingFormat, ReadingSortKey, ReadingStatus,
 };
 use booklog::domain::repositories::{
-    AuthorRepository, BookRepository, GenreRepository, ReadingRepository, TimelineEventRepository,
+    AuthorRepository, BookRepository, GenreRepository, ReadingRepository, TagRepository,
+    TimelineEventRepository,
 };
 use booklog::domain::timeline::TimelineEvent;
 use booklog::infrastructure::backup::{BackupData, BackupService};
@@ -18,7 +21,9 @@ use booklog::infrastructure::database::{Database, DatabasePool};
 use booklog::infrastructure::repositories::authors::SqlAuthorRepository;
 use booklog::infrastructure::repositories::book_repos::SqlBookRepository;
 use booklog::infrastructure::repositories::genres::SqlGenreRepository;
+use booklog::infrastructure::repositories::jobs::SqlJobRepository;
 use booklog::infrastructure::repositories::readings::SqlReadingRepository;
+use booklog::infrastructure::repositories::tags::SqlTagRepository;
 use booklog::infrastructure::repositories::timeline_events::SqlTimelineEventRepository;
 
 use super::helpers::{create_default_author, spawn_app, spawn_app_with_auth};
@@ -50,12 +55,19 @@ async fn create_test_db() -> TestDb {
     let timeline_repo: Arc<dyn TimelineEventRepository> =
         Arc::new(SqlTimelineEventRepository::new(pool.clone()));
 
-    let author_service = AuthorService::new(Arc::clone(&author_repo), Arc::clone(&timeline_repo));
+    let job_queue = JobQueue::new(Arc::new(SqlJobRepository::new(pool.clone())));
+    let timeline_publisher = TimelinePublisher::new(LiveUpdates::new(), job_queue);
+    let author_service = AuthorService::new(
+        Arc::clone(&author_repo),
+        Arc::clone(&timeline_repo),
+        timeline_publisher.clone(),
+    );
     let book_service = BookService::new(
         Arc::clone(&book_repo),
         Arc::clone(&author_repo),
         Arc::clone(&genre_repo),
         Arc::clone(&timeline_repo),
+        timeline_publisher,
     );
 
     TestDb {
@@ -163,6 +175,13 @@ async fn populate_test_data(db: &TestDb) -> (Author, Genre, Genre, Book, Reading
         .create(
             NewAuthor {
                 name: "Ursula K. Le Guin".to_string(),
+                details: AuthorDetails {
+                    birth_year: Some(1929),
+                    death_year: Some(2018),
+                    nationality: Some("American".to_string()),
+                    aliases: vec!["Ursula Kroeber".to_string()],
+                    ..AuthorDetails::default()
+                },
                 created_at: None,
             },
             user_id,
@@ -175,6 +194,7 @@ async fn populate_test_data(db: &TestDb) -> (Author, Genre, Genre, Book, Reading
         .genre_repo
         .insert(NewGenre {
             name: "Science Fiction".to_string(),
+            parent_id: None,
             created_at: None,
         })
         .await
@@ -184,6 +204,7 @@ async fn populate_test_data(db: &TestDb) -> (Author, Genre, Genre, Book, Reading
         .genre_repo
         .insert(NewGenre {
             name: "Fantasy".to_string(),
+            parent_id: None,
             created_at: None,
         })
         .await
@@ -202,6 +223,7 @@ async fn populate_test_data(db: &TestDb) -> (Author, Genre, Genre, Book, Reading
                 isbn: Some("978-0441478125".to_string()),
                 description: Some("A science fiction novel".to_string()),
                 page_count: Some(304),
+                duration_minutes: None,
                 year_published: Some(1969),
                 publisher: Some("Ace Books".to_string()),
                 language: Some("English".to_string()),
@@ -226,11 +248,17 @@ async fn populate_test_data(db: &TestDb) -> (Author, Genre, Genre, Book, Reading
             finished_at: None,
             rating: None,
             quick_reviews: Vec::new(),
+            private: false,
             created_at: None,
         })
         .await
         .expect("failed to create reading");
 
+    SqlTagRepository::new(db.pool.clone())
+        .add(user_id, book.id, "classic")
+        .await
+        .expect("failed to tag book");
+
     (author, sci_fi, fantasy, book, reading)
 }
 
@@ -267,6 +295,8 @@ async fn backup_and_restore_round_trip() {
     assert_eq!(backup_data.images.len(), 1);
     assert_eq!(backup_data.images[0].entity_type, "author");
     assert_eq!(backup_data.images[0].content_type, "image/png");
+    assert_eq!(backup_data.tags.len(), 1);
+    assert_eq!(backup_data.tags[0].tag, "classic");
 
     // 3. Serialize to JSON and deserialize back (verify serde round-trip)
     let json = serde_json::to_string_pretty(&backup_data).expect("failed to serialize backup");
@@ -280,6 +310,7 @@ async fn backup_and_restore_round_trip() {
     assert_eq!(restored_data.book_authors.len(), 1);
     assert_eq!(restored_data.readings.len(), 1);
     assert_eq!(restored_data.images.len(), 1);
+    assert_eq!(restored_data.tags.len(), 1);
 
     // 4. Restore to a fresh database
     let target = create_test_db().await;
@@ -299,6 +330,7 @@ async fn backup_and_restore_round_trip() {
     let restored_author = &target_authors[0];
     assert_eq!(restored_author.id, author.id);
     assert_eq!(restored_author.name, author.name);
+    assert_eq!(restored_author.details, author.details);
     assert_eq!(restored_author.created_at, author.created_at);
 
     // Books
@@ -328,6 +360,13 @@ async fn backup_and_restore_round_trip() {
     assert_eq!(restored_reading.rating, reading.rating);
     assert_eq!(restored_reading.quick_reviews, reading.quick_reviews);
 
+    // Tags
+    let restored_tags = SqlTagRepository::new(target.pool.clone())
+        .list_for_book(restored_reading.user_id, restored_book.id)
+        .await
+        .expect("failed to list tags");
+    assert_eq!(restored_tags, vec!["classic".to_string()]);
+
     // Timeline events
     let target_timeline = list_all_timeline_events(target.timeline_repo.as_ref()).await;
     assert_eq!(target_timeline.len(), source_timeline.len());
@@ -362,6 +401,7 @@ async fn restore_to_non_empty_database_fails() {
     db.author_repo
         .insert(NewAuthor {
             name: "Existing Author".to_string(),
+            details: Default::default(),
             created_at: None,
         })
         .await
@@ -378,6 +418,7 @@ async fn restore_to_non_empty_database_fails() {
         readings: vec![],
         timeline_events: vec![],
         images: vec![],
+        tags: vec![],
     };
 
     // Restore should fail because the database is not empty
@@ -469,6 +510,7 @@ async fn backup_restore_requires_auth() {
         readings: vec![],
         timeline_events: vec![],
         images: vec![],
+        tags: vec![],
     };
 
     let response = client
@@ -499,6 +541,7 @@ async fn backup_restore_non_empty_db_returns_conflict() {
         readings: vec![],
         timeline_events: vec![],
         images: vec![],
+        tags: vec![],
     };
 
     let response = client
@@ -742,6 +785,7 @@ async fn restore_with_fk_violation_does_not_partially_commit() {
         authors: vec![Author {
             id: AuthorId::from(1i64),
             name: "Rollback Author".to_string(),
+            details: Default::default(),
             created_at: chrono::Utc::now(),
         }],
         genres: vec![],
@@ -751,6 +795,7 @@ async fn restore_with_fk_violation_does_not_partially_commit() {
             isbn: None,
             description: None,
             page_count: None,
+            duration_minutes: None,
             year_published: None,
             publisher: None,
             language: None,
@@ -762,6 +807,7 @@ async fn restore_with_fk_violation_does_not_partially_commit() {
         readings: vec![],
         timeline_events: vec![],
         images: vec![],
+        tags: vec![],
     };
 
     let result = db.backup_service.restore(backup).await;
@@ -775,3 +821,49 @@ async fn restore_with_fk_violation_does_not_partially_commit() {
         authors.len()
     );
 }
+
+#[tokio::test]
+async fn restore_links_genre_parents_regardless_of_order() {
+    let db = create_test_db().await;
+
+    // The subgenre has a lower ID than its parent, as happens when a genre is
+    // re-parented under one created later.
+    let backup = BackupData {
+        version: 3,
+        created_at: chrono::Utc::now(),
+        authors: vec![],
+        genres: vec![
+            Genre {
+                id: GenreId::from(1i64),
+                name: "Space Opera".to_string(),
+                parent_id: Some(GenreId::from(2i64)),
+                created_at: chrono::Utc::now(),
+            },
+            Genre {
+                id: GenreId::from(2i64),
+                name: "Science Fiction".to_string(),
+                parent_id: None,
+                created_at: chrono::Utc::now(),
+            },
+        ],
+        books: vec![],
+        book_authors: vec![],
+        readings: vec![],
+        timeline_events: vec![],
+        images: vec![],
+        tags: vec![],
+    };
+
+    db.backup_service
+        .restore(backup)
+        .await
+        .expect("failed to restore backup");
+
+    let exported = db
+        .backup_service
+        .export()
+        .await
+        .expect("failed to export backup");
+    assert_eq!(exported.genres[0].parent_id, Some(GenreId::from(2i64)));
+    assert_eq!(exported.genres[1].parent_id, None);
+}
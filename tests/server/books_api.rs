@@ -29,6 +29,7 @@ async fn creating_a_book_returns_a_201_for_valid_data() {
         isbn: Some("978-0441478125".to_string()),
         description: Some("A science fiction novel".to_string()),
         page_count: Some(304),
+        duration_minutes: None,
         year_published: Some(1969),
         publisher: Some("Ace Books".to_string()),
         language: Some("English".to_string()),
@@ -70,6 +71,7 @@ async fn creating_a_book_persists_the_data() {
         isbn: None,
         description: None,
         page_count: None,
+        duration_minutes: None,
         year_published: None,
         publisher: None,
         language: None,
@@ -112,6 +114,7 @@ async fn creating_a_book_with_nonexistent_author_returns_a_404() {
         isbn: None,
         description: None,
         page_count: None,
+        duration_minutes: None,
         year_published: None,
         publisher: None,
         language: None,
@@ -162,6 +165,7 @@ async fn listing_books_returns_a_200_with_multiple_books() {
         isbn: None,
         description: None,
         page_count: None,
+        duration_minutes: None,
         year_published: None,
         publisher: None,
         language: None,
@@ -179,6 +183,7 @@ async fn listing_books_returns_a_200_with_multiple_books() {
         isbn: None,
         description: None,
         page_count: None,
+        duration_minutes: None,
         year_published: None,
         publisher: None,
         language: None,
@@ -257,6 +262,7 @@ async fn creating_a_book_trims_whitespace_from_fields() {
         isbn: Some("  978-1234567890  ".to_string()),
         description: Some("  A description  ".to_string()),
         page_count: Some(200),
+        duration_minutes: None,
         year_published: None,
         publisher: Some("  Publisher Name  ".to_string()),
         language: Some("  English  ".to_string()),
@@ -298,6 +304,7 @@ async fn creating_a_book_filters_out_zero_page_count() {
         isbn: None,
         description: None,
         page_count: Some(0),
+        duration_minutes: None,
         year_published: None,
         publisher: None,
         language: None,
@@ -321,6 +328,34 @@ async fn creating_a_book_filters_out_zero_page_count() {
     assert_eq!(book.page_count, None);
 }
 
+#[tokio::test]
+async fn updating_a_book_combines_duration_hours_and_minutes() {
+    let app = spawn_app_with_auth().await;
+    let author = create_default_author(&app).await;
+    let book = create_default_book(&app, author.id).await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .put(app.api_url(&format!("/books/{}", book.id)))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .form(&[("duration_hours", "11"), ("duration_minutes", "45")])
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert!(response.status().is_success());
+
+    let updated: booklog::domain::book_items::Book = client
+        .get(app.api_url(&format!("/books/{}", book.id)))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to execute request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(updated.duration_minutes, Some(705));
+}
+
 #[tokio::test]
 async fn updating_a_book_normalizes_fields() {
     let app = spawn_app_with_auth().await;
@@ -398,6 +433,7 @@ async fn creating_a_book_with_duplicate_title_returns_409() {
         isbn: None,
         description: None,
         page_count: None,
+        duration_minutes: None,
         year_published: None,
         publisher: None,
         language: None,
@@ -465,6 +501,7 @@ async fn deleting_a_genre_clears_genre_references_on_books() {
         isbn: None,
         description: None,
         page_count: None,
+        duration_minutes: None,
         year_published: None,
         publisher: None,
         language: None,
@@ -0,0 +1,485 @@
+use booklog::domain::book_items::{Book, NewBook};
+use booklog::domain::challenges::{ChallengeEntry, ChallengeProgress, ChallengeSummary};
+use booklog::domain::ids::{AuthorId, UserId};
+use booklog::domain::readings::{NewReading, Reading, ReadingFormat, ReadingStatus};
+use chrono::NaiveDate;
+use reqwest::Client;
+use serde_json::json;
+
+use crate::helpers::{
+    TestApp, create_author_with_name, create_entity, create_non_admin_token, create_session,
+    new_book, spawn_app_with_auth,
+};
+
+async fn create_book(
+    app: &TestApp,
+    title: &str,
+    author_id: AuthorId,
+    pages: i32,
+    year_published: Option<i32>,
+    language: Option<&str>,
+) -> Book {
+    create_entity(
+        app,
+        "/books",
+        &NewBook {
+            page_count: Some(pages),
+            year_published,
+            language: language.map(str::to_string),
+            ..new_book(author_id, title)
+        },
+    )
+    .await
+}
+
+async fn read(
+    app: &TestApp,
+    token: &str,
+    book: &Book,
+    status: ReadingStatus,
+    finished_at: Option<NaiveDate>,
+) -> Reading {
+    Client::new()
+        .post(app.api_url("/readings"))
+        .bearer_auth(token)
+        .json(&NewReading {
+            user_id: UserId::new(1),
+            book_id: book.id,
+            status,
+            format: Some(ReadingFormat::Physical),
+            started_at: None,
+            finished_at,
+            rating: None,
+            quick_reviews: Vec::new(),
+            private: false,
+            created_at: None,
+        })
+        .send()
+        .await
+        .expect("Failed to execute request")
+        .json()
+        .await
+        .expect("Failed to parse reading")
+}
+
+async fn create_challenge(app: &TestApp, payload: serde_json::Value) -> reqwest::Response {
+    Client::new()
+        .post(app.api_url("/challenges"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&payload)
+        .send()
+        .await
+        .expect("Failed to execute request")
+}
+
+async fn get_progress(app: &TestApp, token: &str, id: impl std::fmt::Display) -> ChallengeProgress {
+    Client::new()
+        .get(app.api_url(&format!("/challenges/{id}")))
+        .bearer_auth(token)
+        .send()
+        .await
+        .expect("Failed to execute request")
+        .json()
+        .await
+        .expect("Failed to parse challenge")
+}
+
+async fn assign(
+    app: &TestApp,
+    token: &str,
+    progress: &ChallengeProgress,
+    prompt: usize,
+    reading: &Reading,
+) -> reqwest::Response {
+    Client::new()
+        .put(app.api_url(&format!(
+            "/challenges/{}/prompts/{}/entry",
+            progress.challenge.id, progress.prompts[prompt].prompt.id
+        )))
+        .bearer_auth(token)
+        .json(&json!({"reading_id": reading.id}))
+        .send()
+        .await
+        .expect("Failed to execute request")
+}
+
+fn date(y: i32, m: u32, d: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(y, m, d)
+}
+
+fn yearly_challenge() -> serde_json::Value {
+    json!({
+        "name": "2025 Challenge",
+        "year": 2025,
+        "shared": true,
+        "prompts": [
+            {"label": "A book translated from another language", "rule": "-language:english"},
+            {"label": "A book over 600 pages", "rule": "pages>600"},
+            {"label": "A book published before 1900", "rule": "year<1900"},
+            {"label": "A book a friend recommended"},
+        ],
+    })
+}
+
+#[tokio::test]
+async fn finished_readings_are_suggested_for_matching_prompts() {
+    let app = spawn_app_with_auth().await;
+    let token = app.auth_token.clone().unwrap();
+    let author = create_author_with_name(&app, "Author").await;
+    let doorstop = create_book(
+        &app,
+        "Doorstop",
+        author.id,
+        900,
+        Some(2001),
+        Some("English"),
+    )
+    .await;
+    let classic = create_book(&app, "Classic", author.id, 300, Some(1869), Some("Russian")).await;
+    let older = create_book(&app, "Older", author.id, 700, Some(2010), Some("English")).await;
+    let current = create_book(&app, "Current", author.id, 650, Some(2020), Some("English")).await;
+    read(
+        &app,
+        &token,
+        &doorstop,
+        ReadingStatus::Read,
+        date(2025, 3, 1),
+    )
+    .await;
+    read(
+        &app,
+        &token,
+        &classic,
+        ReadingStatus::Read,
+        date(2025, 5, 1),
+    )
+    .await;
+    read(&app, &token, &older, ReadingStatus::Read, date(2024, 5, 1)).await;
+    read(&app, &token, &current, ReadingStatus::Reading, None).await;
+
+    let response = create_challenge(&app, yearly_challenge()).await;
+    assert_eq!(response.status(), 201);
+    let created: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(created["prompts"].as_array().unwrap().len(), 4);
+
+    let progress = get_progress(&app, &token, &created["id"]).await;
+    assert_eq!(progress.total, 4);
+    assert_eq!(progress.completed, 0);
+    let titles = |prompt: usize| -> Vec<String> {
+        progress.prompts[prompt]
+            .suggestions
+            .iter()
+            .map(|s| s.book_title.clone())
+            .collect()
+    };
+    assert_eq!(titles(0), vec!["Classic"]);
+    // Readings finished outside the challenge year, or not finished, don't count
+    assert_eq!(titles(1), vec!["Doorstop"]);
+    assert_eq!(titles(2), vec!["Classic"]);
+    assert!(titles(3).is_empty());
+}
+
+#[tokio::test]
+async fn assigned_readings_fill_prompts_once() {
+    let app = spawn_app_with_auth().await;
+    let token = app.auth_token.clone().unwrap();
+    let author = create_author_with_name(&app, "Author").await;
+    let classic = create_book(&app, "Classic", author.id, 300, Some(1869), Some("Russian")).await;
+    let unread = create_book(&app, "Unread", author.id, 300, None, None).await;
+    let old = create_book(&app, "Old", author.id, 300, None, None).await;
+    let classic_reading = read(
+        &app,
+        &token,
+        &classic,
+        ReadingStatus::Read,
+        date(2025, 5, 1),
+    )
+    .await;
+    let unread_reading = read(&app, &token, &unread, ReadingStatus::Reading, None).await;
+    let old_reading = read(&app, &token, &old, ReadingStatus::Read, date(2023, 5, 1)).await;
+
+    let created: serde_json::Value = create_challenge(&app, yearly_challenge())
+        .await
+        .json()
+        .await
+        .unwrap();
+    let progress = get_progress(&app, &token, &created["id"]).await;
+
+    let response = assign(&app, &token, &progress, 0, &classic_reading).await;
+    assert_eq!(response.status(), 200);
+    let entry: ChallengeEntry = response.json().await.unwrap();
+    assert_eq!(entry.reading_id, classic_reading.id);
+
+    // A reading only fills one prompt per challenge
+    let response = assign(&app, &token, &progress, 2, &classic_reading).await;
+    assert_eq!(response.status(), 409);
+    let response = assign(&app, &token, &progress, 3, &unread_reading).await;
+    assert_eq!(response.status(), 400);
+    let response = assign(&app, &token, &progress, 3, &old_reading).await;
+    assert_eq!(response.status(), 400);
+
+    let updated = get_progress(&app, &token, &created["id"]).await;
+    assert_eq!(updated.completed, 1);
+    let entry = updated.prompts[0].entry.as_ref().unwrap();
+    assert_eq!(entry.book_title, "Classic");
+    // Already-assigned readings are no longer suggested elsewhere
+    assert!(updated.prompts[2].suggestions.is_empty());
+
+    let response = Client::new()
+        .delete(app.api_url(&format!(
+            "/challenges/{}/prompts/{}/entry",
+            progress.challenge.id, progress.prompts[0].prompt.id
+        )))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 204);
+    let cleared = get_progress(&app, &token, &created["id"]).await;
+    assert_eq!(cleared.completed, 0);
+    assert_eq!(cleared.prompts[2].suggestions.len(), 1);
+}
+
+#[tokio::test]
+async fn invalid_challenges_are_rejected() {
+    let app = spawn_app_with_auth().await;
+
+    for payload in [
+        json!({"name": ""}),
+        json!({"name": "Challenge", "year": 0}),
+        json!({"name": "Challenge", "prompts": [{"label": ""}]}),
+        json!({"name": "Challenge", "prompts": [{"label": "Loved it", "rule": "rating>=4"}]}),
+        json!({"name": "Challenge", "prompts": [{"label": "Dragons", "rule": "dragons"}]}),
+    ] {
+        let response = create_challenge(&app, payload.clone()).await;
+        assert_eq!(response.status(), 400, "payload: {payload}");
+    }
+}
+
+#[tokio::test]
+async fn shared_challenges_track_progress_per_user() {
+    let app = spawn_app_with_auth().await;
+    let token = app.auth_token.clone().unwrap();
+    let other_token = create_non_admin_token(&app).await;
+    let author = create_author_with_name(&app, "Author").await;
+    let classic = create_book(&app, "Classic", author.id, 300, Some(1869), Some("Russian")).await;
+    let mine = read(
+        &app,
+        &token,
+        &classic,
+        ReadingStatus::Read,
+        date(2025, 5, 1),
+    )
+    .await;
+    let theirs = read(
+        &app,
+        &other_token,
+        &classic,
+        ReadingStatus::Read,
+        date(2025, 6, 1),
+    )
+    .await;
+
+    let shared: serde_json::Value = create_challenge(&app, yearly_challenge())
+        .await
+        .json()
+        .await
+        .unwrap();
+    let private: serde_json::Value = create_challenge(
+        &app,
+        json!({"name": "Private", "prompts": [{"label": "Any"}]}),
+    )
+    .await
+    .json()
+    .await
+    .unwrap();
+
+    let progress = get_progress(&app, &token, &shared["id"]).await;
+    assert_eq!(
+        assign(&app, &token, &progress, 0, &mine).await.status(),
+        200
+    );
+    // Another user's reading cannot be assigned
+    assert_eq!(
+        assign(&app, &token, &progress, 2, &theirs).await.status(),
+        400
+    );
+
+    let other_progress = get_progress(&app, &other_token, &shared["id"]).await;
+    assert_eq!(other_progress.completed, 0);
+    assert_eq!(
+        other_progress.prompts[0].suggestions[0].reading.id,
+        theirs.id
+    );
+    assert_eq!(
+        assign(&app, &other_token, &other_progress, 0, &theirs)
+            .await
+            .status(),
+        200
+    );
+
+    let client = Client::new();
+    let listed: Vec<ChallengeSummary> = client
+        .get(app.api_url("/challenges"))
+        .bearer_auth(&other_token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].completed, 1);
+
+    let response = client
+        .get(app.api_url(&format!("/challenges/{}", private["id"])))
+        .bearer_auth(&other_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 404);
+
+    // Only the owner (or an admin) can change a shared challenge
+    let response = client
+        .put(app.api_url(&format!("/challenges/{}", shared["id"])))
+        .bearer_auth(&other_token)
+        .json(&json!({"name": "Renamed"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 403);
+    let response = client
+        .delete(app.api_url(&format!("/challenges/{}", shared["id"])))
+        .bearer_auth(&other_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 403);
+}
+
+#[tokio::test]
+async fn prompts_can_be_added_updated_and_removed() {
+    let app = spawn_app_with_auth().await;
+    let token = app.auth_token.clone().unwrap();
+    let created: serde_json::Value = create_challenge(&app, json!({"name": "Challenge"}))
+        .await
+        .json()
+        .await
+        .unwrap();
+    let id = &created["id"];
+    let client = Client::new();
+
+    let response = client
+        .post(app.api_url(&format!("/challenges/{id}/prompts")))
+        .bearer_auth(&token)
+        .json(&json!({"label": "An audiobook", "rule": "format:audiobook"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 201);
+    let prompt: serde_json::Value = response.json().await.unwrap();
+
+    let response = client
+        .put(app.api_url(&format!("/challenges/{id}/prompts/{}", prompt["id"])))
+        .bearer_auth(&token)
+        .json(&json!({"rule": ""}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    let progress = get_progress(&app, &token, id).await;
+    assert_eq!(progress.prompts[0].prompt.rule, None);
+
+    let response = client
+        .put(app.api_url(&format!("/challenges/{id}")))
+        .bearer_auth(&token)
+        .json(&json!({"year": 2024, "description": "Yearly"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    let response = client
+        .put(app.api_url(&format!("/challenges/{id}")))
+        .bearer_auth(&token)
+        .json(&json!({"year": ""}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    let progress = get_progress(&app, &token, id).await;
+    assert_eq!(progress.challenge.year, None);
+    assert_eq!(progress.challenge.description.as_deref(), Some("Yearly"));
+
+    let response = client
+        .delete(app.api_url(&format!("/challenges/{id}/prompts/{}", prompt["id"])))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 204);
+    assert_eq!(get_progress(&app, &token, id).await.total, 0);
+
+    let response = client
+        .delete(app.api_url(&format!("/challenges/{id}")))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 204);
+}
+
+#[tokio::test]
+async fn challenge_pages_render_progress() {
+    let app = spawn_app_with_auth().await;
+    let token = app.auth_token.clone().unwrap();
+    let author = create_author_with_name(&app, "Author").await;
+    let classic = create_book(&app, "Classic", author.id, 300, Some(1869), Some("Russian")).await;
+    read(
+        &app,
+        &token,
+        &classic,
+        ReadingStatus::Read,
+        date(2025, 5, 1),
+    )
+    .await;
+    let created: serde_json::Value = create_challenge(&app, yearly_challenge())
+        .await
+        .json()
+        .await
+        .unwrap();
+
+    let session = create_session(&app).await;
+    let client = Client::new();
+    let list = client
+        .get(format!("{}/challenges", app.address))
+        .header("Cookie", format!("booklog_session={session}"))
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(list.contains("2025 Challenge"));
+    assert!(list.contains("0 / 4 prompts"));
+
+    let detail = client
+        .get(format!("{}/challenges/{}", app.address, created["id"]))
+        .header("Cookie", format!("booklog_session={session}"))
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(detail.contains("A book published before 1900"));
+    assert!(detail.contains("Suggested"));
+    assert!(detail.contains("Classic"));
+
+    let edit = client
+        .get(format!("{}/challenges/{}/edit", app.address, created["id"]))
+        .header("Cookie", format!("booklog_session={session}"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(edit.status(), 200);
+}
@@ -175,6 +175,7 @@ async fn authors_create_with_datastar_header_returns_fragment() {
 
     let new_author = NewAuthor {
         name: "Datastar Test Author".to_string(),
+        details: Default::default(),
         created_at: None,
     };
 
@@ -206,6 +207,7 @@ async fn authors_create_without_datastar_header_returns_json() {
 
     let new_author = NewAuthor {
         name: "JSON Test Author".to_string(),
+        details: Default::default(),
         created_at: None,
     };
 
@@ -0,0 +1,253 @@
+use std::collections::HashSet;
+
+use booklog::domain::library::LibraryExport;
+use booklog::presentation::export::obsidian::{
+    NOTES_MARKER, NoteUpdate, VaultFile, match_files, render_vault,
+};
+use booklog::presentation::export::site::render_site;
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+
+use crate::helpers::{
+    TestApp, create_author_with_name, create_book_with_title, create_entity,
+    create_non_admin_token, spawn_app_with_auth,
+};
+
+async fn add_reading(
+    app: &TestApp,
+    book_id: i64,
+    status: &str,
+    rating: Option<f64>,
+    private: bool,
+) {
+    create_entity::<_, Value>(
+        app,
+        "/readings",
+        &json!({
+            "user_id": 1,
+            "book_id": book_id,
+            "status": status,
+            "format": "ereader",
+            "started_at": "2026-01-05",
+            "finished_at": (status == "read").then_some("2026-02-01"),
+            "rating": rating,
+            "private": private,
+        }),
+    )
+    .await;
+}
+
+async fn fetch_export(app: &TestApp, token: &str) -> LibraryExport {
+    let response = Client::new()
+        .get(app.api_url("/export/library"))
+        .bearer_auth(token)
+        .send()
+        .await
+        .expect("failed to fetch export");
+    assert_eq!(response.status(), StatusCode::OK);
+    response.json().await.expect("failed to parse export")
+}
+
+#[tokio::test]
+async fn export_requires_authentication() {
+    let app = spawn_app_with_auth().await;
+
+    let response = Client::new()
+        .get(app.api_url("/export/library"))
+        .send()
+        .await
+        .expect("failed to fetch export");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn export_includes_books_readings_tags_and_authors() {
+    let app = spawn_app_with_auth().await;
+    let author = create_author_with_name(&app, "Frank Herbert").await;
+    let book = create_book_with_title(&app, author.id, "Dune").await;
+    add_reading(&app, book.id.into_inner(), "read", Some(4.5), false).await;
+    add_reading(&app, book.id.into_inner(), "reading", None, true).await;
+    Client::new()
+        .put(app.api_url(&format!("/books/{}/tags", book.id)))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&json!({ "tags": ["desert"] }))
+        .send()
+        .await
+        .expect("failed to set tags");
+
+    let export = fetch_export(&app, app.auth_token.as_ref().unwrap()).await;
+    assert_eq!(export.username, "admin");
+    assert_eq!(export.books.len(), 1);
+    let entry = &export.books[0];
+    assert_eq!(entry.book.book.title, "Dune");
+    assert_eq!(entry.shelf.as_str(), "library");
+    assert_eq!(entry.tags, vec!["desert"]);
+    assert_eq!(entry.readings.len(), 2, "private readings are exported too");
+    assert!(!entry.has_cover);
+    assert_eq!(export.authors.len(), 1);
+    assert_eq!(export.authors[0].name, "Frank Herbert");
+
+    // Other users only see their own library
+    let token = create_non_admin_token(&app).await;
+    let export = fetch_export(&app, &token).await;
+    assert!(export.books.is_empty());
+    assert!(export.authors.is_empty());
+}
+
+#[tokio::test]
+async fn rendered_site_uses_relative_links_and_hides_private_readings() {
+    let app = spawn_app_with_auth().await;
+    let author = create_author_with_name(&app, "Ursula K. Le Guin").await;
+    let book = create_book_with_title(&app, author.id, "The Dispossessed").await;
+    add_reading(&app, book.id.into_inner(), "read", Some(5.0), false).await;
+    let diary = create_book_with_title(&app, author.id, "Secret Diary").await;
+    add_reading(&app, diary.id.into_inner(), "reading", None, true).await;
+
+    let export = fetch_export(&app, app.auth_token.as_ref().unwrap()).await;
+    let covers = HashSet::from([book.id]);
+    let files = render_site(&export, &covers).expect("site renders");
+    let file = |path: &str| {
+        files
+            .iter()
+            .find(|file| file.path == path)
+            .unwrap_or_else(|| panic!("{path} missing"))
+            .contents
+            .as_str()
+    };
+
+    let index = file("index.html");
+    assert!(index.contains("href=\"style.css\""));
+    assert!(index.contains(&format!("href=\"books/{}.html\"", book.id)));
+    assert!(index.contains("The Dispossessed"));
+    assert!(!index.contains("Secret Diary"));
+    assert!(!index.contains("href=\"/"), "links must be relative");
+
+    let page = file(&format!("books/{}.html", book.id));
+    assert!(page.contains("href=\"../style.css\""));
+    assert!(page.contains(&format!("href=\"../authors/{}.html\"", author.id)));
+    assert!(page.contains(&format!("src=\"../covers/{}.jpg\"", book.id)));
+    assert!(page.contains("Ursula K. Le Guin"));
+    assert!(!page.contains("href=\"/"), "links must be relative");
+
+    let search: Vec<Value> = serde_json::from_str(file("search-index.json")).unwrap();
+    assert!(
+        search
+            .iter()
+            .any(|entry| entry["title"] == "The Dispossessed"
+                && entry["url"] == format!("books/{}.html", book.id))
+    );
+    file(&format!("authors/{}.html", author.id));
+    file("style.css");
+}
+
+#[tokio::test]
+async fn vault_notes_link_authors_and_keep_reader_notes() {
+    let app = spawn_app_with_auth().await;
+    let author = create_author_with_name(&app, "Frank Herbert").await;
+    let book = create_book_with_title(&app, author.id, "Dune: Messiah").await;
+    add_reading(&app, book.id.into_inner(), "read", Some(4.5), true).await;
+
+    let export = fetch_export(&app, app.auth_token.as_ref().unwrap()).await;
+    let covers = HashSet::from([book.id]);
+    let notes = render_vault(&export, &covers);
+    let note = notes
+        .iter()
+        .find(|note| note.path == "Books/Dune Messiah.md")
+        .expect("book note");
+    assert!(
+        note.generated
+            .starts_with("---\ntitle: \"Dune: Messiah\"\n")
+    );
+    assert!(
+        note.generated
+            .contains("authors:\n  - \"[[Frank Herbert]]\"\n")
+    );
+    assert!(note.generated.contains("shelf: library\n"));
+    assert!(
+        note.generated
+            .contains(&format!("cover: \"[[attachments/cover-{}.jpg]]\"", book.id))
+    );
+    assert!(
+        note.generated
+            .contains("  - status: read\n    format: ereader\n")
+    );
+    assert!(note.generated.contains("    rating: 4.5\n"));
+    assert!(note.generated.contains("    private: true\n"));
+    assert!(note.generated.ends_with(NOTES_MARKER));
+
+    let author_note = notes
+        .iter()
+        .find(|note| note.path == "Authors/Frank Herbert.md")
+        .expect("author note");
+    assert!(
+        author_note
+            .generated
+            .contains("- [[Dune Messiah|Dune: Messiah]]")
+    );
+
+    // Re-exporting after a change keeps what the reader wrote below the marker
+    let NoteUpdate::Create(first) = note.merge(None) else {
+        panic!("expected a new note");
+    };
+    let edited = format!("{first}My thoughts on the sequel.\n");
+    assert_eq!(note.merge(Some(&edited)), NoteUpdate::Unchanged);
+    add_reading(&app, book.id.into_inner(), "reading", None, false).await;
+    let export = fetch_export(&app, app.auth_token.as_ref().unwrap()).await;
+    let notes = render_vault(&export, &covers);
+    let NoteUpdate::Update(updated) = notes[0].merge(Some(&edited)) else {
+        panic!("expected an update");
+    };
+    assert!(updated.contains("  - status: reading\n"));
+    assert!(updated.ends_with(&format!("{NOTES_MARKER}\nMy thoughts on the sequel.\n")));
+}
+
+#[tokio::test]
+async fn renamed_books_keep_their_vault_notes() {
+    let app = spawn_app_with_auth().await;
+    let author = create_author_with_name(&app, "Frank Herbert").await;
+    let book = create_book_with_title(&app, author.id, "Dune").await;
+    add_reading(&app, book.id.into_inner(), "read", None, false).await;
+
+    let export = fetch_export(&app, app.auth_token.as_ref().unwrap()).await;
+    let notes = render_vault(&export, &HashSet::new());
+    let files: Vec<VaultFile> = notes
+        .iter()
+        .map(|note| {
+            let NoteUpdate::Create(contents) = note.merge(None) else {
+                panic!("expected a new note");
+            };
+            VaultFile {
+                path: note.path.clone(),
+                contents: format!("{contents}Notes on {}.\n", note.path),
+            }
+        })
+        .collect();
+
+    let response = Client::new()
+        .put(app.api_url(&format!("/books/{}", book.id)))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .form(&[("title", "Dune (Deluxe Edition)")])
+        .send()
+        .await
+        .expect("failed to rename book");
+    assert!(response.status().is_success());
+
+    let export = fetch_export(&app, app.auth_token.as_ref().unwrap()).await;
+    let notes = render_vault(&export, &HashSet::new());
+    let matched = match_files(&notes, &files);
+    let (note, file) = notes
+        .iter()
+        .zip(matched)
+        .find(|(note, _)| {
+            note.booklog_id == book.id.into_inner() && note.path.starts_with("Books/")
+        })
+        .expect("book note");
+    assert_eq!(note.path, "Books/Dune (Deluxe Edition).md");
+    let file = file.expect("the note is found under its old name");
+    assert_eq!(file.path, "Books/Dune.md");
+    let NoteUpdate::Update(updated) = note.merge(Some(&file.contents)) else {
+        panic!("expected an update");
+    };
+    assert!(updated.contains("# Dune (Deluxe Edition)"));
+    assert!(updated.ends_with(&format!("{NOTES_MARKER}\nNotes on Books/Dune.md.\n")));
+}
@@ -89,6 +89,41 @@ async fn extract_author_returns_datastar_signals() {
     assert_eq!(body["_authorName"], "Neil Gaiman");
 }
 
+#[tokio::test]
+async fn extract_author_returns_details_as_datastar_signals() {
+    let app = spawn_app_with_openrouter_mock().await;
+    let mock_server = app.mock_server.as_ref().unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/chat/completions"))
+        .respond_with(mock_openrouter_response(
+            r#"{"name": "Iain Banks", "birth_year": 1954, "death_year": 2013, "nationality": "Scottish", "aliases": ["Iain M. Banks"]}"#,
+        ))
+        .mount(mock_server)
+        .await;
+
+    let client = reqwest::Client::new();
+    let payload = serde_json::json!({ "prompt": "Iain Banks" });
+
+    let response = client
+        .post(app.api_url("/extract-author"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .header("datastar-request", "true")
+        .json(&payload)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), 200);
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["_authorBirthYear"], "1954");
+    assert_eq!(body["_authorDeathYear"], "2013");
+    assert_eq!(body["_authorNationality"], "Scottish");
+    assert_eq!(body["_authorAliases"], "Iain M. Banks");
+    assert_eq!(body["_authorWebsite"], "");
+}
+
 #[tokio::test]
 async fn extract_author_requires_auth() {
     let app = spawn_app_with_openrouter_mock().await;
@@ -264,6 +299,49 @@ async fn extract_book_scan_matches_existing_author() {
     assert_eq!(body["_matchedAuthorId"], author.id.into_inner().to_string());
 }
 
+#[tokio::test]
+async fn extract_book_scan_matches_existing_author_by_alias() {
+    let app = spawn_app_with_openrouter_mock().await;
+    let mock_server = app.mock_server.as_ref().unwrap();
+    let client = reqwest::Client::new();
+
+    let author: serde_json::Value = client
+        .post(app.api_url("/authors"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&serde_json::json!({
+            "name": "J.K. Rowling",
+            "aliases": ["Robert Galbraith"],
+        }))
+        .send()
+        .await
+        .expect("Failed to create author")
+        .json()
+        .await
+        .expect("Failed to parse author");
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/chat/completions"))
+        .respond_with(mock_openrouter_response(
+            r#"{"title": "The Cuckoo's Calling", "author_name": "robert galbraith"}"#,
+        ))
+        .mount(mock_server)
+        .await;
+
+    let response = client
+        .post(app.api_url("/extract-book"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .header("datastar-request", "true")
+        .json(&serde_json::json!({ "prompt": "The Cuckoo's Calling" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), 200);
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["_matchedAuthorId"], author["id"].to_string());
+}
+
 #[tokio::test]
 async fn extract_book_scan_matches_existing_book() {
     let app = spawn_app_with_openrouter_mock().await;
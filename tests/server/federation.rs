@@ -0,0 +1,415 @@
+use booklog::infrastructure::http_signatures::{
+    KeyPair, generate_key_pair, parse_signature_header, sign_post, verify,
+};
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+use tokio::time::{Duration, sleep, timeout};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+use crate::helpers::{
+    TestApp, create_author_with_name, create_book_with_title, create_entity, put_form,
+    spawn_app_with_timeline_sync, spawn_app_without_private_network,
+};
+
+/// A remote server hosting the actor `ana`, whose inbox accepts anything.
+struct RemoteServer {
+    server: MockServer,
+    keys: KeyPair,
+}
+
+impl RemoteServer {
+    async fn start() -> Self {
+        let server = MockServer::start().await;
+        let keys = generate_key_pair().unwrap();
+        let actor = format!("{}/users/ana", server.uri());
+        Mock::given(method("GET"))
+            .and(path("/users/ana"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": actor,
+                "type": "Person",
+                "inbox": format!("{actor}/inbox"),
+                "publicKey": {
+                    "id": format!("{actor}#main-key"),
+                    "owner": actor,
+                    "publicKeyPem": keys.public_key_pem,
+                },
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/users/ana/inbox"))
+            .respond_with(ResponseTemplate::new(202))
+            .mount(&server)
+            .await;
+        Self { server, keys }
+    }
+
+    fn actor(&self) -> String {
+        format!("{}/users/ana", self.server.uri())
+    }
+
+    /// POST `activity` to a user's inbox, signed with ana's key.
+    async fn send(&self, url: &str, activity: &Value) -> reqwest::Response {
+        let body = activity.to_string();
+        let signed = sign_post(
+            &self.keys.private_key_pem,
+            &format!("{}#main-key", self.actor()),
+            url,
+            body.as_bytes(),
+        )
+        .unwrap();
+        Client::new()
+            .post(url)
+            .header("content-type", "application/activity+json")
+            .header("date", signed.date)
+            .header("digest", signed.digest)
+            .header("signature", signed.signature)
+            .body(body)
+            .send()
+            .await
+            .unwrap()
+    }
+
+    /// Wait for an activity of `kind` to arrive in ana's inbox.
+    async fn received(&self, kind: &str) -> (Request, Value) {
+        let found = timeout(Duration::from_secs(5), async {
+            loop {
+                let requests = self.server.received_requests().await.unwrap_or_default();
+                for request in requests {
+                    if request.url.path() != "/users/ana/inbox" {
+                        continue;
+                    }
+                    let activity: Value = serde_json::from_slice(&request.body).unwrap();
+                    if activity["type"] == kind {
+                        return (request, activity);
+                    }
+                }
+                sleep(Duration::from_millis(25)).await;
+            }
+        })
+        .await;
+        found.unwrap_or_else(|_| panic!("no {kind} delivered"))
+    }
+
+    async fn inbox_posts(&self) -> usize {
+        self.server
+            .received_requests()
+            .await
+            .unwrap_or_default()
+            .iter()
+            .filter(|request| request.method.as_str() == "POST")
+            .count()
+    }
+}
+
+async fn enable_federation(app: &TestApp, manually_approve: bool) {
+    let response = put_form(
+        app,
+        "/federation/settings",
+        &[
+            ("enabled", "true"),
+            (
+                "manually_approve",
+                if manually_approve { "true" } else { "false" },
+            ),
+        ],
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+}
+
+async fn get_json(url: &str) -> (StatusCode, Value) {
+    let response = Client::new().get(url).send().await.unwrap();
+    let status = response.status();
+    let body = response.json().await.unwrap_or(Value::Null);
+    (status, body)
+}
+
+fn follow(remote: &RemoteServer, app: &TestApp) -> Value {
+    json!({
+        "@context": "https://www.w3.org/ns/activitystreams",
+        "id": format!("{}/follows/1", remote.actor()),
+        "type": "Follow",
+        "actor": remote.actor(),
+        "object": app.page_url("/users/admin"),
+    })
+}
+
+#[tokio::test]
+async fn actors_only_exist_once_federation_is_enabled() {
+    let app = spawn_app_with_timeline_sync().await;
+    let host = app.address.trim_start_matches("http://").to_string();
+    let webfinger = app.page_url(&format!(
+        "/.well-known/webfinger?resource=acct:admin@{host}"
+    ));
+
+    assert_eq!(
+        get_json(&app.page_url("/users/admin")).await.0,
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(get_json(&webfinger).await.0, StatusCode::NOT_FOUND);
+
+    enable_federation(&app, false).await;
+
+    let response = Client::new()
+        .get(app.page_url("/users/admin"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"],
+        "application/activity+json"
+    );
+    let actor: Value = response.json().await.unwrap();
+    assert_eq!(actor["id"], app.page_url("/users/admin"));
+    assert_eq!(actor["inbox"], app.page_url("/users/admin/inbox"));
+    assert_eq!(actor["manuallyApprovesFollowers"], false);
+    assert!(
+        actor["publicKey"]["publicKeyPem"]
+            .as_str()
+            .unwrap()
+            .starts_with("-----BEGIN PUBLIC KEY-----")
+    );
+
+    let (status, jrd) = get_json(&webfinger).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(jrd["links"][0]["href"], actor["id"]);
+
+    let wrong_host = app.page_url("/.well-known/webfinger?resource=acct:admin@elsewhere.example");
+    assert_eq!(get_json(&wrong_host).await.0, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn inbox_rejects_unsigned_and_forged_requests() {
+    let app = spawn_app_with_timeline_sync().await;
+    let remote = RemoteServer::start().await;
+    enable_federation(&app, false).await;
+    let inbox = app.page_url("/users/admin/inbox");
+
+    let response = Client::new()
+        .post(&inbox)
+        .body(follow(&remote, &app).to_string())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // Signed with a key that isn't ana's
+    let forged_keys = generate_key_pair().unwrap();
+    let body = follow(&remote, &app).to_string();
+    let signed = sign_post(
+        &forged_keys.private_key_pem,
+        &format!("{}#main-key", remote.actor()),
+        &inbox,
+        body.as_bytes(),
+    )
+    .unwrap();
+    let response = Client::new()
+        .post(&inbox)
+        .header("date", signed.date)
+        .header("digest", signed.digest)
+        .header("signature", signed.signature)
+        .body(body)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let (_, followers) = get_json(&app.page_url("/users/admin/followers")).await;
+    assert_eq!(followers["totalItems"], 0);
+}
+
+#[tokio::test]
+async fn actors_can_only_be_claimed_by_their_own_url() {
+    let app = spawn_app_with_timeline_sync().await;
+    let remote = RemoteServer::start().await;
+    enable_federation(&app, false).await;
+    let inbox = app.page_url("/users/admin/inbox");
+
+    // mallory's document claims to be ana on the same host, then an actor
+    // on another host entirely
+    for claimed in [
+        remote.actor(),
+        "https://victim.example/users/bob".to_string(),
+    ] {
+        let server = MockServer::start().await;
+        let keys = generate_key_pair().unwrap();
+        let key_id = format!("{}/users/mallory#main-key", server.uri());
+        Mock::given(method("GET"))
+            .and(path("/users/mallory"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": claimed,
+                "type": "Person",
+                "inbox": format!("{}/users/mallory/inbox", server.uri()),
+                "publicKey": {
+                    "id": key_id,
+                    "owner": claimed,
+                    "publicKeyPem": keys.public_key_pem,
+                },
+            })))
+            .mount(&server)
+            .await;
+
+        let body = json!({
+            "id": format!("{claimed}/follows/1"),
+            "type": "Follow",
+            "actor": claimed,
+            "object": app.page_url("/users/admin"),
+        })
+        .to_string();
+        let signed = sign_post(&keys.private_key_pem, &key_id, &inbox, body.as_bytes()).unwrap();
+        let response = Client::new()
+            .post(&inbox)
+            .header("date", signed.date)
+            .header("digest", signed.digest)
+            .header("signature", signed.signature)
+            .body(body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED, "{claimed}");
+    }
+
+    let (_, followers) = get_json(&app.page_url("/users/admin/followers")).await;
+    assert_eq!(followers["totalItems"], 0);
+}
+
+#[tokio::test]
+async fn inbox_does_not_fetch_keys_from_private_addresses() {
+    let app = spawn_app_without_private_network().await;
+    let remote = RemoteServer::start().await;
+    enable_federation(&app, false).await;
+
+    let response = remote
+        .send(&app.page_url("/users/admin/inbox"), &follow(&remote, &app))
+        .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert!(
+        remote
+            .server
+            .received_requests()
+            .await
+            .unwrap_or_default()
+            .is_empty(),
+        "the key URL on a loopback address is never fetched"
+    );
+}
+
+#[tokio::test]
+async fn followers_are_accepted_and_receive_finished_readings() {
+    let app = spawn_app_with_timeline_sync().await;
+    let remote = RemoteServer::start().await;
+    enable_federation(&app, false).await;
+
+    let response = remote
+        .send(&app.page_url("/users/admin/inbox"), &follow(&remote, &app))
+        .await;
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+
+    let (request, accept) = remote.received("Accept").await;
+    assert_eq!(accept["actor"], app.page_url("/users/admin"));
+    assert_eq!(accept["object"]["type"], "Follow");
+
+    // The Accept is signed with the key published on the actor
+    let (_, actor) = get_json(&app.page_url("/users/admin")).await;
+    let header = |name: &str| request.headers.get(name).and_then(|v| v.to_str().ok());
+    let signature = parse_signature_header(header("signature").unwrap()).unwrap();
+    assert_eq!(signature.key_id, actor["publicKey"]["id"]);
+    verify(
+        &signature,
+        actor["publicKey"]["publicKeyPem"].as_str().unwrap(),
+        "POST",
+        "/users/ana/inbox",
+        &request.body,
+        header,
+    )
+    .unwrap();
+
+    let author = create_author_with_name(&app, "Ursula K. Le Guin").await;
+    let book = create_book_with_title(&app, author.id, "The Left Hand of Darkness").await;
+    let _: Value = create_entity(
+        &app,
+        "/readings",
+        &json!({ "user_id": 1, "book_id": book.id, "status": "read", "rating": 4.5 }),
+    )
+    .await;
+
+    let (_, create) = remote.received("Create").await;
+    let content = create["object"]["content"].as_str().unwrap();
+    assert!(content.contains("The Left Hand of Darkness"));
+    assert!(content.contains("Ursula K. Le Guin"));
+    assert!(content.contains("Rated 4.5/5"));
+    assert_eq!(create["object"]["type"], "Note");
+
+    let (_, outbox) = get_json(&app.page_url("/users/admin/outbox")).await;
+    assert_eq!(outbox["totalItems"], 1);
+    assert_eq!(outbox["orderedItems"][0]["id"], create["id"]);
+    let (_, followers) = get_json(&app.page_url("/users/admin/followers")).await;
+    assert_eq!(followers["totalItems"], 1);
+}
+
+#[tokio::test]
+async fn notes_leave_out_hidden_ratings() {
+    let app = spawn_app_with_timeline_sync().await;
+    let remote = RemoteServer::start().await;
+    enable_federation(&app, false).await;
+    remote
+        .send(&app.page_url("/users/admin/inbox"), &follow(&remote, &app))
+        .await;
+    remote.received("Accept").await;
+
+    let response = put_form(&app, "/profile/privacy", &[("show_ratings", "false")]).await;
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    let author = create_author_with_name(&app, "Ursula K. Le Guin").await;
+    let book = create_book_with_title(&app, author.id, "The Dispossessed").await;
+    let _: Value = create_entity(
+        &app,
+        "/readings",
+        &json!({ "user_id": 1, "book_id": book.id, "status": "read", "rating": 4.5 }),
+    )
+    .await;
+
+    let (_, create) = remote.received("Create").await;
+    let content = create["object"]["content"].as_str().unwrap();
+    assert!(content.contains("The Dispossessed"));
+    assert!(!content.contains("Rated"));
+}
+
+#[tokio::test]
+async fn manual_approval_holds_followers_until_approved() {
+    let app = spawn_app_with_timeline_sync().await;
+    let remote = RemoteServer::start().await;
+    enable_federation(&app, true).await;
+
+    let response = remote
+        .send(&app.page_url("/users/admin/inbox"), &follow(&remote, &app))
+        .await;
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+
+    let (_, followers) = get_json(&app.page_url("/users/admin/followers")).await;
+    assert_eq!(followers["totalItems"], 0);
+    sleep(Duration::from_millis(200)).await;
+    assert_eq!(
+        remote.inbox_posts().await,
+        0,
+        "nothing is sent until approval"
+    );
+
+    let (follower_id,): (i64,) = sqlx::query_as("SELECT id FROM activitypub_followers")
+        .fetch_one(&app.pool)
+        .await
+        .unwrap();
+    let response = Client::new()
+        .post(app.api_url(&format!("/federation/followers/{follower_id}/approve")))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    remote.received("Accept").await;
+    let (_, followers) = get_json(&app.page_url("/users/admin/followers")).await;
+    assert_eq!(followers["totalItems"], 1);
+}
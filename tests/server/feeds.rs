@@ -0,0 +1,259 @@
+use booklog::domain::ids::BookId;
+use booklog::domain::readings::Reading;
+use booklog::domain::timeline::NewTimelineEvent;
+use chrono::{Duration, Utc};
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+
+use crate::helpers::{
+    TestApp, create_author_with_name, create_book_with_title, create_entity, create_library_item,
+    put_form, spawn_app_with_auth,
+};
+use crate::images_api::tiny_png_data_url;
+
+async fn create_finished_reading(app: &TestApp, book_id: BookId) -> Reading {
+    create_entity(
+        app,
+        "/readings",
+        &json!({
+            "user_id": 1,
+            "book_id": book_id,
+            "status": "read",
+            "rating": 4.5,
+            "quick_reviews": ["page-turner"],
+        }),
+    )
+    .await
+}
+
+async fn get_feed(app: &TestApp, path: &str) -> reqwest::Response {
+    Client::new()
+        .get(app.page_url(path))
+        .send()
+        .await
+        .expect("failed to fetch feed")
+}
+
+/// Issue a token for `feed` and return the path and query of its Atom URL.
+async fn regenerate_token(app: &TestApp, feed: &str) -> String {
+    let response = Client::new()
+        .post(app.api_url(&format!("/feeds/{feed}/token")))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("failed to regenerate feed token");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = response.json().await.unwrap();
+    let atom_url = body["atom_url"].as_str().unwrap();
+    let path = &atom_url[atom_url.find("/users/").unwrap()..];
+    path.to_string()
+}
+
+#[tokio::test]
+async fn private_feeds_are_hidden_without_a_token() {
+    let app = spawn_app_with_auth().await;
+    create_library_item(&app, "Dune").await;
+
+    let response = get_feed(&app, "/users/admin/feed.atom").await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = get_feed(&app, "/users/admin/feed.rss?token=guess").await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = get_feed(&app, "/users/nobody/feed.atom").await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn feed_tokens_unlock_only_their_own_feed() {
+    let app = spawn_app_with_auth().await;
+    let author = create_author_with_name(&app, "Frank Herbert").await;
+    let book = create_book_with_title(&app, author.id, "Dune").await;
+    create_finished_reading(&app, book.id).await;
+
+    let path = regenerate_token(&app, "all").await;
+    assert!(path.starts_with("/users/admin/feed.atom?token="));
+    let response = get_feed(&app, &path).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(
+        response.headers()["content-type"]
+            .to_str()
+            .unwrap()
+            .starts_with("application/atom+xml")
+    );
+    let body = response.text().await.unwrap();
+    assert!(body.contains("<title>Finished: Dune</title>"));
+    assert!(body.contains("Frank Herbert"));
+    assert!(body.contains("Rated 4.5/5"));
+    assert!(body.contains("Page-turner"));
+
+    // The same token doesn't open the finished-only feed
+    let finished = path.replace("?token=", "?kind=finished&token=");
+    let response = get_feed(&app, &finished).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn regenerating_a_token_replaces_the_old_one() {
+    let app = spawn_app_with_auth().await;
+    let first = regenerate_token(&app, "finished").await;
+    let second = regenerate_token(&app, "finished").await;
+    assert_ne!(first, second);
+
+    assert_eq!(get_feed(&app, &first).await.status(), StatusCode::NOT_FOUND);
+    assert_eq!(get_feed(&app, &second).await.status(), StatusCode::OK);
+
+    let response = Client::new()
+        .delete(app.api_url("/feeds/finished/token"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert_eq!(
+        get_feed(&app, &second).await.status(),
+        StatusCode::NOT_FOUND
+    );
+}
+
+#[tokio::test]
+async fn finished_feed_only_lists_finished_readings() {
+    let app = spawn_app_with_auth().await;
+    create_library_item(&app, "Still Reading").await;
+    let author = create_author_with_name(&app, "Ursula Le Guin").await;
+    let book = create_book_with_title(&app, author.id, "The Dispossessed").await;
+    create_finished_reading(&app, book.id).await;
+
+    let path = regenerate_token(&app, "finished").await;
+    let rss = path.replace("feed.atom", "feed.rss");
+    let response = get_feed(&app, &rss).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(
+        response.headers()["content-type"]
+            .to_str()
+            .unwrap()
+            .starts_with("application/rss+xml")
+    );
+    let body = response.text().await.unwrap();
+    assert!(body.contains("<title>Finished: The Dispossessed</title>"));
+    assert!(!body.contains("Still Reading"));
+}
+
+#[tokio::test]
+async fn public_profiles_serve_feeds_without_a_token() {
+    let app = spawn_app_with_auth().await;
+    create_library_item(&app, "Dune").await;
+
+    let response = put_form(&app, "/profile/visibility", &[("public", "true")]).await;
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+
+    let response = get_feed(&app, "/users/admin/feed.atom").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.text().await.unwrap();
+    assert!(body.contains("Dune"));
+
+    put_form(&app, "/profile/visibility", &[("public", "false")]).await;
+    let response = get_feed(&app, "/users/admin/feed.atom").await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn feeds_leave_out_what_profile_privacy_hides() {
+    let app = spawn_app_with_auth().await;
+    let author = create_author_with_name(&app, "Frank Herbert").await;
+    let book = create_book_with_title(&app, author.id, "Dune").await;
+    create_finished_reading(&app, book.id).await;
+    put_form(&app, "/profile/visibility", &[("public", "true")]).await;
+
+    let response = put_form(
+        &app,
+        "/profile/privacy",
+        &[("show_ratings", "false"), ("show_quick_reviews", "false")],
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+
+    for path in ["/users/admin/feed.atom", "/users/admin/feed.rss"] {
+        let body = get_feed(&app, path).await.text().await.unwrap();
+        assert!(body.contains("Finished: Dune"));
+        assert!(!body.contains("4.5"), "{path} shows the rating");
+        assert!(!body.contains("Page-turner"), "{path} shows quick reviews");
+    }
+}
+
+#[tokio::test]
+async fn hidden_rating_events_do_not_crowd_out_the_rest_of_a_feed() {
+    let app = spawn_app_with_auth().await;
+    let author = create_author_with_name(&app, "Frank Herbert").await;
+    let book = create_book_with_title(&app, author.id, "Dune").await;
+    let reading = create_finished_reading(&app, book.id).await;
+    put_form(&app, "/profile/visibility", &[("public", "true")]).await;
+    put_form(&app, "/profile/privacy", &[("show_ratings", "false")]).await;
+
+    for _ in 0..40 {
+        app.timeline_repo
+            .insert(NewTimelineEvent {
+                user_id: Some(reading.user_id),
+                entity_type: "reading".to_string(),
+                entity_id: reading.id.into_inner(),
+                action: "rated".to_string(),
+                occurred_at: Utc::now() + Duration::hours(1),
+                title: "Rated: Dune".to_string(),
+                details: Vec::new(),
+                genres: Vec::new(),
+                reading_data: None,
+                changes: Vec::new(),
+            })
+            .await
+            .unwrap();
+    }
+
+    let body = get_feed(&app, "/users/admin/feed.atom")
+        .await
+        .text()
+        .await
+        .unwrap();
+    assert!(body.contains("Finished: Dune"));
+    assert!(!body.contains("Rated: Dune"));
+}
+
+#[tokio::test]
+async fn feed_entries_enclose_the_book_cover_thumbnail() {
+    let app = spawn_app_with_auth().await;
+    let author = create_author_with_name(&app, "Frank Herbert").await;
+    let book = create_book_with_title(&app, author.id, "Dune").await;
+    create_finished_reading(&app, book.id).await;
+    put_form(&app, "/profile/visibility", &[("public", "true")]).await;
+
+    let client = Client::new();
+    let response = client
+        .put(app.api_url(&format!("/book/{}/image", book.id)))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&json!({ "image": tiny_png_data_url() }))
+        .send()
+        .await
+        .expect("failed to upload cover");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    let thumbnail = client
+        .get(app.api_url(&format!("/book/{}/thumbnail", book.id)))
+        .send()
+        .await
+        .expect("failed to fetch thumbnail")
+        .bytes()
+        .await
+        .unwrap();
+
+    let body = get_feed(&app, "/users/admin/feed.rss")
+        .await
+        .text()
+        .await
+        .unwrap();
+    let enclosure = format!(
+        "<enclosure url=\"{}/api/v1/book/{}/thumbnail\" length=\"{}\"",
+        booklog::base_url(),
+        book.id,
+        thumbnail.len()
+    );
+    assert!(body.contains(&enclosure), "{body}");
+}
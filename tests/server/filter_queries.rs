@@ -0,0 +1,319 @@
+use booklog::application::errors::ErrorResponse;
+use booklog::domain::book_items::{Book, BookWithAuthors, NewBook};
+use booklog::domain::ids::{AuthorId, BookId, GenreId, UserId};
+use booklog::domain::readings::{
+    NewReading, Reading, ReadingFormat, ReadingStatus, ReadingWithBook,
+};
+use booklog::domain::user_books::UserBook;
+use chrono::NaiveDate;
+use reqwest::Client;
+
+use crate::helpers::{
+    TestApp, create_author_with_name, create_entity, create_genre_with_name, create_session,
+    new_book, spawn_app_with_auth,
+};
+
+async fn create_book(
+    app: &TestApp,
+    title: &str,
+    author_id: AuthorId,
+    genre_id: Option<GenreId>,
+    year_published: Option<i32>,
+) -> Book {
+    create_entity(
+        app,
+        "/books",
+        &NewBook {
+            page_count: Some(320),
+            year_published,
+            primary_genre_id: genre_id,
+            ..new_book(author_id, title)
+        },
+    )
+    .await
+}
+
+async fn create_reading(
+    app: &TestApp,
+    book_id: BookId,
+    format: ReadingFormat,
+    finished_at: Option<NaiveDate>,
+    rating: Option<f64>,
+) -> Reading {
+    create_entity(
+        app,
+        "/readings",
+        &NewReading {
+            user_id: UserId::new(1),
+            book_id,
+            status: if finished_at.is_some() {
+                ReadingStatus::Read
+            } else {
+                ReadingStatus::Reading
+            },
+            format: Some(format),
+            started_at: None,
+            finished_at,
+            rating,
+            quick_reviews: Vec::new(),
+            private: false,
+            created_at: None,
+        },
+    )
+    .await
+}
+
+async fn get_json<T: serde::de::DeserializeOwned>(app: &TestApp, path: &str, q: &str) -> T {
+    let response = Client::new()
+        .get(app.api_url(path))
+        .query(&[("q", q)])
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), 200);
+    response.json().await.expect("Failed to parse response")
+}
+
+async fn get_error(app: &TestApp, path: &str, q: &str) -> String {
+    let response = Client::new()
+        .get(app.api_url(path))
+        .query(&[("q", q)])
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), 400);
+    let error: ErrorResponse = response.json().await.expect("Failed to parse response");
+    error.message
+}
+
+/// Two authors, two genres and three books with readings in different shapes.
+async fn seed_library(app: &TestApp) -> (Book, Book, Book) {
+    let le_guin = create_author_with_name(app, "Ursula K. Le Guin").await;
+    let herbert = create_author_with_name(app, "Frank Herbert").await;
+    let fantasy = create_genre_with_name(app, "Fantasy").await;
+    let scifi = create_genre_with_name(app, "Science Fiction").await;
+
+    let earthsea = create_book(
+        app,
+        "A Wizard of Earthsea",
+        le_guin.id,
+        Some(fantasy.id),
+        Some(1968),
+    )
+    .await;
+    let tehanu = create_book(app, "Tehanu", le_guin.id, Some(fantasy.id), Some(2001)).await;
+    let dune = create_book(app, "Dune", herbert.id, Some(scifi.id), Some(1965)).await;
+
+    create_reading(
+        app,
+        earthsea.id,
+        ReadingFormat::Audiobook,
+        NaiveDate::from_ymd_opt(2024, 3, 1),
+        Some(4.5),
+    )
+    .await;
+    create_reading(
+        app,
+        tehanu.id,
+        ReadingFormat::Physical,
+        NaiveDate::from_ymd_opt(2023, 6, 1),
+        Some(3.0),
+    )
+    .await;
+    create_reading(app, dune.id, ReadingFormat::EReader, None, None).await;
+
+    (earthsea, tehanu, dune)
+}
+
+fn titles<T>(items: &[T], title: impl Fn(&T) -> &str) -> Vec<String> {
+    let mut titles: Vec<String> = items.iter().map(|i| title(i).to_string()).collect();
+    titles.sort();
+    titles
+}
+
+#[tokio::test]
+async fn books_can_be_filtered_by_genre_and_year() {
+    let app = spawn_app_with_auth().await;
+    seed_library(&app).await;
+
+    let books: Vec<BookWithAuthors> = get_json(&app, "/books", "genre:fantasy year>=2000").await;
+
+    assert_eq!(titles(&books, |b| &b.book.title), vec!["Tehanu"]);
+}
+
+#[tokio::test]
+async fn books_can_be_filtered_by_quoted_author() {
+    let app = spawn_app_with_auth().await;
+    seed_library(&app).await;
+
+    let books: Vec<BookWithAuthors> = get_json(&app, "/books", r#"author:"Le Guin""#).await;
+
+    assert_eq!(
+        titles(&books, |b| &b.book.title),
+        vec!["A Wizard of Earthsea", "Tehanu"]
+    );
+}
+
+#[tokio::test]
+async fn negated_terms_exclude_matches() {
+    let app = spawn_app_with_auth().await;
+    seed_library(&app).await;
+
+    let books: Vec<BookWithAuthors> = get_json(&app, "/books", "-genre:fantasy").await;
+
+    assert_eq!(titles(&books, |b| &b.book.title), vec!["Dune"]);
+}
+
+#[tokio::test]
+async fn terms_combine_with_free_text() {
+    let app = spawn_app_with_auth().await;
+    seed_library(&app).await;
+
+    let books: Vec<BookWithAuthors> = get_json(&app, "/books", "genre:fantasy wizard").await;
+
+    assert_eq!(
+        titles(&books, |b| &b.book.title),
+        vec!["A Wizard of Earthsea"]
+    );
+}
+
+#[tokio::test]
+async fn readings_can_be_filtered_by_rating_finished_and_format() {
+    let app = spawn_app_with_auth().await;
+    seed_library(&app).await;
+
+    let readings: Vec<ReadingWithBook> = get_json(
+        &app,
+        "/readings",
+        "rating>=4 finished:2024 format:audiobook",
+    )
+    .await;
+
+    assert_eq!(
+        titles(&readings, |r| &r.book_title),
+        vec!["A Wizard of Earthsea"]
+    );
+}
+
+#[tokio::test]
+async fn readings_can_be_filtered_by_status() {
+    let app = spawn_app_with_auth().await;
+    let (_, _, dune) = seed_library(&app).await;
+
+    let readings: Vec<ReadingWithBook> = get_json(&app, "/readings", "status:reading").await;
+
+    assert_eq!(readings.len(), 1);
+    assert_eq!(readings[0].reading.book_id, dune.id);
+}
+
+#[tokio::test]
+async fn user_books_can_be_filtered_by_reading_fields() {
+    let app = spawn_app_with_auth().await;
+    let (earthsea, tehanu, _) = seed_library(&app).await;
+
+    let user_books: Vec<UserBook> = get_json(&app, "/user-books", "finished<2025 club:no").await;
+
+    let mut book_ids: Vec<BookId> = user_books.iter().map(|ub| ub.book_id).collect();
+    book_ids.sort_by_key(|id| id.into_inner());
+    let mut expected = vec![earthsea.id, tehanu.id];
+    expected.sort_by_key(|id| id.into_inner());
+    assert_eq!(book_ids, expected);
+}
+
+#[tokio::test]
+async fn unknown_fields_return_a_clear_error() {
+    let app = spawn_app_with_auth().await;
+
+    let message = get_error(&app, "/books", "colour:blue").await;
+
+    assert!(
+        message.contains("unknown filter field \"colour\""),
+        "{message}"
+    );
+    assert!(message.contains("genre"), "{message}");
+}
+
+#[tokio::test]
+async fn quoted_text_with_colons_is_searched_literally() {
+    let app = spawn_app_with_auth().await;
+    let herbert = create_author_with_name(&app, "Frank Herbert").await;
+    create_book(&app, "Dune", herbert.id, None, None).await;
+    create_book(&app, "Dune: Messiah", herbert.id, None, None).await;
+
+    let books: Vec<BookWithAuthors> = get_json(&app, "/books", "\"Dune: Messiah\"").await;
+
+    assert_eq!(titles(&books, |b| &b.book.title), vec!["Dune: Messiah"]);
+}
+
+#[tokio::test]
+async fn like_wildcards_in_filter_values_match_literally() {
+    let app = spawn_app_with_auth().await;
+    let author = create_author_with_name(&app, "Author").await;
+    create_book(&app, "Dune", author.id, None, None).await;
+    create_book(&app, "100% Wolf", author.id, None, None).await;
+    create_book(&app, "snake_case", author.id, None, None).await;
+
+    let books: Vec<BookWithAuthors> = get_json(&app, "/books", "title:%").await;
+    assert_eq!(titles(&books, |b| &b.book.title), vec!["100% Wolf"]);
+
+    let books: Vec<BookWithAuthors> = get_json(&app, "/books", "title:_").await;
+    assert_eq!(titles(&books, |b| &b.book.title), vec!["snake_case"]);
+}
+
+#[tokio::test]
+async fn reading_fields_are_rejected_for_books() {
+    let app = spawn_app_with_auth().await;
+
+    let message = get_error(&app, "/books", "rating>=4").await;
+
+    assert!(message.contains("not available for books"), "{message}");
+}
+
+#[tokio::test]
+async fn invalid_values_are_rejected() {
+    let app = spawn_app_with_auth().await;
+
+    let message = get_error(&app, "/readings", "finished:yesterday").await;
+
+    assert!(message.contains("expected a date"), "{message}");
+}
+
+#[tokio::test]
+async fn data_page_shows_filter_errors() {
+    let app = spawn_app_with_auth().await;
+    let session = create_session(&app).await;
+
+    let response = Client::new()
+        .get(format!("{}/data", app.address))
+        .query(&[("type", "library"), ("q", "colour:blue")])
+        .header("Cookie", format!("booklog_session={session}"))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), 200);
+    let body = response.text().await.expect("Failed to read body");
+    assert!(body.contains("unknown filter field"), "{body}");
+}
+
+#[tokio::test]
+async fn data_page_applies_filter_queries() {
+    let app = spawn_app_with_auth().await;
+    seed_library(&app).await;
+    let session = create_session(&app).await;
+
+    let response = Client::new()
+        .get(format!("{}/data", app.address))
+        .query(&[("type", "library"), ("q", "genre:\"science fiction\"")])
+        .header("Cookie", format!("booklog_session={session}"))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), 200);
+    let body = response.text().await.expect("Failed to read body");
+    assert!(body.contains("Dune"));
+    assert!(!body.contains("Tehanu"));
+}
@@ -17,6 +17,7 @@ async fn creating_a_genre_returns_a_201_for_valid_data() {
 
     let new_genre = NewGenre {
         name: "Science Fiction".to_string(),
+        parent_id: None,
         created_at: None,
     };
 
@@ -41,6 +42,7 @@ async fn creating_a_genre_persists_the_data() {
 
     let new_genre = NewGenre {
         name: "Persistent Genre".to_string(),
+        parent_id: None,
         created_at: None,
     };
 
@@ -96,11 +98,13 @@ async fn listing_genres_returns_a_200_with_multiple_genres() {
 
     let genre1 = NewGenre {
         name: "First Genre".to_string(),
+        parent_id: None,
         created_at: None,
     };
 
     let genre2 = NewGenre {
         name: "Second Genre".to_string(),
+        parent_id: None,
         created_at: None,
     };
 
@@ -141,6 +145,7 @@ async fn updating_a_genre_returns_a_200_for_valid_data() {
 
     let update = UpdateGenre {
         name: Some("Updated Genre".to_string()),
+        parent_id: None,
         created_at: None,
     };
 
@@ -167,6 +172,7 @@ async fn updating_a_genre_with_no_changes_returns_a_400() {
 
     let update = UpdateGenre {
         name: None,
+        parent_id: None,
         created_at: None,
     };
 
@@ -188,6 +194,7 @@ async fn updating_a_nonexistent_genre_returns_a_404() {
 
     let update = UpdateGenre {
         name: Some("New Name".to_string()),
+        parent_id: None,
         created_at: None,
     };
 
@@ -234,6 +241,7 @@ async fn creating_a_genre_with_duplicate_name_returns_409() {
 
     let new_genre = NewGenre {
         name: "Duplicate Genre".to_string(),
+        parent_id: None,
         created_at: None,
     };
 
@@ -259,3 +267,156 @@ async fn creating_a_genre_with_duplicate_name_returns_409() {
 
     assert_eq!(response.status(), 409);
 }
+
+async fn create_subgenre(app: &crate::helpers::TestApp, name: &str, parent: &Genre) -> Genre {
+    crate::helpers::create_entity(
+        app,
+        "/genres",
+        &NewGenre {
+            name: name.to_string(),
+            parent_id: Some(parent.id),
+            created_at: None,
+        },
+    )
+    .await
+}
+
+#[tokio::test]
+async fn creating_a_subgenre_records_its_parent() {
+    let app = spawn_app_with_auth().await;
+
+    let parent = create_genre_with_name(&app, "Science Fiction").await;
+    let child = create_subgenre(&app, "Space Opera", &parent).await;
+
+    assert_eq!(parent.parent_id, None);
+    assert_eq!(child.parent_id, Some(parent.id));
+}
+
+#[tokio::test]
+async fn creating_a_genre_with_unknown_parent_returns_400() {
+    let app = spawn_app_with_auth().await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(app.api_url("/genres"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&serde_json::json!({"name": "Orphan", "parent_id": 999_999}))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), 400);
+}
+
+#[tokio::test]
+async fn updating_a_genre_parent_rejects_cycles() {
+    let app = spawn_app_with_auth().await;
+    let client = reqwest::Client::new();
+
+    let root = create_genre_with_name(&app, "Science Fiction").await;
+    let child = create_subgenre(&app, "Space Opera", &root).await;
+    let grandchild = create_subgenre(&app, "Military Space Opera", &child).await;
+
+    for parent in [root.id, grandchild.id] {
+        let response = client
+            .put(app.api_url(&format!("/genres/{}", root.id)))
+            .bearer_auth(app.auth_token.as_ref().unwrap())
+            .json(&serde_json::json!({"parent_id": parent}))
+            .send()
+            .await
+            .expect("Failed to execute request");
+        assert_eq!(response.status(), 400);
+    }
+}
+
+#[tokio::test]
+async fn updating_a_genre_can_move_and_clear_its_parent() {
+    let app = spawn_app_with_auth().await;
+    let client = reqwest::Client::new();
+
+    let fantasy = create_genre_with_name(&app, "Fantasy").await;
+    let science_fiction = create_genre_with_name(&app, "Science Fiction").await;
+    let genre = create_subgenre(&app, "Science Fantasy", &fantasy).await;
+
+    let update = UpdateGenre {
+        parent_id: Some(Some(science_fiction.id)),
+        ..UpdateGenre::default()
+    };
+    let moved: Genre = client
+        .put(app.api_url(&format!("/genres/{}", genre.id)))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&update)
+        .send()
+        .await
+        .expect("Failed to execute request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(moved.parent_id, Some(science_fiction.id));
+
+    // An empty value (as sent by the edit form) moves the genre to the top level
+    let cleared: Genre = client
+        .put(app.api_url(&format!("/genres/{}", genre.id)))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&serde_json::json!({"parent_id": ""}))
+        .send()
+        .await
+        .expect("Failed to execute request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(cleared.parent_id, None);
+    assert_eq!(cleared.name, "Science Fantasy");
+}
+
+#[tokio::test]
+async fn genre_page_lists_books_in_subgenres() {
+    let app = spawn_app_with_auth().await;
+    let session = crate::helpers::create_session(&app).await;
+
+    let author = crate::helpers::create_default_author(&app).await;
+    let root = create_genre_with_name(&app, "Science Fiction").await;
+    let child = create_subgenre(&app, "Space Opera", &root).await;
+    let book: booklog::domain::book_items::Book = crate::helpers::create_entity(
+        &app,
+        "/books",
+        &booklog::domain::book_items::NewBook {
+            title: "Leviathan Wakes".to_string(),
+            authors: vec![booklog::domain::book_items::BookAuthor {
+                author_id: author.id,
+                role: booklog::domain::book_items::AuthorRole::default(),
+            }],
+            isbn: None,
+            description: None,
+            page_count: None,
+            duration_minutes: None,
+            year_published: None,
+            publisher: None,
+            language: None,
+            primary_genre_id: Some(child.id),
+            secondary_genre_id: None,
+            created_at: None,
+        },
+    )
+    .await;
+    let _: booklog::domain::user_books::UserBook = crate::helpers::create_entity(
+        &app,
+        "/user-books",
+        &serde_json::json!({"book_id": book.id}),
+    )
+    .await;
+
+    let body = reqwest::Client::new()
+        .get(format!("{}/genres/{}", app.address, root.id))
+        .header("Cookie", format!("booklog_session={session}"))
+        .send()
+        .await
+        .expect("Failed to execute request")
+        .text()
+        .await
+        .expect("Failed to read body");
+
+    assert!(body.contains("Leviathan Wakes"));
+    assert!(body.contains("Subgenres"));
+    assert!(body.contains("Space Opera"));
+}
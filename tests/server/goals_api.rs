@@ -0,0 +1,331 @@
+use booklog::domain::book_items::{Book, NewBook};
+use booklog::domain::goals::{GoalMetric, GoalPace, GoalProgress};
+use booklog::domain::ids::{AuthorId, GenreId, UserId};
+use booklog::domain::readings::{NewReading, Reading, ReadingFormat, ReadingStatus};
+use chrono::NaiveDate;
+use reqwest::Client;
+use serde_json::json;
+
+use crate::helpers::{
+    TestApp, create_author_with_name, create_entity, create_genre_with_name,
+    create_genre_with_payload, create_non_admin_token, create_session, new_book,
+    spawn_app_with_auth,
+};
+
+async fn create_book(
+    app: &TestApp,
+    title: &str,
+    author_id: AuthorId,
+    pages: i32,
+    genre_id: Option<GenreId>,
+) -> Book {
+    create_entity(
+        app,
+        "/books",
+        &NewBook {
+            page_count: Some(pages),
+            primary_genre_id: genre_id,
+            ..new_book(author_id, title)
+        },
+    )
+    .await
+}
+
+async fn finish(app: &TestApp, book: &Book, format: ReadingFormat, finished_at: NaiveDate) {
+    let _: Reading = create_entity(
+        app,
+        "/readings",
+        &NewReading {
+            user_id: UserId::new(1),
+            book_id: book.id,
+            status: ReadingStatus::Read,
+            format: Some(format),
+            started_at: None,
+            finished_at: Some(finished_at),
+            rating: None,
+            quick_reviews: Vec::new(),
+            private: false,
+            created_at: None,
+        },
+    )
+    .await;
+}
+
+async fn create_goal(app: &TestApp, payload: serde_json::Value) -> reqwest::Response {
+    Client::new()
+        .post(app.api_url("/goals"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&payload)
+        .send()
+        .await
+        .expect("Failed to execute request")
+}
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[tokio::test]
+async fn yearly_goals_count_finished_readings() {
+    let app = spawn_app_with_auth().await;
+    let author = create_author_with_name(&app, "Author").await;
+    let first = create_book(&app, "First", author.id, 200, None).await;
+    let second = create_book(&app, "Second", author.id, 350, None).await;
+    let earlier = create_book(&app, "Earlier", author.id, 500, None).await;
+    finish(&app, &first, ReadingFormat::Physical, date(2025, 2, 1)).await;
+    finish(&app, &second, ReadingFormat::EReader, date(2025, 11, 20)).await;
+    finish(&app, &earlier, ReadingFormat::Physical, date(2024, 12, 31)).await;
+
+    let response = create_goal(&app, json!({"target": 4, "year": 2025})).await;
+    assert_eq!(response.status(), 201);
+    let books: GoalProgress = response.json().await.unwrap();
+    assert_eq!(books.goal.metric, GoalMetric::Books);
+    assert_eq!(books.goal.start_date, date(2025, 1, 1));
+    assert_eq!(books.goal.end_date, date(2025, 12, 31));
+    assert_eq!(books.current, 2);
+    // The period is over, so the whole target was expected
+    assert_eq!(books.expected, 4);
+    assert_eq!(books.ahead_by, -2);
+    assert_eq!(books.pace, GoalPace::Behind);
+
+    let pages: GoalProgress = create_goal(
+        &app,
+        json!({"target": 500, "metric": "pages", "year": 2025}),
+    )
+    .await
+    .json()
+    .await
+    .unwrap();
+    assert_eq!(pages.current, 550);
+    assert_eq!(pages.pace, GoalPace::Complete);
+    assert_eq!(pages.percent, 100);
+
+    let listed: Vec<GoalProgress> = Client::new()
+        .get(app.api_url("/goals"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(listed.len(), 2);
+}
+
+#[tokio::test]
+async fn goals_can_be_limited_to_a_genre_or_format() {
+    let app = spawn_app_with_auth().await;
+    let author = create_author_with_name(&app, "Author").await;
+    let fiction = create_genre_with_name(&app, "Fiction").await;
+    let fantasy = create_genre_with_payload(
+        &app,
+        booklog::domain::genres::NewGenre {
+            name: "Fantasy".to_string(),
+            parent_id: Some(fiction.id),
+            created_at: None,
+        },
+    )
+    .await;
+    let history = create_genre_with_name(&app, "History").await;
+
+    let epic = create_book(&app, "Epic", author.id, 800, Some(fantasy.id)).await;
+    let novel = create_book(&app, "Novel", author.id, 300, Some(fiction.id)).await;
+    let empire = create_book(&app, "Empire", author.id, 400, Some(history.id)).await;
+    finish(&app, &epic, ReadingFormat::Audiobook, date(2025, 6, 10)).await;
+    finish(&app, &novel, ReadingFormat::Physical, date(2025, 7, 1)).await;
+    finish(&app, &empire, ReadingFormat::Audiobook, date(2025, 8, 1)).await;
+
+    let by_genre: GoalProgress = create_goal(
+        &app,
+        json!({
+            "target": 5,
+            "start_date": "2025-06-01",
+            "end_date": "2025-08-31",
+            "genre_id": fiction.id,
+        }),
+    )
+    .await
+    .json()
+    .await
+    .unwrap();
+    // Subgenres count towards their parent
+    assert_eq!(by_genre.current, 2);
+
+    let by_format: GoalProgress = create_goal(
+        &app,
+        json!({"target": 5, "year": 2025, "format": "audiobook"}),
+    )
+    .await
+    .json()
+    .await
+    .unwrap();
+    assert_eq!(by_format.goal.format, Some(ReadingFormat::Audiobook));
+    assert_eq!(by_format.current, 2);
+}
+
+#[tokio::test]
+async fn invalid_goals_are_rejected() {
+    let app = spawn_app_with_auth().await;
+
+    for payload in [
+        json!({"target": 0, "year": 2025}),
+        json!({"year": 2025}),
+        json!({"target": 10, "year": 2025, "start_date": "2025-01-01"}),
+        json!({"target": 10, "start_date": "2025-06-01"}),
+        json!({"target": 10, "start_date": "2025-06-01", "end_date": "2025-05-01"}),
+        json!({"target": 10, "metric": "minutes"}),
+        json!({"target": 10, "format": "scroll"}),
+        json!({"target": 10, "genre_id": 999}),
+    ] {
+        let response = create_goal(&app, payload.clone()).await;
+        assert_eq!(response.status(), 400, "payload: {payload}");
+    }
+}
+
+#[tokio::test]
+async fn goals_without_a_period_cover_the_current_year() {
+    let app = spawn_app_with_auth().await;
+    let response = create_goal(&app, json!({"target": 12})).await;
+    assert_eq!(response.status(), 201);
+
+    let goal: GoalProgress = response.json().await.unwrap();
+    let today = chrono::Utc::now().date_naive();
+    assert_eq!(
+        goal.goal.calendar_year(),
+        Some(chrono::Datelike::year(&today))
+    );
+}
+
+#[tokio::test]
+async fn updating_a_goal_can_clear_constraints() {
+    let app = spawn_app_with_auth().await;
+    let genre = create_genre_with_name(&app, "Poetry").await;
+    let goal: GoalProgress = create_goal(
+        &app,
+        json!({"target": 10, "year": 2025, "genre_id": genre.id, "format": "ereader"}),
+    )
+    .await
+    .json()
+    .await
+    .unwrap();
+    let client = Client::new();
+
+    let response = client
+        .put(app.api_url(&format!("/goals/{}", goal.goal.id)))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&json!({"target": 20, "genre_id": null, "format": ""}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    let updated: GoalProgress = response.json().await.unwrap();
+    assert_eq!(updated.goal.target, 20);
+    assert_eq!(updated.goal.genre_id, None);
+    assert_eq!(updated.goal.format, None);
+
+    let response = client
+        .put(app.api_url(&format!("/goals/{}", goal.goal.id)))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&json!({"end_date": "2024-06-01"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 400);
+
+    let response = client
+        .delete(app.api_url(&format!("/goals/{}", goal.goal.id)))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 204);
+
+    let response = client
+        .get(app.api_url(&format!("/goals/{}", goal.goal.id)))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn other_users_goals_are_not_found() {
+    let app = spawn_app_with_auth().await;
+    let goal: GoalProgress = create_goal(&app, json!({"target": 10, "year": 2025}))
+        .await
+        .json()
+        .await
+        .unwrap();
+    let other_token = create_non_admin_token(&app).await;
+    let client = Client::new();
+
+    let get = client
+        .get(app.api_url(&format!("/goals/{}", goal.goal.id)))
+        .bearer_auth(&other_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(get.status(), 404);
+
+    let delete = client
+        .delete(app.api_url(&format!("/goals/{}", goal.goal.id)))
+        .bearer_auth(&other_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(delete.status(), 404);
+
+    let listed: Vec<GoalProgress> = client
+        .get(app.api_url("/goals"))
+        .bearer_auth(&other_token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert!(listed.is_empty());
+}
+
+#[tokio::test]
+async fn goals_appear_on_home_and_stats_pages() {
+    let app = spawn_app_with_auth().await;
+    create_goal(&app, json!({"target": 24})).await;
+    create_goal(
+        &app,
+        json!({"target": 5000, "metric": "pages", "year": 2020}),
+    )
+    .await;
+
+    let session = create_session(&app).await;
+    let client = Client::new();
+    let year = chrono::Datelike::year(&chrono::Utc::now().date_naive());
+
+    let home = client
+        .get(format!("{}/", app.address))
+        .header("Cookie", format!("booklog_session={session}"))
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(home.contains(&format!("Books in {year}")));
+    assert!(home.contains("0 / 24 books"));
+    // Only goals running today are shown on the home page
+    assert!(!home.contains("Pages in 2020"));
+
+    let stats = client
+        .get(format!("{}/stats", app.address))
+        .header("Cookie", format!("booklog_session={session}"))
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(stats.contains(&format!("Books in {year}")));
+    assert!(stats.contains("Pages in 2020"));
+    assert!(stats.contains("Behind schedule by 5000 pages"));
+}
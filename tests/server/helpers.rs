@@ -69,16 +69,13 @@ pub async fn spawn_app() -> TestApp {
 }
 
 fn test_state_config() -> AppStateConfig {
-    let (stats_tx, _stats_rx) = tokio::sync::mpsc::channel(1);
-    let (timeline_tx, _timeline_rx) = tokio::sync::mpsc::channel(1);
     AppStateConfig {
         webauthn: test_webauthn(),
         insecure_cookies: true,
         openrouter_url: booklog::infrastructure::ai::OPENROUTER_URL.to_string(),
         openrouter_api_key: String::new(),
         openrouter_model: "openrouter/free".to_string(),
-        stats_invalidator: booklog::application::services::StatsInvalidator::new(stats_tx),
-        timeline_invalidator: booklog::application::services::TimelineInvalidator::new(timeline_tx),
+        allow_private_network: true,
     }
 }
 
@@ -220,6 +217,7 @@ pub async fn create_author_with_name(
         app,
         booklog::domain::authors::NewAuthor {
             name: name.to_string(),
+            details: Default::default(),
             created_at: None,
         },
     )
@@ -242,65 +240,51 @@ pub async fn create_genre_with_name(app: &TestApp, name: &str) -> booklog::domai
         app,
         booklog::domain::genres::NewGenre {
             name: name.to_string(),
+            parent_id: None,
             created_at: None,
         },
     )
     .await
 }
 
+/// A `NewBook` by one author with every optional field empty; override
+/// fields with struct update syntax.
+pub fn new_book(
+    author_id: booklog::domain::ids::AuthorId,
+    title: &str,
+) -> booklog::domain::book_items::NewBook {
+    booklog::domain::book_items::NewBook {
+        title: title.to_string(),
+        authors: vec![booklog::domain::book_items::BookAuthor {
+            author_id,
+            role: booklog::domain::book_items::AuthorRole::default(),
+        }],
+        isbn: None,
+        description: None,
+        page_count: None,
+        duration_minutes: None,
+        year_published: None,
+        publisher: None,
+        language: None,
+        primary_genre_id: None,
+        secondary_genre_id: None,
+        created_at: None,
+    }
+}
+
 pub async fn create_book_with_title(
     app: &TestApp,
     author_id: booklog::domain::ids::AuthorId,
     title: &str,
 ) -> booklog::domain::book_items::Book {
-    create_entity(
-        app,
-        "/books",
-        &booklog::domain::book_items::NewBook {
-            title: title.to_string(),
-            authors: vec![booklog::domain::book_items::BookAuthor {
-                author_id,
-                role: booklog::domain::book_items::AuthorRole::default(),
-            }],
-            isbn: None,
-            description: None,
-            page_count: None,
-            year_published: None,
-            publisher: None,
-            language: None,
-            primary_genre_id: None,
-            secondary_genre_id: None,
-            created_at: None,
-        },
-    )
-    .await
+    create_entity(app, "/books", &new_book(author_id, title)).await
 }
 
 pub async fn create_default_book(
     app: &TestApp,
     author_id: booklog::domain::ids::AuthorId,
 ) -> booklog::domain::book_items::Book {
-    create_entity(
-        app,
-        "/books",
-        &booklog::domain::book_items::NewBook {
-            title: "Test Book".to_string(),
-            authors: vec![booklog::domain::book_items::BookAuthor {
-                author_id,
-                role: booklog::domain::book_items::AuthorRole::default(),
-            }],
-            isbn: None,
-            description: None,
-            page_count: None,
-            year_published: None,
-            publisher: None,
-            language: None,
-            primary_genre_id: None,
-            secondary_genre_id: None,
-            created_at: None,
-        },
-    )
-    .await
+    create_book_with_title(app, author_id, "Test Book").await
 }
 
 pub async fn create_default_reading(
@@ -319,6 +303,7 @@ pub async fn create_default_reading(
             finished_at: None,
             rating: None,
             quick_reviews: Vec::new(),
+            private: false,
             created_at: None,
         },
     )
@@ -543,40 +528,37 @@ pub async fn create_non_admin_token(app: &TestApp) -> String {
     token_value
 }
 
-/// Spawn an authenticated test app with the timeline rebuild background task running.
-/// Uses a 50ms debounce so tests can verify timeline refreshes without long waits.
+/// Spawn an authenticated test app with the background job worker running.
+/// Polls every 50ms so tests can verify timeline refreshes without long waits.
 pub async fn spawn_app_with_timeline_sync() -> TestApp {
+    spawn_app_with_timeline_sync_and_config(test_state_config()).await
+}
+
+/// Like [`spawn_app_with_timeline_sync`], but outgoing requests to loopback
+/// and private addresses are refused, as in production.
+pub async fn spawn_app_without_private_network() -> TestApp {
+    spawn_app_with_timeline_sync_and_config(AppStateConfig {
+        allow_private_network: false,
+        ..test_state_config()
+    })
+    .await
+}
+
+async fn spawn_app_with_timeline_sync_and_config(config: AppStateConfig) -> TestApp {
     let database = booklog::infrastructure::database::Database::connect("sqlite::memory:")
         .await
         .expect("Failed to connect to in-memory database");
 
-    let (stats_tx, _stats_rx) = tokio::sync::mpsc::channel(1);
-    let (timeline_tx, timeline_rx) = tokio::sync::mpsc::channel(32);
-
-    let config = AppStateConfig {
-        webauthn: test_webauthn(),
-        insecure_cookies: true,
-        openrouter_url: booklog::infrastructure::ai::OPENROUTER_URL.to_string(),
-        openrouter_api_key: String::new(),
-        openrouter_model: "openrouter/free".to_string(),
-        stats_invalidator: booklog::application::services::StatsInvalidator::new(stats_tx),
-        timeline_invalidator: booklog::application::services::TimelineInvalidator::new(timeline_tx),
-    };
-
     let pool = database.clone_pool();
     let state = AppState::from_database(&database, config);
 
-    // Spawn timeline rebuild task with short debounce for tests
+    // Spawn the job worker with a short poll interval for tests
     tokio::spawn(
-        booklog::application::services::timeline_refresh::timeline_rebuild_task(
-            timeline_rx,
-            Arc::clone(&state.author_repo),
-            Arc::clone(&state.book_repo),
-            Arc::clone(&state.genre_repo),
-            Arc::clone(&state.reading_repo),
-            Arc::clone(&state.timeline_repo),
+        booklog::application::services::JobWorker::new(
+            &state,
             std::time::Duration::from_millis(50),
-        ),
+        )
+        .run(),
     );
 
     let author_repo = state.author_repo.clone();
@@ -2,10 +2,11 @@ use booklog::domain::authors::Author;
 
 use crate::helpers::{
     assert_datastar_headers, assert_html_fragment, create_default_author, spawn_app_with_auth,
+    spawn_app_with_timeline_sync,
 };
 
 /// Generate a minimal valid 1x1 red PNG as a base64 data URL.
-fn tiny_png_data_url() -> String {
+pub(crate) fn tiny_png_data_url() -> String {
     use base64::Engine;
     use image::{ImageBuffer, Rgba};
 
@@ -392,13 +393,30 @@ async fn delete_image_with_datastar_returns_fragment() {
     assert_html_fragment(&body);
 }
 
+/// Staged uploads left once the worker is done with them, waiting briefly
+/// for it to get there.
+async fn uploads_left(app: &crate::helpers::TestApp) -> i64 {
+    let mut uploads = 0;
+    for _ in 0..40 {
+        uploads = sqlx::query_scalar("SELECT COUNT(*) FROM image_uploads")
+            .fetch_one(&app.pool)
+            .await
+            .unwrap();
+        if uploads == 0 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+    uploads
+}
+
 // ===========================================================================
 // Deferred image via create forms
 // ===========================================================================
 
 #[tokio::test]
 async fn create_author_with_image_saves_image() {
-    let app = spawn_app_with_auth().await;
+    let app = spawn_app_with_timeline_sync().await;
     let client = reqwest::Client::new();
 
     let payload = serde_json::json!({
@@ -417,14 +435,75 @@ async fn create_author_with_image_saves_image() {
     assert_eq!(response.status(), 201);
     let author: Author = response.json().await.expect("failed to parse author");
 
-    // Image should be retrievable
-    let img_response = client
-        .get(app.api_url(&image_url("author", author.id)))
+    // The background worker processes the image shortly after
+    let mut status = reqwest::StatusCode::NOT_FOUND;
+    for _ in 0..40 {
+        status = client
+            .get(app.api_url(&image_url("author", author.id)))
+            .send()
+            .await
+            .expect("failed to get image")
+            .status();
+        if status == reqwest::StatusCode::OK {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+
+    assert_eq!(status, 200);
+    assert_eq!(uploads_left(&app).await, 0, "processed uploads are removed");
+}
+
+#[tokio::test]
+async fn deferred_images_wait_outside_the_job_payload() {
+    let app = spawn_app_with_auth().await;
+    let response = reqwest::Client::new()
+        .post(app.api_url("/authors"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&serde_json::json!({ "name": "Image Author", "image": tiny_png_data_url() }))
         .send()
         .await
-        .expect("failed to get image");
+        .expect("failed to create author");
+    assert_eq!(response.status(), 201);
 
-    assert_eq!(img_response.status(), 200);
+    let payload: String =
+        sqlx::query_scalar("SELECT payload FROM jobs WHERE kind = 'process_image'")
+            .fetch_one(&app.pool)
+            .await
+            .unwrap();
+    assert!(!payload.contains("base64"), "{payload}");
+    let uploads: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM image_uploads")
+        .fetch_one(&app.pool)
+        .await
+        .unwrap();
+    assert_eq!(uploads, 1);
+}
+
+#[tokio::test]
+async fn image_jobs_out_of_attempts_drop_their_upload() {
+    let app = spawn_app_with_timeline_sync().await;
+    sqlx::query(
+        "INSERT INTO image_uploads (id, data_url) VALUES (7, 'data:image/png;base64,bm9wZQ==')",
+    )
+    .execute(&app.pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        r#"INSERT INTO jobs (kind, payload, attempts, max_attempts, run_at)
+           VALUES ('process_image',
+                   '{"kind":"process_image","entity_type":"author","entity_id":1,"upload_id":7}',
+                   0, 1, '2000-01-01T00:00:00Z')"#,
+    )
+    .execute(&app.pool)
+    .await
+    .unwrap();
+
+    assert_eq!(uploads_left(&app).await, 0);
+    let status: String = sqlx::query_scalar("SELECT status FROM jobs WHERE kind = 'process_image'")
+        .fetch_one(&app.pool)
+        .await
+        .unwrap();
+    assert_eq!(status, "failed");
 }
 
 #[tokio::test]
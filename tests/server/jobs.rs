@@ -0,0 +1,199 @@
+use booklog::domain::jobs::{JobStatus, NewJob};
+use booklog::domain::repositories::JobRepository;
+use booklog::infrastructure::repositories::jobs::SqlJobRepository;
+use chrono::Utc;
+use reqwest::redirect::Policy;
+
+use crate::helpers::{create_session, spawn_app_with_auth, spawn_app_with_timeline_sync};
+
+fn new_job(dedupe_key: &str) -> NewJob {
+    NewJob::new(
+        "refresh_timeline",
+        r#"{"kind":"refresh_timeline","entity_type":"book","entity_id":1}"#,
+        Some(dedupe_key.to_string()),
+        Utc::now(),
+    )
+}
+
+#[tokio::test]
+async fn pending_jobs_dedupe_and_failures_retry_before_failing() {
+    let app = spawn_app_with_auth().await;
+    let repo = SqlJobRepository::new(app.pool.clone());
+
+    assert!(repo.enqueue(new_job("timeline:book:1")).await.unwrap());
+    assert!(
+        !repo.enqueue(new_job("timeline:book:1")).await.unwrap(),
+        "a pending job with the same key should absorb the duplicate"
+    );
+
+    let claimed = repo.claim_due(Utc::now(), 10).await.unwrap();
+    assert_eq!(claimed.len(), 1);
+    let job = &claimed[0];
+    assert_eq!(job.status, JobStatus::Running);
+    assert_eq!(job.attempts, 1);
+
+    // A new signal while the job runs is queued rather than lost
+    assert!(repo.enqueue(new_job("timeline:book:1")).await.unwrap());
+
+    // Retrying is then redundant; the pending duplicate covers it
+    repo.retry(job, Utc::now(), "boom").await.unwrap();
+    let health = repo.health(Utc::now(), 10).await.unwrap();
+    assert_eq!((health.pending, health.running), (1, 0));
+
+    let job = repo.claim_due(Utc::now(), 10).await.unwrap().remove(0);
+    repo.retry(&job, Utc::now() + chrono::Duration::minutes(5), "boom")
+        .await
+        .unwrap();
+    assert!(
+        repo.claim_due(Utc::now(), 10).await.unwrap().is_empty(),
+        "retries wait for their backoff"
+    );
+    let health = repo.health(Utc::now(), 10).await.unwrap();
+    assert_eq!((health.pending, health.overdue), (1, 0));
+
+    let job = repo
+        .claim_due(Utc::now() + chrono::Duration::minutes(6), 10)
+        .await
+        .unwrap()
+        .remove(0);
+    assert_eq!(job.attempts, 2);
+    repo.fail(job.id, "still broken").await.unwrap();
+
+    let health = repo.health(Utc::now(), 10).await.unwrap();
+    assert_eq!((health.pending, health.failed), (0, 1));
+    assert_eq!(
+        health.recent_failures[0].last_error.as_deref(),
+        Some("still broken")
+    );
+}
+
+#[tokio::test]
+async fn jobs_wait_while_a_duplicate_is_running() {
+    let app = spawn_app_with_auth().await;
+    let repo = SqlJobRepository::new(app.pool.clone());
+
+    repo.enqueue(new_job("timeline:book:1")).await.unwrap();
+    let job = repo.claim_due(Utc::now(), 10).await.unwrap().remove(0);
+    repo.enqueue(new_job("timeline:book:1")).await.unwrap();
+    repo.enqueue(new_job("timeline:book:2")).await.unwrap();
+
+    let claimed = repo.claim_due(Utc::now(), 10).await.unwrap();
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(claimed[0].dedupe_key.as_deref(), Some("timeline:book:2"));
+
+    repo.complete(job.id).await.unwrap();
+    let claimed = repo.claim_due(Utc::now(), 10).await.unwrap();
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(claimed[0].dedupe_key.as_deref(), Some("timeline:book:1"));
+}
+
+#[tokio::test]
+async fn interrupted_jobs_are_requeued() {
+    let app = spawn_app_with_auth().await;
+    let repo = SqlJobRepository::new(app.pool.clone());
+
+    repo.enqueue(new_job("timeline:book:1")).await.unwrap();
+    repo.enqueue(new_job("timeline:book:2")).await.unwrap();
+    repo.claim_due(Utc::now(), 10).await.unwrap();
+    repo.enqueue(new_job("timeline:book:2")).await.unwrap();
+
+    // Book 2 already has a pending job, so only book 1 goes back
+    assert_eq!(repo.requeue_running().await.unwrap(), 1);
+    let health = repo.health(Utc::now(), 10).await.unwrap();
+    assert_eq!((health.pending, health.running), (2, 0));
+}
+
+#[tokio::test]
+async fn admin_page_shows_queue_health_and_failures() {
+    let app = spawn_app_with_auth().await;
+    let repo = SqlJobRepository::new(app.pool.clone());
+    repo.enqueue(new_job("timeline:book:1")).await.unwrap();
+    let job = repo.claim_due(Utc::now(), 10).await.unwrap().remove(0);
+    repo.fail(job.id, "book 1 not found").await.unwrap();
+    repo.enqueue(new_job("timeline:book:2")).await.unwrap();
+
+    let session_token = create_session(&app).await;
+    let client = reqwest::Client::builder()
+        .redirect(Policy::none())
+        .build()
+        .unwrap();
+    let body = client
+        .get(app.page_url("/admin"))
+        .header("Cookie", format!("booklog_session={session_token}"))
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+
+    assert!(body.contains("Background Jobs"));
+    assert!(body.contains("refresh timeline"));
+    assert!(body.contains("book 1 not found"));
+    assert!(body.contains("after 1 attempt") && !body.contains("after 1 attempts"));
+}
+
+fn refresh_job(entity_type: &str, entity_id: i64) -> NewJob {
+    NewJob::new(
+        "refresh_timeline",
+        format!(
+            r#"{{"kind":"refresh_timeline","entity_type":"{entity_type}","entity_id":{entity_id}}}"#
+        ),
+        Some(format!("timeline:{entity_type}:{entity_id}")),
+        Utc::now(),
+    )
+}
+
+/// Poll the jobs table until `query` returns a row.
+async fn wait_for_jobs(
+    app: &crate::helpers::TestApp,
+    query: &'static str,
+) -> Vec<(String, Option<String>)> {
+    tokio::time::timeout(std::time::Duration::from_secs(5), async {
+        loop {
+            let rows: Vec<(String, Option<String>)> =
+                sqlx::query_as(query).fetch_all(&app.pool).await.unwrap();
+            if !rows.is_empty() {
+                return rows;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(25)).await;
+        }
+    })
+    .await
+    .expect("background jobs did not settle")
+}
+
+#[tokio::test]
+async fn timeline_refresh_failures_are_retried() {
+    let app = spawn_app_with_timeline_sync().await;
+    let repo = SqlJobRepository::new(app.pool.clone());
+    repo.enqueue(refresh_job("shelf", 1)).await.unwrap();
+
+    let rows = wait_for_jobs(
+        &app,
+        "SELECT status, last_error FROM jobs \
+         WHERE kind = 'refresh_timeline' AND attempts > 0 AND status != 'running'",
+    )
+    .await;
+    assert_eq!(rows[0].0, "pending");
+    assert!(
+        rows[0]
+            .1
+            .as_deref()
+            .is_some_and(|error| error.contains("unknown entity type")),
+        "{rows:?}"
+    );
+}
+
+#[tokio::test]
+async fn timeline_refreshes_for_deleted_entities_complete() {
+    let app = spawn_app_with_timeline_sync().await;
+    let repo = SqlJobRepository::new(app.pool.clone());
+    repo.enqueue(refresh_job("book", 999)).await.unwrap();
+
+    wait_for_jobs(
+        &app,
+        "SELECT 'done', NULL WHERE NOT EXISTS (SELECT 1 FROM jobs WHERE kind = 'refresh_timeline')",
+    )
+    .await;
+}
@@ -0,0 +1,159 @@
+use reqwest::{Client, Response};
+use tokio::time::{Duration, timeout};
+
+use crate::helpers::{
+    TestApp, create_author_with_name, create_genre_with_name, spawn_app, spawn_app_with_auth,
+};
+
+async fn open_stream(app: &TestApp, page: &str, last_event_id: Option<&str>) -> Response {
+    let mut request = Client::new().get(app.page_url(&format!("/events?page={page}")));
+    if let Some(id) = last_event_id {
+        request = request.header("Last-Event-ID", id);
+    }
+    let response = request.send().await.expect("failed to open event stream");
+    assert_eq!(response.status(), 200);
+    assert!(
+        response.headers()["content-type"]
+            .to_str()
+            .unwrap()
+            .starts_with("text/event-stream")
+    );
+    response
+}
+
+/// Read the stream until `needle` appears, returning everything read.
+async fn read_until(response: &mut Response, needle: &str) -> String {
+    let mut body = String::new();
+    let found = timeout(Duration::from_secs(5), async {
+        while !body.contains(needle) {
+            let chunk = response
+                .chunk()
+                .await
+                .expect("stream failed")
+                .expect("stream ended");
+            body.push_str(&String::from_utf8_lossy(&chunk));
+        }
+    })
+    .await;
+    assert!(found.is_ok(), "timed out waiting for {needle:?} in {body}");
+    body
+}
+
+fn last_event_id(body: &str) -> &str {
+    body.lines()
+        .filter_map(|line| line.strip_prefix("id:"))
+        .map(str::trim)
+        .last()
+        .expect("no event id in stream")
+}
+
+#[tokio::test]
+async fn timeline_stream_patches_in_new_events() {
+    let app = spawn_app_with_auth().await;
+    let mut stream = open_stream(&app, "timeline", None).await;
+
+    create_author_with_name(&app, "Ursula Le Guin").await;
+
+    let body = read_until(&mut stream, "Ursula Le Guin").await;
+    assert!(body.contains("event: datastar-patch-elements"));
+    assert!(body.contains("data: selector #timeline-live"));
+    assert!(body.contains("data: mode prepend"));
+    assert!(body.contains("data: elements <div class=\"timeline-item"));
+}
+
+#[tokio::test]
+async fn reconnecting_with_last_event_id_replays_missed_events() {
+    let app = spawn_app_with_auth().await;
+    let mut stream = open_stream(&app, "timeline", None).await;
+    create_author_with_name(&app, "Octavia Butler").await;
+    let body = read_until(&mut stream, "Octavia Butler").await;
+    let seen = last_event_id(&body).to_string();
+    drop(stream);
+
+    create_author_with_name(&app, "Iain Banks").await;
+
+    let mut stream = open_stream(&app, "timeline", Some(&seen)).await;
+    let body = read_until(&mut stream, "Iain Banks").await;
+    assert!(
+        !body.contains("Octavia Butler"),
+        "events already seen shouldn't be replayed"
+    );
+}
+
+#[tokio::test]
+async fn reconnecting_after_missed_events_expire_asks_for_a_reload() {
+    let app = spawn_app().await;
+    let mut stream = open_stream(&app, "timeline", Some("1")).await;
+
+    let body = read_until(&mut stream, "Reload").await;
+    assert!(body.contains("data: selector #timeline-live-notice"));
+    assert!(body.contains("You may have missed some activity"));
+}
+
+#[tokio::test]
+async fn home_stream_re_renders_recent_activity() {
+    let app = spawn_app_with_auth().await;
+    let mut stream = open_stream(&app, "home", None).await;
+
+    create_author_with_name(&app, "Ted Chiang").await;
+
+    let body = read_until(&mut stream, "Ted Chiang").await;
+    assert!(body.contains("data: selector #home-recent-activity"));
+    assert!(body.contains("data: mode outer"));
+}
+
+#[tokio::test]
+async fn timeline_page_only_streams_its_first_newest_page() {
+    let app = spawn_app().await;
+    let client = Client::new();
+
+    let body = client
+        .get(app.page_url("/timeline"))
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(body.contains("/events?page=timeline'"));
+
+    let body = client
+        .get(app.page_url("/timeline?type=book"))
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(body.contains("/events?page=timeline&#38;type=book'"));
+
+    for query in ["?page=2", "?dir=asc"] {
+        let body = client
+            .get(app.page_url(&format!("/timeline{query}")))
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+        assert!(
+            !body.contains("/events?page=timeline"),
+            "{query} shouldn't stream new events"
+        );
+    }
+}
+
+#[tokio::test]
+async fn filtered_timeline_streams_only_matching_events() {
+    let app = spawn_app_with_auth().await;
+    let mut stream = open_stream(&app, "timeline&type=genre", None).await;
+
+    create_author_with_name(&app, "Ursula Le Guin").await;
+    create_genre_with_name(&app, "Anarchist Utopias").await;
+
+    let body = read_until(&mut stream, "Anarchist Utopias").await;
+    assert!(
+        !body.contains("Ursula Le Guin"),
+        "author events don't match the genre filter"
+    );
+}
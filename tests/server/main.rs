@@ -2,16 +2,29 @@ pub mod auth_api;
 pub mod authors_api;
 pub mod backup;
 pub mod books_api;
+pub mod challenges_api;
 pub mod datastar;
+pub mod export;
 pub mod extraction_api;
+pub mod federation;
+pub mod feeds;
+pub mod filter_queries;
 pub mod form_submissions;
 pub mod genres_api;
+pub mod goals_api;
 pub mod helpers;
 pub mod images_api;
+pub mod jobs;
+pub mod live_events;
 pub mod pages;
 pub mod pagination;
+pub mod profiles;
 pub mod readings_api;
+pub mod shelves_api;
 pub mod stats_api;
+pub mod tags_api;
 pub mod test_macros;
 pub mod timeline;
 pub mod user_books_api;
+pub mod webhooks;
+pub mod widgets;
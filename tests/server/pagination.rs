@@ -1,4 +1,4 @@
-use booklog::domain::authors::{AuthorSortKey, NewAuthor};
+use booklog::domain::authors::{AuthorDetails, AuthorSortKey, NewAuthor};
 use booklog::domain::listing::{ListRequest, PageSize, SortDirection, SortKey};
 
 use crate::helpers::{create_author_with_name, spawn_app, spawn_app_with_auth};
@@ -91,6 +91,34 @@ async fn listing_with_search_no_match_returns_empty() {
     assert_eq!(page.total, 0);
 }
 
+#[tokio::test]
+async fn listing_with_search_matches_aliases_but_not_their_json() {
+    let app = spawn_app_with_auth().await;
+    app.author_repo
+        .insert(NewAuthor {
+            name: "George Orwell".to_string(),
+            details: AuthorDetails {
+                aliases: vec!["Eric Arthur Blair".to_string(), "P.S. Burton".to_string()],
+                ..Default::default()
+            },
+            created_at: None,
+        })
+        .await
+        .unwrap();
+    create_author_with_name(&app, "Alice Walker").await;
+
+    let sort_key = AuthorSortKey::default();
+    let request = ListRequest::show_all(sort_key, sort_key.default_direction());
+    let page = app.author_repo.list(&request, Some("blair")).await.unwrap();
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].name, "George Orwell");
+
+    for term in ["\"", "\",\"", "[", "%", "_"] {
+        let page = app.author_repo.list(&request, Some(term)).await.unwrap();
+        assert!(page.items.is_empty(), "{term:?} matched {:?}", page.items);
+    }
+}
+
 #[tokio::test]
 async fn listing_with_sort_direction_changes_order() {
     let app = spawn_app_with_auth().await;
@@ -98,6 +126,7 @@ async fn listing_with_sort_direction_changes_order() {
     app.author_repo
         .insert(NewAuthor {
             name: "First".to_string(),
+            details: Default::default(),
             created_at: Some(chrono::Utc::now() - chrono::Duration::hours(2)),
         })
         .await
@@ -105,6 +134,7 @@ async fn listing_with_sort_direction_changes_order() {
     app.author_repo
         .insert(NewAuthor {
             name: "Second".to_string(),
+            details: Default::default(),
             created_at: Some(chrono::Utc::now() - chrono::Duration::hours(1)),
         })
         .await
@@ -112,6 +142,7 @@ async fn listing_with_sort_direction_changes_order() {
     app.author_repo
         .insert(NewAuthor {
             name: "Third".to_string(),
+            details: Default::default(),
             created_at: Some(chrono::Utc::now()),
         })
         .await
@@ -0,0 +1,148 @@
+use booklog::domain::readings::Reading;
+use booklog::domain::timeline::TimelineEvent;
+use reqwest::{Client, StatusCode};
+use serde_json::json;
+
+use crate::helpers::{
+    TestApp, create_author_with_name, create_book_with_title, create_entity,
+    create_non_admin_token, put_form, spawn_app_with_auth,
+};
+
+async fn create_reading(app: &TestApp, title: &str, status: &str, private: bool) -> Reading {
+    let author = create_author_with_name(app, &format!("{title} Author")).await;
+    let book = create_book_with_title(app, author.id, title).await;
+    create_entity(
+        app,
+        "/readings",
+        &json!({
+            "user_id": 1,
+            "book_id": book.id,
+            "status": status,
+            "rating": 4.5,
+            "private": private,
+        }),
+    )
+    .await
+}
+
+async fn get_profile(app: &TestApp, username: &str) -> reqwest::Response {
+    Client::new()
+        .get(app.page_url(&format!("/u/{username}")))
+        .send()
+        .await
+        .expect("failed to fetch profile")
+}
+
+async fn list_timeline(app: &TestApp, token: Option<&str>) -> Vec<TimelineEvent> {
+    let mut request = Client::new().get(app.api_url("/timeline"));
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+    let response = request.send().await.expect("failed to list timeline");
+    assert_eq!(response.status(), StatusCode::OK);
+    response
+        .json()
+        .await
+        .expect("failed to parse timeline events")
+}
+
+#[tokio::test]
+async fn profiles_are_hidden_until_made_public() {
+    let app = spawn_app_with_auth().await;
+    create_reading(&app, "Dune", "reading", false).await;
+
+    assert_eq!(
+        get_profile(&app, "admin").await.status(),
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(
+        get_profile(&app, "nobody").await.status(),
+        StatusCode::NOT_FOUND
+    );
+
+    put_form(&app, "/profile/visibility", &[("public", "true")]).await;
+    let response = get_profile(&app, "admin").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.text().await.unwrap();
+    assert!(body.contains("Currently Reading"));
+    assert!(body.contains("Dune"));
+}
+
+#[tokio::test]
+async fn public_profiles_leave_out_private_readings() {
+    let app = spawn_app_with_auth().await;
+    create_reading(&app, "Foundation", "read", false).await;
+    create_reading(&app, "My Secret Diary", "read", true).await;
+    put_form(&app, "/profile/visibility", &[("public", "true")]).await;
+
+    let body = get_profile(&app, "admin").await.text().await.unwrap();
+    assert!(body.contains("Recently Finished"));
+    assert!(body.contains("Foundation"));
+    assert!(!body.contains("My Secret Diary"));
+}
+
+#[tokio::test]
+async fn privacy_settings_hide_ratings_from_profiles() {
+    let app = spawn_app_with_auth().await;
+    create_reading(&app, "Hyperion", "read", false).await;
+    put_form(&app, "/profile/visibility", &[("public", "true")]).await;
+
+    let body = get_profile(&app, "admin").await.text().await.unwrap();
+    assert!(body.contains("Average rating"));
+
+    let response = put_form(&app, "/profile/privacy", &[("show_ratings", "false")]).await;
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+
+    let body = get_profile(&app, "admin").await.text().await.unwrap();
+    assert!(body.contains("Hyperion"));
+    assert!(!body.contains("Average rating"));
+}
+
+#[tokio::test]
+async fn public_shelves_appear_on_profiles() {
+    let app = spawn_app_with_auth().await;
+    put_form(&app, "/profile/visibility", &[("public", "true")]).await;
+    create_entity::<_, serde_json::Value>(
+        &app,
+        "/shelves",
+        &json!({ "name": "Favourites", "public": true }),
+    )
+    .await;
+    create_entity::<_, serde_json::Value>(&app, "/shelves", &json!({ "name": "Guilty Pleasures" }))
+        .await;
+
+    let body = get_profile(&app, "admin").await.text().await.unwrap();
+    assert!(body.contains("Favourites"));
+    assert!(!body.contains("Guilty Pleasures"));
+}
+
+#[tokio::test]
+async fn private_readings_only_reach_their_owners_timeline() {
+    let app = spawn_app_with_auth().await;
+    let reading = create_reading(&app, "Secret History", "reading", true).await;
+
+    let is_private_event = |event: &TimelineEvent| {
+        event.entity_type == "reading" && event.entity_id == i64::from(reading.id)
+    };
+    let owner = list_timeline(&app, app.auth_token.as_deref()).await;
+    assert!(owner.iter().any(is_private_event));
+
+    let other_token = create_non_admin_token(&app).await;
+    let other = list_timeline(&app, Some(&other_token)).await;
+    assert!(!other.iter().any(is_private_event));
+
+    let anonymous = list_timeline(&app, None).await;
+    assert!(!anonymous.iter().any(is_private_event));
+
+    // Making the reading public again brings its events back
+    let response = Client::new()
+        .put(app.api_url(&format!("/readings/{}", reading.id)))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&json!({ "private": false }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let other = list_timeline(&app, Some(&other_token)).await;
+    assert!(other.iter().any(is_private_event));
+}
@@ -24,6 +24,7 @@ async fn creating_a_reading_returns_a_201_for_valid_data() {
         finished_at: None,
         rating: None,
         quick_reviews: Vec::new(),
+        private: false,
         created_at: None,
     };
 
@@ -80,6 +81,7 @@ async fn listing_readings_returns_200_and_correct_data() {
         finished_at: None,
         rating: None,
         quick_reviews: Vec::new(),
+        private: false,
         created_at: None,
     };
 
@@ -275,6 +277,7 @@ async fn creating_a_reading_with_valid_rating_succeeds() {
             finished_at: None,
             rating: Some(valid_rating),
             quick_reviews: Vec::new(),
+            private: false,
             created_at: None,
         };
 
@@ -393,6 +396,7 @@ async fn listing_readings_filtered_by_book_id_returns_only_matching() {
             isbn: None,
             description: None,
             page_count: None,
+            duration_minutes: None,
             year_published: None,
             publisher: None,
             language: None,
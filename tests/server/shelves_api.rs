@@ -0,0 +1,463 @@
+use booklog::domain::book_items::{Book, NewBook};
+use booklog::domain::ids::{AuthorId, BookId, UserId};
+use booklog::domain::readings::{NewReading, Reading, ReadingFormat, ReadingStatus};
+use booklog::domain::shelves::{SavedShelf, SavedShelfWithBooks, ShelfKind};
+use booklog::domain::user_books::UserBook;
+use reqwest::Client;
+use serde_json::json;
+
+use crate::helpers::{
+    TestApp, create_author_with_name, create_entity, create_non_admin_token, create_session,
+    new_book, spawn_app_with_auth,
+};
+
+async fn create_book(app: &TestApp, title: &str, author_id: AuthorId, pages: i32) -> Book {
+    create_entity(
+        app,
+        "/books",
+        &NewBook {
+            page_count: Some(pages),
+            ..new_book(author_id, title)
+        },
+    )
+    .await
+}
+
+async fn shelve(app: &TestApp, book_id: BookId, shelf: &str) -> UserBook {
+    create_entity(
+        app,
+        "/user-books",
+        &json!({"book_id": book_id, "shelf": shelf}),
+    )
+    .await
+}
+
+async fn create_shelf(app: &TestApp, payload: serde_json::Value) -> reqwest::Response {
+    Client::new()
+        .post(app.api_url("/shelves"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&payload)
+        .send()
+        .await
+        .expect("Failed to execute request")
+}
+
+async fn get_shelf(app: &TestApp, id: impl std::fmt::Display) -> SavedShelfWithBooks {
+    Client::new()
+        .get(app.api_url(&format!("/shelves/{id}")))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to execute request")
+        .json()
+        .await
+        .expect("Failed to parse shelf")
+}
+
+async fn add_book(
+    app: &TestApp,
+    shelf_id: impl std::fmt::Display,
+    book_id: BookId,
+) -> reqwest::Response {
+    Client::new()
+        .post(app.api_url(&format!("/shelves/{shelf_id}/books")))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&json!({"book_id": book_id}))
+        .send()
+        .await
+        .expect("Failed to execute request")
+}
+
+fn titles(shelf: &SavedShelfWithBooks) -> Vec<&str> {
+    shelf.books.iter().map(|b| b.book.title.as_str()).collect()
+}
+
+#[tokio::test]
+async fn creating_a_manual_shelf_returns_201() {
+    let app = spawn_app_with_auth().await;
+
+    let response = create_shelf(
+        &app,
+        json!({"name": "  Comfort reads ", "description": "For rainy days"}),
+    )
+    .await;
+    assert_eq!(response.status(), 201);
+
+    let shelf: SavedShelf = response.json().await.unwrap();
+    assert_eq!(shelf.name, "Comfort reads");
+    assert_eq!(shelf.kind, ShelfKind::Manual);
+    assert_eq!(shelf.query, None);
+    assert!(!shelf.pinned);
+
+    let shelves: Vec<SavedShelf> = Client::new()
+        .get(app.api_url("/shelves"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(shelves.len(), 1);
+}
+
+#[tokio::test]
+async fn duplicate_shelf_names_conflict() {
+    let app = spawn_app_with_auth().await;
+
+    create_shelf(&app, json!({"name": "Favourites"})).await;
+    let response = create_shelf(&app, json!({"name": "Favourites"})).await;
+
+    assert_eq!(response.status(), 409);
+}
+
+#[tokio::test]
+async fn manual_shelves_keep_books_in_order() {
+    let app = spawn_app_with_auth().await;
+    let author = create_author_with_name(&app, "Ursula K. Le Guin").await;
+    let first = create_book(&app, "A Wizard of Earthsea", author.id, 200).await;
+    let second = create_book(&app, "The Tombs of Atuan", author.id, 180).await;
+    let third = create_book(&app, "The Farthest Shore", author.id, 220).await;
+
+    let shelf: SavedShelf = create_shelf(&app, json!({"name": "Earthsea"}))
+        .await
+        .json()
+        .await
+        .unwrap();
+    for book in [&first, &second, &third] {
+        assert_eq!(add_book(&app, shelf.id, book.id).await.status(), 204);
+    }
+
+    let loaded = get_shelf(&app, shelf.id).await;
+    assert_eq!(
+        titles(&loaded),
+        vec![
+            "A Wizard of Earthsea",
+            "The Tombs of Atuan",
+            "The Farthest Shore"
+        ]
+    );
+
+    let response = Client::new()
+        .put(app.api_url(&format!("/shelves/{}/books/{}", shelf.id, third.id)))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&json!({"position": 0}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 204);
+
+    let loaded = get_shelf(&app, shelf.id).await;
+    assert_eq!(
+        titles(&loaded),
+        vec![
+            "The Farthest Shore",
+            "A Wizard of Earthsea",
+            "The Tombs of Atuan"
+        ]
+    );
+
+    let response = Client::new()
+        .delete(app.api_url(&format!("/shelves/{}/books/{}", shelf.id, first.id)))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 204);
+
+    let loaded = get_shelf(&app, shelf.id).await;
+    assert_eq!(
+        titles(&loaded),
+        vec!["The Farthest Shore", "The Tombs of Atuan"]
+    );
+}
+
+#[tokio::test]
+async fn adding_the_same_book_twice_conflicts() {
+    let app = spawn_app_with_auth().await;
+    let author = create_author_with_name(&app, "Author").await;
+    let book = create_book(&app, "Once Only", author.id, 100).await;
+    let shelf: SavedShelf = create_shelf(&app, json!({"name": "Singles"}))
+        .await
+        .json()
+        .await
+        .unwrap();
+
+    add_book(&app, shelf.id, book.id).await;
+    let response = add_book(&app, shelf.id, book.id).await;
+
+    assert_eq!(response.status(), 409);
+}
+
+#[tokio::test]
+async fn smart_shelves_evaluate_their_query() {
+    let app = spawn_app_with_auth().await;
+    let author = create_author_with_name(&app, "Author").await;
+    let short = create_book(&app, "Short Unread", author.id, 180).await;
+    let long = create_book(&app, "Long Unread", author.id, 640).await;
+    let wished = create_book(&app, "Short Wishlist", author.id, 120).await;
+    let finished = create_book(&app, "Short Finished", author.id, 150).await;
+
+    shelve(&app, short.id, "library").await;
+    shelve(&app, long.id, "library").await;
+    shelve(&app, wished.id, "wishlist").await;
+    let _: Reading = create_entity(
+        &app,
+        "/readings",
+        &NewReading {
+            user_id: UserId::new(1),
+            book_id: finished.id,
+            status: ReadingStatus::Read,
+            format: Some(ReadingFormat::Physical),
+            started_at: None,
+            finished_at: Some(chrono::NaiveDate::from_ymd_opt(2025, 1, 10).unwrap()),
+            rating: None,
+            quick_reviews: Vec::new(),
+            private: false,
+            created_at: None,
+        },
+    )
+    .await;
+
+    let response = create_shelf(
+        &app,
+        json!({
+            "name": "Quick wins",
+            "kind": "smart",
+            "query": "shelf:library -status:read pages<300",
+        }),
+    )
+    .await;
+    assert_eq!(response.status(), 201);
+    let shelf: SavedShelf = response.json().await.unwrap();
+    assert_eq!(shelf.kind, ShelfKind::Smart);
+
+    let loaded = get_shelf(&app, shelf.id).await;
+    assert_eq!(titles(&loaded), vec!["Short Unread"]);
+
+    // Smart shelves are filled by their query, not by hand
+    let response = add_book(&app, shelf.id, long.id).await;
+    assert_eq!(response.status(), 400);
+}
+
+#[tokio::test]
+async fn smart_shelves_reject_invalid_queries() {
+    let app = spawn_app_with_auth().await;
+
+    let missing = create_shelf(&app, json!({"name": "Empty", "kind": "smart"})).await;
+    assert_eq!(missing.status(), 400);
+
+    let invalid = create_shelf(
+        &app,
+        json!({"name": "Broken", "kind": "smart", "query": "pages>=lots"}),
+    )
+    .await;
+    assert_eq!(invalid.status(), 400);
+
+    let manual: SavedShelf = create_shelf(&app, json!({"name": "Manual"}))
+        .await
+        .json()
+        .await
+        .unwrap();
+    let response = Client::new()
+        .put(app.api_url(&format!("/shelves/{}", manual.id)))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&json!({"query": "pages<300"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 400);
+}
+
+#[tokio::test]
+async fn updating_a_shelf_changes_fields() {
+    let app = spawn_app_with_auth().await;
+    let shelf: SavedShelf = create_shelf(
+        &app,
+        json!({"name": "Smart", "kind": "smart", "query": "pages<300"}),
+    )
+    .await
+    .json()
+    .await
+    .unwrap();
+
+    let response = Client::new()
+        .put(app.api_url(&format!("/shelves/{}", shelf.id)))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&json!({"name": "Shorter", "query": "pages<200", "pinned": true}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+
+    let updated: SavedShelf = response.json().await.unwrap();
+    assert_eq!(updated.name, "Shorter");
+    assert_eq!(updated.query.as_deref(), Some("pages<200"));
+    assert!(updated.pinned);
+}
+
+#[tokio::test]
+async fn other_users_shelves_are_not_found() {
+    let app = spawn_app_with_auth().await;
+    let shelf: SavedShelf = create_shelf(&app, json!({"name": "Private"}))
+        .await
+        .json()
+        .await
+        .unwrap();
+    let other_token = create_non_admin_token(&app).await;
+    let client = Client::new();
+
+    let get = client
+        .get(app.api_url(&format!("/shelves/{}", shelf.id)))
+        .bearer_auth(&other_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(get.status(), 404);
+
+    let delete = client
+        .delete(app.api_url(&format!("/shelves/{}", shelf.id)))
+        .bearer_auth(&other_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(delete.status(), 404);
+
+    let listed: Vec<SavedShelf> = client
+        .get(app.api_url("/shelves"))
+        .bearer_auth(&other_token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert!(listed.is_empty());
+}
+
+#[tokio::test]
+async fn deleting_a_shelf_returns_204() {
+    let app = spawn_app_with_auth().await;
+    let shelf: SavedShelf = create_shelf(&app, json!({"name": "Temporary"}))
+        .await
+        .json()
+        .await
+        .unwrap();
+    let client = Client::new();
+
+    let response = client
+        .delete(app.api_url(&format!("/shelves/{}", shelf.id)))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 204);
+
+    let response = client
+        .get(app.api_url(&format!("/shelves/{}", shelf.id)))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn pinned_shelves_appear_on_home_and_book_pages() {
+    let app = spawn_app_with_auth().await;
+    let author = create_author_with_name(&app, "Author").await;
+    let book = create_book(&app, "Pinned Favourite", author.id, 250).await;
+    let shelf: SavedShelf = create_shelf(&app, json!({"name": "Desert Island", "pinned": true}))
+        .await
+        .json()
+        .await
+        .unwrap();
+    add_book(&app, shelf.id, book.id).await;
+    create_shelf(&app, json!({"name": "Not Pinned Here"})).await;
+
+    let session = create_session(&app).await;
+    let client = Client::new();
+
+    let home = client
+        .get(format!("{}/", app.address))
+        .header("Cookie", format!("booklog_session={session}"))
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(home.contains("Desert Island"));
+    assert!(home.contains("Pinned Favourite"));
+    assert!(!home.contains("Not Pinned Here"));
+
+    let book_page = client
+        .get(format!("{}/books/{}", app.address, book.id))
+        .header("Cookie", format!("booklog_session={session}"))
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(book_page.contains(&format!("/shelves/{}", shelf.id)));
+    // The other manual shelf is offered as an add-to-shelf option
+    assert!(book_page.contains("Not Pinned Here"));
+
+    let shelf_page = client
+        .get(format!("{}/shelves/{}", app.address, shelf.id))
+        .header("Cookie", format!("booklog_session={session}"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(shelf_page.status(), 200);
+    assert!(
+        shelf_page
+            .text()
+            .await
+            .unwrap()
+            .contains("Pinned Favourite")
+    );
+}
+
+#[tokio::test]
+async fn a_broken_smart_shelf_does_not_hide_the_others_on_book_pages() {
+    let app = spawn_app_with_auth().await;
+    let author = create_author_with_name(&app, "Author").await;
+    let book = create_book(&app, "Short Read", author.id, 150).await;
+    shelve(&app, book.id, "library").await;
+    let working: SavedShelf = create_shelf(
+        &app,
+        json!({"name": "Short Ones", "kind": "smart", "query": "pages<300"}),
+    )
+    .await
+    .json()
+    .await
+    .unwrap();
+    let broken: SavedShelf = create_shelf(
+        &app,
+        json!({"name": "Broken", "kind": "smart", "query": "pages<400"}),
+    )
+    .await
+    .json()
+    .await
+    .unwrap();
+    // Saved before a change to the filter syntax, say
+    sqlx::query("UPDATE shelves SET query = 'pages>=lots' WHERE id = ?")
+        .bind(broken.id.into_inner())
+        .execute(&app.pool)
+        .await
+        .unwrap();
+
+    let session = create_session(&app).await;
+    let response = Client::new()
+        .get(format!("{}/books/{}", app.address, book.id))
+        .header("Cookie", format!("booklog_session={session}"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    let book_page = response.text().await.unwrap();
+    assert!(book_page.contains(&format!("/shelves/{}", working.id)));
+    assert!(!book_page.contains(&format!("/shelves/{}\"", broken.id)));
+}
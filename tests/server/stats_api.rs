@@ -2,7 +2,8 @@ use reqwest::Client;
 
 use crate::helpers::{
     assert_full_page, create_author_with_name, create_default_author, create_default_book,
-    create_entity, create_genre_with_name, create_session, spawn_app, spawn_app_with_auth,
+    create_entity, create_genre_with_name, create_non_admin_token, create_session, spawn_app,
+    spawn_app_with_auth,
 };
 
 #[tokio::test]
@@ -139,6 +140,7 @@ async fn recompute_stats_includes_extended_fields() {
         "/authors",
         &booklog::domain::authors::NewAuthor {
             name: "Jane Austen".to_string(),
+            details: Default::default(),
             created_at: None,
         },
     )
@@ -161,6 +163,7 @@ async fn recompute_stats_includes_extended_fields() {
             isbn: None,
             description: None,
             page_count: Some(432),
+            duration_minutes: None,
             year_published: Some(1813),
             publisher: Some("T. Egerton".to_string()),
             language: Some("English".to_string()),
@@ -184,6 +187,7 @@ async fn recompute_stats_includes_extended_fields() {
             finished_at: Some(chrono::NaiveDate::from_ymd_opt(2026, 1, 15).unwrap()),
             rating: Some(4.0),
             quick_reviews: Vec::new(),
+            private: false,
             created_at: None,
         },
     )
@@ -264,6 +268,7 @@ async fn stats_page_shows_new_sections_with_data() {
             isbn: None,
             description: None,
             page_count: Some(300),
+            duration_minutes: None,
             year_published: Some(2020),
             publisher: None,
             language: None,
@@ -286,6 +291,7 @@ async fn stats_page_shows_new_sections_with_data() {
             finished_at: Some(chrono::NaiveDate::from_ymd_opt(2026, 1, 10).unwrap()),
             rating: Some(5.0),
             quick_reviews: Vec::new(),
+            private: false,
             created_at: None,
         },
     )
@@ -343,6 +349,7 @@ async fn stats_page_with_year_filter_returns_200() {
             isbn: None,
             description: None,
             page_count: Some(250),
+            duration_minutes: None,
             year_published: Some(2020),
             publisher: None,
             language: None,
@@ -365,6 +372,7 @@ async fn stats_page_with_year_filter_returns_200() {
             finished_at: Some(chrono::NaiveDate::from_ymd_opt(2026, 1, 15).unwrap()),
             rating: Some(4.5),
             quick_reviews: Vec::new(),
+            private: false,
             created_at: None,
         },
     )
@@ -423,6 +431,7 @@ async fn stats_page_shows_year_tabs_when_data_exists() {
             isbn: None,
             description: None,
             page_count: Some(200),
+            duration_minutes: None,
             year_published: None,
             publisher: None,
             language: None,
@@ -445,6 +454,7 @@ async fn stats_page_shows_year_tabs_when_data_exists() {
             finished_at: Some(chrono::NaiveDate::from_ymd_opt(2026, 6, 1).unwrap()),
             rating: None,
             quick_reviews: Vec::new(),
+            private: false,
             created_at: None,
         },
     )
@@ -507,6 +517,7 @@ async fn stats_with_books_missing_page_count_has_no_extremes() {
             finished_at: Some(chrono::NaiveDate::from_ymd_opt(2026, 2, 1).unwrap()),
             rating: None,
             quick_reviews: Vec::new(),
+            private: false,
             created_at: None,
         },
     )
@@ -554,6 +565,7 @@ async fn stats_with_books_missing_genres_has_empty_genre_counts() {
             isbn: None,
             description: None,
             page_count: Some(200),
+            duration_minutes: None,
             year_published: None,
             publisher: None,
             language: None,
@@ -576,6 +588,7 @@ async fn stats_with_books_missing_genres_has_empty_genre_counts() {
             finished_at: Some(chrono::NaiveDate::from_ymd_opt(2026, 2, 1).unwrap()),
             rating: None,
             quick_reviews: Vec::new(),
+            private: false,
             created_at: None,
         },
     )
@@ -617,6 +630,7 @@ async fn stats_counts_abandoned_readings() {
             isbn: None,
             description: None,
             page_count: None,
+            duration_minutes: None,
             year_published: None,
             publisher: None,
             language: None,
@@ -639,6 +653,7 @@ async fn stats_counts_abandoned_readings() {
             isbn: None,
             description: None,
             page_count: None,
+            duration_minutes: None,
             year_published: None,
             publisher: None,
             language: None,
@@ -662,6 +677,7 @@ async fn stats_counts_abandoned_readings() {
             finished_at: None,
             rating: None,
             quick_reviews: Vec::new(),
+            private: false,
             created_at: None,
         },
     )
@@ -680,6 +696,7 @@ async fn stats_counts_abandoned_readings() {
             finished_at: Some(chrono::NaiveDate::from_ymd_opt(2026, 2, 1).unwrap()),
             rating: None,
             quick_reviews: Vec::new(),
+            private: false,
             created_at: None,
         },
     )
@@ -724,6 +741,7 @@ async fn stats_computes_averages_across_multiple_readings() {
             isbn: None,
             description: None,
             page_count: Some(100),
+            duration_minutes: None,
             year_published: None,
             publisher: None,
             language: None,
@@ -746,6 +764,7 @@ async fn stats_computes_averages_across_multiple_readings() {
             isbn: None,
             description: None,
             page_count: Some(500),
+            duration_minutes: None,
             year_published: None,
             publisher: None,
             language: None,
@@ -769,6 +788,7 @@ async fn stats_computes_averages_across_multiple_readings() {
             finished_at: Some(chrono::NaiveDate::from_ymd_opt(2026, 1, 11).unwrap()),
             rating: Some(3.0),
             quick_reviews: Vec::new(),
+            private: false,
             created_at: None,
         },
     )
@@ -787,6 +807,7 @@ async fn stats_computes_averages_across_multiple_readings() {
             finished_at: Some(chrono::NaiveDate::from_ymd_opt(2026, 1, 21).unwrap()),
             rating: Some(5.0),
             quick_reviews: Vec::new(),
+            private: false,
             created_at: None,
         },
     )
@@ -841,6 +862,104 @@ async fn stats_computes_averages_across_multiple_readings() {
     assert_eq!(shortest[1], 100);
 }
 
+#[tokio::test]
+async fn stats_count_audiobooks_as_listening_time() {
+    let app = spawn_app_with_auth().await;
+    let author = create_default_author(&app).await;
+
+    for (title, pages, minutes, format) in [
+        (
+            "Printed",
+            300,
+            None,
+            booklog::domain::readings::ReadingFormat::Physical,
+        ),
+        (
+            "Narrated",
+            400,
+            Some(600),
+            booklog::domain::readings::ReadingFormat::Audiobook,
+        ),
+    ] {
+        let book = create_entity::<_, booklog::domain::book_items::Book>(
+            &app,
+            "/books",
+            &booklog::domain::book_items::NewBook {
+                title: title.to_string(),
+                authors: vec![booklog::domain::book_items::BookAuthor {
+                    author_id: author.id,
+                    role: booklog::domain::book_items::AuthorRole::Author,
+                }],
+                isbn: None,
+                description: None,
+                page_count: Some(pages),
+                duration_minutes: minutes,
+                year_published: None,
+                publisher: None,
+                language: None,
+                primary_genre_id: None,
+                secondary_genre_id: None,
+                created_at: None,
+            },
+        )
+        .await;
+        let _reading = create_entity::<_, booklog::domain::readings::Reading>(
+            &app,
+            "/readings",
+            &booklog::domain::readings::NewReading {
+                user_id: booklog::domain::ids::UserId::new(1),
+                book_id: book.id,
+                status: booklog::domain::readings::ReadingStatus::Read,
+                format: Some(format),
+                started_at: Some(chrono::NaiveDate::from_ymd_opt(2025, 2, 1).unwrap()),
+                finished_at: Some(chrono::NaiveDate::from_ymd_opt(2025, 2, 11).unwrap()),
+                rating: None,
+                quick_reviews: Vec::new(),
+                private: false,
+                created_at: None,
+            },
+        )
+        .await;
+    }
+
+    let client = Client::new();
+    let body: serde_json::Value = client
+        .post(app.api_url("/stats/recompute"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let reading = &body["reading"];
+
+    assert_eq!(reading["books_all_time"], 2);
+    // The audiobook's pages aren't counted as read
+    assert_eq!(reading["pages_all_time"], 300);
+    let listening = &reading["listening"];
+    assert_eq!(listening["audiobooks"], 1);
+    assert_eq!(listening["minutes"], 600);
+    assert_eq!(listening["paced_days"], 10.0);
+    assert_eq!(
+        listening["yearly_minutes"],
+        serde_json::json!([["2025", 600]])
+    );
+
+    let session_token = create_session(&app).await;
+    let html = client
+        .get(app.page_url("/stats?year=2025"))
+        .header("Cookie", format!("booklog_session={session_token}"))
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(html.contains("Listening"));
+    assert!(html.contains("10h"));
+}
+
 #[tokio::test]
 async fn stats_year_scope_excludes_other_years() {
     let app = spawn_app_with_auth().await;
@@ -859,6 +978,7 @@ async fn stats_year_scope_excludes_other_years() {
             isbn: None,
             description: None,
             page_count: Some(300),
+            duration_minutes: None,
             year_published: None,
             publisher: None,
             language: None,
@@ -881,6 +1001,7 @@ async fn stats_year_scope_excludes_other_years() {
             isbn: None,
             description: None,
             page_count: Some(200),
+            duration_minutes: None,
             year_published: None,
             publisher: None,
             language: None,
@@ -904,6 +1025,7 @@ async fn stats_year_scope_excludes_other_years() {
             finished_at: Some(chrono::NaiveDate::from_ymd_opt(2025, 12, 15).unwrap()),
             rating: Some(3.0),
             quick_reviews: Vec::new(),
+            private: false,
             created_at: None,
         },
     )
@@ -922,6 +1044,7 @@ async fn stats_year_scope_excludes_other_years() {
             finished_at: Some(chrono::NaiveDate::from_ymd_opt(2026, 1, 10).unwrap()),
             rating: Some(5.0),
             quick_reviews: Vec::new(),
+            private: false,
             created_at: None,
         },
     )
@@ -967,6 +1090,7 @@ async fn stats_top_authors_ranks_by_reading_count() {
             isbn: None,
             description: None,
             page_count: None,
+            duration_minutes: None,
             year_published: None,
             publisher: None,
             language: None,
@@ -989,6 +1113,7 @@ async fn stats_top_authors_ranks_by_reading_count() {
             isbn: None,
             description: None,
             page_count: None,
+            duration_minutes: None,
             year_published: None,
             publisher: None,
             language: None,
@@ -1011,6 +1136,7 @@ async fn stats_top_authors_ranks_by_reading_count() {
             isbn: None,
             description: None,
             page_count: None,
+            duration_minutes: None,
             year_published: None,
             publisher: None,
             language: None,
@@ -1034,6 +1160,7 @@ async fn stats_top_authors_ranks_by_reading_count() {
                 finished_at: Some(chrono::NaiveDate::from_ymd_opt(2026, 2, 1).unwrap()),
                 rating: None,
                 quick_reviews: Vec::new(),
+                private: false,
                 created_at: None,
             },
         )
@@ -1058,3 +1185,667 @@ async fn stats_top_authors_ranks_by_reading_count() {
     assert_eq!(top_authors[0][0], "Alice Author");
     assert_eq!(top_authors[0][1], 2);
 }
+
+#[tokio::test]
+async fn stats_roll_subgenres_up_into_parent_genres() {
+    let app = spawn_app_with_auth().await;
+
+    let author = create_entity::<_, booklog::domain::authors::Author>(
+        &app,
+        "/authors",
+        &booklog::domain::authors::NewAuthor {
+            name: "James S. A. Corey".to_string(),
+            details: Default::default(),
+            created_at: None,
+        },
+    )
+    .await;
+
+    let science_fiction = create_genre_with_name(&app, "Science Fiction").await;
+    let space_opera = create_entity::<_, booklog::domain::genres::Genre>(
+        &app,
+        "/genres",
+        &booklog::domain::genres::NewGenre {
+            name: "Space Opera".to_string(),
+            parent_id: Some(science_fiction.id),
+            created_at: None,
+        },
+    )
+    .await;
+    let fantasy = create_genre_with_name(&app, "Fantasy").await;
+
+    // The first book sits in both the subgenre and its parent; it must only count once.
+    for (title, primary, secondary) in [
+        ("Leviathan Wakes", space_opera.id, Some(science_fiction.id)),
+        ("A Wizard of Earthsea", fantasy.id, None),
+    ] {
+        let book = create_entity::<_, booklog::domain::book_items::Book>(
+            &app,
+            "/books",
+            &booklog::domain::book_items::NewBook {
+                title: title.to_string(),
+                authors: vec![booklog::domain::book_items::BookAuthor {
+                    author_id: author.id,
+                    role: booklog::domain::book_items::AuthorRole::Author,
+                }],
+                isbn: None,
+                description: None,
+                page_count: None,
+                duration_minutes: None,
+                year_published: None,
+                publisher: None,
+                language: None,
+                primary_genre_id: Some(primary),
+                secondary_genre_id: secondary,
+                created_at: None,
+            },
+        )
+        .await;
+        let _: booklog::domain::user_books::UserBook = create_entity(
+            &app,
+            "/user-books",
+            &serde_json::json!({"book_id": book.id}),
+        )
+        .await;
+    }
+
+    let response = Client::new()
+        .post(app.api_url("/stats/recompute"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), 200);
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+    let book_summary = &body["book_summary"];
+
+    assert_eq!(
+        book_summary["genre_counts"],
+        serde_json::json!([["Fantasy", 1], ["Science Fiction", 1]])
+    );
+    assert_eq!(book_summary["unique_genres"], 3);
+    assert_eq!(
+        book_summary["subgenre_counts"],
+        serde_json::json!([{
+            "genre": "Science Fiction",
+            "counts": [["Space Opera", 1]],
+            "max_count": 1
+        }])
+    );
+}
+
+async fn create_dated_reading(
+    app: &crate::helpers::TestApp,
+    title: &str,
+    started: (u32, u32),
+    finished: (u32, u32),
+) {
+    let author = create_author_with_name(app, &format!("{title} Author")).await;
+    let book = create_entity::<_, booklog::domain::book_items::Book>(
+        app,
+        "/books",
+        &booklog::domain::book_items::NewBook {
+            title: title.to_string(),
+            authors: vec![booklog::domain::book_items::BookAuthor {
+                author_id: author.id,
+                role: booklog::domain::book_items::AuthorRole::Author,
+            }],
+            isbn: None,
+            description: None,
+            page_count: Some(250),
+            duration_minutes: None,
+            year_published: None,
+            publisher: None,
+            language: None,
+            primary_genre_id: None,
+            secondary_genre_id: None,
+            created_at: None,
+        },
+    )
+    .await;
+    let date = |(m, d)| chrono::NaiveDate::from_ymd_opt(2025, m, d);
+    let _reading = create_entity::<_, booklog::domain::readings::Reading>(
+        app,
+        "/readings",
+        &booklog::domain::readings::NewReading {
+            user_id: booklog::domain::ids::UserId::new(1),
+            book_id: book.id,
+            status: booklog::domain::readings::ReadingStatus::Read,
+            format: None,
+            started_at: date(started),
+            finished_at: date(finished),
+            rating: None,
+            quick_reviews: Vec::new(),
+            private: false,
+            created_at: None,
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn activity_returns_days_and_streaks() {
+    let app = spawn_app_with_auth().await;
+    create_dated_reading(&app, "First", (3, 1), (3, 3)).await;
+    create_dated_reading(&app, "Second", (3, 3), (3, 4)).await;
+
+    let activity: booklog::domain::activity::ReadingActivity = Client::new()
+        .get(app.api_url("/stats/activity?from=2025-03-01&to=2025-03-07"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to execute request")
+        .json()
+        .await
+        .expect("Failed to parse activity");
+
+    assert_eq!(activity.days.len(), 7);
+    assert_eq!(activity.active_days, 4);
+    assert_eq!(activity.books_finished, 2);
+    let overlap = &activity.days[2];
+    assert_eq!(
+        (overlap.started, overlap.finished, overlap.in_progress),
+        (1, 1, 2)
+    );
+    assert!(!activity.days[5].is_active());
+    assert_eq!(activity.streaks.longest, 4);
+    assert_eq!(activity.streaks.current, 0);
+}
+
+#[tokio::test]
+async fn activity_rejects_invalid_ranges() {
+    let app = spawn_app_with_auth().await;
+    let client = Client::new();
+
+    let response = client
+        .get(app.api_url("/stats/activity"))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), 401);
+
+    for query in [
+        "from=2025-03-07&to=2025-03-01",
+        "from=2000-01-01&to=2025-01-01",
+        "from=March",
+    ] {
+        let response = client
+            .get(app.api_url(&format!("/stats/activity?{query}")))
+            .bearer_auth(app.auth_token.as_ref().unwrap())
+            .send()
+            .await
+            .expect("Failed to execute request");
+        assert_eq!(response.status(), 400, "query: {query}");
+    }
+
+    let response = client
+        .get(app.api_url("/stats/activity"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["days"].as_array().unwrap().len(), 365);
+}
+
+#[tokio::test]
+async fn stats_page_year_view_shows_heatmap() {
+    let app = spawn_app_with_auth().await;
+    create_dated_reading(&app, "Heatmap Book", (3, 1), (3, 3)).await;
+    let session_token = create_session(&app).await;
+
+    let body = Client::new()
+        .get(app.page_url("/stats?year=2025"))
+        .header("Cookie", format!("booklog_session={session_token}"))
+        .send()
+        .await
+        .expect("Failed to load stats page")
+        .text()
+        .await
+        .expect("Failed to read body");
+
+    assert!(body.contains("Daily Activity"));
+    assert!(body.contains("3 active days"));
+    assert!(body.contains("Mar 2, 2025 · 1 in progress"));
+}
+
+#[tokio::test]
+async fn stats_range_compares_two_periods() {
+    let app = spawn_app_with_auth().await;
+    create_dated_reading(&app, "Winter One", (1, 20), (2, 10)).await;
+    create_dated_reading(&app, "Winter Two", (3, 1), (3, 4)).await;
+    create_dated_reading(&app, "Summer", (6, 1), (6, 5)).await;
+
+    let report: booklog::domain::stats::StatsReport = Client::new()
+        .get(app.api_url(
+            "/stats?from=2025-01-01&to=2025-03-31&compare_from=2025-04-01&compare_to=2025-06-30",
+        ))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to execute request")
+        .json()
+        .await
+        .expect("Failed to parse stats report");
+
+    assert_eq!(report.current.reading.books_all_time, 2);
+    assert_eq!(report.current.reading.pages_all_time, 500);
+    assert_eq!(report.current.book_summary.total_books, 2);
+    let months: Vec<_> = report.current.reading.monthly_books.iter().collect();
+    assert_eq!(
+        months,
+        [
+            &("Jan".to_string(), 0),
+            &("Feb".to_string(), 1),
+            &("Mar".to_string(), 1)
+        ]
+    );
+
+    let compare = report.compare.expect("compare period");
+    assert_eq!(compare.reading.books_all_time, 1);
+    let delta = report.delta.expect("delta");
+    assert_eq!((delta.books, delta.pages), (1, 250));
+    assert_eq!(delta.average_rating, None);
+}
+
+#[tokio::test]
+async fn stats_range_rejects_invalid_params() {
+    let app = spawn_app_with_auth().await;
+    let client = Client::new();
+
+    let response = client
+        .get(app.api_url("/stats"))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), 401);
+
+    for query in [
+        "from=2025-03-07&to=2025-03-01",
+        "from=2000-01-01&to=2025-01-01",
+        "from=2025-01-01&to=2025-03-31&compare_from=2024-01-01",
+        "from=2025-01-01&to=2025-03-31&compare_from=2024-03-31&compare_to=2024-01-01",
+    ] {
+        let response = client
+            .get(app.api_url(&format!("/stats?{query}")))
+            .bearer_auth(app.auth_token.as_ref().unwrap())
+            .send()
+            .await
+            .expect("Failed to execute request");
+        assert_eq!(response.status(), 400, "query: {query}");
+    }
+
+    let body: serde_json::Value = client
+        .get(app.api_url("/stats"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to execute request")
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(
+        body["current"]["reading"]["monthly_books"]
+            .as_array()
+            .unwrap()
+            .len(),
+        13
+    );
+    assert!(body.get("compare").is_none());
+}
+
+#[tokio::test]
+async fn stats_page_shows_range_comparison() {
+    let app = spawn_app_with_auth().await;
+    create_dated_reading(&app, "Spring Book", (3, 1), (3, 4)).await;
+    let session_token = create_session(&app).await;
+
+    let body = Client::new()
+        .get(app.page_url(
+            "/stats?from=2025-01-01&to=2025-03-31&compare_from=2024-01-01&compare_to=2024-03-31",
+        ))
+        .header("Cookie", format!("booklog_session={session_token}"))
+        .send()
+        .await
+        .expect("Failed to load stats page")
+        .text()
+        .await
+        .expect("Failed to read body");
+
+    assert_full_page(&body);
+    assert!(body.contains("Showing readings finished Jan 1 – Mar 31, 2025"));
+    assert!(body.contains("Jan 1 – Mar 31, 2025 vs Jan 1 – Mar 31, 2024"));
+    assert!(body.contains("+250"));
+    assert!(body.contains(r#"value="2024-01-01""#));
+}
+
+#[tokio::test]
+async fn incremental_stats_match_full_rebuild() {
+    use booklog::application::services::stats::{
+        StatsMetrics, compute_all_stats, rebuild_stats, refresh_stats_years,
+    };
+    use booklog::domain::ids::UserId;
+    use booklog::domain::repositories::StatsRepository;
+    use booklog::infrastructure::repositories::stats::SqlStatsRepository;
+
+    let app = spawn_app_with_auth().await;
+    let repo = SqlStatsRepository::new(app.pool.clone());
+    let metrics = StatsMetrics::default();
+    let user_id = UserId::new(1);
+
+    create_dated_reading(&app, "First", (1, 5), (1, 20)).await;
+    create_dated_reading(&app, "Second", (3, 1), (3, 9)).await;
+    rebuild_stats(&repo, user_id, &metrics)
+        .await
+        .expect("full rebuild failed");
+
+    // A new reading and one moved back a year touch 2024 and 2025 only
+    create_dated_reading(&app, "Third", (6, 1), (6, 30)).await;
+    sqlx::query(
+        "UPDATE readings SET started_at = '2024-11-01', finished_at = '2024-12-01'
+         WHERE book_id = (SELECT id FROM books WHERE title = 'First')",
+    )
+    .execute(&app.pool)
+    .await
+    .expect("Failed to move reading");
+    let years = [Some(2024), Some(2025)].into_iter().collect();
+    refresh_stats_years(&repo, user_id, &years, &metrics)
+        .await
+        .expect("incremental refresh failed");
+
+    let cached = repo
+        .get_cached(user_id)
+        .await
+        .expect("Failed to load cache")
+        .expect("cache should exist");
+    let live = compute_all_stats(&repo, user_id)
+        .await
+        .expect("live stats failed");
+    assert_eq!(cached.reading.books_all_time, 3);
+    assert_eq!(cached.reading.books_all_time, live.reading.books_all_time);
+    assert_eq!(cached.reading.pages_all_time, live.reading.pages_all_time);
+    assert_eq!(
+        cached.reading.average_days_to_finish,
+        live.reading.average_days_to_finish
+    );
+    assert_eq!(cached.reading.monthly_books, live.reading.monthly_books);
+
+    // Reconciliation finds nothing the incremental update missed
+    let rebuild = rebuild_stats(&repo, user_id, &metrics)
+        .await
+        .expect("full rebuild failed");
+    assert_eq!(rebuild.drifted_years, 0);
+
+    let snapshot = metrics.snapshot();
+    assert_eq!(snapshot.full_runs, 2);
+    assert_eq!(snapshot.incremental_runs, 1);
+}
+
+#[tokio::test]
+async fn stats_track_quick_review_sentiment() {
+    use booklog::domain::readings::QuickReview;
+
+    let app = spawn_app_with_auth().await;
+    // 250 pages over a month is a slow read, over 2 days a fast one
+    create_dated_reading(&app, "Slow Book", (1, 1), (2, 1)).await;
+    create_dated_reading(&app, "Fast Book", (3, 1), (3, 3)).await;
+    for (title, rating, review) in [
+        ("Slow Book", 2.0, r#"["Slow burn","Too long"]"#),
+        ("Fast Book", 5.0, r#"["Page-turner","Slow burn"]"#),
+    ] {
+        sqlx::query(
+            "UPDATE readings SET rating = ?, review = ?
+             WHERE book_id = (SELECT id FROM books WHERE title = ?)",
+        )
+        .bind(rating)
+        .bind(review)
+        .bind(title)
+        .execute(&app.pool)
+        .await
+        .expect("Failed to review reading");
+    }
+
+    let report: booklog::domain::stats::StatsReport = Client::new()
+        .get(app.api_url("/stats?from=2025-01-01&to=2025-12-31"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to execute request")
+        .json()
+        .await
+        .expect("Failed to parse stats report");
+
+    let quick_reviews = &report.current.reading.quick_reviews;
+    let slow_burn = &quick_reviews.usage[0];
+    assert_eq!(slow_burn.review, QuickReview::SlowBurn);
+    assert_eq!(slow_burn.readings, 2);
+    assert_eq!(slow_burn.average_rating(), Some(3.5));
+    let by_pace: Vec<_> = quick_reviews
+        .by_pace
+        .iter()
+        .map(|(pace, counts)| (pace.as_str(), counts.balance()))
+        .collect();
+    assert_eq!(by_pace, [("Slow", Some(-0.5)), ("Fast", Some(0.5))]);
+    assert_eq!(quick_reviews.by_rating[0].0, 2.0);
+
+    let authors = &report.current.book_summary.author_sentiment;
+    assert_eq!(authors.len(), 2);
+    assert_eq!(authors[0].0, "Fast Book Author");
+    assert_eq!(authors[0].1.positive, 1);
+
+    let session_token = create_session(&app).await;
+    let body = Client::new()
+        .get(app.page_url("/stats?year=2025"))
+        .header("Cookie", format!("booklog_session={session_token}"))
+        .send()
+        .await
+        .expect("Failed to load stats page")
+        .text()
+        .await
+        .expect("Failed to read body");
+    assert!(body.contains("Quick Reviews"));
+    assert!(body.contains("Slow burn"));
+    assert!(body.contains("By Author"));
+}
+
+#[tokio::test]
+async fn year_review_summarises_finished_books() {
+    let app = spawn_app_with_auth().await;
+    create_dated_reading(&app, "Winter Book", (1, 2), (1, 9)).await;
+    create_dated_reading(&app, "Autumn Book", (10, 1), (10, 20)).await;
+
+    let review: booklog::domain::review::YearInReview = Client::new()
+        .get(app.api_url("/review/2025"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to execute request")
+        .json()
+        .await
+        .expect("Failed to parse review");
+
+    assert_eq!(review.books_read, 2);
+    assert_eq!(review.pages_read, 500);
+    assert_eq!(review.first_finished.unwrap().title, "Winter Book");
+    assert_eq!(review.last_finished.unwrap().title, "Autumn Book");
+}
+
+#[tokio::test]
+async fn year_review_card_is_a_png() {
+    let app = spawn_app_with_auth().await;
+    create_dated_reading(&app, "Card Book", (4, 1), (4, 8)).await;
+
+    let response = Client::new()
+        .get(app.api_url("/review/2025/card.png"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers()["content-type"], "image/png");
+    let bytes = response.bytes().await.expect("Failed to read body");
+    let card = image::load_from_memory(&bytes).expect("card should decode");
+    assert_eq!((card.width(), card.height()), (1080, 1080));
+}
+
+#[tokio::test]
+async fn year_review_page_renders() {
+    let app = spawn_app_with_auth().await;
+    create_dated_reading(&app, "Page Book", (5, 1), (5, 3)).await;
+    let session_token = create_session(&app).await;
+
+    let body = Client::new()
+        .get(app.page_url("/review/2025"))
+        .header("Cookie", format!("booklog_session={session_token}"))
+        .send()
+        .await
+        .expect("Failed to load review page")
+        .text()
+        .await
+        .expect("Failed to read body");
+
+    assert_full_page(&body);
+    assert!(body.contains("2025 in Review"));
+    assert!(body.contains("Page Book"));
+    assert!(body.contains("/api/v1/review/2025/card.png"));
+}
+
+async fn get_household(
+    app: &crate::helpers::TestApp,
+) -> booklog::domain::household::HouseholdStats {
+    Client::new()
+        .get(app.api_url("/stats/household"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("Failed to execute request")
+        .json()
+        .await
+        .expect("Failed to parse household stats")
+}
+
+#[tokio::test]
+async fn household_stats_aggregate_users_and_respect_opt_out() {
+    let app = spawn_app_with_auth().await;
+    create_dated_reading(&app, "Shared Book", (2, 1), (2, 10)).await;
+    create_dated_reading(&app, "Admin Book", (3, 1), (3, 10)).await;
+    let member_token = create_non_admin_token(&app).await;
+    let shared_id: i64 = sqlx::query_scalar("SELECT id FROM books WHERE title = 'Shared Book'")
+        .fetch_one(&app.pool)
+        .await
+        .expect("Failed to find book");
+    let response = Client::new()
+        .post(app.api_url("/readings"))
+        .bearer_auth(&member_token)
+        .json(&serde_json::json!({
+            "book_id": shared_id,
+            "status": "read",
+            "started_at": "2025-04-01",
+            "finished_at": "2025-04-05",
+        }))
+        .send()
+        .await
+        .expect("Failed to create reading");
+    assert!(response.status().is_success());
+
+    let household = get_household(&app).await;
+    assert_eq!(household.members, 2);
+    assert_eq!(household.books_read, 3);
+    assert_eq!(household.pages_read, 750);
+    assert_eq!(household.top_books[0].title, "Shared Book");
+    assert_eq!(household.top_books[0].readers, 2);
+    let board = &household.leaderboards[0];
+    assert_eq!(board.year, 2025);
+    let ranking: Vec<_> = board
+        .entries
+        .iter()
+        .map(|e| (e.username.as_str(), e.books))
+        .collect();
+    assert_eq!(ranking, [("admin", 2), ("non-admin", 1)]);
+
+    let response = Client::new()
+        .put(app.api_url("/stats/household/privacy"))
+        .bearer_auth(&member_token)
+        .json(&serde_json::json!({ "opt_out": true }))
+        .send()
+        .await
+        .expect("Failed to update privacy");
+    assert_eq!(response.status(), 204);
+
+    let household = get_household(&app).await;
+    assert_eq!(household.members, 1);
+    assert_eq!(household.books_read, 2);
+    assert_eq!(household.top_books[0].readers, 1);
+    assert!(
+        household.leaderboards[0]
+            .entries
+            .iter()
+            .all(|e| e.username == "admin")
+    );
+}
+
+#[tokio::test]
+async fn household_stats_leave_out_private_readings() {
+    let app = spawn_app_with_auth().await;
+    create_dated_reading(&app, "Shared Book", (2, 1), (2, 10)).await;
+    let member_token = create_non_admin_token(&app).await;
+    let shared_id: i64 = sqlx::query_scalar("SELECT id FROM books WHERE title = 'Shared Book'")
+        .fetch_one(&app.pool)
+        .await
+        .expect("Failed to find book");
+    let today = chrono::Utc::now().date_naive();
+    let response = Client::new()
+        .post(app.api_url("/readings"))
+        .bearer_auth(&member_token)
+        .json(&serde_json::json!({
+            "book_id": shared_id,
+            "status": "read",
+            "started_at": today,
+            "finished_at": today,
+            "private": true,
+        }))
+        .send()
+        .await
+        .expect("Failed to create reading");
+    assert!(response.status().is_success());
+
+    let household = get_household(&app).await;
+    assert_eq!(household.members, 2);
+    assert_eq!(household.books_read, 1);
+    assert_eq!(household.pages_read, 250);
+    assert_eq!(household.top_books[0].readers, 1);
+    assert_eq!(household.top_authors[0].1, 1);
+    assert!(household.this_month.is_empty());
+    assert!(
+        household
+            .leaderboards
+            .iter()
+            .flat_map(|board| &board.entries)
+            .all(|e| e.username == "admin")
+    );
+}
+
+#[tokio::test]
+async fn household_page_renders() {
+    let app = spawn_app_with_auth().await;
+    create_dated_reading(&app, "Household Book", (6, 1), (6, 3)).await;
+    let session_token = create_session(&app).await;
+
+    let body = Client::new()
+        .get(app.page_url("/stats/household"))
+        .header("Cookie", format!("booklog_session={session_token}"))
+        .send()
+        .await
+        .expect("Failed to load household page")
+        .text()
+        .await
+        .expect("Failed to read body");
+
+    assert_full_page(&body);
+    assert!(body.contains("Household Book"));
+    assert!(body.contains("Leaderboard"));
+    assert!(body.contains("Leave my reading out"));
+}
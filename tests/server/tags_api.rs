@@ -0,0 +1,243 @@
+use booklog::domain::book_items::{Book, NewBook};
+use booklog::domain::ids::{AuthorId, BookId, UserId};
+use booklog::domain::readings::{NewReading, Reading, ReadingFormat, ReadingStatus};
+use booklog::domain::tags::TagCount;
+use booklog::domain::user_books::UserBook;
+use reqwest::Client;
+use serde_json::{Value, json};
+
+use crate::helpers::{
+    TestApp, create_author_with_name, create_entity, create_non_admin_token, create_session,
+    new_book, spawn_app_with_auth,
+};
+
+async fn create_book(app: &TestApp, title: &str, author_id: AuthorId) -> Book {
+    create_entity(
+        app,
+        "/books",
+        &NewBook {
+            page_count: Some(200),
+            ..new_book(author_id, title)
+        },
+    )
+    .await
+}
+
+async fn set_tags(app: &TestApp, book_id: BookId, tags: Value) -> reqwest::Response {
+    Client::new()
+        .put(app.api_url(&format!("/books/{book_id}/tags")))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&json!({ "tags": tags }))
+        .send()
+        .await
+        .expect("Failed to execute request")
+}
+
+async fn get_json<T: serde::de::DeserializeOwned>(app: &TestApp, path: &str, token: &str) -> T {
+    let response = Client::new()
+        .get(app.api_url(path))
+        .bearer_auth(token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), 200, "GET {path}");
+    response.json().await.expect("Failed to parse response")
+}
+
+#[tokio::test]
+async fn setting_tags_normalizes_and_dedupes() {
+    let app = spawn_app_with_auth().await;
+    let author = create_author_with_name(&app, "Author").await;
+    let book = create_book(&app, "Tagged", author.id).await;
+
+    let response = set_tags(&app, book.id, json!(["Comfort Read", "#cosy", "cosy", " "])).await;
+    assert_eq!(response.status(), 200);
+    let tags: Vec<String> = response.json().await.unwrap();
+    assert_eq!(tags, vec!["comfort-read", "cosy"]);
+
+    let token = app.auth_token.clone().unwrap();
+    let stored: Vec<String> = get_json(&app, &format!("/books/{}/tags", book.id), &token).await;
+    assert_eq!(stored, tags);
+
+    // Replacing clears tags that are no longer listed
+    set_tags(&app, book.id, json!(["re-read"])).await;
+    let stored: Vec<String> = get_json(&app, &format!("/books/{}/tags", book.id), &token).await;
+    assert_eq!(stored, vec!["re-read"]);
+}
+
+#[tokio::test]
+async fn adding_and_removing_single_tags() {
+    let app = spawn_app_with_auth().await;
+    let author = create_author_with_name(&app, "Author").await;
+    let book = create_book(&app, "Tagged", author.id).await;
+    let token = app.auth_token.clone().unwrap();
+    let client = Client::new();
+
+    for _ in 0..2 {
+        let response = client
+            .post(app.api_url(&format!("/books/{}/tags", book.id)))
+            .bearer_auth(&token)
+            .json(&json!({"tag": "Sci Fi"}))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 204);
+    }
+
+    let stored: Vec<String> = get_json(&app, &format!("/books/{}/tags", book.id), &token).await;
+    assert_eq!(stored, vec!["sci-fi"]);
+
+    let removed = client
+        .delete(app.api_url(&format!("/books/{}/tags/sci-fi", book.id)))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(removed.status(), 204);
+
+    let missing = client
+        .delete(app.api_url(&format!("/books/{}/tags/sci-fi", book.id)))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(missing.status(), 404);
+}
+
+#[tokio::test]
+async fn tagging_a_missing_book_is_not_found() {
+    let app = spawn_app_with_auth().await;
+
+    let response = set_tags(&app, BookId::new(9999), json!(["nope"])).await;
+
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn tag_list_counts_and_autocompletes() {
+    let app = spawn_app_with_auth().await;
+    let author = create_author_with_name(&app, "Author").await;
+    let first = create_book(&app, "First", author.id).await;
+    let second = create_book(&app, "Second", author.id).await;
+    set_tags(&app, first.id, json!(["fantasy", "favourite"])).await;
+    set_tags(&app, second.id, json!(["fantasy", "sci-fi"])).await;
+    let token = app.auth_token.clone().unwrap();
+
+    let all: Vec<TagCount> = get_json(&app, "/tags", &token).await;
+    assert_eq!(all[0].tag, "fantasy");
+    assert_eq!(all[0].count, 2);
+    assert_eq!(all.len(), 3);
+
+    let completions: Vec<TagCount> = get_json(&app, "/tags?q=FA", &token).await;
+    let names: Vec<&str> = completions.iter().map(|t| t.tag.as_str()).collect();
+    assert_eq!(names, vec!["fantasy", "favourite"]);
+}
+
+#[tokio::test]
+async fn tags_filter_user_books_and_readings() {
+    let app = spawn_app_with_auth().await;
+    let author = create_author_with_name(&app, "Author").await;
+    let cosy = create_book(&app, "Cosy Book", author.id).await;
+    let grim = create_book(&app, "Grim Book", author.id).await;
+    for book in [&cosy, &grim] {
+        let _: UserBook = create_entity(&app, "/user-books", &json!({"book_id": book.id})).await;
+        let _: Reading = create_entity(
+            &app,
+            "/readings",
+            &NewReading {
+                user_id: UserId::new(1),
+                book_id: book.id,
+                status: ReadingStatus::Read,
+                format: Some(ReadingFormat::EReader),
+                started_at: None,
+                finished_at: Some(chrono::NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()),
+                rating: None,
+                quick_reviews: Vec::new(),
+                private: false,
+                created_at: None,
+            },
+        )
+        .await;
+    }
+    set_tags(&app, cosy.id, json!(["comfort-read"])).await;
+    let token = app.auth_token.clone().unwrap();
+
+    let library: Value = get_json(&app, "/user-books?q=tag%3Acomfort", &token).await;
+    let items = library.as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["book_id"], json!(cosy.id));
+    assert_eq!(items[0]["tags"], json!(["comfort-read"]));
+
+    let readings: Value = get_json(&app, "/readings?q=-tag%3Acomfort-read", &token).await;
+    let items = readings.as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["book_title"], "Grim Book");
+
+    let books = Client::new()
+        .get(app.api_url("/books?q=tag%3Acomfort-read"))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(books.status(), 400);
+}
+
+#[tokio::test]
+async fn tags_are_personal() {
+    let app = spawn_app_with_auth().await;
+    let author = create_author_with_name(&app, "Author").await;
+    let book = create_book(&app, "Shared Book", author.id).await;
+    set_tags(&app, book.id, json!(["mine"])).await;
+    let other_token = create_non_admin_token(&app).await;
+
+    let other_tags: Vec<String> =
+        get_json(&app, &format!("/books/{}/tags", book.id), &other_token).await;
+    assert!(other_tags.is_empty());
+
+    let other_counts: Vec<TagCount> = get_json(&app, "/tags", &other_token).await;
+    assert!(other_counts.is_empty());
+}
+
+#[tokio::test]
+async fn tags_appear_on_book_and_stats_pages() {
+    let app = spawn_app_with_auth().await;
+    let author = create_author_with_name(&app, "Author").await;
+    let book = create_book(&app, "Cloudy", author.id).await;
+    let _: Reading = create_entity(
+        &app,
+        "/readings",
+        &NewReading {
+            user_id: UserId::new(1),
+            book_id: book.id,
+            status: ReadingStatus::Read,
+            format: None,
+            started_at: None,
+            finished_at: Some(chrono::NaiveDate::from_ymd_opt(2025, 2, 1).unwrap()),
+            rating: None,
+            quick_reviews: Vec::new(),
+            private: false,
+            created_at: None,
+        },
+    )
+    .await;
+    set_tags(&app, book.id, json!(["stormy-weather"])).await;
+
+    let session = create_session(&app).await;
+    let client = Client::new();
+    for path in [format!("/books/{}", book.id), "/stats".to_string()] {
+        let page = client
+            .get(format!("{}{path}", app.address))
+            .header("Cookie", format!("booklog_session={session}"))
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+        assert!(
+            page.contains("#stormy-weather"),
+            "{path} should show the tag"
+        );
+        assert!(page.contains("q=tag%3Astormy-weather"));
+    }
+}
@@ -1,43 +1,17 @@
 use crate::helpers::{
-    create_author_with_payload, create_default_author, create_default_book, spawn_app,
+    create_author_with_payload, create_book_with_title, create_default_author, create_default_book,
+    create_default_reading, create_entity, create_genre_with_name, new_book, spawn_app,
     spawn_app_with_auth, spawn_app_with_timeline_sync,
 };
 use booklog::domain::authors::NewAuthor;
-use booklog::domain::book_items::{AuthorRole, BookAuthor, NewBook};
-use booklog::domain::ids::AuthorId;
+use booklog::domain::book_items::{AuthorRole, Book, BookAuthor, NewBook};
+use booklog::domain::timeline::{TimelineEvent, TimelineEventChange, TimelineField};
+use booklog::domain::user_books::UserBook;
+use booklog::domain::users::ProfilePrivacy;
+use chrono::Datelike;
 use reqwest::Client;
 use tokio::time::{Duration, sleep};
 
-async fn create_book(app: &crate::helpers::TestApp, author_id: AuthorId, title: &str) {
-    let client = Client::new();
-    let book = NewBook {
-        title: title.to_string(),
-        authors: vec![BookAuthor {
-            author_id,
-            role: AuthorRole::default(),
-        }],
-        isbn: None,
-        description: None,
-        page_count: None,
-        year_published: None,
-        publisher: None,
-        language: None,
-        primary_genre_id: None,
-        secondary_genre_id: None,
-        created_at: None,
-    };
-
-    let response = client
-        .post(app.api_url("/books"))
-        .bearer_auth(app.auth_token.as_ref().unwrap())
-        .json(&book)
-        .send()
-        .await
-        .expect("failed to create book");
-
-    assert_eq!(response.status(), 201);
-}
-
 async fn seed_timeline_with_books(
     app: &crate::helpers::TestApp,
     book_count: usize,
@@ -47,6 +21,7 @@ async fn seed_timeline_with_books(
         app,
         NewAuthor {
             name: author_name.to_string(),
+            details: Default::default(),
             created_at: None,
         },
     )
@@ -58,7 +33,7 @@ async fn seed_timeline_with_books(
     let mut book_titles = Vec::new();
     for index in 0..book_count {
         let title = format!("Seed Book {index:02}");
-        create_book(app, author.id, &title).await;
+        create_book_with_title(app, author.id, &title).await;
         book_titles.push(title);
         // Space out timestamps to keep ordering deterministic.
         sleep(Duration::from_millis(2)).await;
@@ -97,6 +72,7 @@ async fn creating_an_author_surfaces_on_the_timeline() {
         &app,
         NewAuthor {
             name: author_name.to_string(),
+            details: Default::default(),
             created_at: None,
         },
     )
@@ -136,6 +112,7 @@ async fn creating_a_book_surfaces_on_the_timeline() {
         &app,
         NewAuthor {
             name: "Timeline Book Author".to_string(),
+            details: Default::default(),
             created_at: None,
         },
     )
@@ -144,7 +121,7 @@ async fn creating_a_book_surfaces_on_the_timeline() {
 
     sleep(Duration::from_millis(5)).await;
     let book_title = "Timeline Novel";
-    create_book(&app, author_id, book_title).await;
+    create_book_with_title(&app, author_id, book_title).await;
 
     let response = client
         .get(format!("{}/timeline", app.address))
@@ -358,6 +335,7 @@ async fn editing_an_author_updates_timeline_events() {
         &app,
         NewAuthor {
             name: original_name.to_string(),
+            details: Default::default(),
             created_at: None,
         },
     )
@@ -365,7 +343,7 @@ async fn editing_an_author_updates_timeline_events() {
 
     // Also create a book so we can verify cascade
     sleep(Duration::from_millis(5)).await;
-    create_book(&app, author.id, "Cascading Book").await;
+    create_book_with_title(&app, author.id, "Cascading Book").await;
 
     // Verify original name appears in timeline
     sleep(Duration::from_millis(10)).await;
@@ -478,6 +456,7 @@ async fn deleting_an_entity_removes_timeline_events() {
         &app,
         NewAuthor {
             name: "Doomed Author".to_string(),
+            details: Default::default(),
             created_at: None,
         },
     )
@@ -546,3 +525,337 @@ async fn rebuild_endpoint_requires_auth() {
 
     assert_eq!(response.status(), 401);
 }
+
+async fn list_timeline(app: &crate::helpers::TestApp, query: &str) -> Vec<TimelineEvent> {
+    let response = Client::new()
+        .get(format!("{}?{query}", app.api_url("/timeline")))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .expect("failed to list timeline");
+    assert_eq!(response.status(), 200);
+    response
+        .json()
+        .await
+        .expect("failed to parse timeline events")
+}
+
+#[tokio::test]
+async fn timeline_filters_by_type_genre_and_date() {
+    let app = spawn_app_with_auth().await;
+    let client = Client::new();
+
+    let genre = create_genre_with_name(&app, "Filtered Genre").await;
+    let author = create_default_author(&app).await;
+    let genre_book: Book = create_entity(
+        &app,
+        "/books",
+        &NewBook {
+            title: "Genre Book".to_string(),
+            authors: vec![BookAuthor {
+                author_id: author.id,
+                role: AuthorRole::default(),
+            }],
+            isbn: None,
+            description: None,
+            page_count: None,
+            duration_minutes: None,
+            year_published: None,
+            publisher: None,
+            language: None,
+            primary_genre_id: Some(genre.id),
+            secondary_genre_id: None,
+            created_at: None,
+        },
+    )
+    .await;
+    create_book_with_title(&app, author.id, "Other Book").await;
+    create_default_reading(&app, genre_book.id).await;
+
+    sleep(Duration::from_millis(10)).await;
+
+    let readings = list_timeline(&app, "type=reading").await;
+    assert_eq!(readings.len(), 1);
+    assert!(readings.iter().all(|e| e.entity_type == "reading"));
+
+    let in_genre = list_timeline(&app, &format!("genre={}", genre.id)).await;
+    let titles: Vec<&str> = in_genre.iter().map(|e| e.title.as_str()).collect();
+    assert!(titles.iter().all(|t| *t == "Genre Book"), "got {titles:?}");
+    assert_eq!(in_genre.len(), 2, "book and reading events, got {titles:?}");
+
+    assert!(list_timeline(&app, "since=2999-01-01").await.is_empty());
+    assert!(!list_timeline(&app, "until=2999-01-01").await.is_empty());
+
+    let response = client
+        .get(format!("{}/timeline?type=book", app.address))
+        .send()
+        .await
+        .expect("failed to fetch timeline");
+    assert_eq!(response.status(), 200);
+    let body = response.text().await.expect("failed to read response body");
+    assert!(
+        body.contains("Type: Books"),
+        "expected a filter chip: {body}"
+    );
+    assert!(body.contains("Other Book"));
+    assert!(
+        !body.contains("href=\"/readings/"),
+        "reading events should be filtered out"
+    );
+}
+
+async fn put_json(app: &crate::helpers::TestApp, path: &str, body: serde_json::Value) {
+    let response = Client::new()
+        .put(app.api_url(path))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&body)
+        .send()
+        .await
+        .expect("failed to send update");
+    assert_eq!(response.status(), 200);
+}
+
+#[tokio::test]
+async fn moving_a_book_between_shelves_records_the_change() {
+    let app = spawn_app_with_auth().await;
+    let author = create_default_author(&app).await;
+    let book = create_default_book(&app, author.id).await;
+    let user_book: UserBook = create_entity(
+        &app,
+        "/user-books",
+        &serde_json::json!({"book_id": book.id, "shelf": "wishlist"}),
+    )
+    .await;
+
+    put_json(
+        &app,
+        &format!("/user-books/{}", user_book.id),
+        serde_json::json!({"shelf": "library"}),
+    )
+    .await;
+
+    let events = list_timeline(&app, "type=user_book").await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].action, "moved");
+    assert_eq!(events[0].entity_id, book.id.into_inner());
+    assert_eq!(
+        events[0].changes,
+        vec![TimelineEventChange::new(
+            TimelineField::Shelf,
+            Some("Wishlist".to_string()),
+            Some("Library".to_string())
+        )]
+    );
+
+    let body = Client::new()
+        .get(format!("{}/timeline?type=user_book", app.address))
+        .send()
+        .await
+        .expect("failed to fetch timeline")
+        .text()
+        .await
+        .expect("failed to read response body");
+    assert!(body.contains("Moved"), "expected the move badge: {body}");
+    assert!(body.contains("Wishlist"));
+}
+
+#[tokio::test]
+async fn rating_changes_and_abandonment_record_before_and_after() {
+    let app = spawn_app_with_auth().await;
+    let author = create_default_author(&app).await;
+    let book = create_default_book(&app, author.id).await;
+    let reading = create_default_reading(&app, book.id).await;
+    let path = format!("/readings/{}", reading.id);
+
+    put_json(&app, &path, serde_json::json!({"rating": 3.0})).await;
+    put_json(&app, &path, serde_json::json!({"rating": 4.5})).await;
+    put_json(&app, &path, serde_json::json!({"status": "abandoned"})).await;
+    // Saving without changes records nothing
+    put_json(&app, &path, serde_json::json!({"status": "abandoned"})).await;
+
+    let rated = list_timeline(&app, "type=reading&action=rated").await;
+    assert_eq!(rated.len(), 2);
+    // Newest first
+    assert_eq!(rated[0].changes[0].before.as_deref(), Some("3/5"));
+    assert_eq!(rated[0].changes[0].after.as_deref(), Some("4.5/5"));
+    assert_eq!(rated[1].changes[0].before, None);
+
+    let abandoned = list_timeline(&app, "type=reading&action=abandoned").await;
+    assert_eq!(abandoned.len(), 1);
+    assert_eq!(
+        abandoned[0].changes,
+        vec![TimelineEventChange::new(
+            TimelineField::Status,
+            Some("Reading".to_string()),
+            Some("Abandoned".to_string())
+        )]
+    );
+}
+
+const HIDE_ALL: ProfilePrivacy = ProfilePrivacy {
+    show_ratings: false,
+    show_quick_reviews: false,
+};
+
+#[tokio::test]
+async fn redaction_finds_ratings_and_notes_by_key_not_label() {
+    let app = spawn_app_with_auth().await;
+    let author = create_default_author(&app).await;
+    let book = create_default_book(&app, author.id).await;
+    let reading = create_default_reading(&app, book.id).await;
+    let path = format!("/readings/{}", reading.id);
+    put_json(
+        &app,
+        &path,
+        serde_json::json!({"status": "read", "rating": 4.0, "quick_reviews": ["page-turner"]}),
+    )
+    .await;
+
+    let mut events = list_timeline(&app, "type=reading").await;
+    let finished = events.iter().find(|e| e.action == "finished").unwrap();
+    let keys: Vec<_> = finished.details.iter().map(|d| d.key).collect();
+    assert!(keys.contains(&Some(TimelineField::Rating)), "{keys:?}");
+    assert!(
+        keys.contains(&Some(TimelineField::QuickReviews)),
+        "{keys:?}"
+    );
+
+    for event in &mut events {
+        for detail in &mut event.details {
+            detail.label = format!("Relabelled {}", detail.label);
+        }
+        for change in &mut event.changes {
+            change.label = format!("Relabelled {}", change.label);
+        }
+        event.redact(HIDE_ALL);
+        assert!(
+            event
+                .details
+                .iter()
+                .all(|d| !d.value.contains("4/5") && !d.value.contains("Page-turner")),
+            "{:?}",
+            event.details
+        );
+        assert!(
+            event
+                .changes
+                .iter()
+                .all(|c| c.key != Some(TimelineField::Rating)),
+            "{:?}",
+            event.changes
+        );
+    }
+}
+
+#[tokio::test]
+async fn events_stored_before_field_keys_get_them_by_label() {
+    let app = spawn_app_with_auth().await;
+    sqlx::query(
+        r#"INSERT INTO timeline_events (user_id, entity_type, entity_id, action, occurred_at, title, details_json, changes_json)
+           VALUES (1, 'reading', 1, 'finished', '2026-01-01T00:00:00Z', 'Dune',
+                   '[{"label":"Author","value":"Frank Herbert"},{"label":"Rating","value":"4/5"},{"label":"Notes","value":"Page-turner"}]',
+                   '[{"label":"Rating","before":null,"after":"4/5"}]')"#,
+    )
+    .execute(&app.pool)
+    .await
+    .unwrap();
+    sqlx::raw_sql(include_str!(
+        "../../migrations/0020_timeline_field_keys.sql"
+    ))
+    .execute(&app.pool)
+    .await
+    .unwrap();
+
+    let events = list_timeline(&app, "type=reading").await;
+    let keys: Vec<_> = events[0].details.iter().map(|d| d.key).collect();
+    assert_eq!(
+        keys,
+        vec![
+            Some(TimelineField::Author),
+            Some(TimelineField::Rating),
+            Some(TimelineField::QuickReviews)
+        ]
+    );
+    assert_eq!(events[0].changes[0].key, Some(TimelineField::Rating));
+}
+
+#[tokio::test]
+async fn finishing_readings_records_goal_milestones() {
+    let app = spawn_app_with_auth().await;
+    let author = create_default_author(&app).await;
+    let year = chrono::Utc::now().year();
+    let goal_response = Client::new()
+        .post(app.api_url("/goals"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&serde_json::json!({"target": 2, "year": year}))
+        .send()
+        .await
+        .expect("failed to create goal");
+    assert_eq!(goal_response.status(), 201);
+
+    let today = chrono::Utc::now().date_naive().to_string();
+    for title in ["First", "Second"] {
+        let book = create_book_with_title(&app, author.id, title).await;
+        let reading = create_default_reading(&app, book.id).await;
+        put_json(
+            &app,
+            &format!("/readings/{}", reading.id),
+            serde_json::json!({"status": "read", "finished_at": today}),
+        )
+        .await;
+    }
+
+    let milestones = list_timeline(&app, "type=goal&action=milestone").await;
+    let reached: Vec<_> = milestones
+        .iter()
+        .map(|e| e.changes[0].after.as_deref().unwrap_or_default())
+        .collect();
+    assert_eq!(reached, ["100%", "50%"]);
+    assert_eq!(milestones[0].title, format!("{year} reading goal"));
+}
+
+#[tokio::test]
+async fn reading_progress_records_each_milestone_once() {
+    let app = spawn_app_with_auth().await;
+    let author = create_default_author(&app).await;
+    let book: Book = create_entity(
+        &app,
+        "/books",
+        &NewBook {
+            page_count: Some(400),
+            ..new_book(author.id, "Long Book")
+        },
+    )
+    .await;
+    let reading = create_default_reading(&app, book.id).await;
+    let path = format!("/readings/{}", reading.id);
+
+    for page in [50, 120, 130, 320] {
+        put_json(&app, &path, serde_json::json!({"current_page": page})).await;
+    }
+    // Going back and forward again doesn't repeat a milestone
+    put_json(&app, &path, serde_json::json!({"current_page": 10})).await;
+    put_json(&app, &path, serde_json::json!({"current_page": 110})).await;
+
+    let milestones = list_timeline(&app, "type=reading&action=milestone").await;
+    assert_eq!(milestones.len(), 2, "{milestones:?}");
+    // Newest first: 320 pages skips straight past 50% to 75%
+    assert_eq!(
+        milestones[0].changes,
+        vec![
+            TimelineEventChange::new(
+                TimelineField::Milestone,
+                Some("25%".to_string()),
+                Some("75%".to_string())
+            ),
+            TimelineEventChange::new(
+                TimelineField::Progress,
+                Some("130 of 400 pages".to_string()),
+                Some("320 of 400 pages".to_string())
+            ),
+        ]
+    );
+    let first = &milestones[1].changes[0];
+    assert_eq!(first.before, None);
+    assert_eq!(first.after.as_deref(), Some("25%"));
+}
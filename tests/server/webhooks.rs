@@ -0,0 +1,343 @@
+use booklog::infrastructure::webhooks::signature;
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+use tokio::time::{Duration, sleep, timeout};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+use crate::helpers::{
+    TestApp, create_author_with_name, create_book_with_title, create_entity,
+    create_non_admin_token, create_session, post_form, spawn_app_with_auth,
+    spawn_app_with_timeline_sync, spawn_app_without_private_network,
+};
+
+/// Register a webhook for `events` and return its id and secret.
+async fn add_webhook(app: &TestApp, url: &str, events: &[&str]) -> (i64, String) {
+    let response = Client::new()
+        .post(app.api_url("/webhooks"))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .json(&json!({ "url": url, "events": events }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body: Value = response.json().await.unwrap();
+    (
+        body["id"].as_i64().unwrap(),
+        body["secret"].as_str().unwrap().to_string(),
+    )
+}
+
+async fn receiver(status: u16) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .respond_with(ResponseTemplate::new(status))
+        .mount(&server)
+        .await;
+    server
+}
+
+/// Wait for `count` requests to arrive at the receiver.
+async fn received(server: &MockServer, count: usize) -> Vec<Request> {
+    let found = timeout(Duration::from_secs(5), async {
+        loop {
+            let requests = server.received_requests().await.unwrap_or_default();
+            if requests.len() >= count {
+                return requests;
+            }
+            sleep(Duration::from_millis(25)).await;
+        }
+    })
+    .await;
+    found.unwrap_or_else(|_| panic!("expected {count} webhook requests"))
+}
+
+fn event_of(request: &Request) -> Value {
+    serde_json::from_slice(&request.body).unwrap()
+}
+
+async fn finish_a_book(app: &TestApp) {
+    let author = create_author_with_name(app, "Ursula K. Le Guin").await;
+    let book = create_book_with_title(app, author.id, "The Dispossessed").await;
+    let _: Value = create_entity(
+        app,
+        "/readings",
+        &json!({ "user_id": 1, "book_id": book.id, "status": "read", "rating": 4.0 }),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn subscribed_events_are_signed_and_delivered() {
+    let app = spawn_app_with_timeline_sync().await;
+    let server = receiver(204).await;
+    let (_, secret) = add_webhook(&app, &format!("{}/hook", server.uri()), &["finished"]).await;
+
+    finish_a_book(&app).await;
+
+    let requests = received(&server, 1).await;
+    let request = &requests[0];
+    let event = event_of(request);
+    assert_eq!(event["event"], "finished");
+    assert_eq!(event["entity_type"], "reading");
+    assert_eq!(event["username"], "admin");
+    assert!(
+        event["title"]
+            .as_str()
+            .unwrap()
+            .contains("The Dispossessed")
+    );
+    assert_eq!(request.headers["x-booklog-event"], "finished");
+    assert_eq!(
+        request.headers["x-booklog-signature"].to_str().unwrap(),
+        signature(&secret, &request.body).unwrap()
+    );
+
+    // Only the finished event was subscribed to
+    sleep(Duration::from_millis(200)).await;
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn timeline_events_are_queued_for_dispatch_when_written() {
+    // No job worker runs, as if the server stopped right after the write
+    let app = spawn_app_with_auth().await;
+    finish_a_book(&app).await;
+
+    let (queued,): (i64,) = sqlx::query_as(
+        "SELECT COUNT(*) FROM jobs WHERE kind = 'dispatch_webhooks' AND status = 'pending'",
+    )
+    .fetch_one(&app.pool)
+    .await
+    .unwrap();
+    let (events,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM timeline_events")
+        .fetch_one(&app.pool)
+        .await
+        .unwrap();
+    assert!(events > 0);
+    assert_eq!(queued, events, "one dispatch job per timeline event");
+}
+
+#[tokio::test]
+async fn a_slow_endpoint_does_not_hold_up_other_deliveries() {
+    let app = spawn_app_with_timeline_sync().await;
+    let slow = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .respond_with(ResponseTemplate::new(204).set_delay(Duration::from_secs(5)))
+        .mount(&slow)
+        .await;
+    let fast = receiver(204).await;
+    add_webhook(&app, &format!("{}/hook", slow.uri()), &["finished"]).await;
+    add_webhook(&app, &format!("{}/hook", fast.uri()), &["finished"]).await;
+
+    finish_a_book(&app).await;
+
+    let delivered = timeout(Duration::from_secs(2), async {
+        while fast
+            .received_requests()
+            .await
+            .unwrap_or_default()
+            .is_empty()
+        {
+            sleep(Duration::from_millis(25)).await;
+        }
+    })
+    .await;
+    assert!(
+        delivered.is_ok(),
+        "the fast endpoint waited on the slow one"
+    );
+}
+
+#[tokio::test]
+async fn test_events_reach_the_receiver() {
+    let app = spawn_app_with_timeline_sync().await;
+    let server = receiver(200).await;
+    let (id, _) = add_webhook(&app, &format!("{}/hook", server.uri()), &[]).await;
+
+    let response = Client::new()
+        .post(app.api_url(&format!("/webhooks/{id}/test")))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+
+    let requests = received(&server, 1).await;
+    assert_eq!(event_of(&requests[0])["event"], "test");
+    assert_eq!(event_of(&requests[0])["webhook_id"], id);
+}
+
+#[tokio::test]
+async fn failed_deliveries_are_retried_and_logged() {
+    let app = spawn_app_with_timeline_sync().await;
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+    let (id, _) = add_webhook(&app, &format!("{}/hook", server.uri()), &[]).await;
+
+    Client::new()
+        .post(app.api_url(&format!("/webhooks/{id}/test")))
+        .bearer_auth(app.auth_token.as_ref().unwrap())
+        .send()
+        .await
+        .unwrap();
+
+    // Pull the retry forward instead of waiting out the backoff
+    let delivered = timeout(Duration::from_secs(10), async {
+        loop {
+            sqlx::query(
+                "UPDATE jobs SET run_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+                 WHERE status = 'pending'",
+            )
+            .execute(&app.pool)
+            .await
+            .unwrap();
+            let row: Option<(String, i64, Option<i64>)> =
+                sqlx::query_as("SELECT status, attempts, response_status FROM webhook_deliveries")
+                    .fetch_optional(&app.pool)
+                    .await
+                    .unwrap();
+            if let Some(row) = row.filter(|(status, _, _)| status == "delivered") {
+                return row;
+            }
+            sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("delivery should succeed on retry");
+    assert_eq!(delivered, ("delivered".to_string(), 2, Some(200)));
+
+    let page = Client::new()
+        .get(app.page_url("/admin"))
+        .header(
+            "Cookie",
+            format!("booklog_session={}", create_session(&app).await),
+        )
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(page.contains("Recent deliveries"));
+    assert!(page.contains("HTTP 200"));
+}
+
+#[tokio::test]
+async fn only_admins_create_webhooks_for_every_user() {
+    let app = spawn_app_with_timeline_sync().await;
+    let token = create_non_admin_token(&app).await;
+
+    let response = Client::new()
+        .post(app.api_url("/webhooks"))
+        .bearer_auth(&token)
+        .json(&json!({ "url": "https://example.com/hook", "all_users": true }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = post_form(
+        &app,
+        "/webhooks",
+        &[
+            ("url", "https://example.com/hook"),
+            ("events", "finished, rated"),
+            ("all_users", "true"),
+        ],
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+
+    let (id,): (i64,) = sqlx::query_as("SELECT id FROM webhooks WHERE user_id IS NULL")
+        .fetch_one(&app.pool)
+        .await
+        .unwrap();
+    let response = Client::new()
+        .delete(app.api_url(&format!("/webhooks/{id}")))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(
+        response.status(),
+        StatusCode::NOT_FOUND,
+        "other users can't see admin webhooks"
+    );
+}
+
+#[tokio::test]
+async fn non_admin_webhooks_cannot_reach_private_addresses() {
+    let app = spawn_app_without_private_network().await;
+    let server = receiver(200).await;
+    let url = format!("{}/hook", server.uri());
+    let token = create_non_admin_token(&app).await;
+
+    for private in [
+        url.as_str(),
+        "http://169.254.169.254/latest/meta-data",
+        "http://localhost:8080/admin",
+    ] {
+        let response = Client::new()
+            .post(app.api_url("/webhooks"))
+            .bearer_auth(&token)
+            .json(&json!({ "url": private }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{private}");
+    }
+    // Admins may point webhooks at their own network
+    add_webhook(&app, &url, &[]).await;
+
+    // A host that resolved publicly when the webhook was made is checked
+    // again when delivering
+    let (id,): (i64,) = sqlx::query_as(
+        "INSERT INTO webhooks (user_id, url, secret)
+         SELECT id, ?, 'secret' FROM users WHERE username = 'non-admin'
+         RETURNING id",
+    )
+    .bind(&url)
+    .fetch_one(&app.pool)
+    .await
+    .unwrap();
+    let response = Client::new()
+        .post(app.api_url(&format!("/webhooks/{id}/test")))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+
+    let error = timeout(Duration::from_secs(5), async {
+        loop {
+            let row: Option<(Option<String>,)> = sqlx::query_as(
+                "SELECT error FROM webhook_deliveries WHERE webhook_id = ? AND attempts > 0",
+            )
+            .bind(id)
+            .fetch_optional(&app.pool)
+            .await
+            .unwrap();
+            if let Some((error,)) = row {
+                return error;
+            }
+            sleep(Duration::from_millis(25)).await;
+        }
+    })
+    .await
+    .expect("delivery should be attempted");
+    assert!(error.unwrap().contains("non-public address"));
+    assert!(server.received_requests().await.unwrap().is_empty());
+}
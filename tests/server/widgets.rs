@@ -0,0 +1,149 @@
+use reqwest::{Client, StatusCode, header};
+use serde_json::{Value, json};
+
+use crate::helpers::{
+    TestApp, create_author_with_name, create_book_with_title, create_entity, put_form,
+    spawn_app_with_auth,
+};
+
+async fn start_reading(app: &TestApp, title: &str, started_at: &str, private: bool) {
+    let author = create_author_with_name(app, &format!("{title} Author")).await;
+    let book = create_book_with_title(app, author.id, title).await;
+    create_entity::<_, Value>(
+        app,
+        "/readings",
+        &json!({
+            "user_id": 1,
+            "book_id": book.id,
+            "status": "reading",
+            "started_at": started_at,
+            "private": private,
+        }),
+    )
+    .await;
+}
+
+async fn get_widget(app: &TestApp, path: &str) -> reqwest::Response {
+    Client::new()
+        .get(app.page_url(path))
+        .send()
+        .await
+        .expect("failed to fetch widget")
+}
+
+#[tokio::test]
+async fn widgets_follow_profile_visibility() {
+    let app = spawn_app_with_auth().await;
+    start_reading(&app, "Dune", "2026-01-05", false).await;
+
+    for path in [
+        "/u/admin/widget",
+        "/u/admin/widget.json",
+        "/u/admin/widget.svg",
+    ] {
+        assert_eq!(get_widget(&app, path).await.status(), StatusCode::NOT_FOUND);
+    }
+
+    put_form(&app, "/profile/visibility", &[("public", "true")]).await;
+    for path in [
+        "/u/admin/widget",
+        "/u/admin/widget.json",
+        "/u/admin/widget.svg",
+    ] {
+        assert_eq!(get_widget(&app, path).await.status(), StatusCode::OK);
+    }
+}
+
+#[tokio::test]
+async fn html_widget_can_be_framed_and_hides_private_readings() {
+    let app = spawn_app_with_auth().await;
+    start_reading(&app, "Dune", "2026-01-05", false).await;
+    start_reading(&app, "Diary", "2026-01-06", true).await;
+    put_form(&app, "/profile/visibility", &[("public", "true")]).await;
+
+    let response = get_widget(&app, "/u/admin/widget?theme=dark").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get(header::X_FRAME_OPTIONS).is_none());
+    let csp = response.headers()[header::CONTENT_SECURITY_POLICY]
+        .to_str()
+        .unwrap();
+    assert!(csp.contains("frame-ancestors *"));
+
+    let body = response.text().await.unwrap();
+    assert!(body.contains("admin is reading"));
+    assert!(body.contains("Dune"));
+    assert!(body.contains("Since Jan 5"));
+    assert!(!body.contains("Diary"));
+    assert!(body.contains("#292524"), "dark theme background expected");
+
+    // Everything else still refuses to be framed
+    let response = get_widget(&app, "/u/admin").await;
+    assert_eq!(response.headers()[header::X_FRAME_OPTIONS], "DENY");
+}
+
+#[tokio::test]
+async fn json_widget_lists_newest_readings_up_to_count() {
+    let app = spawn_app_with_auth().await;
+    start_reading(&app, "Older", "2026-01-01", false).await;
+    start_reading(&app, "Newer", "2026-02-01", false).await;
+    put_form(&app, "/profile/visibility", &[("public", "true")]).await;
+
+    let response = get_widget(&app, "/u/admin/widget.json?count=1").await;
+    assert_eq!(response.headers()[header::ACCESS_CONTROL_ALLOW_ORIGIN], "*");
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["username"], "admin");
+    assert!(body["profile_url"].as_str().unwrap().ends_with("/u/admin"));
+    let readings = body["readings"].as_array().unwrap();
+    assert_eq!(readings.len(), 1);
+    assert_eq!(readings[0]["title"], "Newer");
+    assert_eq!(readings[0]["authors"], "Newer Author");
+    assert_eq!(readings[0]["started_at"], "2026-02-01");
+    assert!(readings[0]["cover_url"].is_null());
+}
+
+#[tokio::test]
+async fn svg_badge_escapes_titles() {
+    let app = spawn_app_with_auth().await;
+    start_reading(&app, "Pride & Prejudice", "2026-03-01", false).await;
+    put_form(&app, "/profile/visibility", &[("public", "true")]).await;
+
+    let response = get_widget(&app, "/u/admin/widget.svg").await;
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "image/svg+xml");
+    let body = response.text().await.unwrap();
+    assert!(body.starts_with("<svg"));
+    assert!(body.contains("Pride &#38; Prejudice") || body.contains("Pride &amp; Prejudice"));
+}
+
+#[tokio::test]
+async fn widgets_revalidate_with_etags() {
+    let app = spawn_app_with_auth().await;
+    start_reading(&app, "Dune", "2026-01-05", false).await;
+    put_form(&app, "/profile/visibility", &[("public", "true")]).await;
+
+    let response = get_widget(&app, "/u/admin/widget").await;
+    let etag = response.headers()[header::ETAG]
+        .to_str()
+        .unwrap()
+        .to_string();
+    assert!(
+        response.headers()[header::CACHE_CONTROL]
+            .to_str()
+            .unwrap()
+            .starts_with("public")
+    );
+
+    let revalidate = || {
+        Client::new()
+            .get(app.page_url("/u/admin/widget"))
+            .header(header::IF_NONE_MATCH, &etag)
+            .send()
+    };
+    let response = revalidate().await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.headers()[header::ETAG], etag.as_str());
+
+    start_reading(&app, "Hyperion", "2026-02-01", false).await;
+    let response = revalidate().await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_ne!(response.headers()[header::ETAG], etag.as_str());
+}